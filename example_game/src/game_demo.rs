@@ -358,7 +358,8 @@ impl GemCollectorPawn {
         }
 
         // Overlap delegate for gem collection
-        let binding = actor_ref.checked()?.on_actor_begin_overlap().add(move |_self_actor, other| {
+        let binding = actor_ref.checked()?.on_actor_begin_overlap().add(move |overlap| {
+            let other = overlap.other_actor;
             let handle = other.raw();
             if other.cast::<CollectibleGem>().is_ok() {
                 let a: UObjectRef<Actor> = unsafe { UObjectRef::from_raw(handle) };
@@ -743,7 +743,7 @@ impl UikaTestRunner {
             let t = identity_transform();
             let spawned: UObjectRef<Actor> = world.spawn_actor(&t)?;
             let sc = spawned.checked()?;
-            let _binding = sc.on_end_play().add(|_actor, _reason| {
+            let _binding = sc.on_end_play().add(|_payload| {
                 // Callback body
             })?;
             sc.k2_destroy_actor();
@@ -765,7 +765,7 @@ impl UikaTestRunner {
 
         run_test!(self, "J4: on_overlap_binds", {
             let c = self_ref.checked()?;
-            let _binding = c.on_actor_begin_overlap().add(|_overlapped, _other| {
+            let _binding = c.on_actor_begin_overlap().add(|_payload| {
                 // Just verify binding
             })?;
             // Drop the binding to clean up
@@ -40,6 +40,7 @@ void UUikaReifiedClass::UikaClassConstructor(const FObjectInitializer& ObjectIni
     if (ReifiedClass->ComponentDefs.Num() > 0)
     {
         TMap<FName, USceneComponent*> CreatedComponents;
+        TArray<USceneComponent*> AttachToRoot;
 
         for (const FUikaComponentDef& Def : ReifiedClass->ComponentDefs)
         {
@@ -63,6 +64,11 @@ void UUikaReifiedClass::UikaClassConstructor(const FObjectInitializer& ObjectIni
                     if (SceneComp) Actor->SetRootComponent(SceneComp);
                 }
             }
+            else if (Def.bAttachToRoot)
+            {
+                // Deferred: the root may be registered later in ComponentDefs.
+                if (SceneComp) AttachToRoot.Add(SceneComp);
+            }
             else if (Def.AttachParentName != NAME_None)
             {
                 if (SceneComp)
@@ -74,6 +80,18 @@ void UUikaReifiedClass::UikaClassConstructor(const FObjectInitializer& ObjectIni
                 }
             }
         }
+
+        if (AActor* Actor = Cast<AActor>(Obj))
+        {
+            USceneComponent* Root = Actor->GetRootComponent();
+            for (USceneComponent* SceneComp : AttachToRoot)
+            {
+                if (Root && SceneComp != Root)
+                {
+                    SceneComp->SetupAttachment(Root);
+                }
+            }
+        }
     }
 
     // 4. Notify Rust to construct its instance data.
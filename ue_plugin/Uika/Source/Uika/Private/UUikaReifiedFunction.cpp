@@ -3,6 +3,37 @@
 #include "UikaApiTable.h"
 #include "UikaModule.h"
 
+// Run an RPC's `_Validate` function against the RPC's params. The validate
+// function was registered with the same params in the same order plus a bool
+// ReturnValue, so the RPC's params are a prefix of its layout.
+static bool RunValidateFunction(
+    const UUikaReifiedFunction* Rpc,
+    const UUikaReifiedFunction* Validate,
+    UObject* Object,
+    const uint8* ParamsPtr,
+    const FUikaRustCallbacks* Callbacks)
+{
+    FBoolProperty* RetProp = CastField<FBoolProperty>(Validate->GetReturnProperty());
+    if (!RetProp || !Callbacks || !Callbacks->invoke_rust_function)
+    {
+        return true;
+    }
+
+    uint8* ValidateParams = (uint8*)FMemory_Alloca(FMath::Max<int32>(Validate->ParmsSize, 1));
+    FMemory::Memzero(ValidateParams, Validate->ParmsSize);
+    if (ParamsPtr && Rpc->ParmsSize > 0)
+    {
+        FMemory::Memcpy(ValidateParams, ParamsPtr, FMath::Min(Rpc->ParmsSize, Validate->ParmsSize));
+    }
+
+    Callbacks->invoke_rust_function(
+        Validate->CallbackId,
+        UikaUObjectHandle{ Object },
+        ValidateParams);
+
+    return RetProp->GetPropertyValue_InContainer(ValidateParams);
+}
+
 DEFINE_FUNCTION(UUikaReifiedFunction::execCallRustFunction)
 {
     // ---------------------------------------------------------------
@@ -120,7 +151,19 @@ DEFINE_FUNCTION(UUikaReifiedFunction::execCallRustFunction)
     // ---------------------------------------------------------------
 
     const FUikaRustCallbacks* Callbacks = GetUikaRustCallbacks();
-    if (Callbacks && Callbacks->invoke_rust_function)
+
+    // RPCs declared WithValidation: reject the call if `_Validate` fails.
+    bool bValid = true;
+    if (ReifiedFunc->ValidateFunction)
+    {
+        bValid = RunValidateFunction(ReifiedFunc, ReifiedFunc->ValidateFunction, P_THIS, ParamsPtr, Callbacks);
+        if (!bValid)
+        {
+            RPC_ValidateFailed(*FString::Printf(TEXT("%s_Validate"), *ReifiedFunc->GetName()));
+        }
+    }
+
+    if (bValid && Callbacks && Callbacks->invoke_rust_function)
     {
         Callbacks->invoke_rust_function(
             ReifiedFunc->CallbackId,
@@ -129,7 +172,7 @@ DEFINE_FUNCTION(UUikaReifiedFunction::execCallRustFunction)
     }
 
     // Copy return value to RESULT_PARAM.
-    if (RESULT_PARAM && ParamsPtr)
+    if (bValid && RESULT_PARAM && ParamsPtr)
     {
         if (FProperty* RetProp = ReifiedFunc->GetReturnProperty())
         {
@@ -0,0 +1,190 @@
+// UikaAssetApiImpl.cpp — FUikaAssetApi implementation.
+// Async loading through the asset manager's FStreamableManager.
+
+#include "UikaApiTable.h"
+#include "Engine/AssetManager.h"
+#include "Engine/StreamableManager.h"
+
+extern const FUikaRustCallbacks* GetUikaRustCallbacks();
+
+// Live requests, keyed by the ID handed to Rust. Holding the handle keeps the
+// loaded asset referenced until Rust cancels (releases) the request.
+static TMap<uint64, TSharedPtr<FStreamableHandle>> GAssetRequests;
+static uint64 GNextRequestId = 1;
+
+// The request RequestAsyncLoad is running for, and whether its delegate
+// already fired there.
+static uint64 GRequestingId = 0;
+static bool GRequestCompletedEarly = false;
+
+static FStreamableManager& GetStreamableManager()
+{
+    if (UAssetManager::IsInitialized())
+    {
+        return UAssetManager::GetStreamableManager();
+    }
+    static FStreamableManager Fallback;
+    return Fallback;
+}
+
+static void NotifyLoaded(uint64 CallbackId, uint64 RequestId)
+{
+    const FUikaRustCallbacks* Callbacks = GetUikaRustCallbacks();
+    if (Callbacks && Callbacks->invoke_delegate_callback)
+    {
+        uint64 Id = RequestId;
+        Callbacks->invoke_delegate_callback(CallbackId, reinterpret_cast<uint8*>(&Id));
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Implementations
+// ---------------------------------------------------------------------------
+
+static uint64 RequestLoadImpl(const uint8* PathUtf8, uint32 PathLen, uint64 CallbackId)
+{
+    const FSoftObjectPath Path(FString(PathLen, UTF8_TO_TCHAR(reinterpret_cast<const char*>(PathUtf8))));
+    if (Path.IsNull())
+    {
+        return 0;
+    }
+
+    const uint64 RequestId = GNextRequestId++;
+    FStreamableDelegate OnLoaded;
+    if (CallbackId != 0)
+    {
+        OnLoaded = FStreamableDelegate::CreateLambda([CallbackId, RequestId]()
+        {
+            // Fired from inside RequestAsyncLoad (asset already loaded): the
+            // request is not registered yet, so let RequestLoadImpl notify.
+            if (RequestId == GRequestingId)
+            {
+                GRequestCompletedEarly = true;
+                return;
+            }
+            NotifyLoaded(CallbackId, RequestId);
+        });
+    }
+
+    GRequestingId = RequestId;
+    GRequestCompletedEarly = false;
+    TSharedPtr<FStreamableHandle> Handle = GetStreamableManager().RequestAsyncLoad(Path, OnLoaded);
+    GRequestingId = 0;
+
+    if (!Handle.IsValid() || Handle->WasCanceled())
+    {
+        return 0;
+    }
+    // A handle that already completed is kept too: it holds the asset until
+    // Rust releases the request.
+    GAssetRequests.Add(RequestId, Handle);
+    if (GRequestCompletedEarly)
+    {
+        GRequestCompletedEarly = false;
+        NotifyLoaded(CallbackId, RequestId);
+    }
+    return RequestId;
+}
+
+static uint8 PollLoadImpl(uint64 Request, UikaUObjectHandle* OutObject)
+{
+    const TSharedPtr<FStreamableHandle>* Found = GAssetRequests.Find(Request);
+    if (!Found || !Found->IsValid() || (*Found)->WasCanceled())
+    {
+        return 2;
+    }
+    if (!(*Found)->HasLoadCompleted())
+    {
+        return 0;
+    }
+    UObject* Loaded = (*Found)->GetLoadedAsset();
+    if (!Loaded)
+    {
+        return 2;
+    }
+    if (OutObject)
+    {
+        *OutObject = UikaUObjectHandle{ Loaded };
+    }
+    return 1;
+}
+
+static EUikaErrorCode CancelLoadImpl(uint64 Request)
+{
+    TSharedPtr<FStreamableHandle> Handle;
+    if (!GAssetRequests.RemoveAndCopyValue(Request, Handle))
+    {
+        return EUikaErrorCode::InvalidOperation;
+    }
+    if (Handle.IsValid())
+    {
+        if (Handle->IsLoadingInProgress())
+        {
+            Handle->CancelHandle();
+        }
+        else
+        {
+            Handle->ReleaseHandle();
+        }
+    }
+    return EUikaErrorCode::Ok;
+}
+
+static UikaUObjectHandle ResolvePathImpl(const uint8* PathUtf8, uint32 PathLen)
+{
+    const FSoftObjectPath Path(FString(PathLen, UTF8_TO_TCHAR(reinterpret_cast<const char*>(PathUtf8))));
+    return UikaUObjectHandle{ Path.IsNull() ? nullptr : Path.ResolveObject() };
+}
+
+static EUikaErrorCode GetObjectPathImpl(UikaUObjectHandle Obj, uint8* Buf, uint32 BufLen, uint32* OutLen)
+{
+    UObject* Object = static_cast<UObject*>(Obj.ptr);
+    if (!::IsValid(Object))
+    {
+        return EUikaErrorCode::ObjectDestroyed;
+    }
+    const FString Path = FSoftObjectPath(Object).ToString();
+    const FTCHARToUTF8 Utf8(*Path);
+    const uint32 Len = static_cast<uint32>(Utf8.Length());
+    if (OutLen)
+    {
+        *OutLen = Len;
+    }
+    if (Len > BufLen)
+    {
+        return EUikaErrorCode::BufferTooSmall;
+    }
+    if (Buf)
+    {
+        FMemory::Memcpy(Buf, Utf8.Get(), Len);
+    }
+    return EUikaErrorCode::Ok;
+}
+
+// ---------------------------------------------------------------------------
+// Module helpers
+// ---------------------------------------------------------------------------
+
+void UikaAssetCancelAll()
+{
+    for (TPair<uint64, TSharedPtr<FStreamableHandle>>& Pair : GAssetRequests)
+    {
+        if (Pair.Value.IsValid() && Pair.Value->IsLoadingInProgress())
+        {
+            Pair.Value->CancelHandle();
+        }
+    }
+    GAssetRequests.Empty();
+}
+
+// ---------------------------------------------------------------------------
+// Static instance
+// ---------------------------------------------------------------------------
+
+FUikaAssetApi GAssetApi = {
+    &RequestLoadImpl,
+    &PollLoadImpl,
+    &CancelLoadImpl,
+    &ResolvePathImpl,
+    &GetObjectPathImpl,
+};
@@ -0,0 +1,107 @@
+// UikaAssetRegistryApiImpl.cpp — FUikaAssetRegistryApi implementation.
+// Read-only asset registry queries; results cross as tab-separated UTF-8 lines.
+
+#include "UikaApiTable.h"
+#include "AssetRegistry/AssetRegistryModule.h"
+#include "AssetRegistry/IAssetRegistry.h"
+
+static FString Utf8ToFStr(const uint8* Buf, uint32 Len)
+{
+    return FString(Len, UTF8_TO_TCHAR(reinterpret_cast<const char*>(Buf)));
+}
+
+// Serialize `Assets` into `Buf` (see FUikaAssetRegistryApi).
+static EUikaErrorCode WriteAssets(const TArray<FAssetData>& Assets, uint8* Buf, uint32 BufLen, uint32* OutLen)
+{
+    if (!OutLen)
+    {
+        return EUikaErrorCode::NullArgument;
+    }
+    FString Text;
+    for (const FAssetData& Asset : Assets)
+    {
+        Text += Asset.GetSoftObjectPath().ToString();
+        Text += TEXT('\t');
+        Text += Asset.AssetClassPath.ToString();
+        Text += TEXT('\t');
+        Text += Asset.PackageName.ToString();
+        Text += TEXT('\n');
+    }
+
+    FTCHARToUTF8 Utf8(*Text);
+    *OutLen = static_cast<uint32>(Utf8.Length());
+    if (*OutLen > BufLen || (*OutLen > 0 && !Buf))
+    {
+        return EUikaErrorCode::BufferTooSmall;
+    }
+    FMemory::Memcpy(Buf, Utf8.Get(), *OutLen);
+    return EUikaErrorCode::Ok;
+}
+
+static IAssetRegistry& Registry()
+{
+    return FModuleManager::LoadModuleChecked<FAssetRegistryModule>(TEXT("AssetRegistry")).Get();
+}
+
+// ---------------------------------------------------------------------------
+// Implementations
+// ---------------------------------------------------------------------------
+
+static EUikaErrorCode GetAssetsByClassImpl(const uint8* ClassPath, uint32 ClassPathLen,
+    bool bSearchSubClasses, uint8* Buf, uint32 BufLen, uint32* OutLen)
+{
+    if (!ClassPath)
+    {
+        return EUikaErrorCode::NullArgument;
+    }
+    const FTopLevelAssetPath Path(Utf8ToFStr(ClassPath, ClassPathLen));
+    if (Path.IsNull())
+    {
+        return EUikaErrorCode::InvalidOperation;
+    }
+    TArray<FAssetData> Assets;
+    Registry().GetAssetsByClass(Path, Assets, bSearchSubClasses);
+    return WriteAssets(Assets, Buf, BufLen, OutLen);
+}
+
+static EUikaErrorCode GetAssetsByPathImpl(const uint8* PackagePath, uint32 PathLen, bool bRecursive,
+    uint8* Buf, uint32 BufLen, uint32* OutLen)
+{
+    if (!PackagePath)
+    {
+        return EUikaErrorCode::NullArgument;
+    }
+    TArray<FAssetData> Assets;
+    Registry().GetAssetsByPath(FName(Utf8ToFStr(PackagePath, PathLen)), Assets, bRecursive);
+    return WriteAssets(Assets, Buf, BufLen, OutLen);
+}
+
+static EUikaErrorCode GetAssetsByTagValueImpl(const uint8* Tag, uint32 TagLen,
+    const uint8* Value, uint32 ValueLen, uint8* Buf, uint32 BufLen, uint32* OutLen)
+{
+    if (!Tag || !Value)
+    {
+        return EUikaErrorCode::NullArgument;
+    }
+    TMultiMap<FName, FString> TagValues;
+    TagValues.Add(FName(Utf8ToFStr(Tag, TagLen)), Utf8ToFStr(Value, ValueLen));
+    TArray<FAssetData> Assets;
+    Registry().GetAssetsByTagValues(TagValues, Assets);
+    return WriteAssets(Assets, Buf, BufLen, OutLen);
+}
+
+static bool IsLoadingAssetsImpl()
+{
+    return Registry().IsLoadingAssets();
+}
+
+// ---------------------------------------------------------------------------
+// Static instance
+// ---------------------------------------------------------------------------
+
+FUikaAssetRegistryApi GAssetRegistryApi = {
+    &GetAssetsByClassImpl,
+    &GetAssetsByPathImpl,
+    &GetAssetsByTagValueImpl,
+    &IsLoadingAssetsImpl,
+};
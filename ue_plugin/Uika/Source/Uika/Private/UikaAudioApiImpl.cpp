@@ -0,0 +1,184 @@
+// UikaAudioApiImpl.cpp — FUikaAudioApi implementation.
+// Fire-and-forget sounds via UGameplayStatics, plus control of the
+// UAudioComponents spawned for Rust.
+
+#include "UikaApiTable.h"
+#include "UikaFNameHelper.h"
+#include "Components/AudioComponent.h"
+#include "Components/SceneComponent.h"
+#include "Kismet/GameplayStatics.h"
+#include "Sound/SoundBase.h"
+
+static USoundBase* ToSound(UikaUObjectHandle Sound)
+{
+    USoundBase* Result = Cast<USoundBase>(static_cast<UObject*>(Sound.ptr));
+    return ::IsValid(Result) ? Result : nullptr;
+}
+
+static UAudioComponent* ToAudioComponent(UikaUObjectHandle Component)
+{
+    UAudioComponent* Result = Cast<UAudioComponent>(static_cast<UObject*>(Component.ptr));
+    return ::IsValid(Result) ? Result : nullptr;
+}
+
+static FVector ToVector(const double* Xyz)
+{
+    return FVector(Xyz[0], Xyz[1], Xyz[2]);
+}
+
+// ---------------------------------------------------------------------------
+// Implementations
+// ---------------------------------------------------------------------------
+
+static EUikaErrorCode PlaySound2DImpl(UikaUObjectHandle Context, UikaUObjectHandle Sound,
+    float Volume, float Pitch, float StartTime)
+{
+    UObject* ContextObject = static_cast<UObject*>(Context.ptr);
+    USoundBase* SoundBase = ToSound(Sound);
+    if (!::IsValid(ContextObject) || !SoundBase)
+    {
+        return EUikaErrorCode::InvalidCast;
+    }
+    UGameplayStatics::PlaySound2D(ContextObject, SoundBase, Volume, Pitch, StartTime);
+    return EUikaErrorCode::Ok;
+}
+
+static EUikaErrorCode PlaySoundAtLocationImpl(UikaUObjectHandle Context, UikaUObjectHandle Sound,
+    const double* Location, float Volume, float Pitch, float StartTime)
+{
+    UObject* ContextObject = static_cast<UObject*>(Context.ptr);
+    USoundBase* SoundBase = ToSound(Sound);
+    if (!Location)
+    {
+        return EUikaErrorCode::NullArgument;
+    }
+    if (!::IsValid(ContextObject) || !SoundBase)
+    {
+        return EUikaErrorCode::InvalidCast;
+    }
+    UGameplayStatics::PlaySoundAtLocation(ContextObject, SoundBase, ToVector(Location), Volume, Pitch, StartTime);
+    return EUikaErrorCode::Ok;
+}
+
+static UikaUObjectHandle SpawnSound2DImpl(UikaUObjectHandle Context, UikaUObjectHandle Sound,
+    float Volume, float Pitch, float StartTime, bool bAutoDestroy)
+{
+    UObject* ContextObject = static_cast<UObject*>(Context.ptr);
+    USoundBase* SoundBase = ToSound(Sound);
+    if (!::IsValid(ContextObject) || !SoundBase)
+    {
+        return UikaUObjectHandle{ nullptr };
+    }
+    UAudioComponent* Component = UGameplayStatics::SpawnSound2D(ContextObject, SoundBase,
+        Volume, Pitch, StartTime, nullptr, false, bAutoDestroy);
+    return UikaUObjectHandle{ Component };
+}
+
+static UikaUObjectHandle SpawnSoundAtLocationImpl(UikaUObjectHandle Context, UikaUObjectHandle Sound,
+    const double* Location, float Volume, float Pitch, float StartTime, bool bAutoDestroy)
+{
+    UObject* ContextObject = static_cast<UObject*>(Context.ptr);
+    USoundBase* SoundBase = ToSound(Sound);
+    if (!Location || !::IsValid(ContextObject) || !SoundBase)
+    {
+        return UikaUObjectHandle{ nullptr };
+    }
+    UAudioComponent* Component = UGameplayStatics::SpawnSoundAtLocation(ContextObject, SoundBase,
+        ToVector(Location), FRotator::ZeroRotator, Volume, Pitch, StartTime,
+        nullptr, nullptr, bAutoDestroy);
+    return UikaUObjectHandle{ Component };
+}
+
+static UikaUObjectHandle SpawnSoundAttachedImpl(UikaUObjectHandle Sound, UikaUObjectHandle AttachTo,
+    UikaFNameHandle Socket, float Volume, float Pitch, float StartTime, bool bAutoDestroy)
+{
+    USoundBase* SoundBase = ToSound(Sound);
+    USceneComponent* Parent = Cast<USceneComponent>(static_cast<UObject*>(AttachTo.ptr));
+    if (!SoundBase || !::IsValid(Parent))
+    {
+        return UikaUObjectHandle{ nullptr };
+    }
+    UAudioComponent* Component = UGameplayStatics::SpawnSoundAttached(SoundBase, Parent,
+        UikaUnpackFName(Socket.value), FVector::ZeroVector, EAttachLocation::KeepRelativeOffset,
+        false, Volume, Pitch, StartTime, nullptr, nullptr, bAutoDestroy);
+    return UikaUObjectHandle{ Component };
+}
+
+static EUikaErrorCode StopImpl(UikaUObjectHandle Component)
+{
+    UAudioComponent* Audio = ToAudioComponent(Component);
+    if (!Audio)
+    {
+        return EUikaErrorCode::ObjectDestroyed;
+    }
+    Audio->Stop();
+    return EUikaErrorCode::Ok;
+}
+
+static EUikaErrorCode FadeInImpl(UikaUObjectHandle Component, float Duration, float Volume, float StartTime)
+{
+    UAudioComponent* Audio = ToAudioComponent(Component);
+    if (!Audio)
+    {
+        return EUikaErrorCode::ObjectDestroyed;
+    }
+    Audio->FadeIn(Duration, Volume, StartTime);
+    return EUikaErrorCode::Ok;
+}
+
+static EUikaErrorCode FadeOutImpl(UikaUObjectHandle Component, float Duration, float Volume)
+{
+    UAudioComponent* Audio = ToAudioComponent(Component);
+    if (!Audio)
+    {
+        return EUikaErrorCode::ObjectDestroyed;
+    }
+    Audio->FadeOut(Duration, Volume);
+    return EUikaErrorCode::Ok;
+}
+
+static EUikaErrorCode SetVolumeImpl(UikaUObjectHandle Component, float Volume)
+{
+    UAudioComponent* Audio = ToAudioComponent(Component);
+    if (!Audio)
+    {
+        return EUikaErrorCode::ObjectDestroyed;
+    }
+    Audio->SetVolumeMultiplier(Volume);
+    return EUikaErrorCode::Ok;
+}
+
+static EUikaErrorCode SetPitchImpl(UikaUObjectHandle Component, float Pitch)
+{
+    UAudioComponent* Audio = ToAudioComponent(Component);
+    if (!Audio)
+    {
+        return EUikaErrorCode::ObjectDestroyed;
+    }
+    Audio->SetPitchMultiplier(Pitch);
+    return EUikaErrorCode::Ok;
+}
+
+static bool IsPlayingImpl(UikaUObjectHandle Component)
+{
+    UAudioComponent* Audio = ToAudioComponent(Component);
+    return Audio && Audio->IsPlaying();
+}
+
+// ---------------------------------------------------------------------------
+// Static instance
+// ---------------------------------------------------------------------------
+
+FUikaAudioApi GAudioApi = {
+    &PlaySound2DImpl,
+    &PlaySoundAtLocationImpl,
+    &SpawnSound2DImpl,
+    &SpawnSoundAtLocationImpl,
+    &SpawnSoundAttachedImpl,
+    &StopImpl,
+    &FadeInImpl,
+    &FadeOutImpl,
+    &SetVolumeImpl,
+    &SetPitchImpl,
+    &IsPlayingImpl,
+};
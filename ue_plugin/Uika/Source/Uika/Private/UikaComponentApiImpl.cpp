@@ -0,0 +1,149 @@
+// UikaComponentApiImpl.cpp — FUikaComponentApi implementation.
+// Components created from Rust are registered as instance components, the
+// same way the editor's "Add Component" and Blueprint's AddComponent do, so
+// they are serialized with the actor and show up in its component list.
+
+#include "UikaApiTable.h"
+#include "UikaFNameHelper.h"
+#include "Components/ActorComponent.h"
+#include "Components/SceneComponent.h"
+#include "GameFramework/Actor.h"
+
+static AActor* ToActor(UikaUObjectHandle Handle)
+{
+    AActor* Actor = Cast<AActor>(static_cast<UObject*>(Handle.ptr));
+    return ::IsValid(Actor) ? Actor : nullptr;
+}
+
+static USceneComponent* ToSceneComponent(UikaUObjectHandle Handle)
+{
+    USceneComponent* Component = Cast<USceneComponent>(static_cast<UObject*>(Handle.ptr));
+    return ::IsValid(Component) ? Component : nullptr;
+}
+
+static bool IsValidRule(uint8 Rule)
+{
+    return Rule <= static_cast<uint8>(EAttachmentRule::SnapToTarget);
+}
+
+static FAttachmentTransformRules MakeRules(uint8 LocationRule, uint8 RotationRule, uint8 ScaleRule, bool bWeld)
+{
+    return FAttachmentTransformRules(
+        static_cast<EAttachmentRule>(LocationRule),
+        static_cast<EAttachmentRule>(RotationRule),
+        static_cast<EAttachmentRule>(ScaleRule),
+        bWeld);
+}
+
+// ---------------------------------------------------------------------------
+// Implementations
+// ---------------------------------------------------------------------------
+
+static UikaUObjectHandle AddComponentImpl(UikaUObjectHandle ActorHandle, UikaUClassHandle ClsHandle,
+    const uint8* Name, uint32 NameLen)
+{
+    AActor* Actor = ToActor(ActorHandle);
+    UClass* Class = static_cast<UClass*>(ClsHandle.ptr);
+    if (!Actor || !Class || !Class->IsChildOf(UActorComponent::StaticClass())
+        || Class->HasAnyClassFlags(CLASS_Abstract))
+    {
+        return UikaUObjectHandle{ nullptr };
+    }
+
+    FName ComponentName = NAME_None;
+    if (Name && NameLen > 0)
+    {
+        ComponentName = FName(FString(NameLen, UTF8_TO_TCHAR(reinterpret_cast<const char*>(Name))));
+        if (StaticFindObjectFast(nullptr, Actor, ComponentName))
+        {
+            return UikaUObjectHandle{ nullptr };
+        }
+    }
+
+    UActorComponent* Component = NewObject<UActorComponent>(Actor, Class, ComponentName, RF_Transactional);
+    if (USceneComponent* Scene = Cast<USceneComponent>(Component))
+    {
+        if (USceneComponent* Root = Actor->GetRootComponent())
+        {
+            Scene->SetupAttachment(Root);
+        }
+        else
+        {
+            Actor->SetRootComponent(Scene);
+        }
+    }
+    Actor->AddInstanceComponent(Component);
+    Component->RegisterComponent();
+    return UikaUObjectHandle{ Component };
+}
+
+static EUikaErrorCode AttachComponentImpl(UikaUObjectHandle ChildHandle, UikaUObjectHandle ParentHandle,
+    UikaFNameHandle Socket, uint8 LocationRule, uint8 RotationRule, uint8 ScaleRule, bool bWeld)
+{
+    USceneComponent* Child = ToSceneComponent(ChildHandle);
+    USceneComponent* Parent = ToSceneComponent(ParentHandle);
+    if (!Child || !Parent)
+    {
+        return EUikaErrorCode::InvalidCast;
+    }
+    if (!IsValidRule(LocationRule) || !IsValidRule(RotationRule) || !IsValidRule(ScaleRule))
+    {
+        return EUikaErrorCode::InvalidOperation;
+    }
+    const bool bAttached = Child->AttachToComponent(
+        Parent, MakeRules(LocationRule, RotationRule, ScaleRule, bWeld), UikaUnpackFName(Socket.value));
+    return bAttached ? EUikaErrorCode::Ok : EUikaErrorCode::InvalidOperation;
+}
+
+static EUikaErrorCode AttachActorImpl(UikaUObjectHandle ActorHandle, UikaUObjectHandle ParentHandle,
+    UikaFNameHandle Socket, uint8 LocationRule, uint8 RotationRule, uint8 ScaleRule, bool bWeld)
+{
+    AActor* Actor = ToActor(ActorHandle);
+    AActor* Parent = ToActor(ParentHandle);
+    if (!Actor || !Parent)
+    {
+        return EUikaErrorCode::InvalidCast;
+    }
+    if (!IsValidRule(LocationRule) || !IsValidRule(RotationRule) || !IsValidRule(ScaleRule))
+    {
+        return EUikaErrorCode::InvalidOperation;
+    }
+    const bool bAttached = Actor->AttachToActor(
+        Parent, MakeRules(LocationRule, RotationRule, ScaleRule, bWeld), UikaUnpackFName(Socket.value));
+    return bAttached ? EUikaErrorCode::Ok : EUikaErrorCode::InvalidOperation;
+}
+
+static EUikaErrorCode DetachImpl(UikaUObjectHandle Target, uint8 Rule)
+{
+    if (!IsValidRule(Rule))
+    {
+        return EUikaErrorCode::InvalidOperation;
+    }
+    // EDetachmentRule has no SnapToTarget: there is nothing to snap to.
+    const EDetachmentRule DetachRule = Rule == static_cast<uint8>(EAttachmentRule::KeepRelative)
+        ? EDetachmentRule::KeepRelative
+        : EDetachmentRule::KeepWorld;
+    const FDetachmentTransformRules Rules(DetachRule, /*bCallModify=*/ true);
+    if (AActor* Actor = ToActor(Target))
+    {
+        Actor->DetachFromActor(Rules);
+        return EUikaErrorCode::Ok;
+    }
+    if (USceneComponent* Component = ToSceneComponent(Target))
+    {
+        Component->DetachFromComponent(Rules);
+        return EUikaErrorCode::Ok;
+    }
+    return EUikaErrorCode::InvalidCast;
+}
+
+// ---------------------------------------------------------------------------
+// Static instance
+// ---------------------------------------------------------------------------
+
+FUikaComponentApi GComponentApi = {
+    &AddComponentImpl,
+    &AttachComponentImpl,
+    &AttachActorImpl,
+    &DetachImpl,
+};
@@ -0,0 +1,221 @@
+// UikaConfigApiImpl.cpp — FUikaConfigApi implementation.
+// Ini settings through GConfig, addressed by section + key + config file.
+
+#include "UikaApiTable.h"
+#include "Misc/ConfigCacheIni.h"
+
+static FString Utf8ToFStr(const uint8* Buf, uint32 Len)
+{
+    return FString(Len, UTF8_TO_TCHAR(reinterpret_cast<const char*>(Buf)));
+}
+
+// Map a config name to its global ini filename; anything else is used as-is.
+static FString ResolveIni(const uint8* File, uint32 FileLen)
+{
+    const FString Name = Utf8ToFStr(File, FileLen);
+    if (Name == TEXT("Engine"))           { return GEngineIni; }
+    if (Name == TEXT("Game"))             { return GGameIni; }
+    if (Name == TEXT("Input"))            { return GInputIni; }
+    if (Name == TEXT("GameUserSettings")) { return GGameUserSettingsIni; }
+    if (Name == TEXT("Editor"))           { return GEditorIni; }
+    return Name;
+}
+
+// Decoded section/key/file of one entry.
+struct FConfigEntry
+{
+    FString Section;
+    FString Key;
+    FString File;
+};
+
+static bool DecodeEntry(const uint8* Section, uint32 SectionLen, const uint8* Key, uint32 KeyLen,
+    const uint8* File, uint32 FileLen, FConfigEntry& Out)
+{
+    if (!GConfig || !Section || !Key || !File)
+    {
+        return false;
+    }
+    Out.Section = Utf8ToFStr(Section, SectionLen);
+    Out.Key = Utf8ToFStr(Key, KeyLen);
+    Out.File = ResolveIni(File, FileLen);
+    return true;
+}
+
+static EUikaErrorCode WriteUtf8(const FString& Text, uint8* Buf, uint32 BufLen, uint32* OutLen)
+{
+    if (!OutLen)
+    {
+        return EUikaErrorCode::NullArgument;
+    }
+    FTCHARToUTF8 Utf8(*Text);
+    *OutLen = static_cast<uint32>(Utf8.Length());
+    if (*OutLen > BufLen || (*OutLen > 0 && !Buf))
+    {
+        return EUikaErrorCode::BufferTooSmall;
+    }
+    FMemory::Memcpy(Buf, Utf8.Get(), *OutLen);
+    return EUikaErrorCode::Ok;
+}
+
+#define UIKA_DECODE_ENTRY(Entry) \
+    FConfigEntry Entry; \
+    if (!DecodeEntry(Section, SectionLen, Key, KeyLen, File, FileLen, Entry)) \
+    { \
+        return EUikaErrorCode::NullArgument; \
+    }
+
+// ---------------------------------------------------------------------------
+// Implementations
+// ---------------------------------------------------------------------------
+
+static EUikaErrorCode GetStringImpl(const uint8* Section, uint32 SectionLen, const uint8* Key, uint32 KeyLen,
+    const uint8* File, uint32 FileLen, uint8* Buf, uint32 BufLen, uint32* OutLen)
+{
+    UIKA_DECODE_ENTRY(Entry);
+    FString Value;
+    if (!GConfig->GetString(*Entry.Section, *Entry.Key, Value, Entry.File))
+    {
+        return EUikaErrorCode::PropertyNotFound;
+    }
+    return WriteUtf8(Value, Buf, BufLen, OutLen);
+}
+
+static EUikaErrorCode SetStringImpl(const uint8* Section, uint32 SectionLen, const uint8* Key, uint32 KeyLen,
+    const uint8* File, uint32 FileLen, const uint8* Value, uint32 ValueLen)
+{
+    UIKA_DECODE_ENTRY(Entry);
+    if (!Value && ValueLen > 0)
+    {
+        return EUikaErrorCode::NullArgument;
+    }
+    GConfig->SetString(*Entry.Section, *Entry.Key, *Utf8ToFStr(Value, ValueLen), Entry.File);
+    return EUikaErrorCode::Ok;
+}
+
+static EUikaErrorCode GetIntImpl(const uint8* Section, uint32 SectionLen, const uint8* Key, uint32 KeyLen,
+    const uint8* File, uint32 FileLen, int32* Out)
+{
+    UIKA_DECODE_ENTRY(Entry);
+    if (!Out)
+    {
+        return EUikaErrorCode::NullArgument;
+    }
+    return GConfig->GetInt(*Entry.Section, *Entry.Key, *Out, Entry.File)
+        ? EUikaErrorCode::Ok
+        : EUikaErrorCode::PropertyNotFound;
+}
+
+static EUikaErrorCode SetIntImpl(const uint8* Section, uint32 SectionLen, const uint8* Key, uint32 KeyLen,
+    const uint8* File, uint32 FileLen, int32 Value)
+{
+    UIKA_DECODE_ENTRY(Entry);
+    GConfig->SetInt(*Entry.Section, *Entry.Key, Value, Entry.File);
+    return EUikaErrorCode::Ok;
+}
+
+static EUikaErrorCode GetFloatImpl(const uint8* Section, uint32 SectionLen, const uint8* Key, uint32 KeyLen,
+    const uint8* File, uint32 FileLen, double* Out)
+{
+    UIKA_DECODE_ENTRY(Entry);
+    if (!Out)
+    {
+        return EUikaErrorCode::NullArgument;
+    }
+    return GConfig->GetDouble(*Entry.Section, *Entry.Key, *Out, Entry.File)
+        ? EUikaErrorCode::Ok
+        : EUikaErrorCode::PropertyNotFound;
+}
+
+static EUikaErrorCode SetFloatImpl(const uint8* Section, uint32 SectionLen, const uint8* Key, uint32 KeyLen,
+    const uint8* File, uint32 FileLen, double Value)
+{
+    UIKA_DECODE_ENTRY(Entry);
+    GConfig->SetDouble(*Entry.Section, *Entry.Key, Value, Entry.File);
+    return EUikaErrorCode::Ok;
+}
+
+static EUikaErrorCode GetBoolImpl(const uint8* Section, uint32 SectionLen, const uint8* Key, uint32 KeyLen,
+    const uint8* File, uint32 FileLen, bool* Out)
+{
+    UIKA_DECODE_ENTRY(Entry);
+    if (!Out)
+    {
+        return EUikaErrorCode::NullArgument;
+    }
+    return GConfig->GetBool(*Entry.Section, *Entry.Key, *Out, Entry.File)
+        ? EUikaErrorCode::Ok
+        : EUikaErrorCode::PropertyNotFound;
+}
+
+static EUikaErrorCode SetBoolImpl(const uint8* Section, uint32 SectionLen, const uint8* Key, uint32 KeyLen,
+    const uint8* File, uint32 FileLen, bool Value)
+{
+    UIKA_DECODE_ENTRY(Entry);
+    GConfig->SetBool(*Entry.Section, *Entry.Key, Value, Entry.File);
+    return EUikaErrorCode::Ok;
+}
+
+static EUikaErrorCode GetArrayImpl(const uint8* Section, uint32 SectionLen, const uint8* Key, uint32 KeyLen,
+    const uint8* File, uint32 FileLen, uint8* Buf, uint32 BufLen, uint32* OutLen)
+{
+    UIKA_DECODE_ENTRY(Entry);
+    TArray<FString> Values;
+    GConfig->GetArray(*Entry.Section, *Entry.Key, Values, Entry.File);
+    FString Text;
+    for (const FString& Value : Values)
+    {
+        Text += Value;
+        Text += TEXT('\n');
+    }
+    return WriteUtf8(Text, Buf, BufLen, OutLen);
+}
+
+static EUikaErrorCode SetArrayImpl(const uint8* Section, uint32 SectionLen, const uint8* Key, uint32 KeyLen,
+    const uint8* File, uint32 FileLen, const uint8* Values, uint32 ValuesLen)
+{
+    UIKA_DECODE_ENTRY(Entry);
+    if (!Values && ValuesLen > 0)
+    {
+        return EUikaErrorCode::NullArgument;
+    }
+    TArray<FString> Lines;
+    Utf8ToFStr(Values, ValuesLen).ParseIntoArray(Lines, TEXT("\n"), /*InCullEmpty=*/false);
+    // Entries are '\n'-terminated, so the last split piece is the empty tail.
+    if (Lines.Num() > 0 && Lines.Last().IsEmpty())
+    {
+        Lines.Pop();
+    }
+    GConfig->SetArray(*Entry.Section, *Entry.Key, Lines, Entry.File);
+    return EUikaErrorCode::Ok;
+}
+
+static EUikaErrorCode FlushImpl(const uint8* File, uint32 FileLen)
+{
+    if (!GConfig || !File)
+    {
+        return EUikaErrorCode::NullArgument;
+    }
+    GConfig->Flush(/*bRemoveFromCache=*/false, ResolveIni(File, FileLen));
+    return EUikaErrorCode::Ok;
+}
+
+#undef UIKA_DECODE_ENTRY
+
+// ---------------------------------------------------------------------------
+// Static instance
+// ---------------------------------------------------------------------------
+
+FUikaConfigApi GConfigApi = {
+    &GetStringImpl,
+    &SetStringImpl,
+    &GetIntImpl,
+    &SetIntImpl,
+    &GetFloatImpl,
+    &SetFloatImpl,
+    &GetBoolImpl,
+    &SetBoolImpl,
+    &GetArrayImpl,
+    &SetArrayImpl,
+    &FlushImpl,
+};
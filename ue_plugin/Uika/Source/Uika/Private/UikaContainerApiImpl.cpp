@@ -520,20 +520,12 @@ static EUikaErrorCode SetGetElementImpl(UikaUObjectHandle Obj, UikaFPropertyHand
 // Format: [u32 written_1][data_1][u32 written_2][data_2]...
 // For maps: [u32 key_written][key_data][u32 val_written][val_data] per pair.
 
-static EUikaErrorCode ArrayCopyAllImpl(
-    UikaUObjectHandle Obj, UikaFPropertyHandle Prop,
+// Copy elements [Start, Start + Count) of an array (range already validated).
+static EUikaErrorCode CopyArrayRange(FScriptArrayHelper& Helper, FProperty* Inner, int32 Start, int32 Count,
     uint8* OutBuf, uint32 BufSize, uint32* OutTotalWritten, int32* OutCount)
 {
-    UIKA_CHECK_VALID(Obj);
-    FArrayProperty* ArrayProp = CastField<FArrayProperty>(static_cast<FProperty*>(Prop.ptr));
-    if (!ArrayProp) return EUikaErrorCode::TypeMismatch;
-
-    FScriptArrayHelper Helper(ArrayProp, ArrayProp->ContainerPtrToValuePtr<void>(Object));
-    int32 Count = Helper.Num();
     if (OutCount) *OutCount = Count;
 
-    FProperty* Inner = ArrayProp->Inner;
-
     // Fast path: raw memcpy for fixed-size primitive types
     if (IsRawCopyableElement(Inner))
     {
@@ -546,7 +538,7 @@ static EUikaErrorCode ArrayCopyAllImpl(
         }
         if (Count > 0)
         {
-            FMemory::Memcpy(OutBuf, Helper.GetRawPtr(0), TotalSize);
+            FMemory::Memcpy(OutBuf, Helper.GetRawPtr(Start), TotalSize);
         }
         if (OutTotalWritten) *OutTotalWritten = TotalSize;
         if (OutCount) *OutCount = -Count;  // negative = raw format
@@ -572,7 +564,7 @@ static EUikaErrorCode ArrayCopyAllImpl(
 
         uint32 ElemWritten = 0;
         uint32 Remaining = BufSize - Offset - sizeof(uint32);
-        ReadElement(Inner, Helper.GetRawPtr(i),
+        ReadElement(Inner, Helper.GetRawPtr(Start + i),
                     OutBuf + Offset + sizeof(uint32), Remaining, &ElemWritten);
 
         if (Offset + sizeof(uint32) + ElemWritten > BufSize)
@@ -598,37 +590,25 @@ static EUikaErrorCode ArrayCopyAllImpl(
     return EUikaErrorCode::Ok;
 }
 
-static EUikaErrorCode ArraySetAllImpl(
-    UikaUObjectHandle Obj, UikaFPropertyHandle Prop,
+// Write Count elements from a flat buffer into [Start, Start + Count), which
+// the caller has already sized.
+static EUikaErrorCode WriteArrayRange(FScriptArrayHelper& Helper, FProperty* Inner, int32 Start,
     const uint8* InBuf, uint32 BufSize, int32 Count)
 {
-    UIKA_CHECK_VALID(Obj);
-    FArrayProperty* ArrayProp = CastField<FArrayProperty>(static_cast<FProperty*>(Prop.ptr));
-    if (!ArrayProp) return EUikaErrorCode::TypeMismatch;
-
-    FScriptArrayHelper Helper(ArrayProp, ArrayProp->ContainerPtrToValuePtr<void>(Object));
-    FProperty* Inner = ArrayProp->Inner;
-
     // Fast path: raw memcpy for fixed-size primitive types (negative count = raw format)
     if (Count < 0)
     {
         int32 ActualCount = -Count;
-        uint32 ElemSize = Inner->GetSize();
-        uint32 TotalSize = ActualCount * ElemSize;
+        uint32 TotalSize = ActualCount * Inner->GetSize();
         if (TotalSize > BufSize) return EUikaErrorCode::BufferTooSmall;
-        Helper.EmptyValues();
-        Helper.Resize(ActualCount);
         if (ActualCount > 0)
         {
-            FMemory::Memcpy(Helper.GetRawPtr(0), InBuf, TotalSize);
+            FMemory::Memcpy(Helper.GetRawPtr(Start), InBuf, TotalSize);
         }
         return EUikaErrorCode::Ok;
     }
 
     // Framed path: [u32 written][data] per element
-    Helper.EmptyValues();
-    Helper.Resize(Count);
-
     uint32 Offset = 0;
     for (int32 i = 0; i < Count; i++)
     {
@@ -646,33 +626,64 @@ static EUikaErrorCode ArraySetAllImpl(
             return EUikaErrorCode::BufferTooSmall;
         }
 
-        WriteElement(Inner, Helper.GetRawPtr(i), InBuf + Offset, ElemSize);
+        WriteElement(Inner, Helper.GetRawPtr(Start + i), InBuf + Offset, ElemSize);
         Offset += ElemSize;
     }
 
     return EUikaErrorCode::Ok;
 }
 
-static EUikaErrorCode MapCopyAllImpl(
+static EUikaErrorCode ArrayCopyAllImpl(
     UikaUObjectHandle Obj, UikaFPropertyHandle Prop,
     uint8* OutBuf, uint32 BufSize, uint32* OutTotalWritten, int32* OutCount)
 {
     UIKA_CHECK_VALID(Obj);
-    FMapProperty* MapProp = CastField<FMapProperty>(static_cast<FProperty*>(Prop.ptr));
-    if (!MapProp) return EUikaErrorCode::TypeMismatch;
+    FArrayProperty* ArrayProp = CastField<FArrayProperty>(static_cast<FProperty*>(Prop.ptr));
+    if (!ArrayProp) return EUikaErrorCode::TypeMismatch;
 
-    FScriptMapHelper Helper(MapProp, MapProp->ContainerPtrToValuePtr<void>(Object));
-    int32 Count = Helper.Num();
+    FScriptArrayHelper Helper(ArrayProp, ArrayProp->ContainerPtrToValuePtr<void>(Object));
+    return CopyArrayRange(Helper, ArrayProp->Inner, 0, Helper.Num(), OutBuf, BufSize, OutTotalWritten, OutCount);
+}
+
+static EUikaErrorCode ArraySetAllImpl(
+    UikaUObjectHandle Obj, UikaFPropertyHandle Prop,
+    const uint8* InBuf, uint32 BufSize, int32 Count)
+{
+    UIKA_CHECK_VALID(Obj);
+    FArrayProperty* ArrayProp = CastField<FArrayProperty>(static_cast<FProperty*>(Prop.ptr));
+    if (!ArrayProp) return EUikaErrorCode::TypeMismatch;
+
+    FScriptArrayHelper Helper(ArrayProp, ArrayProp->ContainerPtrToValuePtr<void>(Object));
+    if (Count < 0 && static_cast<uint32>(-Count) * ArrayProp->Inner->GetSize() > BufSize)
+    {
+        return EUikaErrorCode::BufferTooSmall;
+    }
+    Helper.EmptyValues();
+    Helper.Resize(FMath::Abs(Count));
+    return WriteArrayRange(Helper, ArrayProp->Inner, 0, InBuf, BufSize, Count);
+}
+
+// Copy up to Count pairs starting at the Start-th valid entry of a map.
+static EUikaErrorCode CopyMapRange(FScriptMapHelper& Helper, FMapProperty* MapProp, int32 Start, int32 Count,
+    uint8* OutBuf, uint32 BufSize, uint32* OutTotalWritten, int32* OutCount)
+{
     if (OutCount) *OutCount = Count;
 
     FProperty* KeyProp = MapProp->KeyProp;
     FProperty* ValueProp = MapProp->ValueProp;
     uint32 Offset = 0;
     int32 MaxIndex = Helper.GetMaxIndex();
+    int32 Skipped = 0;
+    int32 Copied = 0;
 
-    for (int32 i = 0; i < MaxIndex; ++i)
+    for (int32 i = 0; i < MaxIndex && Copied < Count; ++i)
     {
         if (!Helper.IsValidIndex(i)) continue;
+        if (Skipped < Start)
+        {
+            ++Skipped;
+            continue;
+        }
 
         // Key: [u32 written][data]
         if (Offset + sizeof(uint32) > BufSize)
@@ -715,31 +726,38 @@ static EUikaErrorCode MapCopyAllImpl(
 
         FMemory::Memcpy(OutBuf + Offset, &ValWritten, sizeof(uint32));
         Offset += sizeof(uint32) + ValWritten;
+        ++Copied;
     }
 
     if (OutTotalWritten) *OutTotalWritten = Offset;
     return EUikaErrorCode::Ok;
 }
 
-static EUikaErrorCode SetCopyAllImpl(
+static EUikaErrorCode MapCopyAllImpl(
     UikaUObjectHandle Obj, UikaFPropertyHandle Prop,
     uint8* OutBuf, uint32 BufSize, uint32* OutTotalWritten, int32* OutCount)
 {
     UIKA_CHECK_VALID(Obj);
-    FSetProperty* SetProp = CastField<FSetProperty>(static_cast<FProperty*>(Prop.ptr));
-    if (!SetProp) return EUikaErrorCode::TypeMismatch;
+    FMapProperty* MapProp = CastField<FMapProperty>(static_cast<FProperty*>(Prop.ptr));
+    if (!MapProp) return EUikaErrorCode::TypeMismatch;
 
-    FScriptSetHelper Helper(SetProp, SetProp->ContainerPtrToValuePtr<void>(Object));
-    int32 Count = Helper.Num();
-    if (OutCount) *OutCount = Count;
+    FScriptMapHelper Helper(MapProp, MapProp->ContainerPtrToValuePtr<void>(Object));
+    return CopyMapRange(Helper, MapProp, 0, Helper.Num(), OutBuf, BufSize, OutTotalWritten, OutCount);
+}
 
-    FProperty* ElementProp = SetProp->ElementProp;
+// Copy the elements of a set for which Keep returns true.
+static EUikaErrorCode CopySetElements(FScriptSetHelper& Helper, FProperty* ElementProp,
+    TFunctionRef<bool(const uint8*)> Keep, uint8* OutBuf, uint32 BufSize, uint32* OutTotalWritten, int32* OutCount)
+{
     uint32 Offset = 0;
+    int32 Count = 0;
     int32 MaxIndex = Helper.GetMaxIndex();
 
     for (int32 i = 0; i < MaxIndex; ++i)
     {
         if (!Helper.IsValidIndex(i)) continue;
+        const uint8* ElemPtr = Helper.GetElementPtr(i);
+        if (!Keep(ElemPtr)) continue;
 
         if (Offset + sizeof(uint32) > BufSize)
         {
@@ -749,7 +767,7 @@ static EUikaErrorCode SetCopyAllImpl(
 
         uint32 ElemWritten = 0;
         uint32 Remaining = BufSize - Offset - sizeof(uint32);
-        ReadElement(ElementProp, Helper.GetElementPtr(i),
+        ReadElement(ElementProp, ElemPtr,
                     OutBuf + Offset + sizeof(uint32), Remaining, &ElemWritten);
 
         if (Offset + sizeof(uint32) + ElemWritten > BufSize)
@@ -760,12 +778,27 @@ static EUikaErrorCode SetCopyAllImpl(
 
         FMemory::Memcpy(OutBuf + Offset, &ElemWritten, sizeof(uint32));
         Offset += sizeof(uint32) + ElemWritten;
+        ++Count;
     }
 
+    if (OutCount) *OutCount = Count;
     if (OutTotalWritten) *OutTotalWritten = Offset;
     return EUikaErrorCode::Ok;
 }
 
+static EUikaErrorCode SetCopyAllImpl(
+    UikaUObjectHandle Obj, UikaFPropertyHandle Prop,
+    uint8* OutBuf, uint32 BufSize, uint32* OutTotalWritten, int32* OutCount)
+{
+    UIKA_CHECK_VALID(Obj);
+    FSetProperty* SetProp = CastField<FSetProperty>(static_cast<FProperty*>(Prop.ptr));
+    if (!SetProp) return EUikaErrorCode::TypeMismatch;
+
+    FScriptSetHelper Helper(SetProp, SetProp->ContainerPtrToValuePtr<void>(Object));
+    return CopySetElements(Helper, SetProp->ElementProp, [](const uint8*) { return true; },
+                           OutBuf, BufSize, OutTotalWritten, OutCount);
+}
+
 // ---------------------------------------------------------------------------
 // Temp container allocation (for function params)
 // ---------------------------------------------------------------------------
@@ -801,6 +834,282 @@ static void FreeTempImpl(UikaFPropertyHandle Prop, void* Base)
     FMemory::Free(Base);
 }
 
+// ---------------------------------------------------------------------------
+// TArray range operations (FUikaArrayExtApi)
+// ---------------------------------------------------------------------------
+
+static EUikaErrorCode ArrayCopyRangeImpl(
+    UikaUObjectHandle Obj, UikaFPropertyHandle Prop, int32 Start, int32 Count,
+    uint8* OutBuf, uint32 BufSize, uint32* OutTotalWritten, int32* OutCount)
+{
+    UIKA_CHECK_VALID(Obj);
+    FArrayProperty* ArrayProp = CastField<FArrayProperty>(static_cast<FProperty*>(Prop.ptr));
+    if (!ArrayProp) return EUikaErrorCode::TypeMismatch;
+
+    FScriptArrayHelper Helper(ArrayProp, ArrayProp->ContainerPtrToValuePtr<void>(Object));
+    if (Start < 0 || Start > Helper.Num() || Count < 0)
+    {
+        return EUikaErrorCode::IndexOutOfRange;
+    }
+    const int32 Clamped = FMath::Min(Count, Helper.Num() - Start);
+    return CopyArrayRange(Helper, ArrayProp->Inner, Start, Clamped, OutBuf, BufSize, OutTotalWritten, OutCount);
+}
+
+static EUikaErrorCode ArrayAppendAllImpl(
+    UikaUObjectHandle Obj, UikaFPropertyHandle Prop,
+    const uint8* InBuf, uint32 BufSize, int32 Count)
+{
+    UIKA_CHECK_VALID(Obj);
+    FArrayProperty* ArrayProp = CastField<FArrayProperty>(static_cast<FProperty*>(Prop.ptr));
+    if (!ArrayProp) return EUikaErrorCode::TypeMismatch;
+
+    FScriptArrayHelper Helper(ArrayProp, ArrayProp->ContainerPtrToValuePtr<void>(Object));
+    const int32 Start = Helper.Num();
+    Helper.AddValues(FMath::Abs(Count));
+    const EUikaErrorCode Result = WriteArrayRange(Helper, ArrayProp->Inner, Start, InBuf, BufSize, Count);
+    if (Result != EUikaErrorCode::Ok)
+    {
+        // Don't leave half-written elements behind.
+        Helper.RemoveValues(Start, FMath::Abs(Count));
+    }
+    return Result;
+}
+
+static EUikaErrorCode ArrayRemoveRangeImpl(UikaUObjectHandle Obj, UikaFPropertyHandle Prop,
+    int32 Start, int32 Count)
+{
+    UIKA_CHECK_VALID(Obj);
+    FArrayProperty* ArrayProp = CastField<FArrayProperty>(static_cast<FProperty*>(Prop.ptr));
+    if (!ArrayProp) return EUikaErrorCode::TypeMismatch;
+
+    FScriptArrayHelper Helper(ArrayProp, ArrayProp->ContainerPtrToValuePtr<void>(Object));
+    if (Start < 0 || Count < 0 || Start > Helper.Num() - Count)
+    {
+        return EUikaErrorCode::IndexOutOfRange;
+    }
+    if (Count > 0)
+    {
+        Helper.RemoveValues(Start, Count);
+    }
+    return EUikaErrorCode::Ok;
+}
+
+// ---------------------------------------------------------------------------
+// TMap key operations (FUikaMapExtApi)
+// ---------------------------------------------------------------------------
+
+// A default-initialized temporary of one property's type, destroyed on scope exit.
+struct FUikaTempValue
+{
+    FProperty* Prop;
+    uint8* Data;
+
+    explicit FUikaTempValue(FProperty* InProp)
+        : Prop(InProp)
+        , Data(static_cast<uint8*>(FMemory::Malloc(InProp->GetSize(), InProp->GetMinAlignment())))
+    {
+        Prop->InitializeValue(Data);
+    }
+
+    ~FUikaTempValue()
+    {
+        Prop->DestroyValue(Data);
+        FMemory::Free(Data);
+    }
+};
+
+static bool MapContainsKeyImpl(UikaUObjectHandle Obj, UikaFPropertyHandle Prop,
+                               const uint8* KeyBuf, uint32 KeySize)
+{
+    UObject* Object = static_cast<UObject*>(Obj.ptr);
+    if (!::IsValid(Object)) return false;
+
+    FMapProperty* MapProp = CastField<FMapProperty>(static_cast<FProperty*>(Prop.ptr));
+    if (!MapProp) return false;
+
+    FScriptMapHelper Helper(MapProp, MapProp->ContainerPtrToValuePtr<void>(Object));
+    FUikaTempValue Key(MapProp->KeyProp);
+    WriteElement(MapProp->KeyProp, Key.Data, KeyBuf, KeySize);
+    return Helper.FindMapIndexWithKey(Key.Data) != INDEX_NONE;
+}
+
+static EUikaErrorCode MapFindOrAddImpl(UikaUObjectHandle Obj, UikaFPropertyHandle Prop,
+                                       const uint8* KeyBuf, uint32 KeySize,
+                                       const uint8* DefaultBuf, uint32 DefaultSize,
+                                       uint8* OutValBuf, uint32 ValSize, uint32* OutWritten, bool* OutAdded)
+{
+    UIKA_CHECK_VALID(Obj);
+    FMapProperty* MapProp = CastField<FMapProperty>(static_cast<FProperty*>(Prop.ptr));
+    if (!MapProp) return EUikaErrorCode::TypeMismatch;
+    if (!OutAdded) return EUikaErrorCode::NullArgument;
+
+    FScriptMapHelper Helper(MapProp, MapProp->ContainerPtrToValuePtr<void>(Object));
+    FUikaTempValue Key(MapProp->KeyProp);
+    WriteElement(MapProp->KeyProp, Key.Data, KeyBuf, KeySize);
+
+    const uint8* ValuePtr = Helper.FindValueFromHash(Key.Data);
+    *OutAdded = ValuePtr == nullptr;
+    if (*OutAdded)
+    {
+        FUikaTempValue Default(MapProp->ValueProp);
+        WriteElement(MapProp->ValueProp, Default.Data, DefaultBuf, DefaultSize);
+        Helper.AddPair(Key.Data, Default.Data);
+        ValuePtr = Helper.FindValueFromHash(Key.Data);
+    }
+
+    ReadElement(MapProp->ValueProp, ValuePtr, OutValBuf, ValSize, OutWritten);
+    return EUikaErrorCode::Ok;
+}
+
+static EUikaErrorCode MapRemoveAndCopyImpl(UikaUObjectHandle Obj, UikaFPropertyHandle Prop,
+                                           const uint8* KeyBuf, uint32 KeySize,
+                                           uint8* OutValBuf, uint32 ValSize, uint32* OutWritten)
+{
+    UIKA_CHECK_VALID(Obj);
+    FMapProperty* MapProp = CastField<FMapProperty>(static_cast<FProperty*>(Prop.ptr));
+    if (!MapProp) return EUikaErrorCode::TypeMismatch;
+
+    FScriptMapHelper Helper(MapProp, MapProp->ContainerPtrToValuePtr<void>(Object));
+    FUikaTempValue Key(MapProp->KeyProp);
+    WriteElement(MapProp->KeyProp, Key.Data, KeyBuf, KeySize);
+
+    const int32 Index = Helper.FindMapIndexWithKey(Key.Data);
+    if (Index == INDEX_NONE)
+    {
+        return EUikaErrorCode::PropertyNotFound;
+    }
+    ReadElement(MapProp->ValueProp, Helper.GetValuePtr(Index), OutValBuf, ValSize, OutWritten);
+    Helper.RemoveAt(Index);
+    return EUikaErrorCode::Ok;
+}
+
+static EUikaErrorCode MapCopyRangeImpl(
+    UikaUObjectHandle Obj, UikaFPropertyHandle Prop, int32 Start, int32 Count,
+    uint8* OutBuf, uint32 BufSize, uint32* OutTotalWritten, int32* OutCount)
+{
+    UIKA_CHECK_VALID(Obj);
+    FMapProperty* MapProp = CastField<FMapProperty>(static_cast<FProperty*>(Prop.ptr));
+    if (!MapProp) return EUikaErrorCode::TypeMismatch;
+
+    FScriptMapHelper Helper(MapProp, MapProp->ContainerPtrToValuePtr<void>(Object));
+    if (Start < 0 || Start > Helper.Num() || Count < 0)
+    {
+        return EUikaErrorCode::IndexOutOfRange;
+    }
+    const int32 Clamped = FMath::Min(Count, Helper.Num() - Start);
+    return CopyMapRange(Helper, MapProp, Start, Clamped, OutBuf, BufSize, OutTotalWritten, OutCount);
+}
+
+// ---------------------------------------------------------------------------
+// TSet algebra (FUikaSetExtApi)
+// ---------------------------------------------------------------------------
+
+// Resolve the set property of a second operand and check its element type
+// matches the first set's.
+static FSetProperty* OtherSetProp(FSetProperty* SetProp, UikaUObjectHandle OtherObj, UikaFPropertyHandle OtherProp)
+{
+    FSetProperty* Other = CastField<FSetProperty>(static_cast<FProperty*>(OtherProp.ptr));
+    if (!OtherObj.ptr || !Other || !SetProp->ElementProp->SameType(Other->ElementProp))
+    {
+        return nullptr;
+    }
+    return Other;
+}
+
+static EUikaErrorCode SetUnionIntoImpl(UikaUObjectHandle Obj, UikaFPropertyHandle Prop,
+                                       UikaUObjectHandle DstObj, UikaFPropertyHandle DstProp)
+{
+    UIKA_CHECK_VALID(Obj);
+    FSetProperty* SetProp = CastField<FSetProperty>(static_cast<FProperty*>(Prop.ptr));
+    if (!SetProp) return EUikaErrorCode::TypeMismatch;
+    FSetProperty* DstSetProp = OtherSetProp(SetProp, DstObj, DstProp);
+    if (!DstSetProp) return DstObj.ptr ? EUikaErrorCode::TypeMismatch : EUikaErrorCode::ObjectDestroyed;
+
+    void* SetPtr = SetProp->ContainerPtrToValuePtr<void>(Object);
+    void* DstSetPtr = DstSetProp->ContainerPtrToValuePtr<void>(DstObj.ptr);
+    if (SetPtr == DstSetPtr)
+    {
+        return EUikaErrorCode::Ok;
+    }
+    FScriptSetHelper Helper(SetProp, SetPtr);
+    FScriptSetHelper DstHelper(DstSetProp, DstSetPtr);
+    for (int32 i = 0; i < Helper.GetMaxIndex(); ++i)
+    {
+        if (Helper.IsValidIndex(i))
+        {
+            DstHelper.AddElement(Helper.GetElementPtr(i));
+        }
+    }
+    return EUikaErrorCode::Ok;
+}
+
+// Copy the elements of the first set that are (bInOther) or aren't in the second.
+static EUikaErrorCode CopySetCompared(UikaUObjectHandle Obj, UikaFPropertyHandle Prop,
+                                      UikaUObjectHandle OtherObj, UikaFPropertyHandle OtherProp, bool bInOther,
+                                      uint8* OutBuf, uint32 BufSize, uint32* OutTotalWritten, int32* OutCount)
+{
+    UIKA_CHECK_VALID(Obj);
+    FSetProperty* SetProp = CastField<FSetProperty>(static_cast<FProperty*>(Prop.ptr));
+    if (!SetProp) return EUikaErrorCode::TypeMismatch;
+    FSetProperty* OtherSet = OtherSetProp(SetProp, OtherObj, OtherProp);
+    if (!OtherSet) return OtherObj.ptr ? EUikaErrorCode::TypeMismatch : EUikaErrorCode::ObjectDestroyed;
+
+    FScriptSetHelper Helper(SetProp, SetProp->ContainerPtrToValuePtr<void>(Object));
+    FScriptSetHelper OtherHelper(OtherSet, OtherSet->ContainerPtrToValuePtr<void>(OtherObj.ptr));
+    return CopySetElements(Helper, SetProp->ElementProp,
+        [&OtherHelper, bInOther](const uint8* ElemPtr)
+        {
+            return (OtherHelper.FindElementIndexFromHash(ElemPtr) != INDEX_NONE) == bInOther;
+        },
+        OutBuf, BufSize, OutTotalWritten, OutCount);
+}
+
+static EUikaErrorCode SetIntersectionImpl(UikaUObjectHandle Obj, UikaFPropertyHandle Prop,
+                                          UikaUObjectHandle OtherObj, UikaFPropertyHandle OtherProp,
+                                          uint8* OutBuf, uint32 BufSize, uint32* OutTotalWritten, int32* OutCount)
+{
+    return CopySetCompared(Obj, Prop, OtherObj, OtherProp, true, OutBuf, BufSize, OutTotalWritten, OutCount);
+}
+
+static EUikaErrorCode SetDifferenceImpl(UikaUObjectHandle Obj, UikaFPropertyHandle Prop,
+                                        UikaUObjectHandle OtherObj, UikaFPropertyHandle OtherProp,
+                                        uint8* OutBuf, uint32 BufSize, uint32* OutTotalWritten, int32* OutCount)
+{
+    return CopySetCompared(Obj, Prop, OtherObj, OtherProp, false, OutBuf, BufSize, OutTotalWritten, OutCount);
+}
+
+static EUikaErrorCode SetContainsEachImpl(UikaUObjectHandle Obj, UikaFPropertyHandle Prop,
+                                          const uint8* InBuf, uint32 BufSize, int32 Count, bool* OutFound)
+{
+    UIKA_CHECK_VALID(Obj);
+    FSetProperty* SetProp = CastField<FSetProperty>(static_cast<FProperty*>(Prop.ptr));
+    if (!SetProp) return EUikaErrorCode::TypeMismatch;
+    if (Count < 0 || (Count > 0 && (!InBuf || !OutFound))) return EUikaErrorCode::NullArgument;
+
+    FScriptSetHelper Helper(SetProp, SetProp->ContainerPtrToValuePtr<void>(Object));
+    FUikaTempValue Elem(SetProp->ElementProp);
+    uint32 Offset = 0;
+    for (int32 i = 0; i < Count; ++i)
+    {
+        if (Offset + sizeof(uint32) > BufSize)
+        {
+            return EUikaErrorCode::BufferTooSmall;
+        }
+        uint32 ElemSize = 0;
+        FMemory::Memcpy(&ElemSize, InBuf + Offset, sizeof(uint32));
+        Offset += sizeof(uint32);
+        if (Offset + ElemSize > BufSize)
+        {
+            return EUikaErrorCode::BufferTooSmall;
+        }
+
+        WriteElement(SetProp->ElementProp, Elem.Data, InBuf + Offset, ElemSize);
+        OutFound[i] = Helper.FindElementIndexFromHash(Elem.Data) != INDEX_NONE;
+        Offset += ElemSize;
+    }
+    return EUikaErrorCode::Ok;
+}
+
 // ---------------------------------------------------------------------------
 // Static instance
 // ---------------------------------------------------------------------------
@@ -837,3 +1146,23 @@ FUikaContainerApi GContainerApi = {
     &MapCopyAllImpl,
     &SetCopyAllImpl,
 };
+
+FUikaArrayExtApi GArrayExtApi = {
+    &ArrayCopyRangeImpl,
+    &ArrayAppendAllImpl,
+    &ArrayRemoveRangeImpl,
+};
+
+FUikaMapExtApi GMapExtApi = {
+    &MapContainsKeyImpl,
+    &MapFindOrAddImpl,
+    &MapRemoveAndCopyImpl,
+    &MapCopyRangeImpl,
+};
+
+FUikaSetExtApi GSetExtApi = {
+    &SetUnionIntoImpl,
+    &SetIntersectionImpl,
+    &SetDifferenceImpl,
+    &SetContainsEachImpl,
+};
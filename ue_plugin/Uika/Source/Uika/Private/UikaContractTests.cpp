@@ -14,6 +14,7 @@ static_assert(sizeof(UikaFPropertyHandle)    == 8,  "UikaFPropertyHandle must be
 static_assert(sizeof(UikaUFunctionHandle)    == 8,  "UikaUFunctionHandle must be 8 bytes");
 static_assert(sizeof(UikaUStructHandle)      == 8,  "UikaUStructHandle must be 8 bytes");
 static_assert(sizeof(UikaFNameHandle)        == 8,  "UikaFNameHandle must be 8 bytes");
+static_assert(sizeof(UikaFTextHandle)        == 8,  "UikaFTextHandle must be 8 bytes");
 static_assert(sizeof(UikaFWeakObjectHandle)  == 8,  "UikaFWeakObjectHandle must be 8 bytes");
 
 // ---------------------------------------------------------------------------
@@ -22,6 +23,15 @@ static_assert(sizeof(UikaFWeakObjectHandle)  == 8,  "UikaFWeakObjectHandle must
 
 static_assert(sizeof(EUikaErrorCode) == 4, "EUikaErrorCode must be 4 bytes (uint32)");
 
+// ---------------------------------------------------------------------------
+// Physics query structs
+// ---------------------------------------------------------------------------
+
+static_assert(sizeof(FUikaHitResult) == 144, "FUikaHitResult must match UikaHitResult");
+static_assert(sizeof(FUikaCollisionShape) == 32, "FUikaCollisionShape must match UikaCollisionShape");
+static_assert(sizeof(FUikaSaveGameResult) == 24, "FUikaSaveGameResult must match UikaSaveGameResult");
+static_assert(sizeof(FUikaPropertyChange) == 32, "FUikaPropertyChange must match UikaPropertyChange");
+
 // ---------------------------------------------------------------------------
 // Handle alignment
 // ---------------------------------------------------------------------------
@@ -39,3 +49,12 @@ static_assert(alignof(UikaFNameHandle)       == alignof(uint64), "UikaFNameHandl
 
 static_assert(offsetof(UikaFWeakObjectHandle, object_index)         == 0, "FWeakObjectHandle::object_index at offset 0");
 static_assert(offsetof(UikaFWeakObjectHandle, object_serial_number) == 4, "FWeakObjectHandle::object_serial_number at offset 4");
+
+// ---------------------------------------------------------------------------
+// Version headers (must stay at the front so any table version can be read)
+// ---------------------------------------------------------------------------
+
+static_assert(offsetof(FUikaApiTable, version) == 0, "FUikaApiTable::version at offset 0");
+static_assert(offsetof(FUikaApiTable, size) == 4, "FUikaApiTable::size at offset 4");
+static_assert(offsetof(FUikaRustCallbacks, version) == 0, "FUikaRustCallbacks::version at offset 0");
+static_assert(offsetof(FUikaRustCallbacks, size) == 4, "FUikaRustCallbacks::size at offset 4");
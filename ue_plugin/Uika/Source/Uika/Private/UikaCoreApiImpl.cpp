@@ -2,6 +2,7 @@
 
 #include "UikaApiTable.h"
 #include "UObject/UObjectGlobals.h"
+#include "UObject/UObjectArray.h"
 
 static bool IsValidImpl(UikaUObjectHandle Obj)
 {
@@ -32,6 +33,31 @@ static EUikaErrorCode GetNameImpl(UikaUObjectHandle Obj, uint8* Buf, uint32 BufL
     return EUikaErrorCode::Ok;
 }
 
+static EUikaErrorCode GetPathNameImpl(UikaUObjectHandle Obj, uint8* Buf, uint32 BufLen, uint32* OutLen)
+{
+    UObject* Object = static_cast<UObject*>(Obj.ptr);
+    if (!::IsValid(Object))
+    {
+        return EUikaErrorCode::ObjectDestroyed;
+    }
+    const FString Path = Object->GetPathName();
+    const FTCHARToUTF8 Utf8(*Path);
+    const uint32 Len = static_cast<uint32>(Utf8.Length());
+    if (OutLen)
+    {
+        *OutLen = Len;
+    }
+    if (Len > BufLen)
+    {
+        return EUikaErrorCode::BufferTooSmall;
+    }
+    if (Buf)
+    {
+        FMemory::Memcpy(Buf, Utf8.Get(), Len);
+    }
+    return EUikaErrorCode::Ok;
+}
+
 static UikaUClassHandle GetClassImpl(UikaUObjectHandle Obj)
 {
     UObject* Object = static_cast<UObject*>(Obj.ptr);
@@ -134,6 +160,42 @@ static bool IsWeakValidImpl(UikaFWeakObjectHandle WeakHandle)
     return Weak.IsValid();
 }
 
+// ---------------------------------------------------------------------------
+// Class hierarchy
+// ---------------------------------------------------------------------------
+
+static bool IsChildOfImpl(UikaUClassHandle Cls, UikaUClassHandle Parent)
+{
+    // Handles built from arbitrary objects (a resolved soft class path, say)
+    // need not be classes.
+    UClass* Class = Cast<UClass>(static_cast<UObject*>(Cls.ptr));
+    UClass* ParentClass = Cast<UClass>(static_cast<UObject*>(Parent.ptr));
+    if (!Class || !ParentClass)
+    {
+        return false;
+    }
+    return Class->IsChildOf(ParentClass);
+}
+
+static UikaUClassHandle GetSuperClassImpl(UikaUClassHandle Cls)
+{
+    if (!Cls.ptr)
+    {
+        return UikaUClassHandle{ nullptr };
+    }
+    return UikaUClassHandle{ static_cast<UClass*>(Cls.ptr)->GetSuperClass() };
+}
+
+static bool ImplementsInterfaceImpl(UikaUObjectHandle Obj, UikaUClassHandle Interface)
+{
+    UObject* Object = static_cast<UObject*>(Obj.ptr);
+    if (!::IsValid(Object) || !Interface.ptr)
+    {
+        return false;
+    }
+    return Object->GetClass()->ImplementsInterface(static_cast<UClass*>(Interface.ptr));
+}
+
 FUikaCoreApi GCoreApi = {
     &IsValidImpl,
     &GetNameImpl,
@@ -145,4 +207,56 @@ FUikaCoreApi GCoreApi = {
     &MakeWeakImpl,
     &ResolveWeakImpl,
     &IsWeakValidImpl,
+    &IsChildOfImpl,
+    &GetSuperClassImpl,
+    &ImplementsInterfaceImpl,
+    &GetPathNameImpl,
+};
+
+// Scan GUObjectArray from *Cursor, the same walk TObjectIterator does, but
+// resumable so Rust can page through it.
+static EUikaErrorCode ObjectsNextPageImpl(UikaUClassHandle Cls, int32* Cursor, UikaUObjectHandle* Out,
+    uint32 Capacity, uint32* OutCount)
+{
+    UClass* Class = static_cast<UClass*>(Cls.ptr);
+    if (!Class || !Cursor || !OutCount || (!Out && Capacity > 0))
+    {
+        return EUikaErrorCode::NullArgument;
+    }
+    *OutCount = 0;
+    const int32 Num = GUObjectArray.GetObjectArrayNum();
+    int32 Index = FMath::Max(*Cursor, 0);
+    for (; Index < Num && *OutCount < Capacity; ++Index)
+    {
+        FUObjectItem* Item = GUObjectArray.IndexToObject(Index);
+        if (!Item || Item->IsUnreachable())
+        {
+            continue;
+        }
+        UObject* Object = static_cast<UObject*>(Item->GetObject());
+        if (::IsValid(Object) && !Object->HasAnyFlags(RF_ClassDefaultObject) && Object->IsA(Class))
+        {
+            Out[(*OutCount)++] = UikaUObjectHandle{ Object };
+        }
+    }
+    *Cursor = Index < Num ? Index : -1;
+    return EUikaErrorCode::Ok;
+}
+
+FUikaObjectIterApi GObjectIterApi = {
+    &ObjectsNextPageImpl,
+};
+
+static UikaUObjectHandle GetDefaultObjectImpl(UikaUClassHandle Cls)
+{
+    UClass* Class = static_cast<UClass*>(Cls.ptr);
+    if (!Class)
+    {
+        return UikaUObjectHandle{ nullptr };
+    }
+    return UikaUObjectHandle{ Class->GetDefaultObject() };
+}
+
+FUikaCoreExtApi GCoreExtApi = {
+    &GetDefaultObjectImpl,
 };
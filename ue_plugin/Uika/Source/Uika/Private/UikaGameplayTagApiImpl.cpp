@@ -0,0 +1,126 @@
+// UikaGameplayTagApiImpl.cpp — FUikaGameplayTagApi implementation.
+
+#include "UikaApiTable.h"
+#include "UikaGameplayTagHelper.h"
+
+static FGameplayTag ToTag(UikaFNameHandle Handle)
+{
+    return UikaUnpackGameplayTag(Handle.value);
+}
+
+static UikaFNameHandle FromTag(const FGameplayTag& Tag)
+{
+    return UikaFNameHandle{ UikaPackGameplayTag(Tag) };
+}
+
+// ---------------------------------------------------------------------------
+// Implementations
+// ---------------------------------------------------------------------------
+
+static EUikaErrorCode RequestTagImpl(const uint8* NameUtf8, uint32 NameLen, UikaFNameHandle* Out)
+{
+    if (!NameUtf8 || !Out)
+    {
+        return EUikaErrorCode::NullArgument;
+    }
+    const FName TagName(FString(NameLen, UTF8_TO_TCHAR(reinterpret_cast<const char*>(NameUtf8))));
+    const FGameplayTag Tag = FGameplayTag::RequestGameplayTag(TagName, /*ErrorIfNotFound=*/ false);
+    if (!Tag.IsValid())
+    {
+        return EUikaErrorCode::InvalidOperation;
+    }
+    *Out = FromTag(Tag);
+    return EUikaErrorCode::Ok;
+}
+
+static bool MatchesTagImpl(UikaFNameHandle Tag, UikaFNameHandle Other, bool bExact)
+{
+    const FGameplayTag A = ToTag(Tag);
+    const FGameplayTag B = ToTag(Other);
+    return bExact ? A.MatchesTagExact(B) : A.MatchesTag(B);
+}
+
+static UikaFNameHandle RequestDirectParentImpl(UikaFNameHandle Tag)
+{
+    return FromTag(ToTag(Tag).RequestDirectParent());
+}
+
+static EUikaErrorCode ReadContainerImpl(const uint8* Container, UikaFNameHandle* OutTags,
+                                        uint32 Capacity, uint32* OutCount)
+{
+    if (!Container)
+    {
+        return EUikaErrorCode::NullArgument;
+    }
+    const TArray<FGameplayTag>& Tags =
+        reinterpret_cast<const FGameplayTagContainer*>(Container)->GetGameplayTagArray();
+    const uint32 Count = static_cast<uint32>(Tags.Num());
+    if (OutCount)
+    {
+        *OutCount = Count;
+    }
+    if (Count > Capacity)
+    {
+        return EUikaErrorCode::BufferTooSmall;
+    }
+    for (uint32 i = 0; i < Count; ++i)
+    {
+        OutTags[i] = FromTag(Tags[i]);
+    }
+    return EUikaErrorCode::Ok;
+}
+
+static EUikaErrorCode WriteContainerImpl(uint8* Container, const UikaFNameHandle* Tags, uint32 Count)
+{
+    if (!Container || (Count > 0 && !Tags))
+    {
+        return EUikaErrorCode::NullArgument;
+    }
+    FGameplayTagContainer* Target = reinterpret_cast<FGameplayTagContainer*>(Container);
+    Target->Reset(Count);
+    for (uint32 i = 0; i < Count; ++i)
+    {
+        const FGameplayTag Tag = ToTag(Tags[i]);
+        if (Tag.IsValid())
+        {
+            Target->AddTag(Tag);
+        }
+    }
+    return EUikaErrorCode::Ok;
+}
+
+static UikaFNameHandle ReadTagImpl(const uint8* Tag)
+{
+    if (!Tag)
+    {
+        return UikaFNameHandle{ 0 };
+    }
+    return FromTag(*reinterpret_cast<const FGameplayTag*>(Tag));
+}
+
+static EUikaErrorCode WriteTagImpl(uint8* Tag, UikaFNameHandle Name)
+{
+    if (!Tag)
+    {
+        return EUikaErrorCode::NullArgument;
+    }
+    *reinterpret_cast<FGameplayTag*>(Tag) = ToTag(Name);
+    return EUikaErrorCode::Ok;
+}
+
+// ---------------------------------------------------------------------------
+// Static instance
+// ---------------------------------------------------------------------------
+
+FUikaGameplayTagApi GGameplayTagApi = {
+    &RequestTagImpl,
+    &MatchesTagImpl,
+    &RequestDirectParentImpl,
+    &ReadContainerImpl,
+    &WriteContainerImpl,
+};
+
+FUikaGameplayTagExtApi GGameplayTagExtApi = {
+    &ReadTagImpl,
+    &WriteTagImpl,
+};
@@ -0,0 +1,295 @@
+// UikaGasApiImpl.cpp — FUikaGasApi implementation.
+// Gameplay Ability System helpers: attributes by name, ability activation,
+// effect application and attribute-change subscriptions. Subscriptions fire
+// back into Rust via invoke_delegate_callback. Compiled only when the plugin
+// is built with GameplayAbilities (UIKA_WITH_GAS, see Uika.Build.cs).
+
+#include "UikaApiTable.h"
+
+#if UIKA_WITH_GAS
+#include "UikaGameplayTagHelper.h"
+#include "AbilitySystemComponent.h"
+#include "AbilitySystemGlobals.h"
+#include "AttributeSet.h"
+#include "GameplayEffect.h"
+
+extern const FUikaRustCallbacks* GetUikaRustCallbacks();
+
+// Active effects applied from Rust, by the id handed out to Rust.
+struct FUikaActiveEffect
+{
+    TWeakObjectPtr<UAbilitySystemComponent> Component;
+    FActiveGameplayEffectHandle Handle;
+};
+static TMap<uint32, FUikaActiveEffect> GRustActiveEffects;
+static uint32 GNextEffectId = 1;
+
+// Attribute-change subscriptions made from Rust, so they can all be removed
+// before DLL unload.
+struct FUikaAttributeBinding
+{
+    TWeakObjectPtr<UAbilitySystemComponent> Component;
+    FGameplayAttribute Attribute;
+    FDelegateHandle Handle;
+};
+static TMap<uint32, FUikaAttributeBinding> GRustAttributeBindings;
+static uint32 GNextAttributeBindingId = 1;
+
+static UAbilitySystemComponent* ComponentOf(UikaUObjectHandle ActorHandle)
+{
+    AActor* Actor = Cast<AActor>(static_cast<UObject*>(ActorHandle.ptr));
+    if (!::IsValid(Actor))
+    {
+        return nullptr;
+    }
+    return UAbilitySystemGlobals::GetAbilitySystemComponentFromActor(Actor);
+}
+
+// Find "Attribute" or "SetName.Attribute" among the component's attribute
+// sets. SetName matches the class name with or without its U prefix.
+static FGameplayAttribute FindAttribute(UAbilitySystemComponent* Component, const uint8* Name, uint32 NameLen)
+{
+    const FString FullName(NameLen, UTF8_TO_TCHAR(reinterpret_cast<const char*>(Name)));
+    FString SetName;
+    FString AttributeName = FullName;
+    FullName.Split(TEXT("."), &SetName, &AttributeName, ESearchCase::CaseSensitive, ESearchDir::FromEnd);
+
+    for (const UAttributeSet* Set : Component->GetSpawnedAttributes())
+    {
+        if (!Set)
+        {
+            continue;
+        }
+        UClass* SetClass = Set->GetClass();
+        if (!SetName.IsEmpty() && SetClass->GetName() != SetName
+            && SetClass->GetPrefixCPP() + SetClass->GetName() != SetName)
+        {
+            continue;
+        }
+        if (FProperty* Property = FindFProperty<FProperty>(SetClass, *AttributeName))
+        {
+            if (FGameplayAttribute::IsGameplayAttributeDataProperty(Property) || CastField<FFloatProperty>(Property))
+            {
+                return FGameplayAttribute(Property);
+            }
+        }
+    }
+    return FGameplayAttribute();
+}
+
+// ---------------------------------------------------------------------------
+// Implementations
+// ---------------------------------------------------------------------------
+
+static EUikaErrorCode GetAttributeImpl(UikaUObjectHandle Actor, const uint8* Name, uint32 NameLen,
+    bool bBase, float* Out)
+{
+    if (!Name || !Out)
+    {
+        return EUikaErrorCode::NullArgument;
+    }
+    UAbilitySystemComponent* Component = ComponentOf(Actor);
+    if (!Component)
+    {
+        return EUikaErrorCode::InvalidOperation;
+    }
+    const FGameplayAttribute Attribute = FindAttribute(Component, Name, NameLen);
+    if (!Attribute.IsValid())
+    {
+        return EUikaErrorCode::PropertyNotFound;
+    }
+    *Out = bBase ? Component->GetNumericAttributeBase(Attribute) : Component->GetNumericAttribute(Attribute);
+    return EUikaErrorCode::Ok;
+}
+
+static EUikaErrorCode SetAttributeImpl(UikaUObjectHandle Actor, const uint8* Name, uint32 NameLen,
+    bool bBase, float Value)
+{
+    if (!Name)
+    {
+        return EUikaErrorCode::NullArgument;
+    }
+    UAbilitySystemComponent* Component = ComponentOf(Actor);
+    if (!Component)
+    {
+        return EUikaErrorCode::InvalidOperation;
+    }
+    const FGameplayAttribute Attribute = FindAttribute(Component, Name, NameLen);
+    if (!Attribute.IsValid())
+    {
+        return EUikaErrorCode::PropertyNotFound;
+    }
+    if (bBase)
+    {
+        Component->SetNumericAttributeBase(Attribute, Value);
+    }
+    else
+    {
+        UAttributeSet* Set = const_cast<UAttributeSet*>(Component->GetAttributeSet(Attribute.GetAttributeSetClass()));
+        Attribute.SetNumericValueChecked(Value, Set);
+    }
+    return EUikaErrorCode::Ok;
+}
+
+static bool TryActivateAbilityByClassImpl(UikaUObjectHandle Actor, UikaUClassHandle AbilityClass)
+{
+    UAbilitySystemComponent* Component = ComponentOf(Actor);
+    UClass* Class = static_cast<UClass*>(AbilityClass.ptr);
+    if (!Component || !Class)
+    {
+        return false;
+    }
+    return Component->TryActivateAbilityByClass(Class);
+}
+
+static bool TryActivateAbilitiesByTagImpl(UikaUObjectHandle Actor, const UikaFNameHandle* Tags, uint32 Count)
+{
+    UAbilitySystemComponent* Component = ComponentOf(Actor);
+    if (!Component || (!Tags && Count > 0))
+    {
+        return false;
+    }
+    FGameplayTagContainer Container;
+    for (uint32 i = 0; i < Count; ++i)
+    {
+        Container.AddTag(UikaUnpackGameplayTag(Tags[i].value));
+    }
+    return Component->TryActivateAbilitiesByTag(Container);
+}
+
+static EUikaErrorCode ApplyGameplayEffectImpl(UikaUObjectHandle Source, UikaUObjectHandle Target,
+    UikaUClassHandle EffectClass, float Level, uint32* OutEffect)
+{
+    UClass* Class = static_cast<UClass*>(EffectClass.ptr);
+    if (!Class || !OutEffect)
+    {
+        return EUikaErrorCode::NullArgument;
+    }
+    if (!Class->IsChildOf(UGameplayEffect::StaticClass()))
+    {
+        return EUikaErrorCode::InvalidCast;
+    }
+    UAbilitySystemComponent* SourceComponent = ComponentOf(Source);
+    UAbilitySystemComponent* TargetComponent = ComponentOf(Target);
+    if (!SourceComponent || !TargetComponent)
+    {
+        return EUikaErrorCode::InvalidOperation;
+    }
+
+    const FGameplayEffectSpecHandle Spec =
+        SourceComponent->MakeOutgoingSpec(Class, Level, SourceComponent->MakeEffectContext());
+    if (!Spec.IsValid())
+    {
+        return EUikaErrorCode::InvalidOperation;
+    }
+    const FActiveGameplayEffectHandle Active =
+        SourceComponent->ApplyGameplayEffectSpecToTarget(*Spec.Data.Get(), TargetComponent);
+
+    *OutEffect = 0;
+    if (Active.IsValid())
+    {
+        for (auto It = GRustActiveEffects.CreateIterator(); It; ++It)
+        {
+            if (!It->Value.Component.IsValid())
+            {
+                It.RemoveCurrent();
+            }
+        }
+        *OutEffect = GNextEffectId++;
+        GRustActiveEffects.Add(*OutEffect, { TargetComponent, Active });
+    }
+    return EUikaErrorCode::Ok;
+}
+
+static EUikaErrorCode RemoveGameplayEffectImpl(uint32 Effect)
+{
+    FUikaActiveEffect Removed;
+    if (GRustActiveEffects.RemoveAndCopyValue(Effect, Removed))
+    {
+        if (UAbilitySystemComponent* Component = Removed.Component.Get())
+        {
+            Component->RemoveActiveGameplayEffect(Removed.Handle);
+        }
+    }
+    return EUikaErrorCode::Ok;
+}
+
+static uint32 BindAttributeChangedImpl(UikaUObjectHandle Actor, const uint8* Name, uint32 NameLen,
+    uint64 CallbackId)
+{
+    UAbilitySystemComponent* Component = ComponentOf(Actor);
+    if (!Component || !Name)
+    {
+        return 0;
+    }
+    const FGameplayAttribute Attribute = FindAttribute(Component, Name, NameLen);
+    if (!Attribute.IsValid())
+    {
+        return 0;
+    }
+
+    const FDelegateHandle Handle = Component->GetGameplayAttributeValueChangeDelegate(Attribute).AddLambda(
+        [CallbackId](const FOnAttributeChangeData& Data)
+        {
+            const FUikaRustCallbacks* Callbacks = GetUikaRustCallbacks();
+            if (Callbacks && Callbacks->invoke_delegate_callback)
+            {
+                float Params[2] = { Data.OldValue, Data.NewValue };
+                Callbacks->invoke_delegate_callback(CallbackId, reinterpret_cast<uint8*>(Params));
+            }
+        });
+
+    const uint32 Binding = GNextAttributeBindingId++;
+    GRustAttributeBindings.Add(Binding, { Component, Attribute, Handle });
+    return Binding;
+}
+
+static void RemoveAttributeBinding(const FUikaAttributeBinding& Binding)
+{
+    if (UAbilitySystemComponent* Component = Binding.Component.Get())
+    {
+        Component->GetGameplayAttributeValueChangeDelegate(Binding.Attribute).Remove(Binding.Handle);
+    }
+}
+
+static EUikaErrorCode UnbindAttributeChangedImpl(uint32 Binding)
+{
+    FUikaAttributeBinding Removed;
+    if (GRustAttributeBindings.RemoveAndCopyValue(Binding, Removed))
+    {
+        RemoveAttributeBinding(Removed);
+    }
+    return EUikaErrorCode::Ok;
+}
+
+// ---------------------------------------------------------------------------
+// Module helpers
+// ---------------------------------------------------------------------------
+
+void UikaGasRemoveAll()
+{
+    for (const TPair<uint32, FUikaAttributeBinding>& Pair : GRustAttributeBindings)
+    {
+        RemoveAttributeBinding(Pair.Value);
+    }
+    GRustAttributeBindings.Empty();
+    // Effects stay applied; only the ids handed to Rust are forgotten.
+    GRustActiveEffects.Empty();
+}
+
+// ---------------------------------------------------------------------------
+// Static instance
+// ---------------------------------------------------------------------------
+
+FUikaGasApi GGasApi = {
+    &GetAttributeImpl,
+    &SetAttributeImpl,
+    &TryActivateAbilityByClassImpl,
+    &TryActivateAbilitiesByTagImpl,
+    &ApplyGameplayEffectImpl,
+    &RemoveGameplayEffectImpl,
+    &BindAttributeChangedImpl,
+    &UnbindAttributeChangedImpl,
+};
+
+#endif // UIKA_WITH_GAS
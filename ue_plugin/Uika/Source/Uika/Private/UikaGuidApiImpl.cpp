@@ -0,0 +1,31 @@
+// UikaGuidApiImpl.cpp — FUikaGuidApi implementation.
+// Formatting and parsing are done on the Rust side; only generation needs
+// the platform.
+
+#include "UikaApiTable.h"
+#include "Misc/Guid.h"
+
+// ---------------------------------------------------------------------------
+// Implementations
+// ---------------------------------------------------------------------------
+
+static void NewGuidImpl(uint32* Out)
+{
+    if (!Out)
+    {
+        return;
+    }
+    const FGuid Guid = FGuid::NewGuid();
+    Out[0] = Guid.A;
+    Out[1] = Guid.B;
+    Out[2] = Guid.C;
+    Out[3] = Guid.D;
+}
+
+// ---------------------------------------------------------------------------
+// Static instance
+// ---------------------------------------------------------------------------
+
+FUikaGuidApi GGuidApi = {
+    &NewGuidImpl,
+};
@@ -0,0 +1,156 @@
+// UikaInputApiImpl.cpp — FUikaInputApi implementation.
+// Enhanced Input mapping contexts and action bindings. Bound actions fire back
+// into Rust via invoke_delegate_callback.
+
+#include "UikaApiTable.h"
+#include "EnhancedInputComponent.h"
+#include "EnhancedInputSubsystems.h"
+#include "InputAction.h"
+#include "InputMappingContext.h"
+#include "Engine/LocalPlayer.h"
+#include "GameFramework/PlayerController.h"
+
+extern const FUikaRustCallbacks* GetUikaRustCallbacks();
+
+// Every binding made from Rust, so they can all be removed before DLL unload.
+struct FUikaInputBinding
+{
+    TWeakObjectPtr<UEnhancedInputComponent> Component;
+    uint32 Handle;
+};
+static TArray<FUikaInputBinding> GRustInputBindings;
+
+static UEnhancedInputComponent* InputComponentOf(UikaUObjectHandle ActorHandle)
+{
+    AActor* Actor = Cast<AActor>(static_cast<UObject*>(ActorHandle.ptr));
+    if (!::IsValid(Actor))
+    {
+        return nullptr;
+    }
+    return Cast<UEnhancedInputComponent>(Actor->InputComponent);
+}
+
+static UEnhancedInputLocalPlayerSubsystem* SubsystemOf(UikaUObjectHandle ControllerHandle)
+{
+    APlayerController* Controller = Cast<APlayerController>(static_cast<UObject*>(ControllerHandle.ptr));
+    if (!::IsValid(Controller))
+    {
+        return nullptr;
+    }
+    return ULocalPlayer::GetSubsystem<UEnhancedInputLocalPlayerSubsystem>(Controller->GetLocalPlayer());
+}
+
+// ---------------------------------------------------------------------------
+// Implementations
+// ---------------------------------------------------------------------------
+
+static UikaUObjectHandle FindInputAssetImpl(const uint8* PathUtf8, uint32 PathLen, bool bMappingContext)
+{
+    if (!PathUtf8)
+    {
+        return UikaUObjectHandle{ nullptr };
+    }
+    const FString Path(PathLen, UTF8_TO_TCHAR(reinterpret_cast<const char*>(PathUtf8)));
+    UClass* Class = bMappingContext ? UInputMappingContext::StaticClass() : UInputAction::StaticClass();
+    return UikaUObjectHandle{ StaticLoadObject(Class, nullptr, *Path) };
+}
+
+static EUikaErrorCode AddMappingContextImpl(UikaUObjectHandle Controller,
+    UikaUObjectHandle Context, int32 Priority)
+{
+    UInputMappingContext* Mapping = Cast<UInputMappingContext>(static_cast<UObject*>(Context.ptr));
+    if (!::IsValid(Mapping))
+    {
+        return EUikaErrorCode::InvalidCast;
+    }
+    UEnhancedInputLocalPlayerSubsystem* Subsystem = SubsystemOf(Controller);
+    if (!Subsystem)
+    {
+        return EUikaErrorCode::InvalidOperation;
+    }
+    Subsystem->AddMappingContext(Mapping, Priority);
+    return EUikaErrorCode::Ok;
+}
+
+static EUikaErrorCode RemoveMappingContextImpl(UikaUObjectHandle Controller, UikaUObjectHandle Context)
+{
+    UInputMappingContext* Mapping = Cast<UInputMappingContext>(static_cast<UObject*>(Context.ptr));
+    if (!::IsValid(Mapping))
+    {
+        return EUikaErrorCode::InvalidCast;
+    }
+    UEnhancedInputLocalPlayerSubsystem* Subsystem = SubsystemOf(Controller);
+    if (!Subsystem)
+    {
+        return EUikaErrorCode::InvalidOperation;
+    }
+    Subsystem->RemoveMappingContext(Mapping);
+    return EUikaErrorCode::Ok;
+}
+
+static uint32 BindActionImpl(UikaUObjectHandle Actor, UikaUObjectHandle ActionHandle,
+    uint8 TriggerEvent, uint64 CallbackId)
+{
+    UEnhancedInputComponent* Input = InputComponentOf(Actor);
+    UInputAction* Action = Cast<UInputAction>(static_cast<UObject*>(ActionHandle.ptr));
+    if (!Input || !::IsValid(Action))
+    {
+        return 0;
+    }
+
+    FEnhancedInputActionEventBinding& Binding = Input->BindActionValueLambda(
+        Action, static_cast<ETriggerEvent>(TriggerEvent),
+        [CallbackId](const FInputActionValue& Value)
+        {
+            const FUikaRustCallbacks* Callbacks = GetUikaRustCallbacks();
+            if (Callbacks && Callbacks->invoke_delegate_callback)
+            {
+                const FVector Axis = Value.Get<FVector>();
+                double Params[3] = { Axis.X, Axis.Y, Axis.Z };
+                Callbacks->invoke_delegate_callback(CallbackId, reinterpret_cast<uint8*>(Params));
+            }
+        });
+
+    const uint32 Handle = Binding.GetHandle();
+    GRustInputBindings.RemoveAll([](const FUikaInputBinding& B) { return !B.Component.IsValid(); });
+    GRustInputBindings.Add({ Input, Handle });
+    return Handle;
+}
+
+static EUikaErrorCode RemoveBindingImpl(UikaUObjectHandle Actor, uint32 Binding)
+{
+    GRustInputBindings.RemoveAll([Binding](const FUikaInputBinding& B) { return B.Handle == Binding; });
+    if (UEnhancedInputComponent* Input = InputComponentOf(Actor))
+    {
+        Input->RemoveBindingByHandle(Binding);
+    }
+    return EUikaErrorCode::Ok;
+}
+
+// ---------------------------------------------------------------------------
+// Module helpers
+// ---------------------------------------------------------------------------
+
+void UikaInputRemoveAll()
+{
+    for (const FUikaInputBinding& Binding : GRustInputBindings)
+    {
+        if (UEnhancedInputComponent* Input = Binding.Component.Get())
+        {
+            Input->RemoveBindingByHandle(Binding.Handle);
+        }
+    }
+    GRustInputBindings.Empty();
+}
+
+// ---------------------------------------------------------------------------
+// Static instance
+// ---------------------------------------------------------------------------
+
+FUikaInputApi GInputApi = {
+    &FindInputAssetImpl,
+    &AddMappingContextImpl,
+    &RemoveMappingContextImpl,
+    &BindActionImpl,
+    &RemoveBindingImpl,
+};
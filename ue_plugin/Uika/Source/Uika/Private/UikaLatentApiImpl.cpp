@@ -0,0 +1,79 @@
+// UikaLatentApiImpl.cpp — FUikaLatentApi implementation.
+// Latent functions (Delay, MoveComponentTo, ...) report completion by
+// calling ExecutionFunction on the CallbackTarget of their FLatentActionInfo.
+// The target here is a UUikaDelegateProxy whose fake callable forwards to
+// Rust, so completion arrives as a regular delegate callback.
+
+#include "UikaApiTable.h"
+#include "UikaDelegateProxy.h"
+#include "Engine/LatentActionManager.h"
+#include "UObject/StrongObjectPtr.h"
+#include "UObject/UnrealType.h"
+
+// Proxies bound for Rust latent calls, kept alive until released.
+static TMap<uint64, TStrongObjectPtr<UUikaDelegateProxy>> GLatentProxies;
+static int32 GNextLatentUUID = 1;
+
+// ---------------------------------------------------------------------------
+// Implementations
+// ---------------------------------------------------------------------------
+
+static EUikaErrorCode BindLatentInfoImpl(uint8* Params, UikaFPropertyHandle PropHandle, uint64 CallbackId)
+{
+    FStructProperty* Prop = CastField<FStructProperty>(static_cast<FProperty*>(PropHandle.ptr));
+    if (!Params || !PropHandle.ptr)
+    {
+        return EUikaErrorCode::NullArgument;
+    }
+    if (!Prop || Prop->Struct != FLatentActionInfo::StaticStruct())
+    {
+        return EUikaErrorCode::TypeMismatch;
+    }
+
+    UUikaDelegateProxy* Proxy = NewObject<UUikaDelegateProxy>(GetTransientPackage());
+    Proxy->CallbackId = CallbackId;
+    GLatentProxies.Add(CallbackId, TStrongObjectPtr<UUikaDelegateProxy>(Proxy));
+
+    FLatentActionInfo* Info = Prop->ContainerPtrToValuePtr<FLatentActionInfo>(Params);
+    Info->Linkage = 0;
+    Info->UUID = GNextLatentUUID++;
+    Info->ExecutionFunction = UUikaDelegateProxy::FakeFuncName;
+    Info->CallbackTarget = Proxy;
+    return EUikaErrorCode::Ok;
+}
+
+static void ReleaseImpl(uint64 CallbackId)
+{
+    TStrongObjectPtr<UUikaDelegateProxy> Proxy;
+    if (GLatentProxies.RemoveAndCopyValue(CallbackId, Proxy) && Proxy.IsValid())
+    {
+        Proxy->CallbackId = 0;
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Module helpers
+// ---------------------------------------------------------------------------
+
+void UikaLatentReleaseAll()
+{
+    // Callback ids restart after a reload; pending actions must not reach
+    // whatever reuses them.
+    for (TPair<uint64, TStrongObjectPtr<UUikaDelegateProxy>>& Pair : GLatentProxies)
+    {
+        if (Pair.Value.IsValid())
+        {
+            Pair.Value->CallbackId = 0;
+        }
+    }
+    GLatentProxies.Empty();
+}
+
+// ---------------------------------------------------------------------------
+// Static instance
+// ---------------------------------------------------------------------------
+
+FUikaLatentApi GLatentApi = {
+    &BindLatentInfoImpl,
+    &ReleaseImpl,
+};
@@ -3,10 +3,14 @@
 // Provides GC root management and Pinned object destroy notification.
 // - add_gc_root / remove_gc_root: prevent/allow UE garbage collection
 // - register_pinned / unregister_pinned: track Pinned objects for destroy notification
+// - FUikaRustReferenceCollector: reports Rust-held references (reified
+//   instance fields, GcVisible) to the GC through collect_references
 
 #include "UikaApiTable.h"
 #include "UObject/UObjectGlobals.h"
 #include "UObject/UObjectArray.h"
+#include "UObject/GCObject.h"
+#include "UObject/WeakObjectPtr.h"
 
 // Access to Rust callbacks (defined in UikaModule.cpp).
 extern const FUikaRustCallbacks* GetUikaRustCallbacks();
@@ -129,6 +133,135 @@ void UikaPinnedUnregisterDeleteListener()
     GPinnedObjects.Empty();
 }
 
+// ---------------------------------------------------------------------------
+// Rust-held GC references
+// ---------------------------------------------------------------------------
+
+// Objects reported by Rust, by address, each with a weak pointer taken when
+// it was first reported. Rust may keep reporting a stale pointer after its
+// object is gone (a UObjectRef field is not cleared); the weak pointer
+// resolves through the object's GUObjectArray index and serial number, so a
+// dead or garbage object is detected without touching its memory and is not
+// reported. An entry is dropped when its address is reused by a new object,
+// and after a GC pass in which Rust did not report it, so entries for objects
+// Rust no longer holds do not accumulate.
+// A first report is checked against GUObjectArray before the weak pointer is
+// taken: Rust only gets handles to live objects, but an address whose entry
+// was dropped may be reported again after its object is gone.
+struct FUikaReportedObject
+{
+    FWeakObjectPtr Weak;
+    uint32 LastPass;
+};
+static TMap<const UObjectBase*, FUikaReportedObject> GRustReportedObjects;
+static uint32 GRustReportPass = 0;
+static FCriticalSection GRustReferencesLock;
+
+class FUikaRustReferenceListener : public FUObjectArray::FUObjectCreateListener
+{
+public:
+    virtual void NotifyUObjectCreated(const UObjectBase* Object, int32 Index) override
+    {
+        FScopeLock Lock(&GRustReferencesLock);
+        GRustReportedObjects.Remove(Object);
+    }
+
+    virtual void OnUObjectArrayShutdown() override
+    {
+        GUObjectArray.RemoveUObjectCreateListener(this);
+    }
+};
+
+class FUikaRustReferenceCollector : public FGCObject
+{
+public:
+    virtual void AddReferencedObjects(FReferenceCollector& Collector) override
+    {
+        const FUikaRustCallbacks* Callbacks = GetUikaRustCallbacks();
+        if (!Callbacks || !Callbacks->collect_references)
+        {
+            return;
+        }
+        uint32 Pass;
+        {
+            FScopeLock Lock(&GRustReferencesLock);
+            Pass = ++GRustReportPass;
+        }
+        Callbacks->collect_references(&ReportReference, &Collector);
+
+        // Drop the entries Rust stopped reporting.
+        FScopeLock Lock(&GRustReferencesLock);
+        for (auto It = GRustReportedObjects.CreateIterator(); It; ++It)
+        {
+            if (It.Value().LastPass != Pass)
+            {
+                It.RemoveCurrent();
+            }
+        }
+    }
+
+    virtual FString GetReferencerName() const override
+    {
+        return TEXT("FUikaRustReferenceCollector");
+    }
+
+private:
+    static void ReportReference(void* Ctx, UikaUObjectHandle Obj)
+    {
+        UObject* Object = static_cast<UObject*>(Obj.ptr);
+        if (!Object)
+        {
+            return;
+        }
+        {
+            FScopeLock Lock(&GRustReferencesLock);
+            if (FUikaReportedObject* Known = GRustReportedObjects.Find(Object))
+            {
+                Known->LastPass = GRustReportPass;
+                if (Known->Weak.Get() != Object)
+                {
+                    return;
+                }
+            }
+            else
+            {
+                if (GUObjectArray.ObjectToIndex(Object) == INDEX_NONE || !Object->IsValidLowLevelFast())
+                {
+                    return;
+                }
+                GRustReportedObjects.Add(Object, FUikaReportedObject{ FWeakObjectPtr(Object), GRustReportPass });
+            }
+        }
+        static_cast<FReferenceCollector*>(Ctx)->AddReferencedObject(Object);
+    }
+};
+
+static FUikaRustReferenceListener GRustReferenceListener;
+static TUniquePtr<FUikaRustReferenceCollector> GRustReferenceCollector;
+
+// Called from UikaModule.cpp after the Rust DLL is initialized.
+void UikaGcReferencesRegister()
+{
+    if (!GRustReferenceCollector)
+    {
+        GUObjectArray.AddUObjectCreateListener(&GRustReferenceListener);
+        GRustReferenceCollector = MakeUnique<FUikaRustReferenceCollector>();
+    }
+}
+
+// Called from UikaModule.cpp during DLL unload: the objects become ordinary
+// GC candidates again.
+void UikaGcReferencesUnregister()
+{
+    if (GRustReferenceCollector)
+    {
+        GRustReferenceCollector.Reset();
+        GUObjectArray.RemoveUObjectCreateListener(&GRustReferenceListener);
+    }
+    FScopeLock Lock(&GRustReferencesLock);
+    GRustReportedObjects.Empty();
+}
+
 // ---------------------------------------------------------------------------
 // Static instance
 // ---------------------------------------------------------------------------
@@ -5,6 +5,15 @@
 #include "HAL/PlatformFileManager.h"
 #include "HAL/FileManager.h"
 #include "Misc/Paths.h"
+#include "Misc/ScopeLock.h"
+#include "Containers/Ticker.h"
+#include "Common/TcpListener.h"
+#include "Sockets.h"
+#include "SocketSubsystem.h"
+#include "Interfaces/IPv4/IPv4Endpoint.h"
+#include "Misc/FileHelper.h"
+#include "Engine/Engine.h"
+#include "Launch/Resources/Version.h"
 
 DEFINE_LOG_CATEGORY(LogUika);
 
@@ -18,19 +27,73 @@ extern FUikaLifecycleApi  GLifecycleApi;
 extern FUikaReifyApi      GReifyApi;
 extern FUikaWorldApi      GWorldApi;
 extern FUikaWidgetApi     GWidgetApi;
+extern FUikaTimerApi      GTimerApi;
+extern FUikaAssetApi      GAssetApi;
+extern FUikaTextApi       GTextApi;
+extern FUikaGameplayTagApi GGameplayTagApi;
+extern FUikaPhysicsApi    GPhysicsApi;
+extern FUikaInputApi      GInputApi;
+extern FUikaAudioApi      GAudioApi;
+extern FUikaNavigationApi GNavigationApi;
+extern FUikaUiApi         GUiApi;
+extern FUikaAssetRegistryApi GAssetRegistryApi;
+extern FUikaSaveGameApi   GSaveGameApi;
+extern FUikaConfigApi     GConfigApi;
+#if UIKA_WITH_GAS
+extern FUikaGasApi        GGasApi;
+#endif
+extern FUikaArrayExtApi   GArrayExtApi;
+extern FUikaMapExtApi     GMapExtApi;
+extern FUikaSetExtApi     GSetExtApi;
+extern FUikaPropertyInfoApi GPropertyInfoApi;
+extern FUikaObjectIterApi GObjectIterApi;
+extern FUikaCoreExtApi    GCoreExtApi;
+extern FUikaComponentApi  GComponentApi;
+extern FUikaSubsystemApi  GSubsystemApi;
+extern FUikaLatentApi     GLatentApi;
+extern FUikaGuidApi       GGuidApi;
+extern FUikaHitResultApi  GHitResultApi;
+extern FUikaPropertyBulkApi GPropertyBulkApi;
+extern FUikaPropertyNotifyApi GPropertyNotifyApi;
+extern FUikaOutputStashApi GOutputStashApi;
+extern FUikaGameplayTagExtApi GGameplayTagExtApi;
 
 // Reify helpers (defined in UikaReifyApiImpl.cpp)
 extern void UikaReifyRegisterDeleteListener();
 extern void UikaReifyUnregisterDeleteListener();
 
+// Timer helpers (defined in UikaTimerApiImpl.cpp)
+extern void UikaTimerClearAll();
+
+// Asset loading helpers (defined in UikaAssetApiImpl.cpp)
+extern void UikaAssetCancelAll();
+
+// Input helpers (defined in UikaInputApiImpl.cpp)
+extern void UikaInputRemoveAll();
+#if UIKA_WITH_GAS
+extern void UikaGasRemoveAll();
+#endif
+
+// Property change subscriptions (defined in UikaPropertyNotifyApiImpl.cpp)
+extern void UikaPropertyNotifyRemoveAll();
+
+// Latent call helpers (defined in UikaLatentApiImpl.cpp)
+extern void UikaLatentReleaseAll();
+
 // Pinned lifecycle helpers (defined in UikaLifecycleApiImpl.cpp)
 extern void UikaPinnedUnregisterDeleteListener();
+extern void UikaGcReferencesRegister();
+extern void UikaGcReferencesUnregister();
 extern void UikaReifyForEachReifiedInstance(
     TFunctionRef<void(UObject*, UUikaReifiedClass*)> Callback);
 
 // Module-level storage for Rust callbacks (set during StartupModule, read by UUikaDelegateProxy).
 static const FUikaRustCallbacks* GRustCallbacks = nullptr;
 
+// Copy of an older DLL's shorter callback table, zero-extended so the
+// callbacks it lacks read as null.
+static FUikaRustCallbacks GRustCallbacksCompat;
+
 const FUikaRustCallbacks* GetUikaRustCallbacks()
 {
     return GRustCallbacks;
@@ -40,6 +103,9 @@ const FUikaRustCallbacks* GetUikaRustCallbacks()
 extern void UikaFillFuncTable();
 extern void** UikaGetFuncTable();
 extern uint32_t UikaGetFuncCount();
+extern const char* UikaGetBindingEngineVersion();
+extern uint64_t UikaGetBindingConfigHash();
+extern const uint64_t* UikaGetBindingFuncSigs();
 
 #define LOCTEXT_NAMESPACE "FUikaModule"
 
@@ -47,18 +113,118 @@ extern uint32_t UikaGetFuncCount();
 // Logging bridge (the one API implemented in Phase 1 for end-to-end testing)
 // ---------------------------------------------------------------------------
 
+static ELogVerbosity::Type UikaLevelToVerbosity(uint8 Level)
+{
+    switch (Level)
+    {
+    case 0:  return ELogVerbosity::Display;
+    case 1:  return ELogVerbosity::Warning;
+    case 3:  return ELogVerbosity::Log;
+    case 4:  return ELogVerbosity::Verbose;
+    case 5:  return ELogVerbosity::VeryVerbose;
+    default: return ELogVerbosity::Error;
+    }
+}
+
 static void UikaLogImpl(uint8 Level, const uint8* Msg, uint32 MsgLen)
 {
     const FString MsgStr(MsgLen, UTF8_TO_TCHAR(reinterpret_cast<const char*>(Msg)));
     switch (Level)
     {
-    case 0:  UE_LOG(LogUika, Display, TEXT("%s"), *MsgStr); break;
-    case 1:  UE_LOG(LogUika, Warning, TEXT("%s"), *MsgStr); break;
-    default: UE_LOG(LogUika, Error,   TEXT("%s"), *MsgStr); break;
+    case 0:  UE_LOG(LogUika, Display,     TEXT("%s"), *MsgStr); break;
+    case 1:  UE_LOG(LogUika, Warning,     TEXT("%s"), *MsgStr); break;
+    case 3:  UE_LOG(LogUika, Log,         TEXT("%s"), *MsgStr); break;
+    case 4:  UE_LOG(LogUika, Verbose,     TEXT("%s"), *MsgStr); break;
+    case 5:  UE_LOG(LogUika, VeryVerbose, TEXT("%s"), *MsgStr); break;
+    default: UE_LOG(LogUika, Error,       TEXT("%s"), *MsgStr); break;
+    }
+}
+
+// Categories requested from Rust, created on first use. FLogCategoryBase
+// registers itself with the log suppression system, so console verbosity
+// overrides apply as for native categories. Never freed (like UE's own).
+// "LogUika" resolves to the native LogUika rather than a second category of
+// the same name. Locked: the `log` crate bridge logs from any thread.
+static TMap<FName, FLogCategoryBase*> GRustLogCategories;
+static FCriticalSection GRustLogCategoriesLock;
+
+static FLogCategoryBase* FindOrAddRustLogCategory(const uint8* Category, uint32 CategoryLen,
+                                                  ELogVerbosity::Type DefaultVerbosity)
+{
+    const FName CategoryName(FString(CategoryLen, UTF8_TO_TCHAR(reinterpret_cast<const char*>(Category))));
+    FScopeLock Lock(&GRustLogCategoriesLock);
+    FLogCategoryBase*& Cat = GRustLogCategories.FindOrAdd(CategoryName);
+    if (!Cat)
+    {
+        Cat = CategoryName == LogUika.GetCategoryName()
+            ? static_cast<FLogCategoryBase*>(&LogUika)
+            : new FLogCategoryBase(CategoryName, DefaultVerbosity, ELogVerbosity::All);
+    }
+    return Cat;
+}
+
+static void UikaLogCategoryImpl(const uint8* Category, uint32 CategoryLen, uint8 Level,
+                                const uint8* Msg, uint32 MsgLen)
+{
+    FLogCategoryBase* Cat = FindOrAddRustLogCategory(Category, CategoryLen, ELogVerbosity::Log);
+    const ELogVerbosity::Type Verbosity = UikaLevelToVerbosity(Level);
+    if (Cat->IsSuppressed(Verbosity))
+    {
+        return;
+    }
+    const FString MsgStr(MsgLen, UTF8_TO_TCHAR(reinterpret_cast<const char*>(Msg)));
+    GLog->CategorizedLogf(Cat->GetCategoryName(), Verbosity, TEXT("%s"), *MsgStr);
+}
+
+static void UikaAddOnScreenMessageImpl(int32 Key, float Duration, uint32 Color,
+                                       const uint8* Msg, uint32 MsgLen)
+{
+    if (!GEngine)
+    {
+        return;
+    }
+    const FColor DisplayColor(
+        static_cast<uint8>(Color >> 24), static_cast<uint8>(Color >> 16),
+        static_cast<uint8>(Color >> 8), static_cast<uint8>(Color));
+    const FString MsgStr(MsgLen, UTF8_TO_TCHAR(reinterpret_cast<const char*>(Msg)));
+    GEngine->AddOnScreenDebugMessage(Key, Duration, DisplayColor, MsgStr);
+}
+
+static FUikaLoggingApi GLoggingApi = { &UikaLogImpl, &UikaLogCategoryImpl, &UikaAddOnScreenMessageImpl };
+
+static void UikaRegisterLogCategoryImpl(const uint8* Category, uint32 CategoryLen, uint8 DefaultLevel)
+{
+    // Ini and command-line overrides are applied on top when the category
+    // registers itself; a category that already exists keeps its verbosity.
+    FindOrAddRustLogCategory(Category, CategoryLen, UikaLevelToVerbosity(DefaultLevel));
+}
+
+static bool UikaIsLogEnabledImpl(const uint8* Category, uint32 CategoryLen, uint8 Level)
+{
+    const ELogVerbosity::Type Verbosity = UikaLevelToVerbosity(Level);
+    if (CategoryLen == 0)
+    {
+        return !LogUika.IsSuppressed(Verbosity);
     }
+    return !FindOrAddRustLogCategory(Category, CategoryLen, ELogVerbosity::Log)->IsSuppressed(Verbosity);
 }
 
-static FUikaLoggingApi GLoggingApi = { &UikaLogImpl };
+static FUikaLoggingExtApi GLoggingExtApi = { &UikaRegisterLogCategoryImpl, &UikaIsLogEnabledImpl };
+
+static void UikaPanicReportEnsureImpl(const uint8* Msg, uint32 MsgLen)
+{
+    const FString MsgStr(MsgLen, UTF8_TO_TCHAR(reinterpret_cast<const char*>(Msg)));
+    // Always: every Rust panic funnels through this one call site.
+    ensureAlwaysMsgf(false, TEXT("%s"), *MsgStr);
+}
+
+static void UikaPanicFatalImpl(const uint8* Msg, uint32 MsgLen)
+{
+    const FString MsgStr(MsgLen, UTF8_TO_TCHAR(reinterpret_cast<const char*>(Msg)));
+    UE_LOG(LogUika, Fatal, TEXT("%s"), *MsgStr);
+}
+
+static FUikaPanicApi GPanicApi = { &UikaPanicReportEnsureImpl, &UikaPanicFatalImpl };
 
 // ---------------------------------------------------------------------------
 // API table instance
@@ -69,7 +235,8 @@ static FUikaApiTable GApiTable;
 static void FillApiTable()
 {
     FMemory::Memzero(GApiTable);
-    GApiTable.version = 1;
+    GApiTable.version = UIKA_API_VERSION;
+    GApiTable.size = sizeof(FUikaApiTable);
 
     // Implemented sub-tables
     GApiTable.logging    = &GLoggingApi;
@@ -82,11 +249,64 @@ static void FillApiTable()
     GApiTable.reify        = &GReifyApi;
     GApiTable.world        = &GWorldApi;
     GApiTable.widget       = &GWidgetApi;
+    GApiTable.timer        = &GTimerApi;
+    GApiTable.asset        = &GAssetApi;
+    GApiTable.text         = &GTextApi;
+    GApiTable.gameplay_tag = &GGameplayTagApi;
+    GApiTable.physics      = &GPhysicsApi;
+    GApiTable.input        = &GInputApi;
+    GApiTable.audio        = &GAudioApi;
+    GApiTable.navigation   = &GNavigationApi;
+    GApiTable.ui           = &GUiApi;
+    GApiTable.asset_registry = &GAssetRegistryApi;
+    GApiTable.save_game    = &GSaveGameApi;
+    GApiTable.config       = &GConfigApi;
+#if UIKA_WITH_GAS
+    GApiTable.gas          = &GGasApi;
+#else
+    // Built without GameplayAbilities: Rust sees the sub-table as missing.
+    GApiTable.gas          = nullptr;
+#endif
+    GApiTable.array_ext    = &GArrayExtApi;
+    GApiTable.map_ext      = &GMapExtApi;
+    GApiTable.set_ext      = &GSetExtApi;
+    GApiTable.property_info = &GPropertyInfoApi;
+    GApiTable.object_iter  = &GObjectIterApi;
+    GApiTable.core_ext     = &GCoreExtApi;
+    GApiTable.component    = &GComponentApi;
+    GApiTable.subsystem    = &GSubsystemApi;
+    GApiTable.latent       = &GLatentApi;
+    GApiTable.logging_ext  = &GLoggingExtApi;
+    GApiTable.panic        = &GPanicApi;
+    GApiTable.guid         = &GGuidApi;
+    GApiTable.hit_result   = &GHitResultApi;
+    GApiTable.property_bulk = &GPropertyBulkApi;
+    GApiTable.property_notify = &GPropertyNotifyApi;
+    GApiTable.output_stash = &GOutputStashApi;
+    GApiTable.gameplay_tag_ext = &GGameplayTagExtApi;
 
     // Fill generated func_table (Phase 6)
     UikaFillFuncTable();
     GApiTable.func_table = reinterpret_cast<const void* const*>(UikaGetFuncTable());
     GApiTable.func_count = static_cast<uint32>(UikaGetFuncCount());
+
+    // Binding stamp, verified by the Rust side in uika::init
+    const char* EngineVersion = UikaGetBindingEngineVersion();
+    GApiTable.binding_stamp.engine_version = reinterpret_cast<const uint8*>(EngineVersion);
+    GApiTable.binding_stamp.engine_version_len = static_cast<uint32>(FCStringAnsi::Strlen(EngineVersion));
+    GApiTable.binding_stamp.config_hash = UikaGetBindingConfigHash();
+    GApiTable.binding_stamp.func_sigs = reinterpret_cast<const uint64*>(UikaGetBindingFuncSigs());
+
+    const FString RunningVersion = FString::Printf(TEXT("%d.%d.%d"),
+        ENGINE_MAJOR_VERSION, ENGINE_MINOR_VERSION, ENGINE_PATCH_VERSION);
+    const FString ExportVersion = UTF8_TO_TCHAR(EngineVersion);
+    if (!ExportVersion.IsEmpty() && ExportVersion != RunningVersion)
+    {
+        UE_LOG(LogUika, Warning,
+            TEXT("[Uika] Bindings were generated from a UE %s export but this is UE %s; "
+                 "re-run UHT export and `uika build --from 2`."),
+            *ExportVersion, *RunningVersion);
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -104,6 +324,161 @@ void FUikaModule::StaticReload()
     Module.ReloadRustDll();
 }
 
+static FAutoConsoleCommand CmdRunTests(
+    TEXT("Uika.RunTests"),
+    TEXT("Run the Rust in-engine tests (uika::ue_test!). Optional argument: name filter."),
+    FConsoleCommandWithArgsDelegate::CreateLambda([](const TArray<FString>& Args)
+    {
+        FUikaModule& Module = FModuleManager::GetModuleChecked<FUikaModule>(TEXT("Uika"));
+        Module.RunRustTests(Args.Num() > 0 ? Args[0] : FString());
+    }));
+
+int32 FUikaModule::RunRustTests(const FString& Filter)
+{
+    if (!RustCallbacks || !RustCallbacks->run_tests)
+    {
+        UE_LOG(LogUika, Error, TEXT("[Uika] Cannot run tests: Rust DLL is not loaded."));
+        return -1;
+    }
+
+    const FTCHARToUTF8 FilterUtf8(*Filter);
+    const uint32 Failed = RustCallbacks->run_tests(
+        reinterpret_cast<const uint8*>(FilterUtf8.Get()), FilterUtf8.Length());
+    // u32::MAX: the runner itself panicked (already logged by ffi_boundary).
+    return Failed == MAX_uint32 ? -1 : static_cast<int32>(Failed);
+}
+
+// ---------------------------------------------------------------------------
+// Reload requests: the marker file (touched by `uika watch`) and the
+// loopback socket (used by `uika reload`)
+// ---------------------------------------------------------------------------
+
+// Must match RELOAD_MARKER / RELOAD_PORT_FILE in uika-cli/src/reload_cmd.rs.
+static const TCHAR* ReloadMarkerName = TEXT("uika.reload");
+static const TCHAR* ReloadPortFileName = TEXT("uika.reload.port");
+static constexpr float ReloadPollSeconds = 0.25f;
+
+/** Send a one-line reply to a `uika reload` connection and close it. */
+static void ReplyAndClose(FSocket* Socket, const FString& Reply)
+{
+    FTCHARToUTF8 Utf8(*(Reply + TEXT("\n")));
+    int32 Sent = 0;
+    Socket->Send(reinterpret_cast<const uint8*>(Utf8.Get()), Utf8.Length(), Sent);
+    Socket->Close();
+    ISocketSubsystem::Get(PLATFORM_SOCKETSUBSYSTEM)->DestroySocket(Socket);
+}
+
+bool FUikaModule::OnReloadConnection(FSocket* Socket, const FIPv4Endpoint& Endpoint)
+{
+    // One request line, sent by the CLI right after connecting.
+    FString Request;
+    uint8 Byte = 0;
+    int32 Read = 0;
+    while (Request.Len() < 64
+        && Socket->Wait(ESocketWaitConditions::WaitForRead, FTimespan::FromSeconds(2.0))
+        && Socket->Recv(&Byte, 1, Read) && Read == 1 && Byte != '\n')
+    {
+        Request.AppendChar(static_cast<TCHAR>(Byte));
+    }
+
+    if (Request.TrimEnd() != TEXT("reload"))
+    {
+        ReplyAndClose(Socket, FString::Printf(TEXT("error unknown request '%s'"), *Request.TrimEnd()));
+        return true;
+    }
+    // Reloading touches UObjects: answer on the game thread.
+    PendingReloadRequests.Enqueue(Socket);
+    return true;
+}
+
+void FUikaModule::StartReloadListener()
+{
+#if WITH_EDITOR
+    // Editor only: packaged games do not accept reload requests over the
+    // network. Port 0: let the OS pick; the CLI reads the port from the port
+    // file.
+    ReloadListener = new FTcpListener(
+        FIPv4Endpoint(FIPv4Address(127, 0, 0, 1), 0), FTimespan::FromMilliseconds(100), false);
+    if (ReloadListener->GetSocket() == nullptr)
+    {
+        UE_LOG(LogUika, Warning,
+            TEXT("[Uika] Could not open the reload socket; `uika reload` will fall back to the marker file."));
+        delete ReloadListener;
+        ReloadListener = nullptr;
+        return;
+    }
+    ReloadListener->OnConnectionAccepted().BindRaw(this, &FUikaModule::OnReloadConnection);
+
+    const int32 Port = ReloadListener->GetSocket()->GetPortNo();
+    ReloadPortPath = FPaths::Combine(FPaths::GetPath(DllSourcePath), ReloadPortFileName);
+    if (!FFileHelper::SaveStringToFile(FString::FromInt(Port), *ReloadPortPath))
+    {
+        UE_LOG(LogUika, Warning, TEXT("[Uika] Could not write %s."), *ReloadPortPath);
+    }
+#endif
+}
+
+void FUikaModule::StopReloadListener()
+{
+    // Stops the listener thread, so nothing is enqueued after this.
+    delete ReloadListener;
+    ReloadListener = nullptr;
+
+    FSocket* Socket = nullptr;
+    while (PendingReloadRequests.Dequeue(Socket))
+    {
+        ReplyAndClose(Socket, TEXT("error editor is shutting down"));
+    }
+    if (!ReloadPortPath.IsEmpty())
+    {
+        IFileManager::Get().Delete(*ReloadPortPath, false, true, true);
+    }
+}
+
+bool FUikaModule::PollReloadRequests(float DeltaTime)
+{
+    TArray<FSocket*> Requests;
+    FSocket* Socket = nullptr;
+    while (PendingReloadRequests.Dequeue(Socket))
+    {
+        Requests.Add(Socket);
+    }
+
+    const FDateTime Stamp = IFileManager::Get().GetTimeStamp(*ReloadMarkerPath);
+    // StartupModule records the stamp of any stale marker, so only touches
+    // made while the editor is running count as reload requests.
+    const bool bMarkerTouched = Stamp != FDateTime::MinValue() && Stamp != LastReloadMarkerStamp;
+    LastReloadMarkerStamp = Stamp;
+
+    if (Requests.Num() > 0)
+    {
+        // Requests arriving together (and a marker touch) share one reload.
+        UE_LOG(LogUika, Display, TEXT("[Uika] Reload requested by `uika reload`, reloading Rust DLL."));
+        const bool bReloaded = ReloadRustDll();
+        for (FSocket* Request : Requests)
+        {
+            ReplyAndClose(Request, bReloaded
+                ? FString::Printf(TEXT("ok %d"), ReloadCount)
+                : FString(TEXT("error hot reload failed, see the editor log")));
+        }
+    }
+    else if (bMarkerTouched)
+    {
+        UE_LOG(LogUika, Display, TEXT("[Uika] Reload marker touched, reloading Rust DLL."));
+        ReloadRustDll();
+    }
+    return true;
+}
+
+bool FUikaModule::TickRust(float DeltaTime)
+{
+    if (RustCallbacks && RustCallbacks->tick)
+    {
+        RustCallbacks->tick(DeltaTime);
+    }
+    return true;
+}
+
 // ---------------------------------------------------------------------------
 // Module lifecycle
 // ---------------------------------------------------------------------------
@@ -121,6 +496,14 @@ void FUikaModule::StartupModule()
         FPlatformProcess::GetBinariesSubdirectory(),
         TEXT("uika.dll"));
 
+    // Watch for `uika watch` / `uika reload` requests, even if no DLL exists yet.
+    ReloadMarkerPath = FPaths::Combine(FPaths::GetPath(DllSourcePath), ReloadMarkerName);
+    LastReloadMarkerStamp = IFileManager::Get().GetTimeStamp(*ReloadMarkerPath);
+    StartReloadListener();
+    ReloadMarkerTicker = FTSTicker::GetCoreTicker().AddTicker(
+        FTickerDelegate::CreateRaw(this, &FUikaModule::PollReloadRequests),
+        ReloadPollSeconds);
+
     if (!FPaths::FileExists(DllSourcePath))
     {
         UE_LOG(LogUika, Warning,
@@ -159,6 +542,13 @@ void FUikaModule::StartupModule()
 
 void FUikaModule::ShutdownModule()
 {
+    if (ReloadMarkerTicker.IsValid())
+    {
+        FTSTicker::GetCoreTicker().RemoveTicker(ReloadMarkerTicker);
+        ReloadMarkerTicker.Reset();
+    }
+    StopReloadListener();
+
     UnloadRustDll();
 
     // Clean up the hot-copy DLL (now unlocked).
@@ -203,20 +593,68 @@ bool FUikaModule::LoadRustDll(const FString& LoadPath)
         return false;
     }
 
+    if (RustCallbacks->version < UIKA_API_MIN_VERSION)
+    {
+        UE_LOG(LogUika, Error,
+            TEXT("[Uika] DLL API version %u is older than this plugin supports (%u). Rebuild the DLL."),
+            RustCallbacks->version, UIKA_API_MIN_VERSION);
+        if (RustCallbacks->on_shutdown)
+        {
+            RustCallbacks->on_shutdown();
+        }
+        RustCallbacks = nullptr;
+        FPlatformProcess::FreeDllHandle(DllHandle);
+        DllHandle = nullptr;
+        return false;
+    }
+    if (RustCallbacks->size < sizeof(FUikaRustCallbacks))
+    {
+        UE_LOG(LogUika, Warning,
+            TEXT("[Uika] DLL API version %u is older than the plugin's (%u); newer callbacks are disabled."),
+            RustCallbacks->version, UIKA_API_VERSION);
+        FMemory::Memzero(GRustCallbacksCompat);
+        FMemory::Memcpy(&GRustCallbacksCompat, RustCallbacks, RustCallbacks->size);
+        RustCallbacks = &GRustCallbacksCompat;
+    }
+
     // Store globally so UUikaDelegateProxy can access Rust callbacks.
     GRustCallbacks = RustCallbacks;
 
     // Register the UObject delete listener for reified class instance cleanup.
     UikaReifyRegisterDeleteListener();
 
+    // Report Rust-held references to the GC.
+    UikaGcReferencesRegister();
+
+    // Drive the game-thread task queue every frame.
+    RustTickTicker = FTSTicker::GetCoreTicker().AddTicker(
+        FTickerDelegate::CreateRaw(this, &FUikaModule::TickRust));
+
     return true;
 }
 
 void FUikaModule::UnloadRustDll()
 {
+    if (RustTickTicker.IsValid())
+    {
+        FTSTicker::GetCoreTicker().RemoveTicker(RustTickTicker);
+        RustTickTicker.Reset();
+    }
+
     // Unregister delete listeners before shutting down Rust.
     UikaReifyUnregisterDeleteListener();
     UikaPinnedUnregisterDeleteListener();
+    UikaGcReferencesUnregister();
+
+    // Rust timer/load/input/attribute/property/latent callbacks must not fire into an unloaded (or reloaded) DLL.
+    UikaTimerClearAll();
+    UikaAssetCancelAll();
+    UikaInputRemoveAll();
+#if UIKA_WITH_GAS
+    UikaGasRemoveAll();
+#endif
+    UikaPropertyNotifyRemoveAll();
+    UikaLatentReleaseAll();
 
     if (DllHandle)
     {
@@ -292,7 +730,7 @@ void FUikaModule::ReconstructReifiedInstances()
 // Hot reload (DLL swap)
 // ---------------------------------------------------------------------------
 
-void FUikaModule::ReloadRustDll()
+bool FUikaModule::ReloadRustDll()
 {
     UE_LOG(LogUika, Display, TEXT("[Uika] === Hot Reload Begin ==="));
 
@@ -300,7 +738,7 @@ void FUikaModule::ReloadRustDll()
     {
         UE_LOG(LogUika, Error,
             TEXT("[Uika] Hot reload failed: DLL source path not set (was initial load skipped?)"));
-        return;
+        return false;
     }
 
     // Phase 1: Teardown — drop all Rust instances and unload old DLL
@@ -320,7 +758,7 @@ void FUikaModule::ReloadRustDll()
         UE_LOG(LogUika, Error,
             TEXT("[Uika] Hot reload failed: %s not found. Did cargo build succeed?"),
             *DllSourcePath);
-        return;
+        return false;
     }
 
     ReloadCount++;
@@ -334,20 +772,21 @@ void FUikaModule::ReloadRustDll()
         UE_LOG(LogUika, Error,
             TEXT("[Uika] Hot reload failed: could not copy %s → %s (error %u)"),
             *DllSourcePath, *HotDllPath, CopyResult);
-        return;
+        return false;
     }
 
     // Phase 2b: Load the new DLL and re-initialize Rust
     if (!LoadRustDll(HotDllPath))
     {
         UE_LOG(LogUika, Error, TEXT("[Uika] Hot reload failed: could not load new DLL"));
-        return;
+        return false;
     }
 
     // Phase 3: Reconstruct — rebuild Rust instance data
     ReconstructReifiedInstances();
 
     UE_LOG(LogUika, Display, TEXT("[Uika] === Hot Reload Complete ==="));
+    return true;
 }
 
 #undef LOCTEXT_NAMESPACE
@@ -0,0 +1,139 @@
+// UikaNavigationApiImpl.cpp — FUikaNavigationApi implementation.
+// Navmesh path, reachability and projection queries via UNavigationSystemV1.
+
+#include "UikaApiTable.h"
+#include "NavigationSystem.h"
+#include "NavigationPath.h"
+#include "Blueprint/AIBlueprintHelperLibrary.h"
+#include "Engine/Engine.h"
+#include "Engine/World.h"
+#include "GameFramework/Controller.h"
+
+static UWorld* WorldFromContext(UikaUObjectHandle Context)
+{
+    UObject* Object = static_cast<UObject*>(Context.ptr);
+    if (!::IsValid(Object) || !GEngine)
+    {
+        return nullptr;
+    }
+    return GEngine->GetWorldFromContextObject(Object, EGetWorldErrorMode::ReturnNull);
+}
+
+static FVector ToVector(const double* Xyz)
+{
+    return FVector(Xyz[0], Xyz[1], Xyz[2]);
+}
+
+static void FromVector(const FVector& V, double* Out)
+{
+    Out[0] = V.X;
+    Out[1] = V.Y;
+    Out[2] = V.Z;
+}
+
+// ---------------------------------------------------------------------------
+// Implementations
+// ---------------------------------------------------------------------------
+
+static EUikaErrorCode FindPathToLocationImpl(UikaUObjectHandle Context, const double* Start,
+    const double* End, double* OutPoints, uint32 Capacity, uint32* OutCount)
+{
+    if (!Start || !End || !OutCount)
+    {
+        return EUikaErrorCode::NullArgument;
+    }
+    UWorld* World = WorldFromContext(Context);
+    if (!World)
+    {
+        return EUikaErrorCode::ObjectDestroyed;
+    }
+    UNavigationPath* Path = UNavigationSystemV1::FindPathToLocationSynchronously(
+        World, ToVector(Start), ToVector(End));
+    if (!Path || !Path->IsValid())
+    {
+        *OutCount = 0;
+        return EUikaErrorCode::InvalidOperation;
+    }
+
+    const TArray<FVector>& Points = Path->PathPoints;
+    *OutCount = static_cast<uint32>(Points.Num());
+    if (*OutCount > Capacity || (*OutCount > 0 && !OutPoints))
+    {
+        return EUikaErrorCode::BufferTooSmall;
+    }
+    for (int32 i = 0; i < Points.Num(); ++i)
+    {
+        FromVector(Points[i], OutPoints + i * 3);
+    }
+    return EUikaErrorCode::Ok;
+}
+
+static bool FindRandomReachablePointImpl(UikaUObjectHandle Context, const double* Origin,
+    float Radius, double* OutPoint)
+{
+    UWorld* World = WorldFromContext(Context);
+    UNavigationSystemV1* NavSys = World ? FNavigationSystem::GetCurrent<UNavigationSystemV1>(World) : nullptr;
+    if (!NavSys || !Origin || !OutPoint)
+    {
+        return false;
+    }
+    FNavLocation Result;
+    if (!NavSys->GetRandomReachablePointInRadius(ToVector(Origin), Radius, Result))
+    {
+        return false;
+    }
+    FromVector(Result.Location, OutPoint);
+    return true;
+}
+
+static bool ProjectPointToNavigationImpl(UikaUObjectHandle Context, const double* Point,
+    const double* Extent, double* OutPoint)
+{
+    UWorld* World = WorldFromContext(Context);
+    UNavigationSystemV1* NavSys = World ? FNavigationSystem::GetCurrent<UNavigationSystemV1>(World) : nullptr;
+    if (!NavSys || !Point || !OutPoint)
+    {
+        return false;
+    }
+    const FVector QueryExtent = Extent ? ToVector(Extent) : FVector::ZeroVector;
+    FNavLocation Result;
+    const bool bFound = QueryExtent.IsZero()
+        ? NavSys->ProjectPointToNavigation(ToVector(Point), Result)
+        : NavSys->ProjectPointToNavigation(ToVector(Point), Result, QueryExtent);
+    if (!bFound)
+    {
+        return false;
+    }
+    FromVector(Result.Location, OutPoint);
+    return true;
+}
+
+static EUikaErrorCode SimpleMoveToLocationImpl(UikaUObjectHandle ControllerHandle, const double* Goal)
+{
+    if (!Goal)
+    {
+        return EUikaErrorCode::NullArgument;
+    }
+    AController* Controller = Cast<AController>(static_cast<UObject*>(ControllerHandle.ptr));
+    if (!::IsValid(Controller))
+    {
+        return EUikaErrorCode::InvalidCast;
+    }
+    if (!Controller->GetPawn())
+    {
+        return EUikaErrorCode::InvalidOperation;
+    }
+    UAIBlueprintHelperLibrary::SimpleMoveToLocation(Controller, ToVector(Goal));
+    return EUikaErrorCode::Ok;
+}
+
+// ---------------------------------------------------------------------------
+// Static instance
+// ---------------------------------------------------------------------------
+
+FUikaNavigationApi GNavigationApi = {
+    &FindPathToLocationImpl,
+    &FindRandomReachablePointImpl,
+    &ProjectPointToNavigationImpl,
+    &SimpleMoveToLocationImpl,
+};
@@ -0,0 +1,46 @@
+// UikaOutputStashApiImpl.cpp — FUikaOutputStashApi implementation.
+// Oversized string outputs of generated function wrappers, kept per thread
+// until Rust fetches them (see UikaOutputStash.h).
+
+#include "UikaApiTable.h"
+#include "UikaOutputStash.h"
+
+static thread_local TMap<uint32, TArray<ANSICHAR>> GUikaStashedOutputs;
+
+void UikaOutputStashPut(uint32 Slot, const ANSICHAR* Utf8, uint32 Len)
+{
+    GUikaStashedOutputs.Add(Slot, TArray<ANSICHAR>(Utf8, static_cast<int32>(Len)));
+}
+
+// ---------------------------------------------------------------------------
+// Implementations
+// ---------------------------------------------------------------------------
+
+static EUikaErrorCode TakeStringImpl(uint32 Slot, uint8* Buf, uint32 BufLen, uint32* OutLen)
+{
+    TArray<ANSICHAR>* Stashed = GUikaStashedOutputs.Find(Slot);
+    if (!Stashed)
+    {
+        return EUikaErrorCode::InvalidOperation;
+    }
+    const uint32 Len = static_cast<uint32>(Stashed->Num());
+    if (OutLen)
+    {
+        *OutLen = Len;
+    }
+    if (Len > BufLen || (Len > 0 && !Buf))
+    {
+        return EUikaErrorCode::BufferTooSmall;
+    }
+    FMemory::Memcpy(Buf, Stashed->GetData(), Len);
+    GUikaStashedOutputs.Remove(Slot);
+    return EUikaErrorCode::Ok;
+}
+
+// ---------------------------------------------------------------------------
+// Static instance
+// ---------------------------------------------------------------------------
+
+FUikaOutputStashApi GOutputStashApi = {
+    &TakeStringImpl,
+};
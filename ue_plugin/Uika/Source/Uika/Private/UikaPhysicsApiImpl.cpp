@@ -0,0 +1,195 @@
+// UikaPhysicsApiImpl.cpp — FUikaPhysicsApi and FUikaHitResultApi implementation.
+// Line traces, shape sweeps and overlap tests on UWorld, and decoding of
+// FHitResults returned by generated functions.
+
+#include "UikaApiTable.h"
+#include "UikaFNameHelper.h"
+#include "Engine/World.h"
+#include "Engine/Engine.h"
+#include "Engine/HitResult.h"
+#include "CollisionQueryParams.h"
+#include "Components/PrimitiveComponent.h"
+#include "GameFramework/Actor.h"
+
+static UWorld* WorldFromContext(UikaUObjectHandle Context)
+{
+    UObject* Object = static_cast<UObject*>(Context.ptr);
+    if (!::IsValid(Object) || !GEngine)
+    {
+        return nullptr;
+    }
+    return GEngine->GetWorldFromContextObject(Object, EGetWorldErrorMode::ReturnNull);
+}
+
+static FVector ToVector(const double* Xyz)
+{
+    return FVector(Xyz[0], Xyz[1], Xyz[2]);
+}
+
+static void FromVector(const FVector& V, double* Out)
+{
+    Out[0] = V.X;
+    Out[1] = V.Y;
+    Out[2] = V.Z;
+}
+
+static FCollisionQueryParams MakeQueryParams(bool bTraceComplex, const UikaUObjectHandle* Ignore, uint32 IgnoreCount)
+{
+    FCollisionQueryParams Params(SCENE_QUERY_STAT(UikaQuery), bTraceComplex);
+    for (uint32 i = 0; Ignore && i < IgnoreCount; ++i)
+    {
+        if (AActor* Actor = Cast<AActor>(static_cast<UObject*>(Ignore[i].ptr)))
+        {
+            Params.AddIgnoredActor(Actor);
+        }
+    }
+    return Params;
+}
+
+static FCollisionShape ToCollisionShape(const FUikaCollisionShape& Shape)
+{
+    switch (Shape.kind)
+    {
+    case 1:  return FCollisionShape::MakeBox(ToVector(Shape.extent));
+    case 2:  return FCollisionShape::MakeCapsule(Shape.extent[0], Shape.extent[2]);
+    default: return FCollisionShape::MakeSphere(Shape.extent[0]);
+    }
+}
+
+static void FillHitResult(const FHitResult& Hit, FUikaHitResult* Out)
+{
+    Out->actor = UikaUObjectHandle{ Hit.GetActor() };
+    Out->component = UikaUObjectHandle{ Hit.GetComponent() };
+    FromVector(Hit.Location, Out->location);
+    FromVector(Hit.ImpactPoint, Out->impact_point);
+    FromVector(Hit.Normal, Out->normal);
+    FromVector(Hit.ImpactNormal, Out->impact_normal);
+    Out->distance = Hit.Distance;
+    Out->time = Hit.Time;
+    Out->face_index = Hit.FaceIndex;
+    Out->bone_name = UikaFNameHandle{ UikaPackFName(Hit.BoneName) };
+    Out->blocking_hit = Hit.bBlockingHit;
+    Out->start_penetrating = Hit.bStartPenetrating;
+}
+
+// ---------------------------------------------------------------------------
+// Implementations
+// ---------------------------------------------------------------------------
+
+static bool LineTraceSingleImpl(UikaUObjectHandle WorldContext, const double* Start, const double* End,
+                                uint8 Channel, bool bTraceComplex,
+                                const UikaUObjectHandle* Ignore, uint32 IgnoreCount,
+                                FUikaHitResult* OutHit)
+{
+    UWorld* World = WorldFromContext(WorldContext);
+    if (!World || !Start || !End)
+    {
+        return false;
+    }
+    FHitResult Hit;
+    const bool bHit = World->LineTraceSingleByChannel(Hit, ToVector(Start), ToVector(End),
+        static_cast<ECollisionChannel>(Channel), MakeQueryParams(bTraceComplex, Ignore, IgnoreCount));
+    if (bHit && OutHit)
+    {
+        FillHitResult(Hit, OutHit);
+    }
+    return bHit;
+}
+
+static EUikaErrorCode LineTraceMultiImpl(UikaUObjectHandle WorldContext, const double* Start, const double* End,
+                                         uint8 Channel, bool bTraceComplex,
+                                         const UikaUObjectHandle* Ignore, uint32 IgnoreCount,
+                                         FUikaHitResult* OutHits, uint32 Capacity, uint32* OutCount)
+{
+    UWorld* World = WorldFromContext(WorldContext);
+    if (!World)
+    {
+        return EUikaErrorCode::ObjectDestroyed;
+    }
+    if (!Start || !End)
+    {
+        return EUikaErrorCode::NullArgument;
+    }
+    TArray<FHitResult> Hits;
+    World->LineTraceMultiByChannel(Hits, ToVector(Start), ToVector(End),
+        static_cast<ECollisionChannel>(Channel), MakeQueryParams(bTraceComplex, Ignore, IgnoreCount));
+
+    const uint32 Count = static_cast<uint32>(Hits.Num());
+    if (OutCount)
+    {
+        *OutCount = Count;
+    }
+    if (Count > Capacity)
+    {
+        return EUikaErrorCode::BufferTooSmall;
+    }
+    for (uint32 i = 0; i < Count; ++i)
+    {
+        FillHitResult(Hits[i], &OutHits[i]);
+    }
+    return EUikaErrorCode::Ok;
+}
+
+static bool SweepSingleImpl(UikaUObjectHandle WorldContext, const double* Start, const double* End,
+                            const FUikaCollisionShape* Shape, uint8 Channel, bool bTraceComplex,
+                            const UikaUObjectHandle* Ignore, uint32 IgnoreCount,
+                            FUikaHitResult* OutHit)
+{
+    UWorld* World = WorldFromContext(WorldContext);
+    if (!World || !Start || !End || !Shape)
+    {
+        return false;
+    }
+    FHitResult Hit;
+    const bool bHit = World->SweepSingleByChannel(Hit, ToVector(Start), ToVector(End), FQuat::Identity,
+        static_cast<ECollisionChannel>(Channel), ToCollisionShape(*Shape),
+        MakeQueryParams(bTraceComplex, Ignore, IgnoreCount));
+    if (bHit && OutHit)
+    {
+        FillHitResult(Hit, OutHit);
+    }
+    return bHit;
+}
+
+static bool OverlapBlockingTestImpl(UikaUObjectHandle WorldContext, const double* Center,
+                                    const FUikaCollisionShape* Shape, uint8 Channel,
+                                    const UikaUObjectHandle* Ignore, uint32 IgnoreCount)
+{
+    UWorld* World = WorldFromContext(WorldContext);
+    if (!World || !Center || !Shape)
+    {
+        return false;
+    }
+    return World->OverlapBlockingTestByChannel(ToVector(Center), FQuat::Identity,
+        static_cast<ECollisionChannel>(Channel), ToCollisionShape(*Shape),
+        MakeQueryParams(false, Ignore, IgnoreCount));
+}
+
+static EUikaErrorCode DecodeHitResultImpl(UikaUStructHandle UStruct, const uint8* Data, FUikaHitResult* Out)
+{
+    if (!Data || !Out)
+    {
+        return EUikaErrorCode::NullArgument;
+    }
+    if (static_cast<UScriptStruct*>(UStruct.ptr) != FHitResult::StaticStruct())
+    {
+        return EUikaErrorCode::TypeMismatch;
+    }
+    FillHitResult(*reinterpret_cast<const FHitResult*>(Data), Out);
+    return EUikaErrorCode::Ok;
+}
+
+// ---------------------------------------------------------------------------
+// Static instance
+// ---------------------------------------------------------------------------
+
+FUikaPhysicsApi GPhysicsApi = {
+    &LineTraceSingleImpl,
+    &LineTraceMultiImpl,
+    &SweepSingleImpl,
+    &OverlapBlockingTestImpl,
+};
+
+FUikaHitResultApi GHitResultApi = {
+    &DecodeHitResultImpl,
+};
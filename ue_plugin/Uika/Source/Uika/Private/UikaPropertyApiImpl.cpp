@@ -4,6 +4,11 @@
 #include "UObject/UnrealType.h"
 #include "UObject/TextProperty.h"
 #include "UObject/UObjectGlobals.h"
+#include "JsonObjectConverter.h"
+#include "Dom/JsonObject.h"
+#include "Serialization/JsonReader.h"
+#include "Serialization/JsonSerializer.h"
+#include "Policies/CondensedJsonPrintPolicy.h"
 
 // ---------------------------------------------------------------------------
 // Validity macro
@@ -168,6 +173,11 @@ static EUikaErrorCode GetStringImpl(UikaUObjectHandle Obj, UikaFPropertyHandle P
     {
         Value = TextProp->GetPropertyValue_InContainer(Object).ToString();
     }
+    else if (const FSoftObjectProperty* SoftProp = CastField<FSoftObjectProperty>(Property))
+    {
+        // TSoftObjectPtr / TSoftClassPtr cross the boundary as their asset path.
+        Value = SoftProp->GetPropertyValue_InContainer(Object).ToSoftObjectPath().ToString();
+    }
     else
     {
         return EUikaErrorCode::TypeMismatch;
@@ -204,6 +214,10 @@ static EUikaErrorCode SetStringImpl(UikaUObjectHandle Obj, UikaFPropertyHandle P
     {
         TextProp->SetPropertyValue_InContainer(Object, FText::FromString(Value));
     }
+    else if (FSoftObjectProperty* SoftProp = CastField<FSoftObjectProperty>(Property))
+    {
+        SoftProp->SetPropertyValue_InContainer(Object, FSoftObjectPtr(FSoftObjectPath(Value)));
+    }
     else
     {
         return EUikaErrorCode::TypeMismatch;
@@ -379,6 +393,228 @@ static EUikaErrorCode SetPropertyAtImpl(UikaUObjectHandle Obj, UikaFPropertyHand
     return EUikaErrorCode::Ok;
 }
 
+// ---------------------------------------------------------------------------
+// Whole-object JSON
+// ---------------------------------------------------------------------------
+
+static EUikaErrorCode ExportJsonImpl(UikaUObjectHandle Obj, uint8* Buf, uint32 BufLen, uint32* OutLen)
+{
+    UObject* Object = static_cast<UObject*>(Obj.ptr);
+    if (!::IsValid(Object))
+    {
+        return EUikaErrorCode::ObjectDestroyed;
+    }
+    TSharedRef<FJsonObject> JsonObject = MakeShared<FJsonObject>();
+    if (!FJsonObjectConverter::UStructToJsonObject(Object->GetClass(), Object, JsonObject, 0, CPF_Transient))
+    {
+        return EUikaErrorCode::InternalError;
+    }
+
+    FString Json;
+    TSharedRef<TJsonWriter<TCHAR, TCondensedJsonPrintPolicy<TCHAR>>> Writer =
+        TJsonWriterFactory<TCHAR, TCondensedJsonPrintPolicy<TCHAR>>::Create(&Json);
+    if (!FJsonSerializer::Serialize(JsonObject, Writer))
+    {
+        return EUikaErrorCode::InternalError;
+    }
+
+    const FTCHARToUTF8 Utf8(*Json);
+    const uint32 Len = static_cast<uint32>(Utf8.Length());
+    if (OutLen)
+    {
+        *OutLen = Len;
+    }
+    if (Len > BufLen)
+    {
+        return EUikaErrorCode::BufferTooSmall;
+    }
+    if (Buf)
+    {
+        FMemory::Memcpy(Buf, Utf8.Get(), Len);
+    }
+    return EUikaErrorCode::Ok;
+}
+
+static EUikaErrorCode ImportJsonImpl(UikaUObjectHandle Obj, const uint8* JsonUtf8, uint32 Len)
+{
+    UObject* Object = static_cast<UObject*>(Obj.ptr);
+    if (!::IsValid(Object))
+    {
+        return EUikaErrorCode::ObjectDestroyed;
+    }
+    if (!JsonUtf8)
+    {
+        return EUikaErrorCode::NullArgument;
+    }
+
+    const FString Json = FString(FUTF8ToTCHAR(reinterpret_cast<const ANSICHAR*>(JsonUtf8), Len));
+    TSharedPtr<FJsonObject> JsonObject;
+    TSharedRef<TJsonReader<TCHAR>> Reader = TJsonReaderFactory<TCHAR>::Create(Json);
+    if (!FJsonSerializer::Deserialize(Reader, JsonObject) || !JsonObject.IsValid())
+    {
+        return EUikaErrorCode::TypeMismatch;
+    }
+    if (!FJsonObjectConverter::JsonObjectToUStruct(JsonObject.ToSharedRef(), Object->GetClass(), Object, 0, CPF_Transient))
+    {
+        return EUikaErrorCode::TypeMismatch;
+    }
+    return EUikaErrorCode::Ok;
+}
+
+// ---------------------------------------------------------------------------
+// Bulk scalar access (FUikaPropertyBulkApi)
+// ---------------------------------------------------------------------------
+
+// The slot kind of a scalar property; false for strings, structs, containers...
+// Mirrors DescribePropertyImpl, so kinds agree with the property info API.
+static bool GetScalarKind(const FProperty* Property, EUikaReifyPropType& OutKind)
+{
+    if (CastField<FBoolProperty>(Property))             OutKind = EUikaReifyPropType::Bool;
+    else if (CastField<FInt8Property>(Property))        OutKind = EUikaReifyPropType::Int8;
+    else if (CastField<FInt16Property>(Property))       OutKind = EUikaReifyPropType::Int16;
+    else if (CastField<FIntProperty>(Property))         OutKind = EUikaReifyPropType::Int32;
+    else if (CastField<FInt64Property>(Property))       OutKind = EUikaReifyPropType::Int64;
+    else if (CastField<FUInt16Property>(Property))      OutKind = EUikaReifyPropType::UInt16;
+    else if (CastField<FUInt32Property>(Property))      OutKind = EUikaReifyPropType::UInt32;
+    else if (CastField<FUInt64Property>(Property))      OutKind = EUikaReifyPropType::UInt64;
+    else if (CastField<FFloatProperty>(Property))       OutKind = EUikaReifyPropType::Float;
+    else if (CastField<FDoubleProperty>(Property))      OutKind = EUikaReifyPropType::Double;
+    else if (CastField<FNameProperty>(Property))        OutKind = EUikaReifyPropType::Name;
+    else if (CastField<FEnumProperty>(Property))        OutKind = EUikaReifyPropType::Enum;
+    else if (const FByteProperty* ByteProp = CastField<FByteProperty>(Property))
+    {
+        OutKind = ByteProp->Enum ? EUikaReifyPropType::Enum : EUikaReifyPropType::UInt8;
+    }
+    else if (CastField<FClassProperty>(Property))       OutKind = EUikaReifyPropType::Class;
+    else if (CastField<FObjectProperty>(Property))      OutKind = EUikaReifyPropType::Object;
+    else return false;
+    return true;
+}
+
+static bool IsObjectKind(EUikaReifyPropType Kind)
+{
+    return Kind == EUikaReifyPropType::Object || Kind == EUikaReifyPropType::Class;
+}
+
+static uint64 ReadSlot(const FProperty* Property, EUikaReifyPropType Kind, const void* Container)
+{
+    const void* Value = Property->ContainerPtrToValuePtr<void>(Container);
+    uint64 Slot = 0;
+    switch (Kind)
+    {
+    case EUikaReifyPropType::Bool:
+        return CastFieldChecked<FBoolProperty>(Property)->GetPropertyValue(Value) ? 1 : 0;
+    case EUikaReifyPropType::Float:
+        FMemory::Memcpy(&Slot, Value, sizeof(float));
+        return Slot;
+    case EUikaReifyPropType::Double:
+    case EUikaReifyPropType::Name:
+        // FName: the same leading 8 bytes as GetFNameImpl.
+        FMemory::Memcpy(&Slot, Value, sizeof(uint64));
+        return Slot;
+    case EUikaReifyPropType::Object:
+    case EUikaReifyPropType::Class:
+        return reinterpret_cast<uint64>(CastFieldChecked<FObjectPropertyBase>(Property)->GetObjectPropertyValue(Value));
+    case EUikaReifyPropType::Enum:
+        if (const FEnumProperty* EnumProp = CastField<FEnumProperty>(Property))
+        {
+            return static_cast<uint64>(EnumProp->GetUnderlyingProperty()->GetSignedIntPropertyValue(Value));
+        }
+        return *static_cast<const uint8*>(Value);
+    case EUikaReifyPropType::UInt8:
+    case EUikaReifyPropType::UInt16:
+    case EUikaReifyPropType::UInt32:
+    case EUikaReifyPropType::UInt64:
+        return CastFieldChecked<FNumericProperty>(Property)->GetUnsignedIntPropertyValue(Value);
+    default:
+        return static_cast<uint64>(CastFieldChecked<FNumericProperty>(Property)->GetSignedIntPropertyValue(Value));
+    }
+}
+
+static void WriteSlot(FProperty* Property, EUikaReifyPropType Kind, void* Container, uint64 Slot)
+{
+    void* Value = Property->ContainerPtrToValuePtr<void>(Container);
+    switch (Kind)
+    {
+    case EUikaReifyPropType::Bool:
+        CastFieldChecked<FBoolProperty>(Property)->SetPropertyValue(Value, Slot != 0);
+        break;
+    case EUikaReifyPropType::Float:
+        FMemory::Memcpy(Value, &Slot, sizeof(float));
+        break;
+    case EUikaReifyPropType::Double:
+    case EUikaReifyPropType::Name:
+        FMemory::Memcpy(Value, &Slot, sizeof(uint64));
+        break;
+    case EUikaReifyPropType::Object:
+    case EUikaReifyPropType::Class:
+        CastFieldChecked<FObjectPropertyBase>(Property)->SetObjectPropertyValue(Value, reinterpret_cast<UObject*>(Slot));
+        break;
+    case EUikaReifyPropType::Enum:
+        if (FEnumProperty* EnumProp = CastField<FEnumProperty>(Property))
+        {
+            FNumericProperty* UnderlyingProp = const_cast<FNumericProperty*>(EnumProp->GetUnderlyingProperty());
+            UnderlyingProp->SetIntPropertyValue(Value, static_cast<int64>(Slot));
+        }
+        else
+        {
+            *static_cast<uint8*>(Value) = static_cast<uint8>(Slot);
+        }
+        break;
+    case EUikaReifyPropType::UInt8:
+    case EUikaReifyPropType::UInt16:
+    case EUikaReifyPropType::UInt32:
+    case EUikaReifyPropType::UInt64:
+        CastFieldChecked<FNumericProperty>(Property)->SetIntPropertyValue(Value, Slot);
+        break;
+    default:
+        CastFieldChecked<FNumericProperty>(Property)->SetIntPropertyValue(Value, static_cast<int64>(Slot));
+        break;
+    }
+}
+
+static EUikaErrorCode GetManyImpl(UikaUObjectHandle Obj, const UikaFPropertyHandle* Props, uint32 Count,
+    uint64* OutValues, uint32* OutKinds)
+{
+    UIKA_CHECK_VALID(Obj);
+    if (Count > 0 && (!Props || !OutValues || !OutKinds)) return EUikaErrorCode::NullArgument;
+    for (uint32 i = 0; i < Count; ++i)
+    {
+        const FProperty* Property = static_cast<const FProperty*>(Props[i].ptr);
+        if (!Property) return EUikaErrorCode::NullArgument;
+        EUikaReifyPropType Kind;
+        if (!GetScalarKind(Property, Kind)) return EUikaErrorCode::TypeMismatch;
+        OutKinds[i] = static_cast<uint32>(Kind);
+        OutValues[i] = ReadSlot(Property, Kind, Object);
+    }
+    return EUikaErrorCode::Ok;
+}
+
+static EUikaErrorCode SetManyImpl(UikaUObjectHandle Obj, const UikaFPropertyHandle* Props, uint32 Count,
+    const uint64* Values, const uint32* Kinds)
+{
+    UIKA_CHECK_VALID(Obj);
+    if (Count > 0 && (!Props || !Values || !Kinds)) return EUikaErrorCode::NullArgument;
+    // Check every property first so a mismatch leaves the object untouched.
+    for (uint32 i = 0; i < Count; ++i)
+    {
+        const FProperty* Property = static_cast<const FProperty*>(Props[i].ptr);
+        if (!Property) return EUikaErrorCode::NullArgument;
+        EUikaReifyPropType Kind;
+        if (!GetScalarKind(Property, Kind)) return EUikaErrorCode::TypeMismatch;
+        const EUikaReifyPropType Given = static_cast<EUikaReifyPropType>(Kinds[i]);
+        if (Given != Kind && !(IsObjectKind(Given) && IsObjectKind(Kind))) return EUikaErrorCode::TypeMismatch;
+    }
+    for (uint32 i = 0; i < Count; ++i)
+    {
+        FProperty* Property = static_cast<FProperty*>(Props[i].ptr);
+        EUikaReifyPropType Kind;
+        GetScalarKind(Property, Kind);
+        WriteSlot(Property, Kind, Object, Values[i]);
+    }
+    return EUikaErrorCode::Ok;
+}
+
 // ---------------------------------------------------------------------------
 // Static instance
 // ---------------------------------------------------------------------------
@@ -420,4 +656,12 @@ FUikaPropertyApi GPropertyApi = {
     // Indexed access (fixed arrays)
     &GetPropertyAtImpl,
     &SetPropertyAtImpl,
+    // Whole-object JSON
+    &ExportJsonImpl,
+    &ImportJsonImpl,
+};
+
+FUikaPropertyBulkApi GPropertyBulkApi = {
+    &GetManyImpl,
+    &SetManyImpl,
 };
@@ -0,0 +1,158 @@
+// UikaPropertyNotifyApiImpl.cpp — FUikaPropertyNotifyApi implementation.
+// Property change subscriptions made from Rust. In the editor, changes come
+// from FCoreUObjectDelegates::OnObjectPropertyChanged (details panel edits,
+// undo/redo, anything calling PostEditChangeProperty); in every build,
+// notify_changed delivers a change made from code. Subscriptions fire back
+// into Rust via invoke_delegate_callback.
+
+#include "UikaApiTable.h"
+#include "UikaFNameHelper.h"
+#include "UObject/UnrealType.h"
+#include "UObject/UObjectGlobals.h"
+
+extern const FUikaRustCallbacks* GetUikaRustCallbacks();
+
+// Subscriptions made from Rust, by the binding id handed out to Rust.
+struct FUikaPropertySubscription
+{
+    TWeakObjectPtr<UObject> Object;
+    bool bAnyObject;
+    FName Property;  // NAME_None = any property
+    uint64 CallbackId;
+};
+static TMap<uint32, FUikaPropertySubscription> GRustPropertySubscriptions;
+static uint32 GNextPropertySubscriptionId = 1;
+
+#if WITH_EDITOR
+static FDelegateHandle GObjectPropertyChangedHandle;
+#endif
+
+static void DispatchPropertyChanged(UObject* Object, FName Property, FName MemberProperty, uint32 ChangeType)
+{
+    const FUikaRustCallbacks* Callbacks = GetUikaRustCallbacks();
+    if (!Object || !Callbacks || !Callbacks->invoke_delegate_callback)
+    {
+        return;
+    }
+
+    // Collect first: a callback may subscribe or unsubscribe.
+    TArray<uint64, TInlineAllocator<4>> CallbackIds;
+    for (const TPair<uint32, FUikaPropertySubscription>& Pair : GRustPropertySubscriptions)
+    {
+        const FUikaPropertySubscription& Sub = Pair.Value;
+        if (!Sub.bAnyObject && Sub.Object.Get() != Object)
+        {
+            continue;
+        }
+        if (!Sub.Property.IsNone() && Sub.Property != Property && Sub.Property != MemberProperty)
+        {
+            continue;
+        }
+        CallbackIds.Add(Sub.CallbackId);
+    }
+
+    for (uint64 CallbackId : CallbackIds)
+    {
+        FUikaPropertyChange Change;
+        Change.object.ptr = Object;
+        Change.property.value = UikaPackFName(Property);
+        Change.member_property.value = UikaPackFName(MemberProperty);
+        Change.change_type = ChangeType;
+        Callbacks->invoke_delegate_callback(CallbackId, reinterpret_cast<uint8*>(&Change));
+    }
+}
+
+#if WITH_EDITOR
+static void OnObjectPropertyChanged(UObject* Object, FPropertyChangedEvent& Event)
+{
+    const FName Property = Event.GetPropertyName();
+    const FName MemberProperty = Event.GetMemberPropertyName();
+    DispatchPropertyChanged(Object, Property, MemberProperty.IsNone() ? Property : MemberProperty,
+        static_cast<uint32>(Event.ChangeType));
+}
+#endif
+
+// ---------------------------------------------------------------------------
+// Implementations
+// ---------------------------------------------------------------------------
+
+static uint32 SubscribeImpl(UikaUObjectHandle Obj, const uint8* Name, uint32 NameLen, uint64 CallbackId)
+{
+    UObject* Object = static_cast<UObject*>(Obj.ptr);
+    if (Object && !::IsValid(Object))
+    {
+        return 0;
+    }
+    if (!Name && NameLen > 0)
+    {
+        return 0;
+    }
+
+    FUikaPropertySubscription Sub;
+    Sub.Object = Object;
+    Sub.bAnyObject = Object == nullptr;
+    Sub.Property = NameLen > 0
+        ? FName(FString(NameLen, UTF8_TO_TCHAR(reinterpret_cast<const char*>(Name))))
+        : NAME_None;
+    Sub.CallbackId = CallbackId;
+
+#if WITH_EDITOR
+    if (!GObjectPropertyChangedHandle.IsValid())
+    {
+        GObjectPropertyChangedHandle = FCoreUObjectDelegates::OnObjectPropertyChanged.AddStatic(&OnObjectPropertyChanged);
+    }
+#endif
+
+    const uint32 Binding = GNextPropertySubscriptionId++;
+    GRustPropertySubscriptions.Add(Binding, Sub);
+    return Binding;
+}
+
+static EUikaErrorCode UnsubscribeImpl(uint32 Binding)
+{
+    GRustPropertySubscriptions.Remove(Binding);
+    return EUikaErrorCode::Ok;
+}
+
+static EUikaErrorCode NotifyChangedImpl(UikaUObjectHandle Obj, UikaFPropertyHandle Prop)
+{
+    UObject* Object = static_cast<UObject*>(Obj.ptr);
+    if (!::IsValid(Object))
+    {
+        return EUikaErrorCode::ObjectDestroyed;
+    }
+    const FProperty* Property = static_cast<const FProperty*>(Prop.ptr);
+    if (!Property)
+    {
+        return EUikaErrorCode::NullArgument;
+    }
+    const FName Name = Property->GetFName();
+    DispatchPropertyChanged(Object, Name, Name, static_cast<uint32>(EPropertyChangeType::ValueSet));
+    return EUikaErrorCode::Ok;
+}
+
+// ---------------------------------------------------------------------------
+// Module helpers
+// ---------------------------------------------------------------------------
+
+void UikaPropertyNotifyRemoveAll()
+{
+#if WITH_EDITOR
+    if (GObjectPropertyChangedHandle.IsValid())
+    {
+        FCoreUObjectDelegates::OnObjectPropertyChanged.Remove(GObjectPropertyChangedHandle);
+        GObjectPropertyChangedHandle.Reset();
+    }
+#endif
+    GRustPropertySubscriptions.Empty();
+}
+
+// ---------------------------------------------------------------------------
+// Static instance
+// ---------------------------------------------------------------------------
+
+FUikaPropertyNotifyApi GPropertyNotifyApi = {
+    &SubscribeImpl,
+    &UnsubscribeImpl,
+    &NotifyChangedImpl,
+};
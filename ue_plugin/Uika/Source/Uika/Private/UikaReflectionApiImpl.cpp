@@ -179,6 +179,67 @@ static EUikaErrorCode DestroyStructImpl(UikaUStructHandle UStructHandle, uint8*
     return EUikaErrorCode::Ok;
 }
 
+// ---------------------------------------------------------------------------
+// Property type information (FUikaPropertyInfoApi)
+// ---------------------------------------------------------------------------
+
+static EUikaErrorCode DescribePropertyImpl(UikaFPropertyHandle Prop, uint32* OutType, FUikaReifyPropExtra* OutExtra)
+{
+    FProperty* Property = static_cast<FProperty*>(Prop.ptr);
+    if (!Property || !OutType || !OutExtra) return EUikaErrorCode::NullArgument;
+
+    *OutExtra = FUikaReifyPropExtra{};
+    EUikaReifyPropType Type;
+    if (CastField<FBoolProperty>(Property))        Type = EUikaReifyPropType::Bool;
+    else if (CastField<FInt8Property>(Property))   Type = EUikaReifyPropType::Int8;
+    else if (CastField<FInt16Property>(Property))  Type = EUikaReifyPropType::Int16;
+    else if (CastField<FIntProperty>(Property))    Type = EUikaReifyPropType::Int32;
+    else if (CastField<FInt64Property>(Property))  Type = EUikaReifyPropType::Int64;
+    else if (CastField<FUInt16Property>(Property)) Type = EUikaReifyPropType::UInt16;
+    else if (CastField<FUInt32Property>(Property)) Type = EUikaReifyPropType::UInt32;
+    else if (CastField<FUInt64Property>(Property)) Type = EUikaReifyPropType::UInt64;
+    else if (CastField<FFloatProperty>(Property))  Type = EUikaReifyPropType::Float;
+    else if (CastField<FDoubleProperty>(Property)) Type = EUikaReifyPropType::Double;
+    else if (CastField<FStrProperty>(Property))    Type = EUikaReifyPropType::String;
+    else if (CastField<FNameProperty>(Property))   Type = EUikaReifyPropType::Name;
+    else if (CastField<FTextProperty>(Property))   Type = EUikaReifyPropType::Text;
+    else if (FEnumProperty* EnumProp = CastField<FEnumProperty>(Property))
+    {
+        Type = EUikaReifyPropType::Enum;
+        OutExtra->enum_handle = UikaUClassHandle{ EnumProp->GetEnum() };
+        OutExtra->enum_underlying = static_cast<uint32>(EnumProp->GetUnderlyingProperty()->GetSize());
+    }
+    else if (FByteProperty* ByteProp = CastField<FByteProperty>(Property))
+    {
+        // TEnumAsByte is a byte property with an enum attached.
+        Type = ByteProp->Enum ? EUikaReifyPropType::Enum : EUikaReifyPropType::UInt8;
+        OutExtra->enum_handle = UikaUClassHandle{ ByteProp->Enum };
+        OutExtra->enum_underlying = ByteProp->Enum ? 1 : 0;
+    }
+    else if (FClassProperty* ClassProp = CastField<FClassProperty>(Property))
+    {
+        Type = EUikaReifyPropType::Class;
+        OutExtra->class_handle = UikaUClassHandle{ ClassProp->PropertyClass };
+        OutExtra->meta_class_handle = UikaUClassHandle{ ClassProp->MetaClass };
+    }
+    else if (FObjectProperty* ObjectProp = CastField<FObjectProperty>(Property))
+    {
+        Type = EUikaReifyPropType::Object;
+        OutExtra->class_handle = UikaUClassHandle{ ObjectProp->PropertyClass };
+    }
+    else if (FStructProperty* StructProp = CastField<FStructProperty>(Property))
+    {
+        Type = EUikaReifyPropType::Struct;
+        OutExtra->struct_handle = UikaUStructHandle{ StructProp->Struct };
+    }
+    else
+    {
+        return EUikaErrorCode::TypeMismatch;
+    }
+    *OutType = static_cast<uint32>(Type);
+    return EUikaErrorCode::Ok;
+}
+
 // ---------------------------------------------------------------------------
 // Static instance
 // ---------------------------------------------------------------------------
@@ -202,3 +263,7 @@ FUikaReflectionApi GReflectionApi = {
     &InitializeStructImpl,
     &DestroyStructImpl,
 };
+
+FUikaPropertyInfoApi GPropertyInfoApi = {
+    &DescribePropertyImpl,
+};
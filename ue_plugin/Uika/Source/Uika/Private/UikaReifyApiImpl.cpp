@@ -494,11 +494,19 @@ static UikaUFunctionHandle AddFunctionImpl(
     UUikaReifiedFunction* NewFunc = NewObject<UUikaReifiedFunction>(
         Class, FName(*FuncName), RF_Public | RF_MarkAsNative);
 
+    // BlueprintImplementableEvents are created without FUNC_Native: they have
+    // no Rust body, and ProcessEvent skips them unless a Blueprint subclass
+    // provides an override.
+    const bool bNative = (static_cast<EFunctionFlags>(FuncFlags) & FUNC_Native) != 0;
+
     NewFunc->CallbackId = CallbackId;
-    NewFunc->FunctionFlags = static_cast<EFunctionFlags>(FuncFlags) | FUNC_Native;
+    NewFunc->FunctionFlags = static_cast<EFunctionFlags>(FuncFlags);
 
     // Set the native function pointer to the thunk.
-    NewFunc->SetNativeFunc(&UUikaReifiedFunction::execCallRustFunction);
+    if (bNative)
+    {
+        NewFunc->SetNativeFunc(&UUikaReifiedFunction::execCallRustFunction);
+    }
 
     // Link into the class's Children list so TFieldIterator<UFunction> can
     // discover it (used by Blueprint action menu, StaticLink, etc.).
@@ -514,6 +522,12 @@ static UikaUFunctionHandle AddFunctionImpl(
             ? Class->GetSuperClass()->FindFunctionByName(NewFunc->GetFName())
             : nullptr;
 
+        // Not on a parent class: an implementation of a declared interface?
+        for (int32 i = 0; !ParentFunc && i < Class->Interfaces.Num(); ++i)
+        {
+            ParentFunc = Class->Interfaces[i].Class->FindFunctionByName(NewFunc->GetFName());
+        }
+
         if (ParentFunc)
         {
             CopyParamsFromParentFunction(NewFunc, ParentFunc);
@@ -524,7 +538,10 @@ static UikaUFunctionHandle AddFunctionImpl(
     }
 
     // Register the native function name for the VM.
-    Class->AddNativeFunction(*FuncName, &UUikaReifiedFunction::execCallRustFunction);
+    if (bNative)
+    {
+        Class->AddNativeFunction(*FuncName, &UUikaReifiedFunction::execCallRustFunction);
+    }
     Class->AddFunctionToFunctionMap(NewFunc, NewFunc->GetFName());
 
     return UikaUFunctionHandle{ NewFunc };
@@ -621,6 +638,16 @@ static EUikaErrorCode FinalizeClassImpl(UikaUClassHandle Cls)
     Class->Bind();
     Class->StaticLink(true);
 
+    // RPCs need the class's NetFields (RPC ids) before they can be replicated.
+    for (TFieldIterator<UFunction> FuncIt(Class, EFieldIteratorFlags::ExcludeSuper); FuncIt; ++FuncIt)
+    {
+        if (FuncIt->HasAnyFunctionFlags(FUNC_Net))
+        {
+            Class->SetUpRuntimeReplicationData();
+            break;
+        }
+    }
+
     // Build the GC reference token stream so the garbage collector can
     // properly trace UObject* references within instances of this class.
     Class->AssembleReferenceTokenStream(true);
@@ -706,6 +733,7 @@ static EUikaErrorCode AddDefaultSubobjectImpl(
     Def.ComponentClass = CompUClass;
     Def.bIsRoot = (Flags & 1) != 0;
     Def.bIsTransient = (Flags & 2) != 0;
+    Def.bAttachToRoot = (Flags & 4) != 0;
     Def.AttachParentName = AttachLen > 0
         ? FName(ReifyUtf8ToFString(AttachParent, AttachLen))
         : NAME_None;
@@ -734,6 +762,158 @@ static UikaUObjectHandle FindDefaultSubobjectImpl(
     return UikaUObjectHandle{ Sub };
 }
 
+static EUikaErrorCode SetFunctionValidateImpl(
+    UikaUFunctionHandle Func,
+    UikaUFunctionHandle ValidateFunc)
+{
+    UUikaReifiedFunction* Function = Cast<UUikaReifiedFunction>(static_cast<UFunction*>(Func.ptr));
+    UUikaReifiedFunction* Validate = Cast<UUikaReifiedFunction>(static_cast<UFunction*>(ValidateFunc.ptr));
+    if (!Function || !Validate)
+    {
+        return EUikaErrorCode::NullArgument;
+    }
+    if (!Function->HasAnyFunctionFlags(FUNC_Net))
+    {
+        return EUikaErrorCode::InvalidOperation;
+    }
+
+    Function->ValidateFunction = Validate;
+    Function->FunctionFlags |= FUNC_NetValidate;
+    return EUikaErrorCode::Ok;
+}
+
+static UikaUStructHandle CreateStructImpl(const uint8* Name, uint32 NameLen)
+{
+    const FString StructName = ReifyUtf8ToFString(Name, NameLen);
+    UPackage* UikaPackage = GetOrCreateUikaPackage();
+
+    // --- Hot reload path: reuse the struct created by the previous DLL ---
+    if (UScriptStruct* Existing = FindObject<UScriptStruct>(UikaPackage, *StructName))
+    {
+        UE_LOG(LogUika, Display, TEXT("[Uika] Hot reload: reusing existing struct %s"), *StructName);
+        return UikaUStructHandle{ Existing };
+    }
+
+    UScriptStruct* NewStruct = NewObject<UScriptStruct>(
+        UikaPackage, FName(*StructName), RF_Public | RF_Standalone);
+
+#if WITH_EDITORONLY_DATA
+    // Allow the struct as a Blueprint variable / pin type.
+    NewStruct->SetMetaData(TEXT("BlueprintType"), TEXT("true"));
+#endif
+
+    // Prevent garbage collection.
+    NewStruct->AddToRoot();
+
+    UE_LOG(LogUika, Display, TEXT("[Uika] Created reified struct: %s"), *StructName);
+    return UikaUStructHandle{ NewStruct };
+}
+
+static UikaFPropertyHandle AddStructPropertyImpl(
+    UikaUStructHandle Struct,
+    const uint8* Name, uint32 NameLen,
+    uint32 PropType, uint64 PropFlags,
+    const FUikaReifyPropExtra* Extra)
+{
+    UScriptStruct* ScriptStruct = static_cast<UScriptStruct*>(Struct.ptr);
+    if (!ScriptStruct)
+    {
+        return UikaFPropertyHandle{ nullptr };
+    }
+
+    const FName PropName = ReifyUtf8ToFName(Name, NameLen);
+
+    // --- Hot reload path: if a property with this name already exists, reuse it ---
+    for (FField* Field = ScriptStruct->ChildProperties; Field; Field = Field->Next)
+    {
+        if (Field->GetFName() == PropName)
+        {
+            return UikaFPropertyHandle{ CastField<FProperty>(Field) };
+        }
+    }
+
+    FProperty* Prop = CreatePropertyByType(
+        FFieldVariant(ScriptStruct),
+        PropName,
+        static_cast<EUikaReifyPropType>(PropType),
+        Extra);
+
+    if (!Prop)
+    {
+        return UikaFPropertyHandle{ nullptr };
+    }
+
+    Prop->PropertyFlags |= static_cast<EPropertyFlags>(PropFlags);
+
+    // Append (AddCppProperty prepends) so the layout follows declaration order.
+    if (!ScriptStruct->ChildProperties)
+    {
+        ScriptStruct->ChildProperties = Prop;
+    }
+    else
+    {
+        FField* Last = ScriptStruct->ChildProperties;
+        while (Last->Next)
+        {
+            Last = Last->Next;
+        }
+        Last->Next = Prop;
+    }
+
+    return UikaFPropertyHandle{ Prop };
+}
+
+static EUikaErrorCode FinalizeStructImpl(UikaUStructHandle Struct)
+{
+    UScriptStruct* ScriptStruct = static_cast<UScriptStruct*>(Struct.ptr);
+    if (!ScriptStruct)
+    {
+        return EUikaErrorCode::NullArgument;
+    }
+
+    // Hot reload path: already linked, layout must not change under live data.
+    if (ScriptStruct->PropertyLink)
+    {
+        return EUikaErrorCode::Ok;
+    }
+
+    ScriptStruct->Bind();
+    ScriptStruct->StaticLink(true);
+
+    UE_LOG(LogUika, Display, TEXT("[Uika] Finalized reified struct: %s (size: %d)"),
+        *ScriptStruct->GetName(), ScriptStruct->GetStructureSize());
+    return EUikaErrorCode::Ok;
+}
+
+static EUikaErrorCode AddInterfaceImpl(UikaUClassHandle Cls, UikaUClassHandle Interface)
+{
+    UClass* Class = static_cast<UClass*>(Cls.ptr);
+    UClass* InterfaceClass = static_cast<UClass*>(Interface.ptr);
+    if (!Class || !InterfaceClass)
+    {
+        return EUikaErrorCode::NullArgument;
+    }
+    if (!InterfaceClass->HasAnyClassFlags(CLASS_Interface))
+    {
+        UE_LOG(LogUika, Error, TEXT("[Uika] AddInterface: %s is not an interface"), *InterfaceClass->GetName());
+        return EUikaErrorCode::TypeMismatch;
+    }
+
+    // Already implemented (inherited, or hot reload re-registering the class).
+    if (Class->ImplementsInterface(InterfaceClass))
+    {
+        return EUikaErrorCode::Ok;
+    }
+
+    // No native vtable: implemented the way Blueprint classes implement
+    // interfaces, so calls go through ProcessEvent (Execute_* thunks).
+    Class->Interfaces.Emplace(InterfaceClass, 0, /*bImplementedByK2=*/true);
+
+    UE_LOG(LogUika, Display, TEXT("[Uika] %s implements %s"),
+        *Class->GetName(), *InterfaceClass->GetName());
+    return EUikaErrorCode::Ok;
+}
+
 // ---------------------------------------------------------------------------
 // FUikaDeleteListener — Notifies Rust when a reified-class instance is GC'd
 // ---------------------------------------------------------------------------
@@ -815,4 +995,9 @@ FUikaReifyApi GReifyApi = {
     &GetCdoImpl,
     &AddDefaultSubobjectImpl,
     &FindDefaultSubobjectImpl,
+    &SetFunctionValidateImpl,
+    &CreateStructImpl,
+    &AddStructPropertyImpl,
+    &FinalizeStructImpl,
+    &AddInterfaceImpl,
 };
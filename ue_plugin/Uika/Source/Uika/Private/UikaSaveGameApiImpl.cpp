@@ -0,0 +1,192 @@
+// UikaSaveGameApiImpl.cpp — FUikaSaveGameApi implementation.
+// Save slots through UGameplayStatics. Async variants run the platform save
+// system on the thread pool and report back on the game thread via
+// invoke_delegate_callback.
+
+#include "UikaApiTable.h"
+#include "Async/Async.h"
+#include "GameFramework/SaveGame.h"
+#include "Kismet/GameplayStatics.h"
+#include "PlatformFeatures.h"
+#include "SaveGameSystem.h"
+
+extern const FUikaRustCallbacks* GetUikaRustCallbacks();
+
+static FString SlotName(const uint8* Slot, uint32 SlotLen)
+{
+    return FString(SlotLen, UTF8_TO_TCHAR(reinterpret_cast<const char*>(Slot)));
+}
+
+// Invoke a Rust completion callback with `Result` (game thread only).
+static void Complete(uint64 CallbackId, FUikaSaveGameResult& Result)
+{
+    const FUikaRustCallbacks* Callbacks = GetUikaRustCallbacks();
+    if (Callbacks && Callbacks->invoke_delegate_callback)
+    {
+        Callbacks->invoke_delegate_callback(CallbackId, reinterpret_cast<uint8*>(&Result));
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Implementations
+// ---------------------------------------------------------------------------
+
+static UikaUObjectHandle CreateSaveGameObjectImpl(UikaUClassHandle Class)
+{
+    UClass* SaveGameClass = static_cast<UClass*>(Class.ptr);
+    if (!SaveGameClass || !SaveGameClass->IsChildOf(USaveGame::StaticClass()))
+    {
+        return UikaUObjectHandle{ nullptr };
+    }
+    return UikaUObjectHandle{ UGameplayStatics::CreateSaveGameObject(SaveGameClass) };
+}
+
+static EUikaErrorCode SaveGameToSlotImpl(UikaUObjectHandle Object, const uint8* Slot, uint32 SlotLen,
+    int32 UserIndex)
+{
+    if (!Slot)
+    {
+        return EUikaErrorCode::NullArgument;
+    }
+    USaveGame* SaveGame = Cast<USaveGame>(static_cast<UObject*>(Object.ptr));
+    if (!::IsValid(SaveGame))
+    {
+        return EUikaErrorCode::InvalidCast;
+    }
+    return UGameplayStatics::SaveGameToSlot(SaveGame, SlotName(Slot, SlotLen), UserIndex)
+        ? EUikaErrorCode::Ok
+        : EUikaErrorCode::InvalidOperation;
+}
+
+static UikaUObjectHandle LoadGameFromSlotImpl(const uint8* Slot, uint32 SlotLen, int32 UserIndex)
+{
+    if (!Slot)
+    {
+        return UikaUObjectHandle{ nullptr };
+    }
+    return UikaUObjectHandle{ UGameplayStatics::LoadGameFromSlot(SlotName(Slot, SlotLen), UserIndex) };
+}
+
+static bool DoesSaveGameExistImpl(const uint8* Slot, uint32 SlotLen, int32 UserIndex)
+{
+    return Slot && UGameplayStatics::DoesSaveGameExist(SlotName(Slot, SlotLen), UserIndex);
+}
+
+static EUikaErrorCode DeleteGameInSlotImpl(const uint8* Slot, uint32 SlotLen, int32 UserIndex)
+{
+    if (!Slot)
+    {
+        return EUikaErrorCode::NullArgument;
+    }
+    const FString Name = SlotName(Slot, SlotLen);
+    if (!UGameplayStatics::DoesSaveGameExist(Name, UserIndex))
+    {
+        return EUikaErrorCode::Ok;
+    }
+    return UGameplayStatics::DeleteGameInSlot(Name, UserIndex)
+        ? EUikaErrorCode::Ok
+        : EUikaErrorCode::InvalidOperation;
+}
+
+static EUikaErrorCode SaveDataToSlotImpl(const uint8* Data, uint32 Len, const uint8* Slot, uint32 SlotLen,
+    int32 UserIndex)
+{
+    if (!Slot || (!Data && Len > 0))
+    {
+        return EUikaErrorCode::NullArgument;
+    }
+    const TArray<uint8> Bytes(Data, Len);
+    return UGameplayStatics::SaveDataToSlot(Bytes, SlotName(Slot, SlotLen), UserIndex)
+        ? EUikaErrorCode::Ok
+        : EUikaErrorCode::InvalidOperation;
+}
+
+static EUikaErrorCode LoadDataFromSlotImpl(const uint8* Slot, uint32 SlotLen, int32 UserIndex,
+    uint8* Buf, uint32 BufLen, uint32* OutLen)
+{
+    if (!Slot || !OutLen)
+    {
+        return EUikaErrorCode::NullArgument;
+    }
+    TArray<uint8> Bytes;
+    if (!UGameplayStatics::LoadDataFromSlot(Bytes, SlotName(Slot, SlotLen), UserIndex))
+    {
+        return EUikaErrorCode::InvalidOperation;
+    }
+    *OutLen = static_cast<uint32>(Bytes.Num());
+    if (*OutLen > BufLen || (*OutLen > 0 && !Buf))
+    {
+        return EUikaErrorCode::BufferTooSmall;
+    }
+    FMemory::Memcpy(Buf, Bytes.GetData(), *OutLen);
+    return EUikaErrorCode::Ok;
+}
+
+static EUikaErrorCode AsyncSaveDataToSlotImpl(const uint8* Data, uint32 Len, const uint8* Slot,
+    uint32 SlotLen, int32 UserIndex, uint64 CallbackId)
+{
+    if (!Slot || (!Data && Len > 0))
+    {
+        return EUikaErrorCode::NullArgument;
+    }
+    ISaveGameSystem* SaveSystem = IPlatformFeaturesModule::Get().GetSaveGameSystem();
+    if (!SaveSystem)
+    {
+        return EUikaErrorCode::InvalidOperation;
+    }
+
+    Async(EAsyncExecution::ThreadPool,
+        [SaveSystem, Bytes = TArray<uint8>(Data, Len), Name = SlotName(Slot, SlotLen), UserIndex, CallbackId]()
+        {
+            const bool bSuccess = SaveSystem->SaveGame(false, *Name, UserIndex, Bytes);
+            AsyncTask(ENamedThreads::GameThread, [bSuccess, CallbackId]()
+            {
+                FUikaSaveGameResult Result{ bSuccess, nullptr, 0 };
+                Complete(CallbackId, Result);
+            });
+        });
+    return EUikaErrorCode::Ok;
+}
+
+static EUikaErrorCode AsyncLoadDataFromSlotImpl(const uint8* Slot, uint32 SlotLen, int32 UserIndex,
+    uint64 CallbackId)
+{
+    if (!Slot)
+    {
+        return EUikaErrorCode::NullArgument;
+    }
+    ISaveGameSystem* SaveSystem = IPlatformFeaturesModule::Get().GetSaveGameSystem();
+    if (!SaveSystem)
+    {
+        return EUikaErrorCode::InvalidOperation;
+    }
+
+    Async(EAsyncExecution::ThreadPool, [SaveSystem, Name = SlotName(Slot, SlotLen), UserIndex, CallbackId]()
+    {
+        TArray<uint8> Bytes;
+        const bool bSuccess = SaveSystem->LoadGame(false, *Name, UserIndex, Bytes);
+        AsyncTask(ENamedThreads::GameThread, [bSuccess, Bytes = MoveTemp(Bytes), CallbackId]()
+        {
+            FUikaSaveGameResult Result{ bSuccess, bSuccess ? Bytes.GetData() : nullptr,
+                bSuccess ? static_cast<uint32>(Bytes.Num()) : 0 };
+            Complete(CallbackId, Result);
+        });
+    });
+    return EUikaErrorCode::Ok;
+}
+
+// ---------------------------------------------------------------------------
+// Static instance
+// ---------------------------------------------------------------------------
+
+FUikaSaveGameApi GSaveGameApi = {
+    &CreateSaveGameObjectImpl,
+    &SaveGameToSlotImpl,
+    &LoadGameFromSlotImpl,
+    &DoesSaveGameExistImpl,
+    &DeleteGameInSlotImpl,
+    &SaveDataToSlotImpl,
+    &LoadDataFromSlotImpl,
+    &AsyncSaveDataToSlotImpl,
+    &AsyncLoadDataFromSlotImpl,
+};
@@ -0,0 +1,123 @@
+// UikaSubsystemApiImpl.cpp — FUikaSubsystemApi implementation.
+// Class-based subsystem lookup for world, game instance, local player and
+// engine subsystems.
+
+#include "UikaApiTable.h"
+#include "Engine/Engine.h"
+#include "Engine/GameInstance.h"
+#include "Engine/LocalPlayer.h"
+#include "Engine/World.h"
+#include "GameFramework/PlayerController.h"
+#include "Subsystems/EngineSubsystem.h"
+#include "Subsystems/GameInstanceSubsystem.h"
+#include "Subsystems/LocalPlayerSubsystem.h"
+#include "Subsystems/WorldSubsystem.h"
+
+// The class, if it derives from `Base`.
+static UClass* SubsystemClass(UikaUClassHandle ClsHandle, UClass* Base)
+{
+    UClass* Class = static_cast<UClass*>(ClsHandle.ptr);
+    return Class && Class->IsChildOf(Base) ? Class : nullptr;
+}
+
+static UWorld* WorldOf(UikaUObjectHandle Context)
+{
+    UObject* Obj = static_cast<UObject*>(Context.ptr);
+    if (!::IsValid(Obj) || !GEngine)
+    {
+        return nullptr;
+    }
+    return GEngine->GetWorldFromContextObject(Obj, EGetWorldErrorMode::ReturnNull);
+}
+
+// The game instance of the context's world, or of the first game (or PIE)
+// world when there is no context.
+static UGameInstance* GameInstanceOf(UikaUObjectHandle Context)
+{
+    if (Context.ptr)
+    {
+        UWorld* World = WorldOf(Context);
+        return World ? World->GetGameInstance() : nullptr;
+    }
+    if (!GEngine)
+    {
+        return nullptr;
+    }
+    for (const FWorldContext& WorldContext : GEngine->GetWorldContexts())
+    {
+        if (WorldContext.WorldType == EWorldType::Game || WorldContext.WorldType == EWorldType::PIE)
+        {
+            if (UGameInstance* GameInstance = WorldContext.OwningGameInstance)
+            {
+                return GameInstance;
+            }
+        }
+    }
+    return nullptr;
+}
+
+// ---------------------------------------------------------------------------
+// Implementations
+// ---------------------------------------------------------------------------
+
+static UikaUObjectHandle GetWorldSubsystemImpl(UikaUObjectHandle Context, UikaUClassHandle ClsHandle)
+{
+    UClass* Class = SubsystemClass(ClsHandle, UWorldSubsystem::StaticClass());
+    UWorld* World = WorldOf(Context);
+    if (!Class || !World)
+    {
+        return UikaUObjectHandle{ nullptr };
+    }
+    return UikaUObjectHandle{ World->GetSubsystemBase(Class) };
+}
+
+static UikaUObjectHandle GetGameInstanceSubsystemImpl(UikaUObjectHandle Context, UikaUClassHandle ClsHandle)
+{
+    UClass* Class = SubsystemClass(ClsHandle, UGameInstanceSubsystem::StaticClass());
+    UGameInstance* GameInstance = GameInstanceOf(Context);
+    if (!Class || !GameInstance)
+    {
+        return UikaUObjectHandle{ nullptr };
+    }
+    return UikaUObjectHandle{ GameInstance->GetSubsystemBase(Class) };
+}
+
+static UikaUObjectHandle GetLocalPlayerSubsystemImpl(UikaUObjectHandle Player, UikaUClassHandle ClsHandle)
+{
+    UClass* Class = SubsystemClass(ClsHandle, ULocalPlayerSubsystem::StaticClass());
+    UObject* Obj = static_cast<UObject*>(Player.ptr);
+    if (!Class || !::IsValid(Obj))
+    {
+        return UikaUObjectHandle{ nullptr };
+    }
+    ULocalPlayer* LocalPlayer = Cast<ULocalPlayer>(Obj);
+    if (!LocalPlayer)
+    {
+        if (APlayerController* Controller = Cast<APlayerController>(Obj))
+        {
+            LocalPlayer = Controller->GetLocalPlayer();
+        }
+    }
+    return UikaUObjectHandle{ LocalPlayer ? LocalPlayer->GetSubsystemBase(Class) : nullptr };
+}
+
+static UikaUObjectHandle GetEngineSubsystemImpl(UikaUClassHandle ClsHandle)
+{
+    UClass* Class = SubsystemClass(ClsHandle, UEngineSubsystem::StaticClass());
+    if (!Class || !GEngine)
+    {
+        return UikaUObjectHandle{ nullptr };
+    }
+    return UikaUObjectHandle{ GEngine->GetEngineSubsystemBase(Class) };
+}
+
+// ---------------------------------------------------------------------------
+// Static instance
+// ---------------------------------------------------------------------------
+
+FUikaSubsystemApi GSubsystemApi = {
+    &GetWorldSubsystemImpl,
+    &GetGameInstanceSubsystemImpl,
+    &GetLocalPlayerSubsystemImpl,
+    &GetEngineSubsystemImpl,
+};
@@ -0,0 +1,20 @@
+#include "UikaTestRunnerCommandlet.h"
+#include "UikaModule.h"
+
+UUikaTestRunnerCommandlet::UUikaTestRunnerCommandlet()
+{
+    IsClient = false;
+    IsEditor = true;
+    IsServer = false;
+    LogToConsole = true;
+}
+
+int32 UUikaTestRunnerCommandlet::Main(const FString& Params)
+{
+    FString Filter;
+    FParse::Value(*Params, TEXT("Filter="), Filter);
+
+    FUikaModule& Module = FModuleManager::GetModuleChecked<FUikaModule>(TEXT("Uika"));
+    const int32 Failed = Module.RunRustTests(Filter);
+    return Failed == 0 ? 0 : 1;
+}
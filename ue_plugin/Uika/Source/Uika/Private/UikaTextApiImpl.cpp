@@ -0,0 +1,166 @@
+// UikaTextApiImpl.cpp — FUikaTextApi implementation.
+// Texts are handed to Rust as heap-allocated FText copies so localization
+// identity (namespace/key, string table) survives the round trip.
+
+#include "UikaApiTable.h"
+#include "Internationalization/Text.h"
+#include "UObject/UnrealType.h"
+
+static FString Utf8ToFString(const uint8* Utf8, uint32 Len)
+{
+    return FString(Len, UTF8_TO_TCHAR(reinterpret_cast<const char*>(Utf8)));
+}
+
+static UikaFTextHandle MakeHandle(const FText& Text)
+{
+    return UikaFTextHandle{ new FText(Text) };
+}
+
+static const FText* AsText(UikaFTextHandle Handle)
+{
+    return static_cast<const FText*>(Handle.ptr);
+}
+
+// ---------------------------------------------------------------------------
+// Implementations
+// ---------------------------------------------------------------------------
+
+static UikaFTextHandle FromStringImpl(const uint8* Utf8, uint32 Len)
+{
+    return MakeHandle(FText::FromString(Utf8ToFString(Utf8, Len)));
+}
+
+static UikaFTextHandle FromStringTableImpl(const uint8* TableId, uint32 TableIdLen,
+                                            const uint8* Key, uint32 KeyLen)
+{
+    if (!TableId || !Key)
+    {
+        return UikaFTextHandle{ nullptr };
+    }
+    const FName TableName(Utf8ToFString(TableId, TableIdLen));
+    return MakeHandle(FText::FromStringTable(TableName, Utf8ToFString(Key, KeyLen)));
+}
+
+static UikaFTextHandle AsLocalizableImpl(const uint8* Ns, uint32 NsLen,
+                                          const uint8* Key, uint32 KeyLen,
+                                          const uint8* Source, uint32 SourceLen)
+{
+    if (!Key || !Source)
+    {
+        return UikaFTextHandle{ nullptr };
+    }
+    const FString Namespace = Ns ? Utf8ToFString(Ns, NsLen) : FString();
+    return MakeHandle(FText::AsLocalizable_Advanced(
+        *Namespace, *Utf8ToFString(Key, KeyLen), Utf8ToFString(Source, SourceLen)));
+}
+
+static EUikaErrorCode ToStringImpl(UikaFTextHandle Text, uint8* Buf, uint32 BufLen, uint32* OutLen)
+{
+    const FText* Ptr = AsText(Text);
+    if (!Ptr)
+    {
+        return EUikaErrorCode::NullArgument;
+    }
+
+    const FTCHARToUTF8 Utf8(*Ptr->ToString());
+    const uint32 Len = static_cast<uint32>(Utf8.Length());
+    if (OutLen)
+    {
+        *OutLen = Len;
+    }
+    if (Len > BufLen)
+    {
+        return EUikaErrorCode::BufferTooSmall;
+    }
+    if (Buf && Len > 0)
+    {
+        FMemory::Memcpy(Buf, Utf8.Get(), Len);
+    }
+    return EUikaErrorCode::Ok;
+}
+
+static bool IsEmptyImpl(UikaFTextHandle Text)
+{
+    const FText* Ptr = AsText(Text);
+    return !Ptr || Ptr->IsEmpty();
+}
+
+static bool IdenticalToImpl(UikaFTextHandle A, UikaFTextHandle B)
+{
+    const FText* PtrA = AsText(A);
+    const FText* PtrB = AsText(B);
+    if (!PtrA || !PtrB)
+    {
+        return PtrA == PtrB;
+    }
+    return PtrA->IdenticalTo(*PtrB);
+}
+
+static UikaFTextHandle CloneImpl(UikaFTextHandle Text)
+{
+    const FText* Ptr = AsText(Text);
+    return Ptr ? MakeHandle(*Ptr) : UikaFTextHandle{ nullptr };
+}
+
+static void DestroyImpl(UikaFTextHandle Text)
+{
+    delete static_cast<FText*>(Text.ptr);
+}
+
+static EUikaErrorCode GetPropertyImpl(UikaUObjectHandle Obj, UikaFPropertyHandle Prop, UikaFTextHandle* Out)
+{
+    void* Object = Obj.ptr;
+    if (!Object)
+    {
+        return EUikaErrorCode::ObjectDestroyed;
+    }
+    if (!Out)
+    {
+        return EUikaErrorCode::NullArgument;
+    }
+    const FTextProperty* TextProp = CastField<FTextProperty>(static_cast<FProperty*>(Prop.ptr));
+    if (!TextProp)
+    {
+        return EUikaErrorCode::TypeMismatch;
+    }
+    *Out = MakeHandle(TextProp->GetPropertyValue_InContainer(Object));
+    return EUikaErrorCode::Ok;
+}
+
+static EUikaErrorCode SetPropertyImpl(UikaUObjectHandle Obj, UikaFPropertyHandle Prop, UikaFTextHandle Text)
+{
+    void* Object = Obj.ptr;
+    if (!Object)
+    {
+        return EUikaErrorCode::ObjectDestroyed;
+    }
+    const FText* Ptr = AsText(Text);
+    if (!Ptr)
+    {
+        return EUikaErrorCode::NullArgument;
+    }
+    FTextProperty* TextProp = CastField<FTextProperty>(static_cast<FProperty*>(Prop.ptr));
+    if (!TextProp)
+    {
+        return EUikaErrorCode::TypeMismatch;
+    }
+    TextProp->SetPropertyValue_InContainer(Object, *Ptr);
+    return EUikaErrorCode::Ok;
+}
+
+// ---------------------------------------------------------------------------
+// Static instance
+// ---------------------------------------------------------------------------
+
+FUikaTextApi GTextApi = {
+    &FromStringImpl,
+    &FromStringTableImpl,
+    &AsLocalizableImpl,
+    &ToStringImpl,
+    &IsEmptyImpl,
+    &IdenticalToImpl,
+    &CloneImpl,
+    &DestroyImpl,
+    &GetPropertyImpl,
+    &SetPropertyImpl,
+};
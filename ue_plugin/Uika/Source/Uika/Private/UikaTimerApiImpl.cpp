@@ -0,0 +1,160 @@
+// UikaTimerApiImpl.cpp — FUikaTimerApi implementation.
+// Wraps FTimerManager. Timers fire back into Rust via invoke_delegate_callback.
+
+#include "UikaApiTable.h"
+#include "Engine/World.h"
+#include "Engine/Engine.h"
+#include "TimerManager.h"
+
+extern const FUikaRustCallbacks* GetUikaRustCallbacks();
+
+// FTimerHandle is a single uint64; it is passed across the FFI as that value.
+static_assert(sizeof(FTimerHandle) == sizeof(uint64), "FTimerHandle layout changed");
+
+// Every timer set from Rust, so they can all be cleared before DLL unload.
+struct FUikaActiveTimer
+{
+    TWeakObjectPtr<UWorld> World;
+    FTimerHandle Handle;
+};
+static TArray<FUikaActiveTimer> GRustTimers;
+
+static UWorld* WorldFromContext(UikaUObjectHandle Context)
+{
+    UObject* Object = static_cast<UObject*>(Context.ptr);
+    if (!::IsValid(Object) || !GEngine)
+    {
+        return nullptr;
+    }
+    return GEngine->GetWorldFromContextObject(Object, EGetWorldErrorMode::ReturnNull);
+}
+
+static FTimerHandle UnpackHandle(uint64 Packed)
+{
+    FTimerHandle Handle;
+    FMemory::Memcpy(&Handle, &Packed, sizeof(Packed));
+    return Handle;
+}
+
+static uint64 PackHandle(const FTimerHandle& Handle)
+{
+    uint64 Packed = 0;
+    FMemory::Memcpy(&Packed, &Handle, sizeof(Packed));
+    return Packed;
+}
+
+// ---------------------------------------------------------------------------
+// Implementations
+// ---------------------------------------------------------------------------
+
+static uint64 SetTimerImpl(UikaUObjectHandle Context, uint64 CallbackId,
+    float Rate, bool bLooping, float FirstDelay)
+{
+    UWorld* World = WorldFromContext(Context);
+    if (!World)
+    {
+        return 0;
+    }
+
+    // The handle is only known once the timer is set; the delegate reads it
+    // when it fires, to drop a one-shot timer's GRustTimers entry.
+    TSharedRef<FTimerHandle> HandleSlot = MakeShared<FTimerHandle>();
+    FTimerDelegate Delegate = FTimerDelegate::CreateLambda([CallbackId, bLooping, HandleSlot]()
+    {
+        if (!bLooping)
+        {
+            const FTimerHandle Fired = *HandleSlot;
+            GRustTimers.RemoveAll([&Fired](const FUikaActiveTimer& T) { return T.Handle == Fired; });
+        }
+        const FUikaRustCallbacks* Callbacks = GetUikaRustCallbacks();
+        if (Callbacks && Callbacks->invoke_delegate_callback)
+        {
+            Callbacks->invoke_delegate_callback(CallbackId, nullptr);
+        }
+    });
+
+    World->GetTimerManager().SetTimer(*HandleSlot, Delegate, Rate, bLooping, FirstDelay);
+    const FTimerHandle Handle = *HandleSlot;
+    if (!Handle.IsValid())
+    {
+        return 0;
+    }
+
+    GRustTimers.RemoveAll([](const FUikaActiveTimer& T) { return !T.World.IsValid(); });
+    GRustTimers.Add({ World, Handle });
+    return PackHandle(Handle);
+}
+
+static EUikaErrorCode ClearTimerImpl(UikaUObjectHandle Context, uint64 Timer)
+{
+    UWorld* World = WorldFromContext(Context);
+    if (!World)
+    {
+        return EUikaErrorCode::ObjectDestroyed;
+    }
+    FTimerHandle Handle = UnpackHandle(Timer);
+    GRustTimers.RemoveAll([&Handle](const FUikaActiveTimer& T) { return T.Handle == Handle; });
+    World->GetTimerManager().ClearTimer(Handle);
+    return EUikaErrorCode::Ok;
+}
+
+static EUikaErrorCode PauseTimerImpl(UikaUObjectHandle Context, uint64 Timer, bool bPaused)
+{
+    UWorld* World = WorldFromContext(Context);
+    if (!World)
+    {
+        return EUikaErrorCode::ObjectDestroyed;
+    }
+    const FTimerHandle Handle = UnpackHandle(Timer);
+    FTimerManager& TimerManager = World->GetTimerManager();
+    if (!TimerManager.TimerExists(Handle))
+    {
+        return EUikaErrorCode::InvalidOperation;
+    }
+    if (bPaused)
+    {
+        TimerManager.PauseTimer(Handle);
+    }
+    else
+    {
+        TimerManager.UnPauseTimer(Handle);
+    }
+    return EUikaErrorCode::Ok;
+}
+
+static bool IsTimerActiveImpl(UikaUObjectHandle Context, uint64 Timer)
+{
+    UWorld* World = WorldFromContext(Context);
+    if (!World)
+    {
+        return false;
+    }
+    return World->GetTimerManager().IsTimerActive(UnpackHandle(Timer));
+}
+
+// ---------------------------------------------------------------------------
+// Module helpers
+// ---------------------------------------------------------------------------
+
+void UikaTimerClearAll()
+{
+    for (FUikaActiveTimer& Timer : GRustTimers)
+    {
+        if (UWorld* World = Timer.World.Get())
+        {
+            World->GetTimerManager().ClearTimer(Timer.Handle);
+        }
+    }
+    GRustTimers.Empty();
+}
+
+// ---------------------------------------------------------------------------
+// Static instance
+// ---------------------------------------------------------------------------
+
+FUikaTimerApi GTimerApi = {
+    &SetTimerImpl,
+    &ClearTimerImpl,
+    &PauseTimerImpl,
+    &IsTimerActiveImpl,
+};
@@ -0,0 +1,109 @@
+// UikaUiApiImpl.cpp — FUikaUiApi implementation.
+// Viewport, visibility and widget-tree lookup for existing UMG widgets.
+
+#include "UikaApiTable.h"
+#include "Blueprint/UserWidget.h"
+#include "Blueprint/WidgetTree.h"
+#include "Components/Widget.h"
+
+static UWidget* ToWidget(UikaUObjectHandle Handle)
+{
+    UWidget* Widget = Cast<UWidget>(static_cast<UObject*>(Handle.ptr));
+    return ::IsValid(Widget) ? Widget : nullptr;
+}
+
+static UUserWidget* ToUserWidget(UikaUObjectHandle Handle)
+{
+    UUserWidget* Widget = Cast<UUserWidget>(static_cast<UObject*>(Handle.ptr));
+    return ::IsValid(Widget) ? Widget : nullptr;
+}
+
+// ---------------------------------------------------------------------------
+// Implementations
+// ---------------------------------------------------------------------------
+
+static EUikaErrorCode AddToViewportImpl(UikaUObjectHandle Handle, int32 ZOrder, bool bPlayerScreen)
+{
+    UUserWidget* Widget = ToUserWidget(Handle);
+    if (!Widget)
+    {
+        return EUikaErrorCode::InvalidCast;
+    }
+    if (bPlayerScreen)
+    {
+        return Widget->AddToPlayerScreen(ZOrder) ? EUikaErrorCode::Ok : EUikaErrorCode::InvalidOperation;
+    }
+    Widget->AddToViewport(ZOrder);
+    return EUikaErrorCode::Ok;
+}
+
+static EUikaErrorCode RemoveFromParentImpl(UikaUObjectHandle Handle)
+{
+    UWidget* Widget = ToWidget(Handle);
+    if (!Widget)
+    {
+        return EUikaErrorCode::InvalidCast;
+    }
+    Widget->RemoveFromParent();
+    return EUikaErrorCode::Ok;
+}
+
+static bool IsInViewportImpl(UikaUObjectHandle Handle)
+{
+    UUserWidget* Widget = ToUserWidget(Handle);
+    return Widget && Widget->IsInViewport();
+}
+
+static EUikaErrorCode SetVisibilityImpl(UikaUObjectHandle Handle, uint8 Visibility)
+{
+    UWidget* Widget = ToWidget(Handle);
+    if (!Widget)
+    {
+        return EUikaErrorCode::InvalidCast;
+    }
+    if (Visibility > static_cast<uint8>(ESlateVisibility::SelfHitTestInvisible))
+    {
+        return EUikaErrorCode::InvalidOperation;
+    }
+    Widget->SetVisibility(static_cast<ESlateVisibility>(Visibility));
+    return EUikaErrorCode::Ok;
+}
+
+static EUikaErrorCode GetVisibilityImpl(UikaUObjectHandle Handle, uint8* Out)
+{
+    if (!Out)
+    {
+        return EUikaErrorCode::NullArgument;
+    }
+    UWidget* Widget = ToWidget(Handle);
+    if (!Widget)
+    {
+        return EUikaErrorCode::InvalidCast;
+    }
+    *Out = static_cast<uint8>(Widget->GetVisibility());
+    return EUikaErrorCode::Ok;
+}
+
+static UikaUObjectHandle FindWidgetImpl(UikaUObjectHandle Handle, const uint8* NameUtf8, uint32 NameLen)
+{
+    UUserWidget* Widget = ToUserWidget(Handle);
+    if (!Widget || !Widget->WidgetTree || !NameUtf8)
+    {
+        return UikaUObjectHandle{ nullptr };
+    }
+    const FName Name(FString(NameLen, UTF8_TO_TCHAR(reinterpret_cast<const char*>(NameUtf8))));
+    return UikaUObjectHandle{ Widget->WidgetTree->FindWidget(Name) };
+}
+
+// ---------------------------------------------------------------------------
+// Static instance
+// ---------------------------------------------------------------------------
+
+FUikaUiApi GUiApi = {
+    &AddToViewportImpl,
+    &RemoveFromParentImpl,
+    &IsInViewportImpl,
+    &SetVisibilityImpl,
+    &GetVisibilityImpl,
+    &FindWidgetImpl,
+};
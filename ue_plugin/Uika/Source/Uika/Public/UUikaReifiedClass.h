@@ -11,6 +11,7 @@ struct FUikaComponentDef
     UClass* ComponentClass = nullptr;
     bool bIsRoot = false;
     bool bIsTransient = false;
+    bool bAttachToRoot = false; // attach to the actor's root component
     FName AttachParentName; // NAME_None = no parent
 };
 
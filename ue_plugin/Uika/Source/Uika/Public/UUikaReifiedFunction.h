@@ -16,6 +16,11 @@ public:
     // Rust-side callback ID for dispatching to the correct Rust function.
     uint64 CallbackId = 0;
 
+    // For RPCs declared WithValidation: the reified `<Name>_Validate` function,
+    // run before the Rust callback. Same params plus a bool ReturnValue.
+    UPROPERTY()
+    TObjectPtr<UUikaReifiedFunction> ValidateFunction;
+
     // Native thunk called by the Blueprint VM.
     DECLARE_FUNCTION(execCallRustFunction);
 };
@@ -15,6 +15,7 @@ struct UikaUClassHandle   { void* ptr; };
 struct UikaFPropertyHandle { void* ptr; };
 struct UikaUFunctionHandle { void* ptr; };
 struct UikaUStructHandle  { void* ptr; };
+struct UikaFTextHandle    { void* ptr; };   // owning: heap-allocated FText
 struct UikaFNameHandle    { uint64 value; };
 struct UikaFWeakObjectHandle { int32 object_index; int32 object_serial_number; };
 
@@ -57,6 +58,12 @@ struct FUikaCoreApi
     UikaFWeakObjectHandle (*make_weak)(UikaUObjectHandle obj);
     UikaUObjectHandle     (*resolve_weak)(UikaFWeakObjectHandle weak);
     bool                  (*is_weak_valid)(UikaFWeakObjectHandle weak);
+
+    // Class hierarchy
+    bool             (*is_child_of)(UikaUClassHandle cls, UikaUClassHandle parent);
+    UikaUClassHandle (*get_super_class)(UikaUClassHandle cls);
+    bool             (*implements_interface)(UikaUObjectHandle obj, UikaUClassHandle interface_class);
+    EUikaErrorCode   (*get_path_name)(UikaUObjectHandle obj, uint8* buf, uint32 buf_len, uint32* out_len);
 };
 
 // ---------------------------------------------------------------------------
@@ -65,8 +72,14 @@ struct FUikaCoreApi
 
 struct FUikaLoggingApi
 {
-    // level: 0=Display, 1=Warning, 2=Error.  msg is UTF-8 (not null-terminated).
+    // level: 0=Display, 1=Warning, 2=Error, 3=Log, 4=Verbose, 5=VeryVerbose.
+    // msg is UTF-8 (not null-terminated).
     void (*log)(uint8 level, const uint8* msg, uint32 msg_len);
+    void (*log_category)(const uint8* category, uint32 category_len, uint8 level,
+                         const uint8* msg, uint32 msg_len);
+    // color is packed RGBA (0xRRGGBBAA). key -1 always adds a new line.
+    void (*add_on_screen_message)(int32 key, float duration, uint32 color,
+                                  const uint8* msg, uint32 msg_len);
 };
 
 // ---------------------------------------------------------------------------
@@ -128,6 +141,10 @@ struct FUikaPropertyApi
         uint32 index, uint8* out_buf, uint32 buf_size);
     EUikaErrorCode (*set_property_at)(UikaUObjectHandle obj, UikaFPropertyHandle prop,
         uint32 index, const uint8* in_buf, uint32 buf_size);
+
+    // Whole-object JSON
+    EUikaErrorCode (*export_json)(UikaUObjectHandle obj, uint8* buf, uint32 buf_len, uint32* out_len);
+    EUikaErrorCode (*import_json)(UikaUObjectHandle obj, const uint8* json_utf8, uint32 len);
 };
 
 // ---------------------------------------------------------------------------
@@ -314,6 +331,22 @@ struct FUikaReifyApi
     UikaUObjectHandle (*find_default_subobject)(
         UikaUObjectHandle owner,
         const uint8* name, uint32 name_len);
+
+    EUikaErrorCode (*set_function_validate)(
+        UikaUFunctionHandle func,
+        UikaUFunctionHandle validate_func);
+
+    UikaUStructHandle (*create_struct)(const uint8* name, uint32 name_len);
+
+    UikaFPropertyHandle (*add_struct_property)(
+        UikaUStructHandle ustruct,
+        const uint8* name, uint32 name_len,
+        uint32 prop_type, uint64 prop_flags,
+        const FUikaReifyPropExtra* extra);
+
+    EUikaErrorCode (*finalize_struct)(UikaUStructHandle ustruct);
+
+    EUikaErrorCode (*add_interface)(UikaUClassHandle cls, UikaUClassHandle interface_class);
 };
 struct FUikaWidgetApi
 {
@@ -327,6 +360,103 @@ struct FUikaWidgetApi
     UikaUObjectHandle (*get_widget_tree)(UikaUObjectHandle user_widget);
 };
 
+// timer handles are the packed 64-bit FTimerHandle value (0 = invalid).
+// Firing calls invoke_delegate_callback(callback_id, nullptr).
+struct FUikaTimerApi
+{
+    uint64 (*set_timer)(UikaUObjectHandle world_context, uint64 callback_id,
+        float rate, bool looping, float first_delay);
+    EUikaErrorCode (*clear_timer)(UikaUObjectHandle world_context, uint64 timer);
+    EUikaErrorCode (*pause_timer)(UikaUObjectHandle world_context, uint64 timer, bool paused);
+    bool (*is_timer_active)(UikaUObjectHandle world_context, uint64 timer);
+};
+
+// Async loading via FStreamableManager. Requests are non-zero uint64 IDs.
+// Completion calls invoke_delegate_callback(callback_id, &request_id).
+struct FUikaAssetApi
+{
+    uint64 (*request_load)(const uint8* path_utf8, uint32 path_len, uint64 callback_id);
+    // 0 = pending, 1 = loaded (out_object written), 2 = failed/canceled/unknown.
+    uint8 (*poll_load)(uint64 request, UikaUObjectHandle* out_object);
+    EUikaErrorCode (*cancel_load)(uint64 request);
+    // FSoftObjectPath::ResolveObject — null if not in memory. Never loads.
+    UikaUObjectHandle (*resolve_path)(const uint8* path_utf8, uint32 path_len);
+    EUikaErrorCode (*get_object_path)(UikaUObjectHandle obj, uint8* buf, uint32 buf_len, uint32* out_len);
+};
+
+// FText handles own a heap-allocated FText and must be released with destroy().
+// Functions returning a handle return null on failure.
+struct FUikaTextApi
+{
+    UikaFTextHandle (*from_string)(const uint8* utf8, uint32 len);
+    UikaFTextHandle (*from_string_table)(const uint8* table_id, uint32 table_id_len,
+        const uint8* key, uint32 key_len);
+    UikaFTextHandle (*as_localizable)(const uint8* ns, uint32 ns_len,
+        const uint8* key, uint32 key_len, const uint8* source, uint32 source_len);
+    EUikaErrorCode (*to_string)(UikaFTextHandle text, uint8* buf, uint32 buf_len, uint32* out_len);
+    bool (*is_empty)(UikaFTextHandle text);
+    bool (*identical_to)(UikaFTextHandle a, UikaFTextHandle b);
+    UikaFTextHandle (*clone)(UikaFTextHandle text);
+    void (*destroy)(UikaFTextHandle text);
+    EUikaErrorCode (*get_property)(UikaUObjectHandle obj, UikaFPropertyHandle prop, UikaFTextHandle* out);
+    EUikaErrorCode (*set_property)(UikaUObjectHandle obj, UikaFPropertyHandle prop, UikaFTextHandle text);
+};
+
+// ---------------------------------------------------------------------------
+// Physics queries
+// ---------------------------------------------------------------------------
+
+// Flattened FHitResult (matches UikaHitResult). Vectors are world-space xyz.
+struct FUikaHitResult
+{
+    UikaUObjectHandle actor;
+    UikaUObjectHandle component;
+    double location[3];
+    double impact_point[3];
+    double normal[3];
+    double impact_normal[3];
+    double distance;
+    float time;
+    int32 face_index;
+    UikaFNameHandle bone_name;
+    bool blocking_hit;
+    bool start_penetrating;
+};
+
+// kind: 0 = sphere [radius, 0, 0], 1 = box (half extents), 2 = capsule [radius, 0, half_height].
+struct FUikaCollisionShape
+{
+    uint8 kind;
+    double extent[3];
+};
+
+struct FUikaPhysicsApi
+{
+    bool (*line_trace_single)(UikaUObjectHandle world_context, const double* start, const double* end,
+        uint8 channel, bool trace_complex, const UikaUObjectHandle* ignore, uint32 ignore_count,
+        FUikaHitResult* out_hit);
+    EUikaErrorCode (*line_trace_multi)(UikaUObjectHandle world_context, const double* start, const double* end,
+        uint8 channel, bool trace_complex, const UikaUObjectHandle* ignore, uint32 ignore_count,
+        FUikaHitResult* out_hits, uint32 capacity, uint32* out_count);
+    bool (*sweep_single)(UikaUObjectHandle world_context, const double* start, const double* end,
+        const FUikaCollisionShape* shape, uint8 channel, bool trace_complex,
+        const UikaUObjectHandle* ignore, uint32 ignore_count, FUikaHitResult* out_hit);
+    bool (*overlap_blocking_test)(UikaUObjectHandle world_context, const double* center,
+        const FUikaCollisionShape* shape, uint8 channel,
+        const UikaUObjectHandle* ignore, uint32 ignore_count);
+};
+
+// Gameplay tags cross as packed tag names (0 = no tag).
+struct FUikaGameplayTagApi
+{
+    EUikaErrorCode (*request_tag)(const uint8* name_utf8, uint32 name_len, UikaFNameHandle* out);
+    bool (*matches_tag)(UikaFNameHandle tag, UikaFNameHandle other, bool exact);
+    UikaFNameHandle (*request_direct_parent)(UikaFNameHandle tag);
+    EUikaErrorCode (*read_container)(const uint8* container, UikaFNameHandle* out_tags,
+        uint32 capacity, uint32* out_count);
+    EUikaErrorCode (*write_container)(uint8* container, const UikaFNameHandle* tags, uint32 count);
+};
+
 struct FUikaWorldApi
 {
     UikaUObjectHandle (*spawn_actor)(UikaUObjectHandle world, UikaUClassHandle cls,
@@ -351,13 +481,341 @@ struct FUikaWorldApi
         const uint8* transform_buf, uint32 transform_size);
 };
 
+// Enhanced Input. Bindings live on the actor's UEnhancedInputComponent and are
+// identified by its binding handle (0 = invalid). Firing calls
+// invoke_delegate_callback(callback_id, double[3]) with the action value.
+struct FUikaInputApi
+{
+    // mapping_context: load a UInputMappingContext instead of a UInputAction.
+    UikaUObjectHandle (*find_input_asset)(const uint8* path_utf8, uint32 path_len, bool mapping_context);
+    EUikaErrorCode (*add_mapping_context)(UikaUObjectHandle player_controller,
+        UikaUObjectHandle context, int32 priority);
+    EUikaErrorCode (*remove_mapping_context)(UikaUObjectHandle player_controller,
+        UikaUObjectHandle context);
+    // trigger_event is an ETriggerEvent bit.
+    uint32 (*bind_action)(UikaUObjectHandle actor, UikaUObjectHandle action,
+        uint8 trigger_event, uint64 callback_id);
+    EUikaErrorCode (*remove_binding)(UikaUObjectHandle actor, uint32 binding);
+};
+
+// Sound playback. location points to xyz; spawn_* return the UAudioComponent
+// (null on failure).
+struct FUikaAudioApi
+{
+    EUikaErrorCode (*play_sound_2d)(UikaUObjectHandle world_context, UikaUObjectHandle sound,
+        float volume, float pitch, float start_time);
+    EUikaErrorCode (*play_sound_at_location)(UikaUObjectHandle world_context, UikaUObjectHandle sound,
+        const double* location, float volume, float pitch, float start_time);
+    UikaUObjectHandle (*spawn_sound_2d)(UikaUObjectHandle world_context, UikaUObjectHandle sound,
+        float volume, float pitch, float start_time, bool auto_destroy);
+    UikaUObjectHandle (*spawn_sound_at_location)(UikaUObjectHandle world_context, UikaUObjectHandle sound,
+        const double* location, float volume, float pitch, float start_time, bool auto_destroy);
+    UikaUObjectHandle (*spawn_sound_attached)(UikaUObjectHandle sound, UikaUObjectHandle attach_to,
+        UikaFNameHandle socket, float volume, float pitch, float start_time, bool auto_destroy);
+    EUikaErrorCode (*stop)(UikaUObjectHandle component);
+    EUikaErrorCode (*fade_in)(UikaUObjectHandle component, float duration, float volume, float start_time);
+    EUikaErrorCode (*fade_out)(UikaUObjectHandle component, float duration, float volume);
+    EUikaErrorCode (*set_volume)(UikaUObjectHandle component, float volume);
+    EUikaErrorCode (*set_pitch)(UikaUObjectHandle component, float pitch);
+    bool (*is_playing)(UikaUObjectHandle component);
+};
+
+// Navigation queries. Points are double[3]; path points are written as xyz
+// triples.
+struct FUikaNavigationApi
+{
+    EUikaErrorCode (*find_path_to_location)(UikaUObjectHandle world_context, const double* start,
+        const double* end, double* out_points, uint32 capacity, uint32* out_count);
+    bool (*find_random_reachable_point)(UikaUObjectHandle world_context, const double* origin,
+        float radius, double* out_point);
+    bool (*project_point_to_navigation)(UikaUObjectHandle world_context, const double* point,
+        const double* extent, double* out_point);
+    EUikaErrorCode (*simple_move_to_location)(UikaUObjectHandle controller, const double* goal);
+};
+
+// UMG display and lookup on existing widgets. visibility is ESlateVisibility.
+struct FUikaUiApi
+{
+    EUikaErrorCode (*add_to_viewport)(UikaUObjectHandle user_widget, int32 z_order, bool player_screen);
+    EUikaErrorCode (*remove_from_parent)(UikaUObjectHandle widget);
+    bool (*is_in_viewport)(UikaUObjectHandle user_widget);
+    EUikaErrorCode (*set_visibility)(UikaUObjectHandle widget, uint8 visibility);
+    EUikaErrorCode (*get_visibility)(UikaUObjectHandle widget, uint8* out);
+    UikaUObjectHandle (*find_widget)(UikaUObjectHandle user_widget, const uint8* name_utf8, uint32 name_len);
+};
+
+// Asset registry queries. Results are UTF-8 lines of
+// "object_path\tclass_path\tpackage_name"; out_len receives the full length.
+struct FUikaAssetRegistryApi
+{
+    EUikaErrorCode (*get_assets_by_class)(const uint8* class_path, uint32 class_path_len,
+        bool search_sub_classes, uint8* buf, uint32 buf_len, uint32* out_len);
+    EUikaErrorCode (*get_assets_by_path)(const uint8* path, uint32 path_len, bool recursive,
+        uint8* buf, uint32 buf_len, uint32* out_len);
+    EUikaErrorCode (*get_assets_by_tag_value)(const uint8* tag, uint32 tag_len,
+        const uint8* value, uint32 value_len, uint8* buf, uint32 buf_len, uint32* out_len);
+    bool (*is_loading_assets)();
+};
+
+// Async save-game completion payload (params of invoke_delegate_callback).
+// data is only valid during the callback.
+struct FUikaSaveGameResult
+{
+    bool success;
+    const uint8* data;
+    uint32 len;
+};
+
+// Save slots (UGameplayStatics). A slot holds a USaveGame or a raw byte blob.
+struct FUikaSaveGameApi
+{
+    UikaUObjectHandle (*create_save_game_object)(UikaUClassHandle cls);
+    EUikaErrorCode (*save_game_to_slot)(UikaUObjectHandle save_game, const uint8* slot, uint32 slot_len,
+        int32 user_index);
+    UikaUObjectHandle (*load_game_from_slot)(const uint8* slot, uint32 slot_len, int32 user_index);
+    bool (*does_save_game_exist)(const uint8* slot, uint32 slot_len, int32 user_index);
+    EUikaErrorCode (*delete_game_in_slot)(const uint8* slot, uint32 slot_len, int32 user_index);
+    EUikaErrorCode (*save_data_to_slot)(const uint8* data, uint32 len, const uint8* slot, uint32 slot_len,
+        int32 user_index);
+    EUikaErrorCode (*load_data_from_slot)(const uint8* slot, uint32 slot_len, int32 user_index,
+        uint8* buf, uint32 buf_len, uint32* out_len);
+    EUikaErrorCode (*async_save_data_to_slot)(const uint8* data, uint32 len, const uint8* slot,
+        uint32 slot_len, int32 user_index, uint64 callback_id);
+    EUikaErrorCode (*async_load_data_from_slot)(const uint8* slot, uint32 slot_len, int32 user_index,
+        uint64 callback_id);
+};
+
+// Ini settings (GConfig). `file` is a config name (Engine, Game, Input,
+// GameUserSettings, Editor) or an ini filename. Getters return
+// PropertyNotFound for missing keys; setters don't write to disk until flush.
+struct FUikaConfigApi
+{
+    EUikaErrorCode (*get_string)(const uint8* section, uint32 section_len, const uint8* key, uint32 key_len,
+        const uint8* file, uint32 file_len, uint8* buf, uint32 buf_len, uint32* out_len);
+    EUikaErrorCode (*set_string)(const uint8* section, uint32 section_len, const uint8* key, uint32 key_len,
+        const uint8* file, uint32 file_len, const uint8* value, uint32 value_len);
+    EUikaErrorCode (*get_int)(const uint8* section, uint32 section_len, const uint8* key, uint32 key_len,
+        const uint8* file, uint32 file_len, int32* out);
+    EUikaErrorCode (*set_int)(const uint8* section, uint32 section_len, const uint8* key, uint32 key_len,
+        const uint8* file, uint32 file_len, int32 value);
+    EUikaErrorCode (*get_float)(const uint8* section, uint32 section_len, const uint8* key, uint32 key_len,
+        const uint8* file, uint32 file_len, double* out);
+    EUikaErrorCode (*set_float)(const uint8* section, uint32 section_len, const uint8* key, uint32 key_len,
+        const uint8* file, uint32 file_len, double value);
+    EUikaErrorCode (*get_bool)(const uint8* section, uint32 section_len, const uint8* key, uint32 key_len,
+        const uint8* file, uint32 file_len, bool* out);
+    EUikaErrorCode (*set_bool)(const uint8* section, uint32 section_len, const uint8* key, uint32 key_len,
+        const uint8* file, uint32 file_len, bool value);
+    EUikaErrorCode (*get_array)(const uint8* section, uint32 section_len, const uint8* key, uint32 key_len,
+        const uint8* file, uint32 file_len, uint8* buf, uint32 buf_len, uint32* out_len);
+    EUikaErrorCode (*set_array)(const uint8* section, uint32 section_len, const uint8* key, uint32 key_len,
+        const uint8* file, uint32 file_len, const uint8* values, uint32 values_len);
+    EUikaErrorCode (*flush)(const uint8* file, uint32 file_len);
+};
+
+// Gameplay Ability System helpers, through the actor's ability system
+// component. Attributes are named "Health" or "MyAttributeSet.Health".
+struct FUikaGasApi
+{
+    EUikaErrorCode (*get_attribute)(UikaUObjectHandle actor, const uint8* name, uint32 name_len, bool base,
+        float* out);
+    EUikaErrorCode (*set_attribute)(UikaUObjectHandle actor, const uint8* name, uint32 name_len, bool base,
+        float value);
+    bool (*try_activate_ability_by_class)(UikaUObjectHandle actor, UikaUClassHandle ability_class);
+    bool (*try_activate_abilities_by_tag)(UikaUObjectHandle actor, const UikaFNameHandle* tags, uint32 count);
+    EUikaErrorCode (*apply_gameplay_effect)(UikaUObjectHandle source, UikaUObjectHandle target,
+        UikaUClassHandle effect_class, float level, uint32* out_effect);
+    EUikaErrorCode (*remove_gameplay_effect)(uint32 effect);
+    uint32 (*bind_attribute_changed)(UikaUObjectHandle actor, const uint8* name, uint32 name_len,
+        uint64 callback_id);
+    EUikaErrorCode (*unbind_attribute_changed)(uint32 binding);
+};
+
+// TArray range operations (same buffer format as array_copy_all/array_set_all).
+struct FUikaArrayExtApi
+{
+    EUikaErrorCode (*copy_range)(UikaUObjectHandle obj, UikaFPropertyHandle prop, int32 start, int32 count,
+        uint8* out_buf, uint32 buf_size, uint32* out_total_written, int32* out_count);
+    EUikaErrorCode (*append_all)(UikaUObjectHandle obj, UikaFPropertyHandle prop,
+        const uint8* in_buf, uint32 buf_size, int32 count);
+    EUikaErrorCode (*remove_range)(UikaUObjectHandle obj, UikaFPropertyHandle prop, int32 start, int32 count);
+};
+
+// Key-based TMap operations (single-element buffers as map_find/map_add;
+// copy_range uses the map_copy_all format).
+struct FUikaMapExtApi
+{
+    bool (*contains_key)(UikaUObjectHandle obj, UikaFPropertyHandle prop, const uint8* key_buf, uint32 key_size);
+    EUikaErrorCode (*find_or_add)(UikaUObjectHandle obj, UikaFPropertyHandle prop,
+        const uint8* key_buf, uint32 key_size, const uint8* default_val, uint32 default_size,
+        uint8* out_val_buf, uint32 val_buf_size, uint32* out_val_written, bool* out_added);
+    EUikaErrorCode (*remove_and_copy)(UikaUObjectHandle obj, UikaFPropertyHandle prop,
+        const uint8* key_buf, uint32 key_size, uint8* out_val_buf, uint32 val_buf_size, uint32* out_val_written);
+    EUikaErrorCode (*copy_range)(UikaUObjectHandle obj, UikaFPropertyHandle prop, int32 start, int32 count,
+        uint8* out_buf, uint32 buf_size, uint32* out_total_written, int32* out_count);
+};
+
+// TSet algebra with UE-side membership checks (outputs in the set_copy_all
+// format; contains_each takes framed elements).
+struct FUikaSetExtApi
+{
+    EUikaErrorCode (*union_into)(UikaUObjectHandle obj, UikaFPropertyHandle prop,
+        UikaUObjectHandle dst_obj, UikaFPropertyHandle dst_prop);
+    EUikaErrorCode (*intersection)(UikaUObjectHandle obj, UikaFPropertyHandle prop,
+        UikaUObjectHandle other_obj, UikaFPropertyHandle other_prop,
+        uint8* out_buf, uint32 buf_size, uint32* out_total_written, int32* out_count);
+    EUikaErrorCode (*difference)(UikaUObjectHandle obj, UikaFPropertyHandle prop,
+        UikaUObjectHandle other_obj, UikaFPropertyHandle other_prop,
+        uint8* out_buf, uint32 buf_size, uint32* out_total_written, int32* out_count);
+    EUikaErrorCode (*contains_each)(UikaUObjectHandle obj, UikaFPropertyHandle prop,
+        const uint8* in_buf, uint32 buf_size, int32 count, bool* out_found);
+};
+
+// Runtime type information for reflected properties.
+struct FUikaPropertyInfoApi
+{
+    EUikaErrorCode (*describe)(UikaFPropertyHandle prop, uint32* out_type, FUikaReifyPropExtra* out_extra);
+};
+
+// Paged enumeration of loaded objects by class.
+struct FUikaObjectIterApi
+{
+    EUikaErrorCode (*next_page)(UikaUClassHandle cls, int32* cursor, UikaUObjectHandle* out, uint32 capacity,
+        uint32* out_count);
+};
+
+// Core object queries added after FUikaCoreApi was frozen.
+struct FUikaCoreExtApi
+{
+    UikaUObjectHandle (*get_default_object)(UikaUClassHandle cls);
+};
+
+// Runtime component creation and attachment (rules are EAttachmentRule values).
+struct FUikaComponentApi
+{
+    UikaUObjectHandle (*add_component)(UikaUObjectHandle actor, UikaUClassHandle cls, const uint8* name_utf8,
+        uint32 name_len);
+    EUikaErrorCode (*attach_component)(UikaUObjectHandle child, UikaUObjectHandle parent, UikaFNameHandle socket,
+        uint8 location_rule, uint8 rotation_rule, uint8 scale_rule, bool weld_simulated_bodies);
+    EUikaErrorCode (*attach_actor)(UikaUObjectHandle actor, UikaUObjectHandle parent, UikaFNameHandle socket,
+        uint8 location_rule, uint8 rotation_rule, uint8 scale_rule, bool weld_simulated_bodies);
+    EUikaErrorCode (*detach)(UikaUObjectHandle target, uint8 rule);
+};
+
+// Subsystem lookup by class.
+struct FUikaSubsystemApi
+{
+    UikaUObjectHandle (*get_world_subsystem)(UikaUObjectHandle world_context, UikaUClassHandle cls);
+    UikaUObjectHandle (*get_game_instance_subsystem)(UikaUObjectHandle world_context, UikaUClassHandle cls);
+    UikaUObjectHandle (*get_local_player_subsystem)(UikaUObjectHandle player, UikaUClassHandle cls);
+    UikaUObjectHandle (*get_engine_subsystem)(UikaUClassHandle cls);
+};
+
+// Latent function calls; completion is forwarded to invoke_delegate_callback.
+struct FUikaLatentApi
+{
+    EUikaErrorCode (*bind_latent_info)(uint8* params, UikaFPropertyHandle prop, uint64 callback_id);
+    void (*release)(uint64 callback_id);
+};
+
+// Log category registration and verbosity queries (empty category = LogUika).
+struct FUikaLoggingExtApi
+{
+    void (*register_category)(const uint8* category, uint32 category_len, uint8 default_level);
+    bool (*is_enabled)(const uint8* category, uint32 category_len, uint8 level);
+};
+
+// Rust panics forwarded to ensure / the fatal error path.
+struct FUikaPanicApi
+{
+    void (*report_ensure)(const uint8* msg, uint32 msg_len);
+    void (*fatal)(const uint8* msg, uint32 msg_len);
+};
+
+// Guid generation (out = words A, B, C, D).
+struct FUikaGuidApi
+{
+    void (*new_guid)(uint32* out);
+};
+
+// FHitResult in UE memory -> flattened hit (ustruct must be FHitResult).
+struct FUikaHitResultApi
+{
+    EUikaErrorCode (*decode)(UikaUStructHandle ustruct, const uint8* data, FUikaHitResult* out);
+};
+
+// Many scalar properties of one object per call. Values are u64 slots tagged
+// with EUikaReifyPropType (see UikaPropertyBulkApi in api_table.rs).
+struct FUikaPropertyBulkApi
+{
+    EUikaErrorCode (*get_many)(UikaUObjectHandle obj, const UikaFPropertyHandle* props, uint32 count,
+        uint64* out_values, uint32* out_kinds);
+    EUikaErrorCode (*set_many)(UikaUObjectHandle obj, const UikaFPropertyHandle* props, uint32 count,
+        const uint64* values, const uint32* kinds);
+};
+
+// Property change payload (params of invoke_delegate_callback).
+struct FUikaPropertyChange
+{
+    UikaUObjectHandle object;
+    UikaFNameHandle property;         // innermost changed property
+    UikaFNameHandle member_property;  // the object's property containing it
+    uint32 change_type;               // EPropertyChangeType bits
+};
+
+// Property change subscriptions (editor: OnObjectPropertyChanged; any build:
+// notify_changed). obj null = any object, empty name = any property.
+struct FUikaPropertyNotifyApi
+{
+    uint32 (*subscribe)(UikaUObjectHandle obj, const uint8* name, uint32 name_len, uint64 callback_id);
+    EUikaErrorCode (*unsubscribe)(uint32 binding);
+    EUikaErrorCode (*notify_changed)(UikaUObjectHandle obj, UikaFPropertyHandle prop);
+};
+
+// Oversized string outputs of generated function wrappers, stashed per thread
+// by param index (see UikaOutputStash.h) and fetched without calling the UE
+// function again.
+struct FUikaOutputStashApi
+{
+    EUikaErrorCode (*take_string)(uint32 slot, uint8* buf, uint32 buf_len, uint32* out_len);
+};
+
+// Single FGameplayTag values, added after FUikaGameplayTagApi was frozen.
+struct FUikaGameplayTagExtApi
+{
+    UikaFNameHandle (*read_tag)(const uint8* tag);
+    EUikaErrorCode (*write_tag)(uint8* tag, UikaFNameHandle name);
+};
+
+// ---------------------------------------------------------------------------
+// Binding stamp (must match UikaBindingStamp in api_table.rs)
+// ---------------------------------------------------------------------------
+
+// Fingerprint of the codegen run the generated wrappers came from; the Rust
+// side refuses to initialize when a FuncId it shares with the plugin has a
+// different signature hash.
+struct FUikaBindingStamp
+{
+    const uint8* engine_version;
+    uint32       engine_version_len;
+    uint64       config_hash;
+    const uint64* func_sigs;    // func_count entries, 0 = unused id
+};
+
 // ---------------------------------------------------------------------------
 // Main API table
 // ---------------------------------------------------------------------------
 
+// UIKA_API_VERSION / UIKA_API_MIN_VERSION in api_table.rs. From version 4 on,
+// FUikaApiTable and FUikaRustCallbacks only grow at the end; each side reads
+// the other's fields beyond `size` as null.
+static constexpr uint32 UIKA_API_VERSION = 31;
+static constexpr uint32 UIKA_API_MIN_VERSION = 4;
+
 struct FUikaApiTable
 {
     uint32 version;
+    uint32 size;        // sizeof(FUikaApiTable)
 
     // Fixed sub-tables
     const FUikaCoreApi*         core;
@@ -370,10 +828,44 @@ struct FUikaApiTable
     const FUikaWorldApi*        world;
     const FUikaLoggingApi*      logging;
     const FUikaWidgetApi*       widget;
+    const FUikaTimerApi*        timer;
+    const FUikaAssetApi*        asset;
+    const FUikaTextApi*         text;
+    const FUikaGameplayTagApi*  gameplay_tag;
+    const FUikaPhysicsApi*      physics;
 
     // Generated function-pointer array
     const void* const*          func_table;
     uint32                      func_count;
+
+    FUikaBindingStamp           binding_stamp;
+
+    // Appended sub-tables
+    const FUikaInputApi*        input;          // since version 5
+    const FUikaAudioApi*        audio;          // since version 6
+    const FUikaNavigationApi*   navigation;     // since version 7
+    const FUikaUiApi*           ui;             // since version 8
+    const FUikaAssetRegistryApi* asset_registry; // since version 9
+    const FUikaSaveGameApi*     save_game;      // since version 10
+    const FUikaConfigApi*       config;         // since version 11
+    const FUikaGasApi*          gas;            // since version 12
+    const FUikaArrayExtApi*     array_ext;      // since version 13
+    const FUikaMapExtApi*       map_ext;        // since version 14
+    const FUikaSetExtApi*       set_ext;        // since version 15
+    const FUikaPropertyInfoApi* property_info;  // since version 16
+    const FUikaObjectIterApi*   object_iter;    // since version 17
+    const FUikaCoreExtApi*      core_ext;       // since version 18
+    const FUikaComponentApi*    component;      // since version 20
+    const FUikaSubsystemApi*    subsystem;      // since version 21
+    const FUikaLatentApi*       latent;         // since version 23
+    const FUikaLoggingExtApi*   logging_ext;    // since version 24
+    const FUikaPanicApi*        panic;          // since version 25
+    const FUikaGuidApi*         guid;           // since version 26
+    const FUikaHitResultApi*    hit_result;     // since version 27
+    const FUikaPropertyBulkApi* property_bulk;  // since version 28
+    const FUikaPropertyNotifyApi* property_notify; // since version 29
+    const FUikaOutputStashApi* output_stash;       // since version 30
+    const FUikaGameplayTagExtApi* gameplay_tag_ext; // since version 31
 };
 
 // ---------------------------------------------------------------------------
@@ -382,12 +874,18 @@ struct FUikaApiTable
 
 struct FUikaRustCallbacks
 {
+    uint32 version;     // the DLL's UIKA_API_VERSION
+    uint32 size;        // sizeof(UikaRustCallbacks) in the DLL
+
     void (*drop_rust_instance)(UikaUObjectHandle handle, uint64 type_id, uint8* rust_data);
     void (*invoke_rust_function)(uint64 callback_id, UikaUObjectHandle obj, uint8* params);
     void (*invoke_delegate_callback)(uint64 callback_id, uint8* params);
     void (*on_shutdown)();
     void (*construct_rust_instance)(UikaUObjectHandle obj, uint64 type_id, bool is_cdo);
     void (*notify_pinned_destroyed)(UikaUObjectHandle handle);
+    uint32 (*run_tests)(const uint8* filter, uint32 filter_len);
+    void (*collect_references)(void (*report)(void* ctx, UikaUObjectHandle obj), void* ctx);  // since version 19
+    void (*tick)(float delta_seconds);  // since version 22
 };
 
 // ---------------------------------------------------------------------------
@@ -0,0 +1,20 @@
+// Helpers to pass FGameplayTag across the FFI boundary as a packed tag name.
+// Unpacking goes through the tag manager, so unregistered names yield an
+// empty tag rather than an invalid one.
+#pragma once
+#include "GameplayTagContainer.h"
+#include "UikaFNameHelper.h"
+
+static inline FGameplayTag UikaUnpackGameplayTag(uint64_t Packed)
+{
+    if (Packed == 0)
+    {
+        return FGameplayTag();
+    }
+    return FGameplayTag::RequestGameplayTag(UikaUnpackFName(Packed), /*ErrorIfNotFound=*/ false);
+}
+
+static inline uint64_t UikaPackGameplayTag(const FGameplayTag& Tag)
+{
+    return Tag.IsValid() ? UikaPackFName(Tag.GetTagName()) : 0;
+}
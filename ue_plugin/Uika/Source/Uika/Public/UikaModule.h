@@ -1,6 +1,8 @@
 #pragma once
 
 #include "Modules/ModuleManager.h"
+#include "Containers/Ticker.h"
+#include "Containers/Queue.h"
 
 DECLARE_LOG_CATEGORY_EXTERN(LogUika, Log, All);
 
@@ -10,12 +12,18 @@ public:
     virtual void StartupModule() override;
     virtual void ShutdownModule() override;
 
-    /** Unload the current Rust DLL, copy the new one, and reload. */
-    void ReloadRustDll();
+    /** Unload the current Rust DLL, copy the new one, and reload. Returns false on failure. */
+    bool ReloadRustDll();
 
     /** Static entry point for the Uika.Reload console command. */
     static void StaticReload();
 
+    /**
+     * Run the Rust in-engine tests whose names contain Filter (all if empty).
+     * Returns the number of failures, or -1 if the tests could not be run.
+     */
+    int32 RunRustTests(const FString& Filter);
+
 private:
     /** Drop Rust instance data for all reified objects. */
     void TeardownReifiedInstances();
@@ -28,6 +36,24 @@ private:
     /** Load a Rust DLL from the given path and initialize it. */
     bool LoadRustDll(const FString& LoadPath);
 
+    /**
+     * Core ticker callback: reload when `uika watch` touches the marker file,
+     * and answer reload requests from `uika reload`.
+     */
+    bool PollReloadRequests(float DeltaTime);
+
+    /** Listener thread callback: read a `uika reload` request and queue it. */
+    bool OnReloadConnection(class FSocket* Socket, const struct FIPv4Endpoint& Endpoint);
+
+    /** Open the loopback reload socket and write its port file. */
+    void StartReloadListener();
+
+    /** Close the reload socket, drop queued requests and delete the port file. */
+    void StopReloadListener();
+
+    /** Core ticker callback: forward the frame tick to Rust. */
+    bool TickRust(float DeltaTime);
+
     void* DllHandle = nullptr;
     const struct FUikaRustCallbacks* RustCallbacks = nullptr;
 
@@ -39,4 +65,24 @@ private:
 
     /** Incrementing counter for copy-on-reload filenames. */
     int32 ReloadCount = 0;
+
+    /** Marker file next to uika.dll, touched by `uika watch` after a rebuild. */
+    FString ReloadMarkerPath;
+
+    /** Last seen marker timestamp (MinValue if the marker does not exist). */
+    FDateTime LastReloadMarkerStamp = FDateTime::MinValue();
+
+    FTSTicker::FDelegateHandle ReloadMarkerTicker;
+
+    /** Loopback listener for `uika reload` (owned); null if it could not be opened. */
+    class FTcpListener* ReloadListener = nullptr;
+
+    /** File next to uika.dll holding the listener's port. */
+    FString ReloadPortPath;
+
+    /** Connections that sent a reload request, answered on the game thread. */
+    TQueue<class FSocket*, EQueueMode::Mpsc> PendingReloadRequests;
+
+    /** Per-frame tick into the loaded DLL; only registered while one is loaded. */
+    FTSTicker::FDelegateHandle RustTickTicker;
 };
@@ -0,0 +1,16 @@
+// Stash for string outputs of generated function wrappers that did not fit
+// the buffer Rust passed in. The wrapper stores the full UTF-8 text under the
+// param's index and returns BufferTooSmall; Rust then fetches it through
+// FUikaOutputStashApi::take_string instead of calling the UE function again.
+// The map is thread_local and keyed by slot only, so entries are not
+// separated by call: this relies on a wrapper stashing its outputs only after
+// its UE function has returned, and on Rust taking them before making any
+// other call. A nested wrapper call (a Rust callback running inside the UE
+// function) has therefore always taken its own entries before the outer
+// wrapper stashes, even when they use the same slots.
+#pragma once
+#include "CoreMinimal.h"
+
+// Keep Utf8[0..Len) as output `Slot` of the current call on this thread,
+// replacing anything stashed there before.
+void UikaOutputStashPut(uint32 Slot, const ANSICHAR* Utf8, uint32 Len);
@@ -0,0 +1,21 @@
+#pragma once
+
+#include "CoreMinimal.h"
+#include "Commandlets/Commandlet.h"
+#include "UikaTestRunnerCommandlet.generated.h"
+
+// Runs the Rust in-engine tests headless and exits with a nonzero code on
+// failure. Used by `uika test`:
+//   UnrealEditor-Cmd.exe Game.uproject -run=UikaTestRunner [-Filter=name]
+// The commandlet has no game world; tests that need one should be run with
+// `-game -ExecCmds="Uika.RunTests; Quit"` instead (`uika test --game`).
+UCLASS()
+class UUikaTestRunnerCommandlet : public UCommandlet
+{
+    GENERATED_BODY()
+
+public:
+    UUikaTestRunnerCommandlet();
+
+    virtual int32 Main(const FString& Params) override;
+};
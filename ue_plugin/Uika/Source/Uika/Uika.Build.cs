@@ -9,6 +9,9 @@ public class Uika : ModuleRules
 
         // Read module dependencies generated by uika-codegen.
         // Falls back to Core + CoreUObject + Engine if the file doesn't exist yet.
+        // GAS support (UikaGasApiImpl.cpp) is only compiled in when the
+        // `gameplay-abilities` codegen feature lists GameplayAbilities here.
+        bool bWithGas = false;
         string DepsFile = Path.Combine(ModuleDirectory, "Generated", "module_deps.txt");
         if (File.Exists(DepsFile))
         {
@@ -19,6 +22,7 @@ public class Uika : ModuleRules
                 if (Trimmed.Length > 0)
                 {
                     PublicDependencyModuleNames.Add(Trimmed);
+                    bWithGas |= Trimmed == "GameplayAbilities";
                 }
             }
         }
@@ -31,5 +35,16 @@ public class Uika : ModuleRules
                 "Engine",
             });
         }
+
+        // Hand-written API sub-tables that are not tied to a codegen feature.
+        PublicDependencyModuleNames.AddUnique("GameplayTags");
+        PublicDependencyModuleNames.AddUnique("EnhancedInput");
+        PublicDependencyModuleNames.AddUnique("NavigationSystem");
+        PublicDependencyModuleNames.AddUnique("AIModule");
+        PublicDependencyModuleNames.AddUnique("AssetRegistry");
+        PublicDefinitions.Add("UIKA_WITH_GAS=" + (bWithGas ? "1" : "0"));
+        PrivateDependencyModuleNames.AddRange(new string[] { "Json", "JsonUtilities" });
+        // Loopback reload socket for `uika reload`.
+        PrivateDependencyModuleNames.AddRange(new string[] { "Sockets", "Networking" });
     }
 }
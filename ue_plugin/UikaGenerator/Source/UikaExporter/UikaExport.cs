@@ -54,7 +54,8 @@ public static class UikaExport
         "FallbackStruct", "FloatRangeBound", "FloatRange", "Int32RangeBound",
         "Int32Range", "FloatInterval", "Int32Interval", "FrameNumber",
         "SoftObjectPath", "SoftClassPath", "PrimaryAssetType", "PrimaryAssetId",
-        "DateTime", "PolyglotTextData",
+        "DateTime", "PolyglotTextData", "Box", "Matrix", "IntPoint", "IntVector",
+        "GameplayTag", "GameplayTagContainer",
     };
 
     #endregion
@@ -182,6 +183,8 @@ public static class UikaExport
                 ["interfaces"]  = interfaces,
                 ["props"]       = props,
                 ["funcs"]       = funcs,
+                ["tooltip"]     = GetMetaOrNull(classObj, "ToolTip"),
+                ["category"]    = GetMetaOrNull(classObj, "Category"),
             });
         }
 
@@ -242,6 +245,7 @@ public static class UikaExport
                 ["super"]             = superName,
                 ["has_static_struct"] = hasStaticStruct,
                 ["props"]             = props,
+                ["tooltip"]           = GetMetaOrNull(structObj, "ToolTip"),
             });
         }
 
@@ -404,9 +408,11 @@ public static class UikaExport
 
             PopulateSubTypeFields(prop, info);
 
-            info["getter"]  = GetMetaOrNull(prop, "BlueprintGetter");
-            info["setter"]  = GetMetaOrNull(prop, "BlueprintSetter");
-            info["default"] = (JsonNode?)null;
+            info["getter"]   = GetMetaOrNull(prop, "BlueprintGetter");
+            info["setter"]   = GetMetaOrNull(prop, "BlueprintSetter");
+            info["default"]  = (JsonNode?)null;
+            info["tooltip"]  = GetMetaOrNull(prop, "ToolTip");
+            info["category"] = GetMetaOrNull(prop, "Category");
 
             return info;
         }
@@ -596,6 +602,8 @@ public static class UikaExport
                 ["func_flags"] = (long)unchecked((uint)func.FunctionFlags),
                 ["is_static"]  = func.FunctionFlags.HasAnyFlags(EFunctionFlags.Static),
                 ["params"]     = funcParams,
+                ["tooltip"]    = GetMetaOrNull(func, "ToolTip"),
+                ["category"]   = GetMetaOrNull(func, "Category"),
             };
         }
 
@@ -633,7 +641,8 @@ public static class UikaExport
 
             WriteFile("uika_classes", new JsonObject
             {
-                ["classes"] = ToJsonArray(_classes),
+                ["engine_version"] = ReadEngineVersion(),
+                ["classes"]        = ToJsonArray(_classes),
             }, writerOptions);
 
             WriteFile("uika_structs", new JsonObject
@@ -647,6 +656,29 @@ public static class UikaExport
             }, writerOptions);
         }
 
+        /// <summary>
+        /// Engine version from Engine/Build/Build.version ("5.7.0"), embedded in
+        /// the generated bindings' stamp. Null if the file can't be read.
+        /// </summary>
+        private string? ReadEngineVersion()
+        {
+            if (_session.EngineDirectory == null)
+                return null;
+            string path = Path.Combine(_session.EngineDirectory, "Build", "Build.version");
+            try
+            {
+                using JsonDocument doc = JsonDocument.Parse(File.ReadAllText(path));
+                JsonElement root = doc.RootElement;
+                return $"{root.GetProperty("MajorVersion").GetInt32()}."
+                    + $"{root.GetProperty("MinorVersion").GetInt32()}."
+                    + $"{root.GetProperty("PatchVersion").GetInt32()}";
+            }
+            catch (Exception e) when (e is IOException or JsonException or KeyNotFoundException or InvalidOperationException)
+            {
+                return null;
+            }
+        }
+
         private void WriteFile(string name, JsonObject content, JsonWriterOptions writerOptions)
         {
             string path = _factory.MakePath(name, ".json");
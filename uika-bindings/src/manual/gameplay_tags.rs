@@ -0,0 +1,45 @@
+// GameplayTag / GameplayTagContainer ↔ OwnedStruct conversions.
+//
+// Function params of type FGameplayTag are generated as
+// `uika_runtime::GameplayTag` directly; these cover struct properties.
+
+use uika_runtime::{GameplayTag, GameplayTagContainer, OwnedStruct, UikaResult};
+
+use crate::gameplay_tags::{FGameplayTag, FGameplayTagContainer};
+
+pub trait OwnedFGameplayTagExt {
+    fn to_tag(&self) -> GameplayTag;
+}
+
+impl OwnedFGameplayTagExt for OwnedStruct<FGameplayTag> {
+    fn to_tag(&self) -> GameplayTag {
+        unsafe { GameplayTag::read_from(self.as_bytes().as_ptr()) }
+    }
+}
+
+impl FGameplayTag {
+    pub fn from_tag(tag: GameplayTag) -> OwnedStruct<FGameplayTag> {
+        let s = OwnedStruct::<FGameplayTag>::new();
+        // Only fails for a null pointer.
+        unsafe { tag.write_to(s.as_ref().as_ptr().0 as *mut u8) }.expect("write FGameplayTag");
+        s
+    }
+}
+
+pub trait OwnedFGameplayTagContainerExt {
+    fn to_tag_container(&self) -> UikaResult<GameplayTagContainer>;
+}
+
+impl OwnedFGameplayTagContainerExt for OwnedStruct<FGameplayTagContainer> {
+    fn to_tag_container(&self) -> UikaResult<GameplayTagContainer> {
+        unsafe { GameplayTagContainer::read_from(self.as_bytes().as_ptr()) }
+    }
+}
+
+impl FGameplayTagContainer {
+    pub fn from_tag_container(tags: &GameplayTagContainer) -> UikaResult<OwnedStruct<FGameplayTagContainer>> {
+        let s = OwnedStruct::<FGameplayTagContainer>::new();
+        unsafe { tags.write_to(s.as_ref().as_ptr().0 as *mut u8)? };
+        Ok(s)
+    }
+}
@@ -0,0 +1,29 @@
+// Guid ↔ OwnedStruct<FGuid> conversions.
+
+use uika_runtime::{Guid, OwnedStruct};
+
+use crate::core_ue::{FGuid, FGuidExt};
+
+pub trait OwnedFGuidExt {
+    fn to_guid(&self) -> Guid;
+}
+
+impl OwnedFGuidExt for OwnedStruct<FGuid> {
+    fn to_guid(&self) -> Guid {
+        let r = self.as_ref();
+        // Reflected as int32; the bits are the guid's uint32 words.
+        Guid::new(r.get_a() as u32, r.get_b() as u32, r.get_c() as u32, r.get_d() as u32)
+    }
+}
+
+impl FGuid {
+    pub fn from_guid(g: Guid) -> OwnedStruct<FGuid> {
+        let s = OwnedStruct::<FGuid>::new();
+        let r = s.as_ref();
+        r.set_a(g.a as i32);
+        r.set_b(g.b as i32);
+        r.set_c(g.c as i32);
+        r.set_d(g.d as i32);
+        s
+    }
+}
@@ -0,0 +1,23 @@
+// HitResult ↔ OwnedStruct<FHitResult> conversions.
+
+use uika_runtime::{HitResult, OwnedStruct, UStructRef, UikaResult};
+
+use crate::engine::FHitResult;
+
+pub trait OwnedFHitResultExt {
+    /// Decode into a [`HitResult`] (hit actor/component, location, normals,
+    /// bone name, ...).
+    fn to_hit_result(&self) -> UikaResult<HitResult>;
+}
+
+impl OwnedFHitResultExt for OwnedStruct<FHitResult> {
+    fn to_hit_result(&self) -> UikaResult<HitResult> {
+        HitResult::from_struct(&self.as_ref())
+    }
+}
+
+impl OwnedFHitResultExt for UStructRef<FHitResult> {
+    fn to_hit_result(&self) -> UikaResult<HitResult> {
+        HitResult::from_struct(self)
+    }
+}
@@ -0,0 +1,27 @@
+// IVec2 ↔ OwnedStruct<FIntPoint> conversions.
+
+use glam::IVec2;
+use uika_runtime::{OwnedStruct};
+
+use crate::core_ue::{FIntPoint, FIntPointExt};
+
+pub trait OwnedFIntPointExt {
+    fn to_ivec2(&self) -> IVec2;
+}
+
+impl OwnedFIntPointExt for OwnedStruct<FIntPoint> {
+    fn to_ivec2(&self) -> IVec2 {
+        let r = self.as_ref();
+        IVec2::new(r.get_x(), r.get_y())
+    }
+}
+
+impl FIntPoint {
+    pub fn from_ivec2(v: IVec2) -> OwnedStruct<FIntPoint> {
+        let s = OwnedStruct::<FIntPoint>::new();
+        let r = s.as_ref();
+        r.set_x(v.x);
+        r.set_y(v.y);
+        s
+    }
+}
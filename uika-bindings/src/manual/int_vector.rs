@@ -0,0 +1,28 @@
+// IVec3 ↔ OwnedStruct<FIntVector> conversions.
+
+use glam::IVec3;
+use uika_runtime::{OwnedStruct};
+
+use crate::core_ue::{FIntVector, FIntVectorExt};
+
+pub trait OwnedFIntVectorExt {
+    fn to_ivec3(&self) -> IVec3;
+}
+
+impl OwnedFIntVectorExt for OwnedStruct<FIntVector> {
+    fn to_ivec3(&self) -> IVec3 {
+        let r = self.as_ref();
+        IVec3::new(r.get_x(), r.get_y(), r.get_z())
+    }
+}
+
+impl FIntVector {
+    pub fn from_ivec3(v: IVec3) -> OwnedStruct<FIntVector> {
+        let s = OwnedStruct::<FIntVector>::new();
+        let r = s.as_ref();
+        r.set_x(v.x);
+        r.set_y(v.y);
+        r.set_z(v.z);
+        s
+    }
+}
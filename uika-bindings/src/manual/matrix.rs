@@ -0,0 +1,56 @@
+// DMat4 / Mat4 ↔ OwnedStruct<FMatrix> conversions.
+// FMatrix stores four FPlane rows (XPlane..WPlane) and uses row vectors, so
+// each UE row maps to a glam column (the memory layout is identical).
+
+use glam::{DMat4, DVec3, DVec4, Mat4};
+use uika_runtime::{OwnedStruct, Plane};
+
+use crate::core_ue::{FMatrix, FMatrixExt, FPlane};
+use super::plane::OwnedFPlaneExt;
+
+pub trait OwnedFMatrixExt {
+    fn to_dmat4(&self) -> DMat4;
+
+    /// [`to_dmat4`](Self::to_dmat4) narrowed to f32, for rendering code.
+    fn to_mat4(&self) -> Mat4 {
+        self.to_dmat4().as_mat4()
+    }
+}
+
+#[inline]
+fn plane_to_dvec4(p: Plane) -> DVec4 {
+    p.normal.extend(p.d)
+}
+
+#[inline]
+fn dvec4_to_plane(v: DVec4) -> OwnedStruct<FPlane> {
+    FPlane::from_plane(Plane::new(DVec3::new(v.x, v.y, v.z), v.w))
+}
+
+impl OwnedFMatrixExt for OwnedStruct<FMatrix> {
+    fn to_dmat4(&self) -> DMat4 {
+        let r = self.as_ref();
+        DMat4::from_cols(
+            plane_to_dvec4(r.get_x_plane().to_plane()),
+            plane_to_dvec4(r.get_y_plane().to_plane()),
+            plane_to_dvec4(r.get_z_plane().to_plane()),
+            plane_to_dvec4(r.get_w_plane().to_plane()),
+        )
+    }
+}
+
+impl FMatrix {
+    pub fn from_dmat4(m: DMat4) -> OwnedStruct<FMatrix> {
+        let s = OwnedStruct::<FMatrix>::new();
+        let r = s.as_ref();
+        r.set_x_plane(&dvec4_to_plane(m.x_axis));
+        r.set_y_plane(&dvec4_to_plane(m.y_axis));
+        r.set_z_plane(&dvec4_to_plane(m.z_axis));
+        r.set_w_plane(&dvec4_to_plane(m.w_axis));
+        s
+    }
+
+    pub fn from_mat4(m: Mat4) -> OwnedStruct<FMatrix> {
+        Self::from_dmat4(m.as_dmat4())
+    }
+}
@@ -22,12 +22,27 @@ pub mod color;
 pub mod plane;
 #[cfg(feature = "core")]
 pub mod ue_box2d;
+#[cfg(feature = "core")]
+pub mod ue_box;
+#[cfg(feature = "core")]
+pub mod matrix;
+#[cfg(feature = "core")]
+pub mod int_vector;
+#[cfg(feature = "core")]
+pub mod int_point;
+#[cfg(feature = "core")]
+pub mod guid;
+
+#[cfg(feature = "gameplay-tags")]
+pub mod gameplay_tags;
 
 #[cfg(feature = "input")]
 pub mod fkey;
 
 #[cfg(feature = "engine")]
 pub mod world_ext;
+#[cfg(feature = "engine")]
+pub mod hit_result;
 
 #[cfg(feature = "umg")]
 pub mod widget_ext;
@@ -0,0 +1,33 @@
+// UeBox ↔ OwnedStruct<FBox> conversions (nested FVector fields).
+
+use uika_runtime::{OwnedStruct, UeBox};
+
+use crate::core_ue::{FBox, FBoxExt, FVector};
+use super::vector::OwnedFVectorExt;
+
+pub trait OwnedFBoxExt {
+    fn to_ue_box(&self) -> UeBox;
+}
+
+impl OwnedFBoxExt for OwnedStruct<FBox> {
+    fn to_ue_box(&self) -> UeBox {
+        let r = self.as_ref();
+        let min = r.get_min().to_dvec3();
+        let max = r.get_max().to_dvec3();
+        UeBox::new(min, max)
+    }
+}
+
+impl FBox {
+    /// The resulting FBox is marked valid (FBox::IsValid = 1).
+    pub fn from_ue_box(b: UeBox) -> OwnedStruct<FBox> {
+        let s = OwnedStruct::<FBox>::new();
+        let r = s.as_ref();
+        let min = FVector::from_dvec3(b.min);
+        r.set_min(&min);
+        let max = FVector::from_dvec3(b.max);
+        r.set_max(&max);
+        r.set_is_valid(1);
+        s
+    }
+}
@@ -1,6 +1,6 @@
 // Type-safe gameplay wrappers on top of uika_runtime::world raw functions.
 
-use uika_runtime::{OwnedStruct, UObjectRef, UeClass, UikaResult};
+use uika_runtime::{OwnedStruct, SpawnBuilder, UObjectRef, UeClass, UikaResult};
 
 use crate::core_ue::FTransform;
 use crate::engine::{Actor, ActorExt, World};
@@ -78,6 +78,9 @@ pub trait WorldSpawnExt {
         transform: &OwnedStruct<FTransform>,
     ) -> UikaResult<()>;
 
+    /// Deferred spawn with property values applied before BeginPlay.
+    fn spawn_builder<T: UeClass>(&self, transform: &OwnedStruct<FTransform>) -> SpawnBuilder<T>;
+
     fn get_all_actors_of_class<T: UeClass>(&self) -> UikaResult<Vec<UObjectRef<T>>>;
 }
 
@@ -156,6 +159,10 @@ impl WorldSpawnExt for UObjectRef<World> {
         uika_runtime::world::finish_spawning_raw(actor_handle, &transform.to_bytes())
     }
 
+    fn spawn_builder<T: UeClass>(&self, transform: &OwnedStruct<FTransform>) -> SpawnBuilder<T> {
+        SpawnBuilder::new(self.raw(), &transform.to_bytes())
+    }
+
     fn get_all_actors_of_class<T: UeClass>(&self) -> UikaResult<Vec<UObjectRef<T>>> {
         let world = self.checked()?.raw();
         let class = T::static_class();
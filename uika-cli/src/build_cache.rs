@@ -0,0 +1,193 @@
+// Build cache: lets `uika build` skip steps whose inputs did not change.
+//
+// Each step's inputs are hashed by content:
+//
+// 1. UE build:   the project's and plugins' C++/C#/descriptor sources,
+//                minus the generated wrappers;
+// 2. codegen:    uika.config.toml and the UHT JSON exports;
+// 3. UE rebuild: the same sources as step 1, plus the generated wrappers;
+// 4. cargo:      never cached; cargo's own fingerprints also cover path
+//                dependencies, RUSTFLAGS and the profile, and an
+//                up-to-date cargo build is quick;
+// 5. DLL copy:   the built DLL.
+//
+// After a step succeeds its input hash is recorded in
+// `.uika-build-cache.json` in the config directory. On the next build a step
+// is skipped when its hash matches and its output still exists. A step that
+// reruns changes the inputs of the ones after it, so they rerun too.
+// `--force` ignores the recorded hashes (and records fresh ones).
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::hash::Hasher;
+use std::path::{Path, PathBuf};
+
+use uika_codegen::incremental::StableHasher;
+
+use crate::build_cmd::BuildContext;
+
+/// Cache file name, written into the config directory.
+pub const CACHE_NAME: &str = ".uika-build-cache.json";
+
+/// Bump when the cache layout or what a step hashes changes.
+const CACHE_VERSION: u64 = 1;
+
+/// Source extensions UBT builds from.
+const UE_SOURCE_EXTENSIONS: &[&str] = &["h", "hpp", "inl", "c", "cpp", "cs", "uplugin", "uproject"];
+
+/// Directories under a UE project or plugin that hold build output, not
+/// sources.
+const UE_OUTPUT_DIRS: &[&str] = &["Binaries", "Intermediate", "Saved", "DerivedDataCache"];
+
+/// Recorded input hash of each step that last succeeded.
+pub(crate) struct BuildCache {
+    path: PathBuf,
+    steps: BTreeMap<u8, u64>,
+}
+
+impl BuildCache {
+    /// Load the cache of `ctx`'s project. A missing, unreadable or outdated
+    /// cache is empty: every step runs.
+    pub(crate) fn load(ctx: &BuildContext) -> Self {
+        let path = ctx.config_dir.join(CACHE_NAME);
+        let steps = fs::read_to_string(&path)
+            .ok()
+            .and_then(|s| serde_json::from_str::<serde_json::Value>(&s).ok())
+            .filter(|doc| doc.get("version").and_then(|v| v.as_u64()) == Some(CACHE_VERSION))
+            .and_then(|doc| {
+                let steps = doc.get("steps")?.as_object()?;
+                Some(
+                    steps
+                        .iter()
+                        .filter_map(|(step, hash)| {
+                            Some((step.parse().ok()?, u64::from_str_radix(hash.as_str()?, 16).ok()?))
+                        })
+                        .collect(),
+                )
+            })
+            .unwrap_or_default();
+        BuildCache { path, steps }
+    }
+
+    /// Whether `step` last succeeded with inputs hashing to `input`.
+    pub(crate) fn is_fresh(&self, step: u8, input: u64) -> bool {
+        self.steps.get(&step) == Some(&input)
+    }
+
+    /// Record that `step` succeeded with inputs hashing to `input`. Written
+    /// immediately, so a later failing step keeps the earlier ones cached.
+    pub(crate) fn record(&mut self, step: u8, input: u64) {
+        self.steps.insert(step, input);
+        let steps: serde_json::Map<String, serde_json::Value> = self
+            .steps
+            .iter()
+            .map(|(step, hash)| (step.to_string(), format!("{hash:016x}").into()))
+            .collect();
+        let doc = serde_json::json!({ "version": CACHE_VERSION, "steps": steps });
+        let json = serde_json::to_string_pretty(&doc).expect("build cache serializes");
+        if let Err(e) = fs::write(&self.path, json) {
+            eprintln!("  Warning: failed to write {}: {e}", self.path.display());
+        }
+    }
+}
+
+/// Hash of everything `step` reads, or `None` if its output is missing and
+/// it must run regardless.
+pub(crate) fn step_input(ctx: &BuildContext, step: u8) -> Option<u64> {
+    let mut h = StableHasher::default();
+    h.str(env!("CARGO_PKG_VERSION"));
+    match step {
+        1 => {
+            if !ctx.uht_input.join("uika_classes.json").exists() {
+                return None;
+            }
+            h.str(&ctx.engine_path.to_string_lossy());
+            hash_files(&mut h, &ue_sources(ctx, false));
+        }
+        2 => {
+            if !ctx.rust_out.exists() || !ctx.cpp_out.exists() {
+                return None;
+            }
+            let mut files = vec![ctx.config_path.clone()];
+            for dir in std::iter::once(&ctx.uht_input).chain(&ctx.extra_uht_inputs) {
+                files.extend(collect_files(dir, &[], &|path: &Path| has_extension(path, &["json"])));
+            }
+            hash_files(&mut h, &files);
+        }
+        3 => {
+            h.str(&ctx.engine_path.to_string_lossy());
+            hash_files(&mut h, &ue_sources(ctx, true));
+        }
+        // Left to cargo, which knows every input of the build.
+        4 => return None,
+        5 => {
+            if !ctx.plugin_binaries_dir().join("uika.dll").exists() {
+                return None;
+            }
+            hash_files(&mut h, &[ctx.built_dll_path()]);
+        }
+        _ => unreachable!(),
+    }
+    Some(h.finish())
+}
+
+/// C++/C# sources and descriptors of the project and its plugins.
+/// `with_wrappers` includes the generated wrappers (`[codegen.paths] cpp_out`).
+fn ue_sources(ctx: &BuildContext, with_wrappers: bool) -> Vec<PathBuf> {
+    let skip: Vec<&Path> = if with_wrappers { Vec::new() } else { vec![ctx.cpp_out.as_path()] };
+    let is_source = |path: &Path| has_extension(path, UE_SOURCE_EXTENSIONS);
+    let mut files = collect_files(&ctx.project_path.join("Source"), &skip, &is_source);
+    files.extend(collect_files(&ctx.project_path.join("Plugins"), &skip, &is_source));
+    files.push(ctx.uproject_path());
+    files
+}
+
+/// Hash every file's path and content, in a stable order.
+fn hash_files(h: &mut StableHasher, files: &[PathBuf]) {
+    let mut files: Vec<&PathBuf> = files.iter().collect();
+    files.sort();
+    files.dedup();
+    for path in files {
+        h.str(&path.to_string_lossy());
+        match fs::read(path) {
+            Ok(bytes) => {
+                h.write_u64(bytes.len() as u64);
+                h.write(&bytes);
+            }
+            // A missing file hashes differently from an empty one.
+            Err(_) => h.write_u8(0xff),
+        }
+    }
+}
+
+/// Every file under `root` accepted by `filter`, skipping the `skip`
+/// directories, hidden directories, `target` and UE build output.
+fn collect_files(root: &Path, skip: &[&Path], filter: &dyn Fn(&Path) -> bool) -> Vec<PathBuf> {
+    let mut files = Vec::new();
+    let mut stack = vec![root.to_path_buf()];
+    while let Some(dir) = stack.pop() {
+        let Ok(entries) = fs::read_dir(&dir) else { continue };
+        for entry in entries.flatten() {
+            let path = entry.path();
+            let name = entry.file_name();
+            let name = name.to_string_lossy();
+            if path.is_dir() {
+                if name.starts_with('.')
+                    || name == "target"
+                    || UE_OUTPUT_DIRS.contains(&name.as_ref())
+                    || skip.contains(&path.as_path())
+                {
+                    continue;
+                }
+                stack.push(path);
+            } else if filter(&path) {
+                files.push(path);
+            }
+        }
+    }
+    files
+}
+
+fn has_extension(path: &Path, extensions: &[&str]) -> bool {
+    path.extension().and_then(|ext| ext.to_str()).is_some_and(|ext| extensions.contains(&ext))
+}
@@ -7,9 +7,11 @@ use std::time::Instant;
 
 use uika_codegen::config::UikaConfig;
 
+use crate::build_cache::{self, BuildCache};
+
 /// Canonicalize a path, stripping the `\\?\` extended-length prefix that
 /// Windows adds. UBT's .NET XML parser chokes on that prefix.
-fn canonical_no_prefix(path: &Path) -> PathBuf {
+pub(crate) fn canonical_no_prefix(path: &Path) -> PathBuf {
     let abs = path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
     #[cfg(windows)]
     {
@@ -26,8 +28,10 @@ fn canonical_no_prefix(path: &Path) -> PathBuf {
 ///
 /// `config_path` is the path to uika.config.toml.
 /// `step` runs only that step (1-5). `from` starts from that step (1-5).
-/// `step` and `from` are mutually exclusive.
-pub fn run_build(config_path: &Path, step: Option<u8>, from: u8) {
+/// `step` and `from` are mutually exclusive. Steps whose inputs did not
+/// change since they last succeeded are skipped unless `force` is set (see
+/// build_cache.rs).
+pub fn run_build(config_path: &Path, step: Option<u8>, from: u8, force: bool) {
     // Validate step/from
     if step.is_some() && from != 1 {
         eprintln!("Error: --step and --from are mutually exclusive.");
@@ -44,22 +48,8 @@ pub fn run_build(config_path: &Path, step: Option<u8>, from: u8) {
         std::process::exit(1);
     }
 
-    // Load config
-    let config_str = fs::read_to_string(config_path)
-        .unwrap_or_else(|e| panic!("Failed to read {}: {e}", config_path.display()));
-    let config: UikaConfig = toml::from_str(&config_str)
-        .unwrap_or_else(|e| panic!("Failed to parse {}: {e}", config_path.display()));
-
-    // Resolve paths relative to config file directory
-    let config_parent = config_path.parent().unwrap_or(Path::new("."));
-    let config_dir = if config_parent.as_os_str().is_empty() {
-        Path::new(".").canonicalize()
-    } else {
-        config_parent.canonicalize()
-    }
-    .unwrap_or_else(|e| panic!("Failed to canonicalize config dir: {e}"));
-
-    let ctx = BuildContext::new(&config, &config_dir, config_path);
+    let ctx = BuildContext::load(config_path);
+    let mut cache = BuildCache::load(&ctx);
 
     // Determine which steps to run
     let steps: Vec<u8> = if let Some(s) = step {
@@ -102,6 +92,12 @@ pub fn run_build(config_path: &Path, step: Option<u8>, from: u8) {
         );
         eprintln!("{}", "-".repeat(60));
 
+        let input = build_cache::step_input(&ctx, step_num);
+        if !force && input.is_some_and(|input| cache.is_fresh(step_num, input)) {
+            eprintln!("  Inputs unchanged, skipped (--force to rerun)\n");
+            continue;
+        }
+
         match step_num {
             1 => ctx.step1_ue_build(),
             2 => ctx.step2_codegen(),
@@ -110,6 +106,10 @@ pub fn run_build(config_path: &Path, step: Option<u8>, from: u8) {
             5 => ctx.step5_copy_dll(),
             _ => unreachable!(),
         }
+        // Re-hashed when the output was missing before the step ran.
+        if let Some(input) = input.or_else(|| build_cache::step_input(&ctx, step_num)) {
+            cache.record(step_num, input);
+        }
 
         let elapsed = step_start.elapsed().as_secs_f64();
         eprintln!("  Step {step_num} completed in {elapsed:.1}s\n");
@@ -122,21 +122,45 @@ pub fn run_build(config_path: &Path, step: Option<u8>, from: u8) {
 }
 
 /// Resolved build context with all paths pre-computed.
-struct BuildContext {
-    engine_path: PathBuf,
-    project_path: PathBuf,
-    uht_input: PathBuf,
-    config_path: PathBuf,
-    crate_name: String,
-    config_dir: PathBuf,
+pub(crate) struct BuildContext {
+    pub(crate) engine_path: PathBuf,
+    pub(crate) project_path: PathBuf,
+    pub(crate) uht_input: PathBuf,
+    pub(crate) config_path: PathBuf,
+    pub(crate) crate_name: String,
+    pub(crate) config_dir: PathBuf,
     /// Path to an external crate directory (resolved, absolute).
     /// When set, `cargo build` uses `--manifest-path` instead of `-p`.
-    crate_path: Option<PathBuf>,
+    pub(crate) crate_path: Option<PathBuf>,
     /// Extra features for `cargo build` (from `[build].features`).
-    features: Vec<String>,
+    pub(crate) features: Vec<String>,
+    /// UHT export directories of `[[codegen.extra_sources]]` (resolved, absolute).
+    pub(crate) extra_uht_inputs: Vec<PathBuf>,
+    /// Generated Rust / C++ output directories (resolved, absolute).
+    pub(crate) rust_out: PathBuf,
+    pub(crate) cpp_out: PathBuf,
 }
 
 impl BuildContext {
+    /// Load uika.config.toml and resolve paths relative to its directory.
+    pub(crate) fn load(config_path: &Path) -> Self {
+        let config_str = fs::read_to_string(config_path)
+            .unwrap_or_else(|e| panic!("Failed to read {}: {e}", config_path.display()));
+        let config: UikaConfig = toml::from_str(&config_str)
+            .unwrap_or_else(|e| panic!("Failed to parse {}: {e}", config_path.display()));
+
+        // Resolve paths relative to config file directory
+        let config_parent = config_path.parent().unwrap_or(Path::new("."));
+        let config_dir = if config_parent.as_os_str().is_empty() {
+            Path::new(".").canonicalize()
+        } else {
+            config_parent.canonicalize()
+        }
+        .unwrap_or_else(|e| panic!("Failed to canonicalize config dir: {e}"));
+
+        BuildContext::new(&config, &config_dir, config_path)
+    }
+
     fn new(config: &UikaConfig, config_dir: &Path, config_path: &Path) -> Self {
         // Engine path (required for build)
         let engine_path = config
@@ -178,6 +202,16 @@ impl BuildContext {
             .map(|b| b.features.clone())
             .unwrap_or_default();
 
+        let extra_uht_inputs = config
+            .codegen
+            .extra_sources
+            .iter()
+            .map(|source| config_dir.join(&source.uht_input))
+            .collect();
+
+        let rust_out = config_dir.join(&config.codegen.paths.rust_out);
+        let cpp_out = config_dir.join(&config.codegen.paths.cpp_out);
+
         BuildContext {
             engine_path,
             project_path,
@@ -187,9 +221,17 @@ impl BuildContext {
             config_dir: config_dir.to_path_buf(),
             crate_path,
             features,
+            extra_uht_inputs,
+            rust_out,
+            cpp_out,
         }
     }
 
+    /// The Uika plugin's Binaries directory, where uika.dll is installed.
+    pub(crate) fn plugin_binaries_dir(&self) -> PathBuf {
+        self.project_path.join("Plugins/Uika/Binaries/Win64")
+    }
+
     /// Find the .uproject file and derive the Editor target name.
     fn editor_target(&self) -> String {
         let uproject = find_uproject(&self.project_path).unwrap_or_else(|| {
@@ -208,7 +250,7 @@ impl BuildContext {
     }
 
     /// Full path to the .uproject file.
-    fn uproject_path(&self) -> PathBuf {
+    pub(crate) fn uproject_path(&self) -> PathBuf {
         find_uproject(&self.project_path).unwrap_or_else(|| {
             eprintln!(
                 "Error: no .uproject found in {}",
@@ -276,7 +318,7 @@ impl BuildContext {
     }
 
     /// Step 2: Run codegen (in-process).
-    fn step2_codegen(&self) {
+    pub(crate) fn step2_codegen(&self) {
         uika_codegen::run_generate(&self.config_path);
     }
 
@@ -305,6 +347,14 @@ impl BuildContext {
 
     /// Step 4: cargo build --release.
     fn step4_cargo_build(&self) {
+        if let Err(code) = self.cargo_build() {
+            std::process::exit(code);
+        }
+    }
+
+    /// `cargo build --release` for the cdylib crate, returning the exit code
+    /// on failure instead of exiting (used by `uika watch`).
+    pub(crate) fn cargo_build(&self) -> Result<(), i32> {
         let manifest_path_str;
         let mut args = vec!["cargo", "build", "--release"];
 
@@ -325,11 +375,11 @@ impl BuildContext {
             args.push("--features");
             args.push(&features_str);
         }
-        run_cmd(&args);
+        try_run_cmd(&args)
     }
 
-    /// Step 5: Copy built DLL to UE plugin Binaries.
-    fn step5_copy_dll(&self) {
+    /// Where step 4 left the cdylib.
+    pub(crate) fn built_dll_path(&self) -> PathBuf {
         // Cargo converts hyphens to underscores in output filenames
         let dll_filename = format!("{}.dll", self.crate_name.replace('-', "_"));
 
@@ -346,37 +396,58 @@ impl BuildContext {
             .join(&dll_filename);
         let config_target = self.config_dir.join("target/release").join(&dll_filename);
 
-        let src = if external_target.as_ref().is_some_and(|p| p.exists()) {
+        if external_target.as_ref().is_some_and(|p| p.exists()) {
             external_target.unwrap()
         } else if cwd_target.exists() {
             cwd_target
         } else {
             config_target
-        };
+        }
+    }
 
-        let dest_dir = self
-            .project_path
-            .join("Plugins/Uika/Binaries/Win64");
+    /// Step 5: Copy built DLL to UE plugin Binaries.
+    fn step5_copy_dll(&self) {
+        if let Err(message) = self.copy_dll() {
+            eprintln!("Error: {message}");
+            std::process::exit(1);
+        }
+    }
+
+    /// Copy the built DLL to the plugin Binaries as uika.dll, returning the
+    /// failure instead of exiting (used by `uika watch`).
+    pub(crate) fn copy_dll(&self) -> Result<(), String> {
+        let src = self.built_dll_path();
+
+        let dest_dir = self.plugin_binaries_dir();
         let dest = dest_dir.join("uika.dll");
 
         if !src.exists() {
-            eprintln!("Error: DLL not found at {}", src.display());
-            eprintln!("  Did step 4 (cargo build) succeed?");
-            std::process::exit(1);
+            return Err(format!(
+                "DLL not found at {}\n  Did step 4 (cargo build) succeed?",
+                src.display()
+            ));
         }
 
         fs::create_dir_all(&dest_dir)
-            .unwrap_or_else(|e| panic!("Failed to create {}: {e}", dest_dir.display()));
+            .map_err(|e| format!("Failed to create {}: {e}", dest_dir.display()))?;
         fs::copy(&src, &dest)
-            .unwrap_or_else(|e| panic!("Failed to copy DLL: {e}"));
+            .map_err(|e| format!("Failed to copy DLL to {}: {e}", dest.display()))?;
 
         eprintln!("  Copied {}", src.display());
         eprintln!("      -> {} (renamed to uika.dll)", dest.display());
+        Ok(())
     }
 }
 
 /// Run an external command, printing it and exiting on failure.
 fn run_cmd(args: &[&str]) {
+    if let Err(code) = try_run_cmd(args) {
+        std::process::exit(code);
+    }
+}
+
+/// Run an external command, printing it and returning its exit code on failure.
+fn try_run_cmd(args: &[&str]) -> Result<(), i32> {
     let display: String = args.iter().map(|a| *a).collect::<Vec<_>>().join(" ");
     let truncated = if display.len() > 200 {
         format!("{}...", &display[..197])
@@ -393,8 +464,9 @@ fn run_cmd(args: &[&str]) {
     if !status.success() {
         let code = status.code().unwrap_or(1);
         eprintln!("\n  Command failed with exit code {code}");
-        std::process::exit(code);
+        return Err(code);
     }
+    Ok(())
 }
 
 /// Find a .uproject file in the given directory.
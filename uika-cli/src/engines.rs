@@ -0,0 +1,168 @@
+// Engine discovery: find installed Unreal Engine versions for `uika setup`.
+//
+// Sources, per platform:
+// - Windows: the Epic launcher's LauncherInstalled.dat and its
+//   `HKLM\SOFTWARE\EpicGames\Unreal Engine\<version>` keys, plus source
+//   builds registered under `HKCU\Software\Epic Games\Unreal Engine\Builds`
+//   (read with `reg query`, so no registry crate is needed);
+// - Linux / macOS: source builds registered in the engine's Install.ini,
+//   and the usual install directories.
+//
+// An engine's version comes from its Engine/Build/Build.version, not from
+// the directory name, so renamed and source-built engines are identified
+// correctly.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// An engine found on this machine.
+#[derive(Clone, Debug)]
+pub struct EngineInstall {
+    pub path: PathBuf,
+    pub major: u32,
+    pub minor: u32,
+    pub patch: u32,
+}
+
+impl EngineInstall {
+    /// `major.minor`, the form `--engine-version` takes.
+    pub fn version(&self) -> String {
+        format!("{}.{}", self.major, self.minor)
+    }
+
+    /// `major.minor.patch`, the form .uplugin's `EngineVersion` takes.
+    pub fn full_version(&self) -> String {
+        format!("{}.{}.{}", self.major, self.minor, self.patch)
+    }
+
+    /// Read the engine at `path`, if it is one.
+    pub fn at(path: &Path) -> Option<Self> {
+        let text = fs::read_to_string(path.join("Engine/Build/Build.version")).ok()?;
+        let doc: serde_json::Value = serde_json::from_str(&text).ok()?;
+        let field = |name: &str| doc.get(name).and_then(|v| v.as_u64()).map(|v| v as u32);
+        Some(EngineInstall {
+            path: path.to_path_buf(),
+            major: field("MajorVersion")?,
+            minor: field("MinorVersion")?,
+            patch: field("PatchVersion").unwrap_or(0),
+        })
+    }
+
+    /// Whether this engine is `version` (`"5.7"`, or `"5.7.1"` for an exact
+    /// patch).
+    pub fn matches(&self, version: &str) -> bool {
+        version == self.version() || version == self.full_version()
+    }
+}
+
+/// Every engine found, newest first.
+pub fn discover() -> Vec<EngineInstall> {
+    let mut engines: Vec<EngineInstall> = candidate_dirs()
+        .iter()
+        .filter_map(|dir| EngineInstall::at(dir))
+        .collect();
+    engines.sort_by_key(|e| std::cmp::Reverse((e.major, e.minor, e.patch)));
+    engines.dedup_by(|a, b| same_dir(&a.path, &b.path));
+    engines
+}
+
+/// The newest engine matching `version`, or the newest engine at all.
+pub fn find(version: Option<&str>) -> Option<EngineInstall> {
+    discover().into_iter().find(|e| version.is_none_or(|v| e.matches(v)))
+}
+
+fn same_dir(a: &Path, b: &Path) -> bool {
+    match (a.canonicalize(), b.canonicalize()) {
+        (Ok(a), Ok(b)) => a == b,
+        _ => a == b,
+    }
+}
+
+#[cfg(windows)]
+fn candidate_dirs() -> Vec<PathBuf> {
+    let mut dirs = Vec::new();
+
+    // Launcher installs.
+    let program_data = std::env::var("PROGRAMDATA").unwrap_or_else(|_| "C:/ProgramData".into());
+    let launcher = Path::new(&program_data).join("Epic/UnrealEngineLauncher/LauncherInstalled.dat");
+    if let Some(list) = fs::read_to_string(launcher)
+        .ok()
+        .and_then(|s| serde_json::from_str::<serde_json::Value>(&s).ok())
+        .and_then(|doc| doc.get("InstallationList").cloned())
+        .and_then(|list| list.as_array().cloned())
+    {
+        for item in list {
+            let is_engine = item.get("AppName").and_then(|v| v.as_str()).is_some_and(|n| n.starts_with("UE_"));
+            if let (true, Some(location)) = (is_engine, item.get("InstallLocation").and_then(|v| v.as_str())) {
+                dirs.push(PathBuf::from(location));
+            }
+        }
+    }
+    dirs.extend(reg_query(r"HKLM\SOFTWARE\EpicGames\Unreal Engine", Some("InstalledDirectory")));
+
+    // Source builds, registered by the engine's Setup.bat / UnrealVersionSelector.
+    dirs.extend(reg_query(r"HKCU\Software\Epic Games\Unreal Engine\Builds", None));
+
+    let program_files = std::env::var("ProgramFiles").unwrap_or_else(|_| "C:/Program Files".into());
+    dirs.extend(glob_dirs(&Path::new(&program_files).join("Epic Games"), "UE_"));
+    dirs
+}
+
+/// The `REG_SZ` data under `key` (recursively), optionally only of values
+/// named `value`.
+#[cfg(windows)]
+fn reg_query(key: &str, value: Option<&str>) -> Vec<PathBuf> {
+    let mut cmd = std::process::Command::new("reg");
+    cmd.args(["query", key, "/s"]);
+    if let Some(value) = value {
+        cmd.args(["/v", value]);
+    }
+    let Ok(output) = cmd.output() else { return Vec::new() };
+    String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .filter_map(|line| line.split_once("REG_SZ"))
+        .map(|(_, data)| PathBuf::from(data.trim()))
+        .collect()
+}
+
+#[cfg(not(windows))]
+fn candidate_dirs() -> Vec<PathBuf> {
+    let home = std::env::var("HOME").map(PathBuf::from).unwrap_or_default();
+    let mut dirs = Vec::new();
+
+    // Source builds register themselves in Install.ini ([Installations] Id=Path).
+    let install_ini = if cfg!(target_os = "macos") {
+        home.join("Library/Application Support/Epic/UnrealEngine/Install.ini")
+    } else {
+        home.join(".config/Epic/UnrealEngine/Install.ini")
+    };
+    if let Ok(ini) = fs::read_to_string(install_ini) {
+        dirs.extend(
+            ini.lines()
+                .filter(|line| !line.starts_with('['))
+                .filter_map(|line| line.split_once('='))
+                .map(|(_, path)| PathBuf::from(path.trim())),
+        );
+    }
+
+    if cfg!(target_os = "macos") {
+        dirs.extend(glob_dirs(Path::new("/Users/Shared/Epic Games"), "UE_"));
+    } else {
+        dirs.extend(glob_dirs(&home, "UnrealEngine"));
+        dirs.extend(glob_dirs(&home.join("Epic Games"), "UE_"));
+        dirs.extend(glob_dirs(Path::new("/opt"), "UnrealEngine"));
+        dirs.extend(glob_dirs(Path::new("/opt"), "UE_"));
+    }
+    dirs
+}
+
+/// Subdirectories of `parent` whose name starts with `prefix`.
+fn glob_dirs(parent: &Path, prefix: &str) -> Vec<PathBuf> {
+    let Ok(entries) = fs::read_dir(parent) else { return Vec::new() };
+    entries
+        .flatten()
+        .filter(|e| e.file_name().to_string_lossy().starts_with(prefix))
+        .map(|e| e.path())
+        .filter(|p| p.is_dir())
+        .collect()
+}
@@ -0,0 +1,140 @@
+// IDE command: write editor configuration for a Uika project.
+//
+// Writes into `.vscode/` next to uika.config.toml:
+// - settings.json: rust-analyzer settings matching `uika build` (the
+//   `[build].features`, the external crate's manifest as the linked project,
+//   proc macros and build scripts on for `#[uclass]` and the generated
+//   bindings);
+// - tasks.json: tasks running `uika build` (full and Rust-only), `uika
+//   watch` and `uika generate`.
+//
+// Existing files are merged: only the keys and the `uika: ` tasks written
+// here are replaced. A file that isn't plain JSON (VS Code allows comments)
+// is left alone unless `--force` is given.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde_json::{json, Map, Value};
+
+use crate::build_cmd::BuildContext;
+
+/// Label prefix of the tasks this command owns.
+const TASK_PREFIX: &str = "uika: ";
+
+pub fn run_ide(config_path: &Path, force: bool) {
+    let ctx = BuildContext::load(config_path);
+    let vscode = ctx.config_dir.join(".vscode");
+    fs::create_dir_all(&vscode).unwrap_or_else(|e| panic!("Failed to create {}: {e}", vscode.display()));
+
+    let settings_path = vscode.join("settings.json");
+    if let Some(mut settings) = load_object(&settings_path, force) {
+        for (key, value) in rust_analyzer_settings(&ctx) {
+            settings.insert(key, value);
+        }
+        write_json(&settings_path, settings);
+    }
+
+    let tasks_path = vscode.join("tasks.json");
+    if let Some(mut tasks) = load_object(&tasks_path, force) {
+        let mut list: Vec<Value> = tasks
+            .get("tasks")
+            .and_then(|t| t.as_array())
+            .into_iter()
+            .flatten()
+            .filter(|t| !t.get("label").and_then(|l| l.as_str()).is_some_and(|l| l.starts_with(TASK_PREFIX)))
+            .cloned()
+            .collect();
+        list.extend(uika_tasks(&ctx));
+        tasks.insert("version".into(), "2.0.0".into());
+        tasks.insert("tasks".into(), Value::Array(list));
+        write_json(&tasks_path, tasks);
+    }
+}
+
+fn rust_analyzer_settings(ctx: &BuildContext) -> Map<String, Value> {
+    let mut settings = Map::new();
+    settings.insert("rust-analyzer.cargo.features".into(), json!(ctx.features));
+    settings.insert("rust-analyzer.procMacro.enable".into(), json!(true));
+    settings.insert("rust-analyzer.cargo.buildScripts.enable".into(), json!(true));
+    if let Some(crate_path) = &ctx.crate_path {
+        // An external game crate isn't part of a workspace under the config
+        // dir; point rust-analyzer at its manifest.
+        settings.insert(
+            "rust-analyzer.linkedProjects".into(),
+            json!([relative(&ctx.config_dir, &crate_path.join("Cargo.toml"))]),
+        );
+    }
+    settings
+}
+
+fn uika_tasks(ctx: &BuildContext) -> Vec<Value> {
+    let config = relative(&ctx.config_dir, &ctx.config_path);
+    let (command, prefix) = cli_command(&ctx.config_dir);
+    let task = |label: &str, args: &[&str]| {
+        let mut all: Vec<String> = prefix.clone();
+        all.extend(args.iter().map(|a| a.to_string()));
+        all.extend(["--config".to_string(), config.clone()]);
+        json!({
+            "label": format!("{TASK_PREFIX}{label}"),
+            "type": "process",
+            "command": command,
+            "args": all,
+            "options": { "cwd": "${workspaceFolder}" },
+            "problemMatcher": ["$rustc"],
+        })
+    };
+
+    let mut build = task("build", &["build"]);
+    build["group"] = json!({ "kind": "build", "isDefault": true });
+    let mut watch = task("watch", &["watch"]);
+    watch["isBackground"] = json!(true);
+    vec![
+        build,
+        task("build (Rust only)", &["build", "--from", "4"]),
+        task("build (force)", &["build", "--force"]),
+        watch,
+        task("generate", &["generate", "--incremental"]),
+    ]
+}
+
+/// How to invoke the CLI: through cargo inside the uika workspace, otherwise
+/// the installed binary.
+fn cli_command(config_dir: &Path) -> (String, Vec<String>) {
+    if config_dir.join("uika-cli/Cargo.toml").exists() {
+        ("cargo".into(), ["run", "-q", "-p", "uika-cli", "--"].map(String::from).to_vec())
+    } else {
+        ("uika-cli".into(), Vec::new())
+    }
+}
+
+/// The JSON object in `path`, or an empty one if there is no file. `None`
+/// (after a warning) if the file can't be parsed and `force` isn't set.
+fn load_object(path: &Path, force: bool) -> Option<Map<String, Value>> {
+    let Ok(text) = fs::read_to_string(path) else { return Some(Map::new()) };
+    match serde_json::from_str::<Value>(&text) {
+        Ok(Value::Object(map)) => Some(map),
+        _ if force => Some(Map::new()),
+        _ => {
+            eprintln!(
+                "  Skipped {}: not a plain JSON object (comments?). Rerun with --force to overwrite it.",
+                path.display()
+            );
+            None
+        }
+    }
+}
+
+fn write_json(path: &Path, map: Map<String, Value>) {
+    let text = serde_json::to_string_pretty(&Value::Object(map)).expect("JSON serializes");
+    fs::write(path, text + "\n").unwrap_or_else(|e| panic!("Failed to write {}: {e}", path.display()));
+    eprintln!("  Wrote {}", path.display());
+}
+
+/// `path` relative to `base` with forward slashes, or absolute if it isn't
+/// under `base`.
+fn relative(base: &Path, path: &Path) -> String {
+    let path: PathBuf = path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
+    let base = base.canonicalize().unwrap_or_else(|_| base.to_path_buf());
+    path.strip_prefix(&base).unwrap_or(&path).to_string_lossy().replace('\\', "/")
+}
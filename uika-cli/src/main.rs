@@ -1,8 +1,14 @@
-// uika-cli: CLI entry point for Uika tools (codegen, setup, build, sync-plugin).
+// uika-cli: CLI entry point for Uika tools (codegen, setup, build, watch, test, sync-plugin).
 
+mod engines;
 mod setup;
 mod sync_plugin;
 mod build_cmd;
+mod build_cache;
+mod watch_cmd;
+mod test_cmd;
+mod ide_cmd;
+mod reload_cmd;
 
 use std::path::{Path, PathBuf};
 
@@ -24,6 +30,29 @@ enum Commands {
         /// Path to uika.config.toml.
         #[arg(long, default_value = "uika.config.toml")]
         config: PathBuf,
+        /// Only regenerate files whose UHT JSON inputs changed since the last run.
+        #[arg(long)]
+        incremental: bool,
+        /// Report what the allow/blocklists and exportability checks exclude, without writing.
+        #[arg(long)]
+        dry_run: bool,
+        /// Reassign all FuncIds from scratch instead of keeping the persisted map.
+        /// The plugin and the DLL must both be rebuilt afterwards.
+        #[arg(long)]
+        renumber: bool,
+        /// Also write a JSON binding coverage report (see `uika coverage`) to this path.
+        #[arg(long)]
+        coverage: Option<PathBuf>,
+    },
+    /// Report which classes, structs, functions and properties codegen skips and why,
+    /// with per-module percentages, without writing bindings.
+    Coverage {
+        /// Path to uika.config.toml.
+        #[arg(long, default_value = "uika.config.toml")]
+        config: PathBuf,
+        /// Where to write the JSON report.
+        #[arg(long, default_value = "uika_coverage.json")]
+        output: PathBuf,
     },
     /// Extract UE plugin files into a UE project's Plugins/ directory.
     Setup {
@@ -32,12 +61,24 @@ enum Commands {
         /// Path to the UE engine root (e.g. "F:/UE_5.7").
         #[arg(long)]
         engine_path: Option<PathBuf>,
+        /// Use the installed engine of this version (e.g. "5.7"), found in the
+        /// launcher's install list, registered source builds or the usual
+        /// install directories.
+        #[arg(long, conflicts_with = "engine_path")]
+        engine_version: Option<String>,
         /// Path to uika.config.toml (reads [ue].engine_path as fallback).
         #[arg(long)]
         config: Option<PathBuf>,
     },
     /// Sync hand-written plugin files into ue_plugin_embed/ for crates.io packaging.
-    SyncPlugin,
+    SyncPlugin {
+        /// Don't write; exit with status 1 if ue_plugin_embed/ differs from ue_plugin/.
+        #[arg(long)]
+        check: bool,
+        /// Don't write; print the line differences of each modified file.
+        #[arg(long)]
+        diff: bool,
+    },
     /// Run the 5-step build pipeline.
     Build {
         /// Path to uika.config.toml.
@@ -49,6 +90,50 @@ enum Commands {
         /// Start from step N (1-5, default: 1).
         #[arg(long, default_value_t = 1)]
         from: u8,
+        /// Run every selected step, even if its inputs are unchanged since it last succeeded.
+        #[arg(long)]
+        force: bool,
+    },
+    /// Rebuild and hot-reload whenever the config, UHT JSON or crate sources change.
+    Watch {
+        /// Path to uika.config.toml.
+        #[arg(long, default_value = "uika.config.toml")]
+        config: PathBuf,
+        /// Polling interval in milliseconds.
+        #[arg(long, default_value_t = 500)]
+        interval_ms: u64,
+        /// Run codegen and a full build once before watching.
+        #[arg(long)]
+        initial_build: bool,
+    },
+    /// Ask the running editor to hot-reload the Rust DLL and wait for the result.
+    Reload {
+        /// Path to uika.config.toml.
+        #[arg(long, default_value = "uika.config.toml")]
+        config: PathBuf,
+        /// Seconds to wait for the editor to finish reloading.
+        #[arg(long, default_value_t = 30)]
+        timeout_secs: u64,
+    },
+    /// Run the in-engine Rust tests (uika::ue_test!) headless.
+    Test {
+        /// Path to uika.config.toml.
+        #[arg(long, default_value = "uika.config.toml")]
+        config: PathBuf,
+        /// Only run tests whose name contains this string.
+        filter: Option<String>,
+        /// Run in a headless game session (with a world) instead of the commandlet.
+        #[arg(long)]
+        game: bool,
+    },
+    /// Write VS Code rust-analyzer settings and build/watch tasks into .vscode/.
+    Ide {
+        /// Path to uika.config.toml.
+        #[arg(long, default_value = "uika.config.toml")]
+        config: PathBuf,
+        /// Overwrite settings.json / tasks.json even if they can't be parsed as plain JSON.
+        #[arg(long)]
+        force: bool,
     },
 }
 
@@ -56,30 +141,59 @@ fn main() {
     let cli = Cli::parse();
 
     match cli.command {
-        Commands::Setup { project, engine_path, config } => {
-            let engine = resolve_engine_path(engine_path, config.as_deref());
+        Commands::Setup { project, engine_path, engine_version, config } => {
+            let engine = resolve_engine_path(engine_path, engine_version.as_deref(), config.as_deref());
             setup::run_setup(&project, &engine);
         }
-        Commands::SyncPlugin => {
-            sync_plugin::run_sync();
+        Commands::SyncPlugin { check, diff } => {
+            sync_plugin::run_sync(check, diff);
+        }
+        Commands::Generate { config: config_path, incremental, dry_run, renumber, coverage } => {
+            let options = uika_codegen::GenerateOptions { incremental, dry_run, renumber, coverage };
+            uika_codegen::run_generate_with(&config_path, &options);
+        }
+        Commands::Coverage { config, output } => {
+            let options = uika_codegen::GenerateOptions { dry_run: true, coverage: Some(output), ..Default::default() };
+            uika_codegen::run_generate_with(&config, &options);
+        }
+        Commands::Build { config, step, from, force } => {
+            build_cmd::run_build(&config, step, from, force);
         }
-        Commands::Generate { config: config_path } => {
-            uika_codegen::run_generate(&config_path);
+        Commands::Watch { config, interval_ms, initial_build } => {
+            watch_cmd::run_watch(&config, interval_ms, initial_build);
         }
-        Commands::Build { config, step, from } => {
-            build_cmd::run_build(&config, step, from);
+        Commands::Reload { config, timeout_secs } => {
+            reload_cmd::run_reload(&config, timeout_secs);
+        }
+        Commands::Test { config, filter, game } => {
+            test_cmd::run_test(&config, filter.as_deref(), game);
+        }
+        Commands::Ide { config, force } => {
+            ide_cmd::run_ide(&config, force);
         }
     }
 }
 
-/// Resolve engine path from CLI flag or config file fallback.
-fn resolve_engine_path(flag: Option<PathBuf>, config_path: Option<&Path>) -> PathBuf {
+/// Resolve the engine path from the CLI flags, the config file, or the
+/// installed engines.
+fn resolve_engine_path(flag: Option<PathBuf>, version: Option<&str>, config_path: Option<&Path>) -> PathBuf {
     // 1. Explicit --engine-path flag
     if let Some(path) = flag {
         return path;
     }
 
-    // 2. Try reading from config file
+    // 2. --engine-version: that installed engine
+    if let Some(version) = version {
+        if let Some(engine) = engines::find(Some(version)) {
+            eprintln!("Using UE {} at {}", engine.full_version(), engine.path.display());
+            return engine.path;
+        }
+        eprintln!("Error: no installed UE {version} found.");
+        print_installed_engines();
+        std::process::exit(1);
+    }
+
+    // 3. Try reading from config file
     let config_path = config_path.unwrap_or_else(|| Path::new("uika.config.toml"));
     if let Ok(config_str) = std::fs::read_to_string(config_path) {
         if let Ok(config) = toml::from_str::<UikaConfig>(&config_str) {
@@ -89,7 +203,25 @@ fn resolve_engine_path(flag: Option<PathBuf>, config_path: Option<&Path>) -> Pat
         }
     }
 
-    eprintln!("Error: engine path not specified.");
+    // 4. The newest installed engine
+    if let Some(engine) = engines::find(None) {
+        eprintln!("Detected UE {} at {}", engine.full_version(), engine.path.display());
+        return engine.path;
+    }
+
+    eprintln!("Error: engine path not specified and no installed engine found.");
     eprintln!("Provide --engine-path or set [ue].engine_path in uika.config.toml.");
     std::process::exit(1);
 }
+
+fn print_installed_engines() {
+    let found = engines::discover();
+    if found.is_empty() {
+        eprintln!("No installed engines found.");
+        return;
+    }
+    eprintln!("Installed engines:");
+    for engine in found {
+        eprintln!("  {}  {}", engine.full_version(), engine.path.display());
+    }
+}
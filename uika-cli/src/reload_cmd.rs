@@ -0,0 +1,102 @@
+// Reload command: ask a running editor to hot-reload the Rust DLL.
+//
+// The plugin listens on a loopback TCP port and writes that port into
+// `uika.reload.port` next to uika.dll. A request is a `reload` line; the
+// editor answers, once the reload finished on its game thread, with
+// `ok <reload count>` or `error <message>`.
+//
+// Without a port file, or if nothing answers on the port (editor closed, or
+// an older plugin), the reload marker is touched instead: a running editor
+// polls it and reloads, but there is no answer to wait for.
+
+use std::fs;
+use std::io::{BufRead, BufReader, Write};
+use std::net::{Ipv4Addr, SocketAddr, TcpStream};
+use std::path::Path;
+use std::time::{Duration, SystemTime};
+
+use crate::build_cmd::BuildContext;
+
+/// Marker file (in the plugin's Binaries dir) whose mtime the editor polls.
+/// Must match `ReloadMarkerName` in UikaModule.cpp.
+const RELOAD_MARKER: &str = "uika.reload";
+
+/// File (next to the marker) holding the editor's reload port.
+/// Must match `ReloadPortFileName` in UikaModule.cpp.
+const RELOAD_PORT_FILE: &str = "uika.reload.port";
+
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// How a reload request went.
+pub(crate) enum ReloadOutcome {
+    /// The editor reloaded the DLL (`count` reloads since it started).
+    Reloaded { count: u32 },
+    /// The editor tried and failed, or rejected the request.
+    Failed(String),
+    /// No editor answered; the marker was touched for one that is polling.
+    MarkerTouched,
+}
+
+/// `uika reload`: request a reload and report the outcome. Exits with 1 if
+/// the editor reports a failure.
+pub fn run_reload(config_path: &Path, timeout_secs: u64) {
+    let ctx = BuildContext::load(config_path);
+    match request_reload(&ctx, Duration::from_secs(timeout_secs)) {
+        ReloadOutcome::Reloaded { count } => eprintln!("  Editor reloaded the Rust DLL (reload #{count})."),
+        ReloadOutcome::MarkerTouched => {
+            eprintln!("  No editor answered on the reload socket; touched the reload marker instead.")
+        }
+        ReloadOutcome::Failed(message) => {
+            eprintln!("  Editor reload failed: {message}");
+            std::process::exit(1);
+        }
+    }
+}
+
+/// Ask the running editor to reload, waiting up to `timeout` for it to finish.
+pub(crate) fn request_reload(ctx: &BuildContext, timeout: Duration) -> ReloadOutcome {
+    let binaries = ctx.plugin_binaries_dir();
+    let port = fs::read_to_string(binaries.join(RELOAD_PORT_FILE))
+        .ok()
+        .and_then(|s| s.trim().parse::<u16>().ok());
+    if let Some(port) = port {
+        match request_over_socket(port, timeout) {
+            Ok(outcome) => return outcome,
+            // Connection refused: a stale port file from an editor that
+            // didn't shut down cleanly.
+            Err(e) => eprintln!("  Reload socket on port {port} unavailable ({e})."),
+        }
+    }
+    touch_reload_marker(&binaries.join(RELOAD_MARKER));
+    ReloadOutcome::MarkerTouched
+}
+
+fn request_over_socket(port: u16, timeout: Duration) -> std::io::Result<ReloadOutcome> {
+    let addr = SocketAddr::from((Ipv4Addr::LOCALHOST, port));
+    let mut stream = TcpStream::connect_timeout(&addr, CONNECT_TIMEOUT)?;
+    stream.write_all(b"reload\n")?;
+    stream.set_read_timeout(Some(timeout))?;
+
+    let mut reply = String::new();
+    if let Err(e) = BufReader::new(stream).read_line(&mut reply) {
+        // Connected, so an editor has the request; it just didn't finish in time.
+        return Ok(ReloadOutcome::Failed(format!("no answer within {}s ({e})", timeout.as_secs())));
+    }
+    let reply = reply.trim();
+    Ok(match reply.split_once(' ').unwrap_or((reply, "")) {
+        ("ok", count) => ReloadOutcome::Reloaded { count: count.parse().unwrap_or(0) },
+        ("error", message) => ReloadOutcome::Failed(message.to_string()),
+        _ => ReloadOutcome::Failed(format!("unexpected reply {reply:?}")),
+    })
+}
+
+/// Write the current time into the reload marker, bumping its mtime.
+fn touch_reload_marker(marker: &Path) {
+    let stamp = SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .map(|d| d.as_millis())
+        .unwrap_or(0);
+    if let Err(e) = fs::write(marker, stamp.to_string()) {
+        eprintln!("  Warning: failed to write {}: {e}", marker.display());
+    }
+}
@@ -4,6 +4,8 @@
 use std::fs;
 use std::path::Path;
 
+use crate::engines::EngineInstall;
+
 include!(concat!(env!("OUT_DIR"), "/plugin_files.rs"));
 
 const CSPROJ_PROPS_TEMPLATE: &str = r#"<Project>
@@ -33,6 +35,8 @@ features = ["core", "engine"]
 uht_input = "generated/uht"
 rust_out = "uika-bindings/src"
 cpp_out = "{cpp_out}"
+# FuncId assignments kept stable across regenerations (commit this file).
+# func_id_map = "uika_func_ids.json"
 
 [codegen.modules]
 # UE Package name = { module = "rust_module", feature = "cargo_feature" }
@@ -44,16 +48,20 @@ SlateCore = { module = "slate_core", feature = "slate" }
 Slate = { module = "slate", feature = "slate" }
 UMG = { module = "umg", feature = "umg" }
 Niagara = { module = "niagara", feature = "niagara" }
+GameplayTags = { module = "gameplay_tags", feature = "gameplay-tags" }
 GameplayAbilities = { module = "gameplay_abilities", feature = "gameplay-abilities" }
 LevelSequence = { module = "level_sequence", feature = "level-sequence" }
 CinematicCamera = { module = "cinematic_camera", feature = "cinematic" }
 MovieScene = { module = "movie_scene", feature = "movie" }
 MovieSceneTracks = { module = "movie_scene_tracks", feature = "movie" }
 
+# Exact names, globs ("*Library") or "re:" regexes; functions and properties
+# use "Owner.Member". Preview with `uika generate --dry-run`.
 [codegen.blocklist]
 classes = []
 structs = []
 functions = []
+properties = []
 "##;
 
 /// Stub UikaFuncIds.h — empty namespace with FUNC_COUNT = 0.
@@ -88,6 +96,18 @@ void** UikaGetFuncTable() {
 uint32_t UikaGetFuncCount() {
     return 0;
 }
+
+const char* UikaGetBindingEngineVersion() {
+    return \"\";
+}
+
+uint64_t UikaGetBindingConfigHash() {
+    return 0;
+}
+
+const uint64_t* UikaGetBindingFuncSigs() {
+    return nullptr;
+}
 ";
 
 /// Default module_deps.txt content for initial build.
@@ -106,9 +126,10 @@ pub fn run_setup(project_path: &Path, engine_path: &Path) {
     }
 
     // Validate engine path
-    if !engine_path.join("Engine").exists() {
+    let engine = EngineInstall::at(engine_path);
+    if engine.is_none() {
         eprintln!(
-            "Warning: {}/Engine/ not found. Make sure the engine path is correct.",
+            "Warning: {}/Engine/Build/Build.version not found. Make sure the engine path is correct.",
             engine_path.display()
         );
     }
@@ -149,9 +170,16 @@ pub fn run_setup(project_path: &Path, engine_path: &Path) {
     fs::write(&props_path, &props_content)
         .unwrap_or_else(|e| panic!("Failed to write {}: {e}", props_path.display()));
 
+    if let Some(engine) = &engine {
+        adapt_plugin(&plugins_dir, engine);
+    }
+
     eprintln!("  Wrote {} plugin files to {}", written, plugins_dir.display());
     eprintln!("  Generated {}", props_path.display());
-    eprintln!("  Engine path: {}", engine_path_normalized);
+    match &engine {
+        Some(engine) => eprintln!("  Engine: UE {} at {}", engine.full_version(), engine_path_normalized),
+        None => eprintln!("  Engine path: {}", engine_path_normalized),
+    }
 
     // --- Step 2: Generate uika.config.toml in CWD ---
     generate_config(project_path, &engine_path_normalized);
@@ -165,6 +193,44 @@ pub fn run_setup(project_path: &Path, engine_path: &Path) {
     eprintln!("uika setup: done!");
 }
 
+/// Fit the extracted plugin files to `engine`: stamp the .uplugin files'
+/// `EngineVersion` and pick the .NET version the engine's UBT plugins build
+/// against (.NET 6 before UE 5.4, .NET 8 since). The module Build.cs files
+/// have no version-specific settings.
+fn adapt_plugin(plugins_dir: &Path, engine: &EngineInstall) {
+    if engine.major < 5 {
+        eprintln!("  Warning: UE {} is not supported; Uika needs UE 5.", engine.full_version());
+    }
+
+    for uplugin in ["Uika/Uika.uplugin", "UikaGenerator/UikaGenerator.uplugin"] {
+        let path = plugins_dir.join(uplugin);
+        let Ok(text) = fs::read_to_string(&path) else { continue };
+        let line = format!("    \"EngineVersion\": \"{}\",", engine.full_version());
+        let mut lines: Vec<String> = text
+            .lines()
+            .filter(|l| !l.trim_start().starts_with("\"EngineVersion\""))
+            .map(str::to_string)
+            .collect();
+        let at = lines.iter().position(|l| l.trim_start().starts_with("\"VersionName\"")).map_or(1, |i| i + 1);
+        lines.insert(at, line);
+        fs::write(&path, lines.join("\n") + "\n")
+            .unwrap_or_else(|e| panic!("Failed to write {}: {e}", path.display()));
+    }
+
+    let csproj = plugins_dir.join("UikaGenerator/Source/UikaExporter/UikaExporter.ubtplugin.csproj");
+    if let Ok(text) = fs::read_to_string(&csproj) {
+        let framework = if (engine.major, engine.minor) < (5, 4) { "net6.0" } else { "net8.0" };
+        let adapted = text.replace(
+            "<TargetFramework>net8.0</TargetFramework>",
+            &format!("<TargetFramework>{framework}</TargetFramework>"),
+        );
+        if adapted != text {
+            fs::write(&csproj, adapted)
+                .unwrap_or_else(|e| panic!("Failed to write {}: {e}", csproj.display()));
+        }
+    }
+}
+
 /// Generate a starter `uika.config.toml` in the current working directory (if it doesn't exist).
 fn generate_config(project_path: &Path, engine_path: &str) {
     let config_path = Path::new("uika.config.toml");
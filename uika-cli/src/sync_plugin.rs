@@ -1,5 +1,9 @@
 // Sync command: copies hand-written UE plugin files from workspace ue_plugin/
 // into uika-cli/ue_plugin_embed/ for crates.io packaging.
+//
+// `--check` and `--diff` compare instead of copying: `--check` lists the
+// files that differ and exits with status 1 if any do (for CI and pre-publish
+// checks), `--diff` prints the line differences.
 
 use std::collections::HashSet;
 use std::fs;
@@ -8,7 +12,19 @@ use std::path::{Path, PathBuf};
 /// Directories to skip when walking plugin sources.
 const EXCLUDED_DIRS: &[&str] = &["Generated", "Binaries", "Intermediate", "obj"];
 
-pub fn run_sync() {
+/// A difference between the source plugin and the embedded copy.
+enum Drift {
+    /// In ue_plugin/ but not in ue_plugin_embed/.
+    Missing,
+    /// In both, with different contents.
+    Modified,
+    /// In ue_plugin_embed/ but no longer in ue_plugin/.
+    Stale,
+}
+
+/// Sync the embedded plugin, or with `check`/`diff` only report how it
+/// differs from the source (see the module comment).
+pub fn run_sync(check: bool, diff: bool) {
     let manifest_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
     let source_root = manifest_dir.join("..").join("ue_plugin");
     let dest_root = manifest_dir.join("ue_plugin_embed");
@@ -26,6 +42,15 @@ pub fn run_sync() {
     let mut files: Vec<(String, PathBuf)> = Vec::new();
     collect_files(&source_root, &source_root, &mut files);
 
+    if check || diff {
+        let drift = compare(&files, &dest_root);
+        report_drift(&drift, &files, &dest_root, diff);
+        if check && !drift.is_empty() {
+            std::process::exit(1);
+        }
+        return;
+    }
+
     // Track which destination files we write (for stale cleanup)
     let mut written_paths: HashSet<PathBuf> = HashSet::new();
 
@@ -74,6 +99,135 @@ pub fn run_sync() {
     eprintln!("uika sync-plugin: done! ({} files)", files.len());
 }
 
+/// Every file that differs between the source files and `dest_root`, by
+/// relative path.
+fn compare(files: &[(String, PathBuf)], dest_root: &Path) -> Vec<(String, Drift)> {
+    let mut drift = Vec::new();
+    let mut expected: HashSet<PathBuf> = HashSet::new();
+    for (rel_path, src_path) in files {
+        let dest_path = dest_root.join(rel_path);
+        let src_contents = fs::read(src_path)
+            .unwrap_or_else(|e| panic!("Failed to read {}: {e}", src_path.display()));
+        match fs::read(&dest_path) {
+            Ok(existing) if existing == src_contents => {}
+            Ok(_) => drift.push((rel_path.clone(), Drift::Modified)),
+            Err(_) => drift.push((rel_path.clone(), Drift::Missing)),
+        }
+        expected.insert(dest_path);
+    }
+
+    let mut embedded: Vec<PathBuf> = Vec::new();
+    collect_all_files(dest_root, &mut embedded);
+    for path in embedded {
+        if !expected.contains(&path) {
+            let rel = path.strip_prefix(dest_root).unwrap();
+            drift.push((rel.to_string_lossy().replace('\\', "/"), Drift::Stale));
+        }
+    }
+    drift.sort_by(|a, b| a.0.cmp(&b.0));
+    drift
+}
+
+fn report_drift(drift: &[(String, Drift)], files: &[(String, PathBuf)], dest_root: &Path, diff: bool) {
+    if drift.is_empty() {
+        eprintln!("uika sync-plugin: ue_plugin_embed/ is up to date ({} files)", files.len());
+        return;
+    }
+    for (rel_path, kind) in drift {
+        let label = match kind {
+            Drift::Missing => "missing",
+            Drift::Modified => "modified",
+            Drift::Stale => "stale",
+        };
+        eprintln!("  {label}: {rel_path}");
+        if diff && matches!(kind, Drift::Modified) {
+            let src_path = &files.iter().find(|(rel, _)| rel == rel_path).unwrap().1;
+            print_diff(rel_path, src_path, &dest_root.join(rel_path));
+        }
+    }
+    eprintln!(
+        "uika sync-plugin: {} file(s) differ; run `uika sync-plugin` to update ue_plugin_embed/",
+        drift.len()
+    );
+}
+
+/// Print the changed lines of one file, embedded copy (`-`) against the
+/// source (`+`), in hunks headed by their 1-based start lines.
+fn print_diff(rel_path: &str, src_path: &Path, dest_path: &Path) {
+    let (Ok(new), Ok(old)) = (fs::read_to_string(src_path), fs::read_to_string(dest_path)) else {
+        println!("Binary files ue_plugin_embed/{rel_path} and ue_plugin/{rel_path} differ");
+        return;
+    };
+    println!("--- ue_plugin_embed/{rel_path}");
+    println!("+++ ue_plugin/{rel_path}");
+    let old: Vec<&str> = old.lines().collect();
+    let new: Vec<&str> = new.lines().collect();
+    for hunk in diff_lines(&old, &new) {
+        println!("@@ -{} +{} @@", hunk.old_start + 1, hunk.new_start + 1);
+        for line in &hunk.removed {
+            println!("-{line}");
+        }
+        for line in &hunk.added {
+            println!("+{line}");
+        }
+    }
+}
+
+/// A run of changed lines. Starts are 0-based line indices.
+struct Hunk<'a> {
+    old_start: usize,
+    new_start: usize,
+    removed: Vec<&'a str>,
+    added: Vec<&'a str>,
+}
+
+/// The changed runs between `old` and `new`, from a longest common
+/// subsequence of the lines between their common prefix and suffix.
+fn diff_lines<'a>(old: &[&'a str], new: &[&'a str]) -> Vec<Hunk<'a>> {
+    let prefix = old.iter().zip(new).take_while(|(a, b)| a == b).count();
+    let suffix = old[prefix..]
+        .iter()
+        .rev()
+        .zip(new[prefix..].iter().rev())
+        .take_while(|(a, b)| a == b)
+        .count();
+    let old = &old[prefix..old.len() - suffix];
+    let new = &new[prefix..new.len() - suffix];
+
+    // lcs[i][j]: LCS length of old[i..] and new[j..].
+    let mut lcs = vec![vec![0u32; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lcs[i][j] = if old[i] == new[j] { lcs[i + 1][j + 1] + 1 } else { lcs[i + 1][j].max(lcs[i][j + 1]) };
+        }
+    }
+
+    let mut hunks: Vec<Hunk<'a>> = Vec::new();
+    let mut open = false;
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() || j < new.len() {
+        if i < old.len() && j < new.len() && old[i] == new[j] {
+            open = false;
+            i += 1;
+            j += 1;
+            continue;
+        }
+        if !open {
+            hunks.push(Hunk { old_start: prefix + i, new_start: prefix + j, removed: Vec::new(), added: Vec::new() });
+            open = true;
+        }
+        let hunk = hunks.last_mut().unwrap();
+        if j < new.len() && (i == old.len() || lcs[i][j + 1] >= lcs[i + 1][j]) {
+            hunk.added.push(new[j]);
+            j += 1;
+        } else {
+            hunk.removed.push(old[i]);
+            i += 1;
+        }
+    }
+    hunks
+}
+
 fn collect_files(root: &Path, dir: &Path, out: &mut Vec<(String, PathBuf)>) {
     let entries = match fs::read_dir(dir) {
         Ok(e) => e,
@@ -0,0 +1,113 @@
+// Test command: run the in-engine Rust tests headless and report the result.
+//
+// Launches UnrealEditor-Cmd with the UikaTestRunner commandlet (or, with
+// --game, a headless game session running `Uika.RunTests`), echoes the
+// LogUikaTest lines written by uika_runtime::testing, and exits nonzero if
+// any test failed or the run never reported a result.
+
+use std::io::{BufRead, BufReader};
+use std::path::Path;
+use std::process::{Command, Stdio};
+
+use crate::build_cmd::{canonical_no_prefix, BuildContext};
+
+/// Log category the runtime writes test results to (`testing::LOG_CATEGORY`).
+const TEST_CATEGORY: &str = "LogUikaTest:";
+
+/// Run the in-engine tests and exit with 0 on success, 1 on failure.
+///
+/// `filter` selects tests whose name contains it. `game` runs the tests in a
+/// headless `-game` session (with a world) instead of the commandlet.
+pub fn run_test(config_path: &Path, filter: Option<&str>, game: bool) {
+    let ctx = BuildContext::load(config_path);
+
+    let editor_cmd = ctx
+        .engine_path
+        .join("Engine/Binaries/Win64/UnrealEditor-Cmd.exe");
+    if !editor_cmd.exists() {
+        eprintln!("Error: UnrealEditor-Cmd.exe not found at {}", editor_cmd.display());
+        std::process::exit(1);
+    }
+    let uproject = canonical_no_prefix(&ctx.uproject_path());
+
+    let mut cmd = Command::new(&editor_cmd);
+    cmd.arg(&uproject);
+    if game {
+        let run = match filter {
+            Some(f) => format!("Uika.RunTests {f}"),
+            None => "Uika.RunTests".to_string(),
+        };
+        cmd.arg("-game");
+        // UE re-parses its raw command line and needs the value itself
+        // quoted; std's argument quoting would wrap the whole token instead.
+        let exec_cmds = format!("-ExecCmds=\"{run}; Quit\"");
+        #[cfg(windows)]
+        {
+            use std::os::windows::process::CommandExt;
+            cmd.raw_arg(&exec_cmds);
+        }
+        #[cfg(not(windows))]
+        cmd.arg(&exec_cmds);
+        cmd.arg("-nosound");
+    } else {
+        cmd.arg("-run=UikaTestRunner");
+        if let Some(f) = filter {
+            cmd.arg(format!("-Filter={f}"));
+        }
+    }
+    cmd.args(["-unattended", "-nullrhi", "-nosplash", "-stdout", "-FullStdOutLogOutput"]);
+
+    eprintln!("  $ {} {}", editor_cmd.display(), uproject.display());
+    let mut child = cmd
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap_or_else(|e| panic!("Failed to run {}: {e}", editor_cmd.display()));
+
+    let mut passed = 0u32;
+    let mut failed: Vec<String> = Vec::new();
+    let mut reported = false;
+    let stdout = child.stdout.take().expect("piped stdout");
+    for line in BufReader::new(stdout).lines().map_while(Result::ok) {
+        let Some(result) = parse_result_line(&line) else { continue };
+        eprintln!("  {result}");
+        if result.starts_with("PASS ") {
+            passed += 1;
+        } else if let Some(rest) = result.strip_prefix("FAIL ") {
+            failed.push(rest.to_string());
+        } else if result.starts_with("RESULT ") {
+            reported = true;
+        }
+    }
+    let status = child
+        .wait()
+        .unwrap_or_else(|e| panic!("Failed to wait for {}: {e}", editor_cmd.display()));
+
+    eprintln!("\n{}", "=".repeat(60));
+    eprintln!("  {passed} passed, {} failed", failed.len());
+    for f in &failed {
+        eprintln!("    FAIL {f}");
+    }
+    if !reported {
+        eprintln!("  Error: the engine exited before reporting a result.");
+        eprintln!("  Check that the Rust DLL is built and loaded (`uika build --from 4`).");
+    }
+    eprintln!("{}", "=".repeat(60));
+
+    // The commandlet already exits nonzero on failure; -game sessions don't,
+    // so the scraped results decide.
+    if !failed.is_empty() || !reported || !status.success() {
+        std::process::exit(1);
+    }
+}
+
+/// Extract the message of a `LogUikaTest` line, with UE's timestamp,
+/// category and verbosity prefixes stripped.
+fn parse_result_line(line: &str) -> Option<&str> {
+    let idx = line.find(TEST_CATEGORY)?;
+    let rest = line[idx + TEST_CATEGORY.len()..].trim_start();
+    let rest = ["Display:", "Error:", "Warning:"]
+        .iter()
+        .find_map(|v| rest.strip_prefix(v))
+        .unwrap_or(rest);
+    Some(rest.trim())
+}
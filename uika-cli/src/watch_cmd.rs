@@ -0,0 +1,179 @@
+// Watch command: rebuild and hot-reload on change.
+//
+// Polls file modification times (no platform notify API needed) for three
+// inputs: uika.config.toml, the UHT JSON directory, and the user crate's
+// sources. A config or JSON change re-runs codegen before the cargo build;
+// a source change only rebuilds. After a successful build the DLL is copied
+// into the plugin and the running editor is asked to reload it (see
+// reload_cmd.rs).
+
+use std::collections::HashMap;
+use std::fs;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, Instant, SystemTime};
+
+use crate::build_cmd::BuildContext;
+use crate::reload_cmd::{self, ReloadOutcome};
+
+/// How long to wait for the editor to finish a reload.
+const RELOAD_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Quiet period after the last detected change before rebuilding, so a
+/// multi-file save or a UHT export is picked up as one change.
+const SETTLE: Duration = Duration::from_millis(300);
+
+/// Modification times of every watched file.
+type Snapshot = HashMap<PathBuf, SystemTime>;
+
+/// Run the watch loop until interrupted.
+///
+/// `interval_ms` is the polling interval. When `initial_build` is set, a full
+/// codegen + build runs once before watching starts.
+pub fn run_watch(config_path: &Path, interval_ms: u64, initial_build: bool) {
+    let ctx = BuildContext::load(config_path);
+    let interval = Duration::from_millis(interval_ms.max(50));
+    let source_root = ctx.crate_path.clone().unwrap_or_else(|| ctx.config_dir.clone());
+
+    eprintln!("\n{}", "=".repeat(60));
+    eprintln!("  Uika watch");
+    eprintln!("  config:  {}", ctx.config_path.display());
+    eprintln!("  UHT:     {}", ctx.uht_input.display());
+    eprintln!("  sources: {}", source_root.display());
+    eprintln!("{}\n", "=".repeat(60));
+
+    if initial_build {
+        rebuild(&ctx, true);
+    }
+
+    let mut codegen_inputs = snapshot_codegen_inputs(&ctx);
+    let mut sources = snapshot_sources(&ctx, &source_root);
+    eprintln!("Watching {} files (Ctrl+C to stop)...", codegen_inputs.len() + sources.len());
+
+    loop {
+        thread::sleep(interval);
+
+        let new_codegen_inputs = snapshot_codegen_inputs(&ctx);
+        let new_sources = snapshot_sources(&ctx, &source_root);
+        let codegen_changed = changed_paths(&codegen_inputs, &new_codegen_inputs);
+        let sources_changed = changed_paths(&sources, &new_sources);
+        if codegen_changed.is_empty() && sources_changed.is_empty() {
+            continue;
+        }
+
+        // Let in-progress writes finish before building.
+        thread::sleep(SETTLE);
+        for path in codegen_changed.iter().chain(&sources_changed) {
+            eprintln!("  changed: {}", path.display());
+        }
+        rebuild(&ctx, !codegen_changed.is_empty());
+
+        // Re-snapshot after the build: codegen rewrites files under the
+        // source root, and those must not trigger another rebuild.
+        codegen_inputs = snapshot_codegen_inputs(&ctx);
+        sources = snapshot_sources(&ctx, &source_root);
+        eprintln!("Watching for changes...");
+    }
+}
+
+/// Run codegen (if requested), cargo build and the DLL copy, then ask the
+/// editor to reload. Failures are reported and the watcher keeps running.
+fn rebuild(ctx: &BuildContext, codegen: bool) {
+    let start = Instant::now();
+
+    if codegen {
+        eprintln!("{}", "-".repeat(60));
+        eprintln!("  Codegen (JSON -> Rust + C++)");
+        // Codegen reports bad input by panicking; don't let a half-edited
+        // config take the watcher down with it. Incremental mode keeps
+        // untouched outputs' mtimes so cargo only recompiles what changed.
+        let options = uika_codegen::GenerateOptions { incremental: true, ..Default::default() };
+        let codegen = || uika_codegen::run_generate_with(&ctx.config_path, &options);
+        if panic::catch_unwind(AssertUnwindSafe(codegen)).is_err() {
+            eprintln!("  Codegen failed; waiting for the next change.\n");
+            return;
+        }
+        eprintln!(
+            "  Note: if generated C++ wrappers changed, run `uika build --step 3` \
+             and restart the editor."
+        );
+    }
+
+    eprintln!("{}", "-".repeat(60));
+    eprintln!("  cargo build --release (cdylib)");
+    if ctx.cargo_build().is_err() {
+        eprintln!("  Build failed; waiting for the next change.\n");
+        return;
+    }
+
+    if let Err(message) = ctx.copy_dll() {
+        eprintln!("  {message}");
+        eprintln!("  Copy failed; waiting for the next change.\n");
+        return;
+    }
+    let elapsed = start.elapsed().as_secs_f64();
+    match reload_cmd::request_reload(ctx, RELOAD_TIMEOUT) {
+        ReloadOutcome::Reloaded { .. } => eprintln!("  Rebuilt in {elapsed:.1}s; editor reloaded.\n"),
+        ReloadOutcome::MarkerTouched => eprintln!("  Rebuilt in {elapsed:.1}s; editor reload requested.\n"),
+        ReloadOutcome::Failed(message) => eprintln!("  Rebuilt in {elapsed:.1}s; editor reload failed: {message}\n"),
+    }
+}
+
+/// The config file and the UHT JSON files.
+fn snapshot_codegen_inputs(ctx: &BuildContext) -> Snapshot {
+    let mut snap = Snapshot::new();
+    record(&mut snap, &ctx.config_path);
+    if let Ok(entries) = fs::read_dir(&ctx.uht_input) {
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if path.extension().is_some_and(|ext| ext == "json") {
+                record(&mut snap, &path);
+            }
+        }
+    }
+    snap
+}
+
+/// Every `.rs` and `Cargo.toml` file under `root`, skipping build output,
+/// hidden directories and the codegen output directories.
+fn snapshot_sources(ctx: &BuildContext, root: &Path) -> Snapshot {
+    let skip = [ctx.rust_out.as_path(), ctx.cpp_out.as_path(), ctx.uht_input.as_path()];
+    let mut snap = Snapshot::new();
+    let mut stack = vec![root.to_path_buf()];
+    while let Some(dir) = stack.pop() {
+        let Ok(entries) = fs::read_dir(&dir) else { continue };
+        for entry in entries.flatten() {
+            let path = entry.path();
+            let name = entry.file_name();
+            let name = name.to_string_lossy();
+            if path.is_dir() {
+                if name.starts_with('.') || name == "target" || skip.contains(&path.as_path()) {
+                    continue;
+                }
+                stack.push(path);
+            } else if path.extension().is_some_and(|ext| ext == "rs") || name == "Cargo.toml" {
+                record(&mut snap, &path);
+            }
+        }
+    }
+    snap
+}
+
+fn record(snap: &mut Snapshot, path: &Path) {
+    if let Ok(mtime) = fs::metadata(path).and_then(|m| m.modified()) {
+        snap.insert(path.to_path_buf(), mtime);
+    }
+}
+
+/// Paths added, removed or modified between two snapshots.
+fn changed_paths(old: &Snapshot, new: &Snapshot) -> Vec<PathBuf> {
+    let mut changed: Vec<PathBuf> = new
+        .iter()
+        .filter(|(path, mtime)| old.get(*path) != Some(*mtime))
+        .map(|(path, _)| path.clone())
+        .collect();
+    changed.extend(old.keys().filter(|path| !new.contains_key(*path)).cloned());
+    changed.sort();
+    changed
+}
@@ -50,7 +50,41 @@ pub struct CodegenConfig {
     pub features: Vec<String>,
     pub paths: CodegenPaths,
     pub modules: HashMap<String, ModuleMapping>,
+    #[serde(default)]
     pub blocklist: Blocklist,
+    #[serde(default)]
+    pub allowlist: Allowlist,
+    /// Additional UHT exports (`[[codegen.extra_sources]]`), e.g. from
+    /// marketplace plugins.
+    #[serde(default)]
+    pub extra_sources: Vec<ExtraSource>,
+    /// Also generate `unsafe` `view_*` accessors returning a zero-copy
+    /// `uika_runtime::StructView` for struct properties.
+    #[serde(default)]
+    pub struct_views: bool,
+}
+
+impl CodegenConfig {
+    /// Fold every extra source's package mapping into `modules`, so the rest
+    /// of the pipeline (features, module_deps.txt) treats them like engine
+    /// modules. Panics if a package is mapped twice to different modules.
+    pub fn merge_extra_modules(&mut self) {
+        for source in &self.extra_sources {
+            for (pkg, mapping) in &source.modules {
+                if let Some(existing) = self.modules.get(pkg) {
+                    if existing.module != mapping.module || existing.feature != mapping.feature {
+                        panic!(
+                            "extra source '{}' maps package '{pkg}' to module '{}', \
+                             but it is already mapped to '{}'",
+                            source.name, mapping.module, existing.module
+                        );
+                    }
+                    continue;
+                }
+                self.modules.insert(pkg.clone(), mapping.clone());
+            }
+        }
+    }
 }
 
 #[derive(Deserialize)]
@@ -58,31 +92,68 @@ pub struct CodegenPaths {
     pub uht_input: String,
     pub rust_out: String,
     pub cpp_out: String,
+    /// Persisted FuncId assignments, see [`crate::id_map`].
+    #[serde(default = "default_func_id_map")]
+    pub func_id_map: String,
 }
 
+fn default_func_id_map() -> String {
+    crate::id_map::DEFAULT_FUNC_ID_MAP.to_string()
+}
+
+/// A UHT export generated outside the engine module list, e.g. for a
+/// third-party plugin. Its Rust modules live in the bindings crate next to the
+/// engine modules (behind their own features); its C++ wrappers are written
+/// into a subdirectory of `[codegen.paths] cpp_out`.
 #[derive(Deserialize)]
+pub struct ExtraSource {
+    /// Label used in log output and as the default `cpp_subdir`.
+    pub name: String,
+    /// Directory containing this export's uika_classes/structs/enums.json
+    /// (relative to the config file).
+    pub uht_input: String,
+    /// Wrapper output directory, relative to `[codegen.paths] cpp_out` so
+    /// UBT still compiles it as part of the Uika module. Defaults to `name`.
+    #[serde(default)]
+    pub cpp_subdir: Option<String>,
+    /// UE package name → Rust module / Cargo feature, like `[codegen.modules]`.
+    pub modules: HashMap<String, ModuleMapping>,
+}
+
+impl ExtraSource {
+    pub fn cpp_subdir(&self) -> &str {
+        self.cpp_subdir.as_deref().unwrap_or(&self.name)
+    }
+}
+
+#[derive(Deserialize, Clone)]
 pub struct ModuleMapping {
     pub module: String,
     pub feature: String,
 }
 
-#[derive(Deserialize)]
-pub struct Blocklist {
+/// Per-kind name lists for `[codegen.blocklist]` and `[codegen.allowlist]`.
+///
+/// Entries are exact names, globs (`*Library`) or `re:`-prefixed regexes,
+/// see [`crate::pattern`]. Functions and properties are matched against
+/// their qualified "Owner.Member" name.
+#[derive(Deserialize, Default)]
+pub struct FilterList {
+    #[serde(default)]
     pub classes: Vec<String>,
+    #[serde(default)]
     pub structs: Vec<String>,
-    /// Function blocklist in "Class.Function" format.
+    /// Function patterns in "Class.Function" format.
+    #[serde(default)]
     pub functions: Vec<String>,
+    /// Property patterns in "Class.Property" / "Struct.Property" format.
+    #[serde(default)]
+    pub properties: Vec<String>,
 }
 
-impl Blocklist {
-    /// Parse function blocklist entries into (class, function) tuples.
-    pub fn function_tuples(&self) -> Vec<(String, String)> {
-        self.functions
-            .iter()
-            .filter_map(|entry| {
-                let (class, func) = entry.split_once('.')?;
-                Some((class.to_string(), func.to_string()))
-            })
-            .collect()
-    }
-}
+/// Items matching any entry are excluded.
+pub type Blocklist = FilterList;
+
+/// When a kind's list is non-empty, only items matching one of its entries
+/// are kept; an empty list keeps everything.
+pub type Allowlist = FilterList;
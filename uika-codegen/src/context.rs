@@ -20,6 +20,9 @@ pub struct CodegenContext {
     pub module_to_feature: HashMap<String, String>,
     /// Set of enabled Rust module names (derived from features).
     pub enabled_modules: HashSet<String>,
+    /// Rust module name → C++ wrapper subdirectory (relative to `cpp_out`),
+    /// for modules that come from `[[codegen.extra_sources]]`.
+    pub module_cpp_subdir: HashMap<String, String>,
 
     /// Classes grouped by module name. Sorted for determinism.
     pub module_classes: BTreeMap<String, Vec<ClassInfo>>,
@@ -28,12 +31,72 @@ pub struct CodegenContext {
     /// Enums grouped by module name.
     pub module_enums: BTreeMap<String, Vec<EnumInfo>>,
 
-    /// All exportable functions sorted by (module, class, func) for FuncId assignment.
+    /// All exportable functions sorted by (module, class, func). FuncIds come
+    /// from the persisted map (see [`crate::id_map`]) and are not in order.
     pub func_table: Vec<FuncEntry>,
+    /// Size of the function table. Larger than `func_table.len()` when the
+    /// map holds retired ids.
+    pub func_count: u32,
 
     /// Module name → set of other modules whose types it references.
     /// Drives feature dependency emission in the generated `uika-bindings/Cargo.toml`.
     pub module_deps: BTreeMap<String, std::collections::BTreeSet<String>>,
+
+    /// Fingerprint of this run, embedded in the generated Rust and C++ so
+    /// `uika::init` can detect a DLL/plugin mismatch. Set after the
+    /// function table is built.
+    pub binding_stamp: BindingStamp,
+
+    /// `[codegen] struct_views`: generate `unsafe` `view_*` accessors for
+    /// struct properties.
+    pub struct_views: bool,
+}
+
+/// Identifies the export, config and function table a codegen run used.
+#[derive(Clone, Default)]
+pub struct BindingStamp {
+    /// Engine version recorded by the UHT exporter ("" if unknown).
+    pub engine_version: String,
+    /// Hash of the `[codegen]` config section.
+    pub config_hash: u64,
+    /// Signature hash of each FuncId's function (owner, name and
+    /// parameters), indexed by id. 0 marks an unused id.
+    pub func_sigs: Vec<u64>,
+}
+
+impl BindingStamp {
+    pub fn compute(
+        engine_version: &str,
+        codegen_config: &str,
+        func_table: &[FuncEntry],
+        func_count: u32,
+    ) -> Self {
+        use std::hash::Hasher;
+        use crate::incremental::StableHasher;
+
+        let mut config = StableHasher::default();
+        config.str(codegen_config);
+
+        let mut func_sigs = vec![0u64; func_count as usize];
+        for entry in func_table {
+            let mut sig = StableHasher::default();
+            sig.str(&entry.class_name).str(&entry.func_name);
+            for p in &entry.func.params {
+                sig.str(&p.name).str(&p.prop_type);
+                sig.write_u64(p.prop_flags);
+                for name in [&p.class_name, &p.struct_name, &p.enum_name, &p.interface_name] {
+                    sig.str(name.as_deref().unwrap_or(""));
+                }
+            }
+            func_sigs[entry.func_id as usize] = sig.finish().max(1);
+        }
+
+        BindingStamp {
+            engine_version: engine_version.to_string(),
+            config_hash: config.finish(),
+            func_sigs,
+        }
+    }
 }
 
 /// An entry in the global function table.
@@ -79,6 +142,13 @@ impl CodegenContext {
             }
         }
 
+        let mut module_cpp_subdir = HashMap::new();
+        for source in &config.extra_sources {
+            for mapping in source.modules.values() {
+                module_cpp_subdir.insert(mapping.module.clone(), source.cpp_subdir().to_string());
+            }
+        }
+
         // Auto-derive module names for packages not in config
         let mut all_packages = HashSet::new();
         for c in &classes {
@@ -151,11 +221,15 @@ impl CodegenContext {
             package_to_module,
             module_to_feature: module_to_feature_map,
             enabled_modules,
+            module_cpp_subdir,
             module_classes,
             module_structs,
             module_enums,
             func_table: Vec::new(),
+            func_count: 0,
             module_deps: BTreeMap::new(),
+            binding_stamp: BindingStamp::default(),
+            struct_views: config.struct_views,
         };
         ctx.module_deps = ctx.compute_module_deps();
         ctx
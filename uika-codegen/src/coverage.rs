@@ -0,0 +1,265 @@
+// Binding coverage report: what fraction of each module's reflected API made
+// it into the bindings, and why the rest didn't.
+//
+// Built from the items left in the context after filtering and the function
+// table, plus the FilterReport of everything left out (including what Rust
+// generation drops from those items, see `rust_gen::skipped`). Counts cover classes
+// and structs of the enabled modules, and the functions and properties of
+// the classes and structs that are bound (members of an excluded type are not
+// counted separately). Enums are always bound and not reported.
+//
+// Written as JSON by `uika coverage` and `uika generate --coverage <path>`:
+//
+// {
+//   "version": 1,
+//   "summary": { "functions": { "bound": 900, "total": 1000, "percent": 90.0 }, ...,
+//                "categories": { "unsupported_type": 70, "blocklist": 30, ... } },
+//   "modules": { "engine": { same as "summary" }, ... },
+//   "skipped": [ { "kind": "function", "module": "engine", "name": "Actor.Foo",
+//                  "stage": "filter", "category": "unsupported_type", "reason": "...",
+//                  "offending": "Delegate: DelegateProperty" }, ... ]
+// }
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use serde_json::{json, Value};
+
+use crate::context::CodegenContext;
+use crate::filter::{FilterReport, SkipCategory, SkipStage};
+
+/// Bump when the JSON layout changes.
+const REPORT_VERSION: u32 = 1;
+
+/// Item kinds in report order.
+const KINDS: [&str; 4] = ["class", "struct", "function", "property"];
+
+/// Bound and total counts of one kind of item.
+#[derive(Clone, Copy, Default, PartialEq, Debug)]
+pub struct Count {
+    pub bound: usize,
+    pub total: usize,
+}
+
+impl Count {
+    /// Percentage bound; 100 when there is nothing to bind.
+    pub fn percent(&self) -> f64 {
+        if self.total == 0 {
+            100.0
+        } else {
+            self.bound as f64 * 100.0 / self.total as f64
+        }
+    }
+
+    fn to_json(self) -> Value {
+        json!({
+            "bound": self.bound,
+            "total": self.total,
+            "percent": (self.percent() * 10.0).round() / 10.0,
+        })
+    }
+}
+
+/// Counts of one module (or of all modules).
+#[derive(Clone, Default, Debug)]
+pub struct ModuleCoverage {
+    /// Per kind, in [`KINDS`] order.
+    pub counts: [Count; 4],
+    /// Number of skipped items per category.
+    pub categories: BTreeMap<SkipCategory, usize>,
+}
+
+impl ModuleCoverage {
+    /// Counts for `kind` ("class", "struct", "function" or "property").
+    pub fn count(&self, kind: &str) -> Count {
+        KINDS.iter().position(|k| *k == kind).map(|i| self.counts[i]).unwrap_or_default()
+    }
+
+    fn add(&mut self, kind: &str, bound: bool) {
+        if let Some(i) = KINDS.iter().position(|k| *k == kind) {
+            self.counts[i].total += 1;
+            self.counts[i].bound += usize::from(bound);
+        }
+    }
+
+    /// Count an item already counted as bound as skipped after all.
+    fn demote(&mut self, kind: &str) {
+        if let Some(i) = KINDS.iter().position(|k| *k == kind) {
+            self.counts[i].bound = self.counts[i].bound.saturating_sub(1);
+        }
+    }
+
+    fn to_json(&self) -> Value {
+        let mut doc: serde_json::Map<String, Value> = KINDS
+            .iter()
+            .zip(&self.counts)
+            .map(|(kind, count)| (plural(kind).to_string(), count.to_json()))
+            .collect();
+        doc.insert("categories".into(), categories_json(&self.categories));
+        Value::Object(doc)
+    }
+}
+
+/// Coverage of a codegen run.
+pub struct CoverageReport<'a> {
+    pub modules: BTreeMap<String, ModuleCoverage>,
+    pub summary: ModuleCoverage,
+    report: &'a FilterReport,
+}
+
+impl<'a> CoverageReport<'a> {
+    /// Coverage of `ctx` after filtering and building the function table.
+    pub fn compute(ctx: &CodegenContext, report: &'a FilterReport) -> Self {
+        let mut bound: Vec<(&str, &str)> = Vec::new();
+        for (module, classes) in &ctx.module_classes {
+            for class in classes {
+                bound.push(("class", module));
+                bound.extend(class.props.iter().map(|_| ("property", module.as_str())));
+            }
+        }
+        for (module, structs) in &ctx.module_structs {
+            for s in structs {
+                bound.push(("struct", module));
+                bound.extend(s.props.iter().map(|_| ("property", module.as_str())));
+            }
+        }
+        bound.extend(ctx.func_table.iter().map(|entry| ("function", entry.module_name.as_str())));
+        Self::tally(bound, report)
+    }
+
+    /// Coverage from the `(kind, module)` of every bound item and the
+    /// exclusions in `report`. Rust generation's exclusions are of items in
+    /// `bound`, so they lower the bound count instead of adding to the total.
+    pub fn tally<'m>(bound: impl IntoIterator<Item = (&'m str, &'m str)>, report: &'a FilterReport) -> Self {
+        let mut modules: BTreeMap<String, ModuleCoverage> = BTreeMap::new();
+        let mut summary = ModuleCoverage::default();
+        for (kind, module) in bound {
+            modules.entry(module.to_string()).or_default().add(kind, true);
+            summary.add(kind, true);
+        }
+        for e in &report.excluded {
+            let module = modules.entry(e.module.clone()).or_default();
+            for counts in [module, &mut summary] {
+                if e.stage == SkipStage::RustGen {
+                    counts.demote(e.kind);
+                } else {
+                    counts.add(e.kind, false);
+                }
+                *counts.categories.entry(e.category).or_default() += 1;
+            }
+        }
+        CoverageReport { modules, summary, report }
+    }
+
+    pub fn to_json(&self) -> Value {
+        let mut skipped: Vec<_> = self.report.excluded.iter().collect();
+        skipped.sort_by(|a, b| (&a.module, a.kind, &a.name).cmp(&(&b.module, b.kind, &b.name)));
+        let skipped: Vec<Value> = skipped
+            .into_iter()
+            .map(|e| {
+                json!({
+                    "kind": e.kind,
+                    "module": e.module,
+                    "name": e.name,
+                    "stage": e.stage.as_str(),
+                    "category": e.category.as_str(),
+                    "reason": e.reason,
+                    "offending": e.offending,
+                })
+            })
+            .collect();
+        let modules: serde_json::Map<String, Value> =
+            self.modules.iter().map(|(name, m)| (name.clone(), m.to_json())).collect();
+        json!({
+            "version": REPORT_VERSION,
+            "summary": self.summary.to_json(),
+            "modules": modules,
+            "skipped": skipped,
+        })
+    }
+
+    /// Write the JSON report to `path`.
+    pub fn write(&self, path: &Path) {
+        let json = serde_json::to_string_pretty(&self.to_json()).expect("coverage report serializes");
+        std::fs::write(path, json + "\n")
+            .unwrap_or_else(|e| panic!("Failed to write {}: {e}", path.display()));
+        eprintln!("  coverage report: {}", path.display());
+    }
+
+    /// Print per-module percentages and the most common skip categories.
+    pub fn print_summary(&self) {
+        let row = |name: &str, m: &ModuleCoverage| {
+            let cells: Vec<String> = KINDS
+                .iter()
+                .zip(&m.counts)
+                .map(|(kind, c)| format!("{} {:5.1}% ({}/{})", plural(kind), c.percent(), c.bound, c.total))
+                .collect();
+            eprintln!("    {name:<20} {}", cells.join("  "));
+        };
+        eprintln!("  Coverage:");
+        for (name, m) in &self.modules {
+            row(name, m);
+        }
+        row("(all)", &self.summary);
+        let mut categories: Vec<_> = self.summary.categories.iter().collect();
+        categories.sort_by(|a, b| b.1.cmp(a.1).then(a.0.cmp(b.0)));
+        let categories: Vec<String> = categories.iter().map(|(c, n)| format!("{} {n}", c.as_str())).collect();
+        if !categories.is_empty() {
+            eprintln!("  Skipped by category: {}", categories.join(", "));
+        }
+    }
+}
+
+fn plural(kind: &str) -> &'static str {
+    match kind {
+        "class" => "classes",
+        "struct" => "structs",
+        "function" => "functions",
+        _ => "properties",
+    }
+}
+
+fn categories_json(categories: &BTreeMap<SkipCategory, usize>) -> Value {
+    Value::Object(categories.iter().map(|(c, n)| (c.as_str().to_string(), json!(n))).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::filter::{SkipReason, SkipStage};
+
+    #[test]
+    fn tally_counts_bound_and_skipped_per_module() {
+        let mut report = FilterReport::default();
+        let skip = |category| SkipReason::new(category, String::new());
+        report.exclude("function", SkipStage::Filter, "engine", "Actor.Foo".into(), skip(SkipCategory::UnsupportedType));
+        report.exclude("function", SkipStage::Filter, "engine", "Actor.Bar".into(), skip(SkipCategory::Blocklist));
+        report.exclude("class", SkipStage::Filter, "umg", "Widget".into(), skip(SkipCategory::Blocklist));
+        report.exclude("function", SkipStage::RustGen, "engine", "Actor.Baz".into(), skip(SkipCategory::Duplicate));
+
+        let bound = [
+            ("class", "engine"),
+            ("function", "engine"),
+            ("function", "engine"),
+            ("function", "engine"),
+            ("class", "umg"),
+        ];
+        let coverage = CoverageReport::tally(bound, &report);
+
+        let engine = &coverage.modules["engine"];
+        // Actor.Baz was bound, then dropped by Rust generation.
+        assert_eq!(engine.count("function"), Count { bound: 2, total: 5 });
+        assert_eq!(engine.count("class"), Count { bound: 1, total: 1 });
+        assert_eq!(engine.count("property").percent(), 100.0);
+        assert_eq!(coverage.modules["umg"].count("class").percent(), 50.0);
+        assert_eq!(coverage.summary.categories[&SkipCategory::Blocklist], 2);
+
+        let json = coverage.to_json();
+        assert_eq!(json["summary"]["functions"]["percent"], 40.0);
+        assert_eq!(json["modules"]["engine"]["categories"]["unsupported_type"], 1);
+        // Sorted by module, kind, name.
+        assert_eq!(json["skipped"][0]["name"], "Actor.Bar");
+        assert_eq!(json["skipped"][1]["stage"], "rust_gen");
+        assert_eq!(json["skipped"][3]["module"], "umg");
+    }
+}
@@ -2,13 +2,14 @@
 
 use std::collections::BTreeMap;
 
-use crate::context::FuncEntry;
+use crate::context::{BindingStamp, FuncEntry};
 use crate::cpp_gen::wrapper::cpp_wrapper_name;
 
 /// Generate the UikaFillFuncTable.cpp file.
 pub fn generate_fill_table(
     entries: &[FuncEntry],
     _by_class: &BTreeMap<(String, String), Vec<&FuncEntry>>,
+    stamp: &BindingStamp,
 ) -> String {
     let mut out = String::with_capacity(entries.len() * 80 + 1024);
 
@@ -23,7 +24,7 @@ pub fn generate_fill_table(
     }
     out.push('\n');
 
-    // Global function table
+    // Global function table. Slots of retired FuncIds stay null.
     out.push_str(&format!(
         "static void* GUikaFuncTable[UikaFuncId::FUNC_COUNT];\n\n"
     ));
@@ -49,5 +50,17 @@ pub fn generate_fill_table(
         "uint32_t UikaGetFuncCount() {{\n    return UikaFuncId::FUNC_COUNT;\n}}\n"
     ));
 
+    // Binding stamp: compared with BINDING_* in the generated func_ids.rs
+    // by uika::init.
+    out.push_str(&format!(
+        "\nconst char* UikaGetBindingEngineVersion() {{\n    return {:?};\n}}\n\n\
+         uint64_t UikaGetBindingConfigHash() {{\n    return {:#018x}ull;\n}}\n\n",
+        stamp.engine_version, stamp.config_hash
+    ));
+    out.push_str("static const uint64_t GUikaFuncSigs[UikaFuncId::FUNC_COUNT] = {\n");
+    crate::rust_gen::func_ids::push_sig_rows(&mut out, &stamp.func_sigs);
+    out.push_str("};\n\n");
+    out.push_str("const uint64_t* UikaGetBindingFuncSigs() {\n    return GUikaFuncSigs;\n}\n");
+
     out
 }
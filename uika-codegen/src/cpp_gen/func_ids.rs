@@ -3,8 +3,9 @@
 use crate::context::FuncEntry;
 use crate::rust_gen::func_ids::func_id_const_name;
 
-/// Generate the UikaFuncIds.h header file.
-pub fn generate_cpp_func_ids(entries: &[FuncEntry]) -> String {
+/// Generate the UikaFuncIds.h header file. `func_count` is the table size,
+/// including retired FuncIds.
+pub fn generate_cpp_func_ids(entries: &[FuncEntry], func_count: u32) -> String {
     let mut out = String::with_capacity(entries.len() * 60 + 512);
 
     out.push_str("// Auto-generated by uika-codegen. Do not edit.\n\n");
@@ -19,10 +20,7 @@ pub fn generate_cpp_func_ids(entries: &[FuncEntry]) -> String {
     }
 
     out.push('\n');
-    out.push_str(&format!(
-        "    constexpr uint32_t FUNC_COUNT = {};\n",
-        entries.len()
-    ));
+    out.push_str(&format!("    constexpr uint32_t FUNC_COUNT = {func_count};\n"));
 
     out.push_str("\n} // namespace UikaFuncId\n");
 
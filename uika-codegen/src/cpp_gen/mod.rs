@@ -8,9 +8,18 @@ use std::collections::BTreeMap;
 use std::path::Path;
 
 use crate::context::CodegenContext;
+use crate::incremental::{Fingerprints, OutDir, Outputs};
 
 /// Generate all C++ code into the output directory.
-pub fn generate(ctx: &CodegenContext, out_dir: &Path) {
+///
+/// Wrapper files whose inputs are unchanged (per `fingerprints`) are
+/// skipped when `outputs` is in incremental mode.
+pub fn generate(
+    ctx: &CodegenContext,
+    out_dir: &Path,
+    fingerprints: &Fingerprints,
+    outputs: &mut Outputs,
+) {
     std::fs::create_dir_all(out_dir).expect("Failed to create C++ output directory");
 
     // Group func entries by (module, class) for per-file generation
@@ -22,21 +31,30 @@ pub fn generate(ctx: &CodegenContext, out_dir: &Path) {
             .push(entry);
     }
 
+    // Extra sources' wrappers go into their own subdirectories
+    for subdir in ctx.module_cpp_subdir.values() {
+        std::fs::create_dir_all(out_dir.join(subdir))
+            .expect("Failed to create C++ output subdirectory");
+    }
+
     // Generate per-class wrapper files
     for ((module, class), entries) in &by_class {
-        let code = wrapper::generate_wrapper_file(entries, ctx);
-        let filename = format!("UikaFunc_{}_{}.cpp", module, class);
-        std::fs::write(out_dir.join(&filename), code)
-            .unwrap_or_else(|e| panic!("Failed to write {filename}: {e}"));
+        let filename = match ctx.module_cpp_subdir.get(module) {
+            Some(subdir) => format!("{subdir}/UikaFunc_{module}_{class}.cpp"),
+            None => format!("UikaFunc_{module}_{class}.cpp"),
+        };
+        let input = fingerprints.class_input("cpp", class, entries.iter().copied());
+        if !outputs.is_fresh(OutDir::Cpp, &filename, input) {
+            let code = wrapper::generate_wrapper_file(entries, ctx);
+            outputs.write(OutDir::Cpp, &filename, &code);
+        }
     }
 
     // Generate UikaFuncIds.h
-    let ids_code = func_ids::generate_cpp_func_ids(&ctx.func_table);
-    std::fs::write(out_dir.join("UikaFuncIds.h"), ids_code)
-        .expect("Failed to write UikaFuncIds.h");
+    let ids_code = func_ids::generate_cpp_func_ids(&ctx.func_table, ctx.func_count);
+    outputs.write(OutDir::Cpp, "UikaFuncIds.h", &ids_code);
 
     // Generate UikaFillFuncTable.cpp
-    let fill_code = fill_table::generate_fill_table(&ctx.func_table, &by_class);
-    std::fs::write(out_dir.join("UikaFillFuncTable.cpp"), fill_code)
-        .expect("Failed to write UikaFillFuncTable.cpp");
+    let fill_code = fill_table::generate_fill_table(&ctx.func_table, &by_class, &ctx.binding_stamp);
+    outputs.write(OutDir::Cpp, "UikaFillFuncTable.cpp", &fill_code);
 }
@@ -21,31 +21,42 @@ enum ReturnStrategy {
     StringReturn,
     /// FText result -> .ToString() then UTF-8 buffer conversion.
     TextReturn,
+    /// TSoftObjectPtr / TSoftClassPtr result -> asset path as UTF-8.
+    SoftPathReturn,
     /// Struct result -> memcpy to output buffer.
     StructReturn,
     /// FName result -> pack to u64.
     FNameReturn,
+    /// FGameplayTag result -> pack its tag name to u64.
+    GameplayTagReturn,
     /// Container return -> move-assign into temp container.
     ContainerReturn,
 }
 
-/// Post-call action for complex output parameters.
+/// Post-call action for complex output parameters. String outputs carry the
+/// param's index, their slot in the output stash.
 enum PostCallAction {
-    StringOutput(String),
+    StringOutput(String, u32),
     /// FText output param: call .ToString() before UTF-8 conversion.
-    TextOutput(String),
-    StructOutput { name: String, struct_cpp: String },
+    TextOutput(String, u32),
+    /// Soft pointer output param: write its asset path as UTF-8.
+    SoftPathOutput(String, u32),
+    StructOutput(String),
     ObjectOutput(String),
     /// TSoftObjectPtr / TWeakObjectPtr output: call .Get() to extract raw pointer.
     SoftWeakObjectOutput(String),
     FNameOutput(String),
+    /// FGameplayTag output param: pack its tag name to u64.
+    GameplayTagOutput(String),
     EnumOutput { name: String, ffi_type: String },
     /// InOut struct copyback: copy local back to mutable buffer.
     InOutStructCopyback { name: String, struct_cpp: String },
     /// InOut FString copyback: convert __InOut{name} back to UTF-8.
-    InOutStringCopyback(String),
+    InOutStringCopyback(String, u32),
     /// InOut FText copyback: convert __InOut{name}.ToString() back to UTF-8.
-    InOutTextCopyback(String),
+    InOutTextCopyback(String, u32),
+    /// InOut soft pointer copyback: write __InOut{name}'s asset path back as UTF-8.
+    InOutSoftPathCopyback(String, u32),
 }
 
 /// Generate a complete .cpp file with all wrapper functions for a class.
@@ -57,6 +68,7 @@ pub fn generate_wrapper_file(entries: &[&FuncEntry], ctx: &CodegenContext) -> St
     // Collect all required includes
     let mut includes = BTreeSet::new();
     includes.insert("\"UikaApiTable.h\"".to_string());
+    includes.insert("\"UikaOutputStash.h\"".to_string());
 
     let has_container_params = entries.iter().any(|e| {
         e.func.params.iter().any(|p| is_container_param(p))
@@ -86,6 +98,12 @@ pub fn generate_wrapper_file(entries: &[&FuncEntry], ctx: &CodegenContext) -> St
     if has_fname {
         includes.insert("\"UikaFNameHelper.h\"".to_string());
     }
+    let has_gameplay_tag = entries.iter().any(|e| {
+        e.func.params.iter().any(type_map::is_gameplay_tag_param)
+    });
+    if has_gameplay_tag {
+        includes.insert("\"UikaGameplayTagHelper.h\"".to_string());
+    }
 
     for inc in &includes {
         out.push_str(&format!("#include {inc}\n"));
@@ -106,8 +124,45 @@ pub fn generate_wrapper_file(entries: &[&FuncEntry], ctx: &CodegenContext) -> St
     out.push_str("#define UIKA_ERROR_CODES_DEFINED\n");
     out.push_str("static constexpr uint32_t UikaErrorCode_Ok = 0;\n");
     out.push_str("static constexpr uint32_t UikaErrorCode_ObjectDestroyed = 1;\n");
+    out.push_str("static constexpr uint32_t UikaErrorCode_BufferTooSmall = 10;\n");
     out.push_str("#endif\n\n");
 
+    // Output copy helpers (guarded for Unity builds). A string that does not
+    // fit is stashed whole for the Rust side to fetch; the UE function is
+    // never called twice.
+    out.push_str(
+        "#ifndef UIKA_OUTPUT_COPY_DEFINED\n\
+         #define UIKA_OUTPUT_COPY_DEFINED\n\
+         // Write Str into Buf if it fits, otherwise stash it as output Slot (see\n\
+         // UikaOutputStash.h). *OutLen gets the full length; returns false if it\n\
+         // was stashed.\n\
+         static inline bool UikaCopyStringOut(const FString& Str, uint32_t Slot, char* Buf, uint32_t BufLen, uint32_t* OutLen)\n\
+         {\n\
+         \x20   auto Utf8 = StringCast<ANSICHAR>(*Str);\n\
+         \x20   const uint32_t Len = static_cast<uint32_t>(Utf8.Length());\n\
+         \x20   *OutLen = Len;\n\
+         \x20   if (Len > BufLen)\n\
+         \x20   {\n\
+         \x20       UikaOutputStashPut(Slot, Utf8.Get(), Len);\n\
+         \x20       return false;\n\
+         \x20   }\n\
+         \x20   FMemory::Memcpy(Buf, Utf8.Get(), Len);\n\
+         \x20   return true;\n\
+         }\n\
+         // Copy Value into Buf if it fits (a null Buf discards it). *OutSize gets\n\
+         // sizeof(T); returns false if Buf was too small.\n\
+         template <typename T>\n\
+         static inline bool UikaCopyStructOut(const T& Value, void* Buf, uint32_t BufLen, uint32_t* OutSize)\n\
+         {\n\
+         \x20   *OutSize = sizeof(T);\n\
+         \x20   if (!Buf) return true;\n\
+         \x20   if (BufLen < sizeof(T)) return false;\n\
+         \x20   FMemory::Memcpy(Buf, &Value, sizeof(T));\n\
+         \x20   return true;\n\
+         }\n\
+         #endif\n\n",
+    );
+
     // Generate each wrapper
     for entry in entries {
         generate_wrapper_function(&mut out, entry, ctx);
@@ -137,6 +192,11 @@ fn generate_wrapper_function(out: &mut String, entry: &FuncEntry, ctx: &CodegenC
 
     let c_func_name = cpp_wrapper_name(&entry.class_name, func_name);
 
+    // A string output's slot in the output stash: its index in the params.
+    let slot = |param: &ParamInfo| -> u32 {
+        func.params.iter().position(|p| std::ptr::eq(p, param)).expect("param of this function") as u32
+    };
+
     // Classify params
     let mut inputs = Vec::new();
     let mut outputs = Vec::new();
@@ -232,30 +292,25 @@ fn generate_wrapper_function(out: &mut String, entry: &FuncEntry, ctx: &CodegenC
         let name = &param.name;
         match mapped.ffi_to_rust {
             ConversionKind::StringUtf8 => {
-                if param.prop_type == "TextProperty" {
+                if let Some(soft_cpp) = soft_ptr_cpp_type(param, ctx) {
+                    out.push_str(&format!("    {soft_cpp} __Out{name};\n"));
+                    post_call_actions.push(PostCallAction::SoftPathOutput(name.clone(), slot(param)));
+                } else if param.prop_type == "TextProperty" {
                     out.push_str(&format!("    FText __Out{name};\n"));
-                    post_call_actions.push(PostCallAction::TextOutput(name.clone()));
+                    post_call_actions.push(PostCallAction::TextOutput(name.clone(), slot(param)));
                 } else {
                     out.push_str(&format!("    FString __Out{name};\n"));
-                    post_call_actions.push(PostCallAction::StringOutput(name.clone()));
+                    post_call_actions.push(PostCallAction::StringOutput(name.clone(), slot(param)));
                 }
             }
             ConversionKind::StructOpaque => {
                 let struct_cpp = resolve_struct_opaque_cpp_type(param, ctx);
                 out.push_str(&format!("    {struct_cpp} __Out{name};\n"));
-                post_call_actions.push(PostCallAction::StructOutput {
-                    name: name.clone(),
-                    struct_cpp,
-                });
+                post_call_actions.push(PostCallAction::StructOutput(name.clone()));
             }
             ConversionKind::ObjectRef => {
                 match param.prop_type.as_str() {
-                    // TSoftObjectPtr / TWeakObjectPtr are value types, not raw pointers.
-                    "SoftObjectProperty" => {
-                        let bare = resolve_object_cpp_type_bare(ctx, param.class_name.as_deref());
-                        out.push_str(&format!("    TSoftObjectPtr<{bare}> __Out{name};\n"));
-                        post_call_actions.push(PostCallAction::SoftWeakObjectOutput(name.clone()));
-                    }
+                    // TWeakObjectPtr is a value type, not a raw pointer.
                     "WeakObjectProperty" => {
                         let bare = resolve_object_cpp_type_bare(ctx, param.class_name.as_deref());
                         out.push_str(&format!("    TWeakObjectPtr<{bare}> __Out{name};\n"));
@@ -269,8 +324,13 @@ fn generate_wrapper_function(out: &mut String, entry: &FuncEntry, ctx: &CodegenC
                 }
             }
             ConversionKind::FName => {
-                out.push_str(&format!("    FName __Out{name};\n"));
-                post_call_actions.push(PostCallAction::FNameOutput(name.clone()));
+                if type_map::is_gameplay_tag_param(param) {
+                    out.push_str(&format!("    FGameplayTag __Out{name};\n"));
+                    post_call_actions.push(PostCallAction::GameplayTagOutput(name.clone()));
+                } else {
+                    out.push_str(&format!("    FName __Out{name};\n"));
+                    post_call_actions.push(PostCallAction::FNameOutput(name.clone()));
+                }
             }
             ConversionKind::EnumCast => {
                 let cpp_enum = resolve_enum_cpp_type(param);
@@ -314,16 +374,21 @@ fn generate_wrapper_function(out: &mut String, entry: &FuncEntry, ctx: &CodegenC
             let mapped = map_param(param);
             if mapped.rust_to_ffi == ConversionKind::StringUtf8 {
                 let name = &param.name;
-                if param.prop_type == "TextProperty" {
+                if let Some(soft_cpp) = soft_ptr_cpp_type(param, ctx) {
+                    out.push_str(&format!(
+                        "    {soft_cpp} __InOut{name} = {soft_cpp}(FSoftObjectPath(FString(UTF8_TO_TCHAR(std::string({name}, {name}Len).c_str()))));\n"
+                    ));
+                    post_call_actions.push(PostCallAction::InOutSoftPathCopyback(name.clone(), slot(param)));
+                } else if param.prop_type == "TextProperty" {
                     out.push_str(&format!(
                         "    FText __InOut{name} = FText::FromString(FString(UTF8_TO_TCHAR(std::string({name}, {name}Len).c_str())));\n"
                     ));
-                    post_call_actions.push(PostCallAction::InOutTextCopyback(name.clone()));
+                    post_call_actions.push(PostCallAction::InOutTextCopyback(name.clone(), slot(param)));
                 } else {
                     out.push_str(&format!(
                         "    FString __InOut{name} = FString(UTF8_TO_TCHAR(std::string({name}, {name}Len).c_str()));\n"
                     ));
-                    post_call_actions.push(PostCallAction::InOutStringCopyback(name.clone()));
+                    post_call_actions.push(PostCallAction::InOutStringCopyback(name.clone(), slot(param)));
                 }
             }
         }
@@ -372,10 +437,18 @@ fn generate_wrapper_function(out: &mut String, entry: &FuncEntry, ctx: &CodegenC
         format!("Self->{}({})", func_name, call_args.join(", "))
     };
 
+    // String and struct outputs report whether they fit their buffers. If one
+    // did not, the wrapper returns BufferTooSmall; strings that did not fit
+    // are in the output stash, so the call is not repeated.
+    let sized = func.params.iter().any(type_map::has_sized_output);
+    if sized {
+        out.push_str("    bool __UikaFits = true;\n");
+    }
+
     // Emit call + return handling
     if let Some(rp) = return_param {
-        let strategy = return_strategy(rp);
-        emit_return(out, &strategy, &call_expr);
+        let strategy = return_strategy(rp, ctx);
+        emit_return(out, &strategy, &call_expr, slot(rp));
     } else {
         out.push_str(&format!("    {call_expr};\n"));
     }
@@ -385,7 +458,11 @@ fn generate_wrapper_function(out: &mut String, entry: &FuncEntry, ctx: &CodegenC
         emit_post_call(out, action);
     }
 
-    out.push_str("    return UikaErrorCode_Ok;\n");
+    if sized {
+        out.push_str("    return __UikaFits ? UikaErrorCode_Ok : UikaErrorCode_BufferTooSmall;\n");
+    } else {
+        out.push_str("    return UikaErrorCode_Ok;\n");
+    }
     out.push_str("}\n\n");
 }
 
@@ -435,6 +512,11 @@ fn expand_output_sig(params: &mut Vec<String>, param: &ParamInfo) {
             params.push(format!("    uint32_t Out{name}BufLen"));
             params.push(format!("    uint32_t* Out{name}Len"));
         }
+        ConversionKind::StructOpaque => {
+            params.push(format!("    uint8_t* Out{name}"));
+            params.push(format!("    uint32_t Out{name}BufLen"));
+            params.push(format!("    uint32_t* Out{name}Size"));
+        }
         _ => {
             let cpp_type = scalar_output_cpp_type(&mapped, param);
             params.push(format!("    {cpp_type} Out{name}"));
@@ -455,6 +537,11 @@ fn expand_return_sig(params: &mut Vec<String>, param: &ParamInfo) {
             params.push("    uint32_t BufLen".to_string());
             params.push("    uint32_t* OutLen".to_string());
         }
+        ConversionKind::StructOpaque => {
+            params.push("    uint8_t* OutReturnValue".to_string());
+            params.push("    uint32_t ReturnValueBufLen".to_string());
+            params.push("    uint32_t* OutReturnValueSize".to_string());
+        }
         _ => {
             let cpp_type = scalar_output_cpp_type(&mapped, param);
             params.push(format!("    {cpp_type} OutReturnValue"));
@@ -468,15 +555,7 @@ fn expand_return_sig(params: &mut Vec<String>, param: &ParamInfo) {
 
 /// Map a ParamInfo to its MappedType.
 fn map_param(param: &ParamInfo) -> MappedType {
-    type_map::map_property_type(
-        &param.prop_type,
-        param.class_name.as_deref(),
-        param.struct_name.as_deref(),
-        param.enum_name.as_deref(),
-        param.enum_underlying_type.as_deref(),
-        param.meta_class_name.as_deref(),
-        param.interface_name.as_deref(),
-    )
+    type_map::map_param_type(param)
 }
 
 /// Get the C++ type for a single scalar input parameter.
@@ -506,12 +585,13 @@ fn scalar_output_cpp_type(mapped: &MappedType, param: &ParamInfo) -> String {
     match mapped.ffi_to_rust {
         ConversionKind::Identity => format!("{}*", mapped.cpp_type),
         ConversionKind::ObjectRef => "void**".to_string(),
-        ConversionKind::StringUtf8 => unreachable!("strings handled by expand_*_sig"),
+        ConversionKind::StringUtf8 | ConversionKind::StructOpaque => {
+            unreachable!("strings and structs handled by expand_*_sig")
+        }
         ConversionKind::EnumCast => {
             format!("{}*", enum_ffi_ctype(param.enum_underlying_type.as_deref()))
         }
         ConversionKind::IntCast => format!("{}*", int_ctype(&mapped.cpp_type)),
-        ConversionKind::StructOpaque => "uint8_t*".to_string(),
         ConversionKind::FName => "uint64_t*".to_string(),
         ConversionKind::ContainerArray | ConversionKind::ContainerMap | ConversionKind::ContainerSet
         | ConversionKind::Delegate | ConversionKind::MulticastDelegate =>
@@ -567,6 +647,24 @@ fn resolve_struct_opaque_cpp_type(param: &ParamInfo, ctx: &CodegenContext) -> St
     }
 }
 
+/// The C++ soft pointer type (`TSoftObjectPtr<T>` / `TSoftClassPtr<T>`) for a
+/// soft param, or `None` for every other type. Soft params use the string
+/// transport and cross the FFI as their asset path.
+fn soft_ptr_cpp_type(param: &ParamInfo, ctx: &CodegenContext) -> Option<String> {
+    match param.prop_type.as_str() {
+        "SoftObjectProperty" => {
+            let bare = resolve_object_cpp_type_bare(ctx, param.class_name.as_deref());
+            Some(format!("TSoftObjectPtr<{bare}>"))
+        }
+        "SoftClassProperty" => {
+            let cls = param.meta_class_name.as_deref().or(param.class_name.as_deref());
+            let bare = resolve_object_cpp_type_bare(ctx, cls);
+            Some(format!("TSoftClassPtr<{bare}>"))
+        }
+        _ => None,
+    }
+}
+
 /// Get the C++ class name without a trailing * (e.g., "UObject", "AActor").
 fn resolve_object_cpp_type_bare(ctx: &CodegenContext, class_name: Option<&str>) -> String {
     match class_name {
@@ -612,11 +710,7 @@ fn input_arg_expression(param: &ParamInfo, dir: ParamDirection, ctx: &CodegenCon
         ConversionKind::ObjectRef => {
             let cpp_type = resolve_object_cpp_type(ctx, param.class_name.as_deref());
             match param.prop_type.as_str() {
-                // TSoftObjectPtr<T> / TWeakObjectPtr<T> are value types constructed from UObject*.
-                "SoftObjectProperty" => {
-                    let bare_type = resolve_object_cpp_type_bare(ctx, param.class_name.as_deref());
-                    format!("TSoftObjectPtr<{bare_type}>(static_cast<{cpp_type}>({}))", param.name)
-                }
+                // TWeakObjectPtr<T> is a value type constructed from UObject*.
                 "WeakObjectProperty" => {
                     let bare_type = resolve_object_cpp_type_bare(ctx, param.class_name.as_deref());
                     format!("TWeakObjectPtr<{bare_type}>(static_cast<{cpp_type}>({}))", param.name)
@@ -633,7 +727,9 @@ fn input_arg_expression(param: &ParamInfo, dir: ParamDirection, ctx: &CodegenCon
                     "FString(UTF8_TO_TCHAR(std::string({name}, {name}Len).c_str()))",
                     name = param.name
                 );
-                if param.prop_type == "TextProperty" {
+                if let Some(soft_cpp) = soft_ptr_cpp_type(param, ctx) {
+                    format!("{soft_cpp}(FSoftObjectPath({fstring_expr}))")
+                } else if param.prop_type == "TextProperty" {
                     format!("FText::FromString({fstring_expr})")
                 } else {
                     fstring_expr
@@ -658,7 +754,11 @@ fn input_arg_expression(param: &ParamInfo, dir: ParamDirection, ctx: &CodegenCon
         }
         ConversionKind::IntCast => param.name.clone(), // C++ native type, no conversion needed
         ConversionKind::FName => {
-            format!("UikaUnpackFName({})", param.name)
+            if type_map::is_gameplay_tag_param(param) {
+                format!("UikaUnpackGameplayTag({})", param.name)
+            } else {
+                format!("UikaUnpackFName({})", param.name)
+            }
         }
         ConversionKind::ContainerArray | ConversionKind::ContainerMap | ConversionKind::ContainerSet
         | ConversionKind::Delegate | ConversionKind::MulticastDelegate =>
@@ -670,7 +770,7 @@ fn input_arg_expression(param: &ParamInfo, dir: ParamDirection, ctx: &CodegenCon
 // Return handling
 // ---------------------------------------------------------------------------
 
-fn return_strategy(param: &ParamInfo) -> ReturnStrategy {
+fn return_strategy(param: &ParamInfo, ctx: &CodegenContext) -> ReturnStrategy {
     if is_container_param(param) {
         return ReturnStrategy::ContainerReturn;
     }
@@ -679,15 +779,17 @@ fn return_strategy(param: &ParamInfo) -> ReturnStrategy {
     match mapped.ffi_to_rust {
         ConversionKind::Identity | ConversionKind::IntCast => ReturnStrategy::Direct,
         ConversionKind::ObjectRef => {
-            // TSoftObjectPtr / TWeakObjectPtr return value types, not raw pointers.
+            // TWeakObjectPtr returns a value type, not a raw pointer.
             // Need .Get() to extract the UObject* before casting to void*.
             match param.prop_type.as_str() {
-                "SoftObjectProperty" | "WeakObjectProperty" => ReturnStrategy::SoftWeakObjectRef,
+                "WeakObjectProperty" => ReturnStrategy::SoftWeakObjectRef,
                 _ => ReturnStrategy::ObjectRef,
             }
         }
         ConversionKind::StringUtf8 => {
-            if param.prop_type == "TextProperty" {
+            if soft_ptr_cpp_type(param, ctx).is_some() {
+                ReturnStrategy::SoftPathReturn
+            } else if param.prop_type == "TextProperty" {
                 ReturnStrategy::TextReturn
             } else {
                 ReturnStrategy::StringReturn
@@ -703,14 +805,21 @@ fn return_strategy(param: &ParamInfo) -> ReturnStrategy {
             ReturnStrategy::Cast(ctype.to_string())
         }
         ConversionKind::StructOpaque => ReturnStrategy::StructReturn,
-        ConversionKind::FName => ReturnStrategy::FNameReturn,
+        ConversionKind::FName => {
+            if type_map::is_gameplay_tag_param(param) {
+                ReturnStrategy::GameplayTagReturn
+            } else {
+                ReturnStrategy::FNameReturn
+            }
+        }
         ConversionKind::ContainerArray | ConversionKind::ContainerMap | ConversionKind::ContainerSet
         | ConversionKind::Delegate | ConversionKind::MulticastDelegate =>
             unreachable!("container/delegate types are property-only, never function return types"),
     }
 }
 
-fn emit_return(out: &mut String, strategy: &ReturnStrategy, call_expr: &str) {
+/// `slot` is the return param's index, its slot in the output stash.
+fn emit_return(out: &mut String, strategy: &ReturnStrategy, call_expr: &str, slot: u32) {
     match strategy {
         ReturnStrategy::Direct => {
             out.push_str(&format!("    *OutReturnValue = {call_expr};\n"));
@@ -735,28 +844,26 @@ fn emit_return(out: &mut String, strategy: &ReturnStrategy, call_expr: &str) {
         }
         ReturnStrategy::StringReturn => {
             out.push_str(&format!("    FString __UikaResult = {call_expr};\n"));
-            out.push_str(
-                "    auto __UikaUtf8 = StringCast<ANSICHAR>(*__UikaResult);\n\
-                 \x20   int32 __UikaLen = __UikaUtf8.Length();\n\
-                 \x20   if (__UikaLen > static_cast<int32>(BufLen)) __UikaLen = static_cast<int32>(BufLen);\n\
-                 \x20   FMemory::Memcpy(OutBuf, __UikaUtf8.Get(), __UikaLen);\n\
-                 \x20   *OutLen = static_cast<uint32_t>(__UikaLen);\n",
-            );
+            out.push_str(&format!(
+                "    if (!UikaCopyStringOut(__UikaResult, {slot}, OutBuf, BufLen, OutLen)) __UikaFits = false;\n"
+            ));
         }
         ReturnStrategy::TextReturn => {
             out.push_str(&format!("    FString __UikaResult = ({call_expr}).ToString();\n"));
-            out.push_str(
-                "    auto __UikaUtf8 = StringCast<ANSICHAR>(*__UikaResult);\n\
-                 \x20   int32 __UikaLen = __UikaUtf8.Length();\n\
-                 \x20   if (__UikaLen > static_cast<int32>(BufLen)) __UikaLen = static_cast<int32>(BufLen);\n\
-                 \x20   FMemory::Memcpy(OutBuf, __UikaUtf8.Get(), __UikaLen);\n\
-                 \x20   *OutLen = static_cast<uint32_t>(__UikaLen);\n",
-            );
+            out.push_str(&format!(
+                "    if (!UikaCopyStringOut(__UikaResult, {slot}, OutBuf, BufLen, OutLen)) __UikaFits = false;\n"
+            ));
+        }
+        ReturnStrategy::SoftPathReturn => {
+            out.push_str(&format!("    FString __UikaResult = ({call_expr}).ToSoftObjectPath().ToString();\n"));
+            out.push_str(&format!(
+                "    if (!UikaCopyStringOut(__UikaResult, {slot}, OutBuf, BufLen, OutLen)) __UikaFits = false;\n"
+            ));
         }
         ReturnStrategy::StructReturn => {
             out.push_str(&format!("    auto __UikaResult = {call_expr};\n"));
             out.push_str(
-                "    FMemory::Memcpy(OutReturnValue, &__UikaResult, sizeof(__UikaResult));\n",
+                "    if (!UikaCopyStructOut(__UikaResult, OutReturnValue, ReturnValueBufLen, OutReturnValueSize)) __UikaFits = false;\n",
             );
         }
         ReturnStrategy::FNameReturn => {
@@ -765,6 +872,12 @@ fn emit_return(out: &mut String, strategy: &ReturnStrategy, call_expr: &str) {
                 "    *OutReturnValue = UikaPackFName(__UikaResult);\n",
             );
         }
+        ReturnStrategy::GameplayTagReturn => {
+            out.push_str(&format!("    FGameplayTag __UikaResult = {call_expr};\n"));
+            out.push_str(
+                "    *OutReturnValue = UikaPackGameplayTag(__UikaResult);\n",
+            );
+        }
         ReturnStrategy::ContainerReturn => {
             out.push_str(&format!("    __Container_ReturnValue = {call_expr};\n"));
         }
@@ -805,33 +918,31 @@ fn resolve_enum_cpp_type(param: &ParamInfo) -> String {
 
 fn emit_post_call(out: &mut String, action: &PostCallAction) {
     match action {
-        PostCallAction::StringOutput(name) => {
+        PostCallAction::StringOutput(name, slot) => {
             out.push_str(&format!(
-                "    {{\n\
-                 \x20       auto __Utf8 = StringCast<ANSICHAR>(*__Out{name});\n\
-                 \x20       int32 __Len = __Utf8.Length();\n\
-                 \x20       if (__Len > static_cast<int32>(Out{name}BufLen)) __Len = static_cast<int32>(Out{name}BufLen);\n\
-                 \x20       FMemory::Memcpy(Out{name}, __Utf8.Get(), __Len);\n\
-                 \x20       *Out{name}Len = static_cast<uint32_t>(__Len);\n\
-                 \x20   }}\n"
+                "    if (!UikaCopyStringOut(__Out{name}, {slot}, Out{name}, Out{name}BufLen, Out{name}Len)) __UikaFits = false;\n"
             ));
         }
-        PostCallAction::TextOutput(name) => {
+        PostCallAction::TextOutput(name, slot) => {
             // FText output: convert via .ToString() then UTF-8
             out.push_str(&format!(
                 "    {{\n\
                  \x20       FString __Str = __Out{name}.ToString();\n\
-                 \x20       auto __Utf8 = StringCast<ANSICHAR>(*__Str);\n\
-                 \x20       int32 __Len = __Utf8.Length();\n\
-                 \x20       if (__Len > static_cast<int32>(Out{name}BufLen)) __Len = static_cast<int32>(Out{name}BufLen);\n\
-                 \x20       FMemory::Memcpy(Out{name}, __Utf8.Get(), __Len);\n\
-                 \x20       *Out{name}Len = static_cast<uint32_t>(__Len);\n\
+                 \x20       if (!UikaCopyStringOut(__Str, {slot}, Out{name}, Out{name}BufLen, Out{name}Len)) __UikaFits = false;\n\
+                 \x20   }}\n"
+            ));
+        }
+        PostCallAction::SoftPathOutput(name, slot) => {
+            out.push_str(&format!(
+                "    {{\n\
+                 \x20       FString __Str = __Out{name}.ToSoftObjectPath().ToString();\n\
+                 \x20       if (!UikaCopyStringOut(__Str, {slot}, Out{name}, Out{name}BufLen, Out{name}Len)) __UikaFits = false;\n\
                  \x20   }}\n"
             ));
         }
-        PostCallAction::StructOutput { name, struct_cpp } => {
+        PostCallAction::StructOutput(name) => {
             out.push_str(&format!(
-                "    if (Out{name}) {{ FMemory::Memcpy(Out{name}, &__Out{name}, sizeof({struct_cpp})); }}\n"
+                "    if (!UikaCopyStructOut(__Out{name}, Out{name}, Out{name}BufLen, Out{name}Size)) __UikaFits = false;\n"
             ));
         }
         PostCallAction::ObjectOutput(name) => {
@@ -849,6 +960,11 @@ fn emit_post_call(out: &mut String, action: &PostCallAction) {
                 "    *Out{name} = UikaPackFName(__Out{name});\n"
             ));
         }
+        PostCallAction::GameplayTagOutput(name) => {
+            out.push_str(&format!(
+                "    *Out{name} = UikaPackGameplayTag(__Out{name});\n"
+            ));
+        }
         PostCallAction::EnumOutput { name, ffi_type } => {
             out.push_str(&format!(
                 "    *Out{name} = static_cast<{ffi_type}>(__Out{name});\n"
@@ -859,26 +975,24 @@ fn emit_post_call(out: &mut String, action: &PostCallAction) {
                 "    if ({name}) {{ FMemory::Memcpy({name}, &__InOut{name}, sizeof({struct_cpp})); }}\n"
             ));
         }
-        PostCallAction::InOutStringCopyback(name) => {
+        PostCallAction::InOutStringCopyback(name, slot) => {
+            out.push_str(&format!(
+                "    if (!UikaCopyStringOut(__InOut{name}, {slot}, Out{name}, Out{name}BufLen, Out{name}Len)) __UikaFits = false;\n"
+            ));
+        }
+        PostCallAction::InOutTextCopyback(name, slot) => {
             out.push_str(&format!(
                 "    {{\n\
-                 \x20       auto __Utf8 = StringCast<ANSICHAR>(*__InOut{name});\n\
-                 \x20       int32 __Len = __Utf8.Length();\n\
-                 \x20       if (__Len > static_cast<int32>(Out{name}BufLen)) __Len = static_cast<int32>(Out{name}BufLen);\n\
-                 \x20       FMemory::Memcpy(Out{name}, __Utf8.Get(), __Len);\n\
-                 \x20       *Out{name}Len = static_cast<uint32_t>(__Len);\n\
+                 \x20       FString __Str = __InOut{name}.ToString();\n\
+                 \x20       if (!UikaCopyStringOut(__Str, {slot}, Out{name}, Out{name}BufLen, Out{name}Len)) __UikaFits = false;\n\
                  \x20   }}\n"
             ));
         }
-        PostCallAction::InOutTextCopyback(name) => {
+        PostCallAction::InOutSoftPathCopyback(name, slot) => {
             out.push_str(&format!(
                 "    {{\n\
-                 \x20       FString __Str = __InOut{name}.ToString();\n\
-                 \x20       auto __Utf8 = StringCast<ANSICHAR>(*__Str);\n\
-                 \x20       int32 __Len = __Utf8.Length();\n\
-                 \x20       if (__Len > static_cast<int32>(Out{name}BufLen)) __Len = static_cast<int32>(Out{name}BufLen);\n\
-                 \x20       FMemory::Memcpy(Out{name}, __Utf8.Get(), __Len);\n\
-                 \x20       *Out{name}Len = static_cast<uint32_t>(__Len);\n\
+                 \x20       FString __Str = __InOut{name}.ToSoftObjectPath().ToString();\n\
+                 \x20       if (!UikaCopyStringOut(__Str, {slot}, Out{name}, Out{name}BufLen, Out{name}Len)) __UikaFits = false;\n\
                  \x20   }}\n"
             ));
         }
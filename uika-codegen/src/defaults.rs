@@ -37,8 +37,8 @@ pub fn parse_default_literal(
         "UInt32Property" => parse_int_default(default_str, "u32"),
         "UInt64Property" => parse_int_default(default_str, "u64"),
         "EnumProperty" => parse_enum_default(default_str, param, ctx),
-        "ObjectProperty" | "ClassProperty"
-        | "SoftObjectProperty" | "WeakObjectProperty"
+        // Soft pointers are passed by reference and have no default form.
+        "ObjectProperty" | "ClassProperty" | "WeakObjectProperty"
         | "InterfaceProperty" => {
             parse_object_default(default_str, mapped)
         }
@@ -74,7 +74,8 @@ fn parse_object_default(s: &str, mapped: &MappedType) -> Option<String> {
     if s == "None" {
         // Check if this is a typed UObjectRef or an untyped UObjectHandle
         if mapped.rust_to_ffi == ConversionKind::ObjectRef {
-            Some("unsafe { uika_runtime::UObjectRef::from_raw(uika_runtime::UObjectHandle::null()) }".into())
+            let ctor = crate::type_map::object_ref_ctor(&mapped.rust_type);
+            Some(format!("unsafe {{ {ctor}(uika_runtime::UObjectHandle::null()) }}"))
         } else {
             // Untyped UObjectHandle (Identity conversion)
             Some("uika_runtime::UObjectHandle::null()".into())
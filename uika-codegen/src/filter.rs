@@ -1,14 +1,228 @@
-// Secondary filtering: K2_ dedup, FUNC_Native gate, type exportability, overloads.
+// Secondary filtering: allow/blocklists, K2_ dedup, FUNC_Native gate, type
+// exportability, overloads. Every exclusion is recorded with its module,
+// category and reason in a FilterReport (printed in full by `uika generate
+// --dry-run`, and the basis of the coverage report, see `crate::coverage`).
 
 use std::collections::{HashMap, HashSet};
 
-use crate::config::Blocklist;
+use crate::config::{Allowlist, Blocklist};
 use crate::context::CodegenContext;
+use crate::pattern::PatternSet;
 use crate::schema::*;
 use crate::type_map;
 
+/// Why an item was left out, grouped for the coverage report.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub enum SkipCategory {
+    /// Matched a `[codegen.blocklist]` pattern.
+    Blocklist,
+    /// `[codegen.allowlist]` is set and nothing in it matched.
+    Allowlist,
+    /// A property or parameter type the type mapper doesn't handle (including
+    /// delegates and container element types it can't marshal).
+    UnsupportedType,
+    /// References a class, struct or enum that isn't bound (disabled module,
+    /// or excluded itself).
+    UnavailableType,
+    /// Private or protected in C++.
+    NotPublic,
+    /// Not callable through the function table (no FUNC_Native, or declared
+    /// on a UInterface).
+    NotCallable,
+    /// A `K2_` variant of a function that is bound under its plain name.
+    Duplicate,
+}
+
+impl SkipCategory {
+    /// Stable name used in reports.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            SkipCategory::Blocklist => "blocklist",
+            SkipCategory::Allowlist => "allowlist",
+            SkipCategory::UnsupportedType => "unsupported_type",
+            SkipCategory::UnavailableType => "unavailable_type",
+            SkipCategory::NotPublic => "not_public",
+            SkipCategory::NotCallable => "not_callable",
+            SkipCategory::Duplicate => "duplicate",
+        }
+    }
+}
+
+/// Where in the pipeline an item was left out.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum SkipStage {
+    /// [`apply_filters`]: removed from the context.
+    Filter,
+    /// The function table: a function kept by the filters but not given a
+    /// FuncId.
+    FuncTable,
+    /// Rust generation: still in the context (and, for functions, in the
+    /// function table) but no Rust API is generated for it.
+    RustGen,
+}
+
+impl SkipStage {
+    /// Stable name used in reports.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            SkipStage::Filter => "filter",
+            SkipStage::FuncTable => "func_table",
+            SkipStage::RustGen => "rust_gen",
+        }
+    }
+}
+
+/// Why an item was left out.
+#[derive(Clone, Debug)]
+pub struct SkipReason {
+    pub category: SkipCategory,
+    /// Human-readable reason.
+    pub reason: String,
+    /// The parameter or type responsible, if one is: `"Param: TypeProperty"`
+    /// for a parameter, or a type name.
+    pub offending: Option<String>,
+}
+
+impl SkipReason {
+    pub fn new(category: SkipCategory, reason: String) -> Self {
+        SkipReason { category, reason, offending: None }
+    }
+
+    pub fn offending(mut self, offending: impl Into<String>) -> Self {
+        self.offending = Some(offending.into());
+        self
+    }
+
+    /// `offending` naming a parameter and its type.
+    pub fn offending_param(self, param: &ParamInfo) -> Self {
+        let offending = format!("{}: {}", param.name, param.prop_type);
+        self.offending(offending)
+    }
+}
+
+/// One item left out of the generated bindings.
+pub struct Exclusion {
+    /// "class", "struct", "function" or "property".
+    pub kind: &'static str,
+    /// Rust module of the item (or of its owner).
+    pub module: String,
+    /// Type name, or "Owner.Member" for functions and properties.
+    pub name: String,
+    pub stage: SkipStage,
+    pub category: SkipCategory,
+    pub reason: String,
+    pub offending: Option<String>,
+}
+
+/// Everything [`apply_filters`] excluded, and why.
+#[derive(Default)]
+pub struct FilterReport {
+    pub excluded: Vec<Exclusion>,
+}
+
+impl FilterReport {
+    pub fn exclude(&mut self, kind: &'static str, stage: SkipStage, module: &str, name: String, reason: SkipReason) {
+        let SkipReason { category, reason, offending } = reason;
+        self.excluded.push(Exclusion { kind, module: module.to_string(), name, stage, category, reason, offending });
+    }
+
+    /// Number of exclusions of the given kind.
+    pub fn count(&self, kind: &str) -> usize {
+        self.excluded.iter().filter(|e| e.kind == kind).count()
+    }
+
+    /// Print per-kind counts; with `detailed`, every exclusion and its reason.
+    pub fn print(&self, detailed: bool) {
+        const KINDS: [(&str, &str); 4] = [
+            ("class", "classes"),
+            ("struct", "structs"),
+            ("function", "functions"),
+            ("property", "properties"),
+        ];
+        if !detailed {
+            let counts: Vec<String> = KINDS
+                .iter()
+                .map(|(kind, plural)| format!("{} {plural}", self.count(kind)))
+                .collect();
+            eprintln!("  excluded: {}", counts.join(", "));
+            return;
+        }
+        for (kind, plural) in KINDS {
+            let mut items: Vec<&Exclusion> = self.excluded.iter().filter(|e| e.kind == kind).collect();
+            if items.is_empty() {
+                continue;
+            }
+            items.sort_by(|a, b| a.name.cmp(&b.name));
+            eprintln!("  Excluded {plural} ({}):", items.len());
+            for e in items {
+                eprintln!("    {} - {}", e.name, e.reason);
+            }
+        }
+    }
+}
+
+/// Allow- and blocklist patterns for one kind of item.
+struct KindFilter {
+    block: PatternSet,
+    allow: PatternSet,
+}
+
+impl KindFilter {
+    fn new(block: &[String], allow: &[String], what: &str) -> Self {
+        KindFilter {
+            block: PatternSet::parse(block, &format!("blocklist {what}")),
+            allow: PatternSet::parse(allow, &format!("allowlist {what}")),
+        }
+    }
+
+    /// Why `name` is excluded by the config lists, if it is.
+    fn exclusion(&self, name: &str) -> Option<SkipReason> {
+        if let Some(pattern) = self.block.find(name) {
+            return Some(SkipReason::new(SkipCategory::Blocklist, format!("blocklist `{pattern}`")));
+        }
+        if !self.allow.is_empty() && self.allow.find(name).is_none() {
+            return Some(SkipReason::new(SkipCategory::Allowlist, "not in allowlist".to_string()));
+        }
+        None
+    }
+}
+
 /// Apply all filters to the context's module_classes in place.
-pub fn apply_filters(ctx: &mut CodegenContext, blocklist: &Blocklist) {
+///
+/// Excluded structs are removed entirely, so properties and functions that
+/// use them are dropped as referencing an unavailable type.
+pub fn apply_filters(
+    ctx: &mut CodegenContext,
+    blocklist: &Blocklist,
+    allowlist: &Allowlist,
+) -> FilterReport {
+    let mut report = FilterReport::default();
+    let class_filter = KindFilter::new(&blocklist.classes, &allowlist.classes, "classes");
+    let struct_filter = KindFilter::new(&blocklist.structs, &allowlist.structs, "structs");
+    let func_filter = KindFilter::new(&blocklist.functions, &allowlist.functions, "functions");
+    let prop_filter = KindFilter::new(&blocklist.properties, &allowlist.properties, "properties");
+
+    // Remove excluded structs before collecting available types.
+    let mut excluded_structs: HashSet<String> = HashSet::new();
+    for (name, s) in &ctx.structs {
+        if let Some(reason) = struct_filter.exclusion(name) {
+            let module = ctx.module_for_package(&s.package).unwrap_or_default();
+            report.exclude("struct", SkipStage::Filter, module, name.clone(), reason);
+            excluded_structs.insert(name.clone());
+        }
+    }
+    ctx.structs.retain(|name, _| !excluded_structs.contains(name));
+    for (module, structs) in ctx.module_structs.iter_mut() {
+        structs.retain(|s| !excluded_structs.contains(&s.name));
+        for s in structs.iter_mut() {
+            filter_struct_props(module, s, &prop_filter, &mut report);
+        }
+    }
+    for s in ctx.structs.values_mut() {
+        // Keep the lookup copies in sync; already reported above.
+        filter_struct_props("", s, &prop_filter, &mut FilterReport::default());
+    }
+
     // Pre-collect the set of available types to avoid borrowing ctx inside the loop.
     let available_types: HashSet<String> = ctx
         .classes
@@ -18,90 +232,125 @@ pub fn apply_filters(ctx: &mut CodegenContext, blocklist: &Blocklist) {
         .cloned()
         .collect();
 
-    // Build lookup sets from config blocklist
-    let blocked_classes: HashSet<&str> = blocklist.classes.iter().map(|s| s.as_str()).collect();
-    let blocked_structs: HashSet<&str> = blocklist.structs.iter().map(|s| s.as_str()).collect();
-    let blocked_functions: Vec<(String, String)> = blocklist.function_tuples();
-
-    // Remove blocked classes from both module_classes and ctx.classes
-    for cls in &blocklist.classes {
-        ctx.classes.remove(cls);
+    // Remove excluded classes from both module_classes and ctx.classes
+    let mut excluded_classes: HashSet<String> = HashSet::new();
+    for (name, c) in &ctx.classes {
+        if let Some(reason) = class_filter.exclusion(name) {
+            let module = ctx.module_for_package(&c.package).unwrap_or_default();
+            report.exclude("class", SkipStage::Filter, module, name.clone(), reason);
+            excluded_classes.insert(name.clone());
+        }
     }
+    ctx.classes.retain(|name, _| !excluded_classes.contains(name));
 
-    for classes in ctx.module_classes.values_mut() {
-        // Remove blocked classes entirely
-        classes.retain(|c| !blocked_classes.contains(c.name.as_str()));
+    for (module, classes) in ctx.module_classes.iter_mut() {
+        // Remove excluded classes entirely
+        classes.retain(|c| !excluded_classes.contains(&c.name));
 
         for class in classes.iter_mut() {
             // Filter properties
-            class
-                .props
-                .retain(|p| is_property_exportable(p, &available_types));
+            let class_name = &class.name;
+            class.props.retain(|p| {
+                let qualified = format!("{class_name}.{}", p.name);
+                let reason = prop_filter
+                    .exclusion(&qualified)
+                    .or_else(|| property_exclusion(p, &available_types));
+                match reason {
+                    Some(reason) => {
+                        report.exclude("property", SkipStage::Filter, module, qualified, reason);
+                        false
+                    }
+                    None => true,
+                }
+            });
 
             // Filter functions
-            filter_functions(&class.name, &mut class.funcs, &available_types, &blocked_structs, &blocked_functions);
+            filter_functions(
+                module,
+                &class.name,
+                &mut class.funcs,
+                &available_types,
+                &excluded_structs,
+                &func_filter,
+                &mut report,
+            );
         }
     }
+
+    report
+}
+
+/// Apply the property lists to a struct's properties. Type exportability of
+/// struct properties is checked at generation time, not here.
+fn filter_struct_props(module: &str, s: &mut StructInfo, prop_filter: &KindFilter, report: &mut FilterReport) {
+    let struct_name = &s.name;
+    s.props.retain(|p| {
+        let qualified = format!("{struct_name}.{}", p.name);
+        match prop_filter.exclusion(&qualified) {
+            Some(reason) => {
+                report.exclude("property", SkipStage::Filter, module, qualified, reason);
+                false
+            }
+            None => true,
+        }
+    });
 }
 
-/// Check if a property is exportable (supported type, not private/protected, single array dim).
-fn is_property_exportable(prop: &PropertyInfo, available: &HashSet<String>) -> bool {
+/// Why a property is not exportable (unsupported type, not public, or
+/// referencing an unavailable type), if it isn't.
+fn property_exclusion(prop: &PropertyInfo, available: &HashSet<String>) -> Option<SkipReason> {
     // Skip unsupported types
     if !type_map::is_supported_type(&prop.prop_type) {
-        return false;
+        let reason = format!("unsupported type `{}`", prop.prop_type);
+        return Some(SkipReason::new(SkipCategory::UnsupportedType, reason).offending(&prop.prop_type));
     }
 
     // Skip fixed arrays of string/name/text types (CopySingleValue not safe for FString)
     if prop.array_dim > 1 {
         match prop.prop_type.as_str() {
-            "StrProperty" | "NameProperty" | "TextProperty" => return false,
+            "StrProperty" | "NameProperty" | "TextProperty" => {
+                let reason = format!("fixed array of `{}`", prop.prop_type);
+                return Some(SkipReason::new(SkipCategory::UnsupportedType, reason).offending(&prop.prop_type));
+            }
             _ => {} // allow through
         }
     }
 
     // Skip private/protected
     if prop.prop_flags & CPF_NATIVE_ACCESS_PRIVATE != 0 {
-        return false;
+        return Some(SkipReason::new(SkipCategory::NotPublic, "private".to_string()));
     }
     if prop.prop_flags & CPF_NATIVE_ACCESS_PROTECTED != 0 {
-        return false;
+        return Some(SkipReason::new(SkipCategory::NotPublic, "protected".to_string()));
     }
 
     // Delegate properties: validate all params in func_info are exportable
     if is_delegate_type(&prop.prop_type) {
-        return is_delegate_exportable(prop, available);
+        return (!is_delegate_exportable(prop, available))
+            .then(|| {
+                let reason = "delegate signature not exportable".to_string();
+                SkipReason::new(SkipCategory::UnsupportedType, reason).offending(&prop.prop_type)
+            });
     }
 
     // Check referenced types are available
-    if let Some(ref cls) = prop.class_name {
-        if !available.contains(cls) {
-            return false;
-        }
+    let mut referenced = vec![
+        &prop.class_name,
+        &prop.struct_name,
+        &prop.enum_name,
+        &prop.interface_name,
+    ];
+    if matches!(prop.prop_type.as_str(), "ClassProperty" | "SoftClassProperty") {
+        referenced.push(&prop.meta_class_name);
     }
-    if let Some(ref sn) = prop.struct_name {
-        if !available.contains(sn) {
-            return false;
-        }
-    }
-    if let Some(ref en) = prop.enum_name {
-        if !available.contains(en) {
-            return false;
-        }
-    }
-    if let Some(ref iface) = prop.interface_name {
-        if !available.contains(iface) {
-            return false;
-        }
-    }
-    if prop.prop_type == "ClassProperty" {
-        if let Some(ref meta_cls) = prop.meta_class_name {
-            if !available.contains(meta_cls) {
-                return false;
-            }
-        }
-    }
-
-    true
+    referenced
+        .into_iter()
+        .flatten()
+        .find(|name| !available.contains(*name))
+        .map(|name| {
+            let reason = format!("references unavailable type `{name}`");
+            SkipReason::new(SkipCategory::UnavailableType, reason).offending(name.as_str())
+        })
 }
 
 fn is_delegate_type(prop_type: &str) -> bool {
@@ -226,82 +475,33 @@ fn is_inner_type_exportable(inner: &PropertyInfo, available: &HashSet<String>) -
     true
 }
 
-/// Filter functions on a class: FUNC_Native gate, K2_ dedup, param type check, overload rename.
+/// Filter functions on a class: allow/blocklists, FUNC_Native gate, K2_ dedup,
+/// param type check, overload rename.
 fn filter_functions(
+    module: &str,
     class_name: &str,
     funcs: &mut Vec<FunctionInfo>,
     available: &HashSet<String>,
-    blocked_structs: &HashSet<&str>,
-    blocked_functions: &[(String, String)],
+    excluded_structs: &HashSet<String>,
+    func_filter: &KindFilter,
+    report: &mut FilterReport,
 ) {
     // Step 1: Collect all function names for K2_ dedup
     let all_names: HashSet<String> = funcs.iter().map(|f| f.name.clone()).collect();
 
     // Step 2: Filter
     funcs.retain(|f| {
-        // Function-level blocklist (unlinked symbols)
-        if blocked_functions.iter().any(|(c, func)| c == class_name && func == &f.name) {
-            return false;
-        }
-
-        // FUNC_Native gate
-        if f.func_flags & FUNC_NATIVE == 0 {
-            return false;
-        }
-
-        // K2_ dedup: if this is K2_Foo and Foo also exists, skip K2_Foo
-        if f.name.starts_with("K2_") {
-            let base_name = &f.name[3..];
-            if all_names.contains(base_name) {
-                return false;
-            }
-        }
-
-        // Check all param types are supported and referenced types are available
-        for param in &f.params {
-            if !type_map::is_supported_type(&param.prop_type) {
-                return false;
-            }
-            // Delegate-typed params are not valid in function signatures
-            if is_delegate_type(&param.prop_type) {
-                return false;
-            }
-            // Check container inner types are resolvable
-            if matches!(param.prop_type.as_str(), "ArrayProperty" | "MapProperty" | "SetProperty") {
-                if !is_container_param_exportable(param, available) {
-                    return false;
-                }
-            }
-            if let Some(ref cls) = param.class_name {
-                if !available.contains(cls) {
-                    return false;
-                }
-            }
-            if let Some(ref sn) = param.struct_name {
-                if !available.contains(sn) || blocked_structs.contains(sn.as_str()) {
-                    return false;
-                }
-            }
-            if let Some(ref en) = param.enum_name {
-                if !available.contains(en) {
-                    return false;
-                }
-            }
-            if let Some(ref iface) = param.interface_name {
-                if !available.contains(iface) {
-                    return false;
-                }
-            }
-            if param.prop_type == "ClassProperty" {
-                if let Some(ref meta_cls) = param.meta_class_name {
-                    if !available.contains(meta_cls) {
-                        return false;
-                    }
-                }
+        let qualified = format!("{class_name}.{}", f.name);
+        let reason = func_filter
+            .exclusion(&qualified)
+            .or_else(|| function_exclusion(f, &all_names, available, excluded_structs));
+        match reason {
+            Some(reason) => {
+                report.exclude("function", SkipStage::Filter, module, qualified, reason);
+                false
             }
+            None => true,
         }
-
-        true
     });
 
     // Preserve original UE function names before overload renaming
@@ -326,3 +526,65 @@ fn filter_functions(
         }
     }
 }
+
+/// Why a function can't be exported, if it can't.
+fn function_exclusion(
+    f: &FunctionInfo,
+    all_names: &HashSet<String>,
+    available: &HashSet<String>,
+    excluded_structs: &HashSet<String>,
+) -> Option<SkipReason> {
+    // FUNC_Native gate
+    if f.func_flags & FUNC_NATIVE == 0 {
+        return Some(SkipReason::new(SkipCategory::NotCallable, "not FUNC_Native".to_string()));
+    }
+
+    // K2_ dedup: if this is K2_Foo and Foo also exists, skip K2_Foo
+    if let Some(base_name) = f.name.strip_prefix("K2_") {
+        if all_names.contains(base_name) {
+            return Some(SkipReason::new(SkipCategory::Duplicate, format!("K2_ variant of `{base_name}`")));
+        }
+    }
+
+    // Check all param types are supported and referenced types are available
+    for param in &f.params {
+        let pname = &param.name;
+        let skip = |category, reason: String| Some(SkipReason::new(category, reason).offending_param(param));
+        if !type_map::is_supported_type(&param.prop_type) {
+            let reason = format!("parameter `{pname}` has unsupported type `{}`", param.prop_type);
+            return skip(SkipCategory::UnsupportedType, reason);
+        }
+        // Delegate-typed params are not valid in function signatures
+        if is_delegate_type(&param.prop_type) {
+            return skip(SkipCategory::UnsupportedType, format!("parameter `{pname}` is a delegate"));
+        }
+        // Check container inner types are resolvable
+        if matches!(param.prop_type.as_str(), "ArrayProperty" | "MapProperty" | "SetProperty")
+            && !is_container_param_exportable(param, available)
+        {
+            let reason = format!("parameter `{pname}` has an unexportable element type");
+            return skip(SkipCategory::UnsupportedType, reason);
+        }
+        if let Some(sn) = &param.struct_name {
+            if excluded_structs.contains(sn) {
+                let reason = format!("parameter `{pname}` uses excluded struct `{sn}`");
+                return skip(SkipCategory::UnavailableType, reason);
+            }
+        }
+        let mut referenced = vec![
+            &param.class_name,
+            &param.struct_name,
+            &param.enum_name,
+            &param.interface_name,
+        ];
+        if matches!(param.prop_type.as_str(), "ClassProperty" | "SoftClassProperty") {
+            referenced.push(&param.meta_class_name);
+        }
+        if let Some(name) = referenced.into_iter().flatten().find(|n| !available.contains(*n)) {
+            let reason = format!("parameter `{pname}` references unavailable type `{name}`");
+            return skip(SkipCategory::UnavailableType, reason);
+        }
+    }
+
+    None
+}
@@ -0,0 +1,171 @@
+// Persisted FuncId assignments.
+//
+// FuncIds index the plugin's function table, so they must not shift when
+// unrelated functions are added or removed: a hot-reloaded DLL is allowed to
+// run against a plugin generated by an earlier run. The map lives next to
+// uika.config.toml (`[codegen.paths] func_id_map`, default
+// `uika_func_ids.json`) and is meant to be checked in.
+//
+// Functions already in the map keep their id; new ones are appended after the
+// highest id ever handed out. Ids of functions that are no longer exported
+// stay reserved (their table slot is left null), so they can't be reused for
+// a different signature. `uika generate --renumber` discards the map and
+// numbers everything from scratch in (module, class, function) order.
+
+use std::collections::{BTreeMap, HashSet};
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::context::FuncEntry;
+use crate::incremental::{write_if_changed, WriteResult};
+
+/// Default map file name, relative to the config file.
+pub const DEFAULT_FUNC_ID_MAP: &str = "uika_func_ids.json";
+
+#[derive(Serialize, Deserialize, Default)]
+pub struct FuncIdMap {
+    /// Next id to hand out; also the size of the function table.
+    next_id: u32,
+    /// "Class.Function" to FuncId, including retired functions.
+    ids: BTreeMap<String, u32>,
+}
+
+/// What [`FuncIdMap::assign`] did.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct AssignStats {
+    pub kept: usize,
+    pub added: usize,
+    /// Ids in the map with no exported function this run.
+    pub retired: usize,
+}
+
+impl FuncIdMap {
+    /// Load the map, or start an empty one if the file doesn't exist.
+    pub fn load(path: &Path) -> Self {
+        let Ok(text) = std::fs::read_to_string(path) else {
+            return FuncIdMap::default();
+        };
+        let mut map: FuncIdMap = serde_json::from_str(&text)
+            .unwrap_or_else(|e| panic!("Failed to parse {}: {e}", path.display()));
+
+        let mut seen = HashSet::with_capacity(map.ids.len());
+        for (key, &id) in &map.ids {
+            if !seen.insert(id) {
+                panic!(
+                    "{}: FuncId {id} is assigned twice (again to '{key}'); \
+                     fix the file or run `uika generate --renumber`",
+                    path.display()
+                );
+            }
+        }
+        // Tolerate hand edits that raise an id past next_id.
+        if let Some(max) = map.ids.values().max() {
+            map.next_id = map.next_id.max(max + 1);
+        }
+        map
+    }
+
+    /// Set `func_id` on every entry. `entries` must already be sorted, which
+    /// fixes the order new ids are appended in.
+    pub fn assign(&mut self, entries: &mut [FuncEntry]) -> AssignStats {
+        let mut stats = AssignStats::default();
+        let mut used = HashSet::with_capacity(entries.len());
+        for entry in entries.iter_mut() {
+            let key = format!("{}.{}", entry.class_name, entry.func_name);
+            entry.func_id = match self.ids.get(&key) {
+                Some(&id) => {
+                    stats.kept += 1;
+                    id
+                }
+                None => {
+                    let id = self.next_id;
+                    self.next_id += 1;
+                    self.ids.insert(key, id);
+                    stats.added += 1;
+                    id
+                }
+            };
+            used.insert(entry.func_id);
+        }
+        stats.retired = self.ids.values().filter(|id| !used.contains(id)).count();
+        stats
+    }
+
+    /// Size of the function table: one past the highest id handed out.
+    pub fn func_count(&self) -> u32 {
+        self.next_id
+    }
+
+    /// Write the map, leaving the file untouched if nothing changed.
+    pub fn save(&self, path: &Path) -> WriteResult {
+        let mut json = serde_json::to_string_pretty(self).expect("FuncId map serializes");
+        json.push('\n');
+        write_if_changed(path, &json)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schema::FunctionInfo;
+
+    fn entries(names: &[&str]) -> Vec<FuncEntry> {
+        names
+            .iter()
+            .map(|n| {
+                let (class, func) = n.split_once('.').unwrap();
+                let info: FunctionInfo =
+                    serde_json::from_str(&format!(r#"{{"name":"{func}","func_flags":0,"params":[]}}"#))
+                        .unwrap();
+                FuncEntry {
+                    func_id: 0,
+                    module_name: "engine".into(),
+                    class_name: class.into(),
+                    func_name: func.into(),
+                    rust_func_name: func.to_lowercase(),
+                    func: info,
+                    cpp_class_name: format!("A{class}"),
+                    header: String::new(),
+                }
+            })
+            .collect()
+    }
+
+    fn ids(entries: &[FuncEntry]) -> Vec<u32> {
+        entries.iter().map(|e| e.func_id).collect()
+    }
+
+    #[test]
+    fn test_existing_ids_are_stable() {
+        let mut map = FuncIdMap::default();
+        let mut first = entries(&["Actor.GetOwner", "Pawn.GetController"]);
+        map.assign(&mut first);
+        assert_eq!(ids(&first), [0, 1]);
+
+        // A new function sorting before both is appended, not inserted.
+        let mut second = entries(&["Actor.Destroy", "Actor.GetOwner", "Pawn.GetController"]);
+        let stats = map.assign(&mut second);
+        assert_eq!(ids(&second), [2, 0, 1]);
+        assert_eq!(stats, AssignStats { kept: 2, added: 1, retired: 0 });
+        assert_eq!(map.func_count(), 3);
+    }
+
+    #[test]
+    fn test_removed_ids_stay_reserved() {
+        let mut map = FuncIdMap::default();
+        map.assign(&mut entries(&["Actor.GetOwner", "Pawn.GetController"]));
+
+        let mut next = entries(&["Pawn.GetController", "Pawn.Restart"]);
+        let stats = map.assign(&mut next);
+        assert_eq!(ids(&next), [1, 2]);
+        assert_eq!(stats.retired, 1);
+        assert_eq!(map.func_count(), 3);
+
+        let mut renumbered = entries(&["Pawn.GetController", "Pawn.Restart"]);
+        let mut fresh = FuncIdMap::default();
+        fresh.assign(&mut renumbered);
+        assert_eq!(ids(&renumbered), [0, 1]);
+        assert_eq!(fresh.func_count(), 2);
+    }
+}
@@ -0,0 +1,351 @@
+// Incremental codegen: skip files whose inputs did not change.
+//
+// Every generated file is tracked in a manifest next to the Rust output
+// (`.uika-codegen-cache.json`) together with a hash of the inputs it was
+// generated from. A per-type file's input hash combines:
+//
+// - a global fingerprint: codegen version, the config text, the full struct
+//   and enum exports, and the identity (name, package, C++ name, header,
+//   super, interfaces) of every class, which is all a class file reads from
+//   other classes;
+// - the type's own raw UHT JSON entry;
+// - for classes, the FuncIds assigned to its functions (a `--renumber` run
+//   changes them).
+//
+// In incremental mode a file whose recorded hash matches and which still
+// exists on disk is neither regenerated nor rewritten. Aggregate files
+// (mod.rs, lib.rs, FuncId tables, ...) are always regenerated but only
+// written when their content changed, so unchanged outputs keep their mtime
+// and cargo/UBT do not recompile them.
+//
+// Outputs listed in the previous manifest but not produced by this run
+// (removed classes, disabled modules) are deleted in both modes.
+
+use std::collections::{BTreeMap, HashMap};
+use std::hash::Hasher;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::context::FuncEntry;
+
+/// Manifest file name, written into the Rust output directory.
+pub const MANIFEST_NAME: &str = ".uika-codegen-cache.json";
+
+/// Bump when the manifest layout or hashing scheme changes.
+const MANIFEST_VERSION: u32 = 1;
+
+/// 64-bit FNV-1a. Unlike `DefaultHasher`, stable across Rust releases, so
+/// hashes stored in the manifest stay comparable.
+#[derive(Clone, Copy)]
+pub struct StableHasher(u64);
+
+impl Default for StableHasher {
+    fn default() -> Self {
+        StableHasher(0xcbf2_9ce4_8422_2325)
+    }
+}
+
+impl Hasher for StableHasher {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for &b in bytes {
+            self.0 ^= b as u64;
+            self.0 = self.0.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+}
+
+impl StableHasher {
+    /// Hash a length-prefixed string, so adjacent fields can't run together.
+    pub fn str(&mut self, s: &str) -> &mut Self {
+        self.write_u64(s.len() as u64);
+        self.write(s.as_bytes());
+        self
+    }
+}
+
+/// Input hashes derived from the raw UHT JSON and config.
+pub struct Fingerprints {
+    /// Hash of everything every generated file may depend on.
+    pub global: u64,
+    /// Hash of each class's own JSON entry, by class name.
+    classes: HashMap<String, u64>,
+}
+
+impl Fingerprints {
+    /// Fingerprint the raw config and JSON texts, one of each file per UHT
+    /// export (the main one first, then `[[codegen.extra_sources]]`).
+    ///
+    /// The class files are re-read as untyped JSON because the schema types
+    /// only implement `Deserialize`.
+    pub fn compute(config: &str, classes: &[&str], structs: &[&str], enums: &[&str]) -> Self {
+        let classes: Vec<serde_json::Value> = classes
+            .iter()
+            .map(|text| {
+                serde_json::from_str(text)
+                    .unwrap_or_else(|e| panic!("Failed to parse classes JSON: {e}"))
+            })
+            .collect();
+        let entries: Vec<&serde_json::Value> = classes
+            .iter()
+            .filter_map(|file| file.get("classes").and_then(|v| v.as_array()))
+            .flatten()
+            .collect();
+
+        let mut global = StableHasher::default();
+        global.str(env!("CARGO_PKG_VERSION")).str(config);
+        for text in structs.iter().chain(enums) {
+            global.str(text);
+        }
+
+        let mut index: Vec<String> = Vec::with_capacity(entries.len());
+        let mut per_class = HashMap::with_capacity(entries.len());
+        for entry in entries {
+            let name = field(entry, "name");
+            let mut h = StableHasher::default();
+            h.str(&entry.to_string());
+            // First export wins, matching how duplicate classes are dropped.
+            per_class.entry(name.clone()).or_insert(h.finish());
+
+            let interfaces = entry
+                .get("interfaces")
+                .map(|v| v.to_string())
+                .unwrap_or_default();
+            index.push(format!(
+                "{name}|{}|{}|{}|{}|{interfaces}",
+                field(entry, "package"),
+                field(entry, "cpp_name"),
+                field(entry, "header"),
+                field(entry, "super"),
+            ));
+        }
+        index.sort();
+        for line in &index {
+            global.str(line);
+        }
+
+        Fingerprints { global: global.finish(), classes: per_class }
+    }
+
+    /// Input hash for a non-class type file (enum or struct).
+    pub fn type_input(&self, kind: &str, name: &str) -> u64 {
+        let mut h = StableHasher::default();
+        h.write_u64(self.global);
+        h.str(kind).str(name);
+        h.finish()
+    }
+
+    /// Input hash for a class's Rust file or C++ wrapper.
+    pub fn class_input<'a>(
+        &self,
+        kind: &str,
+        class: &str,
+        funcs: impl IntoIterator<Item = &'a FuncEntry>,
+    ) -> u64 {
+        let mut h = StableHasher::default();
+        h.write_u64(self.global);
+        h.str(kind).str(class);
+        h.write_u64(self.classes.get(class).copied().unwrap_or(0));
+        for f in funcs {
+            h.write_u32(f.func_id);
+            h.str(&f.func_name);
+        }
+        h.finish()
+    }
+}
+
+fn field(entry: &serde_json::Value, key: &str) -> String {
+    entry.get(key).and_then(|v| v.as_str()).unwrap_or("").to_string()
+}
+
+/// Which output root a tracked file lives under.
+#[derive(Clone, Copy)]
+pub enum OutDir {
+    Rust,
+    Cpp,
+}
+
+#[derive(Serialize, Deserialize, Default)]
+struct Manifest {
+    version: u32,
+    /// Output key (`rust:<rel>` / `cpp:<rel>`) to input hash. Aggregate
+    /// files are recorded with hash 0 and always regenerated.
+    outputs: BTreeMap<String, u64>,
+}
+
+/// Tracks generated files for one codegen run.
+pub struct Outputs {
+    incremental: bool,
+    rust_out: PathBuf,
+    cpp_out: PathBuf,
+    previous: Manifest,
+    current: Manifest,
+    written: usize,
+    unchanged: usize,
+    skipped: usize,
+}
+
+impl Outputs {
+    /// Load the previous manifest (if any) from `rust_out`.
+    pub fn load(rust_out: &Path, cpp_out: &Path, incremental: bool) -> Self {
+        let previous = std::fs::read_to_string(rust_out.join(MANIFEST_NAME))
+            .ok()
+            .and_then(|s| serde_json::from_str::<Manifest>(&s).ok())
+            .filter(|m| m.version == MANIFEST_VERSION)
+            .unwrap_or_default();
+        Outputs {
+            incremental,
+            rust_out: rust_out.to_path_buf(),
+            cpp_out: cpp_out.to_path_buf(),
+            previous,
+            current: Manifest { version: MANIFEST_VERSION, outputs: BTreeMap::new() },
+            written: 0,
+            unchanged: 0,
+            skipped: 0,
+        }
+    }
+
+    fn key(dir: OutDir, rel: &str) -> String {
+        match dir {
+            OutDir::Rust => format!("rust:{rel}"),
+            OutDir::Cpp => format!("cpp:{rel}"),
+        }
+    }
+
+    fn path(&self, dir: OutDir, rel: &str) -> PathBuf {
+        match dir {
+            OutDir::Rust => self.rust_out.join(rel),
+            OutDir::Cpp => self.cpp_out.join(rel),
+        }
+    }
+
+    /// Record `rel` as an output of this run with the given input hash and
+    /// report whether it is already up to date. Callers skip generation when
+    /// this returns true; otherwise they must follow up with [`write`].
+    ///
+    /// [`write`]: Outputs::write
+    pub fn is_fresh(&mut self, dir: OutDir, rel: &str, input: u64) -> bool {
+        let key = Self::key(dir, rel);
+        let fresh = self.incremental
+            && self.previous.outputs.get(&key) == Some(&input)
+            && self.path(dir, rel).exists();
+        self.current.outputs.insert(key, input);
+        if fresh {
+            self.skipped += 1;
+        }
+        fresh
+    }
+
+    /// Write a generated file. In incremental mode the write is skipped when
+    /// the file already has this content.
+    pub fn write(&mut self, dir: OutDir, rel: &str, content: &str) {
+        self.current.outputs.entry(Self::key(dir, rel)).or_insert(0);
+        let path = self.path(dir, rel);
+        if self.incremental && write_if_changed(&path, content) == WriteResult::Unchanged {
+            self.unchanged += 1;
+            return;
+        }
+        if !self.incremental {
+            std::fs::write(&path, content)
+                .unwrap_or_else(|e| panic!("Failed to write {}: {e}", path.display()));
+        }
+        self.written += 1;
+    }
+
+    /// Delete stale outputs, save the manifest and print a summary.
+    pub fn finish(self) {
+        let mut removed = 0;
+        for key in self.previous.outputs.keys() {
+            if self.current.outputs.contains_key(key) {
+                continue;
+            }
+            let path = match key.split_once(':') {
+                Some(("rust", rel)) => self.rust_out.join(rel),
+                Some(("cpp", rel)) => self.cpp_out.join(rel),
+                _ => continue,
+            };
+            if std::fs::remove_file(&path).is_ok() {
+                removed += 1;
+            }
+        }
+
+        let manifest_path = self.rust_out.join(MANIFEST_NAME);
+        let json = serde_json::to_string_pretty(&self.current).expect("manifest serializes");
+        std::fs::write(&manifest_path, json)
+            .unwrap_or_else(|e| panic!("Failed to write {}: {e}", manifest_path.display()));
+
+        if self.incremental {
+            eprintln!(
+                "  incremental: {} written, {} unchanged, {} skipped, {} removed",
+                self.written, self.unchanged, self.skipped, removed
+            );
+        } else if removed > 0 {
+            eprintln!("  removed {removed} stale output files");
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum WriteResult {
+    Written,
+    Unchanged,
+}
+
+/// Write `content` to `path` unless the file already holds exactly that.
+pub fn write_if_changed(path: &Path, content: &str) -> WriteResult {
+    if std::fs::read(path).is_ok_and(|old| old == content.as_bytes()) {
+        return WriteResult::Unchanged;
+    }
+    std::fs::write(path, content)
+        .unwrap_or_else(|e| panic!("Failed to write {}: {e}", path.display()));
+    WriteResult::Written
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn classes(actor_props: &str, pawn_header: &str) -> String {
+        format!(
+            r#"{{"classes":[
+                {{"name":"Actor","cpp_name":"AActor","package":"Engine","header":"Actor.h","super":"Object","props":{actor_props}}},
+                {{"name":"Pawn","cpp_name":"APawn","package":"Engine","header":"{pawn_header}","super":"Actor","props":[]}}
+            ]}}"#
+        )
+    }
+
+    fn actor_input(fp: &Fingerprints) -> u64 {
+        fp.class_input("rust", "Actor", std::iter::empty())
+    }
+
+    #[test]
+    fn test_class_body_change_is_local() {
+        let a = Fingerprints::compute("", &[&classes("[]", "Pawn.h")], &[], &[]);
+        let b = Fingerprints::compute("", &[&classes(r#"[{"name":"bHidden"}]"#, "Pawn.h")], &[], &[]);
+        assert_eq!(a.global, b.global);
+        assert_ne!(actor_input(&a), actor_input(&b));
+        assert_eq!(
+            a.class_input("rust", "Pawn", std::iter::empty()),
+            b.class_input("rust", "Pawn", std::iter::empty())
+        );
+    }
+
+    #[test]
+    fn test_class_identity_change_is_global() {
+        let a = Fingerprints::compute("", &[&classes("[]", "Pawn.h")], &[], &[]);
+        let b = Fingerprints::compute("", &[&classes("[]", "GameFramework/Pawn.h")], &[], &[]);
+        assert_ne!(a.global, b.global);
+        assert_ne!(actor_input(&a), actor_input(&b));
+    }
+
+    #[test]
+    fn test_config_change_is_global() {
+        let a = Fingerprints::compute("[codegen]", &[&classes("[]", "Pawn.h")], &[], &[]);
+        let b = Fingerprints::compute("[codegen]\n", &[&classes("[]", "Pawn.h")], &[], &[]);
+        assert_ne!(a.global, b.global);
+    }
+}
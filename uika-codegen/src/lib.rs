@@ -7,21 +7,50 @@ pub mod context;
 pub mod type_map;
 pub mod defaults;
 pub mod filter;
+pub mod id_map;
+pub mod pattern;
 pub mod rust_gen;
 pub mod cpp_gen;
+pub mod incremental;
+pub mod coverage;
 
 use std::path::Path;
 
 use crate::config::UikaConfig;
 use crate::schema::{ClassesFile, EnumsFile, StructsFile};
 
+/// Options for [`run_generate_with`].
+#[derive(Clone, Debug, Default)]
+pub struct GenerateOptions {
+    /// Only regenerate files whose UHT JSON inputs changed since the last
+    /// run, and leave files with identical content untouched (see
+    /// [`incremental`]).
+    pub incremental: bool,
+    /// Load and filter the input, print what the allow/blocklists and the
+    /// exportability checks excluded (and why), and write nothing.
+    pub dry_run: bool,
+    /// Write a binding coverage report (see [`coverage`]) to this path. Also
+    /// written in a dry run.
+    pub coverage: Option<std::path::PathBuf>,
+    /// Discard the persisted FuncId map and number every function from
+    /// scratch (see [`id_map`]). Breaks compatibility with plugins built
+    /// from earlier runs.
+    pub renumber: bool,
+}
+
 /// Run the generate command. Main entry point for codegen.
 pub fn run_generate(config_path: &Path) {
+    run_generate_with(config_path, &GenerateOptions::default());
+}
+
+/// Run the generate command with explicit options.
+pub fn run_generate_with(config_path: &Path, options: &GenerateOptions) {
     // Load config
     let config_str = std::fs::read_to_string(config_path)
         .unwrap_or_else(|e| panic!("Failed to read {}: {e}", config_path.display()));
-    let uika_config: UikaConfig = toml::from_str(&config_str)
+    let mut uika_config: UikaConfig = toml::from_str(&config_str)
         .unwrap_or_else(|e| panic!("Failed to parse {}: {e}", config_path.display()));
+    uika_config.codegen.merge_extra_modules();
     let codegen = &uika_config.codegen;
 
     // Resolve paths relative to config file directory
@@ -31,52 +60,52 @@ pub fn run_generate(config_path: &Path) {
         .canonicalize()
         .unwrap_or_else(|e| panic!("Failed to canonicalize config dir: {e}"));
 
-    // Derive JSON paths from config (relative to config dir)
-    let uht_input = config_dir.join(&codegen.paths.uht_input);
-    let classes_path = uht_input.join("uika_classes.json");
-    let structs_path = uht_input.join("uika_structs.json");
-    let enums_path = uht_input.join("uika_enums.json");
     let rust_out = config_dir.join(&codegen.paths.rust_out);
     let cpp_out = config_dir.join(&codegen.paths.cpp_out);
+    let func_id_map_path = config_dir.join(&codegen.paths.func_id_map);
 
     eprintln!("uika-codegen: loading JSON...");
 
-    // Parse JSON files
-    let classes_json: ClassesFile = {
-        let data = std::fs::read_to_string(&classes_path)
-            .unwrap_or_else(|e| panic!("Failed to read {}: {e}", classes_path.display()));
-        serde_json::from_str(&data)
-            .unwrap_or_else(|e| panic!("Failed to parse {}: {e}", classes_path.display()))
-    };
-
-    let structs_json: StructsFile = {
-        let data = std::fs::read_to_string(&structs_path)
-            .unwrap_or_else(|e| panic!("Failed to read {}: {e}", structs_path.display()));
-        serde_json::from_str(&data)
-            .unwrap_or_else(|e| panic!("Failed to parse {}: {e}", structs_path.display()))
-    };
-
-    let enums_json: EnumsFile = {
-        let data = std::fs::read_to_string(&enums_path)
-            .unwrap_or_else(|e| panic!("Failed to read {}: {e}", enums_path.display()));
-        serde_json::from_str(&data)
-            .unwrap_or_else(|e| panic!("Failed to parse {}: {e}", enums_path.display()))
-    };
+    // The main export first, then each extra source
+    let mut exports = vec![UhtExport::read(&config_dir.join(&codegen.paths.uht_input))];
+    for source in &codegen.extra_sources {
+        eprintln!("  extra source '{}'", source.name);
+        exports.push(UhtExport::read(&config_dir.join(&source.uht_input)));
+    }
 
-    eprintln!(
-        "  Loaded {} classes, {} structs, {} enums",
-        classes_json.classes.len(),
-        structs_json.structs.len(),
-        enums_json.enums.len()
-    );
+    let mut classes = Vec::new();
+    let mut structs = Vec::new();
+    let mut enums = Vec::new();
+    let mut engine_version = String::new();
+    let mut seen = std::collections::HashSet::new();
+    let mut duplicates = 0;
+    for (i, export) in exports.iter().enumerate() {
+        let (classes_json, structs_json, enums_json) = export.parse();
+        eprintln!(
+            "  Loaded {} classes, {} structs, {} enums",
+            classes_json.classes.len(),
+            structs_json.structs.len(),
+            enums_json.enums.len()
+        );
+        if i == 0 {
+            engine_version = classes_json.engine_version.unwrap_or_default();
+        }
+        // A plugin export may repeat engine types; the first occurrence wins.
+        let mut keep = |kind: &str, name: &str| {
+            let new = seen.insert(format!("{kind}:{name}"));
+            duplicates += usize::from(!new);
+            new
+        };
+        classes.extend(classes_json.classes.into_iter().filter(|c| keep("class", &c.name)));
+        structs.extend(structs_json.structs.into_iter().filter(|s| keep("struct", &s.name)));
+        enums.extend(enums_json.enums.into_iter().filter(|e| keep("enum", &e.name)));
+    }
+    if duplicates > 0 {
+        eprintln!("  skipped {duplicates} types already defined by an earlier export");
+    }
 
     // Build context
-    let mut ctx = context::CodegenContext::new(
-        classes_json.classes,
-        structs_json.structs,
-        enums_json.enums,
-        codegen,
-    );
+    let mut ctx = context::CodegenContext::new(classes, structs, enums, codegen);
 
     eprintln!(
         "  Enabled modules: {:?}",
@@ -94,16 +123,59 @@ pub fn run_generate(config_path: &Path) {
 
     // Apply filters
     eprintln!("uika-codegen: filtering...");
-    filter::apply_filters(&mut ctx, &codegen.blocklist);
+    let mut report = filter::apply_filters(&mut ctx, &codegen.blocklist, &codegen.allowlist);
 
     // Build function table (assign FuncIds)
     eprintln!("uika-codegen: building function table...");
-    build_func_table(&mut ctx);
-    eprintln!("  {} functions in func_table", ctx.func_table.len());
+    let mut id_map = if options.renumber {
+        eprintln!("  --renumber: assigning all FuncIds from scratch");
+        id_map::FuncIdMap::default()
+    } else {
+        id_map::FuncIdMap::load(&func_id_map_path)
+    };
+    build_func_table(&mut ctx, &mut id_map, &mut report);
+    rust_gen::skipped::collect(&ctx, &mut report);
+    // With a coverage report the per-item list goes there instead.
+    report.print(options.dry_run && options.coverage.is_none());
+    eprintln!(
+        "  {} functions in func_table ({} table slots)",
+        ctx.func_table.len(),
+        ctx.func_count
+    );
+    ctx.binding_stamp = context::BindingStamp::compute(
+        &engine_version,
+        &codegen_section(&config_str),
+        &ctx.func_table,
+        ctx.func_count,
+    );
+
+    if let Some(path) = &options.coverage {
+        let coverage = coverage::CoverageReport::compute(&ctx, &report);
+        coverage.print_summary();
+        coverage.write(path);
+    }
+
+    if options.dry_run {
+        eprintln!("uika-codegen: dry run, nothing written.");
+        return;
+    }
+
+    if id_map.save(&func_id_map_path) == incremental::WriteResult::Written {
+        eprintln!("  updated {}", func_id_map_path.display());
+    }
+
+    let texts = |f: fn(&UhtExport) -> &str| exports.iter().map(f).collect::<Vec<_>>();
+    let fingerprints = incremental::Fingerprints::compute(
+        &config_str,
+        &texts(|e| &e.classes),
+        &texts(|e| &e.structs),
+        &texts(|e| &e.enums),
+    );
+    let mut outputs = incremental::Outputs::load(&rust_out, &cpp_out, options.incremental);
 
     // Generate Rust code
     eprintln!("uika-codegen: generating Rust code...");
-    rust_gen::generate(&ctx, &rust_out);
+    rust_gen::generate(&ctx, &rust_out, &fingerprints, &mut outputs, &report);
 
     // Rewrite uika-bindings/Cargo.toml [features] from the dep graph.
     // Sits next to the rust_out src/ directory.
@@ -122,10 +194,11 @@ pub fn run_generate(config_path: &Path) {
 
     // Generate C++ code
     eprintln!("uika-codegen: generating C++ code...");
-    cpp_gen::generate(&ctx, &cpp_out);
+    cpp_gen::generate(&ctx, &cpp_out, &fingerprints, &mut outputs);
 
     // Generate module_deps.txt for Uika.Build.cs
-    generate_module_deps(codegen, &cpp_out);
+    generate_module_deps(codegen, &mut outputs);
+    outputs.finish();
 
     // Post-generate verification
     eprintln!("uika-codegen: verifying output...");
@@ -134,8 +207,53 @@ pub fn run_generate(config_path: &Path) {
     eprintln!("uika-codegen: done!");
 }
 
+/// Raw JSON texts of one UHT export directory.
+struct UhtExport {
+    dir: std::path::PathBuf,
+    classes: String,
+    structs: String,
+    enums: String,
+}
+
+impl UhtExport {
+    fn read(dir: &Path) -> Self {
+        let read = |name: &str| {
+            let path = dir.join(name);
+            std::fs::read_to_string(&path)
+                .unwrap_or_else(|e| panic!("Failed to read {}: {e}", path.display()))
+        };
+        UhtExport {
+            dir: dir.to_path_buf(),
+            classes: read("uika_classes.json"),
+            structs: read("uika_structs.json"),
+            enums: read("uika_enums.json"),
+        }
+    }
+
+    fn parse(&self) -> (ClassesFile, StructsFile, EnumsFile) {
+        let fail = |name: &str, e: serde_json::Error| -> ! {
+            panic!("Failed to parse {}: {e}", self.dir.join(name).display())
+        };
+        (
+            serde_json::from_str(&self.classes).unwrap_or_else(|e| fail("uika_classes.json", e)),
+            serde_json::from_str(&self.structs).unwrap_or_else(|e| fail("uika_structs.json", e)),
+            serde_json::from_str(&self.enums).unwrap_or_else(|e| fail("uika_enums.json", e)),
+        )
+    }
+}
+
+/// The `[codegen]` table re-serialized, so formatting and comments (and the
+/// unrelated `[ue]`/`[build]` sections) don't affect the binding stamp.
+fn codegen_section(config_str: &str) -> String {
+    let table: toml::Table = toml::from_str(config_str).unwrap_or_default();
+    table
+        .get("codegen")
+        .and_then(|v| toml::to_string(v).ok())
+        .unwrap_or_default()
+}
+
 /// Generate module_deps.txt listing UE module names needed by enabled features.
-fn generate_module_deps(config: &crate::config::CodegenConfig, cpp_out: &Path) {
+fn generate_module_deps(config: &crate::config::CodegenConfig, outputs: &mut incremental::Outputs) {
     use std::collections::BTreeSet;
 
     let enabled_features: std::collections::HashSet<&str> =
@@ -157,9 +275,7 @@ fn generate_module_deps(config: &crate::config::CodegenConfig, cpp_out: &Path) {
         .collect::<Vec<_>>()
         .join("\n");
 
-    let path = cpp_out.join("module_deps.txt");
-    std::fs::write(&path, &content)
-        .unwrap_or_else(|e| panic!("Failed to write {}: {e}", path.display()));
+    outputs.write(incremental::OutDir::Cpp, "module_deps.txt", &content);
 
     eprintln!("  module_deps.txt: {:?}", ue_modules.iter().collect::<Vec<_>>());
 }
@@ -168,20 +284,21 @@ fn generate_module_deps(config: &crate::config::CodegenConfig, cpp_out: &Path) {
 fn verify_output(ctx: &context::CodegenContext, rust_out: &Path, cpp_out: &Path) {
     let mut errors: Vec<String> = Vec::new();
 
-    // 1. FuncId contiguity: IDs must be 0..N-1 with no gaps
-    for (i, entry) in ctx.func_table.iter().enumerate() {
-        if entry.func_id != i as u32 {
+    // 1. FuncIds: unique and inside the table (gaps are retired ids)
+    let mut seen = std::collections::HashSet::with_capacity(ctx.func_table.len());
+    for entry in &ctx.func_table {
+        if entry.func_id >= ctx.func_count || !seen.insert(entry.func_id) {
             errors.push(format!(
-                "FuncId gap: expected {} for module '{}' {}.{}, got {} (total functions: {})",
-                i, entry.module_name, entry.class_name, entry.func_name,
-                entry.func_id, ctx.func_table.len()
+                "FuncId {} for module '{}' {}.{} is out of range or duplicated (table size: {})",
+                entry.func_id, entry.module_name, entry.class_name, entry.func_name,
+                ctx.func_count
             ));
             break; // one error is enough to flag the issue
         }
     }
 
     // 2. Required Rust output files exist and are non-empty
-    let rust_required = ["lib.rs", "func_ids.rs"];
+    let rust_required = ["lib.rs", "func_ids.rs", "skipped.rs"];
     for name in &rust_required {
         let path = rust_out.join(name);
         match std::fs::metadata(&path) {
@@ -231,20 +348,37 @@ fn verify_output(ctx: &context::CodegenContext, rust_out: &Path, cpp_out: &Path)
     }
 }
 
-/// Assign deterministic FuncIds to all exportable functions.
-fn build_func_table(ctx: &mut context::CodegenContext) {
+/// Collect all exportable functions and assign their FuncIds from `id_map`.
+/// Functions left out here are added to `report`.
+fn build_func_table(
+    ctx: &mut context::CodegenContext,
+    id_map: &mut id_map::FuncIdMap,
+    report: &mut filter::FilterReport,
+) {
+    use filter::{SkipCategory, SkipReason, SkipStage};
+
     let mut entries = Vec::new();
 
     for (module_name, classes) in &ctx.module_classes {
         for class in classes {
             // Skip UInterface classes — their functions can't be called directly
             if class.super_class.as_deref() == Some("Interface") {
+                for func in &class.funcs {
+                    report.exclude(
+                        "function",
+                        SkipStage::FuncTable,
+                        module_name,
+                        format!("{}.{}", class.name, func.name),
+                        SkipReason::new(SkipCategory::NotCallable, "declared on a UInterface".to_string())
+                            .offending(class.name.as_str()),
+                    );
+                }
                 continue;
             }
             for func in &class.funcs {
                 // Skip functions with unsupported param types
-                let all_supported = func.params.iter().all(|p| {
-                    type_map::map_property_type(
+                let unsupported = func.params.iter().find(|p| {
+                    !type_map::map_property_type(
                         &p.prop_type,
                         p.class_name.as_deref(),
                         p.struct_name.as_deref(),
@@ -255,7 +389,15 @@ fn build_func_table(ctx: &mut context::CodegenContext) {
                     )
                     .supported
                 });
-                if !all_supported {
+                if let Some(p) = unsupported {
+                    let reason = format!("parameter `{}` has no Rust mapping for `{}`", p.name, p.prop_type);
+                    report.exclude(
+                        "function",
+                        SkipStage::FuncTable,
+                        module_name,
+                        format!("{}.{}", class.name, func.name),
+                        SkipReason::new(SkipCategory::UnsupportedType, reason).offending_param(p),
+                    );
                     continue;
                 }
                 entries.push(context::FuncEntry {
@@ -272,7 +414,8 @@ fn build_func_table(ctx: &mut context::CodegenContext) {
         }
     }
 
-    // Sort by (module, class, func) for deterministic IDs
+    // Sort by (module, class, func): deterministic output, and the order new
+    // IDs are appended in
     entries.sort_by(|a, b| {
         a.module_name
             .cmp(&b.module_name)
@@ -280,10 +423,12 @@ fn build_func_table(ctx: &mut context::CodegenContext) {
             .then_with(|| a.func_name.cmp(&b.func_name))
     });
 
-    // Assign sequential IDs
-    for (i, entry) in entries.iter_mut().enumerate() {
-        entry.func_id = i as u32;
-    }
+    let stats = id_map.assign(&mut entries);
+    eprintln!(
+        "  FuncIds: {} kept, {} new, {} retired",
+        stats.kept, stats.added, stats.retired
+    );
 
     ctx.func_table = entries;
+    ctx.func_count = id_map.func_count();
 }
@@ -0,0 +1,181 @@
+// Name patterns for the codegen allow- and blocklists.
+//
+// An entry is one of:
+//   - an exact name:      "KismetArrayLibrary"
+//   - a glob:             "*Library", "Actor.K2_*", "Get?"
+//                         (`*` any run of characters, `?` one character)
+//   - a regex, prefixed:  "re:^(Get|Set)[A-Z].*Path$"
+//
+// Patterns always match the whole name; for functions and properties that is
+// the qualified "Owner.Member" string. Regexes use the `regex` crate's syntax
+// and run in linear time, so a config entry cannot stall codegen.
+
+use std::fmt;
+
+use regex::Regex;
+
+/// One parsed allow/blocklist entry.
+pub struct NamePattern {
+    source: String,
+    kind: Kind,
+}
+
+enum Kind {
+    Exact,
+    Glob(Vec<char>),
+    Regex(Regex),
+}
+
+impl NamePattern {
+    /// Parse a config entry.
+    pub fn parse(source: &str) -> Result<Self, String> {
+        let kind = if let Some(re) = source.strip_prefix("re:") {
+            // Anchored on both ends: the whole name must match.
+            Kind::Regex(Regex::new(&format!("^(?:{re})$")).map_err(|e| e.to_string())?)
+        } else if source.contains(['*', '?']) {
+            Kind::Glob(source.chars().collect())
+        } else {
+            Kind::Exact
+        };
+        Ok(NamePattern { source: source.to_string(), kind })
+    }
+
+    /// The entry as written in the config.
+    pub fn as_str(&self) -> &str {
+        &self.source
+    }
+
+    /// Whether the pattern matches the whole of `name`.
+    pub fn matches(&self, name: &str) -> bool {
+        match &self.kind {
+            Kind::Exact => self.source == name,
+            Kind::Glob(glob) => {
+                let name: Vec<char> = name.chars().collect();
+                glob_match(glob, &name)
+            }
+            Kind::Regex(re) => re.is_match(name),
+        }
+    }
+}
+
+impl fmt::Debug for NamePattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self.source)
+    }
+}
+
+/// A list of patterns for one kind of item.
+#[derive(Debug, Default)]
+pub struct PatternSet {
+    patterns: Vec<NamePattern>,
+}
+
+impl PatternSet {
+    /// Parse every entry, panicking with the offending entry on a bad regex
+    /// (codegen reports config errors by panicking).
+    pub fn parse(entries: &[String], what: &str) -> Self {
+        let patterns = entries
+            .iter()
+            .map(|e| {
+                NamePattern::parse(e)
+                    .unwrap_or_else(|err| panic!("Invalid {what} pattern {e:?}: {err}"))
+            })
+            .collect();
+        PatternSet { patterns }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.patterns.is_empty()
+    }
+
+    /// The first pattern matching `name`, as written in the config.
+    pub fn find(&self, name: &str) -> Option<&str> {
+        self.patterns.iter().find(|p| p.matches(name)).map(|p| p.as_str())
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Glob
+// ---------------------------------------------------------------------------
+
+fn glob_match(glob: &[char], name: &[char]) -> bool {
+    // Iterative matcher with single-star backtracking.
+    let (mut g, mut n) = (0, 0);
+    let mut star: Option<(usize, usize)> = None;
+    while n < name.len() {
+        match glob.get(g) {
+            Some('*') => {
+                star = Some((g, n));
+                g += 1;
+            }
+            Some('?') => {
+                g += 1;
+                n += 1;
+            }
+            Some(&c) if c == name[n] => {
+                g += 1;
+                n += 1;
+            }
+            _ => match star {
+                Some((sg, sn)) => {
+                    g = sg + 1;
+                    n = sn + 1;
+                    star = Some((sg, sn + 1));
+                }
+                None => return false,
+            },
+        }
+    }
+    glob[g..].iter().all(|&c| c == '*')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn m(pattern: &str, name: &str) -> bool {
+        NamePattern::parse(pattern).unwrap().matches(name)
+    }
+
+    #[test]
+    fn test_exact_and_glob() {
+        assert!(m("Actor", "Actor"));
+        assert!(!m("Actor", "Actors"));
+        assert!(m("*Library", "KismetArrayLibrary"));
+        assert!(!m("*Library", "LibraryHelper"));
+        assert!(m("Actor.K2_*", "Actor.K2_GetActorLocation"));
+        assert!(m("*.Get?", "Pawn.GetX"));
+        assert!(!m("*.Get?", "Pawn.GetXY"));
+        assert!(m("*a*b*", "xxaxxbxx"));
+    }
+
+    #[test]
+    fn test_regex() {
+        assert!(m("re:(Get|Set)[A-Z].*Path", "GetStructTopLevelAssetPath"));
+        assert!(!m("re:(Get|Set)[A-Z].*Path", "GetStructTopLevelAssetPaths"));
+        assert!(m(r"re:^K2_\w+$", "K2_GetActorLocation"));
+        assert!(!m(r"re:K2_\w+", "Actor.K2_Foo"));
+        assert!(m(r"re:.*\.K2_\w+", "Actor.K2_Foo"));
+        assert!(m("re:[^.]+\\.Is[A-Z]\\w*", "Pawn.IsPlayerControlled"));
+        assert!(m("re:a{2,3}b?", "aaa"));
+        assert!(!m("re:a{2,3}b?", "aaaa"));
+        assert!(m("re:(?:ab)*", ""));
+        assert!(m("re:(a*)*b", "aaab"));
+        assert!(!m("re:a|b", "ab"));
+    }
+
+    #[test]
+    fn test_regex_is_not_exponential() {
+        let name = format!("{}b", "a".repeat(64));
+        assert!(!m("re:(a|aa)*c", &name));
+        assert!(!m("re:(a*)*c", &name));
+    }
+
+    #[test]
+    fn test_regex_errors() {
+        assert!(NamePattern::parse("re:(abc").is_err());
+        assert!(NamePattern::parse("re:*abc").is_err());
+        assert!(NamePattern::parse("re:[z-a]").is_err());
+        assert!(NamePattern::parse("re:a{3,1}").is_err());
+    }
+}
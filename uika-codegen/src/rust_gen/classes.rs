@@ -4,13 +4,16 @@ use std::collections::HashSet;
 
 use crate::context::{CodegenContext, FuncEntry};
 use crate::defaults;
+use crate::filter::{SkipCategory, SkipReason};
 use crate::naming::{escape_reserved, to_snake_case};
 use crate::schema::*;
 use crate::type_map::{self, ConversionKind, MappedType, ParamDirection};
 
 use super::delegates;
+use super::docs;
 use super::param_helpers;
 use super::properties::{self, PropertyContext};
+use super::skipped::MemberSkips;
 
 /// Generate Rust code for a single UE class.
 pub fn generate_class(class: &ClassInfo, ctx: &CodegenContext) -> String {
@@ -35,10 +38,8 @@ pub fn generate_class(class: &ClassInfo, ctx: &CodegenContext) -> String {
 
     // Use the JSON `name` as the Rust struct name.
     // This keeps it consistent with UE naming (Actor, Pawn, etc.)
-    out.push_str(&format!(
-        "/// UE class `{cpp_name}`.\n\
-         pub struct {name};\n\n"
-    ));
+    docs::emit_doc(&mut out, "", &docs::class_doc(class));
+    out.push_str(&format!("pub struct {name};\n\n"));
 
     // UeClass trait impl
     let name_bytes_len = name.len();
@@ -75,20 +76,8 @@ pub fn generate_class(class: &ClassInfo, ctx: &CodegenContext) -> String {
         }
     }
 
-    // Collect own functions only (inherited methods are accessed via Deref chain)
-    let mut seen_func_names: HashSet<String> = HashSet::new();
-    let mut class_funcs: Vec<&FuncEntry> = Vec::new();
-    for entry in ctx.func_table.iter().filter(|e| e.class_name == *name) {
-        if seen_func_names.insert(entry.rust_func_name.clone()) {
-            class_funcs.push(entry);
-        }
-    }
-
-    // Collect own property accessor names, deduplicating
-    let (mut prop_names, deduped_props) = properties::collect_deduped_properties(&class.props, Some(ctx));
-
-    // Collect own delegate properties
-    let own_delegate_infos = delegates::collect_delegate_props(&class.props, name, ctx);
+    let ClassMembers { funcs: class_funcs, props: deduped_props, delegates: own_delegate_infos, suppress_setters } =
+        class_members(class, ctx, &mut MemberSkips::default());
 
     // Only generate extension trait if there are own properties, functions, or delegates
     if deduped_props.is_empty() && class_funcs.is_empty()
@@ -97,30 +86,6 @@ pub fn generate_class(class: &ClassInfo, ctx: &CodegenContext) -> String {
         return out;
     }
 
-    // Detect setter-function collisions: when a UFUNCTION matches a property setter name,
-    // keep the UFUNCTION and suppress the setter (Option B from TODO_IMPROVEMENTS)
-    let func_names: HashSet<String> = class_funcs
-        .iter()
-        .map(|e| escape_reserved(&e.rust_func_name))
-        .collect();
-
-    let suppress_setters: HashSet<String> = prop_names
-        .iter()
-        .filter(|n| n.starts_with("set_") && func_names.contains(n.as_str()))
-        .cloned()
-        .collect();
-
-    // Remove suppressed setters from prop_names so they don't block UFUNCTIONs
-    for setter in &suppress_setters {
-        prop_names.remove(setter);
-    }
-
-    // Filter out functions whose names still collide with remaining property accessors
-    let class_funcs: Vec<&FuncEntry> = class_funcs
-        .into_iter()
-        .filter(|e| !prop_names.contains(&escape_reserved(&e.rust_func_name)))
-        .collect();
-
     // PropertyContext for class properties
     let pctx = PropertyContext {
         find_prop_fn: "find_property".to_string(),
@@ -128,6 +93,7 @@ pub fn generate_class(class: &ClassInfo, ctx: &CodegenContext) -> String {
         pre_access: "let h = self.handle();".to_string(),
         container_expr: "h".to_string(),
         is_class: true,
+        owner_cpp: cpp_name.clone(),
     };
 
     // Generate delegate wrapper structs (own only)
@@ -150,7 +116,9 @@ pub fn generate_class(class: &ClassInfo, ctx: &CodegenContext) -> String {
 
     // Function wrapper default impls
     for entry in &class_funcs {
+        let start = out.len();
         generate_function(&mut out, entry, &entry.class_name, ctx);
+        docs::insert_before_fns(&mut out, start, &docs::function_doc(&entry.func, cpp_name));
     }
 
     out.push_str("}\n\n");
@@ -166,6 +134,115 @@ pub fn generate_class(class: &ClassInfo, ctx: &CodegenContext) -> String {
     out
 }
 
+/// The members of a class that get Rust accessors.
+pub(super) struct ClassMembers<'a> {
+    /// Own functions (inherited methods are accessed via Deref chain).
+    pub funcs: Vec<&'a FuncEntry>,
+    pub props: Vec<&'a PropertyInfo>,
+    pub delegates: Vec<delegates::DelegateInfo<'a>>,
+    /// Property setters replaced by a UFUNCTION of the same name.
+    pub suppress_setters: HashSet<String>,
+}
+
+/// Select the members of `class` to generate, adding the ones left out (and
+/// the functions [`function_skip`] rejects) to `skipped`.
+pub(super) fn class_members<'a>(
+    class: &'a ClassInfo,
+    ctx: &'a CodegenContext,
+    skipped: &mut MemberSkips,
+) -> ClassMembers<'a> {
+    let name = &class.name;
+
+    // Collect own functions only (inherited methods are accessed via Deref chain)
+    let mut seen_func_names: HashSet<String> = HashSet::new();
+    let mut class_funcs: Vec<&FuncEntry> = Vec::new();
+    for entry in ctx.func_table.iter().filter(|e| e.class_name == *name) {
+        if seen_func_names.insert(entry.rust_func_name.clone()) {
+            class_funcs.push(entry);
+        } else {
+            let reason = format!("Rust name `{}` already used by another function", entry.rust_func_name);
+            skipped.function(&entry.func_name, SkipReason::new(SkipCategory::Duplicate, reason));
+        }
+    }
+
+    // Collect own property accessor names, deduplicating
+    let (mut prop_names, deduped_props) = properties::collect_deduped_properties(&class.props, Some(ctx), skipped);
+
+    // Collect own delegate properties
+    let own_delegate_infos = delegates::collect_delegate_props(&class.props, name, ctx, skipped);
+
+    // Detect setter-function collisions: when a UFUNCTION matches a property setter name,
+    // keep the UFUNCTION and suppress the setter (Option B from TODO_IMPROVEMENTS)
+    let func_names: HashSet<String> = class_funcs
+        .iter()
+        .map(|e| escape_reserved(&e.rust_func_name))
+        .collect();
+
+    let suppress_setters: HashSet<String> = prop_names
+        .iter()
+        .filter(|n| n.starts_with("set_") && func_names.contains(n.as_str()))
+        .cloned()
+        .collect();
+
+    // Remove suppressed setters from prop_names so they don't block UFUNCTIONs
+    for setter in &suppress_setters {
+        prop_names.remove(setter);
+    }
+
+    // Filter out functions whose names still collide with remaining property accessors
+    let class_funcs: Vec<&FuncEntry> = class_funcs
+        .into_iter()
+        .filter(|e| {
+            let rust_name = escape_reserved(&e.rust_func_name);
+            let collides = prop_names.contains(&rust_name);
+            if collides {
+                let reason = format!("Rust name `{rust_name}` already used by a property accessor");
+                skipped.function(&e.func_name, SkipReason::new(SkipCategory::Duplicate, reason));
+            }
+            !collides
+        })
+        .collect();
+
+    // Kept in `funcs` (and emitted as a "Skipped" comment) so the trait is
+    // generated exactly as before.
+    for entry in &class_funcs {
+        if let Some(skip) = function_skip(entry, ctx) {
+            skipped.function(&entry.func_name, skip);
+        }
+    }
+
+    ClassMembers {
+        funcs: class_funcs,
+        props: deduped_props,
+        delegates: own_delegate_infos,
+        suppress_setters,
+    }
+}
+
+/// Why no wrapper can be generated for `entry`, if none can: a parameter
+/// whose type (or, for containers, element type) has no Rust mapping.
+pub(super) fn function_skip(entry: &FuncEntry, ctx: &CodegenContext) -> Option<SkipReason> {
+    let has_container = entry.func.params.iter().any(is_container_param);
+    for param in &entry.func.params {
+        let pname = &param.name;
+        if has_container && is_container_param(param) {
+            let dir = type_map::param_direction(param);
+            let input_ok = !matches!(dir, ParamDirection::In | ParamDirection::InOut)
+                || container_param_input_type(param, ctx).is_some();
+            let output_ok = !matches!(dir, ParamDirection::Out | ParamDirection::Return | ParamDirection::InOut)
+                || container_param_output_type(param, ctx).is_some();
+            if !(input_ok && output_ok) {
+                let reason = format!("container parameter `{pname}` has an unsupported element type");
+                return Some(SkipReason::new(SkipCategory::UnsupportedType, reason).offending_param(param));
+            }
+        } else if !type_map::map_param_type(param).supported {
+            let reason = format!("parameter `{pname}` has unsupported type `{}`", param.prop_type);
+            return Some(SkipReason::new(SkipCategory::UnsupportedType, reason).offending_param(param));
+        }
+    }
+    None
+}
+
 // ---------------------------------------------------------------------------
 // Container param helpers (delegated to type_map)
 // ---------------------------------------------------------------------------
@@ -185,14 +262,18 @@ fn build_return_type(output_types: &[String]) -> String {
 }
 
 /// Get the Rust type for a scalar Out/InOut param or ReturnValue in a return tuple.
-/// StructOpaque returns `OwnedStruct<FStructName>` when the struct has UeStruct,
-/// otherwise falls back to the raw pointer type.
+/// StructOpaque returns `OwnedStruct<FStructName>` when the struct has UeStruct
+/// (or its decoded type, see [`type_map::decoded_struct_type`]), otherwise
+/// falls back to the raw pointer type.
 fn scalar_out_rust_type_ctx(mapped: &MappedType, struct_name: Option<&str>, ctx: &CodegenContext) -> String {
     match mapped.ffi_to_rust {
         ConversionKind::StructOpaque => {
             if let Some(sn) = struct_name {
                 if let Some(si) = ctx.structs.get(sn) {
                     if si.has_static_struct {
+                        if let Some(decoded) = type_map::decoded_struct_type(Some(sn)) {
+                            return decoded.to_string();
+                        }
                         return format!("uika_runtime::OwnedStruct<{}>", si.cpp_name);
                     }
                 }
@@ -227,11 +308,18 @@ pub(super) fn is_scalar_output_returnable(dir: ParamDirection, mapped: &MappedTy
 
 /// Generate a function wrapper (direct call via func_table).
 fn generate_function(out: &mut String, entry: &FuncEntry, class_name: &str, ctx: &CodegenContext) {
+    if let Some(skip) = function_skip(entry, ctx) {
+        out.push_str(&format!(
+            "    // Skipped: {class_name}.{} ({}; listed in crate::skipped)\n\n",
+            entry.func_name, skip.reason
+        ));
+        return;
+    }
     let has_container = entry.func.params.iter().any(|p| is_container_param(p));
     if has_container {
         generate_container_function(out, entry, class_name, ctx);
     } else {
-        generate_scalar_function(out, entry, class_name, ctx);
+        generate_scalar_function(out, entry, ctx);
     }
 }
 
@@ -239,7 +327,7 @@ fn generate_function(out: &mut String, entry: &FuncEntry, class_name: &str, ctx:
 // Scalar function implementation (no container params — original path)
 // ---------------------------------------------------------------------------
 
-fn generate_scalar_function(out: &mut String, entry: &FuncEntry, class_name: &str, ctx: &CodegenContext) {
+fn generate_scalar_function(out: &mut String, entry: &FuncEntry, ctx: &CodegenContext) {
     let func = &entry.func;
     let rust_fn_name = escape_reserved(&entry.rust_func_name);
     let func_id = entry.func_id;
@@ -254,47 +342,16 @@ fn generate_scalar_function(out: &mut String, entry: &FuncEntry, class_name: &st
         }
     }
 
-    // Map types for all params
-    let mut all_mapped: Vec<(&ParamInfo, ParamDirection, MappedType)> = Vec::new();
-    let mut all_supported = true;
-
-    for param in &func.params {
-        let dir = type_map::param_direction(param);
-        let mapped = type_map::map_property_type(
-            &param.prop_type,
-            param.class_name.as_deref(),
-            param.struct_name.as_deref(),
-            param.enum_name.as_deref(),
-            param.enum_underlying_type.as_deref(),
-            param.meta_class_name.as_deref(),
-            param.interface_name.as_deref(),
-        );
-        if !mapped.supported {
-            all_supported = false;
-            break;
-        }
-        all_mapped.push((param, dir, mapped));
-    }
-
-    if !all_supported {
-        out.push_str(&format!(
-            "    // Skipped: {}.{} (unsupported param type)\n\n",
-            class_name, func.name
-        ));
-        return;
-    }
+    // Map types for all params (all supported, see `function_skip`)
+    let all_mapped: Vec<(&ParamInfo, ParamDirection, MappedType)> = func
+        .params
+        .iter()
+        .map(|param| (param, type_map::param_direction(param), type_map::map_param_type(param)))
+        .collect();
 
     // Determine return type
     let ret_mapped = return_param.map(|rp| {
-        type_map::map_property_type(
-            &rp.prop_type,
-            rp.class_name.as_deref(),
-            rp.struct_name.as_deref(),
-            rp.enum_name.as_deref(),
-            rp.enum_underlying_type.as_deref(),
-            rp.meta_class_name.as_deref(),
-            rp.interface_name.as_deref(),
-        )
+        type_map::map_param_type(rp)
     });
 
     // Build return type: ReturnValue + all Out/InOut scalar params
@@ -330,6 +387,7 @@ fn generate_scalar_function(out: &mut String, entry: &FuncEntry, class_name: &st
     // Input params
     let mut param_names = Vec::new();
     let mut default_unwraps: Vec<(String, String)> = Vec::new(); // (pname, default_expr)
+    let mut soft_borrows: Vec<String> = Vec::new(); // soft pointer params passed as their path
     for (param, dir, mapped) in &all_mapped {
         if *dir == ParamDirection::Return {
             continue;
@@ -345,6 +403,10 @@ fn generate_scalar_function(out: &mut String, entry: &FuncEntry, class_name: &st
         match dir {
             ParamDirection::In | ParamDirection::InOut => {
                 match mapped.rust_to_ffi {
+                    ConversionKind::StringUtf8 if type_map::is_soft_ptr_type(&mapped.rust_type) => {
+                        sig.push_str(&format!("{pname}: &{}, ", mapped.rust_type));
+                        soft_borrows.push(pname.clone());
+                    }
                     ConversionKind::StringUtf8 => {
                         if has_default {
                             sig.push_str(&format!("{pname}: Option<&str>, "));
@@ -399,6 +461,9 @@ fn generate_scalar_function(out: &mut String, entry: &FuncEntry, class_name: &st
     for (pname, default_expr) in &default_unwraps {
         out.push_str(&format!("        let {pname} = {pname}.unwrap_or({default_expr});\n"));
     }
+    for pname in &soft_borrows {
+        out.push_str(&format!("        let {pname} = {pname}.path();\n"));
+    }
 
     // FFI dispatch: load wrapper pointer from func_table and transmute to typed fn.
     out.push_str("        {\n");
@@ -446,7 +511,7 @@ fn generate_scalar_function(out: &mut String, entry: &FuncEntry, class_name: &st
                         ffi_params.push_str("*mut uika_runtime::UObjectHandle, ");
                     }
                     ConversionKind::StructOpaque => {
-                        ffi_params.push_str("*mut u8, ");
+                        ffi_params.push_str("*mut u8, u32, *mut u32, ");
                     }
                     ConversionKind::EnumCast => {
                         ffi_params.push_str(&format!("*mut {}, ", mapped.rust_ffi_type));
@@ -466,7 +531,7 @@ fn generate_scalar_function(out: &mut String, entry: &FuncEntry, class_name: &st
     out.push_str(&format!(
         "        const FN_ID: u32 = {func_id};\n\
          \x20       type Fn = unsafe extern \"C\" fn({ffi_params}) -> uika_runtime::UikaErrorCode;\n\
-         \x20       let __uika_fn: Fn = unsafe {{ std::mem::transmute(*(uika_runtime::api().func_table.add(FN_ID as usize))) }};\n"
+         \x20       let __uika_fn: Fn = unsafe {{ std::mem::transmute(uika_runtime::func_ptr(FN_ID)) }};\n"
     ));
 
     // Get handle for instance methods (pre-validated via ValidHandle)
@@ -475,7 +540,7 @@ fn generate_scalar_function(out: &mut String, entry: &FuncEntry, class_name: &st
     }
 
     // Declare output variables
-    if let Some(_rp) = return_param {
+    if let Some(rp) = return_param {
         let rm = ret_mapped.as_ref().expect("return param must have mapped type");
         match rm.ffi_to_rust {
             ConversionKind::ObjectRef => {
@@ -489,7 +554,9 @@ fn generate_scalar_function(out: &mut String, entry: &FuncEntry, class_name: &st
                 out.push_str(&format!("        let mut _ret: {} = 0;\n", rm.rust_ffi_type));
             }
             ConversionKind::StructOpaque => {
-                out.push_str("        let mut _ret_struct_buf = vec![0u8; 256];\n");
+                let len = param_helpers::struct_buf_len(rp.struct_name.as_deref(), ctx);
+                out.push_str(&format!("        let mut _ret_struct_buf = vec![0u8; {len}];\n"));
+                out.push_str("        let mut _ret_struct_size: u32 = 0;\n");
             }
             _ => {
                 let default = properties::default_value_for(&rm.rust_ffi_type);
@@ -500,7 +567,7 @@ fn generate_scalar_function(out: &mut String, entry: &FuncEntry, class_name: &st
 
     for (param, dir, mapped) in &all_mapped {
         if *dir == ParamDirection::Out {
-            param_helpers::emit_out_param_var_decl(out, param, mapped);
+            param_helpers::emit_out_param_var_decl(out, param, mapped, ctx);
         }
         if *dir == ParamDirection::InOut {
             param_helpers::emit_inout_string_buf_decl(out, param, mapped);
@@ -508,9 +575,9 @@ fn generate_scalar_function(out: &mut String, entry: &FuncEntry, class_name: &st
     }
 
     // Build the FFI call (infallible after pre-validation)
-    out.push_str("        uika_runtime::ffi_infallible(unsafe { __uika_fn(");
+    let mut call = String::from("__uika_fn(");
     if !is_static {
-        out.push_str("h, ");
+        call.push_str("h, ");
     }
     for (param, dir, mapped) in &all_mapped {
         let pname = escape_reserved(&to_snake_case(&param.name));
@@ -518,38 +585,38 @@ fn generate_scalar_function(out: &mut String, entry: &FuncEntry, class_name: &st
             ParamDirection::In | ParamDirection::InOut => {
                 match mapped.rust_to_ffi {
                     ConversionKind::StringUtf8 => {
-                        out.push_str(&format!("{pname}.as_ptr(), {pname}.len() as u32, "));
+                        call.push_str(&format!("{pname}.as_ptr(), {pname}.len() as u32, "));
                         // InOut strings also pass output buffer params
                         if *dir == ParamDirection::InOut {
-                            out.push_str(&format!("{pname}_buf.as_mut_ptr(), {pname}_buf.len() as u32, &mut {pname}_len, "));
+                            call.push_str(&format!("{pname}_buf.as_mut_ptr(), {pname}_buf.len() as u32, &mut {pname}_len, "));
                         }
                     }
                     ConversionKind::ObjectRef => {
-                        out.push_str(&format!("{pname}.raw(), "));
+                        call.push_str(&format!("{pname}.raw(), "));
                     }
                     ConversionKind::EnumCast => {
-                        out.push_str(&format!("{pname} as {}, ", mapped.rust_ffi_type));
+                        call.push_str(&format!("{pname} as {}, ", mapped.rust_ffi_type));
                     }
                     ConversionKind::StructOpaque if *dir == ParamDirection::In
                         && is_struct_owned(param.struct_name.as_deref(), ctx) =>
                     {
-                        out.push_str(&format!("{pname}.as_bytes().as_ptr(), "));
+                        call.push_str(&format!("{pname}.as_bytes().as_ptr(), "));
                     }
                     _ => {
-                        out.push_str(&format!("{pname}, "));
+                        call.push_str(&format!("{pname}, "));
                     }
                 }
             }
             ParamDirection::Out => {
                 match mapped.ffi_to_rust {
                     ConversionKind::StructOpaque => {
-                        out.push_str(&format!("{pname}_buf.as_mut_ptr(), "));
+                        call.push_str(&format!("{pname}_buf.as_mut_ptr(), {pname}_buf.len() as u32, &mut {pname}_size, "));
                     }
                     ConversionKind::StringUtf8 => {
-                        out.push_str(&format!("{pname}_buf.as_mut_ptr(), {pname}_buf.len() as u32, &mut {pname}_len, "));
+                        call.push_str(&format!("{pname}_buf.as_mut_ptr(), {pname}_buf.len() as u32, &mut {pname}_len, "));
                     }
                     _ => {
-                        out.push_str(&format!("&mut {pname}, "));
+                        call.push_str(&format!("&mut {pname}, "));
                     }
                 }
             }
@@ -557,27 +624,54 @@ fn generate_scalar_function(out: &mut String, entry: &FuncEntry, class_name: &st
                 let rm = ret_mapped.as_ref().expect("return param must have mapped type");
                 match rm.ffi_to_rust {
                     ConversionKind::StringUtf8 => {
-                        out.push_str("_ret_buf.as_mut_ptr(), _ret_buf.len() as u32, &mut _ret_len, ");
+                        call.push_str("_ret_buf.as_mut_ptr(), _ret_buf.len() as u32, &mut _ret_len, ");
                     }
                     ConversionKind::ObjectRef => {
-                        out.push_str("&mut _ret, ");
+                        call.push_str("&mut _ret, ");
                     }
                     ConversionKind::StructOpaque => {
-                        out.push_str("_ret_struct_buf.as_mut_ptr(), ");
+                        call.push_str("_ret_struct_buf.as_mut_ptr(), _ret_struct_buf.len() as u32, &mut _ret_struct_size, ");
                     }
                     _ => {
-                        out.push_str("&mut _ret, ");
+                        call.push_str("&mut _ret, ");
                     }
                 }
             }
         }
     }
     // Remove trailing comma+space in the call args
-    let out_len = out.len();
-    if out.ends_with(", ") {
-        out.truncate(out_len - 2);
+    if call.ends_with(", ") {
+        call.truncate(call.len() - 2);
+    }
+    call.push(')');
+    let mut sized_outputs = Vec::new();
+    if let (Some(rp), Some(rm)) = (return_param, &ret_mapped) {
+        match rm.ffi_to_rust {
+            ConversionKind::StringUtf8 => sized_outputs.push(param_helpers::SizedOutput::string(
+                "_ret_buf",
+                "_ret_len",
+                param_helpers::stash_slot(&func.params, rp),
+            )),
+            ConversionKind::StructOpaque => {
+                sized_outputs.push(param_helpers::SizedOutput::structure("_ret_struct_buf", "_ret_struct_size"));
+            }
+            _ => {}
+        }
+    }
+    sized_outputs.extend(
+        all_mapped.iter().filter_map(|(p, _, m)| param_helpers::sized_output_vars(&func.params, p, m)),
+    );
+    if sized_outputs.is_empty() {
+        out.push_str(&format!("        uika_runtime::ffi_infallible(unsafe {{ {call} }});\n"));
+    } else {
+        // Outputs that did not fit would come back truncated: fail loudly in
+        // every build.
+        param_helpers::emit_ffi_call(out, "__result", &call, &sized_outputs);
+        out.push_str(&format!(
+            "        uika_runtime::ffi_outputs_complete(__result, \"{}.{}\");\n",
+            entry.class_name, entry.func_name
+        ));
     }
-    out.push_str(") });\n");
 
     // Return conversion: assemble ReturnValue + Out/InOut params (infallible)
     {
@@ -588,12 +682,13 @@ fn generate_scalar_function(out: &mut String, entry: &FuncEntry, class_name: &st
             let rm = ret_mapped.as_ref().expect("return param must have mapped type");
             match rm.ffi_to_rust {
                 ConversionKind::ObjectRef => {
-                    return_parts.push("unsafe { uika_runtime::UObjectRef::from_raw(_ret) }".to_string());
+                    let ctor = type_map::object_ref_ctor(&rm.rust_type);
+                    return_parts.push(format!("unsafe {{ {ctor}(_ret) }}"));
                 }
                 ConversionKind::StringUtf8 => {
                     out.push_str("        _ret_buf.truncate(_ret_len as usize);\n");
                     out.push_str("        let _ret_str = String::from_utf8_lossy(&_ret_buf).into_owned();\n");
-                    return_parts.push("_ret_str".to_string());
+                    return_parts.push(type_map::string_to_rust_expr(&rm.rust_type, "_ret_str"));
                 }
                 ConversionKind::EnumCast => {
                     let rt = &rm.rust_type;
@@ -607,7 +702,9 @@ fn generate_scalar_function(out: &mut String, entry: &FuncEntry, class_name: &st
                 ConversionKind::StructOpaque => {
                     let rp = return_param.expect("return_param must be Some in return conversion");
                     if is_struct_owned(rp.struct_name.as_deref(), ctx) {
-                        out.push_str("        let _ret_owned = uika_runtime::OwnedStruct::from_bytes(_ret_struct_buf);\n");
+                        out.push_str("        _ret_struct_buf.truncate(_ret_struct_size as usize);\n");
+                        let owned = param_helpers::owned_struct_expr(rp.struct_name.as_deref(), "_ret_struct_buf", ctx);
+                        out.push_str(&format!("        let _ret_owned = {owned};\n"));
                         return_parts.push("_ret_owned".to_string());
                     } else {
                         out.push_str("        let _ret_ptr = _ret_struct_buf.as_ptr();\n");
@@ -670,50 +767,11 @@ fn generate_container_function(out: &mut String, entry: &FuncEntry, class_name:
     }
     let n_containers = container_params.len();
 
-    // Classify all params and check support
-    let mut return_param: Option<&ParamInfo> = None;
-    let mut all_supported = true;
-
-    for param in &func.params {
-        let dir = type_map::param_direction(param);
-        if dir == ParamDirection::Return {
-            return_param = Some(param);
-        }
-        if is_container_param(param) {
-            if (dir == ParamDirection::In || dir == ParamDirection::InOut)
-                && container_param_input_type(param, ctx).is_none()
-            {
-                all_supported = false;
-                break;
-            }
-            if (dir == ParamDirection::Out || dir == ParamDirection::Return || dir == ParamDirection::InOut)
-                && container_param_output_type(param, ctx).is_none()
-            {
-                all_supported = false;
-                break;
-            }
-        } else {
-            let mapped = type_map::map_property_type(
-                &param.prop_type, param.class_name.as_deref(),
-                param.struct_name.as_deref(), param.enum_name.as_deref(),
-                param.enum_underlying_type.as_deref(),
-                param.meta_class_name.as_deref(),
-                param.interface_name.as_deref(),
-            );
-            if !mapped.supported {
-                all_supported = false;
-                break;
-            }
-        }
-    }
-
-    if !all_supported {
-        out.push_str(&format!(
-            "    // Skipped: {}.{} (unsupported container inner type)\n\n",
-            class_name, func.name
-        ));
-        return;
-    }
+    // Find the return param (all params are supported, see `function_skip`)
+    let return_param: Option<&ParamInfo> = func
+        .params
+        .iter()
+        .find(|p| type_map::param_direction(p) == ParamDirection::Return);
 
     // Build return type
     let mut output_types = Vec::new();
@@ -724,13 +782,7 @@ fn generate_container_function(out: &mut String, entry: &FuncEntry, class_name:
             output_types.push(container_param_output_type(rp, ctx)
                     .expect("container return type should be resolvable"));
         } else {
-            let rm = type_map::map_property_type(
-                &rp.prop_type, rp.class_name.as_deref(),
-                rp.struct_name.as_deref(), rp.enum_name.as_deref(),
-                rp.enum_underlying_type.as_deref(),
-                rp.meta_class_name.as_deref(),
-                rp.interface_name.as_deref(),
-            );
+            let rm = type_map::map_param_type(rp);
             output_types.push(scalar_out_rust_type_ctx(&rm, rp.struct_name.as_deref(), ctx));
             scalar_return_mapped = Some(rm);
         }
@@ -742,13 +794,7 @@ fn generate_container_function(out: &mut String, entry: &FuncEntry, class_name:
                 output_types.push(container_param_output_type(param, ctx)
                         .expect("container out-param type should be resolvable"));
             } else {
-                let rm = type_map::map_property_type(
-                    &param.prop_type, param.class_name.as_deref(),
-                    param.struct_name.as_deref(), param.enum_name.as_deref(),
-                    param.enum_underlying_type.as_deref(),
-                    param.meta_class_name.as_deref(),
-                    param.interface_name.as_deref(),
-                );
+                let rm = type_map::map_param_type(param);
                 if is_scalar_output_returnable(dir, &rm) {
                     output_types.push(scalar_out_rust_type_ctx(&rm, param.struct_name.as_deref(), ctx));
                 }
@@ -766,6 +812,7 @@ fn generate_container_function(out: &mut String, entry: &FuncEntry, class_name:
     }
 
     let mut default_unwraps: Vec<(String, String)> = Vec::new();
+    let mut soft_borrows: Vec<String> = Vec::new();
     for param in &func.params {
         let dir = type_map::param_direction(param);
         if dir == ParamDirection::Return || dir == ParamDirection::Out {
@@ -786,6 +833,10 @@ fn generate_container_function(out: &mut String, entry: &FuncEntry, class_name:
                 default_unwraps.push((pname.clone(), default_expr));
             }
             match mapped.rust_to_ffi {
+                ConversionKind::StringUtf8 if type_map::is_soft_ptr_type(&mapped.rust_type) => {
+                    sig.push_str(&format!("{pname}: &{}, ", mapped.rust_type));
+                    soft_borrows.push(pname.clone());
+                }
                 ConversionKind::StringUtf8 => {
                     if has_default {
                         sig.push_str(&format!("{pname}: Option<&str>, "));
@@ -830,6 +881,9 @@ fn generate_container_function(out: &mut String, entry: &FuncEntry, class_name:
     for (pname, default_expr) in &default_unwraps {
         out.push_str(&format!("        let {pname} = {pname}.unwrap_or({default_expr});\n"));
     }
+    for pname in &soft_borrows {
+        out.push_str(&format!("        let {pname} = {pname}.path();\n"));
+    }
 
     out.push_str("        {\n");
 
@@ -896,7 +950,7 @@ fn generate_container_function(out: &mut String, entry: &FuncEntry, class_name:
                     match mapped.ffi_to_rust {
                         ConversionKind::StringUtf8 => ffi_params.push_str("*mut u8, u32, *mut u32, "),
                         ConversionKind::ObjectRef => ffi_params.push_str("*mut uika_runtime::UObjectHandle, "),
-                        ConversionKind::StructOpaque => ffi_params.push_str("*mut u8, "),
+                        ConversionKind::StructOpaque => ffi_params.push_str("*mut u8, u32, *mut u32, "),
                         ConversionKind::EnumCast => ffi_params.push_str(&format!("*mut {}, ", mapped.rust_ffi_type)),
                         _ => ffi_params.push_str(&format!("*mut {}, ", mapped.rust_ffi_type)),
                     }
@@ -910,7 +964,7 @@ fn generate_container_function(out: &mut String, entry: &FuncEntry, class_name:
 
     out.push_str(&format!(
         "        type Fn = unsafe extern \"C\" fn({ffi_params}) -> uika_runtime::UikaErrorCode;\n\
-         \x20       let __uika_fn: Fn = unsafe {{ std::mem::transmute(*(uika_runtime::api().func_table.add(FN_ID as usize))) }};\n"
+         \x20       let __uika_fn: Fn = unsafe {{ std::mem::transmute(uika_runtime::func_ptr(FN_ID)) }};\n"
     ));
 
     // === Get handle (pre-validated via ValidHandle) ===
@@ -951,7 +1005,9 @@ fn generate_container_function(out: &mut String, entry: &FuncEntry, class_name:
                 out.push_str(&format!("        let mut __scalar_ret: {} = 0;\n", rm.rust_ffi_type));
             }
             ConversionKind::StructOpaque => {
-                out.push_str("        let mut __scalar_ret_buf = vec![0u8; 256];\n");
+                let len = param_helpers::struct_buf_len(return_param.and_then(|rp| rp.struct_name.as_deref()), ctx);
+                out.push_str(&format!("        let mut __scalar_ret_buf = vec![0u8; {len}];\n"));
+                out.push_str("        let mut __scalar_ret_size: u32 = 0;\n");
             }
             _ => {
                 let default = properties::default_value_for(&rm.rust_ffi_type);
@@ -965,7 +1021,7 @@ fn generate_container_function(out: &mut String, entry: &FuncEntry, class_name:
         let dir = type_map::param_direction(param);
         if dir == ParamDirection::Out && !is_container_param(param) {
             let mapped = map_param(param);
-            param_helpers::emit_out_param_var_decl(out, param, &mapped);
+            param_helpers::emit_out_param_var_decl(out, param, &mapped, ctx);
         }
         if dir == ParamDirection::InOut && !is_container_param(param) {
             let mapped = map_param(param);
@@ -974,9 +1030,9 @@ fn generate_container_function(out: &mut String, entry: &FuncEntry, class_name:
     }
 
     // === FFI call (deferred error check) ===
-    out.push_str("        let __result = unsafe { __uika_fn(");
+    let mut call = String::from("__uika_fn(");
     if !is_static {
-        out.push_str("h, ");
+        call.push_str("h, ");
     }
     for param in &func.params {
         let dir = type_map::param_direction(param);
@@ -984,7 +1040,7 @@ fn generate_container_function(out: &mut String, entry: &FuncEntry, class_name:
             let cp = container_params.iter().find(|c| std::ptr::eq(c.param, param))
                 .expect("container param must have matching metadata");
             let idx = cp.index;
-            out.push_str(&format!("__temp_{idx}, __cprops[{idx}].0 as *mut u8, "));
+            call.push_str(&format!("__temp_{idx}, __cprops[{idx}].0 as *mut u8, "));
         } else {
             let pname = escape_reserved(&to_snake_case(&param.name));
             let mapped = map_param(param);
@@ -992,37 +1048,37 @@ fn generate_container_function(out: &mut String, entry: &FuncEntry, class_name:
                 ParamDirection::In | ParamDirection::InOut => {
                     match mapped.rust_to_ffi {
                         ConversionKind::StringUtf8 => {
-                            out.push_str(&format!("{pname}.as_ptr(), {pname}.len() as u32, "));
+                            call.push_str(&format!("{pname}.as_ptr(), {pname}.len() as u32, "));
                             if dir == ParamDirection::InOut {
-                                out.push_str(&format!("{pname}_buf.as_mut_ptr(), {pname}_buf.len() as u32, &mut {pname}_len, "));
+                                call.push_str(&format!("{pname}_buf.as_mut_ptr(), {pname}_buf.len() as u32, &mut {pname}_len, "));
                             }
                         }
                         ConversionKind::ObjectRef => {
-                            out.push_str(&format!("{pname}.raw(), "));
+                            call.push_str(&format!("{pname}.raw(), "));
                         }
                         ConversionKind::EnumCast => {
-                            out.push_str(&format!("{pname} as {}, ", mapped.rust_ffi_type));
+                            call.push_str(&format!("{pname} as {}, ", mapped.rust_ffi_type));
                         }
                         ConversionKind::StructOpaque if dir == ParamDirection::In
                             && is_struct_owned(param.struct_name.as_deref(), ctx) =>
                         {
-                            out.push_str(&format!("{pname}.as_bytes().as_ptr(), "));
+                            call.push_str(&format!("{pname}.as_bytes().as_ptr(), "));
                         }
                         _ => {
-                            out.push_str(&format!("{pname}, "));
+                            call.push_str(&format!("{pname}, "));
                         }
                     }
                 }
                 ParamDirection::Out => {
                     match mapped.ffi_to_rust {
                         ConversionKind::StructOpaque => {
-                            out.push_str(&format!("{pname}_buf.as_mut_ptr(), "));
+                            call.push_str(&format!("{pname}_buf.as_mut_ptr(), {pname}_buf.len() as u32, &mut {pname}_size, "));
                         }
                         ConversionKind::StringUtf8 => {
-                            out.push_str(&format!("{pname}_buf.as_mut_ptr(), {pname}_buf.len() as u32, &mut {pname}_len, "));
+                            call.push_str(&format!("{pname}_buf.as_mut_ptr(), {pname}_buf.len() as u32, &mut {pname}_len, "));
                         }
                         _ => {
-                            out.push_str(&format!("&mut {pname}, "));
+                            call.push_str(&format!("&mut {pname}, "));
                         }
                     }
                 }
@@ -1030,16 +1086,16 @@ fn generate_container_function(out: &mut String, entry: &FuncEntry, class_name:
                     let rm = ret_mapped.expect("return param must have mapped type");
                     match rm.ffi_to_rust {
                         ConversionKind::StringUtf8 => {
-                            out.push_str("__scalar_ret_buf.as_mut_ptr(), __scalar_ret_buf.len() as u32, &mut __scalar_ret_len, ");
+                            call.push_str("__scalar_ret_buf.as_mut_ptr(), __scalar_ret_buf.len() as u32, &mut __scalar_ret_len, ");
                         }
                         ConversionKind::ObjectRef => {
-                            out.push_str("&mut __scalar_ret, ");
+                            call.push_str("&mut __scalar_ret, ");
                         }
                         ConversionKind::StructOpaque => {
-                            out.push_str("__scalar_ret_buf.as_mut_ptr(), ");
+                            call.push_str("__scalar_ret_buf.as_mut_ptr(), __scalar_ret_buf.len() as u32, &mut __scalar_ret_size, ");
                         }
                         _ => {
-                            out.push_str("&mut __scalar_ret, ");
+                            call.push_str("&mut __scalar_ret, ");
                         }
                     }
                 }
@@ -1047,11 +1103,28 @@ fn generate_container_function(out: &mut String, entry: &FuncEntry, class_name:
         }
     }
     // Remove trailing comma+space
-    let out_len = out.len();
-    if out.ends_with(", ") {
-        out.truncate(out_len - 2);
+    if call.ends_with(", ") {
+        call.truncate(call.len() - 2);
     }
-    out.push_str(") };\n");
+    call.push(')');
+    let mut sized_outputs = Vec::new();
+    if let (Some(rp), Some(rm)) = (return_param, ret_mapped) {
+        match rm.ffi_to_rust {
+            ConversionKind::StringUtf8 => sized_outputs.push(param_helpers::SizedOutput::string(
+                "__scalar_ret_buf",
+                "__scalar_ret_len",
+                param_helpers::stash_slot(&func.params, rp),
+            )),
+            ConversionKind::StructOpaque => {
+                sized_outputs.push(param_helpers::SizedOutput::structure("__scalar_ret_buf", "__scalar_ret_size"));
+            }
+            _ => {}
+        }
+    }
+    sized_outputs.extend(
+        func.params.iter().filter_map(|p| param_helpers::sized_output_vars(&func.params, p, &map_param(p))),
+    );
+    param_helpers::emit_ffi_call(out, "__result", &call, &sized_outputs);
 
     // === Read output containers (only on success) ===
     for cp in &container_params {
@@ -1072,8 +1145,16 @@ fn generate_container_function(out: &mut String, entry: &FuncEntry, class_name:
     }
     out.push_str("        }\n");
 
-    // === Assert success (infallible after pre-validation) ===
-    out.push_str("        uika_runtime::ffi_infallible(__result);\n");
+    // === Assert success (infallible after pre-validation; outputs that did
+    // not fit would come back truncated, so those fail in every build) ===
+    if sized_outputs.is_empty() {
+        out.push_str("        uika_runtime::ffi_infallible(__result);\n");
+    } else {
+        out.push_str(&format!(
+            "        uika_runtime::ffi_outputs_complete(__result, \"{}.{}\");\n",
+            entry.class_name, entry.func_name
+        ));
+    }
 
     // === Return ===
     emit_container_return(out, return_param, ret_mapped, &container_params, &func.params, ctx);
@@ -1195,12 +1276,13 @@ fn emit_container_return(
         } else if let Some(rm) = ret_mapped {
             match rm.ffi_to_rust {
                 ConversionKind::ObjectRef => {
-                    return_parts.push("unsafe { uika_runtime::UObjectRef::from_raw(__scalar_ret) }".to_string());
+                    let ctor = type_map::object_ref_ctor(&rm.rust_type);
+                    return_parts.push(format!("unsafe {{ {ctor}(__scalar_ret) }}"));
                 }
                 ConversionKind::StringUtf8 => {
                     out.push_str("        __scalar_ret_buf.truncate(__scalar_ret_len as usize);\n");
                     out.push_str("        let __scalar_str = String::from_utf8_lossy(&__scalar_ret_buf).into_owned();\n");
-                    return_parts.push("__scalar_str".to_string());
+                    return_parts.push(type_map::string_to_rust_expr(&rm.rust_type, "__scalar_str"));
                 }
                 ConversionKind::EnumCast => {
                     let rt = &rm.rust_type;
@@ -1216,7 +1298,9 @@ fn emit_container_return(
                 ConversionKind::StructOpaque => {
                     let rp_ref = return_param.expect("return_param must be Some in return conversion");
                     if is_struct_owned(rp_ref.struct_name.as_deref(), ctx) {
-                        out.push_str("        let __scalar_owned = uika_runtime::OwnedStruct::from_bytes(__scalar_ret_buf);\n");
+                        out.push_str("        __scalar_ret_buf.truncate(__scalar_ret_size as usize);\n");
+                        let owned = param_helpers::owned_struct_expr(rp_ref.struct_name.as_deref(), "__scalar_ret_buf", ctx);
+                        out.push_str(&format!("        let __scalar_owned = {owned};\n"));
                         return_parts.push("__scalar_owned".to_string());
                     } else {
                         out.push_str("        let __scalar_ptr = __scalar_ret_buf.as_ptr();\n");
@@ -1258,13 +1342,5 @@ fn emit_container_return(
 
 /// Map a ParamInfo to its MappedType (convenience helper).
 pub(super) fn map_param(param: &ParamInfo) -> MappedType {
-    type_map::map_property_type(
-        &param.prop_type,
-        param.class_name.as_deref(),
-        param.struct_name.as_deref(),
-        param.enum_name.as_deref(),
-        param.enum_underlying_type.as_deref(),
-        param.meta_class_name.as_deref(),
-        param.interface_name.as_deref(),
-    )
+    type_map::map_param_type(param)
 }
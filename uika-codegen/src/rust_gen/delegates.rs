@@ -3,15 +3,21 @@
 // For each delegate property on a class, we generate:
 // 1. A trait method returning a typed delegate handle struct
 // 2. The delegate handle struct with bind()/add() methods that accept typed closures
+// 3. A payload struct holding the decoded signature params (DelegateParams impl)
 //
 // The handle struct wraps the UObject owner + FPropertyHandle, and the bind/add
 // methods register a closure in the Rust delegate_registry, then call the C++ API.
+// When the delegate fires, the registry decodes the params buffer into the
+// payload struct before invoking the closure.
 
 use crate::context::CodegenContext;
+use crate::filter::{SkipCategory, SkipReason};
 use crate::naming::to_snake_case;
 use crate::schema::PropertyInfo;
 use crate::type_map::{self, ConversionKind};
 
+use super::skipped::MemberSkips;
+
 /// Information about a delegate property to generate code for.
 pub struct DelegateInfo<'a> {
     pub prop: &'a PropertyInfo,
@@ -20,6 +26,8 @@ pub struct DelegateInfo<'a> {
     pub rust_name: String,
     /// Struct name for the delegate wrapper (PascalCase).
     pub struct_name: String,
+    /// Struct name for the decoded params, or `()` for a parameterless signature.
+    pub payload_name: String,
     /// Whether this is a multicast delegate.
     pub is_multicast: bool,
     /// Parsed delegate parameters.
@@ -54,6 +62,7 @@ pub fn collect_delegate_props<'a>(
     props: &'a [PropertyInfo],
     class_name: &'a str,
     ctx: &CodegenContext,
+    skipped: &mut MemberSkips,
 ) -> Vec<DelegateInfo<'a>> {
     let mut result = Vec::new();
 
@@ -79,7 +88,11 @@ pub fn collect_delegate_props<'a>(
         // Parse func_info params
         let func_info = match &prop.func_info {
             Some(fi) => fi,
-            None => continue,
+            None => {
+                let reason = "delegate has no signature in the UHT export".to_string();
+                skipped.property(&prop.name, SkipReason::new(SkipCategory::UnsupportedType, reason));
+                continue;
+            }
         };
         let params_json = match func_info.get("params").and_then(|p| p.as_array()) {
             Some(params) => params,
@@ -87,7 +100,7 @@ pub fn collect_delegate_props<'a>(
         };
 
         let mut params = Vec::new();
-        let mut all_supported = true;
+        let mut unsupported: Option<(&str, &str)> = None;
 
         for param_value in params_json {
             let param_name = param_value
@@ -97,7 +110,7 @@ pub fn collect_delegate_props<'a>(
             let param_type = match param_value.get("type").and_then(|t| t.as_str()) {
                 Some(t) => t,
                 None => {
-                    all_supported = false;
+                    unsupported = Some((param_name, "?"));
                     break;
                 }
             };
@@ -105,24 +118,33 @@ pub fn collect_delegate_props<'a>(
             match resolve_delegate_param(param_name, param_type, param_value, ctx) {
                 Some(dp) => params.push(dp),
                 None => {
-                    all_supported = false;
+                    unsupported = Some((param_name, param_type));
                     break;
                 }
             }
         }
 
-        if !all_supported {
+        if let Some((param_name, param_type)) = unsupported {
+            let reason = format!("delegate parameter `{param_name}` of type `{param_type}` can't be mapped");
+            let skip = SkipReason::new(SkipCategory::UnsupportedType, reason).offending(format!("{param_name}: {param_type}"));
+            skipped.property(&prop.name, skip);
             continue;
         }
 
         let rust_name = to_snake_case(&prop.name);
         let struct_name = format!("{}{}Delegate", class_name, prop.name);
+        let payload_name = if params.is_empty() {
+            "()".to_string()
+        } else {
+            format!("{}{}Payload", class_name, prop.name)
+        };
 
         result.push(DelegateInfo {
             prop,
             class_name,
             rust_name,
             struct_name,
+            payload_name,
             is_multicast,
             params,
         });
@@ -285,7 +307,8 @@ pub fn generate_delegate_impls(
     }
 }
 
-/// Generate delegate wrapper structs with typed bind/add methods.
+/// Generate delegate wrapper structs with typed bind/add methods, plus the
+/// payload struct each closure receives.
 /// These are emitted at the top of the class file (before the trait).
 pub fn generate_delegate_structs(
     out: &mut String,
@@ -294,7 +317,7 @@ pub fn generate_delegate_structs(
 ) {
     for d in delegates {
         let struct_name = &d.struct_name;
-        let is_multicast = d.is_multicast;
+        let payload_name = &d.payload_name;
 
         out.push_str(&format!(
             "pub struct {struct_name} {{\n\
@@ -303,197 +326,189 @@ pub fn generate_delegate_structs(
              }}\n\n"
         ));
 
-        // Build the closure parameter types and extraction code.
-        // UHT exports the stripped function name (e.g., "OnEditableTextBoxCommittedEvent"),
-        // but UE stores the signature UFunction with "__DelegateSignature" suffix.
-        let sig_name_base = d.prop.func_info.as_ref()
-            .and_then(|fi| fi.get("name"))
-            .and_then(|n| n.as_str())
-            .unwrap_or(&d.prop.name);
-        let sig_name = format!("{sig_name_base}__DelegateSignature");
-        let sig_name_len = sig_name.len();
-        let sig_byte_lit = format!("b\"{}\\0\"", sig_name);
-
-        // Build closure param types for the user-facing closure signature
-        let callback_params: Vec<String> = d.params.iter().map(|p| p.rust_type.clone()).collect();
-        let callback_sig = callback_params.join(", ");
-
-        let method_name = if is_multicast { "add" } else { "bind" };
-        let api_fn = if is_multicast { "bind_multicast" } else { "bind_unicast" };
+        let method_name = if d.is_multicast { "add" } else { "bind" };
+        let api_fn = if d.is_multicast { "bind_multicast" } else { "bind_unicast" };
 
         out.push_str(&format!(
-            "impl {struct_name} {{\n"
-        ));
-
-        // Generate the bind/add method
-        out.push_str(&format!(
-            "    pub fn {method_name}(&self, mut callback: impl FnMut({callback_sig}) + Send + 'static) -> uika_runtime::UikaResult<uika_runtime::DelegateBinding> {{\n"
+            "impl {struct_name} {{\n\
+             \x20   pub fn {method_name}(&self, callback: impl FnMut({payload_name}) + Send + 'static) -> uika_runtime::UikaResult<uika_runtime::DelegateBinding<{payload_name}>> {{\n\
+             \x20       // SAFETY: `prop` is this delegate's property and the payload\n\
+             \x20       // is generated from its signature.\n\
+             \x20       unsafe {{ uika_runtime::delegate_registry::{api_fn}(self.owner, self.prop, callback) }}\n\
+             \x20   }}\n\
+             }}\n\n"
         ));
 
-        // If there are params, we need to resolve offsets + property handles via OnceLock
         if !d.params.is_empty() {
-            let n_params = d.params.len();
-            out.push_str(&format!(
-                "        static PARAM_INFO: std::sync::OnceLock<[(u32, uika_runtime::FPropertyHandle); {n_params}]> = std::sync::OnceLock::new();\n\
-                 \x20       let param_info = PARAM_INFO.get_or_init(|| unsafe {{\n\
-                 \x20           let sig_func = uika_runtime::ffi_dispatch::reflection_find_function_by_class(\n\
-                 \x20               {class_name}::static_class(),\n\
-                 \x20               {sig_byte_lit}.as_ptr(), {sig_name_len});\n\
-                 \x20           [\n"
-            ));
-
-            for p in &d.params {
-                let param_ue_name = &d.prop.func_info.as_ref()
-                    .and_then(|fi| fi.get("params"))
-                    .and_then(|ps| ps.as_array())
-                    .and_then(|arr| arr.iter().find(|v| {
-                        v.get("name").and_then(|n| n.as_str()).map(|n| to_snake_case(n)) == Some(p.name.clone())
-                    }))
-                    .and_then(|v| v.get("name"))
-                    .and_then(|n| n.as_str())
-                    .unwrap_or(&p.name);
-                let pname_len = param_ue_name.len();
-                let pname_lit = format!("b\"{}\\0\"", param_ue_name);
-                out.push_str(&format!(
-                    "                {{\n\
-                     \x20                   let param_prop = uika_runtime::ffi_dispatch::reflection_get_function_param(\n\
-                     \x20                       sig_func, {pname_lit}.as_ptr(), {pname_len});\n\
-                     \x20                   (uika_runtime::ffi_dispatch::reflection_get_property_offset(param_prop), param_prop)\n\
-                     \x20               }},\n"
-                ));
-            }
-
-            out.push_str(
-                "            ]\n\
-                 \x20       });\n\
-                 \x20       #[allow(unused_variables)] let param_info = param_info;\n"
-            );
-        }
-
-        // Build the closure wrapper that extracts typed params from NativePtr
-        if d.params.is_empty() {
-            out.push_str(&format!(
-                "        let owner = self.owner;\n\
-                 \x20       let prop = self.prop;\n\
-                 \x20       uika_runtime::delegate_registry::{api_fn}(owner, prop, move |_params: uika_runtime::ffi_dispatch::NativePtr| {{\n\
-                 \x20           callback();\n\
-                 \x20       }})\n\
-                 \x20   }}\n"
-            ));
-            out.push_str("}\n\n");
-            continue;
+            generate_payload_struct(out, d, class_name);
         }
+    }
+}
 
+/// Generate the payload struct for a delegate signature and its
+/// `DelegateParams` impl, which reads each param out of the params buffer.
+fn generate_payload_struct(out: &mut String, d: &DelegateInfo, class_name: &str) {
+    let payload_name = &d.payload_name;
+
+    out.push_str(&format!(
+        "/// Params of `{class_name}::{}`, decoded when the delegate fires.\n\
+         pub struct {payload_name} {{\n",
+        d.prop.name
+    ));
+    for p in &d.params {
+        out.push_str(&format!("    pub {}: {},\n", p.name, p.rust_type));
+    }
+    out.push_str("}\n\n");
+
+    // UHT exports the stripped function name (e.g., "OnEditableTextBoxCommittedEvent"),
+    // but UE stores the signature UFunction with "__DelegateSignature" suffix.
+    let sig_name_base = d.prop.func_info.as_ref()
+        .and_then(|fi| fi.get("name"))
+        .and_then(|n| n.as_str())
+        .unwrap_or(&d.prop.name);
+    let sig_name = format!("{sig_name_base}__DelegateSignature");
+    let sig_name_len = sig_name.len();
+    let sig_byte_lit = format!("b\"{}\\0\"", sig_name);
+
+    // Resolve offsets + property handles once via OnceLock
+    let n_params = d.params.len();
+    out.push_str(&format!(
+        "impl uika_runtime::DelegateParams for {payload_name} {{\n\
+         \x20   unsafe fn from_params(params: uika_runtime::ffi_dispatch::NativePtr) -> Self {{\n\
+         \x20       static PARAM_INFO: std::sync::OnceLock<[(u32, uika_runtime::FPropertyHandle); {n_params}]> = std::sync::OnceLock::new();\n\
+         \x20       let param_info = PARAM_INFO.get_or_init(|| unsafe {{\n\
+         \x20           let sig_func = uika_runtime::ffi_dispatch::reflection_find_function_by_class(\n\
+         \x20               {class_name}::static_class(),\n\
+         \x20               {sig_byte_lit}.as_ptr(), {sig_name_len});\n\
+         \x20           [\n"
+    ));
+
+    for p in &d.params {
+        let param_ue_name = &d.prop.func_info.as_ref()
+            .and_then(|fi| fi.get("params"))
+            .and_then(|ps| ps.as_array())
+            .and_then(|arr| arr.iter().find(|v| {
+                v.get("name").and_then(|n| n.as_str()).map(|n| to_snake_case(n)) == Some(p.name.clone())
+            }))
+            .and_then(|v| v.get("name"))
+            .and_then(|n| n.as_str())
+            .unwrap_or(&p.name);
+        let pname_len = param_ue_name.len();
+        let pname_lit = format!("b\"{}\\0\"", param_ue_name);
         out.push_str(&format!(
-            "        let owner = self.owner;\n\
-             \x20       let prop = self.prop;\n\
-             \x20       uika_runtime::delegate_registry::{api_fn}(owner, prop, move |params: uika_runtime::ffi_dispatch::NativePtr| {{\n\
-             \x20           unsafe {{\n"
+            "                {{\n\
+             \x20                   let param_prop = uika_runtime::ffi_dispatch::reflection_get_function_param(\n\
+             \x20                       sig_func, {pname_lit}.as_ptr(), {pname_len});\n\
+             \x20                   (uika_runtime::ffi_dispatch::reflection_get_property_offset(param_prop), param_prop)\n\
+             \x20               }},\n"
         ));
+    }
 
-        // Extract each parameter
-        for (i, p) in d.params.iter().enumerate() {
-            let var_name = &p.name;
-            match &p.conversion {
-                ParamConversion::Primitive(ty) => {
-                    out.push_str(&format!(
-                        "                let {var_name} = uika_runtime::ffi_dispatch::native_mem_read::<{ty}>(params, param_info[{i}].0 as usize);\n"
-                    ));
-                }
-                ParamConversion::ObjectRef(_cls) => {
-                    out.push_str(&format!(
-                        "                let {var_name} = uika_runtime::UObjectRef::from_raw(\n\
-                         \x20                   uika_runtime::ffi_dispatch::native_mem_read::<uika_runtime::UObjectHandle>(params, param_info[{i}].0 as usize)\n\
-                         \x20               );\n"
-                    ));
-                }
-                ParamConversion::Enum { rust_type, repr } => {
-                    out.push_str(&format!(
-                        "                let __raw_{var_name} = uika_runtime::ffi_dispatch::native_mem_read::<{repr}>(params, param_info[{i}].0 as usize);\n\
-                         \x20               let {var_name} = {rust_type}::from_value(__raw_{var_name}).unwrap_or_else(|| std::mem::transmute(__raw_{var_name}));\n"
-                    ));
-                }
-                ParamConversion::FName => {
-                    out.push_str(&format!(
-                        "                let {var_name} = {{\n\
-                         \x20                   let mut __buf = [0u8; 8];\n\
-                         \x20                   let mut __written: u32 = 0;\n\
-                         \x20                   uika_runtime::ffi_infallible(uika_runtime::ffi_dispatch::delegate_read_param(\n\
-                         \x20                       param_info[{i}].1,\n\
-                         \x20                       params,\n\
-                         \x20                       param_info[{i}].0,\n\
-                         \x20                       __buf.as_mut_ptr(),\n\
-                         \x20                       8,\n\
-                         \x20                       &mut __written,\n\
-                         \x20                   ));\n\
-                         \x20                   uika_runtime::FNameHandle(u64::from_ne_bytes(__buf))\n\
-                         \x20               }};\n"
-                    ));
-                }
-                ParamConversion::String => {
-                    out.push_str(&format!(
-                        "                let {var_name} = {{\n\
-                         \x20                   let mut __buf = vec![0u8; 260];\n\
-                         \x20                   let mut __written: u32 = 0;\n\
-                         \x20                   let __err = uika_runtime::ffi_dispatch::delegate_read_param(\n\
-                         \x20                       param_info[{i}].1,\n\
-                         \x20                       params,\n\
-                         \x20                       param_info[{i}].0,\n\
-                         \x20                       __buf.as_mut_ptr(),\n\
-                         \x20                       __buf.len() as u32,\n\
-                         \x20                       &mut __written,\n\
-                         \x20                   );\n\
-                         \x20                   if __err == uika_runtime::UikaErrorCode::BufferTooSmall && __written > 0 {{\n\
-                         \x20                       __buf.resize(__written as usize, 0);\n\
-                         \x20                       uika_runtime::ffi_infallible(uika_runtime::ffi_dispatch::delegate_read_param(\n\
-                         \x20                           param_info[{i}].1,\n\
-                         \x20                           params,\n\
-                         \x20                           param_info[{i}].0,\n\
-                         \x20                           __buf.as_mut_ptr(),\n\
-                         \x20                           __buf.len() as u32,\n\
-                         \x20                           &mut __written,\n\
-                         \x20                       ));\n\
-                         \x20                   }}\n\
-                         \x20                   if __written >= 4 {{\n\
-                         \x20                       let __slen = u32::from_ne_bytes([__buf[0], __buf[1], __buf[2], __buf[3]]) as usize;\n\
-                         \x20                       String::from_utf8_lossy(&__buf[4..4 + __slen]).into_owned()\n\
-                         \x20                   }} else {{\n\
-                         \x20                       String::new()\n\
-                         \x20                   }}\n\
-                         \x20               }};\n"
-                    ));
-                }
-                ParamConversion::Struct { cpp_name, .. } => {
-                    out.push_str(&format!(
-                        "                let {var_name} = {{\n\
-                         \x20                   let __size = uika_runtime::ffi_dispatch::reflection_get_property_size(param_info[{i}].1) as usize;\n\
-                         \x20                   let mut __buf = vec![0u8; __size];\n\
-                         \x20                   let mut __written: u32 = 0;\n\
-                         \x20                   uika_runtime::ffi_infallible(uika_runtime::ffi_dispatch::delegate_read_param(\n\
-                         \x20                       param_info[{i}].1,\n\
-                         \x20                       params,\n\
-                         \x20                       param_info[{i}].0,\n\
-                         \x20                       __buf.as_mut_ptr(),\n\
-                         \x20                       __size as u32,\n\
-                         \x20                       &mut __written,\n\
-                         \x20                   ));\n\
-                         \x20                   uika_runtime::OwnedStruct::<{cpp_name}>::from_bytes(__buf)\n\
-                         \x20               }};\n"
-                    ));
-                }
+    out.push_str(
+        "            ]\n\
+         \x20       });\n\
+         \x20       unsafe {\n"
+    );
+
+    // Extract each parameter
+    for (i, p) in d.params.iter().enumerate() {
+        let var_name = &p.name;
+        match &p.conversion {
+            ParamConversion::Primitive(ty) => {
+                out.push_str(&format!(
+                    "                let {var_name} = uika_runtime::ffi_dispatch::native_mem_read::<{ty}>(params, param_info[{i}].0 as usize);\n"
+                ));
+            }
+            ParamConversion::ObjectRef(_cls) => {
+                out.push_str(&format!(
+                    "                let {var_name} = uika_runtime::UObjectRef::from_raw(\n\
+                     \x20                   uika_runtime::ffi_dispatch::native_mem_read::<uika_runtime::UObjectHandle>(params, param_info[{i}].0 as usize)\n\
+                     \x20               );\n"
+                ));
+            }
+            ParamConversion::Enum { rust_type, repr } => {
+                out.push_str(&format!(
+                    "                let __raw_{var_name} = uika_runtime::ffi_dispatch::native_mem_read::<{repr}>(params, param_info[{i}].0 as usize);\n\
+                     \x20               let {var_name} = {rust_type}::from_value(__raw_{var_name}).unwrap_or_else(|| std::mem::transmute(__raw_{var_name}));\n"
+                ));
+            }
+            ParamConversion::FName => {
+                out.push_str(&format!(
+                    "                let {var_name} = {{\n\
+                     \x20                   let mut __buf = [0u8; 8];\n\
+                     \x20                   let mut __written: u32 = 0;\n\
+                     \x20                   uika_runtime::ffi_infallible(uika_runtime::ffi_dispatch::delegate_read_param(\n\
+                     \x20                       param_info[{i}].1,\n\
+                     \x20                       params,\n\
+                     \x20                       param_info[{i}].0,\n\
+                     \x20                       __buf.as_mut_ptr(),\n\
+                     \x20                       8,\n\
+                     \x20                       &mut __written,\n\
+                     \x20                   ));\n\
+                     \x20                   uika_runtime::FNameHandle(u64::from_ne_bytes(__buf))\n\
+                     \x20               }};\n"
+                ));
+            }
+            ParamConversion::String => {
+                out.push_str(&format!(
+                    "                let {var_name} = {{\n\
+                     \x20                   let mut __buf = vec![0u8; 260];\n\
+                     \x20                   let mut __written: u32 = 0;\n\
+                     \x20                   let __err = uika_runtime::ffi_dispatch::delegate_read_param(\n\
+                     \x20                       param_info[{i}].1,\n\
+                     \x20                       params,\n\
+                     \x20                       param_info[{i}].0,\n\
+                     \x20                       __buf.as_mut_ptr(),\n\
+                     \x20                       __buf.len() as u32,\n\
+                     \x20                       &mut __written,\n\
+                     \x20                   );\n\
+                     \x20                   if __err == uika_runtime::UikaErrorCode::BufferTooSmall && __written > 0 {{\n\
+                     \x20                       __buf.resize(__written as usize, 0);\n\
+                     \x20                       uika_runtime::ffi_infallible(uika_runtime::ffi_dispatch::delegate_read_param(\n\
+                     \x20                           param_info[{i}].1,\n\
+                     \x20                           params,\n\
+                     \x20                           param_info[{i}].0,\n\
+                     \x20                           __buf.as_mut_ptr(),\n\
+                     \x20                           __buf.len() as u32,\n\
+                     \x20                           &mut __written,\n\
+                     \x20                       ));\n\
+                     \x20                   }}\n\
+                     \x20                   if __written >= 4 {{\n\
+                     \x20                       let __slen = u32::from_ne_bytes([__buf[0], __buf[1], __buf[2], __buf[3]]) as usize;\n\
+                     \x20                       String::from_utf8_lossy(&__buf[4..4 + __slen]).into_owned()\n\
+                     \x20                   }} else {{\n\
+                     \x20                       String::new()\n\
+                     \x20                   }}\n\
+                     \x20               }};\n"
+                ));
+            }
+            ParamConversion::Struct { cpp_name, .. } => {
+                out.push_str(&format!(
+                    "                let {var_name} = {{\n\
+                     \x20                   let __size = uika_runtime::ffi_dispatch::reflection_get_property_size(param_info[{i}].1) as usize;\n\
+                     \x20                   let mut __buf = vec![0u8; __size];\n\
+                     \x20                   let mut __written: u32 = 0;\n\
+                     \x20                   uika_runtime::ffi_infallible(uika_runtime::ffi_dispatch::delegate_read_param(\n\
+                     \x20                       param_info[{i}].1,\n\
+                     \x20                       params,\n\
+                     \x20                       param_info[{i}].0,\n\
+                     \x20                       __buf.as_mut_ptr(),\n\
+                     \x20                       __size as u32,\n\
+                     \x20                       &mut __written,\n\
+                     \x20                   ));\n\
+                     \x20                   uika_runtime::OwnedStruct::<{cpp_name}>::from_bytes(__buf)\n\
+                     \x20               }};\n"
+                ));
             }
         }
-
-        // Call the user's callback with extracted params
-        let param_names: Vec<&str> = d.params.iter().map(|p| p.name.as_str()).collect();
-        let call_args = param_names.join(", ");
-        out.push_str(&format!(
-            "                callback({call_args});\n\
-             \x20           }}\n\
-             \x20       }})\n\
-             \x20   }}\n"
-        ));
-
-        out.push_str("}\n\n");
     }
+
+    let field_names: Vec<&str> = d.params.iter().map(|p| p.name.as_str()).collect();
+    let fields = field_names.join(", ");
+    out.push_str(&format!(
+        "                Self {{ {fields} }}\n\
+         \x20       }}\n\
+         \x20   }}\n\
+         }}\n\n"
+    ));
 }
@@ -0,0 +1,240 @@
+// Rustdoc generation from UE `ToolTip` / `Category` metadata.
+//
+// UHT turns the C++ doc comment of every reflected type, property and
+// function into `ToolTip` metadata; the exporter passes it through. Function
+// tooltips keep their `@param` / `@return` tags, which are rendered as
+// `# Arguments` / `# Returns` sections keyed by the Rust parameter names.
+
+use crate::naming::{escape_reserved, to_snake_case};
+use crate::schema::{ClassInfo, FunctionInfo, PropertyInfo, StructInfo};
+use crate::type_map::{self, ParamDirection};
+
+/// Doc lines for a generated class struct.
+pub fn class_doc(class: &ClassInfo) -> Vec<String> {
+    let mut lines = tooltip_lines(class.tooltip.as_deref());
+    push_origin(&mut lines, format!("UE class `{}`", class.cpp_name), class.category.as_deref());
+    lines
+}
+
+/// Doc lines for a generated struct marker type.
+pub fn struct_doc(s: &StructInfo) -> Vec<String> {
+    let mut lines = tooltip_lines(s.tooltip.as_deref());
+    push_origin(
+        &mut lines,
+        format!("Opaque UE struct `{}`. Layout managed by C++ side", s.cpp_name),
+        None,
+    );
+    lines
+}
+
+/// Doc lines for a property's accessors.
+pub fn property_doc(prop: &PropertyInfo, owner_cpp: &str) -> Vec<String> {
+    let mut lines = tooltip_lines(prop.tooltip.as_deref());
+    push_origin(
+        &mut lines,
+        format!("UE property `{owner_cpp}::{}`", prop.name),
+        prop.category.as_deref(),
+    );
+    lines
+}
+
+/// Doc lines for a function wrapper, with `@param` / `@return` tags turned
+/// into sections.
+pub fn function_doc(func: &FunctionInfo, owner_cpp: &str) -> Vec<String> {
+    let ue_name = if func.ue_name.is_empty() { &func.name } else { &func.ue_name };
+
+    let mut description: Vec<String> = Vec::new();
+    let mut params: Vec<(String, String)> = Vec::new();
+    let mut returns = String::new();
+    // Which tag continuation lines belong to.
+    enum Section { Description, Param, Returns }
+    let mut section = Section::Description;
+
+    for line in tooltip_lines(func.tooltip.as_deref()) {
+        if let Some(rest) = line.strip_prefix("@param") {
+            let rest = rest.trim();
+            let (name, desc) = rest.split_once(char::is_whitespace).unwrap_or((rest, ""));
+            params.push((name.to_string(), desc.trim().to_string()));
+            section = Section::Param;
+        } else if let Some(rest) =
+            line.strip_prefix("@returns").or_else(|| line.strip_prefix("@return"))
+        {
+            returns = rest.trim().to_string();
+            section = Section::Returns;
+        } else if line.is_empty() || line.starts_with('@') {
+            description.push(line);
+            section = Section::Description;
+        } else {
+            match section {
+                Section::Description => description.push(line),
+                Section::Param => append(&mut params.last_mut().expect("param section").1, &line),
+                Section::Returns => append(&mut returns, &line),
+            }
+        }
+    }
+    while description.last().is_some_and(|l| l.is_empty()) {
+        description.pop();
+    }
+
+    let mut lines = description;
+    let documented: Vec<(String, &str)> = func
+        .params
+        .iter()
+        .filter(|p| type_map::param_direction(p) != ParamDirection::Return)
+        .filter_map(|p| {
+            let desc = params.iter().find(|(n, _)| n.eq_ignore_ascii_case(&p.name))?;
+            Some((escape_reserved(&to_snake_case(&p.name)), desc.1.as_str()))
+        })
+        .collect();
+    if !documented.is_empty() {
+        separate(&mut lines);
+        lines.push("# Arguments".to_string());
+        lines.push(String::new());
+        for (name, desc) in documented {
+            lines.push(format!("- `{name}`: {desc}"));
+        }
+    }
+    if !returns.is_empty() {
+        separate(&mut lines);
+        lines.push("# Returns".to_string());
+        lines.push(String::new());
+        lines.push(returns);
+    }
+    push_origin(&mut lines, format!("UE function `{owner_cpp}::{ue_name}`"), func.category.as_deref());
+    lines
+}
+
+/// Emit `lines` as `///` comments at `indent`.
+pub fn emit_doc(out: &mut String, indent: &str, lines: &[String]) {
+    for line in lines {
+        if line.is_empty() {
+            out.push_str(&format!("{indent}///\n"));
+        } else {
+            out.push_str(&format!("{indent}/// {line}\n"));
+        }
+    }
+}
+
+/// Insert `lines` as trait-level (4-space) doc comments before every
+/// `    fn` line emitted into `out` since `start`. Used to document accessors
+/// and wrappers produced by several specialised emitters at once.
+pub fn insert_before_fns(out: &mut String, start: usize, lines: &[String]) {
+    if lines.is_empty() {
+        return;
+    }
+    let mut doc = String::new();
+    emit_doc(&mut doc, "    ", lines);
+
+    let tail = out.split_off(start);
+    for chunk in tail.split_inclusive('\n') {
+        if chunk.starts_with("    fn ") {
+            out.push_str(&doc);
+        }
+        out.push_str(chunk);
+    }
+}
+
+/// Split a tooltip into trimmed lines that render as plain rustdoc text.
+///
+/// Leading whitespace is dropped so indented tooltip text doesn't become a
+/// doctest, and markdown/HTML metacharacters are escaped.
+fn tooltip_lines(tooltip: Option<&str>) -> Vec<String> {
+    let mut lines: Vec<String> = Vec::new();
+    for raw in tooltip.unwrap_or("").lines() {
+        let line = escape_markdown(raw.trim());
+        if line.is_empty() && lines.last().is_none_or(|l| l.is_empty()) {
+            continue;
+        }
+        lines.push(line);
+    }
+    while lines.last().is_some_and(|l| l.is_empty()) {
+        lines.pop();
+    }
+    lines
+}
+
+fn escape_markdown(line: &str) -> String {
+    let mut s = line
+        .replace("```", "'''")
+        .replace('<', "\\<")
+        .replace('[', "\\[")
+        .replace(']', "\\]");
+    if s.starts_with('#') || s.starts_with('>') {
+        s.insert(0, '\\');
+    }
+    s
+}
+
+fn append(dst: &mut String, line: &str) {
+    if !dst.is_empty() {
+        dst.push(' ');
+    }
+    dst.push_str(line);
+}
+
+fn separate(lines: &mut Vec<String>) {
+    if lines.last().is_some_and(|l| !l.is_empty()) {
+        lines.push(String::new());
+    }
+}
+
+/// Append the trailing "UE ... `Name`." line, with the category if known.
+fn push_origin(lines: &mut Vec<String>, origin: String, category: Option<&str>) {
+    separate(lines);
+    match category.map(str::trim).filter(|c| !c.is_empty()) {
+        Some(category) => lines.push(format!("{origin} (category: {}).", escape_markdown(category))),
+        None => lines.push(format!("{origin}.")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_function_doc_sections() {
+        let func: FunctionInfo = serde_json::from_str(
+            r#"{
+                "name": "K2_SetActorLocation",
+                "func_flags": 0,
+                "tooltip": "Move the actor instantly.\n    Indented <b>text</b>\n@param NewLocation  The new location\n    to teleport to\n@return Whether the move succeeded",
+                "category": "Transformation",
+                "params": [
+                    {"name": "NewLocation", "type": "StructProperty", "prop_flags": 128},
+                    {"name": "ReturnValue", "type": "BoolProperty", "prop_flags": 1536}
+                ]
+            }"#,
+        )
+        .unwrap();
+        let lines = function_doc(&func, "AActor");
+        assert_eq!(
+            lines,
+            [
+                "Move the actor instantly.",
+                "Indented \\<b>text\\</b>",
+                "",
+                "# Arguments",
+                "",
+                "- `new_location`: The new location to teleport to",
+                "",
+                "# Returns",
+                "",
+                "Whether the move succeeded",
+                "",
+                "UE function `AActor::K2_SetActorLocation` (category: Transformation).",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_insert_before_fns() {
+        let mut out = String::from("pub trait T {\n");
+        let start = out.len();
+        out.push_str("    fn get_x(&self) -> i32 {\n        0\n    }\n    fn set_x(&self, v: i32) {}\n");
+        insert_before_fns(&mut out, start, &["Doc.".to_string()]);
+        assert_eq!(
+            out,
+            "pub trait T {\n    /// Doc.\n    fn get_x(&self) -> i32 {\n        0\n    }\n    /// Doc.\n    fn set_x(&self, v: i32) {}\n"
+        );
+    }
+}
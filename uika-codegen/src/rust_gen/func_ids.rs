@@ -1,9 +1,12 @@
 // Rust FuncId constants generation.
 
-use crate::context::FuncEntry;
+use crate::context::{BindingStamp, FuncEntry};
 
 /// Generate the `func_ids.rs` file with compile-time constants.
-pub fn generate_rust_func_ids(entries: &[FuncEntry]) -> String {
+///
+/// `func_count` is the table size, which exceeds `entries.len()` when
+/// retired FuncIds are kept reserved.
+pub fn generate_rust_func_ids(entries: &[FuncEntry], func_count: u32, stamp: &BindingStamp) -> String {
     let mut out = String::with_capacity(entries.len() * 60 + 256);
     out.push_str("// Auto-generated by uika-codegen. Do not edit.\n\n");
     out.push_str("#![allow(dead_code)]\n\n");
@@ -14,15 +17,37 @@ pub fn generate_rust_func_ids(entries: &[FuncEntry]) -> String {
         out.push_str(&format!("pub const {const_name}: u32 = {id};\n"));
     }
 
+    out.push('\n');
+    out.push_str(&format!("pub const FUNC_COUNT: u32 = {func_count};\n"));
+
+    // Binding stamp: compared with the one UikaFillFuncTable.cpp hands over
+    // in the API table by uika::init.
     out.push('\n');
     out.push_str(&format!(
-        "pub const FUNC_COUNT: u32 = {};\n",
-        entries.len()
+        "pub const BINDING_ENGINE_VERSION: &str = {:?};\n\
+         pub const BINDING_CONFIG_HASH: u64 = {:#018x};\n\n",
+        stamp.engine_version, stamp.config_hash
     ));
+    out.push_str("/// Signature hash per FuncId (0 = unused id).\n");
+    out.push_str("pub static BINDING_FUNC_SIGS: [u64; FUNC_COUNT as usize] = [\n");
+    push_sig_rows(&mut out, &stamp.func_sigs);
+    out.push_str("];\n");
 
     out
 }
 
+/// Append `sigs` as hex literals, four per line, each followed by a comma.
+/// Shared with the C++ `GUikaFuncSigs` array.
+pub fn push_sig_rows(out: &mut String, sigs: &[u64]) {
+    for row in sigs.chunks(4) {
+        out.push_str("   ");
+        for sig in row {
+            out.push_str(&format!(" {sig:#018x},"));
+        }
+        out.push('\n');
+    }
+}
+
 /// Build a constant name like ACTOR_GET_OBJECT_COUNT from class + function names.
 pub fn func_id_const_name(class_name: &str, func_name: &str) -> String {
     let class = to_screaming_snake(class_name);
@@ -9,16 +9,37 @@ pub mod module;
 pub mod func_ids;
 pub mod param_helpers;
 pub mod cargo_toml;
+pub mod docs;
+pub mod skipped;
 
+use std::collections::HashMap;
 use std::path::Path;
 
-use crate::context::CodegenContext;
+use crate::context::{CodegenContext, FuncEntry};
+use crate::filter::FilterReport;
+use crate::incremental::{Fingerprints, OutDir, Outputs};
 
 /// Generate all Rust code into the output directory.
-pub fn generate(ctx: &CodegenContext, out_dir: &Path) {
+///
+/// Per-type files whose inputs are unchanged (per `fingerprints`) are
+/// skipped when `outputs` is in incremental mode. `report` holds every
+/// exclusion of the run, including Rust generation's own (see
+/// [`skipped::collect`]), and becomes `skipped.rs`.
+pub fn generate(
+    ctx: &CodegenContext,
+    out_dir: &Path,
+    fingerprints: &Fingerprints,
+    outputs: &mut Outputs,
+    report: &FilterReport,
+) {
     // Ensure output directory exists
     std::fs::create_dir_all(out_dir).expect("Failed to create Rust output directory");
 
+    let mut class_funcs: HashMap<&str, Vec<&FuncEntry>> = HashMap::new();
+    for entry in &ctx.func_table {
+        class_funcs.entry(entry.class_name.as_str()).or_default().push(entry);
+    }
+
     // Generate per-module code
     for module_name in ctx.enabled_modules.iter() {
         let module_dir = out_dir.join(module_name);
@@ -27,30 +48,32 @@ pub fn generate(ctx: &CodegenContext, out_dir: &Path) {
         // Enums
         if let Some(module_enums) = ctx.module_enums.get(module_name) {
             for e in module_enums {
-                let code = enums::generate_enum(e);
-                let filename = crate::naming::to_snake_case(&e.name) + ".rs";
-                std::fs::write(module_dir.join(&filename), code)
-                    .unwrap_or_else(|err| panic!("Failed to write {filename}: {err}"));
+                let rel = format!("{module_name}/{}.rs", crate::naming::to_snake_case(&e.name));
+                if !outputs.is_fresh(OutDir::Rust, &rel, fingerprints.type_input("enum", &e.name)) {
+                    outputs.write(OutDir::Rust, &rel, &enums::generate_enum(e));
+                }
             }
         }
 
         // Structs
         if let Some(module_structs) = ctx.module_structs.get(module_name) {
             for s in module_structs {
-                let code = structs::generate_struct(s, ctx);
-                let filename = crate::naming::to_snake_case(&s.name) + ".rs";
-                std::fs::write(module_dir.join(&filename), code)
-                    .unwrap_or_else(|err| panic!("Failed to write {filename}: {err}"));
+                let rel = format!("{module_name}/{}.rs", crate::naming::to_snake_case(&s.name));
+                if !outputs.is_fresh(OutDir::Rust, &rel, fingerprints.type_input("struct", &s.name)) {
+                    outputs.write(OutDir::Rust, &rel, &structs::generate_struct(s, ctx));
+                }
             }
         }
 
         // Classes
         if let Some(module_classes) = ctx.module_classes.get(module_name) {
             for c in module_classes {
-                let code = classes::generate_class(c, ctx);
-                let filename = crate::naming::to_snake_case(&c.name) + ".rs";
-                std::fs::write(module_dir.join(&filename), code)
-                    .unwrap_or_else(|err| panic!("Failed to write {filename}: {err}"));
+                let rel = format!("{module_name}/{}.rs", crate::naming::to_snake_case(&c.name));
+                let funcs = class_funcs.get(c.name.as_str()).into_iter().flatten().copied();
+                let input = fingerprints.class_input("rust", &c.name, funcs);
+                if !outputs.is_fresh(OutDir::Rust, &rel, input) {
+                    outputs.write(OutDir::Rust, &rel, &classes::generate_class(c, ctx));
+                }
             }
         }
 
@@ -61,16 +84,17 @@ pub fn generate(ctx: &CodegenContext, out_dir: &Path) {
             ctx.module_structs.get(module_name).map(|v| v.as_slice()),
             ctx.module_classes.get(module_name).map(|v| v.as_slice()),
         );
-        std::fs::write(module_dir.join("mod.rs"), mod_code)
-            .expect("Failed to write module mod.rs");
+        outputs.write(OutDir::Rust, &format!("{module_name}/mod.rs"), &mod_code);
     }
 
     // Generate func_ids.rs
-    let func_ids_code = func_ids::generate_rust_func_ids(&ctx.func_table);
-    std::fs::write(out_dir.join("func_ids.rs"), func_ids_code)
-        .expect("Failed to write func_ids.rs");
+    let func_ids_code = func_ids::generate_rust_func_ids(&ctx.func_table, ctx.func_count, &ctx.binding_stamp);
+    outputs.write(OutDir::Rust, "func_ids.rs", &func_ids_code);
+
+    // Generate skipped.rs
+    outputs.write(OutDir::Rust, "skipped.rs", &skipped::generate_skipped_rs(&report.excluded));
 
     // Generate top-level lib.rs
     let lib_code = module::generate_lib_rs(ctx);
-    std::fs::write(out_dir.join("lib.rs"), lib_code).expect("Failed to write lib.rs");
+    outputs.write(OutDir::Rust, "lib.rs", &lib_code);
 }
@@ -60,7 +60,8 @@ pub fn generate_lib_rs(ctx: &CodegenContext) -> String {
     let mut out = String::with_capacity(1024);
     out.push_str("// Auto-generated by uika-codegen. Do not edit.\n\n");
     out.push_str("#![allow(non_camel_case_types, non_snake_case, dead_code, unused_imports)]\n\n");
-    out.push_str("pub mod func_ids;\n\n");
+    out.push_str("pub mod func_ids;\n");
+    out.push_str("pub mod skipped;\n\n");
 
     // Sort modules for deterministic output
     let mut modules: Vec<&String> = ctx.enabled_modules.iter().collect();
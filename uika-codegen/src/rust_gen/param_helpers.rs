@@ -6,7 +6,7 @@
 use crate::context::CodegenContext;
 use crate::naming::{escape_reserved, to_snake_case};
 use crate::schema::*;
-use crate::type_map::{ConversionKind, MappedType};
+use crate::type_map::{self, ConversionKind, MappedType, ParamDirection, StructDecode};
 
 use super::classes::is_struct_owned;
 use super::properties;
@@ -16,11 +16,13 @@ use super::properties;
 // ---------------------------------------------------------------------------
 
 /// Emit `let mut` declarations for a scalar Out parameter.
-pub fn emit_out_param_var_decl(out: &mut String, param: &ParamInfo, mapped: &MappedType) {
+pub fn emit_out_param_var_decl(out: &mut String, param: &ParamInfo, mapped: &MappedType, ctx: &CodegenContext) {
     let pname = escape_reserved(&to_snake_case(&param.name));
     match mapped.ffi_to_rust {
         ConversionKind::StructOpaque => {
-            out.push_str(&format!("        let mut {pname}_buf = vec![0u8; 256];\n"));
+            let len = struct_buf_len(param.struct_name.as_deref(), ctx);
+            out.push_str(&format!("        let mut {pname}_buf = vec![0u8; {len}];\n"));
+            out.push_str(&format!("        let mut {pname}_size: u32 = 0;\n"));
         }
         ConversionKind::StringUtf8 => {
             out.push_str(&format!("        let mut {pname}_buf = vec![0u8; 512];\n"));
@@ -50,6 +52,88 @@ pub fn emit_inout_string_buf_decl(out: &mut String, param: &ParamInfo, mapped: &
     }
 }
 
+/// Length of the buffer for a struct output, from reflection: through the
+/// struct's type when it has a static struct, else by its UE name. A valid
+/// call's struct outputs therefore always fit.
+pub fn struct_buf_len(struct_name: Option<&str>, ctx: &CodegenContext) -> String {
+    match (struct_name, struct_name.and_then(|sn| ctx.structs.get(sn))) {
+        (_, Some(si)) if si.has_static_struct => format!("uika_runtime::OwnedStruct::<{}>::size()", si.cpp_name),
+        (Some(sn), _) => format!("uika_runtime::containers::struct_size_by_name(\"{sn}\")"),
+        (None, _) => "256".to_string(),
+    }
+}
+
+/// An output the wrapper writes to a caller-sized buffer (see
+/// [`type_map::has_sized_output`]): its buffer and reported-size variables,
+/// and for a string, its slot in the output stash (the param's index).
+pub struct SizedOutput {
+    pub buf: String,
+    pub size: String,
+    pub stash_slot: Option<u32>,
+}
+
+impl SizedOutput {
+    pub fn string(buf: &str, size: &str, slot: u32) -> Self {
+        SizedOutput { buf: buf.to_string(), size: size.to_string(), stash_slot: Some(slot) }
+    }
+
+    pub fn structure(buf: &str, size: &str) -> Self {
+        SizedOutput { buf: buf.to_string(), size: size.to_string(), stash_slot: None }
+    }
+}
+
+/// The stash slot of `param`: its index in the function's `params`, as the
+/// C++ wrapper numbers it.
+pub fn stash_slot(params: &[ParamInfo], param: &ParamInfo) -> u32 {
+    params.iter().position(|p| std::ptr::eq(p, param)).expect("param of this function") as u32
+}
+
+/// The sized output of an Out/InOut param of a function with `params`.
+pub fn sized_output_vars(params: &[ParamInfo], param: &ParamInfo, mapped: &MappedType) -> Option<SizedOutput> {
+    if type_map::param_direction(param) == ParamDirection::Return || !type_map::has_sized_output(param) {
+        return None;
+    }
+    let pname = escape_reserved(&to_snake_case(&param.name));
+    Some(match mapped.ffi_to_rust {
+        ConversionKind::StructOpaque => SizedOutput::structure(&format!("{pname}_buf"), &format!("{pname}_size")),
+        _ => SizedOutput::string(&format!("{pname}_buf"), &format!("{pname}_len"), stash_slot(params, param)),
+    })
+}
+
+// ---------------------------------------------------------------------------
+// FFI call
+// ---------------------------------------------------------------------------
+
+/// Emit the wrapper call `call`, binding its error code to `result`. With
+/// sized outputs, a BufferTooSmall result fetches the strings that did not
+/// fit from the output stash; the UE function runs once. A struct that did
+/// not fit its buffer leaves `result` at BufferTooSmall.
+pub fn emit_ffi_call(out: &mut String, result: &str, call: &str, outputs: &[SizedOutput]) {
+    let strings: Vec<&SizedOutput> = outputs.iter().filter(|o| o.stash_slot.is_some()).collect();
+    if strings.is_empty() {
+        out.push_str(&format!("        let {result} = unsafe {{ {call} }};\n"));
+        return;
+    }
+    out.push_str(&format!(
+        "        let mut {result} = unsafe {{ {call} }};\n\
+         \x20       if {result} == uika_runtime::UikaErrorCode::BufferTooSmall {{\n\
+         \x20           {result} = uika_runtime::output_stash::take_strings(&mut [\n"
+    ));
+    for o in &strings {
+        let slot = o.stash_slot.expect("string output has a stash slot");
+        out.push_str(&format!("                ({slot}, &mut {}, &mut {}),\n", o.buf, o.size));
+    }
+    out.push_str("            ]);\n");
+    for o in outputs.iter().filter(|o| o.stash_slot.is_none()) {
+        out.push_str(&format!(
+            "            if {size} as usize > {buf}.len() {{ {result} = uika_runtime::UikaErrorCode::BufferTooSmall; }}\n",
+            size = o.size,
+            buf = o.buf,
+        ));
+    }
+    out.push_str("        }\n");
+}
+
 // ---------------------------------------------------------------------------
 // Return value conversion (Out/InOut parameters)
 // ---------------------------------------------------------------------------
@@ -65,14 +149,15 @@ pub fn emit_out_param_conversion(
     let pname = escape_reserved(&to_snake_case(&param.name));
     match mapped.ffi_to_rust {
         ConversionKind::ObjectRef => {
-            format!("unsafe {{ uika_runtime::UObjectRef::from_raw({pname}) }}")
+            let ctor = type_map::object_ref_ctor(&mapped.rust_type);
+            format!("unsafe {{ {ctor}({pname}) }}")
         }
         ConversionKind::StringUtf8 => {
             out.push_str(&format!("        {pname}_buf.truncate({pname}_len as usize);\n"));
             out.push_str(&format!(
                 "        let {pname}_str = String::from_utf8_lossy(&{pname}_buf).into_owned();\n"
             ));
-            format!("{pname}_str")
+            type_map::string_to_rust_expr(&mapped.rust_type, &format!("{pname}_str"))
         }
         ConversionKind::EnumCast => {
             let rt = &mapped.rust_type;
@@ -86,9 +171,9 @@ pub fn emit_out_param_conversion(
         }
         ConversionKind::StructOpaque => {
             if is_struct_owned(param.struct_name.as_deref(), ctx) {
-                out.push_str(&format!(
-                    "        let {pname}_owned = uika_runtime::OwnedStruct::from_bytes({pname}_buf);\n"
-                ));
+                out.push_str(&format!("        {pname}_buf.truncate({pname}_size as usize);\n"));
+                let owned = owned_struct_expr(param.struct_name.as_deref(), &format!("{pname}_buf"), ctx);
+                out.push_str(&format!("        let {pname}_owned = {owned};\n"));
                 format!("{pname}_owned")
             } else {
                 out.push_str(&format!("        let {pname}_ptr = {pname}_buf.as_ptr();\n"));
@@ -109,6 +194,30 @@ pub fn emit_out_param_conversion(
     }
 }
 
+/// Expression turning the struct bytes in `buf` into the Rust value of a
+/// struct output: an `OwnedStruct`, or for the structs in
+/// [`type_map::decoded_struct_type`] the value decoded from one.
+pub fn owned_struct_expr(struct_name: Option<&str>, buf: &str, ctx: &CodegenContext) -> String {
+    let decoded = type_map::decoded_struct(struct_name)
+        .zip(struct_name.and_then(|sn| ctx.structs.get(sn)));
+    match decoded {
+        Some(((rust_type, decode), si)) => decode_struct_expr(rust_type, decode, &si.cpp_name, buf),
+        None => format!("uika_runtime::OwnedStruct::from_bytes({buf})"),
+    }
+}
+
+fn decode_struct_expr(rust_type: &str, decode: StructDecode, cpp_name: &str, buf: &str) -> String {
+    match decode {
+        StructDecode::FromStruct => format!(
+            "{rust_type}::from_struct(&uika_runtime::OwnedStruct::<{cpp_name}>::from_bytes({buf}).as_ref())\
+             .expect(\"decode {cpp_name}\")"
+        ),
+        StructDecode::Method(path) => {
+            format!("{path}(&uika_runtime::OwnedStruct::<{cpp_name}>::from_bytes({buf}))")
+        }
+    }
+}
+
 /// Emit the final return expression from a list of return parts.
 pub fn emit_return_expr(out: &mut String, return_parts: &[String]) {
     match return_parts.len() {
@@ -117,3 +226,56 @@ pub fn emit_return_expr(out: &mut String, return_parts: &[String]) {
         _ => out.push_str(&format!("        ({})\n", return_parts.join(", "))),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_oversized_strings_taken_from_stash() {
+        let mut out = String::new();
+        let outputs = [
+            SizedOutput::string("_ret_buf", "_ret_len", 2),
+            SizedOutput::structure("hit_buf", "hit_size"),
+        ];
+        emit_ffi_call(&mut out, "__result", "__uika_fn(h)", &outputs);
+        assert_eq!(out.matches("__uika_fn(h)").count(), 1);
+        assert!(out.contains("output_stash::take_strings(&mut [\n                (2, &mut _ret_buf, &mut _ret_len),"));
+        assert!(out.contains("if hit_size as usize > hit_buf.len() { __result = uika_runtime::UikaErrorCode::BufferTooSmall; }"));
+    }
+
+    #[test]
+    fn test_hit_result_outputs_decoded() {
+        let rust_type = type_map::decoded_struct_type(Some("HitResult")).unwrap();
+        assert_eq!(rust_type, "uika_runtime::HitResult");
+        assert_eq!(type_map::decoded_struct_type(Some("Vector")), None);
+        assert_eq!(
+            decode_struct_expr(rust_type, StructDecode::FromStruct, "FHitResult", "_ret_struct_buf"),
+            "uika_runtime::HitResult::from_struct(&uika_runtime::OwnedStruct::<FHitResult>::from_bytes(\
+             _ret_struct_buf).as_ref()).expect(\"decode FHitResult\")"
+        );
+    }
+
+    #[test]
+    fn test_matrix_and_box_outputs_use_manual_conversions() {
+        let (rust_type, decode) = type_map::decoded_struct(Some("Matrix")).unwrap();
+        assert_eq!(rust_type, "uika_runtime::glam::DMat4");
+        assert_eq!(
+            decode_struct_expr(rust_type, decode, "FMatrix", "m_buf"),
+            "crate::manual::matrix::OwnedFMatrixExt::to_dmat4(&uika_runtime::OwnedStruct::<FMatrix>::from_bytes(m_buf))"
+        );
+        let (rust_type, decode) = type_map::decoded_struct(Some("Box")).unwrap();
+        assert_eq!(rust_type, "uika_runtime::UeBox");
+        assert_eq!(
+            decode_struct_expr(rust_type, decode, "FBox", "b_buf"),
+            "crate::manual::ue_box::OwnedFBoxExt::to_ue_box(&uika_runtime::OwnedStruct::<FBox>::from_bytes(b_buf))"
+        );
+    }
+
+    #[test]
+    fn test_struct_only_outputs_plain_call() {
+        let mut out = String::new();
+        emit_ffi_call(&mut out, "__result", "__uika_fn(h)", &[SizedOutput::structure("hit_buf", "hit_size")]);
+        assert_eq!(out, "        let __result = unsafe { __uika_fn(h) };\n");
+    }
+}
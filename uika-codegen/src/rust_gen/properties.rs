@@ -9,12 +9,18 @@
 //
 // `PropertyContext` captures these differences so all the codegen helpers
 // can be shared.
+//
+// Plain-old-data numeric properties skip the PropertyApi entirely: the
+// property offset is cached once and the value is read/written in place.
 
 use crate::context::CodegenContext;
+use crate::filter::{SkipCategory, SkipReason};
 use crate::naming::{strip_bool_prefix, to_snake_case};
-use crate::schema::PropertyInfo;
+use crate::schema::{PropertyInfo, CPF_IS_PLAIN_OLD_DATA};
 use crate::type_map::{self, ConversionKind, MappedType};
 
+use super::skipped::MemberSkips;
+
 /// Context that parameterizes property codegen for classes vs structs.
 pub struct PropertyContext {
     /// Reflection API function name: "find_property" or "find_struct_property".
@@ -30,14 +36,17 @@ pub struct PropertyContext {
     /// Whether this is a UClass context (true) or struct context (false).
     /// Container properties are only valid in class contexts.
     pub is_class: bool,
+    /// C++ name of the owning class or struct, e.g. "AActor" (for docs).
+    pub owner_cpp: String,
 }
 
 /// Collect supported, deduplicated properties, returning their getter name set and the property list.
 /// Properties referencing types not in the context (e.g., enums/classes from non-enabled modules)
-/// are filtered out.
+/// are filtered out and added to `skipped`.
 pub fn collect_deduped_properties<'a>(
     props: &'a [PropertyInfo],
     ctx: Option<&CodegenContext>,
+    skipped: &mut MemberSkips,
 ) -> (std::collections::HashSet<String>, Vec<&'a PropertyInfo>) {
     let mut prop_names = std::collections::HashSet::new();
     let mut deduped = Vec::new();
@@ -47,6 +56,11 @@ pub fn collect_deduped_properties<'a>(
         if prop.getter.is_some() || prop.setter.is_some() {
             continue;
         }
+        let mut skip = |category, reason: String, offending: Option<&str>| {
+            let mut reason = SkipReason::new(category, reason);
+            reason.offending = offending.map(str::to_string);
+            skipped.property(&prop.name, reason);
+        };
 
         let mapped = type_map::map_property_type(
             &prop.prop_type,
@@ -58,6 +72,8 @@ pub fn collect_deduped_properties<'a>(
             prop.interface_name.as_deref(),
         );
         if !mapped.supported {
+            let reason = format!("no Rust mapping for `{}`", prop.prop_type);
+            skip(SkipCategory::UnsupportedType, reason, Some(&prop.prop_type));
             continue;
         }
 
@@ -75,6 +91,13 @@ pub fn collect_deduped_properties<'a>(
                     _ => prop.class_name.as_deref().map_or(true, |c| ctx.classes.contains_key(c)),
                 };
                 if !type_available {
+                    let referenced = match prop.prop_type.as_str() {
+                        "ClassProperty" => prop.meta_class_name.as_deref().or(prop.class_name.as_deref()),
+                        "InterfaceProperty" => prop.interface_name.as_deref(),
+                        _ => prop.class_name.as_deref(),
+                    };
+                    let reason = format!("references unavailable class `{}`", referenced.unwrap_or("?"));
+                    skip(SkipCategory::UnavailableType, reason, referenced);
                     continue;
                 }
             }
@@ -90,6 +113,9 @@ pub fn collect_deduped_properties<'a>(
                 prop.struct_name.is_some()
             };
             if !valid {
+                let sn = prop.struct_name.as_deref();
+                let reason = format!("struct `{}` is unavailable or has no StaticStruct", sn.unwrap_or("?"));
+                skip(SkipCategory::UnavailableType, reason, sn);
                 continue;
             }
         }
@@ -100,6 +126,8 @@ pub fn collect_deduped_properties<'a>(
             ConversionKind::ContainerArray | ConversionKind::ContainerMap | ConversionKind::ContainerSet
         ) {
             if type_map::resolve_container_rust_type(prop, ctx).is_none() {
+                let reason = format!("`{}` element type can't be mapped", prop.prop_type);
+                skip(SkipCategory::UnsupportedType, reason, Some(&prop.prop_type));
                 continue;
             }
             // Container properties only work on UClass contexts (need UObject owner),
@@ -113,6 +141,7 @@ pub fn collect_deduped_properties<'a>(
                 ConversionKind::EnumCast => {
                     if let Some(en) = &prop.enum_name {
                         if !ctx.enums.contains_key(en.as_str()) {
+                            skip(SkipCategory::UnavailableType, format!("references unavailable enum `{en}`"), Some(en));
                             continue;
                         }
                     }
@@ -120,6 +149,7 @@ pub fn collect_deduped_properties<'a>(
                 ConversionKind::ObjectRef => {
                     if let Some(cn) = &prop.class_name {
                         if !ctx.classes.contains_key(cn.as_str()) {
+                            skip(SkipCategory::UnavailableType, format!("references unavailable class `{cn}`"), Some(cn));
                             continue;
                         }
                     }
@@ -149,6 +179,7 @@ pub fn collect_deduped_properties<'a>(
             format!("get_{rust_name}")
         };
         if prop_names.contains(&getter_name) {
+            skip(SkipCategory::Duplicate, format!("accessor `{getter_name}` already generated for another property"), None);
             continue;
         }
         prop_names.insert(getter_name);
@@ -163,13 +194,26 @@ pub fn collect_deduped_properties<'a>(
 
 /// Generate a getter and setter for a single property (used as default impls in Ext trait).
 ///
-/// Setters whose name appears in `suppress_setters` are skipped.
+/// Setters whose name appears in `suppress_setters` are skipped. Every
+/// accessor is documented from the property's UE metadata.
 pub fn generate_property(
     out: &mut String,
     prop: &PropertyInfo,
     pctx: &PropertyContext,
     ctx: &CodegenContext,
     suppress_setters: &std::collections::HashSet<String>,
+) {
+    let start = out.len();
+    generate_property_accessors(out, prop, pctx, ctx, suppress_setters);
+    super::docs::insert_before_fns(out, start, &super::docs::property_doc(prop, &pctx.owner_cpp));
+}
+
+fn generate_property_accessors(
+    out: &mut String,
+    prop: &PropertyInfo,
+    pctx: &PropertyContext,
+    ctx: &CodegenContext,
+    suppress_setters: &std::collections::HashSet<String>,
 ) {
     let mapped = type_map::map_property_type(
         &prop.prop_type,
@@ -221,17 +265,30 @@ pub fn generate_property(
         return;
     }
 
+    // Plain-old-data numerics: read/write at the cached offset, no PropertyApi call
+    if let Some(pod_type) = pod_fast_path_type(prop, &mapped) {
+        generate_pod_getter(out, &rust_name, &byte_lit, prop_name_len, pctx, &mapped, pod_type);
+        if !suppress_setters.contains(&format!("set_{rust_name}")) {
+            generate_pod_setter(out, &rust_name, &byte_lit, prop_name_len, pctx, &mapped, pod_type);
+        }
+        return;
+    }
+
     // Getter
     match mapped.rust_to_ffi {
         ConversionKind::StringUtf8 => {
-            generate_string_getter(out, &rust_name, &byte_lit, prop_name_len, pctx);
+            generate_string_getter(out, &rust_name, &byte_lit, prop_name_len, pctx, &mapped);
         }
         ConversionKind::StructOpaque => {
-            let struct_cpp = prop.struct_name.as_deref()
-                .and_then(|sn| ctx.structs.get(sn))
+            let struct_info = prop.struct_name.as_deref().and_then(|sn| ctx.structs.get(sn));
+            let struct_cpp = struct_info
                 .map(|si| si.cpp_name.clone())
                 .unwrap_or_else(|| format!("F{}", prop.struct_name.as_deref().unwrap_or("Unknown")));
             generate_struct_getter(out, &rust_name, &byte_lit, prop_name_len, pctx, &struct_cpp);
+            // Views need the struct's UeStruct impl, which needs a StaticStruct.
+            if ctx.struct_views && struct_info.is_some_and(|si| si.has_static_struct) {
+                generate_struct_view(out, &rust_name, &byte_lit, prop_name_len, pctx, &struct_cpp);
+            }
             let setter_name = format!("set_{rust_name}");
             if !suppress_setters.contains(&setter_name) {
                 generate_struct_setter(out, &rust_name, &byte_lit, prop_name_len, pctx, &struct_cpp);
@@ -267,7 +324,7 @@ pub fn generate_property(
     }
     match mapped.rust_to_ffi {
         ConversionKind::StringUtf8 => {
-            generate_string_setter(out, &rust_name, &byte_lit, prop_name_len, pctx);
+            generate_string_setter(out, &rust_name, &byte_lit, prop_name_len, pctx, &mapped);
         }
         ConversionKind::StructOpaque => { /* handled in getter branch with early return */ }
         ConversionKind::ObjectRef => {
@@ -317,6 +374,18 @@ fn emit_prop_lookup(out: &mut String, byte_lit: &str, prop_name_len: usize, pctx
     ));
 }
 
+/// Generates the cached property offset lookup: `None` if the property was
+/// not found, so callers never read at offset 0 by mistake. Must follow
+/// `emit_prop_lookup`.
+fn emit_offset_lookup(out: &mut String) {
+    out.push_str(
+        "        static OFFSET: std::sync::OnceLock<Option<usize>> = std::sync::OnceLock::new();\n\
+         \x20       let offset = *OFFSET.get_or_init(|| {\n\
+         \x20           (!prop.is_null()).then(|| unsafe { uika_runtime::ffi_dispatch::reflection_get_property_offset(prop) as usize })\n\
+         \x20       });\n"
+    );
+}
+
 /// Emit pre-access (validity check) if needed.
 fn emit_pre_access(out: &mut String, pctx: &PropertyContext) {
     if !pctx.pre_access.is_empty() {
@@ -324,6 +393,90 @@ fn emit_pre_access(out: &mut String, pctx: &PropertyContext) {
     }
 }
 
+// ---------------------------------------------------------------------------
+// Plain-old-data fast path
+// ---------------------------------------------------------------------------
+
+/// The Rust type to read/write in place for a CPF_IsPlainOldData numeric
+/// property, or `None` if it must go through the PropertyApi. Bools are
+/// excluded since they may be bitfields; enums keep their validated cast.
+fn pod_fast_path_type<'m>(prop: &PropertyInfo, mapped: &'m MappedType) -> Option<&'m str> {
+    if prop.prop_flags & CPF_IS_PLAIN_OLD_DATA == 0 || prop.array_dim > 1 {
+        return None;
+    }
+    match prop.prop_type.as_str() {
+        "Int8Property" | "Int16Property" | "UInt16Property" | "IntProperty" | "UInt32Property"
+        | "Int64Property" | "UInt64Property" | "FloatProperty" | "DoubleProperty" => {
+            Some(&mapped.rust_type)
+        }
+        "ByteProperty" if prop.enum_name.is_none() => Some(&mapped.rust_type),
+        _ => None,
+    }
+}
+
+fn generate_pod_getter(
+    out: &mut String,
+    rust_name: &str,
+    byte_lit: &str,
+    prop_name_len: usize,
+    pctx: &PropertyContext,
+    mapped: &MappedType,
+    rust_type: &str,
+) {
+    let c = &pctx.container_expr;
+    let getter = &mapped.property_getter;
+    let ffi_type = &mapped.rust_ffi_type;
+    let default = default_value_for(ffi_type);
+    let cast = if ffi_type == rust_type { String::new() } else { format!(" as {rust_type}") };
+
+    out.push_str(&format!(
+        "    fn get_{rust_name}(&self) -> {rust_type} {{\n"
+    ));
+    emit_prop_lookup(out, byte_lit, prop_name_len, pctx);
+    emit_offset_lookup(out);
+    emit_pre_access(out, pctx);
+    // Unresolved property: go through the checked PropertyApi, which reports it.
+    out.push_str(&format!(
+        "        let Some(offset) = offset else {{\n\
+         \x20           let mut out = {default};\n\
+         \x20           uika_runtime::ffi_infallible_ctx(unsafe {{ uika_runtime::ffi_dispatch::property_{getter}({c}, prop, &mut out) }}, \"{rust_name}\");\n\
+         \x20           return out{cast};\n\
+         \x20       }};\n\
+         \x20       unsafe {{ uika_runtime::ffi_dispatch::native_mem_read::<{rust_type}>({c}.0 as uika_runtime::ffi_dispatch::NativePtr, offset) }}\n\
+         \x20   }}\n\n"
+    ));
+}
+
+fn generate_pod_setter(
+    out: &mut String,
+    rust_name: &str,
+    byte_lit: &str,
+    prop_name_len: usize,
+    pctx: &PropertyContext,
+    mapped: &MappedType,
+    rust_type: &str,
+) {
+    let c = &pctx.container_expr;
+    let setter = &mapped.property_setter;
+    let ffi_type = &mapped.rust_ffi_type;
+    let cast = if ffi_type == rust_type { String::new() } else { format!(" as {ffi_type}") };
+
+    out.push_str(&format!(
+        "    fn set_{rust_name}(&self, val: {rust_type}) {{\n"
+    ));
+    emit_prop_lookup(out, byte_lit, prop_name_len, pctx);
+    emit_offset_lookup(out);
+    emit_pre_access(out, pctx);
+    out.push_str(&format!(
+        "        let Some(offset) = offset else {{\n\
+         \x20           uika_runtime::ffi_infallible_ctx(unsafe {{ uika_runtime::ffi_dispatch::property_{setter}({c}, prop, val{cast}) }}, \"{rust_name}\");\n\
+         \x20           return;\n\
+         \x20       }};\n\
+         \x20       unsafe {{ uika_runtime::ffi_dispatch::native_mem_write::<{rust_type}>({c}.0 as uika_runtime::ffi_dispatch::NativePtr, offset, val) }}\n\
+         \x20   }}\n\n"
+    ));
+}
+
 // ---------------------------------------------------------------------------
 // Getters
 // ---------------------------------------------------------------------------
@@ -387,11 +540,15 @@ fn generate_string_getter(
     byte_lit: &str,
     prop_name_len: usize,
     pctx: &PropertyContext,
+    mapped: &MappedType,
 ) {
     let c = &pctx.container_expr;
+    let rust_type = &mapped.rust_type;
+    let value = type_map::string_to_rust_expr(rust_type, "String::from_utf8_lossy(&buf).into_owned()");
 
+    // The getter truncates to the buffer and reports the full length.
     out.push_str(&format!(
-        "    fn get_{rust_name}(&self) -> String {{\n"
+        "    fn get_{rust_name}(&self) -> {rust_type} {{\n"
     ));
     emit_prop_lookup(out, byte_lit, prop_name_len, pctx);
     emit_pre_access(out, pctx);
@@ -401,8 +558,14 @@ fn generate_string_getter(
          \x20       uika_runtime::ffi_infallible_ctx(unsafe {{\n\
          \x20           uika_runtime::ffi_dispatch::property_get_string({c}, prop, buf.as_mut_ptr(), buf.len() as u32, &mut out_len)\n\
          \x20       }}, \"{rust_name}\");\n\
+         \x20       if out_len as usize > buf.len() {{\n\
+         \x20           buf.resize(out_len as usize, 0);\n\
+         \x20           uika_runtime::ffi_infallible_ctx(unsafe {{\n\
+         \x20               uika_runtime::ffi_dispatch::property_get_string({c}, prop, buf.as_mut_ptr(), buf.len() as u32, &mut out_len)\n\
+         \x20           }}, \"{rust_name}\");\n\
+         \x20       }}\n\
          \x20       buf.truncate(out_len as usize);\n\
-         \x20       String::from_utf8_lossy(&buf).into_owned()\n\
+         \x20       {value}\n\
          \x20   }}\n\n"
     ));
 }
@@ -416,6 +579,7 @@ fn generate_object_getter(
     mapped: &MappedType,
 ) {
     let rust_type = &mapped.rust_type;
+    let ctor = type_map::object_ref_ctor(rust_type);
     let c = &pctx.container_expr;
 
     out.push_str(&format!(
@@ -426,7 +590,7 @@ fn generate_object_getter(
     out.push_str(&format!(
         "        let mut raw = uika_runtime::UObjectHandle::null();\n\
          \x20       uika_runtime::ffi_infallible_ctx(unsafe {{ uika_runtime::ffi_dispatch::property_get_object({c}, prop, &mut raw) }}, \"{rust_name}\");\n\
-         \x20       unsafe {{ uika_runtime::UObjectRef::from_raw(raw) }}\n\
+         \x20       unsafe {{ {ctor}(raw) }}\n\
          \x20   }}\n\n"
     ));
 }
@@ -535,14 +699,20 @@ fn generate_string_setter(
     byte_lit: &str,
     prop_name_len: usize,
     pctx: &PropertyContext,
+    mapped: &MappedType,
 ) {
     let c = &pctx.container_expr;
+    let is_soft = type_map::is_soft_ptr_type(&mapped.rust_type);
+    let val_type = if is_soft { format!("&{}", mapped.rust_type) } else { "&str".to_string() };
 
     out.push_str(&format!(
-        "    fn set_{rust_name}(&self, val: &str) {{\n"
+        "    fn set_{rust_name}(&self, val: {val_type}) {{\n"
     ));
     emit_prop_lookup(out, byte_lit, prop_name_len, pctx);
     emit_pre_access(out, pctx);
+    if is_soft {
+        out.push_str("        let val = val.path();\n");
+    }
     out.push_str(&format!(
         "        uika_runtime::ffi_infallible_ctx(unsafe {{\n\
          \x20           uika_runtime::ffi_dispatch::property_set_string({c}, prop, val.as_ptr(), val.len() as u32)\n\
@@ -645,6 +815,38 @@ fn generate_struct_getter(
     ));
 }
 
+/// Zero-copy accessor (`[codegen] struct_views`): a `StructView` of the
+/// property where it lives, at the cached offset. Nothing goes through the
+/// PropertyApi after the first call. The view reads object memory with no
+/// further checks, so the accessor is `unsafe`.
+fn generate_struct_view(
+    out: &mut String,
+    rust_name: &str,
+    byte_lit: &str,
+    prop_name_len: usize,
+    pctx: &PropertyContext,
+    struct_cpp: &str,
+) {
+    let c = &pctx.container_expr;
+
+    out.push_str(&format!(
+        "    /// # Safety\n\
+         \x20   /// The object or struct holding `{rust_name}` must stay alive, and the\n\
+         \x20   /// property must not be written, for as long as the view is used.\n\
+         \x20   unsafe fn view_{rust_name}(&self) -> uika_runtime::UikaResult<uika_runtime::StructView<'_, {struct_cpp}>> {{\n"
+    ));
+    emit_prop_lookup(out, byte_lit, prop_name_len, pctx);
+    emit_offset_lookup(out);
+    emit_pre_access(out, pctx);
+    out.push_str(&format!(
+        "        let Some(offset) = offset else {{\n\
+         \x20           return Err(uika_runtime::UikaError::PropertyNotFound(\"{rust_name}\".into()));\n\
+         \x20       }};\n\
+         \x20       Ok(unsafe {{ uika_runtime::StructView::from_raw(({c}.0 as uika_runtime::ffi_dispatch::NativePtr).add(offset)) }})\n\
+         \x20   }}\n\n"
+    ));
+}
+
 fn generate_struct_setter(
     out: &mut String,
     rust_name: &str,
@@ -709,10 +911,11 @@ fn generate_fixed_array_property(
         }
         ConversionKind::ObjectRef => {
             let rust_type = &mapped.rust_type;
+            let ctor = type_map::object_ref_ctor(rust_type);
             (
                 rust_type.clone(),
-                "let handle = uika_runtime::UObjectHandle::from_addr(u64::from_ne_bytes(buf[..8].try_into().unwrap()));\n\
-                 \x20       Ok(unsafe { uika_runtime::UObjectRef::from_raw(handle) })".to_string(),
+                format!("let handle = uika_runtime::UObjectHandle::from_addr(u64::from_ne_bytes(buf[..8].try_into().unwrap()));\n\
+                 \x20       Ok(unsafe {{ {ctor}(handle) }})"),
                 rust_type.clone(),
                 "let buf = val.raw().to_addr().to_ne_bytes().to_vec();".to_string(),
             )
@@ -0,0 +1,155 @@
+// Skipped-item table: `skipped.rs`, listing everything left out of the
+// bindings (by the filters, the function table or Rust generation) with its
+// category, reason and offending parameter or type. Queryable at runtime
+// through `uika_bindings::skipped`, and the same records feed the coverage
+// report.
+
+use crate::context::CodegenContext;
+use crate::filter::{Exclusion, FilterReport, SkipReason, SkipStage};
+
+use super::{classes, structs};
+
+/// Members of one class or struct that Rust generation leaves out.
+#[derive(Default)]
+pub struct MemberSkips {
+    /// `(kind, member name, reason)`.
+    pub skips: Vec<(&'static str, String, SkipReason)>,
+}
+
+impl MemberSkips {
+    pub fn function(&mut self, name: &str, reason: SkipReason) {
+        self.skips.push(("function", name.to_string(), reason));
+    }
+
+    pub fn property(&mut self, name: &str, reason: SkipReason) {
+        self.skips.push(("property", name.to_string(), reason));
+    }
+}
+
+/// Add the members Rust generation will leave out of each class and struct
+/// to `report`. Runs the same member selection as the generators, so it
+/// covers types whose files are fresh in an incremental run too.
+pub fn collect(ctx: &CodegenContext, report: &mut FilterReport) {
+    let mut add = |module: &str, owner: &str, skipped: MemberSkips| {
+        for (kind, member, reason) in skipped.skips {
+            report.exclude(kind, SkipStage::RustGen, module, format!("{owner}.{member}"), reason);
+        }
+    };
+    for (module, classes) in &ctx.module_classes {
+        for class in classes {
+            let mut skipped = MemberSkips::default();
+            classes::class_members(class, ctx, &mut skipped);
+            add(module, &class.name, skipped);
+        }
+    }
+    for (module, structs) in &ctx.module_structs {
+        for s in structs {
+            let mut skipped = MemberSkips::default();
+            structs::struct_props(s, ctx, &mut skipped);
+            add(module, &s.name, skipped);
+        }
+    }
+}
+
+/// Generate `skipped.rs` from every exclusion of the run.
+pub fn generate_skipped_rs(excluded: &[Exclusion]) -> String {
+    // (owner, name) order, so the generated lookups can binary search.
+    let mut rows: Vec<(&str, &str, &Exclusion)> = excluded
+        .iter()
+        .map(|e| match e.kind {
+            "class" | "struct" => ("", e.name.as_str(), e),
+            _ => e.name.split_once('.').map_or(("", e.name.as_str(), e), |(owner, name)| (owner, name, e)),
+        })
+        .collect();
+    rows.sort_by(|a, b| (a.0, a.1, a.2.kind).cmp(&(b.0, b.1, b.2.kind)));
+
+    let mut out = String::with_capacity(rows.len() * 200 + 2048);
+    out.push_str("// Auto-generated by uika-codegen. Do not edit.\n\n");
+    out.push_str("//! Everything codegen left out of these bindings, and why.\n\n");
+    out.push_str(
+        "/// One item without generated bindings.\n\
+         #[derive(Clone, Copy, Debug)]\n\
+         pub struct Skipped {\n\
+         \x20   /// \"class\", \"struct\", \"function\" or \"property\".\n\
+         \x20   pub kind: &'static str,\n\
+         \x20   /// Bindings module of the item (or of its owner).\n\
+         \x20   pub module: &'static str,\n\
+         \x20   /// UE name of the owning class or struct; \"\" for a class or struct itself.\n\
+         \x20   pub owner: &'static str,\n\
+         \x20   /// UE name of the item (functions may carry an overload suffix).\n\
+         \x20   pub name: &'static str,\n\
+         \x20   /// Where it was left out: \"filter\", \"func_table\" or \"rust_gen\".\n\
+         \x20   pub stage: &'static str,\n\
+         \x20   /// \"blocklist\", \"allowlist\", \"unsupported_type\", \"unavailable_type\",\n\
+         \x20   /// \"not_public\", \"not_callable\" or \"duplicate\".\n\
+         \x20   pub category: &'static str,\n\
+         \x20   pub reason: &'static str,\n\
+         \x20   /// The parameter (`\"Name: Type\"`) or type responsible, if one is.\n\
+         \x20   pub offending: Option<&'static str>,\n\
+         }\n\n",
+    );
+
+    out.push_str("/// Every skipped item, sorted by (owner, name).\n");
+    out.push_str("pub static SKIPPED: &[Skipped] = &[\n");
+    for (owner, name, e) in &rows {
+        let offending = match &e.offending {
+            Some(o) => format!("Some({o:?})"),
+            None => "None".to_string(),
+        };
+        out.push_str(&format!(
+            "    Skipped {{ kind: {:?}, module: {:?}, owner: {owner:?}, name: {name:?}, stage: {:?}, \
+             category: {:?}, reason: {:?}, offending: {offending} }},\n",
+            e.kind,
+            e.module,
+            e.stage.as_str(),
+            e.category.as_str(),
+            e.reason,
+        ));
+    }
+    out.push_str("];\n\n");
+
+    out.push_str(
+        "/// Skipped members of the class or struct `owner` (UE name without prefix,\n\
+         /// e.g. \"Actor\").\n\
+         pub fn skipped_in(owner: &str) -> &'static [Skipped] {\n\
+         \x20   let start = SKIPPED.partition_point(|s| s.owner < owner);\n\
+         \x20   let end = start + SKIPPED[start..].partition_point(|s| s.owner == owner);\n\
+         \x20   &SKIPPED[start..end]\n\
+         }\n\n\
+         /// Why `owner.name` has no bindings, if it was skipped. Pass \"\" as\n\
+         /// `owner` to look up a class or struct.\n\
+         pub fn find(owner: &str, name: &str) -> Option<&'static Skipped> {\n\
+         \x20   skipped_in(owner).iter().find(|s| s.name == name)\n\
+         }\n",
+    );
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::filter::SkipCategory;
+
+    #[test]
+    fn table_is_sorted_by_owner_and_name() {
+        let mut report = FilterReport::default();
+        let reason = |text: &str| SkipReason::new(SkipCategory::UnsupportedType, text.to_string());
+        report.exclude("function", SkipStage::RustGen, "engine", "Pawn.Zed".into(), reason("z"));
+        report.exclude("class", SkipStage::Filter, "engine", "Widget".into(), reason("w"));
+        report.exclude(
+            "function",
+            SkipStage::Filter,
+            "engine",
+            "Actor.Foo".into(),
+            reason("has \"quotes\"").offending("Delegate: DelegateProperty"),
+        );
+
+        let code = generate_skipped_rs(&report.excluded);
+        let widget = code.find(r#"owner: "", name: "Widget""#).unwrap();
+        let foo = code.find(r#"owner: "Actor", name: "Foo""#).unwrap();
+        let zed = code.find(r#"owner: "Pawn", name: "Zed""#).unwrap();
+        assert!(widget < foo && foo < zed);
+        assert!(code.contains(r#"reason: "has \"quotes\"", offending: Some("Delegate: DelegateProperty")"#));
+        assert!(code.contains(r#"stage: "rust_gen""#));
+    }
+}
@@ -1,9 +1,14 @@
-// Rust struct generation: opaque markers, UeStruct trait, and property accessors.
+// Rust struct generation: opaque markers, UeStruct trait, property accessors,
+// and (behind the `serde` feature) a SerdeStruct impl over those accessors.
 
 use crate::context::CodegenContext;
-use crate::schema::StructInfo;
+use crate::filter::{SkipCategory, SkipReason};
+use crate::naming::{strip_bool_prefix, to_snake_case};
+use crate::schema::{PropertyInfo, StructInfo};
 
+use super::docs;
 use super::properties::{self, PropertyContext};
+use super::skipped::MemberSkips;
 
 /// Generate Rust code for a single UE struct.
 pub fn generate_struct(s: &StructInfo, ctx: &CodegenContext) -> String {
@@ -29,10 +34,8 @@ pub fn generate_struct(s: &StructInfo, ctx: &CodegenContext) -> String {
     }
     out.push('\n');
 
-    out.push_str(&format!(
-        "/// Opaque UE struct `{name}`. Layout managed by C++ side.\n\
-         pub struct {name};\n\n"
-    ));
+    docs::emit_doc(&mut out, "", &docs::struct_doc(s));
+    out.push_str(&format!("pub struct {name};\n\n"));
 
     if s.has_static_struct {
         let name_bytes = stripped.as_bytes();
@@ -51,7 +54,7 @@ pub fn generate_struct(s: &StructInfo, ctx: &CodegenContext) -> String {
         ));
 
         // Generate property accessors if the struct has properties and static_struct
-        let (_, deduped_props) = properties::collect_deduped_properties(&s.props, Some(ctx));
+        let deduped_props = struct_props(s, ctx, &mut MemberSkips::default());
         if !deduped_props.is_empty() {
             let pctx = PropertyContext {
                 find_prop_fn: "find_struct_property".to_string(),
@@ -59,6 +62,7 @@ pub fn generate_struct(s: &StructInfo, ctx: &CodegenContext) -> String {
                 pre_access: String::new(), // No validity check for structs
                 container_expr: "self.as_ptr()".to_string(),
                 is_class: false,
+                owner_cpp: name.clone(),
             };
 
             let trait_name = format!("{name}Ext");
@@ -85,9 +89,123 @@ pub fn generate_struct(s: &StructInfo, ctx: &CodegenContext) -> String {
                 "impl {trait_name} for uika_runtime::UStructRef<{name}> {{\n"
             ));
             out.push_str(&body_buf);
-            out.push_str("}\n");
+            out.push_str("}\n\n");
         }
+
+        generate_serde_impl(&mut out, name, &deduped_props, ctx);
     }
 
     out
 }
+
+/// The properties of `s` that get accessors, adding the ones left out to
+/// `skipped`. Without a StaticStruct there is no reflection handle to look
+/// properties up on, so none are generated.
+pub(super) fn struct_props<'a>(s: &'a StructInfo, ctx: &CodegenContext, skipped: &mut MemberSkips) -> Vec<&'a PropertyInfo> {
+    if !s.has_static_struct {
+        for prop in &s.props {
+            let reason = format!("struct `{}` has no StaticStruct", s.name);
+            skipped.property(&prop.name, SkipReason::new(SkipCategory::UnsupportedType, reason));
+        }
+        return Vec::new();
+    }
+    properties::collect_deduped_properties(&s.props, Some(ctx), skipped).1
+}
+
+/// How a struct field is carried through serde.
+enum SerdeField {
+    /// Numbers and bools: the getter/setter type is serialized directly.
+    Value,
+    /// FString / FText: setter takes `&str`.
+    String,
+    /// FName: serialized as its string.
+    Name,
+    /// Nested struct: `OwnedStruct<T>` via its own SerdeStruct impl.
+    Struct(String),
+}
+
+fn serde_field_kind(prop: &PropertyInfo, ctx: &CodegenContext) -> Option<SerdeField> {
+    if prop.array_dim > 1 {
+        return None;
+    }
+    match prop.prop_type.as_str() {
+        "BoolProperty" | "Int8Property" | "Int16Property" | "UInt16Property" | "IntProperty"
+        | "UInt32Property" | "Int64Property" | "UInt64Property" | "FloatProperty"
+        | "DoubleProperty" => Some(SerdeField::Value),
+        "ByteProperty" if prop.enum_name.is_none() => Some(SerdeField::Value),
+        "StrProperty" | "TextProperty" => Some(SerdeField::String),
+        "NameProperty" => Some(SerdeField::Name),
+        "StructProperty" => {
+            let si = ctx.structs.get(prop.struct_name.as_deref()?)?;
+            si.has_static_struct.then(|| SerdeField::Struct(si.cpp_name.clone()))
+        }
+        _ => None,
+    }
+}
+
+/// Generate `impl SerdeStruct` for a struct, serializing each value-like
+/// property under its UE name through the generated accessors.
+fn generate_serde_impl(out: &mut String, name: &str, props: &[&PropertyInfo], ctx: &CodegenContext) {
+    let fields: Vec<(&PropertyInfo, SerdeField)> = props
+        .iter()
+        .filter_map(|p| serde_field_kind(p, ctx).map(|k| (*p, k)))
+        .collect();
+
+    let field_names: Vec<String> = fields.iter().map(|(p, _)| format!("\"{}\"", p.name)).collect();
+    let mut ser = String::new();
+    let mut de = String::new();
+    for (prop, kind) in &fields {
+        let ue_name = &prop.name;
+        let rust_name = if prop.prop_type == "BoolProperty" {
+            strip_bool_prefix(ue_name)
+        } else {
+            to_snake_case(ue_name)
+        };
+        let (get_expr, set_stmt) = match kind {
+            SerdeField::Value => (
+                format!("r.get_{rust_name}()"),
+                format!("r.set_{rust_name}(map.next_value()?)"),
+            ),
+            SerdeField::String => (
+                format!("r.get_{rust_name}()"),
+                format!("r.set_{rust_name}(&map.next_value::<String>()?)"),
+            ),
+            SerdeField::Name => (
+                format!("uika_runtime::FName(r.get_{rust_name}()).to_string_lossy()"),
+                format!("r.set_{rust_name}(uika_runtime::FName::new(&map.next_value::<String>()?).handle())"),
+            ),
+            SerdeField::Struct(cpp_name) => (
+                format!("r.get_{rust_name}()"),
+                format!("r.set_{rust_name}(&map.next_value::<uika_runtime::OwnedStruct<{cpp_name}>>()?)"),
+            ),
+        };
+        ser.push_str(&format!("        s.serialize_field(\"{ue_name}\", &{get_expr})?;\n"));
+        de.push_str(&format!("            \"{ue_name}\" => {set_stmt},\n"));
+    }
+    let (unused_ser, unused_de) = if fields.is_empty() {
+        ("        let _ = (r, s);\n", "        let _ = r;\n")
+    } else {
+        ("", "")
+    };
+
+    out.push_str(&format!(
+        "#[cfg(feature = \"serde\")]\n\
+         impl uika_runtime::SerdeStruct for {name} {{\n\
+         \x20   const NAME: &'static str = \"{name}\";\n\
+         \x20   const FIELDS: &'static [&'static str] = &[{field_list}];\n\n\
+         \x20   fn serialize_fields<S: uika_runtime::serde::ser::SerializeStruct>(r: &uika_runtime::UStructRef<Self>, s: &mut S) -> Result<(), S::Error> {{\n\
+         {unused_ser}{ser}\
+         \x20       Ok(())\n\
+         \x20   }}\n\n\
+         \x20   fn deserialize_field<'de, A: uika_runtime::serde::de::MapAccess<'de>>(r: &uika_runtime::UStructRef<Self>, field: &str, map: &mut A) -> Result<(), A::Error> {{\n\
+         {unused_de}\
+         \x20       match field {{\n\
+         {de}\
+         \x20           _ => uika_runtime::serde_support::skip_field(map)?,\n\
+         \x20       }}\n\
+         \x20       Ok(())\n\
+         \x20   }}\n\
+         }}\n",
+        field_list = field_names.join(", "),
+    ));
+}
@@ -11,6 +11,9 @@ use serde::{Deserialize, Deserializer};
 #[derive(Deserialize)]
 pub struct ClassesFile {
     pub classes: Vec<ClassInfo>,
+    /// Engine version the export came from, e.g. "5.7.0".
+    #[serde(default)]
+    pub engine_version: Option<String>,
 }
 
 #[derive(Deserialize)]
@@ -43,6 +46,10 @@ pub struct ClassInfo {
     pub props: Vec<PropertyInfo>,
     #[serde(default)]
     pub funcs: Vec<FunctionInfo>,
+    /// `ToolTip` metadata (the UE doc comment).
+    pub tooltip: Option<String>,
+    /// `Category` metadata.
+    pub category: Option<String>,
 }
 
 // ---------------------------------------------------------------------------
@@ -62,6 +69,8 @@ pub struct StructInfo {
     pub has_static_struct: bool,
     #[serde(default)]
     pub props: Vec<PropertyInfo>,
+    /// `ToolTip` metadata (the UE doc comment).
+    pub tooltip: Option<String>,
 }
 
 // ---------------------------------------------------------------------------
@@ -106,6 +115,10 @@ pub struct PropertyInfo {
     pub getter: Option<String>,
     pub setter: Option<String>,
     pub default: Option<String>,
+    /// `ToolTip` metadata (the UE doc comment).
+    pub tooltip: Option<String>,
+    /// `Category` metadata.
+    pub category: Option<String>,
 }
 
 fn default_array_dim() -> u32 {
@@ -125,6 +138,10 @@ pub struct FunctionInfo {
     pub is_static: bool,
     #[serde(default)]
     pub params: Vec<ParamInfo>,
+    /// `ToolTip` metadata (the UE doc comment, including `@param` lines).
+    pub tooltip: Option<String>,
+    /// `Category` metadata.
+    pub category: Option<String>,
     /// Original UE function name (before overload renaming). Set by filter.
     #[serde(skip)]
     pub ue_name: String,
@@ -162,6 +179,7 @@ pub struct ParamInfo {
 
 pub use uika_ue_flags::{
     CPF_CONST_PARM, CPF_OUT_PARM, CPF_REFERENCE_PARM, CPF_RETURN_PARM,
+    CPF_IS_PLAIN_OLD_DATA,
     CPF_NATIVE_ACCESS_SPECIFIER_PRIVATE as CPF_NATIVE_ACCESS_PRIVATE,
     CPF_NATIVE_ACCESS_SPECIFIER_PROTECTED as CPF_NATIVE_ACCESS_PROTECTED,
     FUNC_NATIVE, FUNC_STATIC, FUNC_BLUEPRINT_EVENT,
@@ -88,6 +88,7 @@ const SUPPORTED_TYPES: &[&str] = &[
     "MapProperty",
     "SetProperty",
     "SoftObjectProperty",
+    "SoftClassProperty",
     "WeakObjectProperty",
     "InterfaceProperty",
     "DelegateProperty",
@@ -219,9 +220,25 @@ pub fn map_property_type(
                 }
             }
         }
-        "SoftObjectProperty" | "WeakObjectProperty" => {
-            // TSoftObjectPtr<T> / TWeakObjectPtr<T> resolve to UObject* via
-            // FObjectPropertyBase — use the same ObjectRef mapping as ObjectProperty.
+        "SoftObjectProperty" => {
+            // TSoftObjectPtr<T> crosses the FFI as its asset path (UTF-8), so
+            // unloaded assets keep their identity.
+            if let Some(cls) = class_name {
+                soft_ptr_type(&format!("uika_runtime::SoftObjectPtr<{cls}>"), &format!("TSoftObjectPtr<{cls}>"))
+            } else {
+                unsupported("SoftObjectProperty without class_name")
+            }
+        }
+        "SoftClassProperty" => {
+            if let Some(cls) = meta_class_name.or(class_name) {
+                soft_ptr_type(&format!("uika_runtime::SoftClassPtr<{cls}>"), &format!("TSoftClassPtr<{cls}>"))
+            } else {
+                unsupported("SoftClassProperty without meta_class_name")
+            }
+        }
+        "WeakObjectProperty" => {
+            // TWeakObjectPtr<T> resolves to UObject* via FObjectPropertyBase —
+            // use the same ObjectRef mapping as ObjectProperty.
             if let Some(cls) = class_name {
                 MappedType {
                     rust_type: format!("uika_runtime::UObjectRef<{cls}>"),
@@ -247,10 +264,12 @@ pub fn map_property_type(
             }
         }
         "ClassProperty" => {
+            // TSubclassOf<T>: the FFI value is the UClass object itself, the
+            // Rust side is a ClassRef<T> keyed on the MetaClass.
             let effective_class = meta_class_name.or(class_name);
             if let Some(cls) = effective_class {
                 MappedType {
-                    rust_type: format!("uika_runtime::UObjectRef<{cls}>"),
+                    rust_type: format!("uika_runtime::ClassRef<{cls}>"),
                     rust_ffi_type: "uika_runtime::UObjectHandle".into(),
                     cpp_type: format!("{cls}*"),
                     property_getter: "get_object".into(),
@@ -358,6 +377,153 @@ pub fn map_property_type(
     }
 }
 
+/// The unsafe constructor wrapping a raw UObjectHandle into the Rust type of
+/// an [`ConversionKind::ObjectRef`] mapping: `ClassRef` for TSubclassOf,
+/// `UObjectRef` for everything else.
+pub fn object_ref_ctor(rust_type: &str) -> &'static str {
+    if rust_type.starts_with("uika_runtime::ClassRef<") {
+        "uika_runtime::ClassRef::from_raw"
+    } else {
+        "uika_runtime::UObjectRef::from_raw"
+    }
+}
+
+/// Whether `rust_type` is a soft pointer (`SoftObjectPtr<T>` / `SoftClassPtr<T>`).
+/// These use the [`ConversionKind::StringUtf8`] transport: the FFI carries the
+/// asset path, and generated code borrows it with `.path()` and wraps decoded
+/// strings with `new`.
+pub fn is_soft_ptr_type(rust_type: &str) -> bool {
+    rust_type.starts_with("uika_runtime::SoftObjectPtr<")
+        || rust_type.starts_with("uika_runtime::SoftClassPtr<")
+}
+
+/// Convert a decoded `String` expression to the Rust type of a
+/// [`ConversionKind::StringUtf8`] mapping.
+pub fn string_to_rust_expr(rust_type: &str, s: &str) -> String {
+    if is_soft_ptr_type(rust_type) {
+        format!("<{rust_type}>::new({s})")
+    } else {
+        s.to_string()
+    }
+}
+
+/// Whether a function param is an FGameplayTag passed by value, returned, or
+/// output. These map to `uika_runtime::GameplayTag` (a packed tag FName)
+/// instead of an opaque struct buffer. InOut tags stay opaque structs.
+pub fn is_gameplay_tag_param(param: &ParamInfo) -> bool {
+    param.prop_type == "StructProperty"
+        && param.struct_name.as_deref() == Some("GameplayTag")
+        && param_direction(param) != ParamDirection::InOut
+}
+
+/// Structs whose UE layout matches a Rust value type, as
+/// `(struct_name, rust_type, cpp_type)`. Params of these types cross the FFI
+/// by value instead of as an opaque struct buffer.
+const BY_VALUE_STRUCTS: &[(&str, &str, &str)] = &[
+    // Four 32-bit words.
+    ("Guid", "uika_runtime::Guid", "FGuid"),
+    // Two, three and four int32s (TIntPoint / TIntVector3 / TIntRect).
+    ("IntPoint", "uika_runtime::glam::IVec2", "FIntPoint"),
+    ("IntVector", "uika_runtime::glam::IVec3", "FIntVector"),
+    ("IntRect", "uika_runtime::IntRect", "FIntRect"),
+    ("Int32Rect", "uika_runtime::IntRect", "FInt32Rect"),
+];
+
+/// How a [`DECODED_STRUCTS`] value is decoded from its `OwnedStruct`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StructDecode {
+    /// `<rust_type>::from_struct(&owned.as_ref())`, which returns a
+    /// `UikaResult`.
+    FromStruct,
+    /// An infallible conversion method of the manual bindings, called as
+    /// `path(&owned)`.
+    Method(&'static str),
+}
+
+/// Structs that generated functions return (or output) as a decoded Rust
+/// value instead of `OwnedStruct`, as `(struct_name, rust_type, decode)`.
+/// The FFI still carries the struct's bytes; the Rust side decodes them.
+const DECODED_STRUCTS: &[(&str, &str, StructDecode)] = &[
+    ("HitResult", "uika_runtime::HitResult", StructDecode::FromStruct),
+    // FMatrix is 16-byte aligned and FBox carries an IsValid byte, so neither
+    // can cross by value as DMat4 / UeBox.
+    (
+        "Matrix",
+        "uika_runtime::glam::DMat4",
+        StructDecode::Method("crate::manual::matrix::OwnedFMatrixExt::to_dmat4"),
+    ),
+    (
+        "Box",
+        "uika_runtime::UeBox",
+        StructDecode::Method("crate::manual::ue_box::OwnedFBoxExt::to_ue_box"),
+    ),
+];
+
+/// The Rust type a returned or output struct `struct_name` is decoded into,
+/// and how, if it is one of `DECODED_STRUCTS`.
+pub fn decoded_struct(struct_name: Option<&str>) -> Option<(&'static str, StructDecode)> {
+    let name = struct_name?;
+    DECODED_STRUCTS
+        .iter()
+        .find(|(s, _, _)| *s == name)
+        .map(|(_, rust_type, decode)| (*rust_type, *decode))
+}
+
+/// The Rust type of [`decoded_struct`].
+pub fn decoded_struct_type(struct_name: Option<&str>) -> Option<&'static str> {
+    decoded_struct(struct_name).map(|(rust_type, _)| rust_type)
+}
+
+/// The `BY_VALUE_STRUCTS` entry for a function param passed by value,
+/// returned, or output. InOut params stay opaque structs.
+fn by_value_struct(param: &ParamInfo) -> Option<&'static (&'static str, &'static str, &'static str)> {
+    if param.prop_type != "StructProperty" || param_direction(param) == ParamDirection::InOut {
+        return None;
+    }
+    let name = param.struct_name.as_deref()?;
+    BY_VALUE_STRUCTS.iter().find(|(struct_name, _, _)| *struct_name == name)
+}
+
+/// Map a function parameter to its Rust/C++ type information.
+/// Same as [`map_property_type`] except for the overrides in
+/// [`is_gameplay_tag_param`] and `BY_VALUE_STRUCTS` (FGuid, FIntPoint,
+/// FIntVector, FIntRect).
+pub fn map_param_type(param: &ParamInfo) -> MappedType {
+    if let Some((_, rust_type, cpp_type)) = by_value_struct(param) {
+        return MappedType {
+            rust_type: (*rust_type).into(),
+            rust_ffi_type: (*rust_type).into(),
+            cpp_type: (*cpp_type).into(),
+            property_getter: String::new(),
+            property_setter: String::new(),
+            rust_to_ffi: ConversionKind::Identity,
+            ffi_to_rust: ConversionKind::Identity,
+            supported: true,
+        };
+    }
+    if is_gameplay_tag_param(param) {
+        return MappedType {
+            rust_type: "uika_runtime::GameplayTag".into(),
+            rust_ffi_type: "uika_runtime::GameplayTag".into(),
+            cpp_type: "FGameplayTag".into(),
+            property_getter: "get_fname".into(),
+            property_setter: "set_fname".into(),
+            rust_to_ffi: ConversionKind::FName,
+            ffi_to_rust: ConversionKind::FName,
+            supported: true,
+        };
+    }
+    map_property_type(
+        &param.prop_type,
+        param.class_name.as_deref(),
+        param.struct_name.as_deref(),
+        param.enum_name.as_deref(),
+        param.enum_underlying_type.as_deref(),
+        param.meta_class_name.as_deref(),
+        param.interface_name.as_deref(),
+    )
+}
+
 /// Map a param to its direction based on prop_flags.
 pub fn param_direction(param: &ParamInfo) -> ParamDirection {
     use crate::schema::*;
@@ -381,6 +547,19 @@ pub fn param_direction(param: &ParamInfo) -> ParamDirection {
     }
 }
 
+fn soft_ptr_type(rust: &str, cpp: &str) -> MappedType {
+    MappedType {
+        rust_type: rust.into(),
+        rust_ffi_type: "*const u8".into(),
+        cpp_type: cpp.into(),
+        property_getter: "get_string".into(),
+        property_setter: "set_string".into(),
+        rust_to_ffi: ConversionKind::StringUtf8,
+        ffi_to_rust: ConversionKind::StringUtf8,
+        supported: true,
+    }
+}
+
 fn int_type(rust: &str, _cpp: &str) -> MappedType {
     // Map to the FFI type that matches the available PropertyApi methods.
     // Available: get_u8/set_u8, get_i32/set_i32, get_i64/set_i64
@@ -516,7 +695,7 @@ pub fn container_element_rust_type(
                         return None;
                     }
                 }
-                Some(format!("uika_runtime::UObjectRef<{cls}>"))
+                Some(format!("uika_runtime::ClassRef<{cls}>"))
             } else {
                 Some("uika_runtime::UObjectHandle".into())
             }
@@ -596,6 +775,25 @@ pub fn resolve_container_rust_type(
     }
 }
 
+/// Whether a function param is written to a caller-sized buffer: a string
+/// output, or a struct output or return value (InOut structs are updated in
+/// place). Wrappers with such params return BufferTooSmall, with the sizes
+/// needed, when a buffer was too short; strings that did not fit are fetched
+/// from the output stash rather than by calling the function again.
+pub fn has_sized_output(param: &ParamInfo) -> bool {
+    if is_container_param(param) {
+        return false;
+    }
+    let kind = map_param_type(param).ffi_to_rust;
+    match param_direction(param) {
+        ParamDirection::In => false,
+        ParamDirection::InOut => kind == ConversionKind::StringUtf8,
+        ParamDirection::Out | ParamDirection::Return => {
+            matches!(kind, ConversionKind::StringUtf8 | ConversionKind::StructOpaque)
+        }
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Container parameter helpers (for function codegen)
 // ---------------------------------------------------------------------------
@@ -678,3 +876,43 @@ pub fn container_elem_type_str(param: &ParamInfo, ctx: &CodegenContext) -> Optio
         _ => None,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schema::{CPF_OUT_PARM, CPF_REFERENCE_PARM, CPF_RETURN_PARM};
+    use uika_ue_flags::CPF_PARM;
+
+    fn struct_param(struct_name: &str, prop_flags: u64) -> ParamInfo {
+        serde_json::from_value(serde_json::json!({
+            "name": "Value",
+            "type": "StructProperty",
+            "prop_flags": prop_flags,
+            "struct_name": struct_name,
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_int_point_and_vector_params_by_value() {
+        let mapped = map_param_type(&struct_param("IntPoint", CPF_PARM));
+        assert_eq!(mapped.rust_type, "uika_runtime::glam::IVec2");
+        assert_eq!(mapped.cpp_type, "FIntPoint");
+        assert_eq!(mapped.rust_to_ffi, ConversionKind::Identity);
+        let mapped = map_param_type(&struct_param("IntVector", CPF_PARM | CPF_RETURN_PARM));
+        assert_eq!(mapped.rust_type, "uika_runtime::glam::IVec3");
+        assert_eq!(mapped.cpp_type, "FIntVector");
+        // InOut params stay opaque structs.
+        let inout = map_param_type(&struct_param("IntPoint", CPF_PARM | CPF_OUT_PARM | CPF_REFERENCE_PARM));
+        assert_eq!(inout.rust_to_ffi, ConversionKind::StructOpaque);
+    }
+
+    #[test]
+    fn test_matrix_and_box_decoded() {
+        assert_eq!(decoded_struct_type(Some("Matrix")), Some("uika_runtime::glam::DMat4"));
+        assert_eq!(decoded_struct_type(Some("Box")), Some("uika_runtime::UeBox"));
+        // They still cross the FFI as struct bytes.
+        let mapped = map_param_type(&struct_param("Matrix", CPF_PARM | CPF_RETURN_PARM));
+        assert_eq!(mapped.ffi_to_rust, ConversionKind::StructOpaque);
+    }
+}
@@ -3,6 +3,7 @@ use std::ffi::c_void;
 use crate::error::UikaErrorCode;
 use crate::handles::*;
 use crate::reify_types::UikaReifyPropExtra;
+use crate::physics_types::{UikaCollisionShape, UikaHitResult};
 
 // Re-export FWeakObjectHandle for use by api_table consumers.
 pub use crate::handles::FWeakObjectHandle;
@@ -11,12 +12,28 @@ pub use crate::handles::FWeakObjectHandle;
 // Main API table
 // ---------------------------------------------------------------------------
 
+/// `UikaApiTable::version` written by the plugin this crate matches. Bump when
+/// the table layout changes; must match `UIKA_API_VERSION` in UikaApiTable.h.
+/// Also written to `UikaRustCallbacks::version`.
+pub const UIKA_API_VERSION: u32 = 31;
+
+/// Oldest table version a DLL built against this crate can run with. Tables
+/// from `UIKA_API_MIN_VERSION` up to `UIKA_API_VERSION` only differ by fields
+/// appended at the end, which the runtime reads as null when the table's
+/// `size` doesn't cover them.
+pub const UIKA_API_MIN_VERSION: u32 = 4;
+
 /// The top-level table passed from C++ to Rust at init time.
 /// Two tiers: fixed sub-tables (hand-written infrastructure) and a generated
 /// function-pointer flat array (one entry per codegen-exported UE function).
+///
+/// Since version 4 the layout only grows: new sub-tables and fields are
+/// appended at the end, so an older plugin's shorter table is a valid prefix.
 #[repr(C)]
 pub struct UikaApiTable {
     pub version: u32,
+    /// `sizeof(FUikaApiTable)` as compiled into the plugin.
+    pub size: u32,
 
     // ---- Fixed sub-tables (hand-written, infrastructure) ----
     pub core: *const UikaCoreApi,
@@ -29,15 +46,95 @@ pub struct UikaApiTable {
     pub world: *const UikaWorldApi,
     pub logging: *const UikaLoggingApi,
     pub widget: *const UikaWidgetApi,
+    pub timer: *const UikaTimerApi,
+    pub asset: *const UikaAssetApi,
+    pub text: *const UikaTextApi,
+    pub gameplay_tag: *const UikaGameplayTagApi,
+    pub physics: *const UikaPhysicsApi,
 
     // ---- Generated function-pointer array (codegen) ----
     /// Flat array indexed by codegen-assigned FuncId. Each pointer targets a
     /// generated C++ wrapper that directly calls the UE C++ API.
     pub func_table: *const *const c_void,
     pub func_count: u32,
+
+    /// Identifies the exports the generated C++ wrappers were built from.
+    /// Checked against the Rust bindings' own stamp in `uika::init`.
+    pub binding_stamp: UikaBindingStamp,
+
+    // ---- Appended sub-tables (null when the plugin predates them) ----
+    /// Since version 5.
+    pub input: *const UikaInputApi,
+    /// Since version 6.
+    pub audio: *const UikaAudioApi,
+    /// Since version 7.
+    pub navigation: *const UikaNavigationApi,
+    /// Since version 8.
+    pub ui: *const UikaUiApi,
+    /// Since version 9.
+    pub asset_registry: *const UikaAssetRegistryApi,
+    /// Since version 10.
+    pub save_game: *const UikaSaveGameApi,
+    /// Since version 11.
+    pub config: *const UikaConfigApi,
+    /// Since version 12.
+    pub gas: *const UikaGasApi,
+    /// Since version 13.
+    pub array_ext: *const UikaArrayExtApi,
+    /// Since version 14.
+    pub map_ext: *const UikaMapExtApi,
+    /// Since version 15.
+    pub set_ext: *const UikaSetExtApi,
+    /// Since version 16.
+    pub property_info: *const UikaPropertyInfoApi,
+    /// Since version 17.
+    pub object_iter: *const UikaObjectIterApi,
+    /// Since version 18.
+    pub core_ext: *const UikaCoreExtApi,
+    /// Since version 20.
+    pub component: *const UikaComponentApi,
+    /// Since version 21.
+    pub subsystem: *const UikaSubsystemApi,
+    /// Since version 23.
+    pub latent: *const UikaLatentApi,
+    /// Since version 24.
+    pub logging_ext: *const UikaLoggingExtApi,
+    /// Since version 25.
+    pub panic: *const UikaPanicApi,
+    /// Since version 26.
+    pub guid: *const UikaGuidApi,
+    /// Since version 27.
+    pub hit_result: *const UikaHitResultApi,
+    /// Since version 28.
+    pub property_bulk: *const UikaPropertyBulkApi,
+    /// Since version 29.
+    pub property_notify: *const UikaPropertyNotifyApi,
+    /// Since version 30.
+    pub output_stash: *const UikaOutputStashApi,
+    /// Since version 31.
+    pub gameplay_tag_ext: *const UikaGameplayTagExtApi,
 }
 
-// SAFETY: UikaApiTable contains only function pointers and a version field.
+/// Fingerprint of a codegen run, embedded in both the generated Rust
+/// (`func_ids.rs`) and C++ (`UikaFillFuncTable.cpp`) sources. FuncIds are
+/// stable across regenerations, so the DLL and the plugin may come from
+/// different runs as long as every id they share has the same signature.
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct UikaBindingStamp {
+    /// Engine version the UHT export came from, e.g. "5.7.0" (UTF-8, not
+    /// NUL-terminated). Empty if unknown.
+    pub engine_version: *const u8,
+    pub engine_version_len: u32,
+    /// Hash of the `[codegen]` config section.
+    pub config_hash: u64,
+    /// `func_count` signature hashes indexed by FuncId (owner, name and
+    /// parameters). 0 marks an unused id.
+    pub func_sigs: *const u64,
+}
+
+// SAFETY: UikaApiTable contains only function pointers, a version field and
+// the binding stamp (pointing at static data in the plugin).
 // The table is filled once during module startup (FillApiTable in C++) before
 // the pointer is handed to Rust via uika_init. After init, the table is
 // read-only for the lifetime of the DLL, so sharing across threads is safe.
@@ -93,6 +190,28 @@ pub struct UikaCoreApi {
 
     /// Check if a weak pointer is still valid (without resolving).
     pub is_weak_valid: unsafe extern "C" fn(weak: FWeakObjectHandle) -> bool,
+
+    // -- Class hierarchy --
+
+    /// UClass::IsChildOf. Returns false if either handle is null.
+    pub is_child_of: unsafe extern "C" fn(class: UClassHandle, parent: UClassHandle) -> bool,
+
+    /// Get a UClass's super class. Returns null for UObject (the root).
+    pub get_super_class: unsafe extern "C" fn(class: UClassHandle) -> UClassHandle,
+
+    /// UClass::ImplementsInterface on the object's class. `interface` is the
+    /// UInterface class (e.g. UInteractable). False for null/destroyed objects.
+    pub implements_interface: unsafe extern "C" fn(obj: UObjectHandle, interface: UClassHandle) -> bool,
+
+    /// Write the UObject's full path name (UObject::GetPathName, UTF-8) into
+    /// a caller-supplied buffer. Returns BufferTooSmall with `out_len` set
+    /// to the required length if `buf_len` is too short.
+    pub get_path_name: unsafe extern "C" fn(
+        obj: UObjectHandle,
+        buf: *mut u8,
+        buf_len: u32,
+        out_len: *mut u32,
+    ) -> UikaErrorCode,
 }
 
 // ---------------------------------------------------------------------------
@@ -101,9 +220,29 @@ pub struct UikaCoreApi {
 
 #[repr(C)]
 pub struct UikaLoggingApi {
-    /// Bridge to UE_LOG. `level`: 0=Display, 1=Warning, 2=Error.
-    /// `msg` is a UTF-8 byte slice (not null-terminated).
+    /// Bridge to UE_LOG. `level`: 0=Display, 1=Warning, 2=Error, 3=Log,
+    /// 4=Verbose, 5=VeryVerbose. `msg` is a UTF-8 byte slice (not null-terminated).
     pub log: unsafe extern "C" fn(level: u8, msg: *const u8, msg_len: u32),
+
+    /// UE_LOG into a named category (created and registered on first use, so
+    /// `Log <Category> <Verbosity>` works from the console). `level` as for `log`.
+    pub log_category: unsafe extern "C" fn(
+        category: *const u8,
+        category_len: u32,
+        level: u8,
+        msg: *const u8,
+        msg_len: u32,
+    ),
+
+    /// GEngine->AddOnScreenDebugMessage. `key` -1 always adds a new line.
+    /// `color` is packed RGBA (0xRRGGBBAA). No-op when GEngine is null.
+    pub add_on_screen_message: unsafe extern "C" fn(
+        key: i32,
+        duration: f32,
+        color: u32,
+        msg: *const u8,
+        msg_len: u32,
+    ),
 }
 
 // ---------------------------------------------------------------------------
@@ -146,7 +285,8 @@ pub struct UikaPropertyApi {
     pub get_f64: unsafe extern "C" fn(obj: UObjectHandle, prop: FPropertyHandle, out: *mut f64) -> UikaErrorCode,
     pub set_f64: unsafe extern "C" fn(obj: UObjectHandle, prop: FPropertyHandle, val: f64) -> UikaErrorCode,
 
-    // -- String (UTF-8 buffer) --
+    // -- String (UTF-8 buffer; FString, FText, and soft pointers as their asset path) --
+    /// Writes at most `buf_len` bytes; `out_len` receives the full length.
     pub get_string: unsafe extern "C" fn(obj: UObjectHandle, prop: FPropertyHandle, buf: *mut u8, buf_len: u32, out_len: *mut u32) -> UikaErrorCode,
     pub set_string: unsafe extern "C" fn(obj: UObjectHandle, prop: FPropertyHandle, buf: *const u8, len: u32) -> UikaErrorCode,
 
@@ -175,6 +315,21 @@ pub struct UikaPropertyApi {
         obj: UObjectHandle, prop: FPropertyHandle,
         index: u32, in_buf: *const u8, buf_size: u32,
     ) -> UikaErrorCode,
+
+    // -- Whole-object JSON (FJsonObjectConverter; transient properties skipped) --
+
+    /// Serialize every reflected property of `obj` (including containers and
+    /// nested structs) as a condensed UTF-8 JSON object. Returns BufferTooSmall
+    /// with `out_len` set to the required length if `buf_len` is too short.
+    pub export_json: unsafe extern "C" fn(
+        obj: UObjectHandle, buf: *mut u8, buf_len: u32, out_len: *mut u32,
+    ) -> UikaErrorCode,
+    /// Apply a UTF-8 JSON object to `obj`'s reflected properties. Keys that
+    /// do not name a property are ignored. TypeMismatch if the JSON is not an
+    /// object or a value cannot be converted.
+    pub import_json: unsafe extern "C" fn(
+        obj: UObjectHandle, json_utf8: *const u8, len: u32,
+    ) -> UikaErrorCode,
 }
 
 // ---------------------------------------------------------------------------
@@ -456,7 +611,9 @@ pub struct UikaReifyApi {
     pub get_cdo: unsafe extern "C" fn(cls: UClassHandle) -> UObjectHandle,
 
     /// Register a default subobject to be created during class construction.
-    /// `flags`: bitfield — UIKA_COMP_ROOT=1, UIKA_COMP_TRANSIENT=2.
+    /// `flags`: bitfield — UIKA_COMP_ROOT=1, UIKA_COMP_TRANSIENT=2,
+    /// UIKA_COMP_ATTACH_ROOT=4 (attach to the actor's root component, which
+    /// may come from the native parent class).
     /// `attach_parent`/`attach_len`: name of parent subobject (0-len = none).
     pub add_default_subobject: unsafe extern "C" fn(
         cls: UClassHandle,
@@ -471,10 +628,44 @@ pub struct UikaReifyApi {
         owner: UObjectHandle,
         name: *const u8, name_len: u32,
     ) -> UObjectHandle,
+
+    /// Attach a `_Validate` function to an RPC (`FUNC_NetValidate`). The
+    /// validate function takes the same params plus a bool return; if it
+    /// returns false the RPC is rejected via `RPC_ValidateFailed`.
+    pub set_function_validate: unsafe extern "C" fn(
+        func: UFunctionHandle,
+        validate_func: UFunctionHandle,
+    ) -> UikaErrorCode,
+
+    /// Create (or, on hot reload, find) a UScriptStruct in the Uika package.
+    pub create_struct: unsafe extern "C" fn(
+        name: *const u8,
+        name_len: u32,
+    ) -> UStructHandle,
+
+    /// Add a property to a struct created by `create_struct`. Properties are
+    /// laid out in the order they are added.
+    pub add_struct_property: unsafe extern "C" fn(
+        ustruct: UStructHandle,
+        name: *const u8,
+        name_len: u32,
+        prop_type: u32,
+        prop_flags: u64,
+        extra: *const UikaReifyPropExtra,
+    ) -> FPropertyHandle,
+
+    /// Bind and link the struct, computing its size and property offsets.
+    pub finalize_struct: unsafe extern "C" fn(ustruct: UStructHandle) -> UikaErrorCode,
+
+    /// Declare that `cls` implements the UInterface `interface`. Interface
+    /// functions are then implemented as Override ufunctions, whose params
+    /// are copied from the interface's declaration.
+    pub add_interface: unsafe extern "C" fn(cls: UClassHandle, interface: UClassHandle) -> UikaErrorCode,
 }
 
 pub const UIKA_COMP_ROOT: u32 = 1;
 pub const UIKA_COMP_TRANSIENT: u32 = 2;
+pub const UIKA_COMP_ATTACH_ROOT: u32 = 4;
 
 /// Widget creation and WidgetTree management (UMG).
 ///
@@ -565,3 +756,1284 @@ pub struct UikaWorldApi {
         transform_size: u32,
     ) -> UikaErrorCode,
 }
+
+// ---------------------------------------------------------------------------
+// UikaTimerApi
+// ---------------------------------------------------------------------------
+
+/// FTimerManager access. Timer handles are the packed 64-bit FTimerHandle
+/// value (0 = invalid). When a timer fires, C++ calls
+/// `invoke_delegate_callback(callback_id, null)`.
+#[repr(C)]
+pub struct UikaTimerApi {
+    /// Set a timer on the world of `world_context` (any UObject with a world).
+    /// `first_delay` < 0 uses `rate` for the first fire. Returns 0 on failure.
+    pub set_timer: unsafe extern "C" fn(
+        world_context: UObjectHandle,
+        callback_id: u64,
+        rate: f32,
+        looping: bool,
+        first_delay: f32,
+    ) -> u64,
+
+    /// Clear a timer. Clearing an expired timer is not an error.
+    pub clear_timer: unsafe extern "C" fn(world_context: UObjectHandle, timer: u64) -> UikaErrorCode,
+
+    /// Pause (`paused = true`) or resume a timer.
+    pub pause_timer: unsafe extern "C" fn(
+        world_context: UObjectHandle,
+        timer: u64,
+        paused: bool,
+    ) -> UikaErrorCode,
+
+    /// Whether the timer exists and is not paused.
+    pub is_timer_active: unsafe extern "C" fn(world_context: UObjectHandle, timer: u64) -> bool,
+}
+
+// ---------------------------------------------------------------------------
+// UikaAssetApi
+// ---------------------------------------------------------------------------
+
+/// Async asset loading through UE's FStreamableManager. Requests are
+/// identified by a non-zero u64. On completion C++ calls
+/// `invoke_delegate_callback(callback_id, params)` where `params` points to
+/// the u64 request ID.
+#[repr(C)]
+pub struct UikaAssetApi {
+    /// Start loading the object at a soft object path (UTF-8). `callback_id`
+    /// 0 means no completion callback (poll only). Returns 0 on failure.
+    pub request_load: unsafe extern "C" fn(path_utf8: *const u8, path_len: u32, callback_id: u64) -> u64,
+
+    /// Poll a request: 0 = pending, 1 = loaded (`out_object` written),
+    /// 2 = failed, canceled, or unknown request.
+    pub poll_load: unsafe extern "C" fn(request: u64, out_object: *mut UObjectHandle) -> u8,
+
+    /// Cancel the request if still pending and release it. The loaded asset is
+    /// no longer kept alive by the request afterwards.
+    pub cancel_load: unsafe extern "C" fn(request: u64) -> UikaErrorCode,
+
+    /// `FSoftObjectPath::ResolveObject`: the object at `path` if it is already
+    /// in memory, else null. Never loads.
+    pub resolve_path: unsafe extern "C" fn(path_utf8: *const u8, path_len: u32) -> UObjectHandle,
+
+    /// Write `obj`'s soft object path (UTF-8) into `buf`. `out_len` receives
+    /// the full length; returns `BufferTooSmall` if it did not fit.
+    pub get_object_path: unsafe extern "C" fn(
+        obj: UObjectHandle,
+        buf: *mut u8,
+        buf_len: u32,
+        out_len: *mut u32,
+    ) -> UikaErrorCode,
+}
+
+// ---------------------------------------------------------------------------
+// UikaTextApi
+// ---------------------------------------------------------------------------
+
+/// FText construction and conversion. Texts cross the boundary as owning
+/// `FTextHandle`s so localized texts keep their namespace/key (or string
+/// table) identity; every handle returned here must be released with
+/// `destroy`. Functions returning a handle return null on failure.
+#[repr(C)]
+pub struct UikaTextApi {
+    /// `FText::FromString` — culture-invariant, not localized.
+    pub from_string: unsafe extern "C" fn(utf8: *const u8, len: u32) -> FTextHandle,
+
+    /// `FText::FromStringTable(table_id, key)`.
+    pub from_string_table: unsafe extern "C" fn(
+        table_id: *const u8,
+        table_id_len: u32,
+        key: *const u8,
+        key_len: u32,
+    ) -> FTextHandle,
+
+    /// `FText::AsLocalizable_Advanced(namespace, key, source)` — the runtime
+    /// equivalent of `NSLOCTEXT`.
+    pub as_localizable: unsafe extern "C" fn(
+        namespace: *const u8,
+        namespace_len: u32,
+        key: *const u8,
+        key_len: u32,
+        source: *const u8,
+        source_len: u32,
+    ) -> FTextHandle,
+
+    /// Display string for the current culture (UTF-8). `out_len` always
+    /// receives the full length; returns `BufferTooSmall` if it exceeds `buf_len`.
+    pub to_string: unsafe extern "C" fn(
+        text: FTextHandle,
+        buf: *mut u8,
+        buf_len: u32,
+        out_len: *mut u32,
+    ) -> UikaErrorCode,
+
+    pub is_empty: unsafe extern "C" fn(text: FTextHandle) -> bool,
+
+    /// `FText::IdenticalTo` — same source/identity, not just the same display string.
+    pub identical_to: unsafe extern "C" fn(a: FTextHandle, b: FTextHandle) -> bool,
+
+    /// Copy a text into a new handle (FText copies are cheap, ref-counted).
+    pub clone: unsafe extern "C" fn(text: FTextHandle) -> FTextHandle,
+
+    /// Release a handle. Null is a no-op.
+    pub destroy: unsafe extern "C" fn(text: FTextHandle),
+
+    /// Read an FTextProperty into a new handle written to `out`.
+    pub get_property: unsafe extern "C" fn(
+        obj: UObjectHandle,
+        prop: FPropertyHandle,
+        out: *mut FTextHandle,
+    ) -> UikaErrorCode,
+
+    /// Write an FTextProperty, keeping the text's localization identity.
+    pub set_property: unsafe extern "C" fn(
+        obj: UObjectHandle,
+        prop: FPropertyHandle,
+        text: FTextHandle,
+    ) -> UikaErrorCode,
+}
+
+// ---------------------------------------------------------------------------
+// UikaGameplayTagApi
+// ---------------------------------------------------------------------------
+
+/// Gameplay tag queries. A tag crosses the boundary as its packed tag name
+/// (`FNameHandle`, `0` = no tag); containers as arrays of packed names.
+#[repr(C)]
+pub struct UikaGameplayTagApi {
+    /// Look up a registered tag by name (e.g. `"Status.Stunned"`). Returns
+    /// `InvalidOperation` if the tag is not registered.
+    pub request_tag: unsafe extern "C" fn(name_utf8: *const u8, name_len: u32, out: *mut FNameHandle) -> UikaErrorCode,
+
+    /// `FGameplayTag::MatchesTag` (`exact = false`: `"A.B"` matches `"A"`)
+    /// or `MatchesTagExact`.
+    pub matches_tag: unsafe extern "C" fn(tag: FNameHandle, other: FNameHandle, exact: bool) -> bool,
+
+    /// Direct parent tag (`"A.B"` → `"A"`), or 0 for a root tag.
+    pub request_direct_parent: unsafe extern "C" fn(tag: FNameHandle) -> FNameHandle,
+
+    /// Copy the explicit tags of the FGameplayTagContainer at `container`.
+    /// `out_count` always receives the tag count; returns `BufferTooSmall`
+    /// if it exceeds `capacity`.
+    pub read_container: unsafe extern "C" fn(
+        container: *const u8,
+        out_tags: *mut FNameHandle,
+        capacity: u32,
+        out_count: *mut u32,
+    ) -> UikaErrorCode,
+
+    /// Replace the contents of the FGameplayTagContainer at `container`.
+    pub write_container: unsafe extern "C" fn(
+        container: *mut u8,
+        tags: *const FNameHandle,
+        count: u32,
+    ) -> UikaErrorCode,
+}
+
+// ---------------------------------------------------------------------------
+// UikaPhysicsApi
+// ---------------------------------------------------------------------------
+
+/// Scene queries on the world of `world_context`. Points are `[x, y, z]`
+/// (3 f64). `channel` is an ECollisionChannel value. `ignore` lists actors
+/// to skip (may be null when `ignore_count` is 0).
+#[repr(C)]
+pub struct UikaPhysicsApi {
+    /// First blocking hit along the line. Returns whether anything was hit.
+    pub line_trace_single: unsafe extern "C" fn(
+        world_context: UObjectHandle,
+        start: *const f64,
+        end: *const f64,
+        channel: u8,
+        trace_complex: bool,
+        ignore: *const UObjectHandle,
+        ignore_count: u32,
+        out_hit: *mut UikaHitResult,
+    ) -> bool,
+
+    /// All overlaps up to and including the first blocking hit, in order.
+    /// `out_count` always receives the hit count; returns `BufferTooSmall`
+    /// if it exceeds `capacity`.
+    pub line_trace_multi: unsafe extern "C" fn(
+        world_context: UObjectHandle,
+        start: *const f64,
+        end: *const f64,
+        channel: u8,
+        trace_complex: bool,
+        ignore: *const UObjectHandle,
+        ignore_count: u32,
+        out_hits: *mut UikaHitResult,
+        capacity: u32,
+        out_count: *mut u32,
+    ) -> UikaErrorCode,
+
+    /// First blocking hit of `shape` swept from `start` to `end`.
+    pub sweep_single: unsafe extern "C" fn(
+        world_context: UObjectHandle,
+        start: *const f64,
+        end: *const f64,
+        shape: *const UikaCollisionShape,
+        channel: u8,
+        trace_complex: bool,
+        ignore: *const UObjectHandle,
+        ignore_count: u32,
+        out_hit: *mut UikaHitResult,
+    ) -> bool,
+
+    /// Whether `shape` at `center` overlaps any blocking geometry.
+    pub overlap_blocking_test: unsafe extern "C" fn(
+        world_context: UObjectHandle,
+        center: *const f64,
+        shape: *const UikaCollisionShape,
+        channel: u8,
+        ignore: *const UObjectHandle,
+        ignore_count: u32,
+    ) -> bool,
+}
+
+// ---------------------------------------------------------------------------
+// UikaInputApi
+// ---------------------------------------------------------------------------
+
+/// Enhanced Input. Bindings are made on the actor's `UEnhancedInputComponent`
+/// (a pawn's player input component, or the one created by `EnableInput`)
+/// and identified by the component's binding handle (0 = invalid). When a
+/// bound action fires, C++ calls `invoke_delegate_callback(callback_id,
+/// params)` where `params` points to the action value as `[f64; 3]`
+/// (`FInputActionValue::Get<FVector>()`: bool and 1D values use x).
+#[repr(C)]
+pub struct UikaInputApi {
+    /// Load a `UInputAction` or `UInputMappingContext` asset by object path.
+    /// Returns null if it doesn't exist or has another class.
+    pub find_input_asset: unsafe extern "C" fn(
+        path_utf8: *const u8,
+        path_len: u32,
+        mapping_context: bool,
+    ) -> UObjectHandle,
+
+    /// Add a mapping context to the local player of `player_controller`.
+    pub add_mapping_context: unsafe extern "C" fn(
+        player_controller: UObjectHandle,
+        context: UObjectHandle,
+        priority: i32,
+    ) -> UikaErrorCode,
+
+    /// Remove a mapping context added with `add_mapping_context`.
+    pub remove_mapping_context: unsafe extern "C" fn(
+        player_controller: UObjectHandle,
+        context: UObjectHandle,
+    ) -> UikaErrorCode,
+
+    /// Bind `action` on `actor`'s input component for the `ETriggerEvent`
+    /// bit `trigger_event`. Returns the binding handle, or 0 if the actor
+    /// has no `UEnhancedInputComponent`.
+    pub bind_action: unsafe extern "C" fn(
+        actor: UObjectHandle,
+        action: UObjectHandle,
+        trigger_event: u8,
+        callback_id: u64,
+    ) -> u32,
+
+    /// Remove a binding made with `bind_action`. Removing a binding whose
+    /// component is gone is not an error.
+    pub remove_binding: unsafe extern "C" fn(actor: UObjectHandle, binding: u32) -> UikaErrorCode,
+}
+
+// ---------------------------------------------------------------------------
+// UikaAudioApi
+// ---------------------------------------------------------------------------
+
+/// Sound playback through `UGameplayStatics` and `UAudioComponent`. `sound`
+/// is any `USoundBase`. Locations are world-space xyz. The `spawn_*`
+/// functions return the new `UAudioComponent`, or null on failure (no world,
+/// not a sound, or audio disabled); with `auto_destroy` the component is
+/// destroyed when it finishes playing.
+#[repr(C)]
+pub struct UikaAudioApi {
+    /// `UGameplayStatics::PlaySound2D` (fire and forget, not spatialized).
+    pub play_sound_2d: unsafe extern "C" fn(
+        world_context: UObjectHandle,
+        sound: UObjectHandle,
+        volume: f32,
+        pitch: f32,
+        start_time: f32,
+    ) -> UikaErrorCode,
+
+    /// `UGameplayStatics::PlaySoundAtLocation` (fire and forget).
+    pub play_sound_at_location: unsafe extern "C" fn(
+        world_context: UObjectHandle,
+        sound: UObjectHandle,
+        location: *const f64,
+        volume: f32,
+        pitch: f32,
+        start_time: f32,
+    ) -> UikaErrorCode,
+
+    /// `UGameplayStatics::SpawnSound2D`.
+    pub spawn_sound_2d: unsafe extern "C" fn(
+        world_context: UObjectHandle,
+        sound: UObjectHandle,
+        volume: f32,
+        pitch: f32,
+        start_time: f32,
+        auto_destroy: bool,
+    ) -> UObjectHandle,
+
+    /// `UGameplayStatics::SpawnSoundAtLocation`.
+    pub spawn_sound_at_location: unsafe extern "C" fn(
+        world_context: UObjectHandle,
+        sound: UObjectHandle,
+        location: *const f64,
+        volume: f32,
+        pitch: f32,
+        start_time: f32,
+        auto_destroy: bool,
+    ) -> UObjectHandle,
+
+    /// `UGameplayStatics::SpawnSoundAttached` to a scene component, at
+    /// `socket` (0 = the component's origin).
+    pub spawn_sound_attached: unsafe extern "C" fn(
+        sound: UObjectHandle,
+        attach_to: UObjectHandle,
+        socket: FNameHandle,
+        volume: f32,
+        pitch: f32,
+        start_time: f32,
+        auto_destroy: bool,
+    ) -> UObjectHandle,
+
+    /// `UAudioComponent::Stop`.
+    pub stop: unsafe extern "C" fn(component: UObjectHandle) -> UikaErrorCode,
+
+    /// `UAudioComponent::FadeIn` to `volume` over `duration` seconds.
+    pub fade_in: unsafe extern "C" fn(
+        component: UObjectHandle,
+        duration: f32,
+        volume: f32,
+        start_time: f32,
+    ) -> UikaErrorCode,
+
+    /// `UAudioComponent::FadeOut` to `volume` over `duration` seconds, then stop.
+    pub fade_out: unsafe extern "C" fn(component: UObjectHandle, duration: f32, volume: f32) -> UikaErrorCode,
+
+    /// `UAudioComponent::SetVolumeMultiplier`.
+    pub set_volume: unsafe extern "C" fn(component: UObjectHandle, volume: f32) -> UikaErrorCode,
+
+    /// `UAudioComponent::SetPitchMultiplier`.
+    pub set_pitch: unsafe extern "C" fn(component: UObjectHandle, pitch: f32) -> UikaErrorCode,
+
+    /// `UAudioComponent::IsPlaying`. False for a destroyed component.
+    pub is_playing: unsafe extern "C" fn(component: UObjectHandle) -> bool,
+}
+
+// ---------------------------------------------------------------------------
+// UikaNavigationApi
+// ---------------------------------------------------------------------------
+
+/// Navigation mesh queries through `UNavigationSystemV1`, on the world of
+/// `world_context`. Points are world-space xyz.
+#[repr(C)]
+pub struct UikaNavigationApi {
+    /// `FindPathToLocationSynchronously` from `start` to `end`. Writes up to
+    /// `capacity` path points (3 f64 each) to `out_points`; `out_count`
+    /// always receives the full point count. Returns `BufferTooSmall` if it
+    /// exceeds `capacity`, `InvalidOperation` if no path was found.
+    pub find_path_to_location: unsafe extern "C" fn(
+        world_context: UObjectHandle,
+        start: *const f64,
+        end: *const f64,
+        out_points: *mut f64,
+        capacity: u32,
+        out_count: *mut u32,
+    ) -> UikaErrorCode,
+
+    /// `GetRandomReachablePointInRadius` around `origin`. Returns false if
+    /// there is no navigable point.
+    pub find_random_reachable_point: unsafe extern "C" fn(
+        world_context: UObjectHandle,
+        origin: *const f64,
+        radius: f32,
+        out_point: *mut f64,
+    ) -> bool,
+
+    /// `ProjectPointToNavigation`: the closest navigable point within
+    /// `extent` (half size per axis; zeros use the navmesh default).
+    pub project_point_to_navigation: unsafe extern "C" fn(
+        world_context: UObjectHandle,
+        point: *const f64,
+        extent: *const f64,
+        out_point: *mut f64,
+    ) -> bool,
+
+    /// `UAIBlueprintHelperLibrary::SimpleMoveToLocation` for `controller`
+    /// (an AI or player controller with a pawn).
+    pub simple_move_to_location: unsafe extern "C" fn(
+        controller: UObjectHandle,
+        goal: *const f64,
+    ) -> UikaErrorCode,
+}
+
+// ---------------------------------------------------------------------------
+// UikaUiApi
+// ---------------------------------------------------------------------------
+
+/// UMG widget display and lookup. Widgets are created through
+/// `UikaWidgetApi::create_widget`; these operate on existing widgets.
+#[repr(C)]
+pub struct UikaUiApi {
+    /// `UUserWidget::AddToViewport(z_order)`, or `AddToPlayerScreen` when
+    /// `player_screen` is set (split-screen: only the owning player's view).
+    pub add_to_viewport: unsafe extern "C" fn(
+        user_widget: UObjectHandle,
+        z_order: i32,
+        player_screen: bool,
+    ) -> UikaErrorCode,
+
+    /// `UWidget::RemoveFromParent` (also removes a widget from the viewport).
+    pub remove_from_parent: unsafe extern "C" fn(widget: UObjectHandle) -> UikaErrorCode,
+
+    /// `UUserWidget::IsInViewport`.
+    pub is_in_viewport: unsafe extern "C" fn(user_widget: UObjectHandle) -> bool,
+
+    /// `UWidget::SetVisibility` with an `ESlateVisibility` value.
+    pub set_visibility: unsafe extern "C" fn(widget: UObjectHandle, visibility: u8) -> UikaErrorCode,
+
+    /// `UWidget::GetVisibility` as an `ESlateVisibility` value.
+    pub get_visibility: unsafe extern "C" fn(widget: UObjectHandle, out: *mut u8) -> UikaErrorCode,
+
+    /// `UWidgetTree::FindWidget` on a user widget's tree. Returns null if
+    /// there is no widget with that name.
+    pub find_widget: unsafe extern "C" fn(
+        user_widget: UObjectHandle,
+        name_utf8: *const u8,
+        name_len: u32,
+    ) -> UObjectHandle,
+}
+
+// ---------------------------------------------------------------------------
+// UikaAssetRegistryApi
+// ---------------------------------------------------------------------------
+
+/// Asset discovery through `IAssetRegistry`. Queries only read the registry
+/// and never load assets.
+///
+/// Results are written to `buf` as UTF-8 text, one asset per line, with the
+/// fields `object_path \t class_path \t package_name`. `out_len` always
+/// receives the full length; returns `BufferTooSmall` if it exceeds `buf_len`.
+#[repr(C)]
+pub struct UikaAssetRegistryApi {
+    /// Assets whose class is `class_path` (e.g. `/Script/Engine.StaticMesh`),
+    /// or a subclass of it with `search_sub_classes`.
+    pub get_assets_by_class: unsafe extern "C" fn(
+        class_path: *const u8,
+        class_path_len: u32,
+        search_sub_classes: bool,
+        buf: *mut u8,
+        buf_len: u32,
+        out_len: *mut u32,
+    ) -> UikaErrorCode,
+
+    /// Assets in the package path `path` (e.g. `/Game/Weapons`), including
+    /// sub-paths with `recursive`.
+    pub get_assets_by_path: unsafe extern "C" fn(
+        path: *const u8,
+        path_len: u32,
+        recursive: bool,
+        buf: *mut u8,
+        buf_len: u32,
+        out_len: *mut u32,
+    ) -> UikaErrorCode,
+
+    /// Assets whose registry tag `tag` has the value `value`.
+    pub get_assets_by_tag_value: unsafe extern "C" fn(
+        tag: *const u8,
+        tag_len: u32,
+        value: *const u8,
+        value_len: u32,
+        buf: *mut u8,
+        buf_len: u32,
+        out_len: *mut u32,
+    ) -> UikaErrorCode,
+
+    /// Whether the registry is still scanning for assets (results may be
+    /// incomplete until it finishes).
+    pub is_loading_assets: unsafe extern "C" fn() -> bool,
+}
+
+// ---------------------------------------------------------------------------
+// UikaSaveGameApi
+// ---------------------------------------------------------------------------
+
+/// Completion payload of the async save-game calls, passed as `params` to
+/// `invoke_delegate_callback`. `data` is only valid during the callback.
+#[repr(C)]
+pub struct UikaSaveGameResult {
+    pub success: bool,
+    /// Loaded bytes (async loads only; null otherwise).
+    pub data: *const u8,
+    pub len: u32,
+}
+
+/// Save slots through `UGameplayStatics`. Slots are named by a UTF-8 string
+/// plus a platform user index. Besides `USaveGame` objects, a slot can hold
+/// a raw byte blob (`SaveDataToSlot` / `LoadDataFromSlot`).
+#[repr(C)]
+pub struct UikaSaveGameApi {
+    /// `CreateSaveGameObject` for a `USaveGame` subclass. Null on failure.
+    pub create_save_game_object: unsafe extern "C" fn(class: UClassHandle) -> UObjectHandle,
+
+    /// `SaveGameToSlot`.
+    pub save_game_to_slot: unsafe extern "C" fn(
+        save_game: UObjectHandle,
+        slot: *const u8,
+        slot_len: u32,
+        user_index: i32,
+    ) -> UikaErrorCode,
+
+    /// `LoadGameFromSlot`. Null if the slot is missing or not a `USaveGame`.
+    pub load_game_from_slot: unsafe extern "C" fn(
+        slot: *const u8,
+        slot_len: u32,
+        user_index: i32,
+    ) -> UObjectHandle,
+
+    /// `DoesSaveGameExist`.
+    pub does_save_game_exist: unsafe extern "C" fn(slot: *const u8, slot_len: u32, user_index: i32) -> bool,
+
+    /// `DeleteGameInSlot`. Deleting a missing slot is not an error.
+    pub delete_game_in_slot: unsafe extern "C" fn(
+        slot: *const u8,
+        slot_len: u32,
+        user_index: i32,
+    ) -> UikaErrorCode,
+
+    /// `SaveDataToSlot`: write a byte blob.
+    pub save_data_to_slot: unsafe extern "C" fn(
+        data: *const u8,
+        len: u32,
+        slot: *const u8,
+        slot_len: u32,
+        user_index: i32,
+    ) -> UikaErrorCode,
+
+    /// `LoadDataFromSlot`. `out_len` always receives the blob size; returns
+    /// `BufferTooSmall` if it exceeds `buf_len`, `InvalidOperation` if the
+    /// slot doesn't exist.
+    pub load_data_from_slot: unsafe extern "C" fn(
+        slot: *const u8,
+        slot_len: u32,
+        user_index: i32,
+        buf: *mut u8,
+        buf_len: u32,
+        out_len: *mut u32,
+    ) -> UikaErrorCode,
+
+    /// Write a byte blob on a background thread. The data is copied before
+    /// returning. On completion (game thread) C++ calls
+    /// `invoke_delegate_callback(callback_id, &UikaSaveGameResult)`.
+    pub async_save_data_to_slot: unsafe extern "C" fn(
+        data: *const u8,
+        len: u32,
+        slot: *const u8,
+        slot_len: u32,
+        user_index: i32,
+        callback_id: u64,
+    ) -> UikaErrorCode,
+
+    /// Read a byte blob on a background thread; completes like
+    /// `async_save_data_to_slot` with `data`/`len` set on success.
+    pub async_load_data_from_slot: unsafe extern "C" fn(
+        slot: *const u8,
+        slot_len: u32,
+        user_index: i32,
+        callback_id: u64,
+    ) -> UikaErrorCode,
+}
+
+// ---------------------------------------------------------------------------
+// UikaConfigApi
+// ---------------------------------------------------------------------------
+
+/// Ini settings through `GConfig`. Every entry is addressed by UTF-8
+/// `section`, `key` and `file`; `file` is either a config name (`Engine`,
+/// `Game`, `Input`, `GameUserSettings`, `Editor`, resolved to `GEngineIni`
+/// etc.) or an ini filename.
+///
+/// Getters return `PropertyNotFound` when the key doesn't exist. Setters only
+/// change the in-memory config; call `flush` to write the file.
+#[repr(C)]
+pub struct UikaConfigApi {
+    /// `GetString`. `out_len` always receives the value's UTF-8 length;
+    /// returns `BufferTooSmall` if it exceeds `buf_len`.
+    pub get_string: unsafe extern "C" fn(
+        section: *const u8,
+        section_len: u32,
+        key: *const u8,
+        key_len: u32,
+        file: *const u8,
+        file_len: u32,
+        buf: *mut u8,
+        buf_len: u32,
+        out_len: *mut u32,
+    ) -> UikaErrorCode,
+
+    pub set_string: unsafe extern "C" fn(
+        section: *const u8,
+        section_len: u32,
+        key: *const u8,
+        key_len: u32,
+        file: *const u8,
+        file_len: u32,
+        value: *const u8,
+        value_len: u32,
+    ) -> UikaErrorCode,
+
+    pub get_int: unsafe extern "C" fn(
+        section: *const u8,
+        section_len: u32,
+        key: *const u8,
+        key_len: u32,
+        file: *const u8,
+        file_len: u32,
+        out: *mut i32,
+    ) -> UikaErrorCode,
+
+    pub set_int: unsafe extern "C" fn(
+        section: *const u8,
+        section_len: u32,
+        key: *const u8,
+        key_len: u32,
+        file: *const u8,
+        file_len: u32,
+        value: i32,
+    ) -> UikaErrorCode,
+
+    /// `GetDouble`.
+    pub get_float: unsafe extern "C" fn(
+        section: *const u8,
+        section_len: u32,
+        key: *const u8,
+        key_len: u32,
+        file: *const u8,
+        file_len: u32,
+        out: *mut f64,
+    ) -> UikaErrorCode,
+
+    /// `SetDouble`.
+    pub set_float: unsafe extern "C" fn(
+        section: *const u8,
+        section_len: u32,
+        key: *const u8,
+        key_len: u32,
+        file: *const u8,
+        file_len: u32,
+        value: f64,
+    ) -> UikaErrorCode,
+
+    pub get_bool: unsafe extern "C" fn(
+        section: *const u8,
+        section_len: u32,
+        key: *const u8,
+        key_len: u32,
+        file: *const u8,
+        file_len: u32,
+        out: *mut bool,
+    ) -> UikaErrorCode,
+
+    pub set_bool: unsafe extern "C" fn(
+        section: *const u8,
+        section_len: u32,
+        key: *const u8,
+        key_len: u32,
+        file: *const u8,
+        file_len: u32,
+        value: bool,
+    ) -> UikaErrorCode,
+
+    /// `GetArray` (`+Key=` entries), written as `\n`-terminated UTF-8 lines.
+    /// A missing key reads as no lines. Buffer semantics as `get_string`.
+    pub get_array: unsafe extern "C" fn(
+        section: *const u8,
+        section_len: u32,
+        key: *const u8,
+        key_len: u32,
+        file: *const u8,
+        file_len: u32,
+        buf: *mut u8,
+        buf_len: u32,
+        out_len: *mut u32,
+    ) -> UikaErrorCode,
+
+    /// `SetArray`; `values` holds the entries as `\n`-terminated lines.
+    pub set_array: unsafe extern "C" fn(
+        section: *const u8,
+        section_len: u32,
+        key: *const u8,
+        key_len: u32,
+        file: *const u8,
+        file_len: u32,
+        values: *const u8,
+        values_len: u32,
+    ) -> UikaErrorCode,
+
+    /// Write pending changes of `file` to disk.
+    pub flush: unsafe extern "C" fn(file: *const u8, file_len: u32) -> UikaErrorCode,
+}
+
+// ---------------------------------------------------------------------------
+// UikaGasApi
+// ---------------------------------------------------------------------------
+
+/// Gameplay Ability System helpers. Every call goes through the actor's
+/// ability system component (`UAbilitySystemGlobals::GetAbilitySystemComponentFromActor`)
+/// and fails with `InvalidOperation` (or `false` / `0`) if it has none.
+///
+/// Attributes are named by UTF-8 `"Health"` or `"MyAttributeSet.Health"`,
+/// searched in the component's spawned attribute sets; an unknown attribute
+/// is `PropertyNotFound`.
+#[repr(C)]
+pub struct UikaGasApi {
+    /// Base (`base = true`) or current value of an attribute.
+    pub get_attribute: unsafe extern "C" fn(
+        actor: UObjectHandle,
+        name: *const u8,
+        name_len: u32,
+        base: bool,
+        out: *mut f32,
+    ) -> UikaErrorCode,
+
+    /// Set the base value (`SetNumericAttributeBase`) or the current value.
+    /// A set current value is recomputed on the next aggregator update.
+    pub set_attribute: unsafe extern "C" fn(
+        actor: UObjectHandle,
+        name: *const u8,
+        name_len: u32,
+        base: bool,
+        value: f32,
+    ) -> UikaErrorCode,
+
+    /// `TryActivateAbilityByClass`. The ability must already be granted.
+    pub try_activate_ability_by_class: unsafe extern "C" fn(actor: UObjectHandle, ability_class: UClassHandle) -> bool,
+
+    /// `TryActivateAbilitiesByTag` with a container of `count` packed tag
+    /// names. True if any ability activated.
+    pub try_activate_abilities_by_tag: unsafe extern "C" fn(
+        actor: UObjectHandle,
+        tags: *const FNameHandle,
+        count: u32,
+    ) -> bool,
+
+    /// Apply a gameplay effect class from `source`'s component to `target`'s.
+    /// `out_effect` receives an id for the resulting active effect, or 0 for
+    /// instant effects (which leave nothing to remove).
+    pub apply_gameplay_effect: unsafe extern "C" fn(
+        source: UObjectHandle,
+        target: UObjectHandle,
+        effect_class: UClassHandle,
+        level: f32,
+        out_effect: *mut u32,
+    ) -> UikaErrorCode,
+
+    /// Remove an active effect returned by `apply_gameplay_effect`.
+    /// Removing an expired effect is not an error.
+    pub remove_gameplay_effect: unsafe extern "C" fn(effect: u32) -> UikaErrorCode,
+
+    /// Subscribe to an attribute's value-change delegate. On change, C++
+    /// calls `invoke_delegate_callback(callback_id, params)` with `params`
+    /// pointing to `float[2] { old_value, new_value }`. Returns a binding
+    /// id, or 0 on failure.
+    pub bind_attribute_changed: unsafe extern "C" fn(
+        actor: UObjectHandle,
+        name: *const u8,
+        name_len: u32,
+        callback_id: u64,
+    ) -> u32,
+
+    /// Remove a subscription made with `bind_attribute_changed`.
+    pub unbind_attribute_changed: unsafe extern "C" fn(binding: u32) -> UikaErrorCode,
+}
+
+// ---------------------------------------------------------------------------
+// UikaArrayExtApi
+// ---------------------------------------------------------------------------
+
+/// Range operations on `TArray` properties, complementing `UikaContainerApi`.
+/// Buffers use the same format as `array_copy_all` / `array_set_all`: framed
+/// `[u32 written][data]` per element, or contiguous raw elements when the
+/// count is negative.
+#[repr(C)]
+pub struct UikaArrayExtApi {
+    /// Copy up to `count` elements starting at `start` (clamped to the array
+    /// length). `IndexOutOfRange` if `start` is past the end. Buffer-size
+    /// handling as `array_copy_all`.
+    pub copy_range: unsafe extern "C" fn(
+        obj: UObjectHandle,
+        prop: FPropertyHandle,
+        start: i32,
+        count: i32,
+        out_buf: *mut u8,
+        buf_size: u32,
+        out_total_written: *mut u32,
+        out_count: *mut i32,
+    ) -> UikaErrorCode,
+
+    /// Append elements from a flat buffer.
+    pub append_all: unsafe extern "C" fn(
+        obj: UObjectHandle,
+        prop: FPropertyHandle,
+        in_buf: *const u8,
+        buf_size: u32,
+        count: i32,
+    ) -> UikaErrorCode,
+
+    /// Remove `count` elements starting at `start`. `IndexOutOfRange` if the
+    /// range doesn't fit the array.
+    pub remove_range: unsafe extern "C" fn(
+        obj: UObjectHandle,
+        prop: FPropertyHandle,
+        start: i32,
+        count: i32,
+    ) -> UikaErrorCode,
+}
+
+// ---------------------------------------------------------------------------
+// UikaMapExtApi
+// ---------------------------------------------------------------------------
+
+/// Key-based operations on `TMap` properties, complementing
+/// `UikaContainerApi`. Keys are hashed UE-side; key and value buffers use the
+/// single-element format of `map_find` / `map_add`.
+#[repr(C)]
+pub struct UikaMapExtApi {
+    /// Whether `key` is in the map. False for an invalid object or property.
+    pub contains_key: unsafe extern "C" fn(
+        obj: UObjectHandle,
+        prop: FPropertyHandle,
+        key_buf: *const u8,
+        key_size: u32,
+    ) -> bool,
+
+    /// Copy the value for `key` out, first inserting `default_val` if the key
+    /// is absent. `out_added` reports whether the insert happened.
+    pub find_or_add: unsafe extern "C" fn(
+        obj: UObjectHandle,
+        prop: FPropertyHandle,
+        key_buf: *const u8,
+        key_size: u32,
+        default_val: *const u8,
+        default_size: u32,
+        out_val_buf: *mut u8,
+        val_buf_size: u32,
+        out_val_written: *mut u32,
+        out_added: *mut bool,
+    ) -> UikaErrorCode,
+
+    /// Remove `key`, copying its value out first. `PropertyNotFound` if the
+    /// key is absent.
+    pub remove_and_copy: unsafe extern "C" fn(
+        obj: UObjectHandle,
+        prop: FPropertyHandle,
+        key_buf: *const u8,
+        key_size: u32,
+        out_val_buf: *mut u8,
+        val_buf_size: u32,
+        out_val_written: *mut u32,
+    ) -> UikaErrorCode,
+
+    /// Copy up to `count` pairs starting at logical index `start`, in the
+    /// `map_copy_all` format. `IndexOutOfRange` if `start` is past the end.
+    pub copy_range: unsafe extern "C" fn(
+        obj: UObjectHandle,
+        prop: FPropertyHandle,
+        start: i32,
+        count: i32,
+        out_buf: *mut u8,
+        buf_size: u32,
+        out_total_written: *mut u32,
+        out_count: *mut i32,
+    ) -> UikaErrorCode,
+}
+
+// ---------------------------------------------------------------------------
+// UikaSetExtApi
+// ---------------------------------------------------------------------------
+
+/// Set algebra on `TSet` properties, with membership checks hashed UE-side.
+/// Operations on two sets require the same element type (`TypeMismatch`
+/// otherwise). Output buffers use the `set_copy_all` format.
+#[repr(C)]
+pub struct UikaSetExtApi {
+    /// Add every element of the set at `obj`/`prop` to the set at
+    /// `dst_obj`/`dst_prop`.
+    pub union_into: unsafe extern "C" fn(
+        obj: UObjectHandle,
+        prop: FPropertyHandle,
+        dst_obj: UObjectHandle,
+        dst_prop: FPropertyHandle,
+    ) -> UikaErrorCode,
+
+    /// Copy the elements of the first set that are also in `other`.
+    pub intersection: unsafe extern "C" fn(
+        obj: UObjectHandle,
+        prop: FPropertyHandle,
+        other_obj: UObjectHandle,
+        other_prop: FPropertyHandle,
+        out_buf: *mut u8,
+        buf_size: u32,
+        out_total_written: *mut u32,
+        out_count: *mut i32,
+    ) -> UikaErrorCode,
+
+    /// Copy the elements of the first set that are not in `other`.
+    pub difference: unsafe extern "C" fn(
+        obj: UObjectHandle,
+        prop: FPropertyHandle,
+        other_obj: UObjectHandle,
+        other_prop: FPropertyHandle,
+        out_buf: *mut u8,
+        buf_size: u32,
+        out_total_written: *mut u32,
+        out_count: *mut i32,
+    ) -> UikaErrorCode,
+
+    /// Look up `count` framed (`[u32 written][data]`) elements, writing one
+    /// bool per element to `out_found`.
+    pub contains_each: unsafe extern "C" fn(
+        obj: UObjectHandle,
+        prop: FPropertyHandle,
+        in_buf: *const u8,
+        buf_size: u32,
+        count: i32,
+        out_found: *mut bool,
+    ) -> UikaErrorCode,
+}
+
+// ---------------------------------------------------------------------------
+// UikaPropertyInfoApi
+// ---------------------------------------------------------------------------
+
+/// Runtime type information for reflected properties, so values read through
+/// a property found by name can be checked against the expected Rust type.
+#[repr(C)]
+pub struct UikaPropertyInfoApi {
+    /// Describe `prop` as a `UikaReifyPropType` (as u32) plus the class,
+    /// struct or enum it refers to. `TypeMismatch` for property kinds the
+    /// reify types don't cover (containers, delegates, ...).
+    pub describe: unsafe extern "C" fn(
+        prop: FPropertyHandle,
+        out_type: *mut u32,
+        out_extra: *mut UikaReifyPropExtra,
+    ) -> UikaErrorCode,
+}
+
+// ---------------------------------------------------------------------------
+// UikaObjectIterApi
+// ---------------------------------------------------------------------------
+
+/// Paged enumeration of every loaded object of a class, as `TObjectIterator`
+/// does, with the global object array index as the cursor between pages.
+#[repr(C)]
+pub struct UikaObjectIterApi {
+    /// Write up to `capacity` live instances of `class` (subclasses included,
+    /// class default objects excluded) at or after object index `*cursor` to
+    /// `out`, set `*out_count`, and advance `*cursor` past the last index
+    /// examined. `*cursor` is set to -1 once the whole array is scanned.
+    pub next_page: unsafe extern "C" fn(
+        class: UClassHandle,
+        cursor: *mut i32,
+        out: *mut UObjectHandle,
+        capacity: u32,
+        out_count: *mut u32,
+    ) -> UikaErrorCode,
+}
+
+// ---------------------------------------------------------------------------
+// UikaCoreExtApi
+// ---------------------------------------------------------------------------
+
+/// Core object queries added after `UikaCoreApi` was frozen.
+#[repr(C)]
+pub struct UikaCoreExtApi {
+    /// `UClass::GetDefaultObject()`, creating the CDO if needed. Null for a
+    /// null class.
+    pub get_default_object: unsafe extern "C" fn(class: UClassHandle) -> UObjectHandle,
+}
+
+// ---------------------------------------------------------------------------
+// UikaComponentApi
+// ---------------------------------------------------------------------------
+
+/// Runtime component creation and attachment. Attachment rules are
+/// `EAttachmentRule` values (0 = KeepRelative, 1 = KeepWorld,
+/// 2 = SnapToTarget); `socket` may be `FNameHandle(0)` (None).
+#[repr(C)]
+pub struct UikaComponentApi {
+    /// Create a component of `class` named `name` on `actor`, register it
+    /// and add it to the actor's instance components. A scene component
+    /// becomes the root if the actor has none, otherwise it is attached to
+    /// the root. Returns null on failure (not an actor, not a component
+    /// class, or name in use).
+    pub add_component: unsafe extern "C" fn(
+        actor: UObjectHandle,
+        class: UClassHandle,
+        name_utf8: *const u8,
+        name_len: u32,
+    ) -> UObjectHandle,
+
+    /// `USceneComponent::AttachToComponent`.
+    pub attach_component: unsafe extern "C" fn(
+        child: UObjectHandle,
+        parent: UObjectHandle,
+        socket: FNameHandle,
+        location_rule: u8,
+        rotation_rule: u8,
+        scale_rule: u8,
+        weld_simulated_bodies: bool,
+    ) -> UikaErrorCode,
+
+    /// `AActor::AttachToActor`.
+    pub attach_actor: unsafe extern "C" fn(
+        actor: UObjectHandle,
+        parent: UObjectHandle,
+        socket: FNameHandle,
+        location_rule: u8,
+        rotation_rule: u8,
+        scale_rule: u8,
+        weld_simulated_bodies: bool,
+    ) -> UikaErrorCode,
+
+    /// `USceneComponent::DetachFromComponent`, or `AActor::DetachFromActor`
+    /// when `target` is an actor. `rule` applies to location, rotation and
+    /// scale; 2 (SnapToTarget) detaches as KeepWorld.
+    pub detach: unsafe extern "C" fn(target: UObjectHandle, rule: u8) -> UikaErrorCode,
+}
+
+// ---------------------------------------------------------------------------
+// UikaSubsystemApi
+// ---------------------------------------------------------------------------
+
+/// Subsystem lookup by class. Each function returns null when the context
+/// can't be resolved, `class` is not a subsystem of that kind, or the
+/// subsystem is not created (e.g. `ShouldCreateSubsystem` returned false).
+#[repr(C)]
+pub struct UikaSubsystemApi {
+    /// `UWorld::GetSubsystemBase` on the world of `world_context`.
+    pub get_world_subsystem: unsafe extern "C" fn(world_context: UObjectHandle, class: UClassHandle) -> UObjectHandle,
+
+    /// `UGameInstance::GetSubsystemBase` on the game instance of
+    /// `world_context`'s world, or of the first game world when it is null.
+    pub get_game_instance_subsystem:
+        unsafe extern "C" fn(world_context: UObjectHandle, class: UClassHandle) -> UObjectHandle,
+
+    /// `ULocalPlayer::GetSubsystemBase`. `player` is a local player or a
+    /// player controller.
+    pub get_local_player_subsystem: unsafe extern "C" fn(player: UObjectHandle, class: UClassHandle) -> UObjectHandle,
+
+    /// `UEngine::GetEngineSubsystemBase`.
+    pub get_engine_subsystem: unsafe extern "C" fn(class: UClassHandle) -> UObjectHandle,
+}
+
+// ---------------------------------------------------------------------------
+// UikaLatentApi
+// ---------------------------------------------------------------------------
+
+/// Latent function calls from Rust. The completion of a latent action is
+/// routed to `invoke_delegate_callback` with the given callback id.
+#[repr(C)]
+pub struct UikaLatentApi {
+    /// Fill the `FLatentActionInfo` parameter `prop` in the params buffer
+    /// `params` so that the action's completion invokes `callback_id`.
+    /// `TypeMismatch` if `prop` is not an `FLatentActionInfo`.
+    pub bind_latent_info: unsafe extern "C" fn(
+        params: *mut u8,
+        prop: FPropertyHandle,
+        callback_id: u64,
+    ) -> UikaErrorCode,
+
+    /// Release what `bind_latent_info` set up for `callback_id`. A pending
+    /// action is dropped by the engine once its target is collected.
+    pub release: unsafe extern "C" fn(callback_id: u64),
+}
+
+// ---------------------------------------------------------------------------
+// UikaLoggingExtApi
+// ---------------------------------------------------------------------------
+
+/// Log categories and verbosity queries added after `UikaLoggingApi` was
+/// frozen. Levels as for `UikaLoggingApi::log`; an empty category means
+/// `LogUika`.
+#[repr(C)]
+pub struct UikaLoggingExtApi {
+    /// Create the category with `default_level` as its verbosity, unless it
+    /// already exists. Ini (`[Core.Log]`) and `-LogCmds` overrides still apply.
+    pub register_category: unsafe extern "C" fn(category: *const u8, category_len: u32, default_level: u8),
+
+    /// Whether a message at `level` in `category` would be written, given
+    /// the category's current verbosity (creating it like `log_category`).
+    pub is_enabled: unsafe extern "C" fn(category: *const u8, category_len: u32, level: u8) -> bool,
+}
+
+// ---------------------------------------------------------------------------
+// UikaPanicApi
+// ---------------------------------------------------------------------------
+
+/// Forwarding of Rust panics to UE's error reporting, per the runtime's
+/// panic policy. `msg` is a UTF-8 byte slice (not null-terminated).
+#[repr(C)]
+pub struct UikaPanicApi {
+    /// `ensureAlwaysMsgf(false, ...)`: report to the crash reporter and
+    /// break into an attached debugger, then return.
+    pub report_ensure: unsafe extern "C" fn(msg: *const u8, msg_len: u32),
+
+    /// `UE_LOG(LogUika, Fatal, ...)`: crash with the message. Does not return.
+    pub fatal: unsafe extern "C" fn(msg: *const u8, msg_len: u32),
+}
+
+// ---------------------------------------------------------------------------
+// UikaGuidApi
+// ---------------------------------------------------------------------------
+
+#[repr(C)]
+pub struct UikaGuidApi {
+    /// `FGuid::NewGuid()`, written to `out` as the words A, B, C, D.
+    pub new_guid: unsafe extern "C" fn(out: *mut u32),
+}
+
+// ---------------------------------------------------------------------------
+// UikaHitResultApi
+// ---------------------------------------------------------------------------
+
+#[repr(C)]
+pub struct UikaHitResultApi {
+    /// Flatten the FHitResult at `data` (e.g. one returned by a generated
+    /// function) into `out`. `TypeMismatch` unless `ustruct` is FHitResult.
+    pub decode: unsafe extern "C" fn(
+        ustruct: UStructHandle,
+        data: *const u8,
+        out: *mut UikaHitResult,
+    ) -> UikaErrorCode,
+}
+
+// ---------------------------------------------------------------------------
+// UikaPropertyBulkApi
+// ---------------------------------------------------------------------------
+
+/// Read or write many scalar properties of one object in a single call.
+///
+/// Each value travels in a `u64` slot, tagged with its `UikaReifyPropType`
+/// (as u32): bools as 0/1, signed integers and enums sign-extended, unsigned
+/// integers zero-extended, floats as their `f32` bits, doubles as their `f64`
+/// bits, names as the FName handle and objects/classes as the pointer.
+/// Strings, text and structs are not scalar (`TypeMismatch`).
+#[repr(C)]
+pub struct UikaPropertyBulkApi {
+    /// Read `count` properties of `obj` into `out_values`, and each
+    /// property's type into `out_kinds`. `TypeMismatch` if any property is
+    /// not scalar.
+    pub get_many: unsafe extern "C" fn(
+        obj: UObjectHandle,
+        props: *const FPropertyHandle,
+        count: u32,
+        out_values: *mut u64,
+        out_kinds: *mut u32,
+    ) -> UikaErrorCode,
+
+    /// Write `count` properties of `obj` from `values`. `kinds` must match
+    /// each property's type (Object and Class are interchangeable); all are
+    /// checked before anything is written, so a `TypeMismatch` writes nothing.
+    pub set_many: unsafe extern "C" fn(
+        obj: UObjectHandle,
+        props: *const FPropertyHandle,
+        count: u32,
+        values: *const u64,
+        kinds: *const u32,
+    ) -> UikaErrorCode,
+}
+
+// ---------------------------------------------------------------------------
+// UikaPropertyNotifyApi
+// ---------------------------------------------------------------------------
+
+/// A property change, passed as `params` to `invoke_delegate_callback`.
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct UikaPropertyChange {
+    pub object: UObjectHandle,
+    /// The property that changed (for an edit inside a struct, the innermost
+    /// member).
+    pub property: FNameHandle,
+    /// The object's own property containing it; `property` itself for a
+    /// direct change.
+    pub member_property: FNameHandle,
+    /// `EPropertyChangeType` bits (`UIKA_PROPERTY_CHANGE_*`).
+    pub change_type: u32,
+}
+
+pub const UIKA_PROPERTY_CHANGE_UNSPECIFIED: u32 = 1 << 0;
+pub const UIKA_PROPERTY_CHANGE_ARRAY_ADD: u32 = 1 << 1;
+pub const UIKA_PROPERTY_CHANGE_ARRAY_REMOVE: u32 = 1 << 2;
+pub const UIKA_PROPERTY_CHANGE_ARRAY_CLEAR: u32 = 1 << 3;
+pub const UIKA_PROPERTY_CHANGE_VALUE_SET: u32 = 1 << 4;
+pub const UIKA_PROPERTY_CHANGE_DUPLICATE: u32 = 1 << 5;
+/// The value is being dragged (e.g. a slider); a final change follows.
+pub const UIKA_PROPERTY_CHANGE_INTERACTIVE: u32 = 1 << 6;
+pub const UIKA_PROPERTY_CHANGE_REDIRECTED: u32 = 1 << 7;
+pub const UIKA_PROPERTY_CHANGE_ARRAY_MOVE: u32 = 1 << 8;
+
+/// Property change subscriptions. In editor builds, changes reported by
+/// `FCoreUObjectDelegates::OnObjectPropertyChanged` (details panel edits,
+/// undo/redo, `PostEditChangeProperty`) are delivered; `notify_changed`
+/// delivers a change in every build.
+#[repr(C)]
+pub struct UikaPropertyNotifyApi {
+    /// Call `invoke_delegate_callback(callback_id, &UikaPropertyChange)` for
+    /// changes to `obj` (null: any object) whose property or member property
+    /// is named `name` (empty: any property). Returns a binding id, or 0 on
+    /// failure.
+    pub subscribe: unsafe extern "C" fn(
+        obj: UObjectHandle,
+        name: *const u8,
+        name_len: u32,
+        callback_id: u64,
+    ) -> u32,
+
+    /// Remove a subscription made with `subscribe`.
+    pub unsubscribe: unsafe extern "C" fn(binding: u32) -> UikaErrorCode,
+
+    /// Deliver a `VALUE_SET` change of `prop` on `obj` to the matching
+    /// subscriptions, for changes made from code.
+    pub notify_changed: unsafe extern "C" fn(obj: UObjectHandle, prop: FPropertyHandle) -> UikaErrorCode,
+}
+
+// ---------------------------------------------------------------------------
+// UikaOutputStashApi
+// ---------------------------------------------------------------------------
+
+/// String outputs of generated function wrappers that did not fit the
+/// caller's buffer. The wrapper keeps the full UTF-8 text (per thread, keyed
+/// by the param's index in the function) and returns BufferTooSmall; the
+/// bindings fetch it here instead of calling the UE function again.
+#[repr(C)]
+pub struct UikaOutputStashApi {
+    /// Copy stashed output `slot` into `buf` and forget it; `out_len`
+    /// receives its length. BufferTooSmall (and kept) if it does not fit,
+    /// InvalidOperation if nothing is stashed for `slot`.
+    pub take_string: unsafe extern "C" fn(slot: u32, buf: *mut u8, buf_len: u32, out_len: *mut u32) -> UikaErrorCode,
+}
+
+// ---------------------------------------------------------------------------
+// UikaGameplayTagExtApi
+// ---------------------------------------------------------------------------
+
+/// Single FGameplayTag values in UE memory, added after `UikaGameplayTagApi`
+/// was frozen. Tags travel as their packed tag name, as in that table.
+#[repr(C)]
+pub struct UikaGameplayTagExtApi {
+    /// `FGameplayTag::GetTagName` of the FGameplayTag at `tag` (0 for an
+    /// empty tag).
+    pub read_tag: unsafe extern "C" fn(tag: *const u8) -> FNameHandle,
+
+    /// Overwrite the FGameplayTag at `tag` with the tag named `name`, looked
+    /// up through the tag manager (empty if `name` is not registered).
+    pub write_tag: unsafe extern "C" fn(tag: *mut u8, name: FNameHandle) -> UikaErrorCode,
+}
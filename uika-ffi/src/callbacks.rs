@@ -1,9 +1,19 @@
+use std::ffi::c_void;
+
 use crate::handles::UObjectHandle;
 
 /// Callback table filled by Rust and returned to C++ from `uika_init`.
 /// C++ calls into Rust through these function pointers.
+///
+/// Like `UikaApiTable`, the layout only grows at the end; the plugin treats
+/// callbacks beyond `size` as null.
 #[repr(C)]
 pub struct UikaRustCallbacks {
+    /// `UIKA_API_VERSION` of the DLL.
+    pub version: u32,
+    /// `size_of::<UikaRustCallbacks>()` as compiled into the DLL.
+    pub size: u32,
+
     /// Called when a Rust-defined UClass instance is destroyed (reify only).
     pub drop_rust_instance: extern "C" fn(
         handle: UObjectHandle,
@@ -33,4 +43,20 @@ pub struct UikaRustCallbacks {
 
     /// Called by C++ when a Pinned object is destroyed (DestroyActor, level unload, etc.).
     pub notify_pinned_destroyed: extern "C" fn(handle: UObjectHandle),
+
+    /// Run the registered in-engine tests whose names contain `filter`
+    /// (UTF-8, may be empty). Returns the number of failed tests.
+    pub run_tests: extern "C" fn(filter: *const u8, filter_len: u32) -> u32,
+
+    /// Call `report(ctx, obj)` for every UObject Rust holds a GC-visible
+    /// reference to. Called from the plugin's `AddReferencedObjects`. Since
+    /// version 19.
+    pub collect_references: extern "C" fn(report: UikaReportReferenceFn, ctx: *mut c_void),
+
+    /// Called once per frame on the game thread, including in the editor
+    /// outside of play. Since version 22.
+    pub tick: extern "C" fn(delta_seconds: f32),
 }
+
+/// Reporter passed to `UikaRustCallbacks::collect_references`.
+pub type UikaReportReferenceFn = extern "C" fn(ctx: *mut c_void, obj: UObjectHandle);
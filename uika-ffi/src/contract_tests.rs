@@ -1,10 +1,11 @@
 // Compile-time contract tests: ensure handle sizes match C++ expectations.
 // These const assertions fail at compile time if sizes drift.
 
-use std::mem::size_of;
+use std::mem::{offset_of, size_of};
 
 use crate::handles::*;
 use crate::error::UikaErrorCode;
+use crate::physics_types::{UikaCollisionShape, UikaHitResult};
 
 const _: () = assert!(size_of::<UObjectHandle>() == 8);
 const _: () = assert!(size_of::<UClassHandle>() == 8);
@@ -12,5 +13,17 @@ const _: () = assert!(size_of::<FPropertyHandle>() == 8);
 const _: () = assert!(size_of::<UFunctionHandle>() == 8);
 const _: () = assert!(size_of::<UStructHandle>() == 8);
 const _: () = assert!(size_of::<FNameHandle>() == 8);
+const _: () = assert!(size_of::<FTextHandle>() == 8);
 const _: () = assert!(size_of::<FWeakObjectHandle>() == 8);
 const _: () = assert!(size_of::<UikaErrorCode>() == 4);
+const _: () = assert!(size_of::<UikaHitResult>() == 144);
+const _: () = assert!(size_of::<UikaCollisionShape>() == 32);
+const _: () = assert!(size_of::<crate::api_table::UikaBindingStamp>() == 32);
+const _: () = assert!(size_of::<crate::api_table::UikaSaveGameResult>() == 24);
+const _: () = assert!(size_of::<crate::api_table::UikaPropertyChange>() == 32);
+
+// Version header: must stay at the front so any table version can be read.
+const _: () = assert!(offset_of!(crate::api_table::UikaApiTable, version) == 0);
+const _: () = assert!(offset_of!(crate::api_table::UikaApiTable, size) == 4);
+const _: () = assert!(offset_of!(crate::callbacks::UikaRustCallbacks, version) == 0);
+const _: () = assert!(offset_of!(crate::callbacks::UikaRustCallbacks, size) == 4);
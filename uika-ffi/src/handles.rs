@@ -59,6 +59,12 @@ define_ptr_handle! {
     UStructHandle
 }
 
+define_ptr_handle! {
+    /// Owning handle to a heap-allocated FText (keeps its localization
+    /// identity). Must be released with `UikaTextApi::destroy`.
+    FTextHandle
+}
+
 /// FName stored as a raw 64-bit value (ComparisonIndex + Number).
 #[repr(transparent)]
 #[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
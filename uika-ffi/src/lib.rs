@@ -6,6 +6,7 @@ pub mod error;
 pub mod api_table;
 pub mod callbacks;
 pub mod reify_types;
+pub mod physics_types;
 pub mod contract_tests;
 
 pub use handles::*;
@@ -13,4 +14,5 @@ pub use error::*;
 pub use api_table::*;
 pub use callbacks::*;
 pub use reify_types::*;
+pub use physics_types::*;
 pub use uika_ue_flags::*;
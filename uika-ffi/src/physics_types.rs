@@ -0,0 +1,66 @@
+// Physics query FFI types: hit results and collision shapes.
+
+use crate::handles::*;
+
+/// Flattened FHitResult. Vectors are world-space `[x, y, z]`.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct UikaHitResult {
+    /// Hit actor (null if none).
+    pub actor: UObjectHandle,
+    /// Hit primitive component (null if none).
+    pub component: UObjectHandle,
+    /// Location of the trace shape at the time of the hit.
+    pub location: [f64; 3],
+    /// Point of contact on the hit surface.
+    pub impact_point: [f64; 3],
+    /// Normal of the trace shape at the hit.
+    pub normal: [f64; 3],
+    /// Surface normal of the hit object.
+    pub impact_normal: [f64; 3],
+    /// Distance from trace start to `location`.
+    pub distance: f64,
+    /// Fraction along the trace (0..1) where the hit occurred.
+    pub time: f32,
+    /// Face index for complex traces (-1 if unknown).
+    pub face_index: i32,
+    /// Hit skeletal bone (None if not a bone).
+    pub bone_name: FNameHandle,
+    /// Blocking hit (vs. overlap in multi traces).
+    pub blocking_hit: bool,
+    /// Trace started inside the hit geometry.
+    pub start_penetrating: bool,
+}
+
+impl Default for UikaHitResult {
+    fn default() -> Self {
+        Self {
+            actor: UObjectHandle::null(),
+            component: UObjectHandle::null(),
+            location: [0.0; 3],
+            impact_point: [0.0; 3],
+            normal: [0.0; 3],
+            impact_normal: [0.0; 3],
+            distance: 0.0,
+            time: 0.0,
+            face_index: -1,
+            bone_name: FNameHandle(0),
+            blocking_hit: false,
+            start_penetrating: false,
+        }
+    }
+}
+
+/// Shape kind for sweeps and overlaps.
+pub const UIKA_SHAPE_SPHERE: u8 = 0;
+pub const UIKA_SHAPE_BOX: u8 = 1;
+pub const UIKA_SHAPE_CAPSULE: u8 = 2;
+
+/// FCollisionShape. `extent` is `[radius, 0, 0]` for spheres, half extents
+/// for boxes, and `[radius, 0, half_height]` for capsules.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct UikaCollisionShape {
+    pub kind: u8,
+    pub extent: [f64; 3],
+}
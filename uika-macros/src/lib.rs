@@ -1,8 +1,9 @@
-// uika-macros: proc macros for #[uclass], #[ufunction], #[uproperty].
+// uika-macros: proc macros for #[uclass], #[ustruct], #[ufunction], #[uproperty].
 
 mod prop_type;
 mod uclass;
 mod uclass_impl;
+mod ustruct;
 
 /// Attribute macro for defining a Rust struct as a UE class.
 ///
@@ -17,6 +18,13 @@ mod uclass_impl;
 ///     chase_timer: f64,
 /// }
 /// ```
+///
+/// Interfaces are declared with `implements(...)` and their functions
+/// implemented as `#[ufunction(Override)]` methods:
+/// ```ignore
+/// #[uclass(parent = Actor, implements(Interactable))]
+/// pub struct Door {}
+/// ```
 #[proc_macro_attribute]
 pub fn uclass(
     attr: proc_macro::TokenStream,
@@ -43,6 +51,20 @@ pub fn uclass(
 ///     }
 ///
 ///     fn helper(&self) -> f32 { self.health() * 2.0 }  // plain Rust method
+///
+///     // RPC: Server/Client/NetMulticast, optionally Reliable and WithValidation.
+///     #[ufunction(Server, Reliable, WithValidation)]
+///     fn server_heal(&mut self, amount: f32) { /* ... */ }
+///
+///     fn server_heal_validate(&self, amount: f32) -> bool { amount >= 0.0 }
+///
+///     // Implemented only in Blueprint subclasses; calling it runs the override.
+///     #[ufunction(BlueprintImplementableEvent)]
+///     fn on_spotted(&self, distance: f32);
+///
+///     // Rust default in `on_died_implementation`, overridable in Blueprint.
+///     #[ufunction(BlueprintNativeEvent)]
+///     fn on_died(&mut self) { /* default behavior */ }
 /// }
 /// ```
 #[proc_macro_attribute]
@@ -55,3 +77,30 @@ pub fn uclass_impl(
         Err(err) => err.to_compile_error().into(),
     }
 }
+
+/// Attribute macro for defining a Rust struct as a UE struct (UScriptStruct).
+///
+/// All fields must be `#[uproperty]`. The struct can then be used as a
+/// Blueprint pin or variable type, and as a `UStructRef<T>` ufunction param.
+///
+/// # Example
+/// ```ignore
+/// #[ustruct]
+/// #[derive(Clone, Copy, Default)]
+/// pub struct DamageInfo {
+///     #[uproperty(BlueprintReadWrite)]
+///     amount: f32,
+///     #[uproperty(BlueprintReadWrite)]
+///     critical: bool,
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn ustruct(
+    attr: proc_macro::TokenStream,
+    item: proc_macro::TokenStream,
+) -> proc_macro::TokenStream {
+    match ustruct::expand_ustruct(attr.into(), item.into()) {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
@@ -16,6 +16,7 @@ use crate::prop_type;
 struct UClassArgs {
     parent_path: syn::Path,  // Full Rust path for compile-time type checking
     parent_name: String,     // Last segment string for runtime find_class
+    interfaces: Vec<syn::Path>, // implements(...): UInterface types
 }
 
 fn parse_uclass_args(attr: TokenStream) -> syn::Result<UClassArgs> {
@@ -31,7 +32,14 @@ fn parse_uclass_args(attr: TokenStream) -> syn::Result<UClassArgs> {
             });
 
     let mut parent_path: Option<syn::Path> = None;
+    let mut interfaces: Vec<syn::Path> = Vec::new();
     for meta in &metas {
+        if let Meta::List(list) = meta
+            && list.path.is_ident("implements")
+        {
+            let paths = list.parse_args_with(Punctuated::<syn::Path, Token![,]>::parse_terminated)?;
+            interfaces.extend(paths);
+        }
         if let Meta::NameValue(nv) = meta {
             if nv.path.is_ident("parent") {
                 if let Expr::Path(expr_path) = &nv.value {
@@ -62,7 +70,7 @@ fn parse_uclass_args(attr: TokenStream) -> syn::Result<UClassArgs> {
         .last()
         .map(|s| s.ident.to_string())
         .unwrap_or_default();
-    Ok(UClassArgs { parent_path, parent_name })
+    Ok(UClassArgs { parent_path, parent_name, interfaces })
 }
 
 /// Specifiers parsed from #[uproperty(...)].
@@ -72,14 +80,14 @@ fn parse_uclass_args(attr: TokenStream) -> syn::Result<UClassArgs> {
 /// instances. Always initialize critical values in your `receive_begin_play` or
 /// init function instead of relying solely on CDO defaults.
 #[derive(Default)]
-struct UPropertyArgs {
+pub(crate) struct UPropertyArgs {
     blueprint_read_write: bool,
     blueprint_read_only: bool,
     edit_anywhere: bool,
-    default_expr: Option<Expr>,
+    pub(crate) default_expr: Option<Expr>,
 }
 
-fn parse_uproperty_args(attr: &syn::Attribute) -> syn::Result<UPropertyArgs> {
+pub(crate) fn parse_uproperty_args(attr: &syn::Attribute) -> syn::Result<UPropertyArgs> {
     let mut args = UPropertyArgs::default();
     let nested = attr.parse_args_with(
         Punctuated::<Meta, Token![,]>::parse_terminated,
@@ -110,17 +118,19 @@ fn parse_uproperty_args(attr: &syn::Attribute) -> syn::Result<UPropertyArgs> {
 // Component attribute parsing
 // ---------------------------------------------------------------------------
 
-/// Parsed #[component(...)] attributes.
+/// Parsed #[component(...)] / #[ucomponent(...)] attributes.
+#[derive(Default)]
 struct ComponentArgs {
     is_root: bool,
+    is_transient: bool,
     attach_to: Option<String>,
+    attach_root: bool,
+    /// Explicit component type (`#[ucomponent(StaticMeshComponent, ...)]`).
+    component_type: Option<syn::Path>,
 }
 
 fn parse_component_args(attr: &syn::Attribute) -> syn::Result<ComponentArgs> {
-    let mut args = ComponentArgs {
-        is_root: false,
-        attach_to: None,
-    };
+    let mut args = ComponentArgs::default();
 
     // #[component] with no parens → defaults
     let nested = match attr.parse_args_with(
@@ -158,6 +168,78 @@ fn parse_component_args(attr: &syn::Attribute) -> syn::Result<ComponentArgs> {
     Ok(args)
 }
 
+/// Parse `#[ucomponent(Type, root, transient, attach = Root | other_field)]`.
+fn parse_ucomponent_args(attr: &syn::Attribute) -> syn::Result<ComponentArgs> {
+    let mut args = ComponentArgs::default();
+    let nested = attr.parse_args_with(
+        Punctuated::<Meta, Token![,]>::parse_terminated,
+    )?;
+    for meta in &nested {
+        match meta {
+            Meta::Path(p) if p.is_ident("root") => args.is_root = true,
+            Meta::Path(p) if p.is_ident("transient") => args.is_transient = true,
+            Meta::Path(p) => {
+                if args.component_type.is_some() {
+                    return Err(syn::Error::new_spanned(p, "component type specified twice"));
+                }
+                args.component_type = Some(p.clone());
+            }
+            Meta::NameValue(nv) if nv.path.is_ident("attach") => match &nv.value {
+                Expr::Path(ep) if ep.path.is_ident("Root") => args.attach_root = true,
+                Expr::Path(ep) if ep.path.get_ident().is_some() => {
+                    args.attach_to = Some(ep.path.get_ident().unwrap().to_string());
+                }
+                Expr::Lit(syn::ExprLit { lit: syn::Lit::Str(s), .. }) => {
+                    args.attach_to = Some(s.value());
+                }
+                other => {
+                    return Err(syn::Error::new_spanned(
+                        other,
+                        "attach must be `Root` or the name of another component field",
+                    ));
+                }
+            },
+            other => {
+                return Err(syn::Error::new_spanned(other, "unknown ucomponent specifier"));
+            }
+        }
+    }
+    if args.is_root && (args.attach_root || args.attach_to.is_some()) {
+        return Err(syn::Error::new_spanned(attr, "a root component cannot be attached"));
+    }
+    Ok(args)
+}
+
+/// The `T` of a `ComponentRef<T>` / `UObjectRef<T>` field type.
+fn component_ref_inner(ty: &syn::Type) -> Option<syn::Path> {
+    let syn::Type::Path(tp) = ty else { return None };
+    let seg = tp.path.segments.last()?;
+    if seg.ident != "ComponentRef" && seg.ident != "UObjectRef" {
+        return None;
+    }
+    let syn::PathArguments::AngleBracketed(ab) = &seg.arguments else { return None };
+    ab.args.iter().find_map(|a| match a {
+        syn::GenericArgument::Type(syn::Type::Path(inner)) => Some(inner.path.clone()),
+        _ => None,
+    })
+}
+
+/// Whether a Rust field's type is one `ReportReferences` covers:
+/// `UObjectRef<T>`, `ComponentRef<T>`, `GcVisible<T>`, or an `Option` / `Vec`
+/// of those.
+fn holds_object_refs(ty: &syn::Type) -> bool {
+    let syn::Type::Path(tp) = ty else { return false };
+    let Some(seg) = tp.path.segments.last() else { return false };
+    match seg.ident.to_string().as_str() {
+        "UObjectRef" | "ComponentRef" | "GcVisible" => true,
+        "Option" | "Vec" => {
+            let syn::PathArguments::AngleBracketed(ab) = &seg.arguments else { return false };
+            matches!(ab.args.first(), Some(syn::GenericArgument::Type(inner)) if holds_object_refs(inner))
+        }
+        _ => false,
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Field classification
 // ---------------------------------------------------------------------------
@@ -177,7 +259,9 @@ struct ComponentField {
     ident: Ident,
     component_type: syn::Path,
     is_root: bool,
+    is_transient: bool,
     attach_to: Option<String>,
+    attach_root: bool,
 }
 
 // ---------------------------------------------------------------------------
@@ -222,12 +306,38 @@ pub fn expand_uclass(attr: TokenStream, item: TokenStream) -> syn::Result<TokenS
             .iter()
             .find(|a| a.path().is_ident("component"));
 
+        let ucomp_attr = field
+            .attrs
+            .iter()
+            .find(|a| a.path().is_ident("ucomponent"));
+
         let uprop_attr = field
             .attrs
             .iter()
             .find(|a| a.path().is_ident("uproperty"));
 
-        if let Some(attr) = comp_attr {
+        if let Some(attr) = ucomp_attr {
+            let cargs = parse_ucomponent_args(attr)?;
+            let component_type = cargs
+                .component_type
+                .clone()
+                .or_else(|| component_ref_inner(&field_ty))
+                .ok_or_else(|| {
+                    syn::Error::new_spanned(
+                        &field_ty,
+                        "#[ucomponent] needs a component type: `#[ucomponent(StaticMeshComponent)]` \
+                         or a `ComponentRef<StaticMeshComponent>` field",
+                    )
+                })?;
+            components.push(ComponentField {
+                ident: field_ident,
+                component_type,
+                is_root: cargs.is_root,
+                is_transient: cargs.is_transient,
+                attach_to: cargs.attach_to,
+                attach_root: cargs.attach_root,
+            });
+        } else if let Some(attr) = comp_attr {
             let cargs = parse_component_args(attr)?;
             // Extract the type as a path for UeClass trait bound
             let component_type = match &field_ty {
@@ -243,7 +353,9 @@ pub fn expand_uclass(attr: TokenStream, item: TokenStream) -> syn::Result<TokenS
                 ident: field_ident,
                 component_type,
                 is_root: cargs.is_root,
+                is_transient: false,
                 attach_to: cargs.attach_to,
+                attach_root: false,
             });
         } else if let Some(attr) = uprop_attr {
             let pargs = parse_uproperty_args(attr)?;
@@ -303,6 +415,18 @@ pub fn expand_uclass(attr: TokenStream, item: TokenStream) -> syn::Result<TokenS
         })
         .collect();
 
+    // Rust fields holding UObject references are reported to the GC.
+    let report_ref_stmts: Vec<TokenStream> = rust_fields
+        .iter()
+        .filter(|f| holds_object_refs(&f.ty))
+        .map(|f| {
+            let ident = &f.ident;
+            quote! {
+                ::uika::runtime::gc::ReportReferences::report_references(&_data.#ident, _report);
+            }
+        })
+        .collect();
+
     let rust_data_struct = quote! {
         #[doc(hidden)]
         pub struct #rust_data_name {
@@ -486,27 +610,7 @@ pub fn expand_uclass(attr: TokenStream, item: TokenStream) -> syn::Result<TokenS
         let prop_type_expr = &info.prop_type_expr;
         let prop_var = format_ident!("_prop_{}", prop.ident);
 
-        // Compute flags:
-        //   BlueprintReadWrite → visible + editable in Details and Blueprint
-        //   BlueprintReadOnly  → visible in Blueprint (get only) + visible but greyed in Details
-        //   EditAnywhere       → editable in Details
-        let mut flag_parts = Vec::new();
-        if prop.args.blueprint_read_write || prop.args.blueprint_read_only {
-            flag_parts.push(quote! { ::uika::ffi::CPF_BLUEPRINT_VISIBLE });
-        }
-        if prop.args.blueprint_read_only {
-            flag_parts.push(quote! { ::uika::ffi::CPF_BLUEPRINT_READ_ONLY });
-            // VisibleAnywhere: show in Details as read-only
-            flag_parts.push(quote! { ::uika::ffi::CPF_EDIT });
-            flag_parts.push(quote! { ::uika::ffi::CPF_EDIT_CONST });
-        }
-        if prop.args.edit_anywhere || prop.args.blueprint_read_write {
-            flag_parts.push(quote! { ::uika::ffi::CPF_EDIT });
-        }
-        if flag_parts.is_empty() {
-            flag_parts.push(quote! { 0u64 });
-        }
-        let flags_expr = quote! { #(#flag_parts)|* };
+        let flags_expr = property_flags_expr(&prop.args);
 
         add_prop_stmts.push(quote! {
             let #prop_var = unsafe {
@@ -515,7 +619,7 @@ pub fn expand_uclass(attr: TokenStream, item: TokenStream) -> syn::Result<TokenS
                     [#(#ue_name_bytes),*].as_ptr(),
                     #ue_name_len,
                     #prop_type_expr as u32,
-                    #flags_expr,
+                    (#flags_expr).bits(),
                     std::ptr::null(),
                 )
             };
@@ -532,6 +636,23 @@ pub fn expand_uclass(attr: TokenStream, item: TokenStream) -> syn::Result<TokenS
         }
     }
 
+    // Generate add_interface calls (before functions are registered, so
+    // Override ufunctions can find the interface's declarations)
+    let add_interface_stmts: Vec<TokenStream> = args
+        .interfaces
+        .iter()
+        .map(|iface| {
+            quote! {
+                unsafe {
+                    ::uika::runtime::ffi_dispatch::reify_add_interface(
+                        class,
+                        <#iface as ::uika::runtime::UeClass>::static_class(),
+                    );
+                }
+            }
+        })
+        .collect();
+
     // Generate add_default_subobject calls
     let mut add_comp_stmts: Vec<TokenStream> = Vec::new();
     for comp in &components {
@@ -544,6 +665,12 @@ pub fn expand_uclass(attr: TokenStream, item: TokenStream) -> syn::Result<TokenS
         if comp.is_root {
             flags |= 1; // UIKA_COMP_ROOT
         }
+        if comp.is_transient {
+            flags |= 2; // UIKA_COMP_TRANSIENT
+        }
+        if comp.attach_root {
+            flags |= 4; // UIKA_COMP_ATTACH_ROOT
+        }
 
         let (attach_ptr, attach_len) = if let Some(ref attach_name) = comp.attach_to {
             let attach_pascal = prop_type::to_pascal_case(attach_name);
@@ -614,6 +741,13 @@ pub fn expand_uclass(attr: TokenStream, item: TokenStream) -> syn::Result<TokenS
                             let _ = unsafe { Box::from_raw(ptr as *mut #rust_data_name) };
                         }
                     },
+                    references_fn: |ptr, _report| {
+                        if ptr.is_null() {
+                            return;
+                        }
+                        let _data = unsafe { &*(ptr as *const #rust_data_name) };
+                        #(#report_ref_stmts)*
+                    },
                 },
             );
 
@@ -651,6 +785,9 @@ pub fn expand_uclass(attr: TokenStream, item: TokenStream) -> syn::Result<TokenS
             // Add properties (finalize deferred to __uika_finalize)
             #(#add_prop_stmts)*
 
+            // Declare implemented interfaces
+            #(#add_interface_stmts)*
+
             // Register default subobjects
             #(#add_comp_stmts)*
         }
@@ -694,12 +831,19 @@ pub fn expand_uclass(attr: TokenStream, item: TokenStream) -> syn::Result<TokenS
         })
         .collect();
 
+    let iface_type_checks: Vec<TokenStream> = args
+        .interfaces
+        .iter()
+        .map(|iface| quote! { _assert_ue_class::<#iface>(); })
+        .collect();
+
     let parent_check = quote! {
         const _: () = {
             fn _uika_parent_check() {
                 fn _assert_ue_class<T: ::uika::runtime::UeClass>() {}
                 _assert_ue_class::<#parent_path>();
                 #(#comp_type_checks)*
+                #(#iface_type_checks)*
             }
         };
     };
@@ -749,6 +893,30 @@ pub fn expand_uclass(attr: TokenStream, item: TokenStream) -> syn::Result<TokenS
 // Helpers
 // ---------------------------------------------------------------------------
 
+/// Compute CPF_* flags for a #[uproperty]:
+///   BlueprintReadWrite → visible + editable in Details and Blueprint
+///   BlueprintReadOnly  → visible in Blueprint (get only) + visible but greyed in Details
+///   EditAnywhere       → editable in Details
+pub(crate) fn property_flags_expr(args: &UPropertyArgs) -> TokenStream {
+    let mut flag_parts = Vec::new();
+    if args.blueprint_read_write || args.blueprint_read_only {
+        flag_parts.push(quote! { ::uika::ue_flags::PropertyFlags::BLUEPRINT_VISIBLE });
+    }
+    if args.blueprint_read_only {
+        flag_parts.push(quote! { ::uika::ue_flags::PropertyFlags::BLUEPRINT_READ_ONLY });
+        // VisibleAnywhere: show in Details as read-only
+        flag_parts.push(quote! { ::uika::ue_flags::PropertyFlags::EDIT });
+        flag_parts.push(quote! { ::uika::ue_flags::PropertyFlags::EDIT_CONST });
+    }
+    if args.edit_anywhere || args.blueprint_read_write {
+        flag_parts.push(quote! { ::uika::ue_flags::PropertyFlags::EDIT });
+    }
+    if flag_parts.is_empty() {
+        flag_parts.push(quote! { ::uika::ue_flags::PropertyFlags::empty() });
+    }
+    quote! { #(#flag_parts)|* }
+}
+
 pub(crate) fn to_snake_case(s: &str) -> String {
     let mut result = String::new();
     for (i, c) in s.chars().enumerate() {
@@ -3,16 +3,18 @@
 
 use proc_macro2::TokenStream;
 use quote::{format_ident, quote};
-use syn::{parse2, FnArg, Ident, ImplItem, ImplItemFn, ItemImpl, Meta, ReturnType, Token, Type};
+use syn::{parse2, Attribute, FnArg, Ident, ImplItem, ImplItemFn, ItemImpl, Meta, ReturnType, Signature, Token, Type, Visibility};
+use syn::parse::{Parse, ParseStream};
 use syn::punctuated::Punctuated;
 
 use crate::prop_type;
-use crate::uclass::{to_snake_case, to_screaming_snake};
+use crate::uclass::to_screaming_snake;
 
 // ---------------------------------------------------------------------------
 // Parsed ufunction info
 // ---------------------------------------------------------------------------
 
+#[derive(Clone)]
 struct ParamInfo {
     rust_name: Ident,
     ue_name: String,
@@ -21,11 +23,71 @@ struct ParamInfo {
 
 struct UFunctionInfo {
     method_ident: Ident,
+    /// Method the registered callback calls (`<method>_implementation` for
+    /// BlueprintNativeEvent, otherwise `method_ident`).
+    impl_ident: Ident,
     ue_name: String,
     params: Vec<ParamInfo>,
     return_type: Option<ParamInfo>,
     is_mut: bool,
     is_override: bool,
+    /// RPC specifiers (Server/Client/NetMulticast + Reliable/WithValidation).
+    rpc: Option<RpcInfo>,
+    /// Hidden `<Name>_Validate` function registered for a WithValidation RPC.
+    is_validate: bool,
+    event: Option<EventKind>,
+}
+
+/// Blueprint-overridable events declared in Rust.
+#[derive(Clone, Copy, PartialEq)]
+enum EventKind {
+    /// No Rust body; only Blueprint subclasses implement it.
+    Implementable,
+    /// Rust default implementation, overridable in Blueprint.
+    Native,
+}
+
+/// A bodyless method in an impl block (`fn on_hit(&self, damage: f32);`),
+/// which syn only yields as verbatim tokens.
+struct BodylessFn {
+    attrs: Vec<Attribute>,
+    vis: Visibility,
+    sig: Signature,
+}
+
+impl Parse for BodylessFn {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let attrs = input.call(Attribute::parse_outer)?;
+        let vis: Visibility = input.parse()?;
+        let sig: Signature = input.parse()?;
+        input.parse::<Token![;]>()?;
+        Ok(BodylessFn { attrs, vis, sig })
+    }
+}
+
+impl BodylessFn {
+    fn into_impl_fn(self) -> ImplItemFn {
+        ImplItemFn {
+            attrs: self.attrs,
+            vis: self.vis,
+            defaultness: None,
+            sig: self.sig,
+            block: syn::parse_quote!({}),
+        }
+    }
+}
+
+struct RpcInfo {
+    kind: RpcKind,
+    reliable: bool,
+    with_validation: bool,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum RpcKind {
+    Server,
+    Client,
+    NetMulticast,
 }
 
 // ---------------------------------------------------------------------------
@@ -47,34 +109,74 @@ pub fn expand_uclass_impl(_attr: TokenStream, item: TokenStream) -> syn::Result<
     let rust_data_name = format_ident!("__{}RustData", struct_name);
     let class_handle_name = format_ident!("__UIKA_CLASS_HANDLE_{}", to_screaming_snake(&struct_name_str));
 
-    // Classify methods: collect #[ufunction] info, strip attrs
+    // Classify methods: collect #[ufunction] info, strip attrs. Events are
+    // rewritten: the declared method becomes a ProcessEvent caller (so
+    // Blueprint overrides run), and a native event's body moves to
+    // `<method>_implementation`.
     let mut ufunctions: Vec<UFunctionInfo> = Vec::new();
     let mut clean_impl = input.clone();
+    clean_impl.items.clear();
 
     for item in &input.items {
-        if let ImplItem::Fn(method) = item {
-            let has_ufunction = method.attrs.iter().any(|a| a.path().is_ident("ufunction"));
-            if has_ufunction {
-                ufunctions.push(parse_ufunction(method)?);
+        let method = match item {
+            ImplItem::Fn(method) => Some((method.clone(), true)),
+            ImplItem::Verbatim(tokens) => parse2::<BodylessFn>(tokens.clone())
+                .ok()
+                .filter(|f| f.attrs.iter().any(|a| a.path().is_ident("ufunction")))
+                .map(|f| (f.into_impl_fn(), false)),
+            _ => None,
+        };
+        let Some((mut method, has_body)) = method else {
+            clean_impl.items.push(item.clone());
+            continue;
+        };
+
+        if !method.attrs.iter().any(|a| a.path().is_ident("ufunction")) {
+            clean_impl.items.push(ImplItem::Fn(method));
+            continue;
+        }
+        let uf = parse_ufunction(&method)?;
+        method.attrs.retain(|a| !a.path().is_ident("ufunction"));
+        match (uf.event, has_body) {
+            (Some(EventKind::Implementable), true) => {
+                return Err(syn::Error::new_spanned(
+                    &method.sig,
+                    "BlueprintImplementableEvent must be declared without a body: `fn name(&self, ...);`",
+                ));
+            }
+            (Some(EventKind::Implementable), false) => {
+                clean_impl.items.push(ImplItem::Fn(event_caller(&method, &uf)));
             }
+            (Some(EventKind::Native), _) => {
+                let mut implementation = method.clone();
+                implementation.sig.ident = uf.impl_ident.clone();
+                clean_impl.items.push(ImplItem::Fn(implementation));
+                clean_impl.items.push(ImplItem::Fn(event_caller(&method, &uf)));
+            }
+            (None, false) => {
+                return Err(syn::Error::new_spanned(&method.sig, "ufunction requires a body"));
+            }
+            (None, true) => clean_impl.items.push(ImplItem::Fn(method)),
         }
-    }
 
-    // Strip #[ufunction] attrs from the emitted impl block
-    for item in &mut clean_impl.items {
-        if let ImplItem::Fn(method) = item {
-            method.attrs.retain(|a| !a.path().is_ident("ufunction"));
+        // The validate shim is registered first so the RPC can link to it.
+        if uf.rpc.as_ref().is_some_and(|r| r.with_validation) {
+            ufunctions.push(validate_function_info(&uf));
         }
+        ufunctions.push(uf);
     }
 
     // Generate register_functions body
-    let register_fns_name = format_ident!(
-        "__uika_register_{}_functions",
-        to_snake_case(&struct_name_str),
-    );
-
     let mut register_stmts: Vec<TokenStream> = Vec::new();
 
+    // `impl ActorLifecycle for X`: forward AActor's BeginPlay/EndPlay events.
+    let is_actor_lifecycle = input.trait_.as_ref().is_some_and(|(_, path, _)| {
+        path.segments.last().is_some_and(|seg| seg.ident == "ActorLifecycle")
+    });
+    if is_actor_lifecycle {
+        register_stmts.extend(actor_lifecycle_stmts(&struct_name, &rust_data_name));
+    }
+
     for uf in &ufunctions {
         let ue_name = &uf.ue_name;
         let ue_name_bytes = ue_name.as_bytes();
@@ -83,11 +185,50 @@ pub fn expand_uclass_impl(_attr: TokenStream, item: TokenStream) -> syn::Result<
 
         let flags_expr = if uf.is_override {
             quote! {
-                ::uika::ffi::FUNC_NATIVE | ::uika::ffi::FUNC_BLUEPRINT_EVENT | ::uika::ffi::FUNC_PUBLIC
+                ::uika::ue_flags::FunctionFlags::NATIVE
+                    | ::uika::ue_flags::FunctionFlags::BLUEPRINT_EVENT
+                    | ::uika::ue_flags::FunctionFlags::PUBLIC
+            }
+        } else if uf.is_validate {
+            quote! { ::uika::ue_flags::FunctionFlags::NATIVE | ::uika::ue_flags::FunctionFlags::PUBLIC }
+        } else if let Some(event) = uf.event {
+            // Implementable events have no native body: UE skips them in
+            // ProcessEvent unless a Blueprint subclass overrides them.
+            let native_flag = if event == EventKind::Native {
+                quote! { ::uika::ue_flags::FunctionFlags::NATIVE | }
+            } else {
+                quote! {}
+            };
+            quote! {
+                #native_flag ::uika::ue_flags::FunctionFlags::EVENT
+                    | ::uika::ue_flags::FunctionFlags::BLUEPRINT_EVENT
+                    | ::uika::ue_flags::FunctionFlags::BLUEPRINT_CALLABLE
+                    | ::uika::ue_flags::FunctionFlags::PUBLIC
+            }
+        } else if let Some(ref rpc) = uf.rpc {
+            let kind_flag = match rpc.kind {
+                RpcKind::Server => quote! { ::uika::ue_flags::FunctionFlags::NET_SERVER },
+                RpcKind::Client => quote! { ::uika::ue_flags::FunctionFlags::NET_CLIENT },
+                RpcKind::NetMulticast => quote! { ::uika::ue_flags::FunctionFlags::NET_MULTICAST },
+            };
+            let reliable_flag = if rpc.reliable {
+                quote! { | ::uika::ue_flags::FunctionFlags::NET_RELIABLE }
+            } else {
+                quote! {}
+            };
+            quote! {
+                ::uika::ue_flags::FunctionFlags::NATIVE
+                    | ::uika::ue_flags::FunctionFlags::EVENT
+                    | ::uika::ue_flags::FunctionFlags::NET
+                    | #kind_flag #reliable_flag
+                    | ::uika::ue_flags::FunctionFlags::BLUEPRINT_CALLABLE
+                    | ::uika::ue_flags::FunctionFlags::PUBLIC
             }
         } else {
             quote! {
-                ::uika::ffi::FUNC_NATIVE | ::uika::ffi::FUNC_BLUEPRINT_CALLABLE | ::uika::ffi::FUNC_PUBLIC
+                ::uika::ue_flags::FunctionFlags::NATIVE
+                    | ::uika::ue_flags::FunctionFlags::BLUEPRINT_CALLABLE
+                    | ::uika::ue_flags::FunctionFlags::PUBLIC
             }
         };
 
@@ -191,38 +332,47 @@ pub fn expand_uclass_impl(_attr: TokenStream, item: TokenStream) -> syn::Result<
         };
 
         // Method call expression
+        let impl_ident = &uf.impl_ident;
         let call_expr = if uf.return_type.is_some() {
-            quote! { let __ret = __this.#method_ident(#(#param_idents),*); }
+            quote! { let __ret = __this.#impl_ident(#(#param_idents),*); }
         } else {
-            quote! { __this.#method_ident(#(#param_idents),*); }
+            quote! { __this.#impl_ident(#(#param_idents),*); }
         };
 
         // Register callback and add function + params
         let func_var = format_ident!("__func_{}", method_ident);
 
+        let callback_id_expr = if uf.event == Some(EventKind::Implementable) {
+            quote! { let __callback_id = 0u64; }
+        } else {
+            quote! {
+                let __callback_id = {
+                    let callback_id = ::uika::runtime::reify_registry::register_function(
+                        move |obj: ::uika::ffi::UObjectHandle, rust_data: *mut u8, params: ::uika::runtime::ffi_dispatch::NativePtr| {
+                            static OFFSETS: std::sync::OnceLock<[u32; #total_offsets]> = std::sync::OnceLock::new();
+                            let offsets = OFFSETS.get_or_init(|| unsafe {
+                                let cls = <#struct_name as ::uika::runtime::UeClass>::static_class();
+                                let func = ::uika::runtime::ffi_dispatch::reflection_find_function_by_class(
+                                    cls,
+                                    [#(#ue_name_bytes),*].as_ptr(),
+                                    #ue_name_len,
+                                );
+                                [#(#offset_inits),*]
+                            });
+                            #(#param_reads)*
+                            #return_zero_init
+                            #this_binding
+                            #call_expr
+                            #return_write
+                        }
+                    );
+                    callback_id
+                };
+            }
+        };
+
         register_stmts.push(quote! {
-            let __callback_id = {
-                let callback_id = ::uika::runtime::reify_registry::register_function(
-                    move |obj: ::uika::ffi::UObjectHandle, rust_data: *mut u8, params: ::uika::runtime::ffi_dispatch::NativePtr| {
-                        static OFFSETS: std::sync::OnceLock<[u32; #total_offsets]> = std::sync::OnceLock::new();
-                        let offsets = OFFSETS.get_or_init(|| unsafe {
-                            let cls = <#struct_name as ::uika::runtime::UeClass>::static_class();
-                            let func = ::uika::runtime::ffi_dispatch::reflection_find_function_by_class(
-                                cls,
-                                [#(#ue_name_bytes),*].as_ptr(),
-                                #ue_name_len,
-                            );
-                            [#(#offset_inits),*]
-                        });
-                        #(#param_reads)*
-                        #return_zero_init
-                        #this_binding
-                        #call_expr
-                        #return_write
-                    }
-                );
-                callback_id
-            };
+            #callback_id_expr
 
             let #func_var = unsafe {
                 ::uika::runtime::ffi_dispatch::reify_add_function(
@@ -230,18 +380,52 @@ pub fn expand_uclass_impl(_attr: TokenStream, item: TokenStream) -> syn::Result<
                     [#(#ue_name_bytes),*].as_ptr(),
                     #ue_name_len,
                     __callback_id,
-                    #flags_expr,
+                    (#flags_expr).bits(),
                 )
             };
         });
 
+        // Link a WithValidation RPC to its (already registered) validate shim.
+        if uf.rpc.as_ref().is_some_and(|r| r.with_validation) {
+            let validate_var = format_ident!("__func_{}_validate", method_ident);
+            register_stmts.push(quote! {
+                unsafe {
+                    ::uika::runtime::ffi_dispatch::reify_set_function_validate(#func_var, #validate_var);
+                }
+            });
+        }
+
         // Add function params — skip for Override (C++ copies from parent function)
         if !uf.is_override {
             for param in &uf.params {
-                let info = prop_type::map_type(&param.rust_ty).unwrap();
                 let param_ue_name = &param.ue_name;
                 let param_ue_bytes = param_ue_name.as_bytes();
                 let param_ue_len = param_ue_name.len() as u32;
+
+                // UStructRef<T>: a struct param typed by T's UScriptStruct.
+                if let Some(struct_ty) = ustruct_ref_inner(&param.rust_ty) {
+                    register_stmts.push(quote! {
+                        {
+                            let extra = ::uika::ffi::UikaReifyPropExtra {
+                                struct_handle: <#struct_ty as ::uika::runtime::UeStruct>::static_struct(),
+                                ..Default::default()
+                            };
+                            unsafe {
+                                ::uika::runtime::ffi_dispatch::reify_add_function_param(
+                                    #func_var,
+                                    [#(#param_ue_bytes),*].as_ptr(),
+                                    #param_ue_len,
+                                    ::uika::ffi::UikaReifyPropType::Struct as u32,
+                                    ::uika::ue_flags::PropertyFlags::PARM.bits(),
+                                    &extra,
+                                );
+                            }
+                        }
+                    });
+                    continue;
+                }
+
+                let info = prop_type::map_type(&param.rust_ty).unwrap();
                 let prop_type_expr = &info.prop_type_expr;
 
                 register_stmts.push(quote! {
@@ -251,7 +435,7 @@ pub fn expand_uclass_impl(_attr: TokenStream, item: TokenStream) -> syn::Result<
                             [#(#param_ue_bytes),*].as_ptr(),
                             #param_ue_len,
                             #prop_type_expr as u32,
-                            ::uika::ffi::CPF_PARM,
+                            ::uika::ue_flags::PropertyFlags::PARM.bits(),
                             std::ptr::null(),
                         );
                     }
@@ -270,7 +454,10 @@ pub fn expand_uclass_impl(_attr: TokenStream, item: TokenStream) -> syn::Result<
                             b"ReturnValue".as_ptr(),
                             11u32,
                             #prop_type_expr as u32,
-                            ::uika::ffi::CPF_PARM | ::uika::ffi::CPF_OUT_PARM | ::uika::ffi::CPF_RETURN_PARM,
+                            (::uika::ue_flags::PropertyFlags::PARM
+                                | ::uika::ue_flags::PropertyFlags::OUT_PARM
+                                | ::uika::ue_flags::PropertyFlags::RETURN_PARM)
+                                .bits(),
                             std::ptr::null(),
                         );
                     }
@@ -279,29 +466,93 @@ pub fn expand_uclass_impl(_attr: TokenStream, item: TokenStream) -> syn::Result<
         }
     }
 
-    let register_functions_fn = quote! {
-        #[doc(hidden)]
-        pub fn #register_fns_name() {
-            let cls = match #class_handle_name.get() {
-                Some(&c) if !c.is_null() => c,
-                _ => return,
-            };
-            #(#register_stmts)*
-        }
-    };
-
+    // Scoped in an anonymous const so a type can have several
+    // #[uclass_impl] blocks (e.g. `impl X` plus `impl ActorLifecycle for X`).
     Ok(quote! {
         #clean_impl
-        #register_functions_fn
 
-        ::uika::__inventory::submit! {
-            ::uika::runtime::reify_registry::ClassFunctionRegistration {
-                register_functions: #register_fns_name,
+        const _: () = {
+            fn __uika_register_functions() {
+                let cls = match #class_handle_name.get() {
+                    Some(&c) if !c.is_null() => c,
+                    _ => return,
+                };
+                #(#register_stmts)*
             }
-        }
+
+            ::uika::__inventory::submit! {
+                ::uika::runtime::reify_registry::ClassFunctionRegistration {
+                    register_functions: __uika_register_functions,
+                }
+            }
+        };
     })
 }
 
+/// Register `ReceiveBeginPlay` / `ReceiveEndPlay` overrides that call the
+/// type's `ActorLifecycle` methods. Params are copied from AActor's events.
+fn actor_lifecycle_stmts(struct_name: &Ident, rust_data_name: &Ident) -> Vec<TokenStream> {
+    let this_binding = quote! {
+        let mut __this = #struct_name {
+            __obj: obj,
+            __rust_data: rust_data as *mut #rust_data_name,
+        };
+    };
+    let flags = quote! {
+        ::uika::ue_flags::FunctionFlags::NATIVE
+            | ::uika::ue_flags::FunctionFlags::BLUEPRINT_EVENT
+            | ::uika::ue_flags::FunctionFlags::PUBLIC
+    };
+
+    let begin_play = quote! {
+        {
+            let __callback_id = ::uika::runtime::reify_registry::register_function(
+                move |obj: ::uika::ffi::UObjectHandle, rust_data: *mut u8, _params: ::uika::runtime::ffi_dispatch::NativePtr| {
+                    #this_binding
+                    <#struct_name as ::uika::runtime::ActorLifecycle>::begin_play(&mut __this);
+                }
+            );
+            unsafe {
+                ::uika::runtime::ffi_dispatch::reify_add_function(
+                    cls, b"ReceiveBeginPlay".as_ptr(), 16u32, __callback_id, (#flags).bits(),
+                );
+            }
+        }
+    };
+
+    let end_play = quote! {
+        {
+            let __callback_id = ::uika::runtime::reify_registry::register_function(
+                move |obj: ::uika::ffi::UObjectHandle, rust_data: *mut u8, params: ::uika::runtime::ffi_dispatch::NativePtr| {
+                    static OFFSET: std::sync::OnceLock<u32> = std::sync::OnceLock::new();
+                    let offset = *OFFSET.get_or_init(|| unsafe {
+                        let cls = <#struct_name as ::uika::runtime::UeClass>::static_class();
+                        let func = ::uika::runtime::ffi_dispatch::reflection_find_function_by_class(
+                            cls, b"ReceiveEndPlay".as_ptr(), 14u32,
+                        );
+                        let p = ::uika::runtime::ffi_dispatch::reflection_get_function_param(
+                            func, b"EndPlayReason".as_ptr(), 13u32,
+                        );
+                        ::uika::runtime::ffi_dispatch::reflection_get_property_offset(p)
+                    });
+                    let reason = ::uika::runtime::EndPlayReason::from_raw(unsafe {
+                        ::uika::runtime::ffi_dispatch::native_mem_read::<u8>(params, offset as usize)
+                    });
+                    #this_binding
+                    <#struct_name as ::uika::runtime::ActorLifecycle>::end_play(&mut __this, reason);
+                }
+            );
+            unsafe {
+                ::uika::runtime::ffi_dispatch::reify_add_function(
+                    cls, b"ReceiveEndPlay".as_ptr(), 14u32, __callback_id, (#flags).bits(),
+                );
+            }
+        }
+    };
+
+    vec![begin_play, end_play]
+}
+
 // ---------------------------------------------------------------------------
 // Parsing helpers
 // ---------------------------------------------------------------------------
@@ -313,8 +564,34 @@ fn parse_ufunction(method: &ImplItemFn) -> syn::Result<UFunctionInfo> {
         Vec::new()
     };
     let is_override = specifiers.iter().any(|s| s == "Override");
+    let rpc = parse_rpc_specifiers(method, &specifiers, is_override)?;
+    let event = match (
+        specifiers.iter().any(|s| s == "BlueprintImplementableEvent"),
+        specifiers.iter().any(|s| s == "BlueprintNativeEvent"),
+    ) {
+        (false, false) => None,
+        (true, false) => Some(EventKind::Implementable),
+        (false, true) => Some(EventKind::Native),
+        (true, true) => {
+            return Err(syn::Error::new_spanned(
+                &method.sig.ident,
+                "BlueprintImplementableEvent and BlueprintNativeEvent are mutually exclusive",
+            ));
+        }
+    };
+    if event.is_some() && (is_override || rpc.is_some()) {
+        return Err(syn::Error::new_spanned(
+            &method.sig.ident,
+            "Blueprint events cannot also be Override or RPC functions",
+        ));
+    }
 
     let method_ident = method.sig.ident.clone();
+    let impl_ident = if event == Some(EventKind::Native) {
+        format_ident!("{}_implementation", method_ident)
+    } else {
+        method_ident.clone()
+    };
     let ue_name = prop_type::to_pascal_case(&method_ident.to_string());
 
     // Check for self receiver and its mutability
@@ -339,10 +616,16 @@ fn parse_ufunction(method: &ImplItemFn) -> syn::Result<UFunctionInfo> {
                 // Override functions get their param types from the parent UFunction
                 // (C++ copies them), so any Copy+repr(C) type is valid.
                 // Non-override functions must use types known to reify_add_function_param.
-                if !is_override && prop_type::map_type(&ty).is_none() {
+                if !is_override && prop_type::map_type(&ty).is_none() && ustruct_ref_inner(&ty).is_none() {
+                    return Err(syn::Error::new_spanned(
+                        &ty,
+                        "unsupported ufunction parameter type: only bool/i32/i64/u8/f32/f64 and UStructRef<T> are supported",
+                    ));
+                }
+                if event.is_some() && is_ustruct_ref_type(&ty) {
                     return Err(syn::Error::new_spanned(
                         &ty,
-                        "unsupported ufunction parameter type: only bool/i32/i64/u8/f32/f64 are supported",
+                        "Blueprint event parameters cannot be UStructRef<T>",
                     ));
                 }
                 let ue_name = prop_type::to_pascal_case(&name.to_string());
@@ -368,6 +651,9 @@ fn parse_ufunction(method: &ImplItemFn) -> syn::Result<UFunctionInfo> {
                         "unsupported ufunction return type: only bool/i32/i64/u8/f32/f64 are supported",
                     ));
                 }
+                if rpc.is_some() {
+                    return Err(syn::Error::new_spanned(ty, "RPC ufunctions cannot return a value"));
+                }
                 Some(ParamInfo {
                     rust_name: Ident::new("ReturnValue", proc_macro2::Span::call_site()),
                     ue_name: "ReturnValue".to_string(),
@@ -379,14 +665,144 @@ fn parse_ufunction(method: &ImplItemFn) -> syn::Result<UFunctionInfo> {
 
     Ok(UFunctionInfo {
         method_ident,
+        impl_ident,
         ue_name,
         params,
         return_type,
         is_mut,
         is_override,
+        rpc,
+        is_validate: false,
+        event,
     })
 }
 
+/// Build the hidden `<Name>_Validate` function for a WithValidation RPC. It
+/// calls the user's `<method>_validate(&self, ...same params) -> bool`.
+fn validate_function_info(rpc: &UFunctionInfo) -> UFunctionInfo {
+    let method_ident = format_ident!("{}_validate", rpc.method_ident);
+    UFunctionInfo {
+        impl_ident: method_ident.clone(),
+        method_ident,
+        ue_name: format!("{}_Validate", rpc.ue_name),
+        params: rpc.params.clone(),
+        return_type: Some(ParamInfo {
+            rust_name: Ident::new("ReturnValue", proc_macro2::Span::call_site()),
+            ue_name: "ReturnValue".to_string(),
+            rust_ty: syn::parse_quote!(bool),
+        }),
+        is_mut: false,
+        is_override: false,
+        rpc: None,
+        is_validate: true,
+        event: None,
+    }
+}
+
+/// Body for an event's declared method: call the UFunction through
+/// ProcessEvent on the object's actual class, so a Blueprint override (if
+/// any) runs instead of the Rust default.
+fn event_caller(method: &ImplItemFn, uf: &UFunctionInfo) -> ImplItemFn {
+    let ue_name = &uf.ue_name;
+    let sets = uf.params.iter().map(|p| {
+        let rust_name = &p.rust_name;
+        let rust_ty = &p.rust_ty;
+        let param_ue_name = &p.ue_name;
+        quote! { __call.set::<#rust_ty>(#param_ue_name, #rust_name)?; }
+    });
+    // A failed call (destroyed object, missing UFunction) is logged under
+    // the event's name; the caller gets a zeroed return value.
+    let report = quote! {
+        ::uika::runtime::logging::log_category(
+            "LogUika",
+            ::uika::runtime::LOG_ERROR,
+            &format!("[Uika] event {} failed: {}", #ue_name, __err),
+        );
+    };
+    let body = match uf.return_type {
+        Some(ref ret) => {
+            let ret_ty = &ret.rust_ty;
+            quote! {{
+                let __result = (|| -> ::uika::runtime::UikaResult<#ret_ty> {
+                    let mut __call = ::uika::runtime::DynamicCall::new(&self.as_ref(), #ue_name)?;
+                    #(#sets)*
+                    __call.call()?.get::<#ret_ty>("ReturnValue")
+                })();
+                __result.unwrap_or_else(|__err| {
+                    #report
+                    unsafe { std::mem::zeroed::<#ret_ty>() }
+                })
+            }}
+        }
+        None => quote! {{
+            let __result = (|| -> ::uika::runtime::UikaResult<()> {
+                let mut __call = ::uika::runtime::DynamicCall::new(&self.as_ref(), #ue_name)?;
+                #(#sets)*
+                __call.call()?;
+                Ok(())
+            })();
+            if let Err(__err) = __result {
+                #report
+            }
+        }},
+    };
+    let mut caller = method.clone();
+    caller.block = syn::parse2(body).expect("event caller body");
+    caller
+}
+
+fn parse_rpc_specifiers(
+    method: &ImplItemFn,
+    specifiers: &[String],
+    is_override: bool,
+) -> syn::Result<Option<RpcInfo>> {
+    let has = |name: &str| specifiers.iter().any(|s| s == name);
+    let kinds: Vec<RpcKind> = [
+        ("Server", RpcKind::Server),
+        ("Client", RpcKind::Client),
+        ("NetMulticast", RpcKind::NetMulticast),
+    ]
+    .into_iter()
+    .filter(|(name, _)| has(name))
+    .map(|(_, kind)| kind)
+    .collect();
+    let reliable = has("Reliable");
+    let with_validation = has("WithValidation");
+
+    let kind = match kinds.as_slice() {
+        [] => {
+            if reliable || with_validation {
+                return Err(syn::Error::new_spanned(
+                    &method.sig.ident,
+                    "Reliable/WithValidation require one of Server, Client or NetMulticast",
+                ));
+            }
+            return Ok(None);
+        }
+        [kind] => *kind,
+        _ => {
+            return Err(syn::Error::new_spanned(
+                &method.sig.ident,
+                "only one of Server, Client or NetMulticast may be specified",
+            ));
+        }
+    };
+    if is_override {
+        return Err(syn::Error::new_spanned(
+            &method.sig.ident,
+            "Override functions cannot be RPCs",
+        ));
+    }
+    if has("Reliable") && has("Unreliable") {
+        return Err(syn::Error::new_spanned(
+            &method.sig.ident,
+            "Reliable and Unreliable are mutually exclusive",
+        ));
+    }
+
+    Ok(Some(RpcInfo { kind, reliable, with_validation }))
+}
+
 /// Check if a type is `UStructRef<T>` by examining the last path segment.
 fn is_ustruct_ref_type(ty: &Type) -> bool {
     if let Type::Path(tp) = ty {
@@ -397,6 +813,20 @@ fn is_ustruct_ref_type(ty: &Type) -> bool {
     false
 }
 
+/// The `T` of a `UStructRef<T>` type, if `ty` is one.
+fn ustruct_ref_inner(ty: &Type) -> Option<&Type> {
+    let Type::Path(tp) = ty else { return None };
+    let seg = tp.path.segments.last()?;
+    if seg.ident != "UStructRef" {
+        return None;
+    }
+    let syn::PathArguments::AngleBracketed(args) = &seg.arguments else { return None };
+    args.args.iter().find_map(|a| match a {
+        syn::GenericArgument::Type(t) => Some(t),
+        _ => None,
+    })
+}
+
 fn parse_ufunction_specifiers(attr: &syn::Attribute) -> syn::Result<Vec<String>> {
     let mut specifiers = Vec::new();
     if let Ok(nested) = attr.parse_args_with(
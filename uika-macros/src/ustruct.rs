@@ -0,0 +1,197 @@
+// #[ustruct] macro expansion: reifies a plain Rust struct with #[uproperty]
+// fields as a UScriptStruct. The Rust struct keeps its own layout; values are
+// copied to and from UE struct memory by property offset.
+
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{parse2, Fields, ItemStruct};
+
+use crate::prop_type;
+use crate::uclass::{parse_uproperty_args, property_flags_expr, to_screaming_snake, to_snake_case};
+
+pub fn expand_ustruct(_attr: TokenStream, item: TokenStream) -> syn::Result<TokenStream> {
+    let mut input: ItemStruct = parse2(item)?;
+
+    let struct_name = input.ident.clone();
+    let struct_name_str = struct_name.to_string();
+
+    let fields = match &mut input.fields {
+        Fields::Named(f) => &mut f.named,
+        _ => {
+            return Err(syn::Error::new_spanned(
+                &input,
+                "#[ustruct] requires a struct with named fields.\n\n\
+                 Example:\n\
+                 \x20   #[ustruct]\n\
+                 \x20   pub struct DamageInfo {\n\
+                 \x20       #[uproperty(BlueprintReadWrite)]\n\
+                 \x20       amount: f32,\n\
+                 \x20   }",
+            ));
+        }
+    };
+
+    // Every field lives in UE memory, so every field must be a #[uproperty].
+    let mut add_prop_stmts: Vec<TokenStream> = Vec::new();
+    let mut offset_inits: Vec<TokenStream> = Vec::new();
+    let mut field_reads: Vec<TokenStream> = Vec::new();
+    let mut field_writes: Vec<TokenStream> = Vec::new();
+
+    for (i, field) in fields.iter_mut().enumerate() {
+        let field_ident = field.ident.clone().unwrap();
+        let field_ty = field.ty.clone();
+
+        let attr = field
+            .attrs
+            .iter()
+            .find(|a| a.path().is_ident("uproperty"))
+            .ok_or_else(|| {
+                syn::Error::new_spanned(&field_ident, "#[ustruct] fields must all be #[uproperty]")
+            })?;
+        let pargs = parse_uproperty_args(attr)?;
+        if let Some(ref default_expr) = pargs.default_expr {
+            return Err(syn::Error::new_spanned(
+                default_expr,
+                "#[ustruct] fields do not support `default`; implement Default for the struct instead",
+            ));
+        }
+        let info = prop_type::map_type(&field_ty).ok_or_else(|| {
+            syn::Error::new_spanned(
+                &field_ty,
+                "unsupported uproperty type: only bool/i32/i64/u8/f32/f64 are supported",
+            )
+        })?;
+        field.attrs.retain(|a| !a.path().is_ident("uproperty"));
+
+        let ue_name = prop_type::to_pascal_case(&field_ident.to_string());
+        let ue_name_bytes = ue_name.as_bytes();
+        let ue_name_len = ue_name.len() as u32;
+        let prop_type_expr = &info.prop_type_expr;
+        let flags_expr = property_flags_expr(&pargs);
+        let idx = syn::Index::from(i);
+
+        add_prop_stmts.push(quote! {
+            unsafe {
+                ::uika::runtime::ffi_dispatch::reify_add_struct_property(
+                    ustruct,
+                    [#(#ue_name_bytes),*].as_ptr(),
+                    #ue_name_len,
+                    #prop_type_expr as u32,
+                    (#flags_expr).bits(),
+                    std::ptr::null(),
+                );
+            }
+        });
+        offset_inits.push(quote! {
+            {
+                let p = ::uika::runtime::ffi_dispatch::reflection_find_struct_property(
+                    ustruct,
+                    [#(#ue_name_bytes),*].as_ptr(),
+                    #ue_name_len,
+                );
+                ::uika::runtime::ffi_dispatch::reflection_get_property_offset(p)
+            }
+        });
+        field_reads.push(quote! {
+            #field_ident: unsafe {
+                ::uika::runtime::ffi_dispatch::native_mem_read::<#field_ty>(ptr, offsets[#idx] as usize)
+            },
+        });
+        field_writes.push(quote! {
+            unsafe {
+                ::uika::runtime::ffi_dispatch::native_mem_write(ptr, offsets[#idx] as usize, self.#field_ident);
+            }
+        });
+    }
+
+    let field_count = offset_inits.len();
+    let struct_handle_name = format_ident!("__UIKA_STRUCT_HANDLE_{}", to_screaming_snake(&struct_name_str));
+    let register_fn_name = format_ident!("__uika_register_struct_{}", to_snake_case(&struct_name_str));
+    let struct_name_bytes = struct_name_str.as_bytes();
+    let struct_name_len = struct_name_str.len() as u32;
+
+    Ok(quote! {
+        #input
+
+        #[doc(hidden)]
+        pub static #struct_handle_name: std::sync::OnceLock<::uika::ffi::UStructHandle> = std::sync::OnceLock::new();
+
+        impl ::uika::runtime::UeStruct for #struct_name {
+            fn static_struct() -> ::uika::ffi::UStructHandle {
+                *#struct_handle_name.get().expect(concat!("Failed to find UScriptStruct for ", stringify!(#struct_name), " — was it registered?"))
+            }
+        }
+
+        impl #struct_name {
+            fn __uika_offsets() -> &'static [u32; #field_count] {
+                static OFFSETS: std::sync::OnceLock<[u32; #field_count]> = std::sync::OnceLock::new();
+                OFFSETS.get_or_init(|| unsafe {
+                    let ustruct = <#struct_name as ::uika::runtime::UeStruct>::static_struct();
+                    [#(#offset_inits),*]
+                })
+            }
+
+            /// Copy a value out of UE struct memory.
+            ///
+            /// # Safety
+            /// `ptr` must point to a live instance of this struct's UScriptStruct.
+            pub unsafe fn read_from(ptr: ::uika::runtime::ffi_dispatch::NativePtr) -> Self {
+                let offsets = Self::__uika_offsets();
+                Self {
+                    #(#field_reads)*
+                }
+            }
+
+            /// Copy this value into UE struct memory.
+            ///
+            /// # Safety
+            /// `ptr` must point to a live, writable instance of this struct's UScriptStruct.
+            pub unsafe fn write_to(&self, ptr: ::uika::runtime::ffi_dispatch::NativePtr) {
+                let offsets = Self::__uika_offsets();
+                #(#field_writes)*
+            }
+
+            /// Copy a value out of a struct reference (e.g. a ufunction param).
+            pub fn from_struct_ref(r: &::uika::runtime::UStructRef<Self>) -> Self {
+                unsafe { Self::read_from(r.as_ptr().0 as ::uika::runtime::ffi_dispatch::NativePtr) }
+            }
+
+            /// Copy this value into the struct a reference points to.
+            pub fn write_struct_ref(&self, r: &::uika::runtime::UStructRef<Self>) {
+                unsafe { self.write_to(r.as_ptr().0 as ::uika::runtime::ffi_dispatch::NativePtr) }
+            }
+        }
+
+        #[doc(hidden)]
+        pub fn #register_fn_name() {
+            let ustruct = unsafe {
+                ::uika::runtime::ffi_dispatch::reify_create_struct(
+                    [#(#struct_name_bytes),*].as_ptr(),
+                    #struct_name_len,
+                )
+            };
+            if ustruct.is_null() {
+                let msg = concat!("[Uika] ", stringify!(#struct_name), ": create_struct failed");
+                let bytes = msg.as_bytes();
+                unsafe { ::uika::runtime::ffi_dispatch::logging_log(2, bytes.as_ptr(), bytes.len() as u32); }
+                return;
+            }
+            #struct_handle_name.set(ustruct).ok();
+
+            #(#add_prop_stmts)*
+
+            let result = unsafe { ::uika::runtime::ffi_dispatch::reify_finalize_struct(ustruct) };
+            if result != ::uika::ffi::UikaErrorCode::Ok {
+                let msg = concat!("[Uika] ", stringify!(#struct_name), ": finalize_struct failed");
+                let bytes = msg.as_bytes();
+                unsafe { ::uika::runtime::ffi_dispatch::logging_log(2, bytes.as_ptr(), bytes.len() as u32); }
+            }
+        }
+
+        ::uika::__inventory::submit! {
+            ::uika::runtime::reify_registry::StructRegistration {
+                register: #register_fn_name,
+            }
+        }
+    })
+}
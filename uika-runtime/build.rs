@@ -3,6 +3,10 @@
 //! For each sub-table function, emits a thin wrapper that dispatches through
 //! the API table and centralizes the `unsafe` boundary, e.g.:
 //!   `pub unsafe fn core_is_valid(p0: UObjectHandle) -> bool`.
+//!
+//! Also writes `api_manifest.json`, a machine-readable description of the
+//! same contract for tooling that shouldn't re-parse Rust source. Set
+//! `UIKA_API_MANIFEST_OUT` to copy it to a fixed path.
 
 use std::env;
 use std::fmt::Write as FmtWrite;
@@ -17,6 +21,9 @@ fn main() {
 
     let source = fs::read_to_string(api_table_path).expect("Failed to read api_table.rs");
     let tables = parse_api_table(&source);
+    let api_version = parse_api_version(&source);
+    // feature = "ffi-trace": time every dispatch wrapper (see ffi_trace.rs).
+    let trace = env::var_os("CARGO_FEATURE_FFI_TRACE").is_some();
 
     let mut output = String::new();
     writeln!(output, "// Auto-generated by uika-runtime/build.rs — do not edit").unwrap();
@@ -54,20 +61,207 @@ fn main() {
 
     for table in &tables {
         for func in &table.functions {
-            generate_dispatch_wrapper(&mut output, table, func);
+            generate_dispatch_wrapper(&mut output, table, func, trace);
         }
     }
 
+    writeln!(output, "/// Sub-tables the loaded plugin doesn't provide (it predates them).").unwrap();
+    writeln!(output, "pub fn missing_sub_tables() -> Vec<&'static str> {{").unwrap();
+    writeln!(output, "    let api = crate::api::api();").unwrap();
+    writeln!(output, "    let mut missing = Vec::new();").unwrap();
+    for table in &tables {
+        writeln!(
+            output,
+            "    if api.{0}.is_null() {{ missing.push(\"{0}\"); }}",
+            table.module_name
+        )
+        .unwrap();
+    }
+    writeln!(output, "    missing").unwrap();
+    writeln!(output, "}}").unwrap();
+
     let out_dir = env::var("OUT_DIR").unwrap();
     let out_path = Path::new(&out_dir).join("ffi_dispatch.rs");
     fs::write(&out_path, &output).expect("Failed to write ffi_dispatch.rs");
+
+    let manifest = generate_api_manifest(api_version, &tables);
+    fs::write(Path::new(&out_dir).join("api_manifest.json"), &manifest)
+        .expect("Failed to write api_manifest.json");
+    println!("cargo:rerun-if-env-changed=UIKA_API_MANIFEST_OUT");
+    if let Some(dest) = env::var_os("UIKA_API_MANIFEST_OUT") {
+        fs::write(&dest, &manifest).unwrap_or_else(|e| {
+            panic!("Failed to write {}: {e}", Path::new(&dest).display())
+        });
+    }
+
+    // feature = "test-harness": stub sub-tables for the mock engine.
+    if env::var_os("CARGO_FEATURE_TEST_HARNESS").is_some() {
+        let stubs = generate_mock_stubs(&tables);
+        fs::write(Path::new(&out_dir).join("mock_stubs.rs"), stubs)
+            .expect("Failed to write mock_stubs.rs");
+    }
+}
+
+// ---------------------------------------------------------------------------
+// API manifest
+// ---------------------------------------------------------------------------
+
+/// Emit the API contract as JSON:
+///
+/// ```json
+/// { "api_version": 3, "modules": [ { "module": "core", "table": "UikaCoreApi",
+///   "functions": [ { "name": "is_valid",
+///     "params": [ { "name": "obj", "type": "UObjectHandle", "class": "handle" } ],
+///     "returns": { "type": "bool", "class": "scalar" } } ] } ] }
+/// ```
+///
+/// Modules and functions keep their declaration order, which is also the
+/// `#[repr(C)]` field order. `returns` is `null` for functions without a
+/// return value. Types are spelled as in api_table.rs. All names are Rust
+/// identifiers, so nothing needs escaping.
+fn generate_api_manifest(api_version: u32, tables: &[SubTable]) -> String {
+    let mut out = String::new();
+    writeln!(out, "{{").unwrap();
+    writeln!(out, "  \"api_version\": {api_version},").unwrap();
+    writeln!(out, "  \"source\": \"uika-ffi/src/api_table.rs\",").unwrap();
+    writeln!(out, "  \"modules\": [").unwrap();
+    for (t, table) in tables.iter().enumerate() {
+        writeln!(out, "    {{").unwrap();
+        writeln!(out, "      \"module\": \"{}\",", table.module_name).unwrap();
+        writeln!(out, "      \"table\": \"{}\",", table.struct_name).unwrap();
+        writeln!(out, "      \"functions\": [").unwrap();
+        for (f, func) in table.functions.iter().enumerate() {
+            let params: Vec<String> = func
+                .params
+                .iter()
+                .map(|p| format!("{{ \"name\": \"{}\", {} }}", p.name, manifest_type(&p.ty)))
+                .collect();
+            let returns = match &func.return_type {
+                None => "null".to_string(),
+                Some(ty) => format!("{{ {} }}", manifest_type(ty)),
+            };
+            writeln!(out, "        {{").unwrap();
+            writeln!(out, "          \"name\": \"{}\",", func.name).unwrap();
+            writeln!(out, "          \"params\": [{}],", params.join(", ")).unwrap();
+            writeln!(out, "          \"returns\": {returns}").unwrap();
+            let sep = if f + 1 < table.functions.len() { "," } else { "" };
+            writeln!(out, "        }}{sep}").unwrap();
+        }
+        writeln!(out, "      ]").unwrap();
+        let sep = if t + 1 < tables.len() { "," } else { "" };
+        writeln!(out, "    }}{sep}").unwrap();
+    }
+    writeln!(out, "  ]").unwrap();
+    writeln!(out, "}}").unwrap();
+    out
+}
+
+/// `"type": ..., "class": ...` for one parameter or return type.
+fn manifest_type(ty: &ApiType) -> String {
+    format!("\"type\": \"{}\", \"class\": \"{}\"", ffi_type_str(ty), type_class(ty))
+}
+
+/// Coarse marshalling class of a type, so consumers can treat whole groups
+/// alike without knowing every handle or scalar name.
+fn type_class(ty: &ApiType) -> &'static str {
+    match ty {
+        ApiType::UikaErrorCode => "error_code",
+        ApiType::FWeakObjectHandle => "weak_handle",
+        ApiType::CVoidPtr { .. } => "opaque_ptr",
+        ApiType::NamedStructPtr { .. } => "struct_ptr",
+        ApiType::Ptr { mutability: Mutability::Const, .. } => "in_ptr",
+        ApiType::Ptr { mutability: Mutability::Mut, .. } => "out_ptr",
+        _ if is_handle_type(ty) => "handle",
+        _ => "scalar",
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Mock stub generation (test-harness)
+// ---------------------------------------------------------------------------
+
+/// For each sub-table, emit `pub(crate) fn <module>_api() -> Uika<X>Api`
+/// filling every entry with a stub that returns a neutral value (null
+/// handle, zero, false, or `UikaErrorCode::InvalidOperation`). The test
+/// harness overrides the entries it models.
+fn generate_mock_stubs(tables: &[SubTable]) -> String {
+    let mut out = String::new();
+    writeln!(out, "// Auto-generated by uika-runtime/build.rs — do not edit").unwrap();
+    writeln!(out, "// Source: uika-ffi/src/api_table.rs").unwrap();
+    writeln!(out).unwrap();
+    writeln!(out, "use uika_ffi::*;").unwrap();
+    writeln!(out).unwrap();
+
+    for table in tables {
+        writeln!(out, "pub(crate) fn {}_api() -> {} {{", table.module_name, table.struct_name).unwrap();
+        writeln!(out, "    {} {{", table.struct_name).unwrap();
+        for func in &table.functions {
+            let params: Vec<String> = func
+                .params
+                .iter()
+                .map(|p| format!("_: {}", ffi_type_str(&p.ty)))
+                .collect();
+            let (ret, value) = match &func.return_type {
+                None => (String::new(), String::new()),
+                Some(ty) => (format!(" -> {}", ffi_type_str(ty)), stub_return_value(ty)),
+            };
+            writeln!(
+                out,
+                "        {}: {{ unsafe extern \"C\" fn stub({}){ret} {{ {value} }} stub }},",
+                func.name,
+                params.join(", ")
+            )
+            .unwrap();
+        }
+        writeln!(out, "    }}").unwrap();
+        writeln!(out, "}}").unwrap();
+        writeln!(out).unwrap();
+    }
+    out
+}
+
+/// The type exactly as declared in api_table.rs (unlike `rust_type_str`,
+/// which maps opaque payload pointers to `NativePtr`).
+fn ffi_type_str(ty: &ApiType) -> String {
+    match ty {
+        ApiType::CVoidPtr { mutability } => format!("{} core::ffi::c_void", mut_qual(*mutability)),
+        ApiType::NamedStructPtr { mutability, name } => format!("{} {name}", mut_qual(*mutability)),
+        ApiType::Ptr { mutability, pointee } => {
+            format!("{} {}", mut_qual(*mutability), ffi_type_str(pointee))
+        }
+        _ => rust_type_str(ty),
+    }
+}
+
+fn stub_return_value(ty: &ApiType) -> String {
+    match ty {
+        ApiType::UObjectHandle
+        | ApiType::UClassHandle
+        | ApiType::FPropertyHandle
+        | ApiType::UFunctionHandle
+        | ApiType::UStructHandle
+        | ApiType::FTextHandle => format!("{}::null()", rust_type_str(ty)),
+        ApiType::FNameHandle | ApiType::FWeakObjectHandle => format!("{}::default()", rust_type_str(ty)),
+        ApiType::UikaErrorCode => "UikaErrorCode::InvalidOperation".into(),
+        ApiType::Bool => "false".into(),
+        ApiType::F32 | ApiType::F64 => "0.0".into(),
+        ApiType::U8 | ApiType::U16 | ApiType::U32 | ApiType::U64 | ApiType::I32 | ApiType::I64 => {
+            "0".into()
+        }
+        ApiType::Ptr { mutability: Mutability::Mut, .. }
+        | ApiType::NamedStructPtr { mutability: Mutability::Mut, .. }
+        | ApiType::CVoidPtr { mutability: Mutability::Mut } => "core::ptr::null_mut()".into(),
+        ApiType::Ptr { .. } | ApiType::NamedStructPtr { .. } | ApiType::CVoidPtr { .. } => {
+            "core::ptr::null()".into()
+        }
+    }
 }
 
 // ---------------------------------------------------------------------------
 // Dispatch wrapper generation
 // ---------------------------------------------------------------------------
 
-fn generate_dispatch_wrapper(out: &mut String, table: &SubTable, func: &ApiFn) {
+fn generate_dispatch_wrapper(out: &mut String, table: &SubTable, func: &ApiFn, trace: bool) {
     let fn_name = format!("{}_{}", table.module_name, func.name);
 
     let rust_params: Vec<(String, String)> = func
@@ -91,7 +285,30 @@ fn generate_dispatch_wrapper(out: &mut String, table: &SubTable, func: &ApiFn) {
     }
     writeln!(out, ") -> {rust_ret} {{").unwrap();
 
-    write!(out, "    ((*crate::api::api().{}).{})(", table.module_name, func.name).unwrap();
+    if trace {
+        writeln!(
+            out,
+            "    let _trace = crate::ffi_trace::enter(\"{}\", \"{}\");",
+            table.module_name, func.name
+        )
+        .unwrap();
+    }
+    // A plugin older than this DLL may lack the sub-table: fallible calls
+    // report InvalidOperation, the rest panic with a clear message.
+    writeln!(out, "    let table = crate::api::api().{};", table.module_name).unwrap();
+    writeln!(out, "    if table.is_null() {{").unwrap();
+    if func.return_type == Some(ApiType::UikaErrorCode) {
+        writeln!(out, "        return UikaErrorCode::InvalidOperation;").unwrap();
+    } else {
+        writeln!(
+            out,
+            "        crate::api::sub_table_unavailable(\"{}\", \"{}\");",
+            table.module_name, func.name
+        )
+        .unwrap();
+    }
+    writeln!(out, "    }}").unwrap();
+    write!(out, "    ((*table).{})(", func.name).unwrap();
     for (i, (name, _ty)) in rust_params.iter().enumerate() {
         if i > 0 {
             write!(out, ", ").unwrap();
@@ -117,6 +334,7 @@ fn rust_type_str(ty: &ApiType) -> String {
         ApiType::UFunctionHandle => "UFunctionHandle".into(),
         ApiType::UStructHandle => "UStructHandle".into(),
         ApiType::FNameHandle => "FNameHandle".into(),
+        ApiType::FTextHandle => "FTextHandle".into(),
         ApiType::FWeakObjectHandle => "FWeakObjectHandle".into(),
         ApiType::UikaErrorCode => "UikaErrorCode".into(),
         ApiType::Bool => "bool".into(),
@@ -133,7 +351,7 @@ fn rust_type_str(ty: &ApiType) -> String {
         ApiType::CVoidPtr { mutability: Mutability::Mut } => "NativePtr".into(),
         ApiType::CVoidPtr { mutability: Mutability::Const } => "*const core::ffi::c_void".into(),
         ApiType::NamedStructPtr { mutability, name } => {
-            format!("{} uika_ffi::reify_types::{name}", mut_qual(*mutability))
+            format!("{} uika_ffi::{name}", mut_qual(*mutability))
         }
         ApiType::Ptr { mutability, pointee } => {
             if matches!(**pointee, ApiType::U8) && *mutability == Mutability::Mut {
@@ -157,6 +375,7 @@ fn mut_qual(m: Mutability) -> &'static str {
 
 #[derive(Debug, Clone)]
 struct SubTable {
+    struct_name: String,
     module_name: String,
     functions: Vec<ApiFn>,
 }
@@ -170,6 +389,7 @@ struct ApiFn {
 
 #[derive(Debug, Clone)]
 struct ApiParam {
+    name: String,
     ty: ApiType,
 }
 
@@ -181,6 +401,7 @@ enum ApiType {
     UFunctionHandle,
     UStructHandle,
     FNameHandle,
+    FTextHandle,
     FWeakObjectHandle,
     UikaErrorCode,
     Bool,
@@ -219,6 +440,20 @@ fn parse_api_table(source: &str) -> Vec<SubTable> {
     tables
 }
 
+/// Value of `pub const UIKA_API_VERSION: u32 = N;`.
+fn parse_api_version(source: &str) -> u32 {
+    let file: File = syn::parse_str(source).expect("Failed to parse api_table.rs");
+    for item in &file.items {
+        if let Item::Const(c) = item
+            && c.ident == "UIKA_API_VERSION"
+            && let syn::Expr::Lit(syn::ExprLit { lit: syn::Lit::Int(lit), .. }) = &*c.expr
+        {
+            return lit.base10_parse().expect("UIKA_API_VERSION is not a u32");
+        }
+    }
+    panic!("UIKA_API_VERSION not found in api_table.rs");
+}
+
 fn parse_sub_table(s: &ItemStruct) -> Option<SubTable> {
     let struct_name = s.ident.to_string();
     let module_name = derive_module_name(&struct_name);
@@ -239,7 +474,7 @@ fn parse_sub_table(s: &ItemStruct) -> Option<SubTable> {
     if functions.is_empty() {
         return None;
     }
-    Some(SubTable { module_name, functions })
+    Some(SubTable { struct_name, module_name, functions })
 }
 
 fn derive_module_name(struct_name: &str) -> String {
@@ -285,7 +520,8 @@ fn parse_fn_field(_name: &str, ty: &Type) -> Option<ApiFn> {
 
 fn parse_bare_fn_arg(arg: &BareFnArg) -> Option<ApiParam> {
     let ty = parse_api_type(&arg.ty)?;
-    Some(ApiParam { ty })
+    let name = arg.name.as_ref().map(|(ident, _)| ident.to_string()).unwrap_or_default();
+    Some(ApiParam { name, ty })
 }
 
 fn parse_api_type(ty: &Type) -> Option<ApiType> {
@@ -300,6 +536,7 @@ fn parse_api_type(ty: &Type) -> Option<ApiType> {
                 "UFunctionHandle" => Some(ApiType::UFunctionHandle),
                 "UStructHandle" => Some(ApiType::UStructHandle),
                 "FNameHandle" => Some(ApiType::FNameHandle),
+                "FTextHandle" => Some(ApiType::FTextHandle),
                 "FWeakObjectHandle" => Some(ApiType::FWeakObjectHandle),
                 "UikaErrorCode" => Some(ApiType::UikaErrorCode),
                 "bool" => Some(ApiType::Bool),
@@ -358,6 +595,7 @@ fn is_handle_type(ty: &ApiType) -> bool {
             | ApiType::UFunctionHandle
             | ApiType::UStructHandle
             | ApiType::FNameHandle
+            | ApiType::FTextHandle
     )
 }
 
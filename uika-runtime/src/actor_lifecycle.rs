@@ -0,0 +1,48 @@
+// Actor lifecycle hooks for reified (#[uclass]) actors.
+//
+// `#[uclass_impl] impl ActorLifecycle for MyActor { ... }` registers overrides
+// of AActor's ReceiveBeginPlay / ReceiveEndPlay events that forward to these
+// trait methods, so no Blueprint wrapper or #[ufunction(Override)] is needed.
+
+/// Why an actor's play ended (`EEndPlayReason::Type`).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum EndPlayReason {
+    /// Explicitly destroyed.
+    Destroyed,
+    /// Level transition (seamless travel or map change).
+    LevelTransition,
+    /// Play-in-editor session ended.
+    EndPlayInEditor,
+    /// The actor's level was unloaded or removed from the world.
+    RemovedFromWorld,
+    /// The application is shutting down.
+    Quit,
+}
+
+impl EndPlayReason {
+    /// Convert the raw `EEndPlayReason` byte. Unknown values map to `Destroyed`.
+    pub fn from_raw(raw: u8) -> Self {
+        match raw {
+            1 => EndPlayReason::LevelTransition,
+            2 => EndPlayReason::EndPlayInEditor,
+            3 => EndPlayReason::RemovedFromWorld,
+            4 => EndPlayReason::Quit,
+            _ => EndPlayReason::Destroyed,
+        }
+    }
+}
+
+/// BeginPlay / EndPlay hooks for a `#[uclass]` actor. Both default to no-ops;
+/// implement the trait in a `#[uclass_impl]` block to have them dispatched.
+///
+/// These replace the parent's Blueprint `ReceiveBeginPlay`/`ReceiveEndPlay`
+/// events, so don't also declare those as `#[ufunction(Override)]`.
+pub trait ActorLifecycle {
+    /// Called when play begins for this actor (after components are initialized).
+    fn begin_play(&mut self) {}
+
+    /// Called when play ends for this actor, before it is destroyed or unloaded.
+    fn end_play(&mut self, reason: EndPlayReason) {
+        let _ = reason;
+    }
+}
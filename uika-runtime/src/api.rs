@@ -15,13 +15,43 @@ static API: OnceLock<ApiRef> = OnceLock::new();
 
 /// Store the API table pointer. Called once by `uika_init`.
 /// Panics if called more than once.
-pub fn init_api(table: *const UikaApiTable) {
+///
+/// A table from an older plugin (smaller `size`) is copied into a
+/// zero-extended one, so the sub-tables it lacks read as null.
+///
+/// # Safety
+/// `table` must point to the plugin's table, live for the DLL's lifetime.
+pub unsafe fn init_api(table: *const UikaApiTable) {
     assert!(!table.is_null(), "init_api called with null pointer");
+    let table = unsafe { complete_table(table) };
     if API.set(ApiRef(table)).is_err() {
         panic!("init_api called more than once");
     }
 }
 
+/// Return `table`, or a leaked zero-extended copy if the plugin's table is
+/// shorter than this crate's `UikaApiTable`. Tables older than
+/// `UIKA_API_MIN_VERSION` have no `size` field and are returned unchanged
+/// (`uika::init` refuses them).
+///
+/// # Safety
+/// `table` must point to a live table of at least the size it declares.
+pub(crate) unsafe fn complete_table(table: *const UikaApiTable) -> *const UikaApiTable {
+    let full = std::mem::size_of::<UikaApiTable>();
+    // SAFETY: version and size lead every table since UIKA_API_MIN_VERSION.
+    let (version, size) = unsafe { ((*table).version, (*table).size as usize) };
+    if version < uika_ffi::UIKA_API_MIN_VERSION || size >= full {
+        return table;
+    }
+    // SAFETY: every field is a pointer, integer or struct of those, for
+    // which all-zero is valid; the plugin provides `size` readable bytes.
+    unsafe {
+        let copy: &mut UikaApiTable = Box::leak(Box::new(std::mem::zeroed()));
+        std::ptr::copy_nonoverlapping(table as *const u8, copy as *mut UikaApiTable as *mut u8, size);
+        copy
+    }
+}
+
 /// Access the global API table. Panics if called before `init_api`.
 #[inline(always)]
 pub fn api() -> &'static UikaApiTable {
@@ -35,3 +65,46 @@ pub fn api() -> &'static UikaApiTable {
 pub fn is_api_initialized() -> bool {
     API.get().is_some()
 }
+
+/// Wrapper pointer for a generated FuncId, used by the generated bindings.
+///
+/// FuncIds are stable across regenerations, so a hot-reloaded DLL may carry
+/// functions the running plugin was built without. Calling one panics (and
+/// is reported by the surrounding FFI boundary) instead of jumping through a
+/// missing slot.
+#[inline]
+pub fn func_ptr(id: u32) -> *const std::ffi::c_void {
+    let api = api();
+    let ptr = if id < api.func_count {
+        // SAFETY: func_table holds func_count entries.
+        unsafe { *api.func_table.add(id as usize) }
+    } else {
+        std::ptr::null()
+    };
+    if ptr.is_null() {
+        func_unavailable(id);
+    }
+    ptr
+}
+
+#[cold]
+#[inline(never)]
+fn func_unavailable(id: u32) -> ! {
+    panic!(
+        "FuncId {id} is not in the loaded plugin ({} functions); \
+         rebuild the plugin (`uika build --from 3`)",
+        api().func_count
+    )
+}
+
+/// Called by the `ffi_dispatch` wrappers when the plugin has no `module`
+/// sub-table (see [`crate::ffi_dispatch::missing_sub_tables`]).
+#[cold]
+#[inline(never)]
+pub fn sub_table_unavailable(module: &str, func: &str) -> ! {
+    panic!(
+        "uika API `{module}::{func}` is not provided by the loaded plugin \
+         (API version {}); rebuild the plugin (`uika build --from 3`)",
+        api().version
+    )
+}
@@ -0,0 +1,130 @@
+// Asset registry: discover assets by class, path or tag without loading them.
+//
+// Results arrive as UTF-8 lines of `object_path \t class_path \t package_name`
+// (see `UikaAssetRegistryApi`) and are parsed into [`AssetData`].
+
+use uika_ffi::UikaErrorCode;
+
+use crate::assets::SoftObjectPath;
+use crate::error::{check_ffi, UikaError, UikaResult};
+use crate::ffi_dispatch;
+
+/// What the asset registry knows about an asset (`FAssetData`).
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct AssetData {
+    /// Object path, e.g. `/Game/Meshes/SM_Rock.SM_Rock`. Load it with
+    /// [`SoftObjectPath::load_sync`] or [`SoftObjectPath::load_async`].
+    pub object_path: SoftObjectPath,
+    /// Class path, e.g. `/Script/Engine.StaticMesh`.
+    pub class_path: String,
+    /// Package name, e.g. `/Game/Meshes/SM_Rock`.
+    pub package_name: String,
+}
+
+impl AssetData {
+    /// Asset name: the last component of the object path.
+    pub fn asset_name(&self) -> &str {
+        let path = self.object_path.as_str();
+        path.rsplit(['.', '/']).next().unwrap_or(path)
+    }
+}
+
+/// Assets of the class at `class_path` (e.g. `/Script/Engine.StaticMesh`),
+/// including subclasses with `include_subclasses`.
+pub fn get_assets_by_class(class_path: &str, include_subclasses: bool) -> UikaResult<Vec<AssetData>> {
+    query(|buf, len, out_len| unsafe {
+        ffi_dispatch::asset_registry_get_assets_by_class(
+            class_path.as_ptr(),
+            class_path.len() as u32,
+            include_subclasses,
+            buf,
+            len,
+            out_len,
+        )
+    })
+}
+
+/// Assets under the package path `path` (e.g. `/Game/Weapons`), including
+/// sub-folders with `recursive`.
+pub fn get_assets_by_path(path: &str, recursive: bool) -> UikaResult<Vec<AssetData>> {
+    query(|buf, len, out_len| unsafe {
+        ffi_dispatch::asset_registry_get_assets_by_path(
+            path.as_ptr(),
+            path.len() as u32,
+            recursive,
+            buf,
+            len,
+            out_len,
+        )
+    })
+}
+
+/// Assets whose registry tag `tag` has the value `value` (tags come from
+/// `AssetRegistrySearchable` properties and `GetAssetRegistryTags`).
+pub fn get_assets_by_tag_value(tag: &str, value: &str) -> UikaResult<Vec<AssetData>> {
+    query(|buf, len, out_len| unsafe {
+        ffi_dispatch::asset_registry_get_assets_by_tag_value(
+            tag.as_ptr(),
+            tag.len() as u32,
+            value.as_ptr(),
+            value.len() as u32,
+            buf,
+            len,
+            out_len,
+        )
+    })
+}
+
+/// Whether the registry is still discovering assets. Queries made before it
+/// finishes (early in editor startup) may miss assets.
+pub fn is_loading_assets() -> bool {
+    unsafe { ffi_dispatch::asset_registry_is_loading_assets() }
+}
+
+/// Run a query, growing the buffer once if the first attempt is too small.
+fn query(mut call: impl FnMut(*mut u8, u32, &mut u32) -> UikaErrorCode) -> UikaResult<Vec<AssetData>> {
+    let mut buf = vec![0u8; 4096];
+    let mut out_len: u32 = 0;
+    let mut code = call(buf.as_mut_ptr(), buf.len() as u32, &mut out_len);
+    if code == UikaErrorCode::BufferTooSmall {
+        buf.resize(out_len as usize, 0);
+        code = call(buf.as_mut_ptr(), buf.len() as u32, &mut out_len);
+    }
+    check_ffi(code)?;
+    buf.truncate(out_len as usize);
+    let text = String::from_utf8(buf)
+        .map_err(|_| UikaError::Internal("asset registry result is not valid UTF-8".into()))?;
+    Ok(parse_asset_list(&text))
+}
+
+fn parse_asset_list(text: &str) -> Vec<AssetData> {
+    text.lines()
+        .filter_map(|line| {
+            let mut fields = line.split('\t');
+            let (object_path, class_path, package_name) =
+                (fields.next()?, fields.next()?, fields.next()?);
+            Some(AssetData {
+                object_path: SoftObjectPath::new(object_path),
+                class_path: class_path.to_string(),
+                package_name: package_name.to_string(),
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_asset_lines() {
+        let assets = parse_asset_list(
+            "/Game/Meshes/SM_Rock.SM_Rock\t/Script/Engine.StaticMesh\t/Game/Meshes/SM_Rock\n\
+             malformed line\n",
+        );
+        assert_eq!(assets.len(), 1);
+        assert_eq!(assets[0].class_path, "/Script/Engine.StaticMesh");
+        assert_eq!(assets[0].package_name, "/Game/Meshes/SM_Rock");
+        assert_eq!(assets[0].asset_name(), "SM_Rock");
+    }
+}
@@ -0,0 +1,433 @@
+// Soft object paths, soft pointers and async asset loading (FStreamableManager).
+//
+// Completion is reported through the delegate registry: C++ fires
+// `invoke_delegate_callback(callback_id, &request_id)` when a load finishes.
+// Requests can also be polled, so callers without a callback (or an executor)
+// can check progress each tick.
+
+use std::future::Future;
+use std::marker::PhantomData;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Waker};
+
+use uika_ffi::{UClassHandle, UObjectHandle, UikaErrorCode};
+
+use crate::class_ref::ClassRef;
+use crate::delegate_registry::{register_callback, unregister_callback};
+use crate::error::{check_ffi, UikaError, UikaResult};
+use crate::ffi_dispatch;
+use crate::lock_or_recover;
+use crate::object_ref::UObjectRef;
+use crate::traits::UeClass;
+use crate::world::load_object_raw;
+
+/// A path to an asset that may not be loaded yet (FSoftObjectPath),
+/// e.g. `/Game/Meshes/SM_Rock.SM_Rock`.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct SoftObjectPath(String);
+
+impl SoftObjectPath {
+    pub fn new(path: impl Into<String>) -> Self {
+        SoftObjectPath(path.into())
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Whether the path is empty.
+    pub fn is_null(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Load synchronously (blocks the game thread if not yet loaded).
+    pub fn load_sync<T: UeClass>(&self) -> UikaResult<UObjectRef<T>> {
+        let h = load_object_raw(T::static_class(), &self.0)?;
+        unsafe { UObjectRef::<T>::from_raw(h) }.cast()
+    }
+
+    /// Start an async load. See [`load_async`].
+    pub fn load_async<T: UeClass>(&self) -> UikaResult<AssetLoad<T>> {
+        load_async(self)
+    }
+
+    /// The path of a loaded object.
+    pub fn of_object<T: UeClass>(obj: UObjectRef<T>) -> UikaResult<Self> {
+        let h = obj.checked()?.raw();
+        let mut buf = vec![0u8; 256];
+        let mut out_len: u32 = 0;
+        let mut code = unsafe {
+            ffi_dispatch::asset_get_object_path(h, buf.as_mut_ptr(), buf.len() as u32, &mut out_len)
+        };
+        if code == UikaErrorCode::BufferTooSmall {
+            buf.resize(out_len as usize, 0);
+            code = unsafe {
+                ffi_dispatch::asset_get_object_path(h, buf.as_mut_ptr(), buf.len() as u32, &mut out_len)
+            };
+        }
+        check_ffi(code)?;
+        buf.truncate(out_len as usize);
+        String::from_utf8(buf)
+            .map(SoftObjectPath)
+            .map_err(|_| UikaError::Internal("object path is not valid UTF-8".into()))
+    }
+
+    /// The object at this path if it is already in memory. Never loads.
+    fn resolve(&self) -> Option<UObjectHandle> {
+        if self.is_null() {
+            return None;
+        }
+        let h = unsafe { ffi_dispatch::asset_resolve_path(self.0.as_ptr(), self.0.len() as u32) };
+        (!h.is_null()).then_some(h)
+    }
+}
+
+impl From<&str> for SoftObjectPath {
+    fn from(s: &str) -> Self {
+        SoftObjectPath(s.to_owned())
+    }
+}
+
+impl From<String> for SoftObjectPath {
+    fn from(s: String) -> Self {
+        SoftObjectPath(s)
+    }
+}
+
+impl std::fmt::Display for SoftObjectPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+/// A `TSoftObjectPtr<T>`: a reference to an asset by path, which may or may
+/// not be loaded. Generated bindings use it for SoftObjectProperty params and
+/// properties; the value crosses the FFI as the path string.
+pub struct SoftObjectPtr<T: UeClass> {
+    path: SoftObjectPath,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<T: UeClass> SoftObjectPtr<T> {
+    pub fn new(path: impl Into<SoftObjectPath>) -> Self {
+        SoftObjectPtr { path: path.into(), _marker: PhantomData }
+    }
+
+    /// The null soft pointer (empty path).
+    pub fn null() -> Self {
+        Self::new(SoftObjectPath::default())
+    }
+
+    /// A soft pointer to an already loaded object.
+    pub fn from_object(obj: UObjectRef<T>) -> UikaResult<Self> {
+        SoftObjectPath::of_object(obj).map(Self::new)
+    }
+
+    pub fn path(&self) -> &str {
+        self.path.as_str()
+    }
+
+    pub fn soft_path(&self) -> &SoftObjectPath {
+        &self.path
+    }
+
+    pub fn is_null(&self) -> bool {
+        self.path.is_null()
+    }
+
+    /// Whether the asset is currently in memory.
+    pub fn is_loaded(&self) -> bool {
+        self.get().is_some()
+    }
+
+    /// The asset if it is already loaded and is a `T`. Never loads.
+    pub fn get(&self) -> Option<UObjectRef<T>> {
+        let h = self.path.resolve()?;
+        unsafe { UObjectRef::<T>::from_raw(h) }.cast().ok()
+    }
+
+    /// Load synchronously (blocks the game thread if not yet loaded).
+    pub fn load(&self) -> UikaResult<UObjectRef<T>> {
+        self.path.load_sync()
+    }
+
+    /// Start an async load. See [`load_async`].
+    pub fn load_async(&self) -> UikaResult<AssetLoad<T>> {
+        load_async(&self.path)
+    }
+
+    /// Start an async load and call `callback` when it finishes. See
+    /// [`load_async_then`].
+    pub fn load_async_then(
+        &self,
+        callback: impl FnOnce(UikaResult<UObjectRef<T>>) + Send + 'static,
+    ) -> UikaResult<AssetLoad<T>> {
+        load_async_then(&self.path, callback)
+    }
+}
+
+/// A `TSoftClassPtr<T>`: a class (usually a Blueprint) referenced by path,
+/// resolving to a [`ClassRef<T>`] once loaded.
+pub struct SoftClassPtr<T: UeClass> {
+    path: SoftObjectPath,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<T: UeClass> SoftClassPtr<T> {
+    /// `path` is the class path, e.g. `/Game/BP_Enemy.BP_Enemy_C`.
+    pub fn new(path: impl Into<SoftObjectPath>) -> Self {
+        SoftClassPtr { path: path.into(), _marker: PhantomData }
+    }
+
+    pub fn null() -> Self {
+        Self::new(SoftObjectPath::default())
+    }
+
+    pub fn path(&self) -> &str {
+        self.path.as_str()
+    }
+
+    pub fn soft_path(&self) -> &SoftObjectPath {
+        &self.path
+    }
+
+    pub fn is_null(&self) -> bool {
+        self.path.is_null()
+    }
+
+    /// Whether the class is currently in memory.
+    pub fn is_loaded(&self) -> bool {
+        self.get().is_some()
+    }
+
+    /// The class if it is already loaded and derives from `T`. Never loads.
+    pub fn get(&self) -> Option<ClassRef<T>> {
+        let h = self.path.resolve()?;
+        class_of_object(h).ok()
+    }
+
+    /// Load synchronously (blocks the game thread if not yet loaded).
+    pub fn load(&self) -> UikaResult<ClassRef<T>> {
+        let h = load_object_raw(UClassHandle::null(), self.path.as_str())?;
+        class_of_object(h)
+    }
+
+    /// Start an async load and call `callback` with the class when it
+    /// finishes. Keep the returned handle alive until then.
+    pub fn load_async_then(
+        &self,
+        callback: impl FnOnce(UikaResult<ClassRef<T>>) + Send + 'static,
+    ) -> UikaResult<ClassLoad<T>> {
+        let typed: LoadedCallback = Box::new(move |result: UikaResult<UObjectHandle>| {
+            callback(result.and_then(class_of_object));
+        });
+        start_load(&self.path, Some(typed)).map(ClassLoad)
+    }
+}
+
+/// The object at a class path as a class deriving from `T`. A path can name
+/// any object (a Blueprint asset rather than its `_C` class, say), so the
+/// object is checked to be a UClass first: `TypeMismatch` if it is not.
+fn class_of_object<T: UeClass>(h: UObjectHandle) -> UikaResult<ClassRef<T>> {
+    let uclass = unsafe { ffi_dispatch::reflection_find_class(b"Class".as_ptr(), 5) };
+    if !h.is_null() && !unsafe { ffi_dispatch::core_is_a(h, uclass) } {
+        return Err(UikaError::TypeMismatch);
+    }
+    ClassRef::new(UClassHandle(h.0))
+}
+
+/// An in-flight class load started by [`SoftClassPtr::load_async_then`].
+/// Dropping it cancels the load.
+#[must_use = "dropping a ClassLoad cancels the load"]
+pub struct ClassLoad<T: UeClass>(AssetLoad<T>);
+
+impl<T: UeClass> ClassLoad<T> {
+    /// Whether the load has finished (successfully or not).
+    pub fn is_done(&self) -> bool {
+        poll_request(self.0.request).is_some()
+    }
+
+    /// Cancel the load. Equivalent to dropping the handle.
+    pub fn cancel(self) {}
+}
+
+macro_rules! impl_soft_ptr_common {
+    ($ty:ident) => {
+        impl<T: UeClass> Clone for $ty<T> {
+            fn clone(&self) -> Self {
+                Self::new(self.path.clone())
+            }
+        }
+
+        impl<T: UeClass> Default for $ty<T> {
+            fn default() -> Self {
+                Self::null()
+            }
+        }
+
+        impl<T: UeClass> PartialEq for $ty<T> {
+            fn eq(&self, other: &Self) -> bool {
+                self.path == other.path
+            }
+        }
+
+        impl<T: UeClass> Eq for $ty<T> {}
+
+        impl<T: UeClass> std::hash::Hash for $ty<T> {
+            fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+                self.path.hash(state);
+            }
+        }
+
+        impl<T: UeClass> std::fmt::Debug for $ty<T> {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.debug_tuple(stringify!($ty)).field(&self.path.as_str()).finish()
+            }
+        }
+    };
+}
+
+impl_soft_ptr_common!(SoftObjectPtr);
+impl_soft_ptr_common!(SoftClassPtr);
+
+/// State of an async load.
+#[derive(Debug)]
+pub enum AssetLoadState<T: UeClass> {
+    Pending,
+    Loaded(UObjectRef<T>),
+    Failed(UikaError),
+}
+
+type LoadedCallback = Box<dyn FnOnce(UikaResult<UObjectHandle>) + Send>;
+
+#[derive(Default)]
+struct LoadShared {
+    waker: Option<Waker>,
+}
+
+/// An in-flight async load. Poll it with [`state`](Self::state), `.await` it,
+/// or pass a callback to [`load_async_then`].
+///
+/// Dropping the handle cancels a pending load and releases the request, after
+/// which the asset is only kept alive by other references.
+#[must_use = "dropping an AssetLoad cancels the load"]
+pub struct AssetLoad<T: UeClass> {
+    request: u64,
+    callback_id: u64,
+    shared: Arc<Mutex<LoadShared>>,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<T: UeClass> AssetLoad<T> {
+    /// Query the current state without blocking.
+    pub fn state(&self) -> AssetLoadState<T> {
+        match poll_request(self.request) {
+            None => AssetLoadState::Pending,
+            Some(Ok(h)) => match unsafe { UObjectRef::<T>::from_raw(h) }.cast() {
+                Ok(obj) => AssetLoadState::Loaded(obj),
+                Err(e) => AssetLoadState::Failed(e),
+            },
+            Some(Err(e)) => AssetLoadState::Failed(e),
+        }
+    }
+
+    /// Whether the load has finished (successfully or not).
+    pub fn is_done(&self) -> bool {
+        !matches!(self.state(), AssetLoadState::Pending)
+    }
+
+    /// Cancel the load. Equivalent to dropping the handle.
+    pub fn cancel(self) {
+        // Drop handles the cleanup.
+    }
+}
+
+impl<T: UeClass> Future for AssetLoad<T> {
+    type Output = UikaResult<UObjectRef<T>>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        // Register the waker before checking, so a completion in between
+        // still wakes this task.
+        lock_or_recover(&self.shared).waker = Some(cx.waker().clone());
+        match self.state() {
+            AssetLoadState::Pending => Poll::Pending,
+            AssetLoadState::Loaded(obj) => Poll::Ready(Ok(obj)),
+            AssetLoadState::Failed(e) => Poll::Ready(Err(e)),
+        }
+    }
+}
+
+impl<T: UeClass> Drop for AssetLoad<T> {
+    fn drop(&mut self) {
+        unregister_callback(self.callback_id);
+        if crate::api::is_api_initialized() {
+            let _ = unsafe { ffi_dispatch::asset_cancel_load(self.request) };
+        }
+    }
+}
+
+/// Start loading `path` asynchronously. The returned handle can be polled or
+/// awaited; the load is canceled if it is dropped before completion.
+pub fn load_async<T: UeClass>(path: &SoftObjectPath) -> UikaResult<AssetLoad<T>> {
+    start_load(path, None)
+}
+
+/// Start loading `path` asynchronously and call `callback` on the game thread
+/// once it finishes. Keep the returned handle alive until then.
+pub fn load_async_then<T: UeClass>(
+    path: &SoftObjectPath,
+    callback: impl FnOnce(UikaResult<UObjectRef<T>>) + Send + 'static,
+) -> UikaResult<AssetLoad<T>> {
+    let typed: LoadedCallback = Box::new(move |result: UikaResult<UObjectHandle>| {
+        callback(result.and_then(|h| unsafe { UObjectRef::<T>::from_raw(h) }.cast()));
+    });
+    start_load(path, Some(typed))
+}
+
+fn start_load<T: UeClass>(
+    path: &SoftObjectPath,
+    mut on_loaded: Option<LoadedCallback>,
+) -> UikaResult<AssetLoad<T>> {
+    if path.is_null() {
+        return Err(UikaError::InvalidOperation("load_async: empty path".into()));
+    }
+
+    let shared = Arc::new(Mutex::new(LoadShared::default()));
+    let callback_id = {
+        let shared = Arc::clone(&shared);
+        register_callback(move |params| {
+            // params points to the u64 request ID.
+            let request = unsafe { ffi_dispatch::native_mem_read::<u64>(params, 0) };
+            let waker = lock_or_recover(&shared).waker.take();
+            if let Some(w) = waker {
+                w.wake();
+            }
+            if let Some(f) = on_loaded.take() {
+                let result = poll_request(request).unwrap_or_else(|| {
+                    Err(UikaError::Internal("load completed but request still pending".into()))
+                });
+                f(result);
+            }
+        })
+    };
+
+    let request = unsafe {
+        ffi_dispatch::asset_request_load(path.0.as_ptr(), path.0.len() as u32, callback_id)
+    };
+    if request == 0 {
+        unregister_callback(callback_id);
+        return Err(UikaError::InvalidOperation(format!("load_async: failed to request {path}")));
+    }
+    Ok(AssetLoad { request, callback_id, shared, _marker: PhantomData })
+}
+
+/// `None` while pending, otherwise the loaded object or the failure.
+fn poll_request(request: u64) -> Option<UikaResult<UObjectHandle>> {
+    let mut out = UObjectHandle::null();
+    match unsafe { ffi_dispatch::asset_poll_load(request, &mut out) } {
+        0 => None,
+        1 => Some(Ok(out)),
+        _ => Some(Err(UikaError::InvalidOperation("async load failed or was canceled".into()))),
+    }
+}
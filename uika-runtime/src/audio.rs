@@ -0,0 +1,219 @@
+// Audio: play sounds and control the audio components spawned for them.
+//
+// `play_*` functions are fire-and-forget. `spawn_*` functions return an
+// [`AudioHandle`] that tracks the spawned `UAudioComponent` through a weak
+// reference, so it can be stopped or faded later and reports
+// `ObjectDestroyed` once the component is gone (e.g. auto-destroyed after
+// finishing).
+
+use glam::DVec3;
+use uika_ffi::{FWeakObjectHandle, UObjectHandle};
+
+use crate::error::{check_ffi, UikaError, UikaResult};
+use crate::ffi_dispatch;
+use crate::fname::FName;
+use crate::object_ref::UObjectRef;
+use crate::traits::UeClass;
+
+/// Playback parameters shared by every play/spawn function.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SoundParams {
+    /// Volume multiplier.
+    pub volume: f32,
+    /// Pitch multiplier.
+    pub pitch: f32,
+    /// Seconds into the sound to start playing from.
+    pub start_time: f32,
+    /// Destroy the spawned component when the sound finishes (spawn only).
+    pub auto_destroy: bool,
+}
+
+impl Default for SoundParams {
+    fn default() -> Self {
+        SoundParams { volume: 1.0, pitch: 1.0, start_time: 0.0, auto_destroy: true }
+    }
+}
+
+impl SoundParams {
+    pub fn volume(mut self, volume: f32) -> Self {
+        self.volume = volume;
+        self
+    }
+
+    pub fn pitch(mut self, pitch: f32) -> Self {
+        self.pitch = pitch;
+        self
+    }
+
+    pub fn start_time(mut self, seconds: f32) -> Self {
+        self.start_time = seconds;
+        self
+    }
+
+    /// Keep the spawned component after the sound finishes, so it can be
+    /// replayed or faded in again.
+    pub fn keep_alive(mut self) -> Self {
+        self.auto_destroy = false;
+        self
+    }
+}
+
+/// Play a non-spatialized sound (UI, music stingers) in `world_context`'s world.
+pub fn play_sound_2d<C: UeClass, S: UeClass>(
+    world_context: UObjectRef<C>,
+    sound: UObjectRef<S>,
+    params: SoundParams,
+) -> UikaResult<()> {
+    let ctx = world_context.checked()?.raw();
+    let sound = sound.checked()?.raw();
+    check_ffi(unsafe {
+        ffi_dispatch::audio_play_sound_2d(ctx, sound, params.volume, params.pitch, params.start_time)
+    })
+}
+
+/// Play a sound at a world location.
+pub fn play_sound_at_location<C: UeClass, S: UeClass>(
+    world_context: UObjectRef<C>,
+    sound: UObjectRef<S>,
+    location: DVec3,
+    params: SoundParams,
+) -> UikaResult<()> {
+    let ctx = world_context.checked()?.raw();
+    let sound = sound.checked()?.raw();
+    let location = location.to_array();
+    check_ffi(unsafe {
+        ffi_dispatch::audio_play_sound_at_location(
+            ctx,
+            sound,
+            location.as_ptr(),
+            params.volume,
+            params.pitch,
+            params.start_time,
+        )
+    })
+}
+
+/// Spawn a non-spatialized audio component and start playing.
+pub fn spawn_sound_2d<C: UeClass, S: UeClass>(
+    world_context: UObjectRef<C>,
+    sound: UObjectRef<S>,
+    params: SoundParams,
+) -> UikaResult<AudioHandle> {
+    let ctx = world_context.checked()?.raw();
+    let sound = sound.checked()?.raw();
+    AudioHandle::new(unsafe {
+        ffi_dispatch::audio_spawn_sound_2d(
+            ctx,
+            sound,
+            params.volume,
+            params.pitch,
+            params.start_time,
+            params.auto_destroy,
+        )
+    })
+}
+
+/// Spawn an audio component at a world location and start playing.
+pub fn spawn_sound_at_location<C: UeClass, S: UeClass>(
+    world_context: UObjectRef<C>,
+    sound: UObjectRef<S>,
+    location: DVec3,
+    params: SoundParams,
+) -> UikaResult<AudioHandle> {
+    let ctx = world_context.checked()?.raw();
+    let sound = sound.checked()?.raw();
+    let location = location.to_array();
+    AudioHandle::new(unsafe {
+        ffi_dispatch::audio_spawn_sound_at_location(
+            ctx,
+            sound,
+            location.as_ptr(),
+            params.volume,
+            params.pitch,
+            params.start_time,
+            params.auto_destroy,
+        )
+    })
+}
+
+/// Spawn an audio component attached to a scene component (optionally at
+/// `socket`) so the sound follows it.
+pub fn spawn_sound_attached<S: UeClass, P: UeClass>(
+    sound: UObjectRef<S>,
+    attach_to: UObjectRef<P>,
+    socket: Option<FName>,
+    params: SoundParams,
+) -> UikaResult<AudioHandle> {
+    let sound = sound.checked()?.raw();
+    let parent = attach_to.checked()?.raw();
+    let socket = socket.map(|s| s.handle()).unwrap_or_default();
+    AudioHandle::new(unsafe {
+        ffi_dispatch::audio_spawn_sound_attached(
+            sound,
+            parent,
+            socket,
+            params.volume,
+            params.pitch,
+            params.start_time,
+            params.auto_destroy,
+        )
+    })
+}
+
+/// A spawned `UAudioComponent`. Holds a weak reference: the component is
+/// owned by the engine and may be destroyed at any time.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct AudioHandle {
+    weak: FWeakObjectHandle,
+}
+
+impl AudioHandle {
+    fn new(component: UObjectHandle) -> UikaResult<Self> {
+        if component.is_null() {
+            return Err(UikaError::InvalidOperation(
+                "spawning the sound failed (no world, not a sound, or audio disabled)".into(),
+            ));
+        }
+        Ok(AudioHandle { weak: unsafe { ffi_dispatch::core_make_weak(component) } })
+    }
+
+    fn resolve(&self) -> UikaResult<UObjectHandle> {
+        let h = unsafe { ffi_dispatch::core_resolve_weak(self.weak) };
+        if h.is_null() { Err(UikaError::ObjectDestroyed) } else { Ok(h) }
+    }
+
+    /// The audio component, if it still exists. `T` is typically the
+    /// generated `AudioComponent` type.
+    pub fn component<T: UeClass>(&self) -> Option<UObjectRef<T>> {
+        let h = self.resolve().ok()?;
+        unsafe { UObjectRef::<T>::from_raw(h) }.cast().ok()
+    }
+
+    /// Whether the component still exists and is playing.
+    pub fn is_playing(&self) -> bool {
+        self.resolve().is_ok_and(|h| unsafe { ffi_dispatch::audio_is_playing(h) })
+    }
+
+    /// Stop immediately.
+    pub fn stop(&self) -> UikaResult<()> {
+        check_ffi(unsafe { ffi_dispatch::audio_stop(self.resolve()?) })
+    }
+
+    /// (Re)start playing, fading in to `volume` over `duration` seconds.
+    pub fn fade_in(&self, duration: f32, volume: f32) -> UikaResult<()> {
+        check_ffi(unsafe { ffi_dispatch::audio_fade_in(self.resolve()?, duration, volume, 0.0) })
+    }
+
+    /// Fade to `volume` over `duration` seconds, then stop.
+    pub fn fade_out(&self, duration: f32, volume: f32) -> UikaResult<()> {
+        check_ffi(unsafe { ffi_dispatch::audio_fade_out(self.resolve()?, duration, volume) })
+    }
+
+    pub fn set_volume(&self, volume: f32) -> UikaResult<()> {
+        check_ffi(unsafe { ffi_dispatch::audio_set_volume(self.resolve()?, volume) })
+    }
+
+    pub fn set_pitch(&self, pitch: f32) -> UikaResult<()> {
+        check_ffi(unsafe { ffi_dispatch::audio_set_pitch(self.resolve()?, pitch) })
+    }
+}
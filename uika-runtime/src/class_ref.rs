@@ -0,0 +1,124 @@
+// ClassRef<T>: typed reference to a UClass that is T or a subclass of T.
+// The Rust side of TSubclassOf<T>. Generated code passes it across the FFI
+// as the UClass object's UObjectHandle, like any other UObject* property.
+
+use std::fmt;
+use std::marker::PhantomData;
+
+use uika_ffi::{UClassHandle, UObjectHandle};
+
+use crate::error::{UikaError, UikaResult};
+use crate::ffi_dispatch;
+use crate::object_ref::UObjectRef;
+use crate::traits::UeClass;
+
+/// A `TSubclassOf<T>`: either null or a UClass that is `T` or derives from it.
+///
+/// Construct with [`of`](Self::of) for a statically known class, or
+/// [`new`](Self::new) to validate a class handle obtained at runtime.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct ClassRef<T: UeClass> {
+    handle: UClassHandle,
+    _marker: PhantomData<*const T>,
+}
+
+unsafe impl<T: UeClass> Send for ClassRef<T> {}
+
+impl<T: UeClass> ClassRef<T> {
+    /// The null class (an unset `TSubclassOf`).
+    #[inline]
+    pub fn null() -> Self {
+        ClassRef { handle: UClassHandle::null(), _marker: PhantomData }
+    }
+
+    /// `T`'s own UClass.
+    #[inline]
+    pub fn of_self() -> Self {
+        ClassRef { handle: T::static_class(), _marker: PhantomData }
+    }
+
+    /// The UClass of `U`, which must derive from `T`. Checked at runtime,
+    /// since the class hierarchy is only known to UE.
+    pub fn of<U: UeClass>() -> UikaResult<Self> {
+        Self::new(U::static_class())
+    }
+
+    /// Validate that `class` is `T` or a subclass of `T`.
+    pub fn new(class: UClassHandle) -> UikaResult<Self> {
+        if class.is_null() {
+            return Err(UikaError::NullArgument);
+        }
+        if unsafe { ffi_dispatch::core_is_child_of(class, T::static_class()) } {
+            Ok(ClassRef { handle: class, _marker: PhantomData })
+        } else {
+            Err(UikaError::InvalidCast)
+        }
+    }
+
+    /// Create from the UClass object's raw FFI handle (as stored in a
+    /// TSubclassOf property or function param).
+    ///
+    /// # Safety
+    /// The handle must be null or point to a UClass that is `T` or a
+    /// subclass of `T`.
+    #[inline]
+    pub unsafe fn from_raw(handle: UObjectHandle) -> Self {
+        ClassRef { handle: UClassHandle(handle.0), _marker: PhantomData }
+    }
+
+    /// The UClass as an object handle, for passing across the FFI.
+    #[inline]
+    pub fn raw(&self) -> UObjectHandle {
+        UObjectHandle(self.handle.0)
+    }
+
+    /// The class handle.
+    #[inline]
+    pub fn class(&self) -> UClassHandle {
+        self.handle
+    }
+
+    #[inline]
+    pub fn is_null(&self) -> bool {
+        self.handle.is_null()
+    }
+
+    /// Whether this class is `U` or derives from it.
+    pub fn is_child_of<U: UeClass>(&self) -> bool {
+        !self.is_null() && unsafe { ffi_dispatch::core_is_child_of(self.handle, U::static_class()) }
+    }
+
+    /// The class default object of this (possibly Blueprint) class. See
+    /// [`UeClass::default_object`].
+    pub fn default_object(&self) -> UikaResult<UObjectRef<T>> {
+        if self.is_null() {
+            return Err(UikaError::NullArgument);
+        }
+        let h = unsafe { ffi_dispatch::core_ext_get_default_object(self.handle) };
+        Ok(unsafe { UObjectRef::from_raw(h) })
+    }
+
+    /// Narrow to a subclass `U` of `T`. Fails if the class does not derive from `U`.
+    pub fn cast<U: UeClass>(self) -> UikaResult<ClassRef<U>> {
+        ClassRef::<U>::new(self.handle)
+    }
+}
+
+impl<T: UeClass> Default for ClassRef<T> {
+    fn default() -> Self {
+        Self::null()
+    }
+}
+
+impl<T: UeClass> From<ClassRef<T>> for UClassHandle {
+    fn from(c: ClassRef<T>) -> Self {
+        c.handle
+    }
+}
+
+impl<T: UeClass> fmt::Debug for ClassRef<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ClassRef").field("handle", &self.handle).finish()
+    }
+}
@@ -0,0 +1,177 @@
+// Components: create components on live actors and attach scene components
+// and actors to each other.
+//
+// Components added here are registered instance components, like ones added
+// with Blueprint's AddComponent: a scene component becomes the actor's root
+// if it has none, otherwise it starts out attached to the root.
+
+use uika_ffi::UClassHandle;
+
+use crate::class_ref::ClassRef;
+use crate::error::{check_ffi, UikaError, UikaResult};
+use crate::ffi_dispatch;
+use crate::fname::FName;
+use crate::object_ref::UObjectRef;
+use crate::traits::UeClass;
+
+/// How one part of a transform is treated when attaching or detaching.
+/// Maps to UE's `EAttachmentRule` / `EDetachmentRule`.
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum AttachmentRule {
+    /// Keep the relative transform; the world transform changes.
+    #[default]
+    KeepRelative = 0,
+    /// Keep the world transform; the relative transform is recomputed.
+    KeepWorld = 1,
+    /// Snap to the parent (or socket). Detaching has no snap rule and
+    /// treats this as `KeepWorld`.
+    SnapToTarget = 2,
+}
+
+/// Rules for location, rotation and scale when attaching, as
+/// `FAttachmentTransformRules`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct AttachmentRules {
+    pub location: AttachmentRule,
+    pub rotation: AttachmentRule,
+    pub scale: AttachmentRule,
+    /// Weld simulated bodies of the child to the parent.
+    pub weld_simulated_bodies: bool,
+}
+
+impl AttachmentRules {
+    pub const KEEP_RELATIVE: Self = Self::all(AttachmentRule::KeepRelative);
+    pub const KEEP_WORLD: Self = Self::all(AttachmentRule::KeepWorld);
+    pub const SNAP_TO_TARGET: Self = Self::all(AttachmentRule::SnapToTarget);
+
+    /// The same rule for location, rotation and scale, without welding.
+    pub const fn all(rule: AttachmentRule) -> Self {
+        AttachmentRules { location: rule, rotation: rule, scale: rule, weld_simulated_bodies: false }
+    }
+
+    pub const fn weld(mut self) -> Self {
+        self.weld_simulated_bodies = true;
+        self
+    }
+}
+
+impl Default for AttachmentRules {
+    fn default() -> Self {
+        Self::KEEP_RELATIVE
+    }
+}
+
+/// Component creation and attachment on actors.
+///
+/// ```ignore
+/// let mesh = actor.add_component::<UStaticMeshComponent>("Mesh")?;
+/// let light = actor.add_component::<UPointLightComponent>("Glow")?;
+/// components::attach_component(light, mesh, Some(FName::new("Tip")), AttachmentRules::SNAP_TO_TARGET)?;
+/// ```
+pub trait ActorComponentsExt {
+    /// Create and register a component of type `T` named `name`.
+    fn add_component<T: UeClass>(&self, name: &str) -> UikaResult<UObjectRef<T>>;
+
+    /// [`add_component`](Self::add_component) for a runtime-chosen
+    /// component class (e.g. a Blueprint component).
+    fn add_component_of_class<T: UeClass>(&self, class: ClassRef<T>, name: &str) -> UikaResult<UObjectRef<T>>;
+
+    /// Attach this actor's root to `parent`'s root (or its `socket`).
+    fn attach_to_actor<P: UeClass>(
+        &self,
+        parent: UObjectRef<P>,
+        socket: Option<FName>,
+        rules: AttachmentRules,
+    ) -> UikaResult<()>;
+
+    /// Detach this actor from whatever it is attached to. `SnapToTarget`
+    /// detaches as `KeepWorld`.
+    fn detach_from_actor(&self, rule: AttachmentRule) -> UikaResult<()>;
+}
+
+impl<A: UeClass> ActorComponentsExt for UObjectRef<A> {
+    fn add_component<T: UeClass>(&self, name: &str) -> UikaResult<UObjectRef<T>> {
+        add_component_raw(self, T::static_class(), name)
+    }
+
+    fn add_component_of_class<T: UeClass>(&self, class: ClassRef<T>, name: &str) -> UikaResult<UObjectRef<T>> {
+        if class.is_null() {
+            return Err(UikaError::NullArgument);
+        }
+        add_component_raw(self, class.class(), name)
+    }
+
+    fn attach_to_actor<P: UeClass>(
+        &self,
+        parent: UObjectRef<P>,
+        socket: Option<FName>,
+        rules: AttachmentRules,
+    ) -> UikaResult<()> {
+        let actor = self.checked()?.raw();
+        let parent = parent.checked()?.raw();
+        let socket = socket.map(|s| s.handle()).unwrap_or_default();
+        check_ffi(unsafe {
+            ffi_dispatch::component_attach_actor(
+                actor,
+                parent,
+                socket,
+                rules.location as u8,
+                rules.rotation as u8,
+                rules.scale as u8,
+                rules.weld_simulated_bodies,
+            )
+        })
+    }
+
+    fn detach_from_actor(&self, rule: AttachmentRule) -> UikaResult<()> {
+        let actor = self.checked()?.raw();
+        check_ffi(unsafe { ffi_dispatch::component_detach(actor, rule as u8) })
+    }
+}
+
+fn add_component_raw<A: UeClass, T: UeClass>(
+    actor: &UObjectRef<A>,
+    class: UClassHandle,
+    name: &str,
+) -> UikaResult<UObjectRef<T>> {
+    let actor = actor.checked()?.raw();
+    let component =
+        unsafe { ffi_dispatch::component_add_component(actor, class, name.as_ptr(), name.len() as u32) };
+    if component.is_null() {
+        return Err(UikaError::InvalidOperation(format!(
+            "add_component '{name}' failed (not an actor, not a component class, or name in use)"
+        )));
+    }
+    Ok(unsafe { UObjectRef::from_raw(component) })
+}
+
+/// Attach scene component `child` to `parent` (or its `socket`).
+pub fn attach_component<C: UeClass, P: UeClass>(
+    child: UObjectRef<C>,
+    parent: UObjectRef<P>,
+    socket: Option<FName>,
+    rules: AttachmentRules,
+) -> UikaResult<()> {
+    let child = child.checked()?.raw();
+    let parent = parent.checked()?.raw();
+    let socket = socket.map(|s| s.handle()).unwrap_or_default();
+    check_ffi(unsafe {
+        ffi_dispatch::component_attach_component(
+            child,
+            parent,
+            socket,
+            rules.location as u8,
+            rules.rotation as u8,
+            rules.scale as u8,
+            rules.weld_simulated_bodies,
+        )
+    })
+}
+
+/// Detach scene component `child` from its parent. `SnapToTarget` detaches
+/// as `KeepWorld`.
+pub fn detach_component<C: UeClass>(child: UObjectRef<C>, rule: AttachmentRule) -> UikaResult<()> {
+    let child = child.checked()?.raw();
+    check_ffi(unsafe { ffi_dispatch::component_detach(child, rule as u8) })
+}
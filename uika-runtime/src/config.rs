@@ -0,0 +1,261 @@
+// Config: read and write ini settings through GConfig.
+//
+// Lets Rust gameplay code honor the same `DefaultGame.ini` /
+// `GameUserSettings.ini` values as the rest of the project. Setters change
+// the in-memory config only; call [`flush`] to write the file to disk.
+
+use uika_ffi::UikaErrorCode;
+
+use crate::error::{check_ffi, UikaError, UikaResult};
+use crate::ffi_dispatch;
+
+/// Which ini file an entry lives in.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ConfigFile<'a> {
+    /// `GEngineIni` (`DefaultEngine.ini` and its overrides).
+    Engine,
+    /// `GGameIni` (`DefaultGame.ini` and its overrides).
+    Game,
+    /// `GInputIni`.
+    Input,
+    /// `GGameUserSettingsIni`: per-user settings that survive restarts.
+    GameUserSettings,
+    /// `GEditorIni`.
+    Editor,
+    /// Any other ini, by filename.
+    Path(&'a str),
+}
+
+impl ConfigFile<'_> {
+    fn as_str(&self) -> &str {
+        match self {
+            ConfigFile::Engine => "Engine",
+            ConfigFile::Game => "Game",
+            ConfigFile::Input => "Input",
+            ConfigFile::GameUserSettings => "GameUserSettings",
+            ConfigFile::Editor => "Editor",
+            ConfigFile::Path(path) => path,
+        }
+    }
+}
+
+/// Signature shared by the scalar getters of `UikaConfigApi`.
+type ScalarGetter<T> =
+    unsafe fn(*const u8, u32, *const u8, u32, *const u8, u32, *mut T) -> UikaErrorCode;
+
+/// Signature shared by the buffer getters of `UikaConfigApi`.
+type BufferGetter =
+    unsafe fn(*const u8, u32, *const u8, u32, *const u8, u32, *mut u8, u32, *mut u32) -> UikaErrorCode;
+
+/// `None` if the key doesn't exist.
+///
+/// ```ignore
+/// let bots = config::get_int("/Script/MyGame.MatchSettings", "MaxBots", ConfigFile::Game)?
+///     .unwrap_or(8);
+/// ```
+pub fn get_int(section: &str, key: &str, file: ConfigFile) -> UikaResult<Option<i32>> {
+    get_scalar(section, key, file, ffi_dispatch::config_get_int)
+}
+
+pub fn set_int(section: &str, key: &str, file: ConfigFile, value: i32) -> UikaResult<()> {
+    let file = file.as_str();
+    check_ffi(unsafe {
+        ffi_dispatch::config_set_int(
+            section.as_ptr(),
+            section.len() as u32,
+            key.as_ptr(),
+            key.len() as u32,
+            file.as_ptr(),
+            file.len() as u32,
+            value,
+        )
+    })
+}
+
+/// `None` if the key doesn't exist.
+pub fn get_float(section: &str, key: &str, file: ConfigFile) -> UikaResult<Option<f64>> {
+    get_scalar(section, key, file, ffi_dispatch::config_get_float)
+}
+
+pub fn set_float(section: &str, key: &str, file: ConfigFile, value: f64) -> UikaResult<()> {
+    let file = file.as_str();
+    check_ffi(unsafe {
+        ffi_dispatch::config_set_float(
+            section.as_ptr(),
+            section.len() as u32,
+            key.as_ptr(),
+            key.len() as u32,
+            file.as_ptr(),
+            file.len() as u32,
+            value,
+        )
+    })
+}
+
+/// `None` if the key doesn't exist. Accepts the usual ini spellings
+/// (`True`, `true`, `1`, `yes`, ...).
+pub fn get_bool(section: &str, key: &str, file: ConfigFile) -> UikaResult<Option<bool>> {
+    get_scalar(section, key, file, ffi_dispatch::config_get_bool)
+}
+
+pub fn set_bool(section: &str, key: &str, file: ConfigFile, value: bool) -> UikaResult<()> {
+    let file = file.as_str();
+    check_ffi(unsafe {
+        ffi_dispatch::config_set_bool(
+            section.as_ptr(),
+            section.len() as u32,
+            key.as_ptr(),
+            key.len() as u32,
+            file.as_ptr(),
+            file.len() as u32,
+            value,
+        )
+    })
+}
+
+/// `None` if the key doesn't exist.
+pub fn get_string(section: &str, key: &str, file: ConfigFile) -> UikaResult<Option<String>> {
+    get_text(section, key, file, ffi_dispatch::config_get_string)
+}
+
+pub fn set_string(section: &str, key: &str, file: ConfigFile, value: &str) -> UikaResult<()> {
+    let file = file.as_str();
+    check_ffi(unsafe {
+        ffi_dispatch::config_set_string(
+            section.as_ptr(),
+            section.len() as u32,
+            key.as_ptr(),
+            key.len() as u32,
+            file.as_ptr(),
+            file.len() as u32,
+            value.as_ptr(),
+            value.len() as u32,
+        )
+    })
+}
+
+/// The values of an array entry (`+Key=Value` lines). A missing key reads as
+/// an empty array.
+pub fn get_array(section: &str, key: &str, file: ConfigFile) -> UikaResult<Vec<String>> {
+    let text = get_text(section, key, file, ffi_dispatch::config_get_array)?;
+    Ok(text.as_deref().map(decode_array).unwrap_or_default())
+}
+
+/// Replace an array entry. Values must not contain newlines.
+pub fn set_array<S: AsRef<str>>(section: &str, key: &str, file: ConfigFile, values: &[S]) -> UikaResult<()> {
+    let encoded = encode_array(values)?;
+    let file = file.as_str();
+    check_ffi(unsafe {
+        ffi_dispatch::config_set_array(
+            section.as_ptr(),
+            section.len() as u32,
+            key.as_ptr(),
+            key.len() as u32,
+            file.as_ptr(),
+            file.len() as u32,
+            encoded.as_ptr(),
+            encoded.len() as u32,
+        )
+    })
+}
+
+/// Write pending changes to `file` on disk.
+pub fn flush(file: ConfigFile) -> UikaResult<()> {
+    let file = file.as_str();
+    check_ffi(unsafe { ffi_dispatch::config_flush(file.as_ptr(), file.len() as u32) })
+}
+
+fn get_scalar<T: Default>(
+    section: &str,
+    key: &str,
+    file: ConfigFile,
+    getter: ScalarGetter<T>,
+) -> UikaResult<Option<T>> {
+    let file = file.as_str();
+    let mut out = T::default();
+    let code = unsafe {
+        getter(
+            section.as_ptr(),
+            section.len() as u32,
+            key.as_ptr(),
+            key.len() as u32,
+            file.as_ptr(),
+            file.len() as u32,
+            &mut out,
+        )
+    };
+    found(code, out)
+}
+
+/// Run a buffer getter, growing the buffer once if the value is larger.
+fn get_text(section: &str, key: &str, file: ConfigFile, getter: BufferGetter) -> UikaResult<Option<String>> {
+    let file = file.as_str();
+    let read = |buf: &mut Vec<u8>, out_len: &mut u32| unsafe {
+        getter(
+            section.as_ptr(),
+            section.len() as u32,
+            key.as_ptr(),
+            key.len() as u32,
+            file.as_ptr(),
+            file.len() as u32,
+            buf.as_mut_ptr(),
+            buf.len() as u32,
+            out_len,
+        )
+    };
+    let mut buf = vec![0u8; 256];
+    let mut out_len: u32 = 0;
+    let mut code = read(&mut buf, &mut out_len);
+    if code == UikaErrorCode::BufferTooSmall {
+        buf.resize(out_len as usize, 0);
+        code = read(&mut buf, &mut out_len);
+    }
+    let Some(mut buf) = found(code, buf)? else {
+        return Ok(None);
+    };
+    buf.truncate(out_len as usize);
+    String::from_utf8(buf)
+        .map(Some)
+        .map_err(|_| UikaError::Internal("config value is not valid UTF-8".into()))
+}
+
+/// Map a getter's result code: a missing key is `None`, not an error.
+fn found<T>(code: UikaErrorCode, value: T) -> UikaResult<Option<T>> {
+    if code == UikaErrorCode::PropertyNotFound {
+        return Ok(None);
+    }
+    check_ffi(code).map(|()| Some(value))
+}
+
+fn encode_array<S: AsRef<str>>(values: &[S]) -> UikaResult<String> {
+    let mut encoded = String::new();
+    for value in values {
+        let value = value.as_ref();
+        if value.contains('\n') {
+            return Err(UikaError::InvalidOperation(format!(
+                "config array value contains a newline: {value:?}"
+            )));
+        }
+        encoded.push_str(value);
+        encoded.push('\n');
+    }
+    Ok(encoded)
+}
+
+fn decode_array(text: &str) -> Vec<String> {
+    text.lines().map(str::to_string).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn array_round_trips_through_lines() {
+        let values = ["/Game/Maps/Arena", "", "/Game/Maps/Lobby"];
+        let encoded = encode_array(&values).unwrap();
+        assert_eq!(encoded, "/Game/Maps/Arena\n\n/Game/Maps/Lobby\n");
+        assert_eq!(decode_array(&encoded), values);
+        assert!(encode_array(&["a\nb"]).is_err());
+    }
+}
@@ -6,6 +6,8 @@
 use std::collections::{HashMap, HashSet};
 use std::hash::Hash;
 use std::marker::PhantomData;
+use std::ops::{Bound, RangeBounds};
+use std::sync::{Mutex, OnceLock};
 
 use uika_ffi::{FPropertyHandle, UObjectHandle, UikaErrorCode};
 
@@ -14,8 +16,10 @@ use uika_ffi::{FPropertyHandle, UObjectHandle, UikaErrorCode};
 /// Used for stack-allocated FFI transport buffers to avoid heap allocation.
 const MAX_ELEM_BUF: usize = 4096;
 
+use crate::class_ref::ClassRef;
 use crate::error::{check_ffi, ffi_infallible, UikaError, UikaResult};
 use crate::ffi_dispatch;
+use crate::lock_or_recover;
 use crate::object_ref::UObjectRef;
 use crate::struct_ref::UStructRef;
 use crate::traits::{UeClass, UeStruct};
@@ -141,6 +145,23 @@ unsafe impl<T: UeClass> ContainerElement for UObjectRef<T> {
     }}
 }
 
+// ClassRef<T>: the UClass object's handle, same transport as UObjectRef
+unsafe impl<T: UeClass> ContainerElement for ClassRef<T> {
+    const BUF_SIZE: u32 = std::mem::size_of::<UObjectHandle>() as u32;
+
+    #[inline]
+    unsafe fn read_from_buf(buf: *const u8, _written: u32) -> Self { unsafe {
+        let handle = (buf as *const UObjectHandle).read_unaligned();
+        ClassRef::from_raw(handle)
+    }}
+
+    #[inline]
+    unsafe fn write_to_buf(&self, buf: *mut u8) -> u32 { unsafe {
+        (buf as *mut UObjectHandle).write_unaligned(self.raw());
+        std::mem::size_of::<UObjectHandle>() as u32
+    }}
+}
+
 // String: C++ uses [u32 len][utf8 bytes] format
 unsafe impl ContainerElement for String {
     // Max buffer: 4 bytes length prefix + up to 4092 bytes of UTF-8 data
@@ -260,14 +281,10 @@ impl<T: ContainerElement> UeArray<T> {
         check_ffi(unsafe { ffi_dispatch::container_array_clear(self.owner, self.prop) })
     }
 
-    /// Returns an iterator over the elements.
+    /// Returns an iterator over the elements. Elements are fetched in
+    /// chunks, one FFI call per chunk, rather than one call per element.
     pub fn iter(&self) -> UeArrayIter<'_, T> {
-        let len = self.len().unwrap_or(0);
-        UeArrayIter {
-            array: self,
-            index: 0,
-            len,
-        }
+        UeArrayIter::new(UeArray::new(self.owner, self.prop))
     }
 }
 
@@ -280,27 +297,70 @@ impl<'a, T: ContainerElement> IntoIterator for &'a UeArray<T> {
     }
 }
 
+impl<T: ContainerElement> IntoIterator for UeArray<T> {
+    type Item = UikaResult<T>;
+    type IntoIter = UeArrayIter<'static, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        UeArrayIter::new(self)
+    }
+}
+
+/// Transport buffer budget for one chunk of [`UeArrayIter`].
+const ITER_CHUNK_BYTES: usize = 64 * 1024;
+
 /// Iterator over `UeArray<T>` elements.
+///
+/// The length is read once up front. Elements are copied out a chunk at a
+/// time; after an error the iterator yields `Err` once and then ends.
 pub struct UeArrayIter<'a, T: ContainerElement> {
-    array: &'a UeArray<T>,
+    array: UeArray<T>,
     index: usize,
     len: usize,
+    chunk: std::vec::IntoIter<T>,
+    _marker: PhantomData<&'a ()>,
+}
+
+impl<T: ContainerElement> UeArrayIter<'_, T> {
+    fn new(array: UeArray<T>) -> Self {
+        let len = array.len().unwrap_or(0);
+        UeArrayIter {
+            array,
+            index: 0,
+            len,
+            chunk: Vec::new().into_iter(),
+            _marker: PhantomData,
+        }
+    }
 }
 
 impl<T: ContainerElement> Iterator for UeArrayIter<'_, T> {
     type Item = UikaResult<T>;
 
     fn next(&mut self) -> Option<Self::Item> {
+        if let Some(elem) = self.chunk.next() {
+            return Some(Ok(elem));
+        }
         if self.index >= self.len {
             return None;
         }
-        let result = self.array.get(self.index);
-        self.index += 1;
-        Some(result)
+        let per_chunk = (ITER_CHUNK_BYTES / (T::BUF_SIZE as usize + 4)).max(1);
+        let count = per_chunk.min(self.len - self.index);
+        match self.array.read_range(self.index, count) {
+            Ok(elems) => {
+                self.index += count;
+                self.chunk = elems.into_iter();
+                self.chunk.next().map(Ok)
+            }
+            Err(e) => {
+                self.index = self.len;
+                Some(Err(e))
+            }
+        }
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        let remaining = self.len.saturating_sub(self.index);
+        let remaining = self.chunk.len() + self.len.saturating_sub(self.index);
         (remaining, Some(remaining))
     }
 }
@@ -356,6 +416,25 @@ pub struct BulkArrayIter<T: ContainerElement> {
     _marker: PhantomData<T>,
 }
 
+impl<T: ContainerElement> BulkArrayIter<T> {
+    /// Wrap a bulk-copy buffer. A negative `count` means the raw format.
+    fn decode(buf: Vec<u8>, count: i32) -> Self {
+        let (count, raw_elem_size) = if count < 0 {
+            ((-count) as usize, T::BUF_SIZE as usize)
+        } else {
+            (count as usize, 0)
+        };
+        BulkArrayIter {
+            buf,
+            count,
+            index: 0,
+            offset: 0,
+            raw_elem_size,
+            _marker: PhantomData,
+        }
+    }
+}
+
 impl<T: ContainerElement> Iterator for BulkArrayIter<T> {
     type Item = T;
 
@@ -495,74 +574,21 @@ impl<T: ContainerElement> ExactSizeIterator for BulkSetIter<T> {}
 impl<T: ContainerElement> UeArray<T> {
     /// Bulk-copy all elements to a `Vec<T>` in a single FFI call.
     pub fn to_vec(&self) -> UikaResult<Vec<T>> {
-        let len = self.len()?;
-        if len == 0 {
-            return Ok(Vec::new());
-        }
-        let owner = self.owner;
-        let prop = self.prop;
-        // Raw-copyable types have no per-element framing overhead
-        let estimate = if T::RAW_COPYABLE {
-            len * T::BUF_SIZE as usize
-        } else {
-            len * (T::BUF_SIZE as usize + 4)
-        };
-        let (buf, count) = bulk_copy_with_retry(estimate, |out, size, written, cnt| unsafe {
-            ffi_dispatch::container_array_copy_all(owner, prop, out, size, written, cnt)
-        })?;
-        // Negative count = raw format from C++
-        let (actual_count, raw_elem_size) = if count < 0 {
-            ((-count) as usize, T::BUF_SIZE as usize)
-        } else {
-            (count as usize, 0)
-        };
-        Ok(BulkArrayIter::<T> {
-            buf,
-            count: actual_count,
-            index: 0,
-            offset: 0,
-            raw_elem_size,
-            _marker: PhantomData,
-        }
-        .collect())
+        Ok(self.bulk_iter()?.collect())
     }
 
     /// Bulk-fetch all elements as a lazy iterator (single FFI call).
     pub fn bulk_iter(&self) -> UikaResult<BulkArrayIter<T>> {
         let len = self.len()?;
         if len == 0 {
-            return Ok(BulkArrayIter {
-                buf: Vec::new(),
-                count: 0,
-                index: 0,
-                offset: 0,
-                raw_elem_size: 0,
-                _marker: PhantomData,
-            });
+            return Ok(BulkArrayIter::decode(Vec::new(), 0));
         }
         let owner = self.owner;
         let prop = self.prop;
-        let estimate = if T::RAW_COPYABLE {
-            len * T::BUF_SIZE as usize
-        } else {
-            len * (T::BUF_SIZE as usize + 4)
-        };
-        let (buf, count) = bulk_copy_with_retry(estimate, |out, size, written, cnt| unsafe {
+        let (buf, count) = bulk_copy_with_retry(bulk_estimate::<T>(len), |out, size, written, cnt| unsafe {
             ffi_dispatch::container_array_copy_all(owner, prop, out, size, written, cnt)
         })?;
-        let (actual_count, raw_elem_size) = if count < 0 {
-            ((-count) as usize, T::BUF_SIZE as usize)
-        } else {
-            (count as usize, 0)
-        };
-        Ok(BulkArrayIter {
-            buf,
-            count: actual_count,
-            index: 0,
-            offset: 0,
-            raw_elem_size,
-            _marker: PhantomData,
-        })
+        Ok(BulkArrayIter::decode(buf, count))
     }
 
     /// Replace all array elements from a slice in a single FFI call.
@@ -570,45 +596,127 @@ impl<T: ContainerElement> UeArray<T> {
         if items.is_empty() {
             return self.clear();
         }
-        if T::RAW_COPYABLE {
-            // Raw format: contiguous elements, no per-element framing
-            let elem_size = T::BUF_SIZE as usize;
-            let mut buf = vec![0u8; items.len() * elem_size];
-            for (i, item) in items.iter().enumerate() {
-                unsafe { item.write_to_buf(buf.as_mut_ptr().add(i * elem_size)); }
-            }
-            // Negative count signals raw format to C++
+        let (buf, count) = encode_elements(items);
+        check_ffi(unsafe {
+            ffi_dispatch::container_array_set_all(self.owner, self.prop, buf.as_ptr(), buf.len() as u32, count)
+        })
+    }
+
+    /// Append every element of `items` in a single FFI call.
+    ///
+    /// ```ignore
+    /// actor.scores().extend([10, 20, 30])?;
+    /// ```
+    pub fn extend<I: IntoIterator<Item = T>>(&self, items: I) -> UikaResult<()> {
+        let items: Vec<T> = items.into_iter().collect();
+        if items.is_empty() {
+            return Ok(());
+        }
+        if !has_array_ext() {
+            return items.iter().try_for_each(|item| self.push(item));
+        }
+        let (buf, count) = encode_elements(&items);
+        check_ffi(unsafe {
+            ffi_dispatch::array_ext_append_all(self.owner, self.prop, buf.as_ptr(), buf.len() as u32, count)
+        })
+    }
+
+    /// Remove the elements in `range` and return them, in order.
+    /// `IndexOutOfRange` if the range doesn't fit the array.
+    ///
+    /// ```ignore
+    /// let oldest = actor.history().drain(..10)?;
+    /// ```
+    pub fn drain(&self, range: impl RangeBounds<usize>) -> UikaResult<Vec<T>> {
+        let len = self.len()?;
+        let start = match range.start_bound() {
+            Bound::Included(&i) => i,
+            Bound::Excluded(&i) => i.checked_add(1).ok_or(UikaError::IndexOutOfRange)?,
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&i) => i.checked_add(1).ok_or(UikaError::IndexOutOfRange)?,
+            Bound::Excluded(&i) => i,
+            Bound::Unbounded => len,
+        };
+        if start > end || end > len {
+            return Err(UikaError::IndexOutOfRange);
+        }
+        if start == end {
+            return Ok(Vec::new());
+        }
+        let drained = self.read_range(start, end - start)?;
+        if has_array_ext() {
             check_ffi(unsafe {
-                ffi_dispatch::container_array_set_all(
-                    self.owner,
-                    self.prop,
-                    buf.as_ptr(),
-                    buf.len() as u32,
-                    -(items.len() as i32),
-                )
-            })
+                ffi_dispatch::array_ext_remove_range(self.owner, self.prop, start as i32, (end - start) as i32)
+            })?;
         } else {
-            // Framed format: [u32 written][data] per element
-            let mut buf = Vec::with_capacity(items.len() * (T::BUF_SIZE as usize + 4));
-            let mut elem_buf = [0u8; MAX_ELEM_BUF];
-            for item in items {
-                let written = unsafe { item.write_to_buf(elem_buf.as_mut_ptr()) };
-                buf.extend_from_slice(&written.to_ne_bytes());
-                buf.extend_from_slice(&elem_buf[..written as usize]);
+            for _ in start..end {
+                self.remove(start)?;
             }
-            check_ffi(unsafe {
-                ffi_dispatch::container_array_set_all(
-                    self.owner,
-                    self.prop,
-                    buf.as_ptr(),
-                    buf.len() as u32,
-                    items.len() as i32,
-                )
-            })
         }
+        Ok(drained)
+    }
+
+    /// Copy `count` elements starting at `start` in one FFI call. Falls back
+    /// to per-element reads on plugins without the array range API.
+    fn read_range(&self, start: usize, count: usize) -> UikaResult<Vec<T>> {
+        if !has_array_ext() {
+            return (start..start + count).map(|i| self.get(i)).collect();
+        }
+        let owner = self.owner;
+        let prop = self.prop;
+        let (buf, n) = bulk_copy_with_retry(bulk_estimate::<T>(count), |out, size, written, cnt| unsafe {
+            ffi_dispatch::array_ext_copy_range(owner, prop, start as i32, count as i32, out, size, written, cnt)
+        })?;
+        Ok(BulkArrayIter::decode(buf, n).collect())
     }
 }
 
+/// Whether the loaded plugin provides `UikaArrayExtApi` (API version 13+).
+fn has_array_ext() -> bool {
+    !crate::api::api().array_ext.is_null()
+}
+
+/// Transport buffer size guess for `len` elements of `T`.
+fn bulk_estimate<T: ContainerElement>(len: usize) -> usize {
+    // Raw-copyable types have no per-element framing overhead
+    if T::RAW_COPYABLE {
+        len * T::BUF_SIZE as usize
+    } else {
+        len * (T::BUF_SIZE as usize + 4)
+    }
+}
+
+/// Encode elements in the bulk write format. Returns the buffer and the count
+/// to pass along with it (negative for the raw format).
+fn encode_elements<T: ContainerElement>(items: &[T]) -> (Vec<u8>, i32) {
+    if T::RAW_COPYABLE {
+        // Raw format: contiguous elements, no per-element framing
+        let elem_size = T::BUF_SIZE as usize;
+        let mut buf = vec![0u8; items.len() * elem_size];
+        for (i, item) in items.iter().enumerate() {
+            unsafe { item.write_to_buf(buf.as_mut_ptr().add(i * elem_size)); }
+        }
+        // Negative count signals raw format to C++
+        (buf, -(items.len() as i32))
+    } else {
+        (encode_framed(items), items.len() as i32)
+    }
+}
+
+/// Encode elements in the framed format: `[u32 written][data]` per element.
+fn encode_framed<T: ContainerElement>(items: &[T]) -> Vec<u8> {
+    let mut buf = Vec::with_capacity(items.len() * (T::BUF_SIZE as usize + 4));
+    let mut elem_buf = [0u8; MAX_ELEM_BUF];
+    for item in items {
+        let written = unsafe { item.write_to_buf(elem_buf.as_mut_ptr()) };
+        buf.extend_from_slice(&written.to_ne_bytes());
+        buf.extend_from_slice(&elem_buf[..written as usize]);
+    }
+    buf
+}
+
 // ---------------------------------------------------------------------------
 // UeMap<K, V>
 // ---------------------------------------------------------------------------
@@ -733,14 +841,175 @@ impl<K: ContainerElement, V: ContainerElement> UeMap<K, V> {
         })
     }
 
-    /// Returns an iterator over key-value pairs.
+    /// Returns an iterator over key-value pairs. Pairs are fetched in chunks,
+    /// one FFI call per chunk.
     pub fn iter(&self) -> UeMapIter<'_, K, V> {
-        let len = self.len().unwrap_or(0);
-        UeMapIter {
-            map: self,
+        UeMapIter::new(UeMap::new(self.owner, self.prop))
+    }
+
+    /// Look up a value by key; `None` if the key is not in the map.
+    pub fn get_by_key(&self, key: &K) -> UikaResult<Option<V>> {
+        match self.find(key) {
+            Ok(val) => Ok(Some(val)),
+            Err(UikaError::PropertyNotFound(_)) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Whether `key` is in the map, without copying its value out.
+    pub fn contains_key(&self, key: &K) -> UikaResult<bool> {
+        if !has_map_ext() {
+            return Ok(self.get_by_key(key)?.is_some());
+        }
+        let mut key_buf = [0u8; MAX_ELEM_BUF];
+        let key_written = unsafe { key.write_to_buf(key_buf.as_mut_ptr()) };
+        Ok(unsafe {
+            ffi_dispatch::map_ext_contains_key(self.owner, self.prop, key_buf.as_ptr(), key_written)
+        })
+    }
+
+    /// Remove `key` and return its value; `None` if the key was absent.
+    pub fn remove_by_key(&self, key: &K) -> UikaResult<Option<V>> {
+        if !has_map_ext() {
+            let val = self.get_by_key(key)?;
+            if val.is_some() {
+                self.remove(key)?;
+            }
+            return Ok(val);
+        }
+        let mut key_buf = [0u8; MAX_ELEM_BUF];
+        let key_written = unsafe { key.write_to_buf(key_buf.as_mut_ptr()) };
+        let mut val_buf = [0u8; MAX_ELEM_BUF];
+        let mut val_written: u32 = 0;
+
+        let code = unsafe {
+            ffi_dispatch::map_ext_remove_and_copy(
+                self.owner,
+                self.prop,
+                key_buf.as_ptr(),
+                key_written,
+                val_buf.as_mut_ptr(),
+                V::BUF_SIZE,
+                &mut val_written,
+            )
+        };
+        if code == UikaErrorCode::PropertyNotFound {
+            return Ok(None);
+        }
+        check_ffi(code)?;
+        Ok(Some(unsafe { V::read_from_buf(val_buf.as_ptr(), val_written) }))
+    }
+
+    /// The entry for `key`, for in-place upserts.
+    ///
+    /// ```ignore
+    /// let kills = actor.kill_counts();
+    /// kills.entry(player_name).and_modify(|n| *n += 1)?.or_insert(1)?;
+    /// ```
+    pub fn entry(&self, key: K) -> MapEntry<'_, K, V> {
+        MapEntry { map: self, key }
+    }
+
+    /// Copy `count` pairs starting at logical index `start` in one FFI call.
+    /// Falls back to per-pair reads on plugins without the map key API.
+    fn read_range(&self, start: usize, count: usize) -> UikaResult<Vec<(K, V)>> {
+        if !has_map_ext() {
+            return (start..start + count).map(|i| self.get_pair(i)).collect();
+        }
+        let owner = self.owner;
+        let prop = self.prop;
+        let estimate = count * (K::BUF_SIZE as usize + V::BUF_SIZE as usize + 8);
+        let (buf, n) = bulk_copy_with_retry(estimate, |out, size, written, cnt| unsafe {
+            ffi_dispatch::map_ext_copy_range(owner, prop, start as i32, count as i32, out, size, written, cnt)
+        })?;
+        Ok(BulkMapIter::<K, V> {
+            buf,
+            count: n as usize,
             index: 0,
-            len,
+            offset: 0,
+            _marker: PhantomData,
         }
+        .collect())
+    }
+}
+
+/// Whether the loaded plugin provides `UikaMapExtApi` (API version 14+).
+fn has_map_ext() -> bool {
+    !crate::api::api().map_ext.is_null()
+}
+
+/// A key's slot in a [`UeMap`], returned by [`UeMap::entry`].
+///
+/// Values are copies, so modifications are written back to the map by the
+/// entry methods rather than through a reference.
+pub struct MapEntry<'a, K: ContainerElement, V: ContainerElement> {
+    map: &'a UeMap<K, V>,
+    key: K,
+}
+
+impl<K: ContainerElement, V: ContainerElement> MapEntry<'_, K, V> {
+    pub fn key(&self) -> &K {
+        &self.key
+    }
+
+    /// The current value, if the key is present.
+    pub fn get(&self) -> UikaResult<Option<V>> {
+        self.map.get_by_key(&self.key)
+    }
+
+    /// Return the value, inserting `default` first if the key is absent.
+    pub fn or_insert(self, default: V) -> UikaResult<V> {
+        if !has_map_ext() {
+            return self.or_insert_with(|| default);
+        }
+        let mut key_buf = [0u8; MAX_ELEM_BUF];
+        let key_written = unsafe { self.key.write_to_buf(key_buf.as_mut_ptr()) };
+        let mut default_buf = [0u8; MAX_ELEM_BUF];
+        let default_written = unsafe { default.write_to_buf(default_buf.as_mut_ptr()) };
+        let mut val_buf = [0u8; MAX_ELEM_BUF];
+        let mut val_written: u32 = 0;
+        let mut added = false;
+
+        check_ffi(unsafe {
+            ffi_dispatch::map_ext_find_or_add(
+                self.map.owner,
+                self.map.prop,
+                key_buf.as_ptr(),
+                key_written,
+                default_buf.as_ptr(),
+                default_written,
+                val_buf.as_mut_ptr(),
+                V::BUF_SIZE,
+                &mut val_written,
+                &mut added,
+            )
+        })?;
+        Ok(unsafe { V::read_from_buf(val_buf.as_ptr(), val_written) })
+    }
+
+    /// Return the value, inserting `default()` first if the key is absent.
+    /// `default` only runs when needed.
+    pub fn or_insert_with(self, default: impl FnOnce() -> V) -> UikaResult<V> {
+        if let Some(val) = self.get()? {
+            return Ok(val);
+        }
+        let val = default();
+        self.map.add(&self.key, &val)?;
+        Ok(val)
+    }
+
+    /// If the key is present, apply `f` to its value and write it back.
+    pub fn and_modify(self, f: impl FnOnce(&mut V)) -> UikaResult<Self> {
+        if let Some(mut val) = self.get()? {
+            f(&mut val);
+            self.map.add(&self.key, &val)?;
+        }
+        Ok(self)
+    }
+
+    /// Set the value, replacing any existing one.
+    pub fn insert(self, value: V) -> UikaResult<()> {
+        self.map.add(&self.key, &value)
     }
 }
 
@@ -753,27 +1022,67 @@ impl<'a, K: ContainerElement, V: ContainerElement> IntoIterator for &'a UeMap<K,
     }
 }
 
+impl<K: ContainerElement, V: ContainerElement> IntoIterator for UeMap<K, V> {
+    type Item = UikaResult<(K, V)>;
+    type IntoIter = UeMapIter<'static, K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        UeMapIter::new(self)
+    }
+}
+
 /// Iterator over `UeMap<K, V>` key-value pairs.
+///
+/// Chunked like [`UeArrayIter`]; after an error it yields `Err` once and
+/// then ends.
 pub struct UeMapIter<'a, K: ContainerElement, V: ContainerElement> {
-    map: &'a UeMap<K, V>,
+    map: UeMap<K, V>,
     index: usize,
     len: usize,
+    chunk: std::vec::IntoIter<(K, V)>,
+    _marker: PhantomData<&'a ()>,
+}
+
+impl<K: ContainerElement, V: ContainerElement> UeMapIter<'_, K, V> {
+    fn new(map: UeMap<K, V>) -> Self {
+        let len = map.len().unwrap_or(0);
+        UeMapIter {
+            map,
+            index: 0,
+            len,
+            chunk: Vec::new().into_iter(),
+            _marker: PhantomData,
+        }
+    }
 }
 
 impl<K: ContainerElement, V: ContainerElement> Iterator for UeMapIter<'_, K, V> {
     type Item = UikaResult<(K, V)>;
 
     fn next(&mut self) -> Option<Self::Item> {
+        if let Some(pair) = self.chunk.next() {
+            return Some(Ok(pair));
+        }
         if self.index >= self.len {
             return None;
         }
-        let result = self.map.get_pair(self.index);
-        self.index += 1;
-        Some(result)
+        let pair_size = K::BUF_SIZE as usize + V::BUF_SIZE as usize + 8;
+        let count = (ITER_CHUNK_BYTES / pair_size).max(1).min(self.len - self.index);
+        match self.map.read_range(self.index, count) {
+            Ok(pairs) => {
+                self.index += count;
+                self.chunk = pairs.into_iter();
+                self.chunk.next().map(Ok)
+            }
+            Err(e) => {
+                self.index = self.len;
+                Some(Err(e))
+            }
+        }
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        let remaining = self.len.saturating_sub(self.index);
+        let remaining = self.chunk.len() + self.len.saturating_sub(self.index);
         (remaining, Some(remaining))
     }
 }
@@ -990,6 +1299,96 @@ impl<T: ContainerElement> UeSet<T> {
     }
 }
 
+// ---------------------------------------------------------------------------
+// UeSet algebra
+// ---------------------------------------------------------------------------
+
+impl<T: ContainerElement> UeSet<T> {
+    /// Add every element of this set to `target`. Elements are copied
+    /// UE-side without passing through Rust.
+    pub fn union_into(&self, target: &UeSet<T>) -> UikaResult<()> {
+        if !has_set_ext() {
+            return self.bulk_iter()?.try_for_each(|elem| target.add(&elem));
+        }
+        check_ffi(unsafe {
+            ffi_dispatch::set_ext_union_into(self.owner, self.prop, target.owner, target.prop)
+        })
+    }
+
+    /// The elements of this set that are also in `other`.
+    pub fn intersection(&self, other: &UeSet<T>) -> UikaResult<Vec<T>> {
+        self.compare(other, true)
+    }
+
+    /// The elements of this set that are not in `other`.
+    ///
+    /// ```ignore
+    /// let to_unlock = wanted.difference(&owned)?;
+    /// ```
+    pub fn difference(&self, other: &UeSet<T>) -> UikaResult<Vec<T>> {
+        self.compare(other, false)
+    }
+
+    /// Whether each of `items` is in the set, in order, from one FFI call.
+    pub fn contains_each(&self, items: &[T]) -> UikaResult<Vec<bool>> {
+        if !has_set_ext() {
+            return items.iter().map(|item| self.contains(item)).collect();
+        }
+        let buf = encode_framed(items);
+        let mut found = vec![false; items.len()];
+        check_ffi(unsafe {
+            ffi_dispatch::set_ext_contains_each(
+                self.owner,
+                self.prop,
+                buf.as_ptr(),
+                buf.len() as u32,
+                items.len() as i32,
+                found.as_mut_ptr(),
+            )
+        })?;
+        Ok(found)
+    }
+
+    /// Elements of this set that are (`in_other`) or aren't in `other`.
+    fn compare(&self, other: &UeSet<T>, in_other: bool) -> UikaResult<Vec<T>> {
+        if !has_set_ext() {
+            let mut kept = Vec::new();
+            for elem in self.bulk_iter()? {
+                if other.contains(&elem)? == in_other {
+                    kept.push(elem);
+                }
+            }
+            return Ok(kept);
+        }
+        let len = self.len()?;
+        if len == 0 {
+            return Ok(Vec::new());
+        }
+        let call = if in_other {
+            ffi_dispatch::set_ext_intersection
+        } else {
+            ffi_dispatch::set_ext_difference
+        };
+        let estimate = len * (T::BUF_SIZE as usize + 4);
+        let (buf, count) = bulk_copy_with_retry(estimate, |out, size, written, cnt| unsafe {
+            call(self.owner, self.prop, other.owner, other.prop, out, size, written, cnt)
+        })?;
+        Ok(BulkSetIter::<T> {
+            buf,
+            count: count as usize,
+            index: 0,
+            offset: 0,
+            _marker: PhantomData,
+        }
+        .collect())
+    }
+}
+
+/// Whether the loaded plugin provides `UikaSetExtApi` (API version 15+).
+fn has_set_ext() -> bool {
+    !crate::api::api().set_ext.is_null()
+}
+
 impl<T: ContainerElement + Hash + Eq> UeSet<T> {
     /// Bulk-copy all elements to a `HashSet` in a single FFI call.
     pub fn to_hash_set(&self) -> UikaResult<HashSet<T>> {
@@ -1007,6 +1406,23 @@ impl<T: ContainerElement + Hash + Eq> UeSet<T> {
 // OwnedStruct<T>: owned copy of struct data from a container
 // ---------------------------------------------------------------------------
 
+/// Size in bytes of the UE struct named `name` (without its `F` prefix), as
+/// reflection reports it, for structs the bindings have no type for. Looked
+/// up once per name; 0 if there is no such struct.
+pub fn struct_size_by_name(name: &'static str) -> usize {
+    static SIZES: OnceLock<Mutex<HashMap<&'static str, usize>>> = OnceLock::new();
+    let sizes = SIZES.get_or_init(|| Mutex::new(HashMap::new()));
+    if let Some(&size) = lock_or_recover(sizes).get(name) {
+        return size;
+    }
+    let size = unsafe {
+        let ustruct = ffi_dispatch::reflection_find_struct(name.as_ptr(), name.len() as u32);
+        if ustruct.is_null() { 0 } else { ffi_dispatch::reflection_get_struct_size(ustruct) as usize }
+    };
+    lock_or_recover(sizes).insert(name, size);
+    size
+}
+
 /// An owned copy of UE struct data retrieved from a container.
 ///
 /// Since UE structs are opaque (their layout is managed by C++), this type
@@ -1019,6 +1435,11 @@ pub struct OwnedStruct<T: UeStruct> {
 }
 
 impl<T: UeStruct> OwnedStruct<T> {
+    /// Size in bytes of `T` as UE reflection reports it.
+    pub fn size() -> usize {
+        unsafe { ffi_dispatch::reflection_get_struct_size(T::static_struct()) as usize }
+    }
+
     /// Allocate a new struct initialized via C++ default constructor.
     ///
     /// Uses the UE reflection system to determine the struct's size,
@@ -1026,13 +1447,12 @@ impl<T: UeStruct> OwnedStruct<T> {
     /// to properly construct non-trivial members (TArray, FString, etc.).
     /// The struct is destroyed via `UScriptStruct::DestroyStruct` on drop.
     pub fn new() -> Self {
-        let ustruct = T::static_struct();
-        let size = unsafe { ffi_dispatch::reflection_get_struct_size(ustruct) };
+        let size = Self::size();
         debug_assert!(size > 0, "get_struct_size returned 0 for {}", std::any::type_name::<T>());
 
-        let mut data = vec![0u8; size as usize];
+        let mut data = vec![0u8; size];
         ffi_infallible(unsafe {
-            ffi_dispatch::reflection_initialize_struct(ustruct, data.as_mut_ptr())
+            ffi_dispatch::reflection_initialize_struct(T::static_struct(), data.as_mut_ptr())
         });
         OwnedStruct {
             data,
@@ -1063,6 +1483,10 @@ impl<T: UeStruct> OwnedStruct<T> {
         &self.data
     }
 
+    pub(crate) fn as_bytes_mut(&mut self) -> &mut [u8] {
+        &mut self.data
+    }
+
     /// Get the raw bytes of the struct data as an owned Vec.
     pub fn to_bytes(&self) -> Vec<u8> {
         self.data.clone()
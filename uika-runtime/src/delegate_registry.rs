@@ -3,6 +3,7 @@
 // which looks up and calls the registered closure.
 
 use std::collections::HashMap;
+use std::marker::PhantomData;
 use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::{Mutex, OnceLock};
 
@@ -10,8 +11,10 @@ use crate::lock_or_recover;
 
 use uika_ffi::{FPropertyHandle, UObjectHandle, UikaErrorCode};
 
-use crate::error::{check_ffi, UikaResult};
+use crate::error::{check_ffi, UikaError, UikaResult};
 use crate::ffi_dispatch::NativePtr;
+use crate::object_ref::UObjectRef;
+use crate::traits::UeClass;
 
 type DelegateCallback = Option<Box<dyn FnMut(NativePtr) + Send>>;
 
@@ -34,6 +37,11 @@ pub fn unregister_callback(id: u64) {
     lock_or_recover(registry()).remove(&id);
 }
 
+/// Check whether a callback ID is still registered.
+pub fn is_registered(id: u64) -> bool {
+    lock_or_recover(registry()).contains_key(&id)
+}
+
 /// Clear all callbacks and reset the ID counter.
 /// Called during shutdown before DLL unload (enables hot reload).
 pub fn clear_all() {
@@ -73,19 +81,54 @@ pub fn invoke(callback_id: u64, params: NativePtr) {
     }
 }
 
+// ---------------------------------------------------------------------------
+// DelegateParams — typed decoding of delegate params buffers
+// ---------------------------------------------------------------------------
+
+/// Decodes a delegate's ProcessEvent params buffer into a typed value that is
+/// handed to the bound closure.
+///
+/// `NativePtr` (the raw buffer) and `()` (ignore all params) are provided;
+/// the generated `*Payload` structs implement this for concrete delegate
+/// signatures.
+pub trait DelegateParams: Sized + 'static {
+    /// Decode the params buffer.
+    ///
+    /// # Safety
+    /// `params` must point to a params buffer laid out for the delegate's
+    /// signature function, valid for the duration of the call.
+    unsafe fn from_params(params: NativePtr) -> Self;
+}
+
+impl DelegateParams for NativePtr {
+    #[inline]
+    unsafe fn from_params(params: NativePtr) -> Self {
+        params
+    }
+}
+
+impl DelegateParams for () {
+    #[inline]
+    unsafe fn from_params(_params: NativePtr) -> Self {}
+}
+
 // ---------------------------------------------------------------------------
 // DelegateBinding — RAII handle for delegate bindings
 // ---------------------------------------------------------------------------
 
 /// RAII handle that unbinds a delegate and unregisters the callback on drop.
-pub struct DelegateBinding {
+///
+/// `T` is the [`DelegateParams`] type the bound closure receives; untyped
+/// bindings (the default) receive the raw params buffer.
+pub struct DelegateBinding<T = NativePtr> {
     callback_id: u64,
     owner: UObjectHandle,
     prop: FPropertyHandle,
     is_multicast: bool,
+    _marker: PhantomData<fn(T)>,
 }
 
-impl DelegateBinding {
+impl<T> DelegateBinding<T> {
     /// Create a new binding handle. Should only be called by generated code.
     pub fn new(
         callback_id: u64,
@@ -98,6 +141,7 @@ impl DelegateBinding {
             owner,
             prop,
             is_multicast,
+            _marker: PhantomData,
         }
     }
 
@@ -106,18 +150,26 @@ impl DelegateBinding {
         self.callback_id
     }
 
+    /// Whether the callback is still registered and its owner is still alive.
+    /// Returns `false` once the owning object has been destroyed, since UE
+    /// drops the delegate together with its owner.
+    pub fn is_bound(&self) -> bool {
+        is_registered(self.callback_id)
+            && unsafe { crate::ffi_dispatch::core_is_valid(self.owner) }
+    }
+
     /// Manually unbind without waiting for drop. Consumes self.
     pub fn unbind(self) {
         // Drop will handle the cleanup.
     }
 }
 
-impl Drop for DelegateBinding {
+impl<T> Drop for DelegateBinding<T> {
     fn drop(&mut self) {
         // Unregister from Rust registry.
         unregister_callback(self.callback_id);
 
-        // Unbind on the C++ side.
+        // Unbind on the C++ side (the C++ side ignores destroyed owners).
         if crate::api::is_api_initialized() {
             unsafe {
                 if self.is_multicast {
@@ -138,13 +190,27 @@ impl Drop for DelegateBinding {
 // High-level bind helpers (used by generated code)
 // ---------------------------------------------------------------------------
 
-/// Bind a Rust closure to a unicast delegate property.
-pub fn bind_unicast(
+/// Register `callback` so that it receives the params buffer decoded as `T`.
+///
+/// # Safety
+/// The callback may only be bound to delegates whose signature is `T`'s.
+unsafe fn register_typed<T: DelegateParams>(mut callback: impl FnMut(T) + Send + 'static) -> u64 {
+    register_callback(move |params| callback(unsafe { T::from_params(params) }))
+}
+
+/// Bind a Rust closure to a unicast delegate property. The closure receives
+/// the params decoded as `T` (the raw buffer for `NativePtr`).
+///
+/// # Safety
+/// `prop` must be a delegate property of `owner`'s class and `T` the payload
+/// of its signature (`NativePtr` and `()` fit any signature). Generated
+/// delegate wrappers guarantee this.
+pub unsafe fn bind_unicast<T: DelegateParams>(
     owner: UObjectHandle,
     prop: FPropertyHandle,
-    callback: impl FnMut(NativePtr) + Send + 'static,
-) -> UikaResult<DelegateBinding> {
-    let id = register_callback(callback);
+    callback: impl FnMut(T) + Send + 'static,
+) -> UikaResult<DelegateBinding<T>> {
+    let id = unsafe { register_typed(callback) };
     let result = unsafe { crate::ffi_dispatch::delegate_bind_delegate(owner, prop, id) };
     if result != UikaErrorCode::Ok {
         unregister_callback(id);
@@ -153,13 +219,17 @@ pub fn bind_unicast(
     Ok(DelegateBinding::new(id, owner, prop, false))
 }
 
-/// Add a Rust closure to a multicast delegate property.
-pub fn bind_multicast(
+/// Add a Rust closure to a multicast delegate property. The closure receives
+/// the params decoded as `T` (the raw buffer for `NativePtr`).
+///
+/// # Safety
+/// As for [`bind_unicast`].
+pub unsafe fn bind_multicast<T: DelegateParams>(
     owner: UObjectHandle,
     prop: FPropertyHandle,
-    callback: impl FnMut(NativePtr) + Send + 'static,
-) -> UikaResult<DelegateBinding> {
-    let id = register_callback(callback);
+    callback: impl FnMut(T) + Send + 'static,
+) -> UikaResult<DelegateBinding<T>> {
+    let id = unsafe { register_typed(callback) };
     let result = unsafe { crate::ffi_dispatch::delegate_add_multicast(owner, prop, id) };
     if result != UikaErrorCode::Ok {
         unregister_callback(id);
@@ -167,3 +237,35 @@ pub fn bind_multicast(
     }
     Ok(DelegateBinding::new(id, owner, prop, true))
 }
+
+/// Add a typed Rust closure to a multicast delegate property, looked up by
+/// name on the owner's class. The closure receives the params decoded as `T`.
+///
+/// ```ignore
+/// let binding = unsafe { bind_multicast_typed::<(), _>(actor, "OnDestroyed", |()| { ... }) }?;
+/// ```
+///
+/// # Safety
+/// The property is found by name only; its signature is not checked. `T`
+/// must be the payload of that delegate's signature. `NativePtr` and `()`
+/// fit any signature.
+pub unsafe fn bind_multicast_typed<T: DelegateParams, C: UeClass>(
+    owner: UObjectRef<C>,
+    prop_name: &str,
+    callback: impl FnMut(T) + Send + 'static,
+) -> UikaResult<DelegateBinding<T>> {
+    let h = owner.checked()?.raw();
+    let prop = find_delegate_property(h, prop_name)?;
+    unsafe { bind_multicast(h, prop, callback) }
+}
+
+fn find_delegate_property(owner: UObjectHandle, prop_name: &str) -> UikaResult<FPropertyHandle> {
+    let prop = unsafe {
+        let class = crate::ffi_dispatch::core_get_class(owner);
+        crate::ffi_dispatch::reflection_find_property(class, prop_name.as_ptr(), prop_name.len() as u32)
+    };
+    if prop.is_null() {
+        return Err(UikaError::PropertyNotFound(prop_name.into()));
+    }
+    Ok(prop)
+}
@@ -4,12 +4,21 @@
 // direct call path. It uses UE's reflection system to find functions, allocate
 // parameter buffers, set/get parameter values, and invoke via ProcessEvent.
 
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Waker};
+
 use uika_ffi::{FPropertyHandle, UFunctionHandle, UObjectHandle};
 
+use crate::containers::{ContainerElement, OwnedStruct, UeArray};
+use crate::delegate_registry::{register_callback, unregister_callback};
 use crate::error::{check_ffi, UikaError, UikaResult};
 use crate::ffi_dispatch::{self, NativePtr, NATIVE_PTR_NULL, native_ptr_is_null};
+use crate::field::{check_field_type, FieldValue};
+use crate::lock_or_recover;
 use crate::object_ref::UObjectRef;
-use crate::traits::UeClass;
+use crate::traits::{UeClass, UeStruct};
 
 /// Builder for a reflection-based function call.
 ///
@@ -20,6 +29,10 @@ use crate::traits::UeClass;
 /// let result = call.call()?;
 /// let ret: f32 = result.get::<f32>("ReturnValue")?;
 /// ```
+///
+/// Struct and array parameters go through [`set_struct`](Self::set_struct) /
+/// [`set_array`](Self::set_array), which check the parameter's reflected type
+/// instead of trusting the caller.
 pub struct DynamicCall {
     obj: UObjectHandle,
     func: UFunctionHandle,
@@ -61,6 +74,46 @@ impl DynamicCall {
         Ok(())
     }
 
+    /// Copy a struct into the named parameter. `TypeMismatch` unless the
+    /// parameter is a `T`.
+    pub fn set_struct<T: UeStruct>(&mut self, name: &str, value: &OwnedStruct<T>) -> UikaResult<()> {
+        let prop = find_param(self.func, name)?;
+        check_field_type::<OwnedStruct<T>>(prop)?;
+        unsafe { value.write(params_handle(self.params), prop) }
+    }
+
+    /// Fill the named `TArray` parameter with `items`.
+    pub fn set_array<T: ContainerElement>(&mut self, name: &str, items: &[T]) -> UikaResult<()> {
+        let prop = find_param(self.func, name)?;
+        UeArray::<T>::new(params_handle(self.params), prop).set_all(items)
+    }
+
+    /// Set the parameters named in `names` from the tuple `args`, call, and
+    /// read `ReturnValue` as `R` (nothing is read when `R` is `()`). The
+    /// tuple's arity must match the number of names at compile time.
+    ///
+    /// ```ignore
+    /// let dist: f32 = DynamicCall::new(&actor, "GetDistanceTo")?
+    ///     .call_typed::<(UObjectHandle,), f32>(["OtherActor"], (other.raw(),))?;
+    /// ```
+    ///
+    /// Every argument and `R` is checked against the reflected parameter
+    /// type first: `TypeMismatch` if one doesn't match.
+    pub fn call_typed<A: DynamicArgs, R: DynamicReturn>(mut self, names: A::Names, args: A) -> UikaResult<R> {
+        args.set_all(&mut self, names)?;
+        let result = self.call()?;
+        R::read_return(&result)
+    }
+
+    /// Write `value` into the named parameter through the property API.
+    /// `TypeMismatch` unless the parameter holds an `F`.
+    fn set_field<F: FieldValue>(&mut self, name: &str, value: &F) -> UikaResult<()> {
+        let prop = find_param(self.func, name)?;
+        check_field_type::<F>(prop)?;
+        // SAFETY: the params buffer holds `prop`, whose type was just checked.
+        unsafe { value.write(params_handle(self.params), prop) }
+    }
+
     /// Invoke the function via ProcessEvent. Consumes this builder and returns
     /// a `DynamicCallResult` for reading output/return values.
     pub fn call(mut self) -> UikaResult<DynamicCallResult> {
@@ -77,19 +130,118 @@ impl DynamicCall {
         Ok(result)
     }
 
-    /// Look up a named parameter and return its property handle + offset.
-    fn find_param(&self, name: &str) -> UikaResult<(FPropertyHandle, u32)> {
-        let prop = unsafe {
-            ffi_dispatch::reflection_get_function_param(self.func, name.as_ptr(), name.len() as u32)
+    /// Call a latent function (one taking an `FLatentActionInfo`, like
+    /// `Delay` or `MoveComponentTo`), filling the parameter `latent_info`.
+    /// The returned future completes when the action does, with the
+    /// parameters for reading outputs.
+    ///
+    /// ```ignore
+    /// let mut call = DynamicCall::new(&system_library, "Delay")?;
+    /// call.set("WorldContextObject", actor.raw())?;
+    /// call.set("Duration", 1.5f32)?;
+    /// call.call_latent("LatentInfo")?.await;
+    /// ```
+    pub fn call_latent(mut self, latent_info: &str) -> UikaResult<LatentCall> {
+        let prop = find_param(self.func, latent_info)?;
+        let shared = Arc::new(Mutex::new(LatentShared::default()));
+        let callback_id = {
+            let shared = Arc::clone(&shared);
+            register_callback(move |_params| {
+                let mut shared = lock_or_recover(&shared);
+                shared.done = true;
+                if let Some(waker) = shared.waker.take() {
+                    waker.wake();
+                }
+            })
         };
-        if prop.is_null() {
-            return Err(UikaError::PropertyNotFound(name.to_string()));
+        let bound = check_ffi(unsafe { ffi_dispatch::latent_bind_latent_info(self.params, prop, callback_id) })
+            .and_then(|()| check_ffi(unsafe {
+                ffi_dispatch::reflection_call_function(self.obj, self.func, self.params)
+            }));
+        if let Err(e) = bound {
+            unregister_callback(callback_id);
+            unsafe { ffi_dispatch::latent_release(callback_id) };
+            return Err(e);
         }
+        let result = DynamicCallResult { func: self.func, params: self.params };
+        self.params = NATIVE_PTR_NULL;
+        Ok(LatentCall { callback_id, shared, result: Some(result) })
+    }
+
+    /// Look up a named parameter and return its property handle + offset.
+    fn find_param(&self, name: &str) -> UikaResult<(FPropertyHandle, u32)> {
+        let prop = find_param(self.func, name)?;
         let offset = unsafe { ffi_dispatch::reflection_get_property_offset(prop) };
         Ok((prop, offset))
     }
 }
 
+fn find_param(func: UFunctionHandle, name: &str) -> UikaResult<FPropertyHandle> {
+    let prop = unsafe { ffi_dispatch::reflection_get_function_param(func, name.as_ptr(), name.len() as u32) };
+    if prop.is_null() {
+        return Err(UikaError::PropertyNotFound(name.to_string()));
+    }
+    Ok(prop)
+}
+
+/// The params buffer as a property container: the property and container
+/// APIs address values by offset, so they work on it like on an object.
+fn params_handle(params: NativePtr) -> UObjectHandle {
+    UObjectHandle(params.cast())
+}
+
+/// A tuple of [`FieldValue`] arguments for [`DynamicCall::call_typed`], with
+/// one parameter name per element.
+pub trait DynamicArgs {
+    /// `[&str; N]` for an N-tuple.
+    type Names;
+
+    fn set_all(self, call: &mut DynamicCall, names: Self::Names) -> UikaResult<()>;
+}
+
+macro_rules! dynamic_args {
+    ($n:literal; $($arg:ident $idx:tt),*) => {
+        impl<$($arg: FieldValue),*> DynamicArgs for ($($arg,)*) {
+            type Names = [&'static str; $n];
+
+            #[allow(unused_variables)]
+            fn set_all(self, call: &mut DynamicCall, names: Self::Names) -> UikaResult<()> {
+                $(call.set_field(names[$idx], &self.$idx)?;)*
+                Ok(())
+            }
+        }
+    };
+}
+
+dynamic_args!(0;);
+dynamic_args!(1; A 0);
+dynamic_args!(2; A 0, B 1);
+dynamic_args!(3; A 0, B 1, C 2);
+dynamic_args!(4; A 0, B 1, C 2, D 3);
+dynamic_args!(5; A 0, B 1, C 2, D 3, E 4);
+dynamic_args!(6; A 0, B 1, C 2, D 3, E 4, F 5);
+
+/// The return type of [`DynamicCall::call_typed`]: a [`FieldValue`] read from
+/// `ReturnValue`, or `()` for functions without one.
+pub trait DynamicReturn: Sized {
+    fn read_return(result: &DynamicCallResult) -> UikaResult<Self>;
+}
+
+impl DynamicReturn for () {
+    fn read_return(_result: &DynamicCallResult) -> UikaResult<()> {
+        Ok(())
+    }
+}
+
+impl<F: FieldValue> DynamicReturn for F {
+    fn read_return(result: &DynamicCallResult) -> UikaResult<F> {
+        let prop = find_param(result.func, "ReturnValue")?;
+        check_field_type::<F>(prop)?;
+        // SAFETY: the params buffer holds `prop`, whose type was just checked.
+        unsafe { F::read(params_handle(result.params), prop) }
+    }
+}
+
 impl Drop for DynamicCall {
     fn drop(&mut self) {
         if !native_ptr_is_null(self.params) {
@@ -111,18 +263,27 @@ impl DynamicCallResult {
     /// # Safety contract
     /// `T` must match the actual UE property type at the named parameter.
     pub fn get<T: Copy>(&self, name: &str) -> UikaResult<T> {
-        let prop = unsafe {
-            ffi_dispatch::reflection_get_function_param(self.func, name.as_ptr(), name.len() as u32)
-        };
-        if prop.is_null() {
-            return Err(UikaError::PropertyNotFound(name.to_string()));
-        }
+        let prop = find_param(self.func, name)?;
         let offset = unsafe { ffi_dispatch::reflection_get_property_offset(prop) };
         // SAFETY: The offset is provided by UE reflection and the caller
         // guarantees T matches the property type.
         let value = unsafe { ffi_dispatch::native_mem_read(self.params, offset as usize) };
         Ok(value)
     }
+
+    /// Copy a struct output parameter or return value. `TypeMismatch` unless
+    /// the parameter is a `T`.
+    pub fn get_struct<T: UeStruct>(&self, name: &str) -> UikaResult<OwnedStruct<T>> {
+        let prop = find_param(self.func, name)?;
+        check_field_type::<OwnedStruct<T>>(prop)?;
+        unsafe { OwnedStruct::<T>::read(params_handle(self.params), prop) }
+    }
+
+    /// Copy a `TArray` output parameter or return value.
+    pub fn get_array<T: ContainerElement>(&self, name: &str) -> UikaResult<Vec<T>> {
+        let prop = find_param(self.func, name)?;
+        UeArray::<T>::new(params_handle(self.params), prop).to_vec()
+    }
 }
 
 impl Drop for DynamicCallResult {
@@ -132,3 +293,59 @@ impl Drop for DynamicCallResult {
         }
     }
 }
+
+#[derive(Default)]
+struct LatentShared {
+    done: bool,
+    waker: Option<Waker>,
+}
+
+/// A latent function call in progress, from [`DynamicCall::call_latent`].
+///
+/// Dropping it before completion stops waiting but leaks the parameter
+/// buffer: the engine may still write outputs into it.
+#[must_use = "futures do nothing unless awaited"]
+pub struct LatentCall {
+    callback_id: u64,
+    shared: Arc<Mutex<LatentShared>>,
+    result: Option<DynamicCallResult>,
+}
+
+impl LatentCall {
+    /// Whether the latent action has completed.
+    pub fn is_done(&self) -> bool {
+        lock_or_recover(&self.shared).done
+    }
+}
+
+impl Future for LatentCall {
+    type Output = DynamicCallResult;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<DynamicCallResult> {
+        {
+            let mut shared = lock_or_recover(&self.shared);
+            if !shared.done {
+                shared.waker = Some(cx.waker().clone());
+                return Poll::Pending;
+            }
+        }
+        match self.result.take() {
+            Some(result) => Poll::Ready(result),
+            None => panic!("LatentCall polled after completion"),
+        }
+    }
+}
+
+impl Drop for LatentCall {
+    fn drop(&mut self) {
+        unregister_callback(self.callback_id);
+        if crate::api::is_api_initialized() {
+            unsafe { ffi_dispatch::latent_release(self.callback_id) };
+        }
+        if !self.is_done()
+            && let Some(result) = self.result.take()
+        {
+            std::mem::forget(result);
+        }
+    }
+}
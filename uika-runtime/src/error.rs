@@ -1,6 +1,7 @@
 // Error types for the Uika runtime.
 
 use std::fmt;
+use std::panic::Location;
 
 use uika_ffi::UikaErrorCode;
 
@@ -17,6 +18,98 @@ pub enum UikaError {
     InvalidOperation(String),
     Internal(String),
     BufferTooSmall,
+    /// Another error with context attached by [`UikaError::context`] or
+    /// [`UikaError::in_ffi_function`]; the runtime never adds it on its own.
+    /// Match on [`UikaError::kind`] to see through it.
+    Context(Box<ErrorContext>),
+}
+
+/// Where an error came from and what the code was doing at the time.
+#[derive(Debug)]
+pub struct ErrorContext {
+    /// The underlying error; never itself a `Context`.
+    pub error: UikaError,
+    /// Context messages, innermost first.
+    pub context: Vec<String>,
+    /// The FFI function that reported the error, when known.
+    pub ffi_function: Option<String>,
+    /// Where the error was first given context.
+    pub location: Option<&'static Location<'static>>,
+}
+
+impl UikaError {
+    /// The underlying error, looking through any attached context.
+    ///
+    /// ```ignore
+    /// if let Err(e) = spawn_wave() && matches!(e.kind(), UikaError::ObjectDestroyed) { ... }
+    /// ```
+    pub fn kind(&self) -> &UikaError {
+        match self {
+            UikaError::Context(ctx) => &ctx.error,
+            other => other,
+        }
+    }
+
+    /// Attach a context message describing what was being done. The first
+    /// call also records the caller's location.
+    #[track_caller]
+    pub fn context(self, context: impl Into<String>) -> UikaError {
+        let mut ctx = self.into_context();
+        ctx.context.push(context.into());
+        UikaError::Context(ctx)
+    }
+
+    /// Record the FFI function that reported this error.
+    #[track_caller]
+    pub fn in_ffi_function(self, name: impl Into<String>) -> UikaError {
+        let mut ctx = self.into_context();
+        ctx.ffi_function = Some(name.into());
+        UikaError::Context(ctx)
+    }
+
+    #[track_caller]
+    fn into_context(self) -> Box<ErrorContext> {
+        match self {
+            UikaError::Context(ctx) => ctx,
+            error => Box::new(ErrorContext {
+                error,
+                context: Vec::new(),
+                ffi_function: None,
+                location: Some(Location::caller()),
+            }),
+        }
+    }
+}
+
+/// `.context(..)` on a [`UikaResult`].
+///
+/// ```ignore
+/// let enemy = world.spawn_actor::<AEnemy>(&transform).context("spawning enemy wave")?;
+/// ```
+pub trait UikaResultExt<T> {
+    /// Attach `context` to the error, see [`UikaError::context`].
+    fn context(self, context: impl Into<String>) -> UikaResult<T>;
+
+    /// Like [`context`](Self::context), building the message only on error.
+    fn with_context<S: Into<String>>(self, f: impl FnOnce() -> S) -> UikaResult<T>;
+}
+
+impl<T> UikaResultExt<T> for UikaResult<T> {
+    #[track_caller]
+    fn context(self, context: impl Into<String>) -> UikaResult<T> {
+        match self {
+            Ok(value) => Ok(value),
+            Err(e) => Err(e.context(context)),
+        }
+    }
+
+    #[track_caller]
+    fn with_context<S: Into<String>>(self, f: impl FnOnce() -> S) -> UikaResult<T> {
+        match self {
+            Ok(value) => Ok(value),
+            Err(e) => Err(e.context(f())),
+        }
+    }
 }
 
 impl fmt::Display for UikaError {
@@ -32,11 +125,30 @@ impl fmt::Display for UikaError {
             UikaError::InvalidOperation(msg) => write!(f, "invalid operation: {msg}"),
             UikaError::Internal(msg) => write!(f, "internal error: {msg}"),
             UikaError::BufferTooSmall => write!(f, "buffer too small"),
+            UikaError::Context(ctx) => {
+                for context in ctx.context.iter().rev() {
+                    write!(f, "{context}: ")?;
+                }
+                write!(f, "{}", ctx.error)?;
+                match (&ctx.ffi_function, ctx.location) {
+                    (Some(func), Some(at)) => write!(f, " (in {func}, at {at})"),
+                    (Some(func), None) => write!(f, " (in {func})"),
+                    (None, Some(at)) => write!(f, " (at {at})"),
+                    (None, None) => Ok(()),
+                }
+            }
         }
     }
 }
 
-impl std::error::Error for UikaError {}
+impl std::error::Error for UikaError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            UikaError::Context(ctx) => Some(&ctx.error),
+            _ => None,
+        }
+    }
+}
 
 /// Convenience alias used throughout the runtime and generated code.
 pub type UikaResult<T> = Result<T, UikaError>;
@@ -50,7 +162,9 @@ pub fn check_ffi(code: UikaErrorCode) -> UikaResult<()> {
     }
 }
 
-/// Like `check_ffi`, but enriches property/function errors with the given name.
+/// Like `check_ffi`, but enriches property/function errors with the given
+/// name. Errors stay bare variants, so `Err(UikaError::ObjectDestroyed)`
+/// patterns keep matching.
 pub fn check_ffi_ctx(code: UikaErrorCode, context: &str) -> UikaResult<()> {
     match code {
         UikaErrorCode::Ok => Ok(()),
@@ -86,6 +200,22 @@ pub fn ffi_infallible_ctx(code: UikaErrorCode, ctx: &str) {
     );
 }
 
+/// Assert that a generated call with string or struct outputs returned `Ok`.
+/// Struct buffers are sized from reflection and oversized strings are taken
+/// from the output stash, so a valid call never fails here; unlike
+/// [`ffi_infallible`] this still panics in release builds, because outputs
+/// that came back truncated must not be used.
+#[track_caller]
+pub fn ffi_outputs_complete(code: UikaErrorCode, ctx: &str) {
+    assert_eq!(
+        code,
+        UikaErrorCode::Ok,
+        "FFI '{}' returned {:?}; its outputs did not fit",
+        ctx,
+        code
+    );
+}
+
 impl From<UikaErrorCode> for UikaError {
     #[allow(clippy::match_same_arms)]
     fn from(code: UikaErrorCode) -> Self {
@@ -141,6 +271,32 @@ mod tests {
         }
     }
 
+    #[test]
+    fn check_ffi_ctx_returns_bare_variants() {
+        assert!(matches!(check_ffi_ctx(UikaErrorCode::ObjectDestroyed, "Health"), Err(UikaError::ObjectDestroyed)));
+        assert!(matches!(
+            check_ffi_ctx(UikaErrorCode::PropertyNotFound, "Health"),
+            Err(UikaError::PropertyNotFound(name)) if name == "Health"
+        ));
+    }
+
+    #[test]
+    fn context_chains_and_keeps_the_kind() {
+        let line = line!() + 1;
+        let err = check_ffi(UikaErrorCode::ObjectDestroyed).map_err(|e| e.in_ffi_function("property_get_i32"))
+            .context("reading health")
+            .with_context(|| format!("spawning wave {}", 3))
+            .unwrap_err();
+        assert!(matches!(err.kind(), UikaError::ObjectDestroyed));
+        let msg = err.to_string();
+        assert!(
+            msg.starts_with("spawning wave 3: reading health: object has been destroyed (in property_get_i32, at "),
+            "{msg}"
+        );
+        assert!(msg.contains(&format!("{}:{line}:", file!())), "{msg}");
+        assert!(std::error::Error::source(&err).is_some());
+    }
+
     #[test]
     fn display_formats_are_human_readable() {
         let err = UikaError::PropertyNotFound("Health".into());
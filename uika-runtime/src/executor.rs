@@ -0,0 +1,328 @@
+// Async executor for game-thread code: write latent logic (wait, load,
+// wait again) as an `async` block instead of a hand-written state machine.
+//
+// Futures are polled on the game thread from the plugin's per-frame tick,
+// so they may hold UObject references and call into UE freely; they don't
+// need to be `Send`. A spawned future is first polled on the next tick.
+//
+// ```ignore
+// executor::spawn_local(async move {
+//     executor::delay(2.0).await;
+//     let mesh = SoftObjectPtr::<UStaticMesh>::new(path).load_async()?.await?;
+//     ...
+// });
+// ```
+//
+// On shutdown (and hot reload) every pending future is dropped.
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Wake, Waker};
+
+use crate::error::UikaResult;
+use crate::lock_or_recover;
+use crate::object_ref::UObjectRef;
+use crate::timers::{set_timer, TimerHandle, TimerOptions};
+use crate::traits::UeClass;
+
+type LocalFuture = Pin<Box<dyn Future<Output = ()>>>;
+
+/// Tasks woken since the last tick. Shared with wakers, which may be
+/// invoked from any thread.
+#[derive(Default)]
+struct ReadyQueue(Mutex<Vec<u64>>);
+
+struct TaskWaker {
+    id: u64,
+    ready: Arc<ReadyQueue>,
+}
+
+impl Wake for TaskWaker {
+    fn wake(self: Arc<Self>) {
+        self.wake_by_ref();
+    }
+
+    fn wake_by_ref(self: &Arc<Self>) {
+        lock_or_recover(&self.ready.0).push(self.id);
+    }
+}
+
+struct Task {
+    future: LocalFuture,
+    state: Arc<TaskState>,
+}
+
+#[derive(Default)]
+struct Executor {
+    tasks: HashMap<u64, Task>,
+    next_id: u64,
+    /// Seconds of tick time since the executor started.
+    now: f64,
+    timers: Vec<(f64, Waker)>,
+    frame_waiters: Vec<Waker>,
+    ready: Arc<ReadyQueue>,
+}
+
+thread_local! {
+    // The game thread's executor. Other threads get their own, which is
+    // only ever driven by tests.
+    static EXECUTOR: RefCell<Executor> = RefCell::new(Executor::default());
+}
+
+/// Handle to a future started with [`spawn_local`]. Dropping it does not
+/// cancel the future.
+#[derive(Clone, Debug)]
+pub struct LocalTask {
+    state: Arc<TaskState>,
+}
+
+#[derive(Debug, Default)]
+struct TaskState {
+    finished: AtomicBool,
+    cancelled: AtomicBool,
+}
+
+impl LocalTask {
+    /// Whether the future has completed (or was cancelled and dropped).
+    pub fn is_finished(&self) -> bool {
+        self.state.finished.load(Ordering::Acquire)
+    }
+
+    /// Drop the future on the next tick without polling it again. Pending
+    /// loads, timers and latent calls it was waiting on are released.
+    /// Safe to call from any thread.
+    pub fn cancel(&self) {
+        self.state.cancelled.store(true, Ordering::Release);
+    }
+}
+
+/// Run `future` on the game thread, polled from the per-frame tick.
+pub fn spawn_local(future: impl Future<Output = ()> + 'static) -> LocalTask {
+    let state = Arc::new(TaskState::default());
+    EXECUTOR.with_borrow_mut(|ex| {
+        let id = ex.next_id;
+        ex.next_id += 1;
+        ex.tasks.insert(id, Task { future: Box::pin(future), state: Arc::clone(&state) });
+        lock_or_recover(&ex.ready.0).push(id);
+    });
+    LocalTask { state }
+}
+
+/// Advance the clock by `delta_seconds` and poll every woken future. Called
+/// once per frame from the plugin's tick.
+pub fn tick(delta_seconds: f32) {
+    let (woken, cancelled, ready) = EXECUTOR.with_borrow_mut(|ex| {
+        ex.now += f64::from(delta_seconds);
+        let now = ex.now;
+        let mut woken = std::mem::take(&mut ex.frame_waiters);
+        let (due, pending) = std::mem::take(&mut ex.timers).into_iter().partition(|(at, _)| *at <= now);
+        ex.timers = pending;
+        woken.extend(due.into_iter().map(|(_, w): (f64, Waker)| w));
+
+        let cancelled: Vec<u64> =
+            ex.tasks.iter().filter(|(_, t)| t.state.cancelled.load(Ordering::Acquire)).map(|(id, _)| *id).collect();
+        let cancelled: Vec<Task> = cancelled.iter().filter_map(|id| ex.tasks.remove(id)).collect();
+        (woken, cancelled, Arc::clone(&ex.ready))
+    });
+    // Dropped outside the borrow: their destructors may call back in.
+    for task in cancelled {
+        task.state.finished.store(true, Ordering::Release);
+    }
+    woken.into_iter().for_each(Waker::wake);
+
+    let mut ids = std::mem::take(&mut *lock_or_recover(&ready.0));
+    ids.sort_unstable();
+    ids.dedup();
+    for id in ids {
+        // Out of the map while polling, so the future can spawn or await.
+        let Some(mut task) = EXECUTOR.with_borrow_mut(|ex| ex.tasks.remove(&id)) else {
+            continue;
+        };
+        let waker = Waker::from(Arc::new(TaskWaker { id, ready: Arc::clone(&ready) }));
+        let poll = crate::ffi_boundary(Poll::Ready(()), std::panic::AssertUnwindSafe(|| {
+            task.future.as_mut().poll(&mut Context::from_waker(&waker))
+        }));
+        match poll {
+            Poll::Ready(()) => task.state.finished.store(true, Ordering::Release),
+            Poll::Pending => {
+                EXECUTOR.with_borrow_mut(|ex| ex.tasks.insert(id, task));
+            }
+        }
+    }
+}
+
+/// Drop every pending future. Called during on_shutdown.
+pub fn clear_all() {
+    let tasks = EXECUTOR.with_borrow_mut(|ex| {
+        ex.timers.clear();
+        ex.frame_waiters.clear();
+        lock_or_recover(&ex.ready.0).clear();
+        std::mem::take(&mut ex.tasks)
+    });
+    // Dropped outside the borrow: their destructors may call back in.
+    drop(tasks);
+}
+
+/// Completes `seconds` after it is first polled, measured in frame time
+/// (not affected by pause or time dilation; see [`world_delay`]).
+pub fn delay(seconds: f32) -> Delay {
+    Delay { seconds: f64::from(seconds), deadline: None }
+}
+
+/// Future returned by [`delay`].
+#[must_use = "futures do nothing unless awaited"]
+pub struct Delay {
+    seconds: f64,
+    deadline: Option<f64>,
+}
+
+impl Future for Delay {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        let seconds = self.seconds;
+        EXECUTOR.with_borrow_mut(|ex| {
+            let deadline = *self.deadline.get_or_insert(ex.now + seconds);
+            if ex.now >= deadline {
+                return Poll::Ready(());
+            }
+            ex.timers.push((deadline, cx.waker().clone()));
+            Poll::Pending
+        })
+    }
+}
+
+/// Completes on the next tick.
+pub fn next_frame() -> NextFrame {
+    NextFrame { yielded: false }
+}
+
+/// Future returned by [`next_frame`].
+#[must_use = "futures do nothing unless awaited"]
+pub struct NextFrame {
+    yielded: bool,
+}
+
+impl Future for NextFrame {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        if self.yielded {
+            return Poll::Ready(());
+        }
+        self.yielded = true;
+        EXECUTOR.with_borrow_mut(|ex| ex.frame_waiters.push(cx.waker().clone()));
+        Poll::Pending
+    }
+}
+
+/// Completes after `seconds` of game time in `world_context`'s world, using
+/// a world timer: it respects pause and time dilation, like Blueprint's
+/// Delay node. Dropping the future clears the timer.
+pub fn world_delay<C: UeClass>(world_context: UObjectRef<C>, seconds: f32) -> UikaResult<WorldDelay> {
+    let shared = Arc::new(Mutex::new(TimerShared::default()));
+    let timer = {
+        let shared = Arc::clone(&shared);
+        set_timer(world_context, TimerOptions::once(seconds), move || {
+            let mut shared = lock_or_recover(&shared);
+            shared.fired = true;
+            if let Some(waker) = shared.waker.take() {
+                waker.wake();
+            }
+        })?
+    };
+    Ok(WorldDelay { timer: Some(timer), shared })
+}
+
+#[derive(Default)]
+struct TimerShared {
+    fired: bool,
+    waker: Option<Waker>,
+}
+
+/// Future returned by [`world_delay`].
+#[must_use = "futures do nothing unless awaited"]
+pub struct WorldDelay {
+    timer: Option<TimerHandle>,
+    shared: Arc<Mutex<TimerShared>>,
+}
+
+impl Future for WorldDelay {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        let mut shared = lock_or_recover(&self.shared);
+        if shared.fired {
+            drop(shared);
+            // A one-shot timer is already gone; nothing left to clear.
+            self.timer = None;
+            return Poll::Ready(());
+        }
+        shared.waker = Some(cx.waker().clone());
+        Poll::Pending
+    }
+}
+
+impl Drop for WorldDelay {
+    fn drop(&mut self) {
+        if let Some(timer) = self.timer.take()
+            && crate::api::is_api_initialized()
+        {
+            let _ = timer.clear();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    #[test]
+    fn futures_advance_with_ticks() {
+        let steps = Rc::new(Cell::new(0));
+        let task = spawn_local({
+            let steps = Rc::clone(&steps);
+            async move {
+                steps.set(1);
+                next_frame().await;
+                steps.set(2);
+                delay(1.0).await;
+                steps.set(3);
+            }
+        });
+        assert_eq!(steps.get(), 0, "first poll happens on tick");
+        tick(0.5);
+        assert_eq!(steps.get(), 1);
+        tick(0.5);
+        assert_eq!(steps.get(), 2);
+        tick(0.5);
+        assert_eq!(steps.get(), 2);
+        tick(0.6);
+        assert_eq!(steps.get(), 3);
+        assert!(task.is_finished());
+    }
+
+    #[test]
+    fn cancelled_tasks_are_dropped() {
+        let steps = Rc::new(Cell::new(0));
+        let task = spawn_local({
+            let steps = Rc::clone(&steps);
+            async move {
+                delay(1.0).await;
+                steps.set(1);
+            }
+        });
+        tick(0.1);
+        task.cancel();
+        tick(0.1);
+        assert!(task.is_finished());
+        tick(2.0);
+        assert_eq!(steps.get(), 0);
+    }
+}
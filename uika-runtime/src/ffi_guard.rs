@@ -1,14 +1,110 @@
 // FFI boundary guard: wraps Rust callbacks to catch panics before they
 // cross the FFI boundary (which is undefined behavior).
 
+use std::cell::RefCell;
+use std::sync::atomic::{AtomicBool, AtomicU8, Ordering};
+
 use crate::api::is_api_initialized;
 
+/// What [`ffi_boundary`] does after catching a panic. Every policy logs the
+/// panic message, location and (see [`set_capture_backtraces`]) backtrace
+/// to `LogUika` first.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+#[repr(u8)]
+pub enum PanicPolicy {
+    /// Return the default value and carry on.
+    #[default]
+    LogAndContinue = 0,
+    /// As `LogAndContinue`, and stop calling the functions of the Rust class
+    /// whose function panicked (panics elsewhere just continue). The class
+    /// stays disabled until the DLL is reloaded.
+    DisableClass = 1,
+    /// Report the panic as a UE ensure (sent to the crash reporter, breaks
+    /// into an attached debugger), then continue.
+    Ensure = 2,
+    /// Crash the process through UE's fatal error path, so the crash reporter
+    /// collects the panic message.
+    Crash = 3,
+}
+
+static POLICY: AtomicU8 = AtomicU8::new(PanicPolicy::LogAndContinue as u8);
+static CAPTURE_BACKTRACES: AtomicBool = AtomicBool::new(true);
+
+/// Set the policy for panics caught at the FFI boundary. Usually called once
+/// at startup, e.g. `Crash` in tests and CI, `Ensure` in development.
+pub fn set_panic_policy(policy: PanicPolicy) {
+    POLICY.store(policy as u8, Ordering::Relaxed);
+}
+
+/// The current [`PanicPolicy`].
+pub fn panic_policy() -> PanicPolicy {
+    match POLICY.load(Ordering::Relaxed) {
+        1 => PanicPolicy::DisableClass,
+        2 => PanicPolicy::Ensure,
+        3 => PanicPolicy::Crash,
+        _ => PanicPolicy::LogAndContinue,
+    }
+}
+
+/// Whether the panic hook captures a backtrace (on by default). Capturing is
+/// slow; turn it off if panics are part of normal control flow.
+pub fn set_capture_backtraces(capture: bool) {
+    CAPTURE_BACKTRACES.store(capture, Ordering::Relaxed);
+}
+
+/// What the panic hook recorded about the last panic on this thread.
+pub(crate) struct PanicReport {
+    /// Payload text and location.
+    pub(crate) message: String,
+    backtrace: Option<String>,
+}
+
+thread_local! {
+    static LAST_PANIC: RefCell<Option<PanicReport>> = const { RefCell::new(None) };
+}
+
+/// Install a panic hook recording the location and backtrace of each panic
+/// for [`ffi_boundary`] to log (the payload alone has neither). The previous
+/// hook still runs. Called by `uika::init`.
+pub fn install_panic_hook() {
+    let previous = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        let location = info.location().map(|l| format!(" at {}:{}", l.file(), l.line())).unwrap_or_default();
+        let message = format!("{}{location}", payload_text(info.payload()));
+        let backtrace = CAPTURE_BACKTRACES
+            .load(Ordering::Relaxed)
+            .then(|| std::backtrace::Backtrace::force_capture().to_string());
+        // The hook can run while the slot is borrowed (a panic in a Drop
+        // during reporting); losing that report is fine.
+        let _ = LAST_PANIC.try_with(|last| {
+            if let Ok(mut last) = last.try_borrow_mut() {
+                *last = Some(PanicReport { message, backtrace });
+            }
+        });
+        previous(info);
+    }));
+}
+
+/// Take what the panic hook recorded about the last panic on this thread.
+/// Every place that catches a panic takes it, so it is never attributed to
+/// a later panic (one re-raised with `resume_unwind` skips the hook).
+pub(crate) fn take_panic_report() -> Option<PanicReport> {
+    LAST_PANIC.with_borrow_mut(Option::take)
+}
+
+/// Record `report`, taken on another thread, before re-raising its panic on
+/// this one.
+pub(crate) fn restore_panic_report(report: Option<PanicReport>) {
+    LAST_PANIC.with_borrow_mut(|last| *last = report);
+}
+
 /// Execute `f` and catch any panic, returning `default` on failure.
 ///
 /// All `extern "C"` functions called by C++ should wrap their body in this
 /// guard. A panic that escapes across FFI is instant UB; this prevents that.
 ///
-/// If the API table is initialized, the panic message is logged via UE_LOG.
+/// If the API table is initialized, the panic is logged via UE_LOG and then
+/// handled according to the [`PanicPolicy`].
 pub fn ffi_boundary<F, R>(default: R, f: F) -> R
 where
     F: FnOnce() -> R + std::panic::UnwindSafe,
@@ -16,32 +112,59 @@ where
     match std::panic::catch_unwind(f) {
         Ok(value) => value,
         Err(payload) => {
-            // Best-effort logging. If the API isn't initialized yet, we can't
-            // log through UE, so the panic is silently swallowed (still better
-            // than UB).
+            let report = take_panic_report();
+            // Before init we can't log through UE, so the panic is silently
+            // swallowed (still better than UB).
             if is_api_initialized() {
-                let msg = panic_message(&payload);
-                let bytes = msg.as_bytes();
-                unsafe {
-                    crate::ffi_dispatch::logging_log(2, bytes.as_ptr(), bytes.len() as u32);
-                }
+                report_panic(&payload, report);
             }
             default
         }
     }
 }
 
-/// Extract a human-readable message from a panic payload.
-fn panic_message(payload: &Box<dyn std::any::Any + Send>) -> String {
+fn report_panic(payload: &Box<dyn std::any::Any + Send>, report: Option<PanicReport>) {
+    let (message, backtrace) = match report {
+        Some(report) => (format!("[Uika] Rust panic: {}", report.message), report.backtrace),
+        None => (panic_message(payload), None),
+    };
+    crate::logging::log_category("LogUika", crate::logging::LOG_ERROR, &message);
+    if let Some(backtrace) = backtrace {
+        crate::logging::log_category("LogUika", crate::logging::LOG_ERROR, &format!("[Uika] backtrace:\n{backtrace}"));
+    }
+    let bytes = message.as_bytes();
+    match panic_policy() {
+        PanicPolicy::LogAndContinue | PanicPolicy::DisableClass => {}
+        PanicPolicy::Ensure => {
+            if !crate::api::api().panic.is_null() {
+                unsafe { crate::ffi_dispatch::panic_report_ensure(bytes.as_ptr(), bytes.len() as u32) };
+            }
+        }
+        PanicPolicy::Crash => {
+            if !crate::api::api().panic.is_null() {
+                unsafe { crate::ffi_dispatch::panic_fatal(bytes.as_ptr(), bytes.len() as u32) };
+            }
+            // `fatal` doesn't return; a plugin without it gets a plain abort.
+            std::process::abort();
+        }
+    }
+}
+
+fn payload_text(payload: &(dyn std::any::Any + Send)) -> &str {
     if let Some(s) = payload.downcast_ref::<&str>() {
-        format!("[Uika] Rust panic: {s}")
+        s
     } else if let Some(s) = payload.downcast_ref::<String>() {
-        format!("[Uika] Rust panic: {s}")
+        s
     } else {
-        "[Uika] Rust panic (unknown payload)".to_string()
+        "(unknown payload)"
     }
 }
 
+/// Extract a human-readable message from a panic payload.
+fn panic_message(payload: &Box<dyn std::any::Any + Send>) -> String {
+    format!("[Uika] Rust panic: {}", payload_text(payload.as_ref()))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
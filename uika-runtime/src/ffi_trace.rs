@@ -0,0 +1,199 @@
+// FFI call tracing (feature = "ffi-trace").
+//
+// With the feature enabled, build.rs wraps every ffi_dispatch function in an
+// `enter()` guard that times the call and hands an `FfiCall` record to the
+// installed sink on drop. The default sink reports calls slower than the
+// configured threshold to UE's log under `LogUikaFfi`, so slow bridge calls
+// show up in the editor's Output Log. Install a custom sink to forward calls
+// into a profiler instead. With the `tracing` feature as well, each call also
+// runs inside a trace-level `ffi` span (fields `module` and `function`), so
+// subscribers see UE calls nested under the spans that made them, and
+// `SlowFfiSpanLayer` reports slow `ffi` spans to `LogUikaFfi` from a
+// tracing-subscriber stack.
+//
+// Calls made while a sink is running (e.g. the log call the default sink
+// itself makes) are not traced, so sinks may freely call back into UE.
+
+use std::cell::Cell;
+use std::sync::RwLock;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant};
+
+use crate::{read_or_recover, write_or_recover};
+
+/// One completed call through the API table.
+#[derive(Clone, Copy, Debug)]
+pub struct FfiCall {
+    /// Sub-table name, e.g. `"property"`.
+    pub module: &'static str,
+    /// Function name within the sub-table, e.g. `"get_f32"`.
+    pub function: &'static str,
+    pub duration: Duration,
+}
+
+/// Receives every traced call. Runs on the calling thread, so keep it cheap.
+pub type TraceSink = fn(&FfiCall);
+
+static SINK: RwLock<TraceSink> = RwLock::new(log_slow_calls);
+static SLOW_THRESHOLD_NS: AtomicU64 = AtomicU64::new(100_000);
+
+thread_local! {
+    static IN_SINK: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Replace the trace sink. The default is [`log_slow_calls`].
+pub fn set_sink(sink: TraceSink) {
+    *write_or_recover(&SINK) = sink;
+}
+
+/// Minimum duration for [`log_slow_calls`] to report a call (default 100 µs).
+pub fn set_slow_threshold(threshold: Duration) {
+    SLOW_THRESHOLD_NS.store(threshold.as_nanos() as u64, Ordering::Relaxed);
+}
+
+/// The default sink: log calls at or above the slow threshold to `LogUikaFfi`.
+pub fn log_slow_calls(call: &FfiCall) {
+    log_slow(call.module, call.function, call.duration);
+}
+
+/// Guard returned by [`enter`]; reports the call to the sink when dropped.
+pub struct CallGuard {
+    module: &'static str,
+    function: &'static str,
+    start: Instant,
+    #[cfg(feature = "tracing")]
+    _span: tracing::span::EnteredSpan,
+}
+
+/// Start timing a call. Used by the generated ffi_dispatch wrappers.
+#[inline]
+pub fn enter(module: &'static str, function: &'static str) -> CallGuard {
+    CallGuard {
+        module,
+        function,
+        start: Instant::now(),
+        #[cfg(feature = "tracing")]
+        _span: tracing::trace_span!("ffi", module, function).entered(),
+    }
+}
+
+impl Drop for CallGuard {
+    fn drop(&mut self) {
+        let duration = self.start.elapsed();
+        let Some(_scope) = SinkScope::enter() else {
+            return;
+        };
+        let sink = *read_or_recover(&SINK);
+        sink(&FfiCall { module: self.module, function: self.function, duration });
+    }
+}
+
+/// Marks the current thread as reporting a call; clears the mark on drop,
+/// so a panicking sink does not leave tracing off for the thread.
+struct SinkScope;
+
+impl SinkScope {
+    /// `None` if the thread is already reporting a call.
+    fn enter() -> Option<SinkScope> {
+        if IN_SINK.with(|f| f.replace(true)) { None } else { Some(SinkScope) }
+    }
+}
+
+impl Drop for SinkScope {
+    fn drop(&mut self) {
+        IN_SINK.with(|f| f.set(false));
+    }
+}
+
+fn log_slow(module: &str, function: &str, duration: Duration) {
+    let threshold = Duration::from_nanos(SLOW_THRESHOLD_NS.load(Ordering::Relaxed));
+    if duration >= threshold {
+        let msg = format!(
+            "slow FFI call {}::{} took {:.3} ms",
+            module,
+            function,
+            duration.as_secs_f64() * 1000.0
+        );
+        crate::logging::log_category("LogUikaFfi", crate::logging::LOG_WARNING, &msg);
+    }
+}
+
+/// A `tracing-subscriber` layer logging `ffi` spans that last at least the
+/// slow threshold (see [`set_slow_threshold`]) to `LogUikaFfi`. It reports
+/// the same calls as [`log_slow_calls`], so install a quieter sink with it.
+///
+/// ```ignore
+/// use tracing_subscriber::prelude::*;
+/// ffi_trace::set_sink(|_| {});
+/// tracing_subscriber::registry().with(SlowFfiSpanLayer::default()).init();
+/// ```
+#[cfg(feature = "tracing")]
+#[derive(Default)]
+pub struct SlowFfiSpanLayer {
+    open: std::sync::Mutex<std::collections::HashMap<tracing_core::span::Id, OpenSpan>>,
+}
+
+#[cfg(feature = "tracing")]
+struct OpenSpan {
+    module: String,
+    function: String,
+    start: Instant,
+}
+
+#[cfg(feature = "tracing")]
+impl tracing_core::field::Visit for OpenSpan {
+    fn record_str(&mut self, field: &tracing_core::Field, value: &str) {
+        match field.name() {
+            "module" => self.module = value.to_string(),
+            "function" => self.function = value.to_string(),
+            _ => {}
+        }
+    }
+
+    fn record_debug(&mut self, field: &tracing_core::Field, value: &dyn std::fmt::Debug) {
+        self.record_str(field, &format!("{value:?}"));
+    }
+}
+
+#[cfg(feature = "tracing")]
+impl<S: tracing_core::Subscriber> tracing_subscriber::Layer<S> for SlowFfiSpanLayer {
+    fn on_new_span(
+        &self,
+        attrs: &tracing_core::span::Attributes<'_>,
+        id: &tracing_core::span::Id,
+        _ctx: tracing_subscriber::layer::Context<'_, S>,
+    ) {
+        // Spans of the calls made while logging one are not timed.
+        if attrs.metadata().name() != "ffi" || IN_SINK.with(Cell::get) {
+            return;
+        }
+        let mut span = OpenSpan { module: String::new(), function: String::new(), start: Instant::now() };
+        attrs.record(&mut span);
+        crate::lock_or_recover(&self.open).insert(id.clone(), span);
+    }
+
+    fn on_close(&self, id: tracing_core::span::Id, _ctx: tracing_subscriber::layer::Context<'_, S>) {
+        let Some(span) = crate::lock_or_recover(&self.open).remove(&id) else {
+            return;
+        };
+        if let Some(_scope) = SinkScope::enter() {
+            log_slow(&span.module, &span.function, span.start.elapsed());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn panicking_sink_does_not_disable_tracing() {
+        let result = std::panic::catch_unwind(|| {
+            let _scope = SinkScope::enter().expect("not reporting yet");
+            assert!(SinkScope::enter().is_none());
+            panic!("sink failed");
+        });
+        assert!(result.is_err());
+        assert!(SinkScope::enter().is_some());
+    }
+}
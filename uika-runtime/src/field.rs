@@ -0,0 +1,221 @@
+// Field access by name: read and write reflected properties of a struct (or
+// object) without a generated accessor, and walk into nested structs.
+//
+// Used for structs the bindings don't cover, such as user Blueprint structs.
+// The property is found by name, and its reflected type is checked against
+// the requested Rust type before any memory is touched, so a wrong type is a
+// `TypeMismatch` error rather than a garbage read.
+
+use uika_ffi::{FNameHandle, FPropertyHandle, UObjectHandle, UikaReifyPropExtra, UikaReifyPropType};
+
+use crate::containers::OwnedStruct;
+use crate::error::{check_ffi, UikaError, UikaResult};
+use crate::ffi_dispatch;
+use crate::fname::FName;
+use crate::struct_ref::UStructRef;
+use crate::traits::UeStruct;
+
+/// A Rust type that can be read from and written to a reflected property.
+///
+/// # Safety
+/// `read`/`write` must only touch the property through the property API,
+/// using the accessor that matches every kind `matches` accepts.
+pub unsafe trait FieldValue: Sized {
+    /// Whether a property of reflected type `kind` holds this Rust type.
+    fn matches(kind: UikaReifyPropType, extra: &UikaReifyPropExtra) -> bool;
+
+    /// # Safety
+    /// `container` must point to live memory holding `prop`, and `prop` must
+    /// have passed [`matches`](Self::matches).
+    unsafe fn read(container: UObjectHandle, prop: FPropertyHandle) -> UikaResult<Self>;
+
+    /// # Safety
+    /// As for [`read`](Self::read).
+    unsafe fn write(&self, container: UObjectHandle, prop: FPropertyHandle) -> UikaResult<()>;
+}
+
+/// Check that `prop` holds an `F`; `TypeMismatch` otherwise.
+pub(crate) fn check_field_type<F: FieldValue>(prop: FPropertyHandle) -> UikaResult<()> {
+    let mut kind = 0u32;
+    let mut extra = UikaReifyPropExtra::default();
+    let code = unsafe { ffi_dispatch::property_info_describe(prop, &mut kind, &mut extra) };
+    let described = check_ffi(code).ok().and_then(|()| reify_type(kind));
+    match described {
+        Some(kind) if F::matches(kind, &extra) => Ok(()),
+        _ => Err(UikaError::TypeMismatch),
+    }
+}
+
+pub(crate) fn reify_type(kind: u32) -> Option<UikaReifyPropType> {
+    use UikaReifyPropType::*;
+    [
+        Bool, Int8, Int16, Int32, Int64, UInt8, UInt16, UInt32, UInt64, Float, Double, String, Name, Text,
+        Object, Class, Struct, Enum,
+    ]
+    .into_iter()
+    .find(|t| *t as u32 == kind)
+}
+
+macro_rules! scalar_field {
+    ($ty:ty, $kind:ident, $get:ident, $set:ident) => {
+        unsafe impl FieldValue for $ty {
+            fn matches(kind: UikaReifyPropType, _extra: &UikaReifyPropExtra) -> bool {
+                kind == UikaReifyPropType::$kind
+            }
+
+            unsafe fn read(container: UObjectHandle, prop: FPropertyHandle) -> UikaResult<Self> {
+                let mut out = <$ty>::default();
+                check_ffi(unsafe { ffi_dispatch::$get(container, prop, &mut out) })?;
+                Ok(out)
+            }
+
+            unsafe fn write(&self, container: UObjectHandle, prop: FPropertyHandle) -> UikaResult<()> {
+                check_ffi(unsafe { ffi_dispatch::$set(container, prop, *self) })
+            }
+        }
+    };
+}
+
+scalar_field!(bool, Bool, property_get_bool, property_set_bool);
+scalar_field!(i32, Int32, property_get_i32, property_set_i32);
+scalar_field!(i64, Int64, property_get_i64, property_set_i64);
+scalar_field!(u8, UInt8, property_get_u8, property_set_u8);
+scalar_field!(f32, Float, property_get_f32, property_set_f32);
+scalar_field!(f64, Double, property_get_f64, property_set_f64);
+
+/// FString and FText fields.
+unsafe impl FieldValue for String {
+    fn matches(kind: UikaReifyPropType, _extra: &UikaReifyPropExtra) -> bool {
+        matches!(kind, UikaReifyPropType::String | UikaReifyPropType::Text)
+    }
+
+    unsafe fn read(container: UObjectHandle, prop: FPropertyHandle) -> UikaResult<Self> {
+        let mut buf = vec![0u8; 256];
+        let mut out_len: u32 = 0;
+        let read = |buf: &mut Vec<u8>, out_len: &mut u32| {
+            check_ffi(unsafe {
+                ffi_dispatch::property_get_string(container, prop, buf.as_mut_ptr(), buf.len() as u32, out_len)
+            })
+        };
+        read(&mut buf, &mut out_len)?;
+        // The getter truncates to the buffer and reports the full length.
+        if out_len as usize > buf.len() {
+            buf.resize(out_len as usize, 0);
+            read(&mut buf, &mut out_len)?;
+        }
+        buf.truncate(out_len as usize);
+        String::from_utf8(buf).map_err(|_| UikaError::Internal("string field is not valid UTF-8".into()))
+    }
+
+    unsafe fn write(&self, container: UObjectHandle, prop: FPropertyHandle) -> UikaResult<()> {
+        check_ffi(unsafe { ffi_dispatch::property_set_string(container, prop, self.as_ptr(), self.len() as u32) })
+    }
+}
+
+unsafe impl FieldValue for FName {
+    fn matches(kind: UikaReifyPropType, _extra: &UikaReifyPropExtra) -> bool {
+        kind == UikaReifyPropType::Name
+    }
+
+    unsafe fn read(container: UObjectHandle, prop: FPropertyHandle) -> UikaResult<Self> {
+        let mut out = FNameHandle(0);
+        check_ffi(unsafe { ffi_dispatch::property_get_fname(container, prop, &mut out) })?;
+        Ok(FName(out))
+    }
+
+    unsafe fn write(&self, container: UObjectHandle, prop: FPropertyHandle) -> UikaResult<()> {
+        check_ffi(unsafe { ffi_dispatch::property_set_fname(container, prop, self.0) })
+    }
+}
+
+/// Object and class fields, as untyped handles.
+unsafe impl FieldValue for UObjectHandle {
+    fn matches(kind: UikaReifyPropType, _extra: &UikaReifyPropExtra) -> bool {
+        matches!(kind, UikaReifyPropType::Object | UikaReifyPropType::Class)
+    }
+
+    unsafe fn read(container: UObjectHandle, prop: FPropertyHandle) -> UikaResult<Self> {
+        let mut out = UObjectHandle::null();
+        check_ffi(unsafe { ffi_dispatch::property_get_object(container, prop, &mut out) })?;
+        Ok(out)
+    }
+
+    unsafe fn write(&self, container: UObjectHandle, prop: FPropertyHandle) -> UikaResult<()> {
+        check_ffi(unsafe { ffi_dispatch::property_set_object(container, prop, *self) })
+    }
+}
+
+/// Nested struct fields of exactly type `S`.
+unsafe impl<S: UeStruct> FieldValue for OwnedStruct<S> {
+    fn matches(kind: UikaReifyPropType, extra: &UikaReifyPropExtra) -> bool {
+        kind == UikaReifyPropType::Struct && extra.struct_handle == S::static_struct()
+    }
+
+    unsafe fn read(container: UObjectHandle, prop: FPropertyHandle) -> UikaResult<Self> {
+        let mut value = OwnedStruct::<S>::new();
+        let bytes = value.as_bytes_mut();
+        check_ffi(unsafe {
+            ffi_dispatch::property_get_struct(container, prop, bytes.as_mut_ptr(), bytes.len() as u32)
+        })?;
+        Ok(value)
+    }
+
+    unsafe fn write(&self, container: UObjectHandle, prop: FPropertyHandle) -> UikaResult<()> {
+        let bytes = self.as_bytes();
+        check_ffi(unsafe { ffi_dispatch::property_set_struct(container, prop, bytes.as_ptr(), bytes.len() as u32) })
+    }
+}
+
+/// Find the property `name` of struct `S`.
+pub(crate) fn find_struct_field<S: UeStruct>(name: &str) -> UikaResult<FPropertyHandle> {
+    let prop =
+        unsafe { ffi_dispatch::reflection_find_struct_property(S::static_struct(), name.as_ptr(), name.len() as u32) };
+    if prop.is_null() {
+        return Err(UikaError::PropertyNotFound(format!("{}::{name}", std::any::type_name::<S>())));
+    }
+    Ok(prop)
+}
+
+impl<T: UeStruct> UStructRef<T> {
+    /// Read the field `name` through reflection.
+    pub fn field<F: FieldValue>(&self, name: &str) -> UikaResult<F> {
+        let prop = find_struct_field::<T>(name)?;
+        check_field_type::<F>(prop)?;
+        unsafe { F::read(self.as_ptr(), prop) }
+    }
+
+    /// A reference to the nested struct field `name`, pointing into this
+    /// struct's memory (nothing is copied).
+    ///
+    /// ```ignore
+    /// let hit: UStructRef<FHitResult> = ...;
+    /// let x: f64 = hit.field_struct::<FVector>("ImpactPoint")?.field("X")?;
+    /// ```
+    pub fn field_struct<U: UeStruct>(&self, name: &str) -> UikaResult<UStructRef<U>> {
+        let prop = find_struct_field::<T>(name)?;
+        check_field_type::<OwnedStruct<U>>(prop)?;
+        let offset = unsafe { ffi_dispatch::reflection_get_property_offset(prop) };
+        let base = self.as_ptr().0 as *mut u8;
+        Ok(unsafe { UStructRef::from_raw(base.add(offset as usize)) })
+    }
+}
+
+impl<T: UeStruct> OwnedStruct<T> {
+    /// Read the field `name` through reflection.
+    ///
+    /// ```ignore
+    /// let loadout: OwnedStruct<FLoadout> = ...;
+    /// let ammo: i32 = loadout.get_field("Ammo")?;
+    /// ```
+    pub fn get_field<F: FieldValue>(&self, name: &str) -> UikaResult<F> {
+        self.as_ref().field(name)
+    }
+
+    /// Write the field `name` through reflection.
+    pub fn set_field<F: FieldValue>(&mut self, name: &str, value: &F) -> UikaResult<()> {
+        let prop = find_struct_field::<T>(name)?;
+        check_field_type::<F>(prop)?;
+        let container = UObjectHandle(self.as_bytes_mut().as_mut_ptr().cast());
+        unsafe { value.write(container, prop) }
+    }
+}
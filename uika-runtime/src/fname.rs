@@ -1,7 +1,16 @@
 // FName: ergonomic wrapper around FNameHandle.
 // Provides construction from &str and Display for string conversion.
+//
+// Both directions are memoized in a per-thread intern cache, so hot loops
+// that build the same names (or print the same handles) only cross the FFI
+// once per distinct value. The cache is generation-stamped: bumping the
+// global generation (on shutdown / hot reload) lazily clears every thread's
+// cache the next time it is touched.
 
+use std::cell::RefCell;
+use std::collections::HashMap;
 use std::fmt;
+use std::sync::atomic::{AtomicU32, Ordering};
 
 use uika_ffi::FNameHandle;
 
@@ -19,11 +28,16 @@ impl FName {
     /// The "None" name (index 0).
     pub const NONE: FName = FName(FNameHandle(0));
 
-    /// Create an FName from a string.
+    /// Create an FName from a string. Repeated names are served from the
+    /// per-thread intern cache.
     pub fn new(name: &str) -> Self {
+        if let Some(handle) = with_cache(|c| c.by_str.get(name).copied()) {
+            return FName(handle);
+        }
         let handle = unsafe {
             ffi_dispatch::core_make_fname(name.as_ptr(), name.len() as u32)
         };
+        with_cache(|c| c.insert_str(name, handle));
         FName(handle)
     }
 
@@ -39,8 +53,13 @@ impl FName {
         self.0 .0 == 0
     }
 
-    /// Convert to a String. Returns an error only if the FFI call fails.
+    /// Convert to a String. Failures are rendered as `<invalid FName>` /
+    /// `<invalid UTF-8>` rather than returned. Successful conversions are
+    /// cached per thread.
     pub fn to_string_lossy(&self) -> String {
+        if let Some(s) = with_cache(|c| c.by_handle.get(&self.0.0).cloned()) {
+            return s;
+        }
         // Stack buffer — 256 bytes is enough for virtually all FNames.
         let mut buf = [0u8; 256];
         let mut out_len: u32 = 0;
@@ -55,12 +74,72 @@ impl FName {
         if check_ffi(code).is_err() {
             return String::from("<invalid FName>");
         }
-        std::str::from_utf8(&buf[..out_len as usize])
-            .map(|s| s.to_owned())
-            .unwrap_or_else(|_| String::from("<invalid UTF-8>"))
+        match std::str::from_utf8(&buf[..out_len as usize]) {
+            Ok(s) => {
+                with_cache(|c| c.insert_handle(self.0, s));
+                s.to_owned()
+            }
+            Err(_) => String::from("<invalid UTF-8>"),
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Intern cache
+// ---------------------------------------------------------------------------
+
+/// Entries per direction before a thread's cache is flushed. Names are
+/// usually a small, hot set; the cap only guards against unbounded growth
+/// from generated names.
+const CACHE_CAPACITY: usize = 4096;
+
+static GENERATION: AtomicU32 = AtomicU32::new(0);
+
+#[derive(Default)]
+struct InternCache {
+    generation: u32,
+    by_str: HashMap<Box<str>, FNameHandle>,
+    by_handle: HashMap<u64, String>,
+}
+
+impl InternCache {
+    fn insert_str(&mut self, name: &str, handle: FNameHandle) {
+        if self.by_str.len() >= CACHE_CAPACITY {
+            self.by_str.clear();
+        }
+        self.by_str.insert(name.into(), handle);
+    }
+
+    fn insert_handle(&mut self, handle: FNameHandle, name: &str) {
+        if self.by_handle.len() >= CACHE_CAPACITY {
+            self.by_handle.clear();
+        }
+        self.by_handle.insert(handle.0, name.to_owned());
     }
 }
 
+thread_local! {
+    static CACHE: RefCell<InternCache> = RefCell::new(InternCache::default());
+}
+
+/// Run `f` on this thread's cache, first dropping it if it is stale.
+fn with_cache<R>(f: impl FnOnce(&mut InternCache) -> R) -> R {
+    CACHE.with(|cell| {
+        let mut cache = cell.borrow_mut();
+        let generation = GENERATION.load(Ordering::Acquire);
+        if cache.generation != generation {
+            *cache = InternCache { generation, ..Default::default() };
+        }
+        f(&mut cache)
+    })
+}
+
+/// Invalidate every thread's FName intern cache.
+/// Called during shutdown before DLL unload (enables hot reload).
+pub fn clear_cache() {
+    GENERATION.fetch_add(1, Ordering::AcqRel);
+}
+
 impl Default for FName {
     fn default() -> Self {
         FName::NONE
@@ -0,0 +1,210 @@
+// Gameplay tags: FGameplayTag / FGameplayTagContainer as plain Rust values.
+//
+// A tag is its packed tag name (layout-compatible with the FNameHandle the
+// FFI uses), so generated functions taking or returning FGameplayTag use
+// `GameplayTag` directly. Containers are held as a Vec of tags on the Rust
+// side and converted when read from or written to UE memory.
+
+use std::fmt;
+
+use uika_ffi::{FNameHandle, UikaErrorCode};
+
+use crate::error::{check_ffi, UikaError, UikaResult};
+use crate::ffi_dispatch::{self, NativePtr};
+use crate::fname::FName;
+
+/// A registered gameplay tag, e.g. `Status.Stunned`. Copy-able and cheap to
+/// compare; hierarchical queries go through the tag manager.
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
+pub struct GameplayTag(FNameHandle);
+
+impl GameplayTag {
+    /// The empty tag.
+    pub const NONE: GameplayTag = GameplayTag(FNameHandle(0));
+
+    /// Look up a registered tag by name. Fails if the tag is not registered
+    /// in the project's tag tables.
+    pub fn request(name: &str) -> UikaResult<Self> {
+        let mut out = FNameHandle(0);
+        let code = unsafe {
+            ffi_dispatch::gameplay_tag_request_tag(name.as_ptr(), name.len() as u32, &mut out)
+        };
+        if code == UikaErrorCode::InvalidOperation {
+            return Err(UikaError::InvalidOperation(format!("gameplay tag '{name}' is not registered")));
+        }
+        check_ffi(code)?;
+        Ok(GameplayTag(out))
+    }
+
+    /// Wrap a tag name. The name is not validated against the tag tables;
+    /// use [`request`](Self::request) for user input and
+    /// [`read_from`](Self::read_from) for an FGameplayTag in UE memory.
+    #[inline]
+    pub fn from_name(name: FName) -> Self {
+        GameplayTag(name.handle())
+    }
+
+    /// Whether this is a real tag (not [`NONE`](Self::NONE)).
+    #[inline]
+    pub fn is_valid(&self) -> bool {
+        self.0 .0 != 0
+    }
+
+    /// The tag's full name as an FName.
+    #[inline]
+    pub fn name(&self) -> FName {
+        FName(self.0)
+    }
+
+    /// Hierarchical match: `A.B.C` matches `A.B` and `A.B.C`, but not `A.X`.
+    pub fn matches(&self, other: GameplayTag) -> bool {
+        unsafe { ffi_dispatch::gameplay_tag_matches_tag(self.0, other.0, false) }
+    }
+
+    /// Exact match only.
+    pub fn matches_exact(&self, other: GameplayTag) -> bool {
+        unsafe { ffi_dispatch::gameplay_tag_matches_tag(self.0, other.0, true) }
+    }
+
+    /// The direct parent (`A.B` for `A.B.C`), or `None` for a root tag.
+    pub fn parent(&self) -> Option<GameplayTag> {
+        let parent = GameplayTag(unsafe { ffi_dispatch::gameplay_tag_request_direct_parent(self.0) });
+        parent.is_valid().then_some(parent)
+    }
+
+    /// Read the FGameplayTag at `ptr`.
+    ///
+    /// # Safety
+    /// `ptr` must point to a live FGameplayTag.
+    pub unsafe fn read_from(ptr: *const u8) -> Self {
+        GameplayTag(unsafe { ffi_dispatch::gameplay_tag_ext_read_tag(ptr) })
+    }
+
+    /// Overwrite the FGameplayTag at `ptr` with this tag.
+    ///
+    /// # Safety
+    /// `ptr` must point to a live, writable FGameplayTag.
+    pub unsafe fn write_to(&self, ptr: NativePtr) -> UikaResult<()> {
+        check_ffi(unsafe { ffi_dispatch::gameplay_tag_ext_write_tag(ptr, self.0) })
+    }
+}
+
+impl fmt::Display for GameplayTag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_valid() {
+            write!(f, "{}", self.name())
+        } else {
+            f.write_str("None")
+        }
+    }
+}
+
+/// A set of gameplay tags (FGameplayTagContainer). Queries are hierarchical
+/// unless named `*_exact`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct GameplayTagContainer {
+    tags: Vec<GameplayTag>,
+}
+
+impl GameplayTagContainer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.tags.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.tags.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = GameplayTag> + '_ {
+        self.tags.iter().copied()
+    }
+
+    /// Add a tag. Invalid and duplicate tags are ignored.
+    pub fn add(&mut self, tag: GameplayTag) {
+        if tag.is_valid() && !self.tags.contains(&tag) {
+            self.tags.push(tag);
+        }
+    }
+
+    /// Remove a tag. Returns whether it was present.
+    pub fn remove(&mut self, tag: GameplayTag) -> bool {
+        let before = self.tags.len();
+        self.tags.retain(|t| *t != tag);
+        self.tags.len() != before
+    }
+
+    /// Whether any tag in the container matches `tag` (so a container holding
+    /// `A.B.C` has `A.B`).
+    pub fn has_tag(&self, tag: GameplayTag) -> bool {
+        self.tags.iter().any(|t| t.matches(tag))
+    }
+
+    pub fn has_tag_exact(&self, tag: GameplayTag) -> bool {
+        self.tags.contains(&tag)
+    }
+
+    /// Whether the container has any of `other`'s tags.
+    pub fn has_any(&self, other: &GameplayTagContainer) -> bool {
+        other.iter().any(|t| self.has_tag(t))
+    }
+
+    /// Whether the container has all of `other`'s tags. True if `other` is empty.
+    pub fn has_all(&self, other: &GameplayTagContainer) -> bool {
+        other.iter().all(|t| self.has_tag(t))
+    }
+
+    /// Read the FGameplayTagContainer at `ptr`.
+    ///
+    /// # Safety
+    /// `ptr` must point to a live FGameplayTagContainer.
+    pub unsafe fn read_from(ptr: *const u8) -> UikaResult<Self> {
+        let mut tags = vec![FNameHandle(0); 16];
+        let mut count: u32 = 0;
+        let mut code = unsafe {
+            ffi_dispatch::gameplay_tag_read_container(ptr, tags.as_mut_ptr(), tags.len() as u32, &mut count)
+        };
+        if code == UikaErrorCode::BufferTooSmall {
+            tags.resize(count as usize, FNameHandle(0));
+            code = unsafe {
+                ffi_dispatch::gameplay_tag_read_container(ptr, tags.as_mut_ptr(), tags.len() as u32, &mut count)
+            };
+        }
+        check_ffi(code)?;
+        tags.truncate(count as usize);
+        Ok(GameplayTagContainer { tags: tags.into_iter().map(GameplayTag).collect() })
+    }
+
+    /// Overwrite the FGameplayTagContainer at `ptr` with this container's tags.
+    ///
+    /// # Safety
+    /// `ptr` must point to a live, writable FGameplayTagContainer.
+    pub unsafe fn write_to(&self, ptr: NativePtr) -> UikaResult<()> {
+        // GameplayTag is repr(transparent) over FNameHandle.
+        let names = self.tags.as_ptr() as *const FNameHandle;
+        check_ffi(unsafe { ffi_dispatch::gameplay_tag_write_container(ptr, names, self.tags.len() as u32) })
+    }
+}
+
+impl FromIterator<GameplayTag> for GameplayTagContainer {
+    fn from_iter<I: IntoIterator<Item = GameplayTag>>(iter: I) -> Self {
+        let mut c = GameplayTagContainer::new();
+        for tag in iter {
+            c.add(tag);
+        }
+        c
+    }
+}
+
+impl<'a> IntoIterator for &'a GameplayTagContainer {
+    type Item = GameplayTag;
+    type IntoIter = std::iter::Copied<std::slice::Iter<'a, GameplayTag>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.tags.iter().copied()
+    }
+}
@@ -0,0 +1,218 @@
+// Gameplay Ability System: typed helpers over an actor's ability system
+// component.
+//
+// The `gameplay-abilities` bindings expose the raw GAS classes; this module
+// covers the everyday calls without going through them. Every function takes
+// the actor that owns (or provides, via `IAbilitySystemInterface`) the
+// component. Attributes are named `"Health"` or `"MyAttributeSet.Health"`.
+//
+// Behind the `gas` feature (uika's `gameplay-abilities`): the plugin only
+// implements the GAS sub-table when built with the GameplayAbilities module.
+
+use uika_ffi::{FNameHandle, UClassHandle, UObjectHandle, UikaErrorCode};
+
+use crate::class_ref::ClassRef;
+use crate::delegate_registry::{register_callback, unregister_callback};
+use crate::error::{check_ffi, UikaError, UikaResult};
+use crate::ffi_dispatch;
+use crate::gameplay_tag::GameplayTag;
+use crate::object_ref::UObjectRef;
+use crate::traits::UeClass;
+
+/// Current value of an attribute (base plus active modifiers).
+pub fn attribute<C: UeClass>(actor: UObjectRef<C>, name: &str) -> UikaResult<f32> {
+    get_attribute(actor, name, false)
+}
+
+/// Base value of an attribute, without temporary modifiers.
+pub fn attribute_base<C: UeClass>(actor: UObjectRef<C>, name: &str) -> UikaResult<f32> {
+    get_attribute(actor, name, true)
+}
+
+/// Set an attribute's base value; the current value is recomputed from it.
+pub fn set_attribute_base<C: UeClass>(actor: UObjectRef<C>, name: &str, value: f32) -> UikaResult<()> {
+    set_attribute_impl(actor, name, true, value)
+}
+
+/// Overwrite an attribute's current value directly. Active modifiers
+/// recompute it on their next update; prefer [`set_attribute_base`] or a
+/// gameplay effect for lasting changes.
+pub fn set_attribute<C: UeClass>(actor: UObjectRef<C>, name: &str, value: f32) -> UikaResult<()> {
+    set_attribute_impl(actor, name, false, value)
+}
+
+fn get_attribute<C: UeClass>(actor: UObjectRef<C>, name: &str, base: bool) -> UikaResult<f32> {
+    let actor = actor.checked()?.raw();
+    let mut out = 0.0f32;
+    let code = unsafe {
+        ffi_dispatch::gas_get_attribute(actor, name.as_ptr(), name.len() as u32, base, &mut out)
+    };
+    attribute_result(code, name)?;
+    Ok(out)
+}
+
+fn set_attribute_impl<C: UeClass>(actor: UObjectRef<C>, name: &str, base: bool, value: f32) -> UikaResult<()> {
+    let actor = actor.checked()?.raw();
+    let code = unsafe {
+        ffi_dispatch::gas_set_attribute(actor, name.as_ptr(), name.len() as u32, base, value)
+    };
+    attribute_result(code, name)
+}
+
+fn attribute_result(code: UikaErrorCode, name: &str) -> UikaResult<()> {
+    match code {
+        UikaErrorCode::PropertyNotFound => {
+            Err(UikaError::PropertyNotFound(format!("gameplay attribute '{name}'")))
+        }
+        UikaErrorCode::InvalidOperation => Err(no_component()),
+        code => check_ffi(code),
+    }
+}
+
+fn no_component() -> UikaError {
+    UikaError::InvalidOperation("actor has no AbilitySystemComponent".into())
+}
+
+/// Try to activate the granted ability of class `A`. `Ok(false)` if it is
+/// not granted or can't activate right now (cooldown, cost, blocking tags).
+pub fn try_activate_ability<A: UeClass, C: UeClass>(actor: UObjectRef<C>) -> UikaResult<bool> {
+    let actor = actor.checked()?.raw();
+    Ok(unsafe { ffi_dispatch::gas_try_activate_ability_by_class(actor, A::static_class()) })
+}
+
+/// [`try_activate_ability`] for a runtime-chosen ability class (e.g. a
+/// Blueprint ability loaded from a soft class path).
+pub fn try_activate_ability_of_class<A: UeClass, C: UeClass>(
+    actor: UObjectRef<C>,
+    class: ClassRef<A>,
+) -> UikaResult<bool> {
+    let actor = actor.checked()?.raw();
+    Ok(unsafe { ffi_dispatch::gas_try_activate_ability_by_class(actor, class.class()) })
+}
+
+/// Try to activate every granted ability matching any of `tags`. `Ok(true)`
+/// if at least one activated.
+pub fn try_activate_abilities_by_tag<C: UeClass>(actor: UObjectRef<C>, tags: &[GameplayTag]) -> UikaResult<bool> {
+    let actor = actor.checked()?.raw();
+    // GameplayTag is a transparent FNameHandle.
+    let tags_ptr = tags.as_ptr() as *const FNameHandle;
+    Ok(unsafe { ffi_dispatch::gas_try_activate_abilities_by_tag(actor, tags_ptr, tags.len() as u32) })
+}
+
+/// An active (duration or infinite) gameplay effect applied from Rust.
+/// Dropping the handle does NOT remove the effect; call
+/// [`remove`](Self::remove) for that.
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub struct ActiveEffect {
+    id: u32,
+}
+
+impl ActiveEffect {
+    /// Remove the effect from its target. Removing an effect that already
+    /// expired is not an error.
+    pub fn remove(self) -> UikaResult<()> {
+        check_ffi(unsafe { ffi_dispatch::gas_remove_gameplay_effect(self.id) })
+    }
+}
+
+/// Apply gameplay effect class `E` from `source`'s component to `target`'s
+/// at `level`. Returns the active effect, or `None` for instant effects.
+///
+/// ```ignore
+/// gas::apply_effect::<GE_Burning, _, _>(attacker, victim, 1.0)?;
+/// ```
+pub fn apply_effect<E: UeClass, S: UeClass, T: UeClass>(
+    source: UObjectRef<S>,
+    target: UObjectRef<T>,
+    level: f32,
+) -> UikaResult<Option<ActiveEffect>> {
+    apply_effect_raw(source, target, E::static_class(), level)
+}
+
+/// [`apply_effect`] for a runtime-chosen effect class (typically a Blueprint
+/// `GameplayEffect`).
+pub fn apply_effect_of_class<E: UeClass, S: UeClass, T: UeClass>(
+    source: UObjectRef<S>,
+    target: UObjectRef<T>,
+    class: ClassRef<E>,
+    level: f32,
+) -> UikaResult<Option<ActiveEffect>> {
+    apply_effect_raw(source, target, class.class(), level)
+}
+
+fn apply_effect_raw<S: UeClass, T: UeClass>(
+    source: UObjectRef<S>,
+    target: UObjectRef<T>,
+    class: UClassHandle,
+    level: f32,
+) -> UikaResult<Option<ActiveEffect>> {
+    let source = source.checked()?.raw();
+    let target = target.checked()?.raw();
+    let mut id = 0u32;
+    let code = unsafe { ffi_dispatch::gas_apply_gameplay_effect(source, target, class, level, &mut id) };
+    if code == UikaErrorCode::InvalidOperation {
+        return Err(UikaError::InvalidOperation(
+            "apply_effect failed (source or target has no AbilitySystemComponent)".into(),
+        ));
+    }
+    check_ffi(code)?;
+    Ok((id != 0).then_some(ActiveEffect { id }))
+}
+
+/// An attribute value change delivered to [`on_attribute_changed`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct AttributeChange {
+    pub old_value: f32,
+    pub new_value: f32,
+}
+
+/// Handle to a subscription made with [`on_attribute_changed`]. Dropping the
+/// handle does NOT unsubscribe; call [`unsubscribe`](Self::unsubscribe).
+#[derive(Debug)]
+pub struct AttributeSubscription {
+    binding: u32,
+    callback_id: u64,
+}
+
+impl AttributeSubscription {
+    /// Remove the subscription and release its closure.
+    pub fn unsubscribe(self) -> UikaResult<()> {
+        unregister_callback(self.callback_id);
+        check_ffi(unsafe { ffi_dispatch::gas_unbind_attribute_changed(self.binding) })
+    }
+}
+
+/// Call `callback` whenever the current value of attribute `name` changes on
+/// `actor`'s ability system component.
+///
+/// ```ignore
+/// gas::on_attribute_changed(pawn, "Health", move |change| {
+///     if change.new_value <= 0.0 { on_death(); }
+/// })?;
+/// ```
+pub fn on_attribute_changed<C: UeClass>(
+    actor: UObjectRef<C>,
+    name: &str,
+    mut callback: impl FnMut(AttributeChange) + Send + 'static,
+) -> UikaResult<AttributeSubscription> {
+    let actor: UObjectHandle = actor.checked()?.raw();
+    let callback_id = register_callback(move |params| {
+        if params.is_null() {
+            return;
+        }
+        // SAFETY: C++ passes a `float[2] { old, new }`.
+        let [old_value, new_value] = unsafe { std::ptr::read_unaligned(params as *const [f32; 2]) };
+        callback(AttributeChange { old_value, new_value });
+    });
+
+    let binding = unsafe {
+        ffi_dispatch::gas_bind_attribute_changed(actor, name.as_ptr(), name.len() as u32, callback_id)
+    };
+    if binding == 0 {
+        unregister_callback(callback_id);
+        return Err(UikaError::InvalidOperation(format!(
+            "on_attribute_changed: no attribute '{name}' (or no AbilitySystemComponent)"
+        )));
+    }
+    Ok(AttributeSubscription { binding, callback_id })
+}
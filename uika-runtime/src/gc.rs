@@ -0,0 +1,188 @@
+// GC reference reporting: UObjects held by Rust that UE's garbage collector
+// should treat as referenced.
+//
+// A plain `UObjectRef` is invisible to the GC, and `Pinned` makes an object a
+// GC root (plus an FFI round trip each way). The plugin instead asks Rust for
+// its references on every GC pass through the `collect_references` callback,
+// which reports:
+// - `UObjectRef` / `GcVisible` fields in the Rust data of reified instances
+//   (collected by the code `#[uclass]` generates), and
+// - every live `GcVisible` handle, wherever it is stored.
+//
+// A reported object stays alive as long as it is reported, like a
+// `UPROPERTY()` reference; it can still be destroyed explicitly
+// (`DestroyActor`, level unload), so access remains checked.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::marker::PhantomData;
+use std::ops::Deref;
+use std::sync::{Mutex, OnceLock};
+
+use uika_ffi::UObjectHandle;
+
+use crate::lock_or_recover;
+use crate::object_ref::UObjectRef;
+use crate::traits::UeClass;
+
+/// Handles owned by live `GcVisible`s, with a count per object.
+fn visible_handles() -> &'static Mutex<HashMap<u64, usize>> {
+    static HANDLES: OnceLock<Mutex<HashMap<u64, usize>>> = OnceLock::new();
+    HANDLES.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// A `UObjectRef` that UE's GC sees as a reference while it is alive.
+///
+/// Cheaper than [`Pinned`](crate::Pinned): creating and dropping one touches
+/// only a Rust-side registry. Use it for objects kept in Rust state outside
+/// reified instances (resources, caches, subsystem-like singletons).
+///
+/// ```ignore
+/// let material = GcVisible::new(load_object::<UMaterial>(path)?);
+/// cache.insert(key, material);
+/// ```
+pub struct GcVisible<T: UeClass> {
+    obj: UObjectRef<T>,
+    _marker: PhantomData<*const T>, // !Sync, like Pinned
+}
+
+unsafe impl<T: UeClass> Send for GcVisible<T> {}
+
+impl<T: UeClass> GcVisible<T> {
+    pub fn new(obj: UObjectRef<T>) -> Self {
+        if !obj.raw().is_null() {
+            *lock_or_recover(visible_handles()).entry(obj.raw().to_addr()).or_insert(0) += 1;
+        }
+        GcVisible { obj, _marker: PhantomData }
+    }
+
+    /// The reference, without GC visibility.
+    pub fn get(&self) -> UObjectRef<T> {
+        unsafe { UObjectRef::from_raw(self.obj.raw()) }
+    }
+}
+
+impl<T: UeClass> Clone for GcVisible<T> {
+    fn clone(&self) -> Self {
+        GcVisible::new(self.get())
+    }
+}
+
+impl<T: UeClass> Drop for GcVisible<T> {
+    fn drop(&mut self) {
+        let addr = self.obj.raw().to_addr();
+        let mut handles = lock_or_recover(visible_handles());
+        if let Some(count) = handles.get_mut(&addr) {
+            *count -= 1;
+            if *count == 0 {
+                handles.remove(&addr);
+            }
+        }
+    }
+}
+
+impl<T: UeClass> Deref for GcVisible<T> {
+    type Target = UObjectRef<T>;
+
+    fn deref(&self) -> &UObjectRef<T> {
+        &self.obj
+    }
+}
+
+impl<T: UeClass> From<UObjectRef<T>> for GcVisible<T> {
+    fn from(obj: UObjectRef<T>) -> Self {
+        GcVisible::new(obj)
+    }
+}
+
+impl<T: UeClass> fmt::Debug for GcVisible<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("GcVisible").field(&self.obj.raw()).finish()
+    }
+}
+
+/// Types holding UObject references to report to the GC. `#[uclass]` calls
+/// this for Rust fields of these types (and `Option`/`Vec` of them).
+pub trait ReportReferences {
+    fn report_references(&self, report: &mut dyn FnMut(UObjectHandle));
+}
+
+impl<T: UeClass> ReportReferences for UObjectRef<T> {
+    fn report_references(&self, report: &mut dyn FnMut(UObjectHandle)) {
+        if !self.raw().is_null() {
+            report(self.raw());
+        }
+    }
+}
+
+impl<T: UeClass> ReportReferences for GcVisible<T> {
+    // Already reported through the registry.
+    fn report_references(&self, _report: &mut dyn FnMut(UObjectHandle)) {}
+}
+
+impl<R: ReportReferences> ReportReferences for Option<R> {
+    fn report_references(&self, report: &mut dyn FnMut(UObjectHandle)) {
+        if let Some(r) = self {
+            r.report_references(report);
+        }
+    }
+}
+
+impl<R: ReportReferences> ReportReferences for Vec<R> {
+    fn report_references(&self, report: &mut dyn FnMut(UObjectHandle)) {
+        for r in self {
+            r.report_references(report);
+        }
+    }
+}
+
+/// Report every object Rust holds a GC-visible reference to. Called from the
+/// plugin's `AddReferencedObjects`, possibly off the game thread.
+pub fn collect_references(report: &mut dyn FnMut(UObjectHandle)) {
+    let handles: Vec<u64> = lock_or_recover(visible_handles()).keys().copied().collect();
+    for addr in handles {
+        report(UObjectHandle::from_addr(addr));
+    }
+    crate::reify_registry::report_instance_references(report);
+}
+
+/// Forget every `GcVisible` handle. Called during on_shutdown.
+pub fn clear_all() {
+    lock_or_recover(visible_handles()).clear();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use uika_ffi::UClassHandle;
+
+    #[derive(Clone, Copy)]
+    struct Thing;
+    impl UeClass for Thing {
+        fn static_class() -> UClassHandle {
+            UClassHandle::null()
+        }
+    }
+
+    fn reported() -> Vec<u64> {
+        let mut out = Vec::new();
+        collect_references(&mut |h| out.push(h.to_addr()));
+        out
+    }
+
+    #[test]
+    fn gc_visible_reports_until_last_clone_drops() {
+        let obj: UObjectRef<Thing> = unsafe { UObjectRef::from_raw(UObjectHandle::from_addr(0xdead_0010)) };
+        let first = GcVisible::new(obj);
+        let second = first.clone();
+        assert!(reported().contains(&0xdead_0010));
+        drop(first);
+        assert!(reported().contains(&0xdead_0010));
+        drop(second);
+        assert!(!reported().contains(&0xdead_0010));
+
+        let mut fields = Vec::new();
+        vec![Some(obj), None].report_references(&mut |h| fields.push(h.to_addr()));
+        assert_eq!(fields, [0xdead_0010]);
+    }
+}
@@ -0,0 +1,169 @@
+// Enhanced Input: mapping contexts and action bindings to Rust closures.
+//
+// Bound actions fire through the delegate registry: C++ calls
+// `invoke_delegate_callback(callback_id, params)` with the action value as
+// three f64s (`FInputActionValue::Get<FVector>()`).
+
+use glam::{DVec2, DVec3};
+use uika_ffi::UObjectHandle;
+
+use crate::delegate_registry::{register_callback, unregister_callback};
+use crate::error::{check_ffi, UikaError, UikaResult};
+use crate::ffi_dispatch;
+use crate::object_ref::UObjectRef;
+use crate::traits::UeClass;
+
+/// When a bound action fires (`ETriggerEvent`).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum TriggerEvent {
+    /// Every tick the action's triggers are met (e.g. while a key is held).
+    Triggered,
+    /// The first tick the action is evaluated after being idle.
+    Started,
+    /// Triggers are being evaluated but not met yet (e.g. a hold in progress).
+    Ongoing,
+    /// Evaluation stopped before the triggers were met.
+    Canceled,
+    /// Triggers were met and evaluation stopped (e.g. the key was released).
+    Completed,
+}
+
+impl TriggerEvent {
+    /// The `ETriggerEvent` bit.
+    pub fn bits(self) -> u8 {
+        match self {
+            TriggerEvent::Triggered => 1 << 0,
+            TriggerEvent::Started => 1 << 1,
+            TriggerEvent::Ongoing => 1 << 2,
+            TriggerEvent::Canceled => 1 << 3,
+            TriggerEvent::Completed => 1 << 4,
+        }
+    }
+}
+
+/// The value of an input action when it fired. Digital (bool) and 1D
+/// actions only use `x`.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct InputValue(pub DVec3);
+
+impl InputValue {
+    /// Digital value: whether the action is actuated.
+    pub fn as_bool(self) -> bool {
+        self.0 != DVec3::ZERO
+    }
+
+    /// 1D axis value.
+    pub fn axis1d(self) -> f64 {
+        self.0.x
+    }
+
+    /// 2D axis value (e.g. a thumbstick or WASD movement).
+    pub fn axis2d(self) -> DVec2 {
+        self.0.truncate()
+    }
+
+    /// 3D axis value.
+    pub fn axis3d(self) -> DVec3 {
+        self.0
+    }
+}
+
+/// Load an input asset by object path, e.g. `/Game/Input/IA_Jump.IA_Jump`.
+///
+/// `T` is the generated `InputAction` or `InputMappingContext` type.
+pub fn find_input_action<T: UeClass>(path: &str) -> UikaResult<UObjectRef<T>> {
+    find_input_asset(path, false)
+}
+
+/// Load an input mapping context by object path. See [`find_input_action`].
+pub fn find_mapping_context<T: UeClass>(path: &str) -> UikaResult<UObjectRef<T>> {
+    find_input_asset(path, true)
+}
+
+fn find_input_asset<T: UeClass>(path: &str, mapping_context: bool) -> UikaResult<UObjectRef<T>> {
+    let h = unsafe {
+        ffi_dispatch::input_find_input_asset(path.as_ptr(), path.len() as u32, mapping_context)
+    };
+    if h.is_null() {
+        return Err(UikaError::InvalidOperation(format!("input asset not found: {path}")));
+    }
+    unsafe { UObjectRef::<T>::from_raw(h) }.cast()
+}
+
+/// Add a mapping context to `player_controller`'s local player. Higher
+/// `priority` contexts win when they map the same key.
+pub fn add_mapping_context<P: UeClass, M: UeClass>(
+    player_controller: UObjectRef<P>,
+    context: UObjectRef<M>,
+    priority: i32,
+) -> UikaResult<()> {
+    let pc = player_controller.checked()?.raw();
+    let ctx = context.checked()?.raw();
+    check_ffi(unsafe { ffi_dispatch::input_add_mapping_context(pc, ctx, priority) })
+}
+
+/// Remove a mapping context added with [`add_mapping_context`].
+pub fn remove_mapping_context<P: UeClass, M: UeClass>(
+    player_controller: UObjectRef<P>,
+    context: UObjectRef<M>,
+) -> UikaResult<()> {
+    let pc = player_controller.checked()?.raw();
+    let ctx = context.checked()?.raw();
+    check_ffi(unsafe { ffi_dispatch::input_remove_mapping_context(pc, ctx) })
+}
+
+/// Handle to an action binding made with [`bind_action`]. Dropping the handle
+/// does NOT remove the binding; call [`remove`](Self::remove) for that.
+#[derive(Debug)]
+pub struct InputBinding {
+    actor: UObjectHandle,
+    binding: u32,
+    callback_id: u64,
+}
+
+impl InputBinding {
+    /// Remove the binding and release its closure.
+    pub fn remove(self) -> UikaResult<()> {
+        unregister_callback(self.callback_id);
+        check_ffi(unsafe { ffi_dispatch::input_remove_binding(self.actor, self.binding) })
+    }
+}
+
+/// Call `callback` whenever `action` reaches `event` on `actor`'s Enhanced
+/// Input component. For a pawn, bind once its input component exists (e.g.
+/// when it is possessed); other actors need `EnableInput` first.
+///
+/// ```ignore
+/// let jump = input::find_input_action::<InputAction>("/Game/Input/IA_Jump.IA_Jump")?;
+/// input::bind_action(pawn, jump, TriggerEvent::Started, move |_| character.jump())?;
+/// ```
+pub fn bind_action<C: UeClass, A: UeClass>(
+    actor: UObjectRef<C>,
+    action: UObjectRef<A>,
+    event: TriggerEvent,
+    mut callback: impl FnMut(InputValue) + Send + 'static,
+) -> UikaResult<InputBinding> {
+    let actor = actor.checked()?.raw();
+    let action = action.checked()?.raw();
+
+    let callback_id = register_callback(move |params| {
+        let value = if params.is_null() {
+            DVec3::ZERO
+        } else {
+            // SAFETY: C++ passes a `double[3]`.
+            let xyz = unsafe { std::ptr::read_unaligned(params as *const [f64; 3]) };
+            DVec3::from_array(xyz)
+        };
+        callback(InputValue(value));
+    });
+
+    let binding =
+        unsafe { ffi_dispatch::input_bind_action(actor, action, event.bits(), callback_id) };
+    if binding == 0 {
+        unregister_callback(callback_id);
+        return Err(UikaError::InvalidOperation(
+            "bind_action failed (actor has no EnhancedInputComponent)".into(),
+        ));
+    }
+    Ok(InputBinding { actor, binding, callback_id })
+}
@@ -0,0 +1,51 @@
+// JSON export/import of UObject properties (feature = "json").
+//
+// The property walk happens on the C++ side (FJsonObjectConverter), which
+// already understands every FProperty kind including containers and nested
+// structs. Rust only moves the UTF-8 JSON text across the FFI and parses it
+// with serde_json. Transient properties are skipped in both directions.
+
+use serde_json::Value;
+use uika_ffi::UikaErrorCode;
+
+use crate::error::{check_ffi, UikaError, UikaResult};
+use crate::ffi_dispatch;
+use crate::traits::UeHandle;
+
+/// Snapshot all reflected (non-transient) properties of `obj` as a JSON
+/// object keyed by property name.
+///
+/// ```ignore
+/// let snapshot = uika::runtime::json::export_object(&actor)?;
+/// ulog!(LOG_DISPLAY, "{snapshot:#}");
+/// ```
+pub fn export_object(obj: &impl UeHandle) -> UikaResult<Value> {
+    let h = obj.checked_handle()?;
+    let mut buf = vec![0u8; 4096];
+    let mut out_len: u32 = 0;
+    let mut code = unsafe {
+        ffi_dispatch::property_export_json(h, buf.as_mut_ptr(), buf.len() as u32, &mut out_len)
+    };
+    if code == UikaErrorCode::BufferTooSmall {
+        buf.resize(out_len as usize, 0);
+        code = unsafe {
+            ffi_dispatch::property_export_json(h, buf.as_mut_ptr(), buf.len() as u32, &mut out_len)
+        };
+    }
+    check_ffi(code)?;
+    buf.truncate(out_len as usize);
+    serde_json::from_slice(&buf)
+        .map_err(|e| UikaError::Internal(format!("exported JSON failed to parse: {e}")))
+}
+
+/// Write the properties present in `value` (a JSON object, e.g. from
+/// [`export_object`]) into `obj`. Properties missing from `value` keep their
+/// current values; unknown keys are ignored.
+pub fn apply_json(obj: &impl UeHandle, value: &Value) -> UikaResult<()> {
+    if !value.is_object() {
+        return Err(UikaError::TypeMismatch);
+    }
+    let h = obj.checked_handle()?;
+    let json = value.to_string();
+    check_ffi(unsafe { ffi_dispatch::property_import_json(h, json.as_ptr(), json.len() as u32) })
+}
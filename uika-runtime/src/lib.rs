@@ -12,10 +12,23 @@ pub mod traits;
 pub mod ffi_dispatch {
     include!(concat!(env!("OUT_DIR"), "/ffi_dispatch.rs"));
 }
+
+/// JSON description of the FFI contract (sub-tables, functions, parameter
+/// and return types), generated by `build.rs` from the same source as
+/// [`ffi_dispatch`]. See `generate_api_manifest` in build.rs for the layout.
+pub const API_MANIFEST_JSON: &str = include_str!(concat!(env!("OUT_DIR"), "/api_manifest.json"));
+
 pub mod object_ref;
+pub mod class_ref;
 pub mod struct_ref;
+pub mod field;
+pub mod property_bulk;
+pub mod property_notify;
+pub mod struct_view;
 pub mod pinned;
+pub mod gc;
 pub mod dynamic_call;
+pub mod output_stash;
 pub mod logging;
 pub mod ffi_guard;
 pub mod containers;
@@ -24,35 +37,97 @@ pub mod reify_registry;
 pub mod ue_math;
 pub mod fname;
 pub mod weak_ptr;
+pub mod object_iter;
 pub mod widget;
+pub mod umg;
 pub mod world;
+pub mod components;
+pub mod subsystems;
+pub mod tasks;
+pub mod executor;
+pub mod timers;
+pub mod input;
+pub mod audio;
+pub mod navigation;
+pub mod assets;
+pub mod asset_registry;
+pub mod savegame;
+pub mod config;
+#[cfg(feature = "gas")]
+pub mod gas;
+pub mod text;
+pub mod gameplay_tag;
+pub mod actor_lifecycle;
+pub mod testing;
+#[cfg(feature = "serde")]
+pub mod serde_support;
+#[cfg(feature = "json")]
+pub mod json;
+#[cfg(feature = "ffi-trace")]
+pub mod ffi_trace;
+#[cfg(feature = "test-harness")]
+pub mod test_harness;
 
 // Re-export the primary public API surface.
-pub use api::{api, init_api};
-pub use error::{check_ffi, check_ffi_ctx, ffi_infallible, ffi_infallible_ctx, UikaError, UikaResult};
+pub use api::{api, func_ptr, init_api};
+pub use error::{check_ffi, check_ffi_ctx, ffi_infallible, ffi_infallible_ctx, ffi_outputs_complete, ErrorContext, UikaError, UikaResult, UikaResultExt};
 pub use traits::{UeClass, UeStruct, UeEnum, UeHandle, ValidHandle, HasParent};
-pub use object_ref::{Checked, UObjectRef};
+pub use object_ref::{Checked, ComponentRef, UObjectRef};
+pub use class_ref::ClassRef;
 pub use struct_ref::UStructRef;
+pub use field::FieldValue;
+pub use property_bulk::{PackedValue, PackedValues};
+pub use property_notify::{PropertyChange, PropertySubscription};
+pub use struct_view::{FieldOffset, PodField, StructView};
 pub use pinned::Pinned;
-pub use dynamic_call::{DynamicCall, DynamicCallResult};
-pub use logging::{LOG_DISPLAY, LOG_WARNING, LOG_ERROR};
-pub use ffi_guard::ffi_boundary;
+pub use gc::GcVisible;
+pub use dynamic_call::{DynamicArgs, DynamicCall, DynamicCallResult, DynamicReturn, LatentCall};
+pub use logging::{LOG_DISPLAY, LOG_WARNING, LOG_ERROR, LOG_LOG, LOG_VERBOSE, LOG_VERY_VERBOSE};
+pub use ffi_guard::{ffi_boundary, set_panic_policy, PanicPolicy};
 pub use containers::{ContainerElement, OwnedStruct, UeArray, UeMap, UeSet};
-pub use delegate_registry::DelegateBinding;
+pub use delegate_registry::{DelegateBinding, DelegateParams};
 
 // Phase 10 re-exports.
 pub use fname::FName;
+pub use text::FText;
+pub use gameplay_tag::{GameplayTag, GameplayTagContainer};
 pub use struct_ref::struct_ref_from_param;
 pub use weak_ptr::TWeakObjectPtr;
+pub use object_iter::ObjectIter;
+pub use timers::{TimerHandle, TimerOptions};
+pub use input::{InputBinding, InputValue, TriggerEvent};
+pub use audio::{AudioHandle, SoundParams};
+pub use assets::{AssetLoad, AssetLoadState, ClassLoad, SoftClassPtr, SoftObjectPath, SoftObjectPtr};
+pub use asset_registry::AssetData;
+pub use savegame::SaveSlot;
+pub use config::ConfigFile;
+#[cfg(feature = "gas")]
+pub use gas::{ActiveEffect, AttributeChange, AttributeSubscription};
+pub use world::{CollisionChannel, CollisionShape, HitResult, SpawnBuilder, TraceParams};
+pub use components::{ActorComponentsExt, AttachmentRule, AttachmentRules};
+pub use tasks::BackgroundTask;
+pub use executor::{spawn_local, LocalTask};
+pub use actor_lifecycle::{ActorLifecycle, EndPlayReason};
 pub use ue_math::{
     Rotator, Transform, LinearColor, Color,
-    Plane, Ray, Sphere, UeBox, UeBox2d, BoxSphereBounds,
+    Plane, Ray, Sphere, UeBox, UeBox2d, BoxSphereBounds, IntRect, Guid, GuidFormat,
 };
 
+// Generated code names glam types through this path, so crates with
+// generated bindings don't need their own glam dependency.
+pub use glam;
+
+#[cfg(feature = "serde")]
+pub use serde_support::SerdeStruct;
+// Generated SerdeStruct impls name serde through the runtime, so
+// uika-bindings needs no serde dependency of its own.
+#[cfg(feature = "serde")]
+pub use serde;
+
 // Re-export FFI types needed by generated code in uika-bindings.
 pub use uika_ffi::{
     UObjectHandle, UClassHandle, FPropertyHandle, UStructHandle,
-    FNameHandle, FWeakObjectHandle, UikaErrorCode,
+    FNameHandle, FTextHandle, FWeakObjectHandle, UikaErrorCode,
 };
 
 // ---------------------------------------------------------------------------
@@ -1,9 +1,187 @@
-// Logging bridge to UE_LOG.
+// Logging bridge to UE_LOG and on-screen debug messages.
+
+use crate::ue_math::Color;
 
 /// Log level constants for the `ulog!` macro.
 pub const LOG_DISPLAY: u8 = 0;
 pub const LOG_WARNING: u8 = 1;
 pub const LOG_ERROR: u8 = 2;
+pub const LOG_LOG: u8 = 3;
+pub const LOG_VERBOSE: u8 = 4;
+pub const LOG_VERY_VERBOSE: u8 = 5;
+
+/// Log a message into a named UE log category (created on first use).
+/// Prefer the [`ulog!`](crate::ulog) macro.
+pub fn log_category(category: &str, level: u8, msg: &str) {
+    // SAFETY: the logging sub-table pointer is always valid after init.
+    unsafe {
+        crate::ffi_dispatch::logging_log_category(
+            category.as_ptr(),
+            category.len() as u32,
+            level,
+            msg.as_ptr(),
+            msg.len() as u32,
+        );
+    }
+}
+
+/// Register a log category with `default_level` as its verbosity: messages
+/// more verbose than that are dropped (and never formatted by
+/// [`ulog!`](crate::ulog)). Ini (`[Core.Log]`), `-LogCmds` and the `Log`
+/// console command override it, as for native categories.
+///
+/// Categories are otherwise created on first use with verbosity
+/// `LOG_LOG`, so register them before logging to them, e.g. in `on_init`:
+///
+/// ```ignore
+/// logging::register_category("LogMyGame", LOG_VERBOSE);
+/// ```
+///
+/// A category that already exists keeps its verbosity.
+pub fn register_category(category: &str, default_level: u8) {
+    if !has_logging_ext() {
+        return;
+    }
+    unsafe {
+        crate::ffi_dispatch::logging_ext_register_category(category.as_ptr(), category.len() as u32, default_level);
+    }
+}
+
+/// Whether a message at `level` in `category` (`None` for `LogUika`) would
+/// be written under the category's current verbosity. Always true with a
+/// plugin older than API version 24, which filters after formatting.
+pub fn is_enabled(category: Option<&str>, level: u8) -> bool {
+    if !has_logging_ext() {
+        return true;
+    }
+    let category = category.unwrap_or("");
+    unsafe { crate::ffi_dispatch::logging_ext_is_enabled(category.as_ptr(), category.len() as u32, level) }
+}
+
+/// Whether the loaded plugin provides `UikaLoggingExtApi` (API version 24+).
+fn has_logging_ext() -> bool {
+    !crate::api::api().logging_ext.is_null()
+}
+
+/// Show a message on screen via `GEngine->AddOnScreenDebugMessage`.
+/// A `key` of -1 always adds a new line; any other key replaces the previous
+/// message with the same key. Prefer the [`uscreen!`](crate::uscreen) macro.
+pub fn screen_message(key: i32, duration: f32, color: Color, msg: &str) {
+    let packed = u32::from_be_bytes([color.r, color.g, color.b, color.a]);
+    unsafe {
+        crate::ffi_dispatch::logging_add_on_screen_message(
+            key,
+            duration,
+            packed,
+            msg.as_ptr(),
+            msg.len() as u32,
+        );
+    }
+}
+
+/// Category for messages from the `log` and `tracing` bridges.
+pub const RUST_LOG_CATEGORY: &str = "LogRust";
+
+/// A [`log::Log`] backend writing to the UE output log, so `log::info!` and
+/// friends from any crate in the DLL end up next to `UE_LOG` output.
+///
+/// Records go to `LogRust`, prefixed with their target:
+/// `error!` → Error, `warn!` → Warning, `info!` → Log, `debug!` → Verbose,
+/// `trace!` → VeryVerbose. Filter with `Log LogRust Verbose` (or
+/// `[Core.Log]` in the ini files) like any UE category.
+///
+/// Installed by `uika::init`; see [`install_log_backend`].
+pub struct UeLogger;
+
+fn log_level(level: log::Level) -> u8 {
+    match level {
+        log::Level::Error => LOG_ERROR,
+        log::Level::Warn => LOG_WARNING,
+        log::Level::Info => LOG_LOG,
+        log::Level::Debug => LOG_VERBOSE,
+        log::Level::Trace => LOG_VERY_VERBOSE,
+    }
+}
+
+impl log::Log for UeLogger {
+    fn enabled(&self, metadata: &log::Metadata<'_>) -> bool {
+        crate::api::is_api_initialized() && is_enabled(Some(RUST_LOG_CATEGORY), log_level(metadata.level()))
+    }
+
+    fn log(&self, record: &log::Record<'_>) {
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+        let msg = format!("[{}] {}", record.target(), record.args());
+        log_category(RUST_LOG_CATEGORY, log_level(record.level()), &msg);
+    }
+
+    fn flush(&self) {}
+}
+
+/// Make [`UeLogger`] the `log` backend. Does nothing if the DLL already set
+/// one (a logger installed before `uika::init` wins). Safe to call from any
+/// thread once the API table is set: UE's log is thread-safe.
+pub fn install_log_backend() {
+    static LOGGER: UeLogger = UeLogger;
+    register_category(RUST_LOG_CATEGORY, LOG_LOG);
+    if log::set_logger(&LOGGER).is_ok() {
+        // Level filtering is UE's; let every record reach `enabled`.
+        log::set_max_level(log::LevelFilter::Trace);
+    }
+}
+
+/// A `tracing-subscriber` layer writing events to the UE output log, with
+/// the same category and level mapping as [`UeLogger`]. Fields other than
+/// `message` are appended as `key=value`.
+///
+/// Not installed automatically; compose it into your subscriber:
+///
+/// ```ignore
+/// use tracing_subscriber::prelude::*;
+/// tracing_subscriber::registry().with(UeTracingLayer).init();
+/// ```
+#[cfg(feature = "tracing")]
+pub struct UeTracingLayer;
+
+#[cfg(feature = "tracing")]
+fn tracing_level(level: &tracing_core::Level) -> u8 {
+    match *level {
+        tracing_core::Level::ERROR => LOG_ERROR,
+        tracing_core::Level::WARN => LOG_WARNING,
+        tracing_core::Level::INFO => LOG_LOG,
+        tracing_core::Level::DEBUG => LOG_VERBOSE,
+        _ => LOG_VERY_VERBOSE,
+    }
+}
+
+#[cfg(feature = "tracing")]
+struct EventMessage(String);
+
+#[cfg(feature = "tracing")]
+impl tracing_core::field::Visit for EventMessage {
+    fn record_debug(&mut self, field: &tracing_core::Field, value: &dyn std::fmt::Debug) {
+        use std::fmt::Write;
+        if field.name() == "message" {
+            let _ = write!(self.0, " {value:?}");
+        } else {
+            let _ = write!(self.0, " {}={value:?}", field.name());
+        }
+    }
+}
+
+#[cfg(feature = "tracing")]
+impl<S: tracing_core::Subscriber> tracing_subscriber::Layer<S> for UeTracingLayer {
+    fn enabled(&self, metadata: &tracing_core::Metadata<'_>, _ctx: tracing_subscriber::layer::Context<'_, S>) -> bool {
+        crate::api::is_api_initialized() && is_enabled(Some(RUST_LOG_CATEGORY), tracing_level(metadata.level()))
+    }
+
+    fn on_event(&self, event: &tracing_core::Event<'_>, _ctx: tracing_subscriber::layer::Context<'_, S>) {
+        let mut msg = EventMessage(format!("[{}]", event.metadata().target()));
+        event.record(&mut msg);
+        log_category(RUST_LOG_CATEGORY, tracing_level(event.metadata().level()), &msg.0);
+    }
+}
 
 /// Log a message through UE_LOG.
 ///
@@ -12,18 +190,48 @@ pub const LOG_ERROR: u8 = 2;
 /// ulog!(LOG_DISPLAY, "Actor {} has {} health", name, hp);
 /// ulog!(LOG_WARNING, "something suspicious");
 /// ulog!(LOG_ERROR, "fatal: {err}");
+/// ulog!(category: LogMyGame, LOG_VERBOSE, "spawned {count} enemies");
 /// ```
 ///
-/// Level constants: `LOG_DISPLAY` (0), `LOG_WARNING` (1), `LOG_ERROR` (2).
+/// Level constants: `LOG_DISPLAY` (0), `LOG_WARNING` (1), `LOG_ERROR` (2),
+/// `LOG_LOG` (3), `LOG_VERBOSE` (4), `LOG_VERY_VERBOSE` (5).
+/// Without a category, messages go to `LogUika`. Messages the category's
+/// verbosity filters out are not formatted; see
+/// [`register_category`](crate::logging::register_category).
 #[macro_export]
 macro_rules! ulog {
+    (category: $category:ident, $level:expr, $($arg:tt)*) => {{
+        let level = $level;
+        if $crate::logging::is_enabled(Some(stringify!($category)), level) {
+            let msg = format!($($arg)*);
+            $crate::logging::log_category(stringify!($category), level, &msg);
+        }
+    }};
     ($level:expr, $($arg:tt)*) => {{
-        let msg = format!($($arg)*);
-        let bytes = msg.as_bytes();
-        // SAFETY: api() is initialized before any Rust code can run, and the
-        // logging sub-table pointer is always valid after init.
-        unsafe {
-            $crate::ffi_dispatch::logging_log($level, bytes.as_ptr(), bytes.len() as u32);
+        let level = $level;
+        if $crate::logging::is_enabled(None, level) {
+            let msg = format!($($arg)*);
+            let bytes = msg.as_bytes();
+            // SAFETY: api() is initialized before any Rust code can run, and the
+            // logging sub-table pointer is always valid after init.
+            unsafe {
+                $crate::ffi_dispatch::logging_log(level, bytes.as_ptr(), bytes.len() as u32);
+            }
         }
     }};
 }
+
+/// Print a message on screen (`GEngine->AddOnScreenDebugMessage`).
+///
+/// Usage:
+/// ```ignore
+/// uscreen!(-1, 5.0, Color::RED, "hit {}", target);
+/// uscreen!(1, 0.0, Color::WHITE, "fps: {fps:.1}"); // key 1: replaced each frame
+/// ```
+#[macro_export]
+macro_rules! uscreen {
+    ($key:expr, $duration:expr, $color:expr, $($arg:tt)*) => {{
+        let msg = format!($($arg)*);
+        $crate::logging::screen_message($key, $duration, $color, &msg);
+    }};
+}
@@ -0,0 +1,97 @@
+// Navigation: navmesh queries and simple AI movement.
+//
+// Thin wrappers over UNavigationSystemV1 so AI code doesn't need
+// DynamicCall. All queries run synchronously on the game thread.
+
+use glam::DVec3;
+use uika_ffi::UikaErrorCode;
+
+use crate::error::{check_ffi, UikaResult};
+use crate::ffi_dispatch;
+use crate::object_ref::UObjectRef;
+use crate::traits::UeClass;
+
+/// Path points from `start` to `end` on the navmesh of `world_context`'s
+/// world, including both endpoints. Fails with `InvalidOperation` if no path
+/// exists.
+pub fn find_path_to_location<C: UeClass>(
+    world_context: UObjectRef<C>,
+    start: DVec3,
+    end: DVec3,
+) -> UikaResult<Vec<DVec3>> {
+    let ctx = world_context.checked()?.raw();
+    let (start, end) = (start.to_array(), end.to_array());
+    let mut points = vec![0.0f64; 32 * 3];
+    let mut count: u32 = 0;
+    let query = |points: &mut Vec<f64>, count: &mut u32| unsafe {
+        ffi_dispatch::navigation_find_path_to_location(
+            ctx,
+            start.as_ptr(),
+            end.as_ptr(),
+            points.as_mut_ptr(),
+            (points.len() / 3) as u32,
+            count,
+        )
+    };
+    let mut code = query(&mut points, &mut count);
+    if code == UikaErrorCode::BufferTooSmall {
+        points.resize(count as usize * 3, 0.0);
+        code = query(&mut points, &mut count);
+    }
+    check_ffi(code)?;
+    Ok(points
+        .chunks_exact(3)
+        .take(count as usize)
+        .map(|p| DVec3::new(p[0], p[1], p[2]))
+        .collect())
+}
+
+/// A random navigable point reachable from `origin` within `radius`.
+pub fn find_random_reachable_point<C: UeClass>(
+    world_context: UObjectRef<C>,
+    origin: DVec3,
+    radius: f32,
+) -> UikaResult<Option<DVec3>> {
+    let ctx = world_context.checked()?.raw();
+    let origin = origin.to_array();
+    let mut out = [0.0f64; 3];
+    let found = unsafe {
+        ffi_dispatch::navigation_find_random_reachable_point(
+            ctx,
+            origin.as_ptr(),
+            radius,
+            out.as_mut_ptr(),
+        )
+    };
+    Ok(found.then(|| DVec3::from_array(out)))
+}
+
+/// The navigable point closest to `point`, searching within `extent` (half
+/// size per axis). `None` uses the navmesh's default query extent.
+pub fn project_point_to_navigation<C: UeClass>(
+    world_context: UObjectRef<C>,
+    point: DVec3,
+    extent: Option<DVec3>,
+) -> UikaResult<Option<DVec3>> {
+    let ctx = world_context.checked()?.raw();
+    let point = point.to_array();
+    let extent = extent.unwrap_or(DVec3::ZERO).to_array();
+    let mut out = [0.0f64; 3];
+    let found = unsafe {
+        ffi_dispatch::navigation_project_point_to_navigation(
+            ctx,
+            point.as_ptr(),
+            extent.as_ptr(),
+            out.as_mut_ptr(),
+        )
+    };
+    Ok(found.then(|| DVec3::from_array(out)))
+}
+
+/// Move `controller`'s pawn to `goal` along the navmesh
+/// (`UAIBlueprintHelperLibrary::SimpleMoveToLocation`).
+pub fn simple_move_to_location<C: UeClass>(controller: UObjectRef<C>, goal: DVec3) -> UikaResult<()> {
+    let controller = controller.checked()?.raw();
+    let goal = goal.to_array();
+    check_ffi(unsafe { ffi_dispatch::navigation_simple_move_to_location(controller, goal.as_ptr()) })
+}
@@ -0,0 +1,86 @@
+// Object iteration: every loaded instance of a class, across all worlds and
+// outside them (assets, subsystems, transient objects).
+//
+// This walks the whole global object array, so it is meant for tools,
+// debugging and occasional scans; per-world actor queries are much cheaper.
+// Objects are fetched a page at a time; ones created or destroyed while the
+// iterator is live may or may not be seen.
+
+use std::marker::PhantomData;
+
+use uika_ffi::{UClassHandle, UObjectHandle};
+
+use crate::error::check_ffi;
+use crate::ffi_dispatch;
+use crate::object_ref::UObjectRef;
+use crate::traits::UeClass;
+
+/// Handles fetched per FFI call.
+const PAGE_SIZE: usize = 256;
+
+/// Every live instance of `T`, subclasses included. Class default objects
+/// are skipped.
+///
+/// ```ignore
+/// for pickup in object_iter::objects_of_class::<APickup>() {
+///     ulog!(LOG_DISPLAY, "{}", pickup.get_name()?);
+/// }
+/// ```
+pub fn objects_of_class<T: UeClass>() -> ObjectIter<T> {
+    ObjectIter {
+        class: T::static_class(),
+        cursor: 0,
+        page: Vec::new().into_iter(),
+        _marker: PhantomData,
+    }
+}
+
+/// Iterator returned by [`objects_of_class`].
+pub struct ObjectIter<T: UeClass> {
+    class: UClassHandle,
+    /// Next object array index to scan; -1 once the array is exhausted.
+    cursor: i32,
+    page: std::vec::IntoIter<UObjectHandle>,
+    _marker: PhantomData<T>,
+}
+
+impl<T: UeClass> ObjectIter<T> {
+    fn fetch_page(&mut self) -> bool {
+        let mut buf = vec![UObjectHandle::null(); PAGE_SIZE];
+        let mut count = 0u32;
+        let code = unsafe {
+            ffi_dispatch::object_iter_next_page(
+                self.class,
+                &mut self.cursor,
+                buf.as_mut_ptr(),
+                PAGE_SIZE as u32,
+                &mut count,
+            )
+        };
+        if check_ffi(code).is_err() {
+            self.cursor = -1;
+            return false;
+        }
+        buf.truncate(count as usize);
+        self.page = buf.into_iter();
+        true
+    }
+}
+
+impl<T: UeClass> Iterator for ObjectIter<T> {
+    type Item = UObjectRef<T>;
+
+    fn next(&mut self) -> Option<UObjectRef<T>> {
+        loop {
+            if let Some(h) = self.page.next() {
+                return Some(unsafe { UObjectRef::from_raw(h) });
+            }
+            // A page can come back empty while the scan is still going.
+            if self.cursor < 0 || !self.fetch_page() {
+                return None;
+            }
+        }
+    }
+}
+
+impl<T: UeClass> std::iter::FusedIterator for ObjectIter<T> {}
@@ -4,11 +4,13 @@
 // invalid at any time between GC sweeps. Use `Pinned<T>` when you need
 // to guarantee liveness.
 
+use std::fmt;
 use std::marker::PhantomData;
 use std::ops::Deref;
 
-use uika_ffi::{UClassHandle, UObjectHandle};
+use uika_ffi::{UClassHandle, UObjectHandle, UikaErrorCode};
 
+use crate::class_ref::ClassRef;
 use crate::error::{check_ffi, UikaError, UikaResult};
 use crate::ffi_dispatch;
 use crate::pinned::Pinned;
@@ -87,24 +89,32 @@ impl<T: UeClass> UObjectRef<T> {
     }
 
     /// Upgrade to a `Pinned<T>`, adding a GC root to keep the object alive.
+    #[track_caller]
     pub fn pin(self) -> UikaResult<Pinned<T>> {
         Pinned::new(self)
     }
 
+    /// Run `f` with the object pinned, unpinning when it returns, or
+    /// unwinds: the GC root can't leak past the scope.
+    ///
+    /// ```ignore
+    /// let total = inventory.pin_scoped(|inv| sum_items(inv))?;
+    /// ```
+    #[track_caller]
+    pub fn pin_scoped<R>(self, f: impl FnOnce(&Pinned<T>) -> R) -> UikaResult<R> {
+        let pinned = Pinned::new(self)?;
+        Ok(f(&pinned))
+    }
+
     /// Get the object's FName as a String.
     pub fn get_name(&self) -> UikaResult<String> {
-        let h = self.checked()?.raw();
-        // Stack buffer — 256 bytes is enough for virtually all UObject names.
-        let mut buf = [0u8; 256];
-        let mut out_len: u32 = 0;
-        let code = unsafe {
-            ffi_dispatch::core_get_name(h, buf.as_mut_ptr(), buf.len() as u32, &mut out_len)
-        };
-        check_ffi(code)?;
-        // C++ writes valid UTF-8 (converted from TCHAR).
-        std::str::from_utf8(&buf[..out_len as usize])
-            .map(|s| s.to_owned())
-            .map_err(|_| UikaError::Internal("name is not valid UTF-8".into()))
+        object_name(self.checked()?.raw())
+    }
+
+    /// Get the object's full path name, e.g.
+    /// `/Game/Maps/Arena.Arena:PersistentLevel.Pickup_3`.
+    pub fn get_path_name(&self) -> UikaResult<String> {
+        object_path_name(self.checked()?.raw())
     }
 
     /// Get the object's UClass handle.
@@ -113,6 +123,13 @@ impl<T: UeClass> UObjectRef<T> {
         Ok(unsafe { ffi_dispatch::core_get_class(h) })
     }
 
+    /// Get the object's UClass as a typed `ClassRef<T>` (e.g. to spawn
+    /// another instance of the same class).
+    pub fn class_ref(&self) -> UikaResult<ClassRef<T>> {
+        let h = self.checked()?.raw();
+        Ok(unsafe { ClassRef::from_raw(UObjectHandle(ffi_dispatch::core_get_class(h).0)) })
+    }
+
     /// Get the object's Outer.
     pub fn get_outer(&self) -> UikaResult<UObjectHandle> {
         let h = self.checked()?.raw();
@@ -123,10 +140,71 @@ impl<T: UeClass> UObjectRef<T> {
     /// Returns `false` if the object has been destroyed.
     #[inline]
     pub fn is_a<U: UeClass>(&self) -> bool {
-        self.is_valid() && unsafe { ffi_dispatch::core_is_a(self.handle, U::static_class()) }
+        // The C++ side already rejects destroyed objects, so one FFI call suffices.
+        unsafe { ffi_dispatch::core_is_a(self.handle, U::static_class()) }
+    }
+
+    /// Non-allocating cast: `Some` if the object is alive and an instance of `U`.
+    /// Prefer this over [`cast`](Self::cast) for frequent type tests (e.g. in tick).
+    #[inline]
+    pub fn try_cast<U: UeClass>(self) -> Option<UObjectRef<U>> {
+        if self.is_a::<U>() {
+            Some(UObjectRef {
+                handle: self.handle,
+                _marker: PhantomData,
+            })
+        } else {
+            None
+        }
+    }
+
+    /// Check whether this object's class implements the interface `I`
+    /// (the UInterface type, e.g. `Interactable`). Returns `false` if the
+    /// object has been destroyed.
+    #[inline]
+    pub fn implements<I: UeClass>(&self) -> bool {
+        unsafe { ffi_dispatch::core_implements_interface(self.handle, I::static_class()) }
+    }
+
+    /// Cast to an interface reference. Fails if the object is destroyed or
+    /// does not implement `I`. Interface functions are invoked via
+    /// [`DynamicCall`](crate::DynamicCall), which dispatches through
+    /// ProcessEvent like UE's `Execute_*` thunks.
+    pub fn cast_to_interface<I: UeClass>(self) -> UikaResult<UObjectRef<I>> {
+        let h = self.checked()?.raw();
+        if unsafe { ffi_dispatch::core_implements_interface(h, I::static_class()) } {
+            Ok(UObjectRef {
+                handle: self.handle,
+                _marker: PhantomData,
+            })
+        } else {
+            Err(UikaError::InvalidCast)
+        }
+    }
+
+    /// Find the most-derived UClass that both `self` and `other` are instances of.
+    /// Returns `None` if either object has been destroyed.
+    pub fn common_base_with<U: UeClass>(&self, other: UObjectRef<U>) -> Option<UClassHandle> {
+        let other_class = unsafe { ffi_dispatch::core_get_class(other.raw()) };
+        let mut class = unsafe { ffi_dispatch::core_get_class(self.handle) };
+        if other_class.is_null() {
+            return None;
+        }
+        while !class.is_null() {
+            if unsafe { ffi_dispatch::core_is_child_of(other_class, class) } {
+                return Some(class);
+            }
+            class = unsafe { ffi_dispatch::core_get_super_class(class) };
+        }
+        None
     }
 }
 
+/// A default subobject (component) of a `#[uclass]` actor, as declared with
+/// `#[ucomponent(...)]`. Accessors return a typed reference to the instance's
+/// component.
+pub type ComponentRef<T> = UObjectRef<T>;
+
 impl<T: HasParent> UObjectRef<T> {
     /// Infallible upcast to the parent class. Zero-cost (same handle).
     #[inline]
@@ -157,15 +235,103 @@ impl<T: UeClass> UeHandle for UObjectRef<T> {
     }
 }
 
-impl<T: UeClass> std::fmt::Debug for UObjectRef<T> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("UObjectRef")
-            .field("handle", &self.handle)
-            .field("valid", &self.is_valid())
-            .finish()
+impl<T: UeClass> fmt::Debug for UObjectRef<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        debug_object(f, "UObjectRef", self.handle, self.is_valid())
     }
 }
 
+impl<T: UeClass> fmt::Display for UObjectRef<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        display_object(f, self.handle, self.is_valid())
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Name / path helpers (shared with Checked and Pinned formatting)
+// ---------------------------------------------------------------------------
+
+/// The FName of a live object (also works for UClass objects).
+fn object_name(h: UObjectHandle) -> UikaResult<String> {
+    // Stack buffer — 256 bytes is enough for virtually all UObject names.
+    let mut buf = [0u8; 256];
+    let mut out_len: u32 = 0;
+    let code = unsafe {
+        ffi_dispatch::core_get_name(h, buf.as_mut_ptr(), buf.len() as u32, &mut out_len)
+    };
+    check_ffi(code)?;
+    // C++ writes valid UTF-8 (converted from TCHAR).
+    std::str::from_utf8(&buf[..out_len as usize])
+        .map(|s| s.to_owned())
+        .map_err(|_| UikaError::Internal("name is not valid UTF-8".into()))
+}
+
+/// The full path name of a live object.
+fn object_path_name(h: UObjectHandle) -> UikaResult<String> {
+    let mut buf = vec![0u8; 256];
+    let mut out_len: u32 = 0;
+    let mut code = unsafe {
+        ffi_dispatch::core_get_path_name(h, buf.as_mut_ptr(), buf.len() as u32, &mut out_len)
+    };
+    if code == UikaErrorCode::BufferTooSmall {
+        buf.resize(out_len as usize, 0);
+        code = unsafe {
+            ffi_dispatch::core_get_path_name(h, buf.as_mut_ptr(), buf.len() as u32, &mut out_len)
+        };
+    }
+    check_ffi(code)?;
+    buf.truncate(out_len as usize);
+    String::from_utf8(buf).map_err(|_| UikaError::Internal("path name is not valid UTF-8".into()))
+}
+
+/// The name of a live object's class.
+fn object_class_name(h: UObjectHandle) -> UikaResult<String> {
+    let class = unsafe { ffi_dispatch::core_get_class(h) };
+    if class.is_null() {
+        return Err(UikaError::ObjectDestroyed);
+    }
+    object_name(UObjectHandle(class.0))
+}
+
+/// `Kind { class, name, path }` for a live object, `Kind { handle, valid: false }`
+/// otherwise.
+pub(crate) fn debug_object(
+    f: &mut fmt::Formatter<'_>,
+    kind: &str,
+    h: UObjectHandle,
+    alive: bool,
+) -> fmt::Result {
+    if alive
+        && let (Ok(class), Ok(name), Ok(path)) =
+            (object_class_name(h), object_name(h), object_path_name(h))
+    {
+        return f
+            .debug_struct(kind)
+            .field("class", &class)
+            .field("name", &name)
+            .field("path", &path)
+            .finish();
+    }
+    f.debug_struct(kind)
+        .field("handle", &h)
+        .field("valid", &false)
+        .finish()
+}
+
+/// `Class /Path/To.Object` (like UObject::GetFullName) for a live object,
+/// `None` for null and `<destroyed>` for a dead handle.
+pub(crate) fn display_object(f: &mut fmt::Formatter<'_>, h: UObjectHandle, alive: bool) -> fmt::Result {
+    if h.is_null() {
+        return f.write_str("None");
+    }
+    if alive
+        && let (Ok(class), Ok(path)) = (object_class_name(h), object_path_name(h))
+    {
+        return write!(f, "{class} {path}");
+    }
+    f.write_str("<destroyed>")
+}
+
 // ---------------------------------------------------------------------------
 // Checked<T>
 // ---------------------------------------------------------------------------
@@ -222,6 +388,12 @@ impl<T: UeClass> Checked<T> {
             Err(UikaError::InvalidCast)
         }
     }
+
+    /// Non-allocating cast: `Some` if the object is an instance of `U`.
+    #[inline]
+    pub fn try_cast<U: UeClass>(self) -> Option<Checked<U>> {
+        self.is_a::<U>().then(|| Checked::new_unchecked(self.handle))
+    }
 }
 
 impl<T: HasParent> Checked<T> {
@@ -249,10 +421,14 @@ impl<T: UeClass> ValidHandle for Checked<T> {
     }
 }
 
-impl<T: UeClass> std::fmt::Debug for Checked<T> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("Checked")
-            .field("handle", &self.handle)
-            .finish()
+impl<T: UeClass> fmt::Debug for Checked<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        debug_object(f, "Checked", self.handle, true)
+    }
+}
+
+impl<T: UeClass> fmt::Display for Checked<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        display_object(f, self.handle, true)
     }
 }
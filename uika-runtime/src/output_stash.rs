@@ -0,0 +1,32 @@
+// Output stash: string outputs of generated function calls that did not fit
+// the buffer the bindings passed in.
+//
+// The C++ wrapper keeps such a string whole, under the param's index, and
+// returns BufferTooSmall. The bindings then fetch it from here instead of
+// calling the UE function a second time, which would repeat its side effects.
+
+use uika_ffi::UikaErrorCode;
+
+use crate::ffi_dispatch;
+
+/// Fetch the stashed strings of the call that just returned BufferTooSmall.
+///
+/// Each output is `(slot, buffer, reported length)`; only those whose length
+/// exceeds their buffer are fetched, into the buffer grown to fit. Returns
+/// the first failure, or `Ok` once every such string is in place.
+pub fn take_strings(outputs: &mut [(u32, &mut Vec<u8>, &mut u32)]) -> UikaErrorCode {
+    let mut result = UikaErrorCode::Ok;
+    for (slot, buf, len) in outputs.iter_mut() {
+        if (**len as usize) <= buf.len() {
+            continue;
+        }
+        buf.resize(**len as usize, 0);
+        let code = unsafe {
+            ffi_dispatch::output_stash_take_string(*slot, buf.as_mut_ptr(), buf.len() as u32, &mut **len)
+        };
+        if code != UikaErrorCode::Ok && result == UikaErrorCode::Ok {
+            result = code;
+        }
+    }
+    result
+}
@@ -4,11 +4,17 @@
 // + remove_gc_root. The GC root prevents garbage collection, while the pinned
 // registration enables fast alive-flag checking via a local AtomicBool instead
 // of an FFI is_valid call on every method invocation.
+//
+// Pins are meant to be short-lived or owned by something with a clear
+// lifetime; a forgotten one keeps its object (and whatever it references)
+// out of GC for good. `set_pin_age_warning` turns on a debug mode that warns
+// about pins held longer than a number of frames.
 
 use std::collections::HashMap;
 use std::marker::PhantomData;
 use std::ops::Deref;
-use std::sync::atomic::{AtomicBool, Ordering};
+use std::panic::Location;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::sync::{Arc, Mutex, OnceLock};
 
 use crate::lock_or_recover;
@@ -17,8 +23,9 @@ use uika_ffi::UObjectHandle;
 
 use crate::error::{UikaError, UikaResult};
 use crate::ffi_dispatch;
-use crate::object_ref::{Checked, UObjectRef};
+use crate::object_ref::{debug_object, display_object, Checked, UObjectRef};
 use crate::traits::{HasParent, UeClass, UeHandle, ValidHandle};
+use crate::weak_ptr::TWeakObjectPtr;
 
 // ---------------------------------------------------------------------------
 // Alive registry — maps UObject pointer → alive flag for fast checked_handle
@@ -45,6 +52,58 @@ pub fn clear_all() {
     if let Ok(mut registry) = alive_registry().lock() {
         registry.clear();
     }
+    lock_or_recover(pin_ages()).clear();
+}
+
+// ---------------------------------------------------------------------------
+// Pin age tracking (debug mode)
+// ---------------------------------------------------------------------------
+
+/// Frames before a pin is reported; 0 when tracking is off.
+static PIN_WARN_FRAMES: AtomicU64 = AtomicU64::new(0);
+static FRAME: AtomicU64 = AtomicU64::new(0);
+static NEXT_PIN_ID: AtomicU64 = AtomicU64::new(1);
+
+struct PinAge {
+    pinned_at: u64,
+    type_name: &'static str,
+    location: &'static Location<'static>,
+    warned: bool,
+}
+
+fn pin_ages() -> &'static Mutex<HashMap<u64, PinAge>> {
+    static AGES: OnceLock<Mutex<HashMap<u64, PinAge>>> = OnceLock::new();
+    AGES.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// Warn (once per pin, to `LogUika`) about `Pinned` values held longer than
+/// `frames` frames, naming the type and where it was pinned. `None` turns
+/// it off (the default); only pins created while it is on are tracked.
+pub fn set_pin_age_warning(frames: Option<u64>) {
+    PIN_WARN_FRAMES.store(frames.map_or(0, |f| f.max(1)), Ordering::Relaxed);
+}
+
+/// Advance the frame count and report pins over the age limit. Called once
+/// per frame from the plugin's tick.
+pub fn tick() {
+    let frame = FRAME.fetch_add(1, Ordering::Relaxed) + 1;
+    let limit = PIN_WARN_FRAMES.load(Ordering::Relaxed);
+    if limit == 0 {
+        return;
+    }
+    let mut warnings = Vec::new();
+    for age in lock_or_recover(pin_ages()).values_mut() {
+        if !age.warned && frame - age.pinned_at > limit {
+            age.warned = true;
+            warnings.push(format!(
+                "[Uika] Pinned<{}> from {} held for more than {limit} frames",
+                age.type_name, age.location
+            ));
+        }
+    }
+    for msg in warnings {
+        crate::logging::log_category("LogUika", crate::logging::LOG_WARNING, &msg);
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -67,6 +126,8 @@ pub fn clear_all() {
 pub struct Pinned<T: UeClass> {
     handle: UObjectHandle,
     alive: Arc<AtomicBool>,
+    /// Key in `pin_ages`, 0 when untracked.
+    pin_id: u64,
     _marker: PhantomData<*const T>, // !Sync
 }
 
@@ -75,6 +136,7 @@ unsafe impl<T: UeClass> Send for Pinned<T> {}
 impl<T: UeClass> Pinned<T> {
     /// Pin an object by adding a GC root and registering for destroy notification.
     /// Fails if the object is already destroyed.
+    #[track_caller]
     pub fn new(obj: UObjectRef<T>) -> UikaResult<Self> {
         if !obj.is_valid() {
             return Err(UikaError::ObjectDestroyed);
@@ -88,13 +150,31 @@ impl<T: UeClass> Pinned<T> {
             ffi_dispatch::lifecycle_add_gc_root(obj.raw());
             ffi_dispatch::lifecycle_register_pinned(obj.raw());
         }
+        let mut pin_id = 0;
+        if PIN_WARN_FRAMES.load(Ordering::Relaxed) != 0 {
+            pin_id = NEXT_PIN_ID.fetch_add(1, Ordering::Relaxed);
+            let age = PinAge {
+                pinned_at: FRAME.load(Ordering::Relaxed),
+                type_name: std::any::type_name::<T>(),
+                location: Location::caller(),
+                warned: false,
+            };
+            lock_or_recover(pin_ages()).insert(pin_id, age);
+        }
         Ok(Pinned {
             handle: obj.raw(),
             alive,
+            pin_id,
             _marker: PhantomData,
         })
     }
 
+    /// A weak pointer to the pinned object, which stays usable after the
+    /// pin is dropped.
+    pub fn downgrade(&self) -> TWeakObjectPtr<T> {
+        TWeakObjectPtr::from_ref(&self.as_ref())
+    }
+
     /// Check whether the pinned object is still alive (local memory read).
     #[inline]
     pub fn is_alive(&self) -> bool {
@@ -128,6 +208,9 @@ impl<T: UeClass> Pinned<T> {
 
 impl<T: UeClass> Drop for Pinned<T> {
     fn drop(&mut self) {
+        if self.pin_id != 0 {
+            lock_or_recover(pin_ages()).remove(&self.pin_id);
+        }
         // Remove from alive registry.
         lock_or_recover(alive_registry()).remove(&self.handle.to_addr());
         // Unregister from C++ destroy notification, then remove GC root.
@@ -165,10 +248,13 @@ impl<T: UeClass> UeHandle for Pinned<T> {
 
 impl<T: UeClass> std::fmt::Debug for Pinned<T> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("Pinned")
-            .field("handle", &self.handle)
-            .field("alive", &self.is_alive())
-            .finish()
+        debug_object(f, "Pinned", self.handle, self.is_alive())
+    }
+}
+
+impl<T: UeClass> std::fmt::Display for Pinned<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        display_object(f, self.handle, self.is_alive())
     }
 }
 
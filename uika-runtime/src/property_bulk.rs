@@ -0,0 +1,267 @@
+// Bulk property access: read or write many scalar properties of one object
+// in a single FFI call, for systems that touch dozens of properties per actor
+// per frame (AI scoring, UI bindings).
+//
+// Values travel as u64 slots tagged with the property's reflected type (see
+// `UikaPropertyBulkApi`), so typed reads out of `PackedValues` are checked
+// like `FieldValue` reads, and a write whose values don't match the
+// properties is rejected before anything is written.
+
+use uika_ffi::{FNameHandle, FPropertyHandle, UObjectHandle, UikaReifyPropType};
+
+use crate::error::{check_ffi, UikaError, UikaResult};
+use crate::ffi_dispatch;
+use crate::field::reify_type;
+use crate::fname::FName;
+use crate::traits::{UeClass, UeHandle};
+
+/// A scalar Rust type that can be carried in a [`PackedValues`] slot.
+pub trait PackedValue: Sized {
+    /// The property type this value is written as by [`PackedValues::push`].
+    const KIND: UikaReifyPropType;
+
+    /// Whether a slot of reflected type `kind` holds this Rust type.
+    fn matches(kind: UikaReifyPropType) -> bool {
+        kind == Self::KIND
+    }
+
+    fn from_slot(slot: u64) -> Self;
+
+    fn to_slot(&self) -> u64;
+}
+
+macro_rules! packed_int {
+    ($ty:ty, $kind:ident) => {
+        impl PackedValue for $ty {
+            const KIND: UikaReifyPropType = UikaReifyPropType::$kind;
+
+            fn from_slot(slot: u64) -> Self {
+                slot as $ty
+            }
+
+            fn to_slot(&self) -> u64 {
+                // Signed values sign-extend, unsigned zero-extend.
+                *self as i64 as u64
+            }
+        }
+    };
+}
+
+packed_int!(i8, Int8);
+packed_int!(i16, Int16);
+packed_int!(i32, Int32);
+packed_int!(u8, UInt8);
+packed_int!(u16, UInt16);
+packed_int!(u32, UInt32);
+
+/// Int64 properties, and enums as their underlying value.
+impl PackedValue for i64 {
+    const KIND: UikaReifyPropType = UikaReifyPropType::Int64;
+
+    fn matches(kind: UikaReifyPropType) -> bool {
+        matches!(kind, UikaReifyPropType::Int64 | UikaReifyPropType::Enum)
+    }
+
+    fn from_slot(slot: u64) -> Self {
+        slot as i64
+    }
+
+    fn to_slot(&self) -> u64 {
+        *self as u64
+    }
+}
+
+impl PackedValue for u64 {
+    const KIND: UikaReifyPropType = UikaReifyPropType::UInt64;
+
+    fn from_slot(slot: u64) -> Self {
+        slot
+    }
+
+    fn to_slot(&self) -> u64 {
+        *self
+    }
+}
+
+impl PackedValue for bool {
+    const KIND: UikaReifyPropType = UikaReifyPropType::Bool;
+
+    fn from_slot(slot: u64) -> Self {
+        slot != 0
+    }
+
+    fn to_slot(&self) -> u64 {
+        u64::from(*self)
+    }
+}
+
+impl PackedValue for f32 {
+    const KIND: UikaReifyPropType = UikaReifyPropType::Float;
+
+    fn from_slot(slot: u64) -> Self {
+        f32::from_bits(slot as u32)
+    }
+
+    fn to_slot(&self) -> u64 {
+        u64::from(self.to_bits())
+    }
+}
+
+impl PackedValue for f64 {
+    const KIND: UikaReifyPropType = UikaReifyPropType::Double;
+
+    fn from_slot(slot: u64) -> Self {
+        f64::from_bits(slot)
+    }
+
+    fn to_slot(&self) -> u64 {
+        self.to_bits()
+    }
+}
+
+impl PackedValue for FName {
+    const KIND: UikaReifyPropType = UikaReifyPropType::Name;
+
+    fn from_slot(slot: u64) -> Self {
+        FName(FNameHandle(slot))
+    }
+
+    fn to_slot(&self) -> u64 {
+        self.0.0
+    }
+}
+
+/// Object and class properties, as untyped handles.
+impl PackedValue for UObjectHandle {
+    const KIND: UikaReifyPropType = UikaReifyPropType::Object;
+
+    fn matches(kind: UikaReifyPropType) -> bool {
+        matches!(kind, UikaReifyPropType::Object | UikaReifyPropType::Class)
+    }
+
+    fn from_slot(slot: u64) -> Self {
+        UObjectHandle::from_addr(slot)
+    }
+
+    fn to_slot(&self) -> u64 {
+        self.to_addr()
+    }
+}
+
+/// Scalar property values, one per property in the order they were read
+/// (or pushed), each tagged with its property type.
+///
+/// ```ignore
+/// let values = property_bulk::get_many(&pawn, &[health, stamina, target])?;
+/// let health: f32 = values.get(0)?;
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PackedValues {
+    slots: Vec<u64>,
+    kinds: Vec<u32>,
+}
+
+impl PackedValues {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_capacity(capacity: usize) -> Self {
+        PackedValues { slots: Vec::with_capacity(capacity), kinds: Vec::with_capacity(capacity) }
+    }
+
+    pub fn len(&self) -> usize {
+        self.slots.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.slots.is_empty()
+    }
+
+    /// The property type of value `index`.
+    pub fn kind(&self, index: usize) -> Option<UikaReifyPropType> {
+        self.kinds.get(index).copied().and_then(reify_type)
+    }
+
+    /// Value `index` as a `T`; `TypeMismatch` if the property holds another
+    /// type.
+    pub fn get<T: PackedValue>(&self, index: usize) -> UikaResult<T> {
+        let kind = self.kind(index).ok_or(UikaError::IndexOutOfRange)?;
+        if !T::matches(kind) {
+            return Err(UikaError::TypeMismatch);
+        }
+        Ok(T::from_slot(self.slots[index]))
+    }
+
+    /// Replace value `index`, keeping its property type.
+    pub fn set<T: PackedValue>(&mut self, index: usize, value: T) -> UikaResult<()> {
+        let kind = self.kind(index).ok_or(UikaError::IndexOutOfRange)?;
+        if !T::matches(kind) {
+            return Err(UikaError::TypeMismatch);
+        }
+        self.slots[index] = value.to_slot();
+        Ok(())
+    }
+
+    /// Append a value for the next property of a [`set_many`].
+    pub fn push<T: PackedValue>(&mut self, value: T) {
+        self.slots.push(value.to_slot());
+        self.kinds.push(T::KIND as u32);
+    }
+
+    /// Append an enum value (its underlying integer).
+    pub fn push_enum(&mut self, value: i64) {
+        self.slots.push(value as u64);
+        self.kinds.push(UikaReifyPropType::Enum as u32);
+    }
+}
+
+/// Find the property `name` of class `T`, for [`get_many`] / [`set_many`].
+/// Look handles up once and keep them; they stay valid for the session.
+pub fn find_property<T: UeClass>(name: &str) -> UikaResult<FPropertyHandle> {
+    let prop = unsafe { ffi_dispatch::reflection_find_property(T::static_class(), name.as_ptr(), name.len() as u32) };
+    if prop.is_null() {
+        return Err(UikaError::PropertyNotFound(format!("{}::{name}", std::any::type_name::<T>())));
+    }
+    Ok(prop)
+}
+
+/// Read the scalar properties `props` of `obj` in one call. `TypeMismatch`
+/// if any of them is a string, text, struct or container property.
+pub fn get_many(obj: &impl UeHandle, props: &[FPropertyHandle]) -> UikaResult<PackedValues> {
+    let h = obj.checked_handle()?;
+    let mut values = PackedValues { slots: vec![0; props.len()], kinds: vec![0; props.len()] };
+    check_ffi(unsafe {
+        ffi_dispatch::property_bulk_get_many(
+            h,
+            props.as_ptr(),
+            props.len() as u32,
+            values.slots.as_mut_ptr(),
+            values.kinds.as_mut_ptr(),
+        )
+    })?;
+    Ok(values)
+}
+
+/// Write `values` to the properties `props` of `obj` in one call, value `i`
+/// to property `i`. Every value's type must match its property's (checked
+/// before anything is written); values read with [`get_many`] always do.
+pub fn set_many(obj: &impl UeHandle, props: &[FPropertyHandle], values: &PackedValues) -> UikaResult<()> {
+    if values.len() != props.len() {
+        return Err(UikaError::InvalidOperation(format!(
+            "set_many got {} values for {} properties",
+            values.len(),
+            props.len()
+        )));
+    }
+    let h = obj.checked_handle()?;
+    check_ffi(unsafe {
+        ffi_dispatch::property_bulk_set_many(
+            h,
+            props.as_ptr(),
+            props.len() as u32,
+            values.slots.as_ptr(),
+            values.kinds.as_ptr(),
+        )
+    })
+}
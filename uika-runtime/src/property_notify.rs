@@ -0,0 +1,126 @@
+// Property change notifications: call a Rust closure when a property of a
+// UObject changes, for editor tooling and reactive gameplay code.
+//
+// In editor builds, every change reported through
+// `FCoreUObjectDelegates::OnObjectPropertyChanged` is delivered: details
+// panel edits, undo/redo, and anything calling `PostEditChangeProperty`.
+// Packaged builds have no such hook, so gameplay code that changes a property
+// announces it with `notify_changed`, which delivers to the same
+// subscriptions in every build. Plain property writes notify nobody.
+
+use uika_ffi::{FPropertyHandle, UObjectHandle, UikaPropertyChange};
+
+use crate::delegate_registry::{register_callback, unregister_callback};
+use crate::error::{check_ffi, UikaError, UikaResult};
+use crate::ffi_dispatch;
+use crate::fname::FName;
+use crate::traits::UeHandle;
+
+pub use uika_ffi::{
+    UIKA_PROPERTY_CHANGE_ARRAY_ADD, UIKA_PROPERTY_CHANGE_ARRAY_CLEAR, UIKA_PROPERTY_CHANGE_ARRAY_MOVE,
+    UIKA_PROPERTY_CHANGE_ARRAY_REMOVE, UIKA_PROPERTY_CHANGE_DUPLICATE, UIKA_PROPERTY_CHANGE_INTERACTIVE,
+    UIKA_PROPERTY_CHANGE_REDIRECTED, UIKA_PROPERTY_CHANGE_UNSPECIFIED, UIKA_PROPERTY_CHANGE_VALUE_SET,
+};
+
+/// A property change delivered to [`on_property_changed`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PropertyChange {
+    /// The object whose property changed.
+    pub object: UObjectHandle,
+    /// The property that changed; for an edit inside a struct, the innermost
+    /// member (`"X"` of `Location.X`).
+    pub property: FName,
+    /// The object's own property containing it (`"Location"`); the same as
+    /// `property` for a direct change.
+    pub member_property: FName,
+    /// `EPropertyChangeType` bits (`UIKA_PROPERTY_CHANGE_*`).
+    pub change_type: u32,
+}
+
+impl PropertyChange {
+    /// The value is still being dragged in the editor; a final change follows.
+    pub fn is_interactive(&self) -> bool {
+        self.change_type & UIKA_PROPERTY_CHANGE_INTERACTIVE != 0
+    }
+}
+
+/// Handle to a subscription made with [`on_property_changed`] or
+/// [`on_any_property_changed`]. Dropping the handle does NOT unsubscribe;
+/// call [`unsubscribe`](Self::unsubscribe).
+#[derive(Debug)]
+pub struct PropertySubscription {
+    binding: u32,
+    callback_id: u64,
+}
+
+impl PropertySubscription {
+    /// Remove the subscription and release its closure.
+    pub fn unsubscribe(self) -> UikaResult<()> {
+        unregister_callback(self.callback_id);
+        check_ffi(unsafe { ffi_dispatch::property_notify_unsubscribe(self.binding) })
+    }
+}
+
+/// Call `callback` whenever a property of `obj` changes; only property
+/// `name` (matched against both the property and its member property) if
+/// given.
+///
+/// ```ignore
+/// property_notify::on_property_changed(&light, Some("Intensity"), move |change| {
+///     if !change.is_interactive() { rebake(change.object); }
+/// })?;
+/// ```
+pub fn on_property_changed(
+    obj: &impl UeHandle,
+    name: Option<&str>,
+    callback: impl FnMut(PropertyChange) + Send + 'static,
+) -> UikaResult<PropertySubscription> {
+    let h = obj.checked_handle()?;
+    subscribe(h, name, callback)
+}
+
+/// Call `callback` whenever a property of any object changes; only property
+/// `name` if given.
+pub fn on_any_property_changed(
+    name: Option<&str>,
+    callback: impl FnMut(PropertyChange) + Send + 'static,
+) -> UikaResult<PropertySubscription> {
+    subscribe(UObjectHandle::null(), name, callback)
+}
+
+fn subscribe(
+    obj: UObjectHandle,
+    name: Option<&str>,
+    mut callback: impl FnMut(PropertyChange) + Send + 'static,
+) -> UikaResult<PropertySubscription> {
+    let name = name.unwrap_or("");
+    let callback_id = register_callback(move |params| {
+        if params.is_null() {
+            return;
+        }
+        // SAFETY: C++ passes a `FUikaPropertyChange`.
+        let change = unsafe { std::ptr::read_unaligned(params as *const UikaPropertyChange) };
+        callback(PropertyChange {
+            object: change.object,
+            property: FName(change.property),
+            member_property: FName(change.member_property),
+            change_type: change.change_type,
+        });
+    });
+
+    let binding =
+        unsafe { ffi_dispatch::property_notify_subscribe(obj, name.as_ptr(), name.len() as u32, callback_id) };
+    if binding == 0 {
+        unregister_callback(callback_id);
+        return Err(UikaError::InvalidOperation("property subscription rejected".into()));
+    }
+    Ok(PropertySubscription { binding, callback_id })
+}
+
+/// Tell subscribers that property `prop` of `obj` was changed from code
+/// (delivered as `UIKA_PROPERTY_CHANGE_VALUE_SET`). Works in every build;
+/// unlike `PostEditChangeProperty`, nothing else on the UE side is triggered.
+pub fn notify_changed(obj: &impl UeHandle, prop: FPropertyHandle) -> UikaResult<()> {
+    let h = obj.checked_handle()?;
+    check_ffi(unsafe { ffi_dispatch::property_notify_notify_changed(h, prop) })
+}
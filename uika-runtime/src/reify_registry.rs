@@ -6,7 +6,7 @@
 // 2. Function registry: maps callback_id -> Rust function closure
 // 3. Instance data: maps UObject pointer -> allocated Rust data
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::sync::{Arc, Mutex, OnceLock, RwLock};
 
 use crate::{lock_or_recover, read_or_recover, write_or_recover};
@@ -15,6 +15,12 @@ use crate::{lock_or_recover, read_or_recover, write_or_recover};
 // Inventory-based auto-registration
 // ---------------------------------------------------------------------------
 
+/// Submitted by `#[ustruct]` — creates and links the UScriptStruct.
+pub struct StructRegistration {
+    pub register: fn(),
+}
+inventory::collect!(StructRegistration);
+
 /// Submitted by `#[uclass]` — holds register + finalize fn pointers.
 pub struct ClassRegistration {
     pub register: fn(),
@@ -28,8 +34,14 @@ pub struct ClassFunctionRegistration {
 }
 inventory::collect!(ClassFunctionRegistration);
 
-/// Three-phase iteration: register all → register all functions → finalize all.
+/// Structs first (classes and functions may use them), then three phases:
+/// register all → register all functions → finalize all.
 pub fn register_all_from_inventory() {
+    let mut struct_count = 0u32;
+    for sreg in inventory::iter::<StructRegistration> {
+        (sreg.register)();
+        struct_count += 1;
+    }
     let mut class_count = 0u32;
     for reg in inventory::iter::<ClassRegistration> {
         (reg.register)();
@@ -47,8 +59,8 @@ pub fn register_all_from_inventory() {
     // Log registration summary (helps diagnose hot-reload issues).
     let total_funcs = read_or_recover(func_registry()).len();
     let msg = format!(
-        "[Uika] register_all_from_inventory: {} classes, {} impl blocks, {} function callbacks",
-        class_count, func_reg_count, total_funcs,
+        "[Uika] register_all_from_inventory: {} structs, {} classes, {} impl blocks, {} function callbacks",
+        struct_count, class_count, func_reg_count, total_funcs,
     );
     let bytes = msg.as_bytes();
     unsafe {
@@ -67,6 +79,8 @@ pub struct RustTypeInfo {
     pub construct_fn: fn() -> *mut u8,
     /// Drop and deallocate an instance previously created by `construct_fn`.
     pub drop_fn: unsafe fn(*mut u8),
+    /// Report the UObjects an instance's Rust data references, for GC.
+    pub references_fn: unsafe fn(*const u8, &mut dyn FnMut(UObjectHandle)),
 }
 
 use crate::ffi_dispatch::NativePtr;
@@ -85,6 +99,8 @@ type ReifyFunctionCallback = Arc<dyn Fn(UObjectHandle, *mut u8, NativePtr) + Sen
 static TYPE_REGISTRY: OnceLock<Mutex<HashMap<u64, RustTypeInfo>>> = OnceLock::new();
 static FUNC_REGISTRY: OnceLock<RwLock<Vec<ReifyFunctionCallback>>> = OnceLock::new();
 static INSTANCE_DATA: OnceLock<RwLock<HashMap<u64, InstanceEntry>>> = OnceLock::new();
+/// Types whose functions panicked under `PanicPolicy::DisableClass`.
+static DISABLED_TYPES: OnceLock<Mutex<HashSet<u64>>> = OnceLock::new();
 
 struct InstanceEntry {
     data: *mut u8,
@@ -103,6 +119,10 @@ fn func_registry() -> &'static RwLock<Vec<ReifyFunctionCallback>> {
     FUNC_REGISTRY.get_or_init(|| RwLock::new(Vec::new()))
 }
 
+fn disabled_types() -> &'static Mutex<HashSet<u64>> {
+    DISABLED_TYPES.get_or_init(|| Mutex::new(HashSet::new()))
+}
+
 fn instance_data() -> &'static RwLock<HashMap<u64, InstanceEntry>> {
     INSTANCE_DATA.get_or_init(|| RwLock::new(HashMap::new()))
 }
@@ -181,10 +201,15 @@ pub fn invoke_function(callback_id: u64, obj: UObjectHandle, params: NativePtr)
     let key = obj.to_addr();
 
     // Look up instance data for this object (read lock — non-exclusive).
-    let rust_data = read_or_recover(instance_data())
+    let (rust_data, type_id) = read_or_recover(instance_data())
         .get(&key)
-        .map(|e| e.data)
-        .unwrap_or(std::ptr::null_mut());
+        .map(|e| (e.data, Some(e.type_id)))
+        .unwrap_or((std::ptr::null_mut(), None));
+    if let Some(type_id) = type_id
+        && lock_or_recover(disabled_types()).contains(&type_id)
+    {
+        return;
+    }
 
     // Clone the callback Arc out of the registry and release the read lock
     // BEFORE invoking the callback. This prevents deadlocks if the callback
@@ -195,7 +220,15 @@ pub fn invoke_function(callback_id: u64, obj: UObjectHandle, params: NativePtr)
     };
 
     if let Some(func) = func {
-        func(obj, rust_data, params);
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| func(obj, rust_data, params)));
+        if let Err(payload) = result {
+            if let Some(type_id) = type_id
+                && crate::ffi_guard::panic_policy() == crate::ffi_guard::PanicPolicy::DisableClass
+            {
+                disable_type(type_id);
+            }
+            std::panic::resume_unwind(payload);
+        }
     } else if crate::api::is_api_initialized() {
         let vec_len = read_or_recover(func_registry()).len();
         let msg = format!(
@@ -209,6 +242,16 @@ pub fn invoke_function(callback_id: u64, obj: UObjectHandle, params: NativePtr)
     }
 }
 
+/// Stop invoking the functions of `type_id` (until the next reload).
+fn disable_type(type_id: u64) {
+    if !lock_or_recover(disabled_types()).insert(type_id) || !crate::api::is_api_initialized() {
+        return;
+    }
+    let name = lock_or_recover(type_registry()).get(&type_id).map_or("<unknown>", |info| info.name);
+    let msg = format!("[Uika] {name} panicked; its functions are disabled until the DLL is reloaded");
+    crate::logging::log_category("LogUika", crate::logging::LOG_ERROR, &msg);
+}
+
 /// Clear all registries and drop all instance data.
 /// Called during shutdown before DLL unload (enables hot reload).
 pub fn clear_all() {
@@ -233,6 +276,23 @@ pub fn clear_all() {
     if let Some(types) = TYPE_REGISTRY.get() {
         lock_or_recover(types).clear();
     }
+    if let Some(disabled) = DISABLED_TYPES.get() {
+        lock_or_recover(disabled).clear();
+    }
+}
+
+/// Report the references held by every live instance's Rust data.
+pub(crate) fn report_instance_references(report: &mut dyn FnMut(UObjectHandle)) {
+    let Some(instances) = INSTANCE_DATA.get() else {
+        return;
+    };
+    let map = read_or_recover(instances);
+    let types = lock_or_recover(type_registry());
+    for entry in map.values() {
+        if let Some(info) = types.get(&entry.type_id) {
+            unsafe { (info.references_fn)(entry.data, report) };
+        }
+    }
 }
 
 /// Get the Rust instance data pointer for a UObject.
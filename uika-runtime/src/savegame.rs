@@ -0,0 +1,287 @@
+// SaveGame: save slots holding a `USaveGame` object or a raw byte blob.
+//
+// A [`SaveSlot`] names a slot (plus platform user index). Rust data does not
+// need a `USaveGame` subclass: with the `serde` and `json` features,
+// [`SaveSlot::save`] / [`SaveSlot::load`] round-trip any serde value
+// (including `OwnedStruct<T>` of a `#[ustruct]`) through the slot's byte blob.
+// The `*_async` variants do the disk I/O on a background thread and call back
+// on the game thread.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+
+use uika_ffi::{UikaErrorCode, UikaSaveGameResult};
+
+use crate::delegate_registry::{register_callback, unregister_callback};
+use crate::error::{check_ffi, UikaError, UikaResult};
+use crate::ffi_dispatch::{self, NativePtr};
+use crate::object_ref::UObjectRef;
+use crate::traits::UeClass;
+
+/// Create a save game object of class `T` (a `USaveGame` subclass), ready to
+/// fill in and pass to [`SaveSlot::save_object`].
+pub fn create_save_game<T: UeClass>() -> UikaResult<UObjectRef<T>> {
+    let h = unsafe { ffi_dispatch::save_game_create_save_game_object(T::static_class()) };
+    if h.is_null() {
+        return Err(UikaError::InvalidOperation("create_save_game: class is not a USaveGame".into()));
+    }
+    Ok(unsafe { UObjectRef::from_raw(h) })
+}
+
+/// A named save slot for one platform user.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct SaveSlot {
+    name: String,
+    user_index: i32,
+}
+
+impl SaveSlot {
+    /// Slot `name` for the first local user.
+    pub fn new(name: impl Into<String>) -> Self {
+        SaveSlot { name: name.into(), user_index: 0 }
+    }
+
+    /// Slot `name` for the platform user `user_index`.
+    pub fn for_user(name: impl Into<String>, user_index: i32) -> Self {
+        SaveSlot { name: name.into(), user_index }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn user_index(&self) -> i32 {
+        self.user_index
+    }
+
+    pub fn exists(&self) -> bool {
+        unsafe {
+            ffi_dispatch::save_game_does_save_game_exist(
+                self.name.as_ptr(),
+                self.name.len() as u32,
+                self.user_index,
+            )
+        }
+    }
+
+    /// Delete the slot. Deleting a missing slot succeeds.
+    pub fn delete(&self) -> UikaResult<()> {
+        check_ffi(unsafe {
+            ffi_dispatch::save_game_delete_game_in_slot(
+                self.name.as_ptr(),
+                self.name.len() as u32,
+                self.user_index,
+            )
+        })
+    }
+
+    /// Save a `USaveGame` object (see [`create_save_game`]).
+    pub fn save_object<T: UeClass>(&self, save_game: UObjectRef<T>) -> UikaResult<()> {
+        let obj = save_game.checked()?.raw();
+        check_ffi(unsafe {
+            ffi_dispatch::save_game_save_game_to_slot(
+                obj,
+                self.name.as_ptr(),
+                self.name.len() as u32,
+                self.user_index,
+            )
+        })
+    }
+
+    /// Load the slot's `USaveGame` object. `Ok(None)` if the slot doesn't
+    /// exist or holds no save game object.
+    pub fn load_object<T: UeClass>(&self) -> UikaResult<Option<UObjectRef<T>>> {
+        let h = unsafe {
+            ffi_dispatch::save_game_load_game_from_slot(
+                self.name.as_ptr(),
+                self.name.len() as u32,
+                self.user_index,
+            )
+        };
+        if h.is_null() {
+            return Ok(None);
+        }
+        unsafe { UObjectRef::<T>::from_raw(h) }.cast().map(Some)
+    }
+
+    /// Write `data` as the slot's contents, replacing what was there.
+    pub fn save_bytes(&self, data: &[u8]) -> UikaResult<()> {
+        check_ffi(unsafe {
+            ffi_dispatch::save_game_save_data_to_slot(
+                data.as_ptr(),
+                data.len() as u32,
+                self.name.as_ptr(),
+                self.name.len() as u32,
+                self.user_index,
+            )
+        })
+    }
+
+    /// Read the slot's contents. `Ok(None)` if the slot doesn't exist.
+    pub fn load_bytes(&self) -> UikaResult<Option<Vec<u8>>> {
+        if !self.exists() {
+            return Ok(None);
+        }
+        let mut buf = vec![0u8; 4096];
+        let mut out_len: u32 = 0;
+        let read = |buf: &mut Vec<u8>, out_len: &mut u32| unsafe {
+            ffi_dispatch::save_game_load_data_from_slot(
+                self.name.as_ptr(),
+                self.name.len() as u32,
+                self.user_index,
+                buf.as_mut_ptr(),
+                buf.len() as u32,
+                out_len,
+            )
+        };
+        let mut code = read(&mut buf, &mut out_len);
+        if code == UikaErrorCode::BufferTooSmall {
+            buf.resize(out_len as usize, 0);
+            code = read(&mut buf, &mut out_len);
+        }
+        check_ffi(code)?;
+        buf.truncate(out_len as usize);
+        Ok(Some(buf))
+    }
+
+    /// [`save_bytes`](Self::save_bytes) on a background thread. `on_done`
+    /// runs on the game thread once the write finishes.
+    pub fn save_bytes_async(
+        &self,
+        data: &[u8],
+        on_done: impl FnOnce(UikaResult<()>) + Send + 'static,
+    ) -> UikaResult<()> {
+        let callback_id = register_once(move |result| {
+            on_done(if result.success {
+                Ok(())
+            } else {
+                Err(UikaError::InvalidOperation("async save to slot failed".into()))
+            });
+        });
+        let code = unsafe {
+            ffi_dispatch::save_game_async_save_data_to_slot(
+                data.as_ptr(),
+                data.len() as u32,
+                self.name.as_ptr(),
+                self.name.len() as u32,
+                self.user_index,
+                callback_id,
+            )
+        };
+        start_async(code, callback_id)
+    }
+
+    /// [`load_bytes`](Self::load_bytes) on a background thread. `on_done`
+    /// runs on the game thread with `Ok(None)` if the slot couldn't be read
+    /// (typically because it doesn't exist).
+    pub fn load_bytes_async(
+        &self,
+        on_done: impl FnOnce(UikaResult<Option<Vec<u8>>>) + Send + 'static,
+    ) -> UikaResult<()> {
+        let callback_id = register_once(move |result| {
+            let bytes = result.success.then(|| {
+                if result.data.is_null() {
+                    return Vec::new();
+                }
+                // SAFETY: C++ keeps `data` alive for the duration of the callback.
+                unsafe { std::slice::from_raw_parts(result.data, result.len as usize) }.to_vec()
+            });
+            on_done(Ok(bytes));
+        });
+        let code = unsafe {
+            ffi_dispatch::save_game_async_load_data_from_slot(
+                self.name.as_ptr(),
+                self.name.len() as u32,
+                self.user_index,
+                callback_id,
+            )
+        };
+        start_async(code, callback_id)
+    }
+}
+
+/// Typed save data: serde values stored as JSON in the slot's byte blob.
+#[cfg(all(feature = "serde", feature = "json"))]
+impl SaveSlot {
+    /// Serialize `value` into the slot.
+    ///
+    /// ```ignore
+    /// #[derive(Serialize, Deserialize)]
+    /// struct Progress { level: u32, spawn: OwnedStruct<FVector> }
+    ///
+    /// let slot = SaveSlot::new("progress");
+    /// slot.save(&progress)?;
+    /// let restored: Option<Progress> = slot.load()?;
+    /// ```
+    pub fn save<V: serde::Serialize>(&self, value: &V) -> UikaResult<()> {
+        self.save_bytes(&encode(value)?)
+    }
+
+    /// Deserialize the slot's contents. `Ok(None)` if the slot doesn't exist.
+    pub fn load<V: serde::de::DeserializeOwned>(&self) -> UikaResult<Option<V>> {
+        self.load_bytes()?.map(|bytes| decode(&bytes)).transpose()
+    }
+
+    /// [`save`](Self::save) with the write on a background thread. The value
+    /// is serialized before this returns.
+    pub fn save_async<V: serde::Serialize>(
+        &self,
+        value: &V,
+        on_done: impl FnOnce(UikaResult<()>) + Send + 'static,
+    ) -> UikaResult<()> {
+        self.save_bytes_async(&encode(value)?, on_done)
+    }
+
+    /// [`load`](Self::load) with the read on a background thread;
+    /// deserialization happens on the game thread before `on_done` runs.
+    pub fn load_async<V: serde::de::DeserializeOwned>(
+        &self,
+        on_done: impl FnOnce(UikaResult<Option<V>>) + Send + 'static,
+    ) -> UikaResult<()> {
+        self.load_bytes_async(move |result| {
+            on_done(result.and_then(|bytes| bytes.map(|b| decode(&b)).transpose()));
+        })
+    }
+}
+
+#[cfg(all(feature = "serde", feature = "json"))]
+fn encode<V: serde::Serialize>(value: &V) -> UikaResult<Vec<u8>> {
+    serde_json::to_vec(value)
+        .map_err(|e| UikaError::InvalidOperation(format!("save data failed to serialize: {e}")))
+}
+
+#[cfg(all(feature = "serde", feature = "json"))]
+fn decode<V: serde::de::DeserializeOwned>(bytes: &[u8]) -> UikaResult<V> {
+    serde_json::from_slice(bytes)
+        .map_err(|e| UikaError::InvalidOperation(format!("save data failed to deserialize: {e}")))
+}
+
+/// Register a completion callback that unregisters itself after it fires.
+fn register_once(on_done: impl FnOnce(&UikaSaveGameResult) + Send + 'static) -> u64 {
+    let id = Arc::new(AtomicU64::new(0));
+    let mut on_done = Some(on_done);
+    let callback_id = {
+        let id = Arc::clone(&id);
+        register_callback(move |params: NativePtr| {
+            if let Some(f) = on_done.take()
+                && !params.is_null()
+            {
+                // SAFETY: C++ passes a `FUikaSaveGameResult*`.
+                f(unsafe { &*(params as *const UikaSaveGameResult) });
+            }
+            unregister_callback(id.load(Ordering::Acquire));
+        })
+    };
+    // Completion is always posted to a later game-thread tick, so the id is
+    // stored before the callback can run.
+    id.store(callback_id, Ordering::Release);
+    callback_id
+}
+
+fn start_async(code: UikaErrorCode, callback_id: u64) -> UikaResult<()> {
+    let result = check_ffi(code);
+    if result.is_err() {
+        unregister_callback(callback_id);
+    }
+    result
+}
@@ -0,0 +1,84 @@
+// Serde support for UE structs (feature = "serde").
+//
+// UE structs are opaque byte buffers on the Rust side, so they cannot derive
+// Serialize/Deserialize. Instead codegen implements `SerdeStruct` for every
+// generated struct type, walking its reflected properties through the
+// generated getters/setters. `OwnedStruct<T>` then serializes as a struct
+// keyed by the UE property names (`{"X": 1.0, "Y": 2.0, "Z": 3.0}`).
+//
+// Only value-like properties take part: numbers, bools, strings, FNames (as
+// their string) and nested structs. Object references, containers, enums
+// and delegates are skipped.
+
+use std::fmt;
+use std::marker::PhantomData;
+
+use serde::de::{IgnoredAny, MapAccess, Visitor};
+use serde::ser::SerializeStruct;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::containers::OwnedStruct;
+use crate::struct_ref::UStructRef;
+use crate::traits::UeStruct;
+
+/// A UE struct whose reflected properties can be serialized field by field.
+/// Implemented by generated code; not intended for manual implementation.
+pub trait SerdeStruct: UeStruct + Sized {
+    /// The struct name used as the serde struct name (e.g. `"FVector"`).
+    const NAME: &'static str;
+    /// The serialized property names, in declaration order.
+    const FIELDS: &'static [&'static str];
+
+    /// Serialize every field of `r` into `s`.
+    fn serialize_fields<S: SerializeStruct>(r: &UStructRef<Self>, s: &mut S) -> Result<(), S::Error>;
+
+    /// Read the value for `field` from `map` and write it into `r`. Unknown
+    /// fields are consumed and ignored.
+    fn deserialize_field<'de, A: MapAccess<'de>>(
+        r: &UStructRef<Self>,
+        field: &str,
+        map: &mut A,
+    ) -> Result<(), A::Error>;
+}
+
+impl<T: SerdeStruct> Serialize for OwnedStruct<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut s = serializer.serialize_struct(T::NAME, T::FIELDS.len())?;
+        T::serialize_fields(&self.as_ref(), &mut s)?;
+        s.end()
+    }
+}
+
+impl<'de, T: SerdeStruct> Deserialize<'de> for OwnedStruct<T> {
+    /// Starts from the struct's C++ default and overwrites the fields present
+    /// in the input, so missing fields keep their UE defaults.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_struct(T::NAME, T::FIELDS, OwnedStructVisitor(PhantomData))
+    }
+}
+
+struct OwnedStructVisitor<T>(PhantomData<fn() -> T>);
+
+impl<'de, T: SerdeStruct> Visitor<'de> for OwnedStructVisitor<T> {
+    type Value = OwnedStruct<T>;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "struct {}", T::NAME)
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let value = OwnedStruct::<T>::new();
+        let r = value.as_ref();
+        while let Some(key) = map.next_key::<String>()? {
+            T::deserialize_field(&r, &key, &mut map)?;
+        }
+        Ok(value)
+    }
+}
+
+/// Consume and discard the value of an unknown field. Used by generated
+/// `deserialize_field` fallbacks.
+pub fn skip_field<'de, A: MapAccess<'de>>(map: &mut A) -> Result<(), A::Error> {
+    map.next_value::<IgnoredAny>().map(|_| ())
+}
+
@@ -0,0 +1,162 @@
+// StructView<'a, T>: zero-copy, read-only view of a struct living in UE-owned
+// memory (a property of a UObject, a parameter buffer, a container element).
+//
+// Fields are read in place at their reflected offsets instead of copying the
+// whole struct into an OwnedStruct first. Offsets are resolved once into a
+// `FieldOffset`, meant to be cached in a `static`, so a per-frame read of a
+// transform's location is one unaligned load per field.
+//
+// Only plain-old-data numerics are read in place (`PodField`); other field
+// types go through the property API via `field`, as on `UStructRef`.
+
+use std::marker::PhantomData;
+
+use uika_ffi::UObjectHandle;
+
+use crate::containers::OwnedStruct;
+use crate::error::UikaResult;
+use crate::ffi_dispatch::{self, NativePtr};
+use crate::field::{check_field_type, find_struct_field, FieldValue};
+use crate::struct_ref::UStructRef;
+use crate::traits::UeStruct;
+
+/// A field type that can be read straight out of struct memory.
+///
+/// # Safety
+/// Every reflected property `matches` accepts must store the value with
+/// exactly this type's size and representation (no bitfields, no
+/// conversions).
+pub unsafe trait PodField: FieldValue + Copy {}
+
+unsafe impl PodField for i32 {}
+unsafe impl PodField for i64 {}
+unsafe impl PodField for u8 {}
+unsafe impl PodField for f32 {}
+unsafe impl PodField for f64 {}
+
+/// The resolved byte offset of field `F` of struct `T`.
+///
+/// Resolve once and cache it:
+///
+/// ```ignore
+/// static X: OnceLock<FieldOffset<FVector, f64>> = OnceLock::new();
+/// let x = view.get(X.get_or_init(|| FieldOffset::resolve("X").unwrap()));
+/// ```
+pub struct FieldOffset<T: UeStruct, F: FieldValue> {
+    offset: usize,
+    _marker: PhantomData<fn() -> (T, F)>,
+}
+
+impl<T: UeStruct, F: FieldValue> FieldOffset<T, F> {
+    /// Find the field `name` of `T` and check that it holds an `F`.
+    pub fn resolve(name: &str) -> UikaResult<Self> {
+        let prop = find_struct_field::<T>(name)?;
+        check_field_type::<F>(prop)?;
+        let offset = unsafe { ffi_dispatch::reflection_get_property_offset(prop) } as usize;
+        Ok(FieldOffset { offset, _marker: PhantomData })
+    }
+
+    /// Byte offset of the field from the start of the struct.
+    #[inline]
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+}
+
+impl<T: UeStruct, F: FieldValue> Clone for FieldOffset<T, F> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: UeStruct, F: FieldValue> Copy for FieldOffset<T, F> {}
+
+impl<T: UeStruct, F: FieldValue> std::fmt::Debug for FieldOffset<T, F> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FieldOffset").field("offset", &self.offset).finish()
+    }
+}
+
+/// A read-only view of a `T` in memory owned by someone else, valid for `'a`.
+///
+/// Nothing is copied: [`get`](Self::get) reads a field where it lives, and
+/// [`nested`](Self::nested) narrows the view to a nested struct. For views
+/// handed out by generated accessors, `'a` borrows the object reference; the
+/// UObject itself must stay alive while the view is used, as for any other
+/// access through the reference.
+pub struct StructView<'a, T: UeStruct> {
+    ptr: NativePtr,
+    _marker: PhantomData<&'a T>,
+}
+
+impl<'a, T: UeStruct> StructView<'a, T> {
+    /// View the `T` at `ptr`.
+    ///
+    /// # Safety
+    /// `ptr` must point to a live, initialized `T` that is not written
+    /// through any other path for `'a`.
+    #[inline]
+    pub unsafe fn from_raw(ptr: NativePtr) -> Self {
+        StructView { ptr, _marker: PhantomData }
+    }
+
+    /// Read a plain-old-data field in place.
+    #[inline]
+    pub fn get<F: PodField>(&self, field: &FieldOffset<T, F>) -> F {
+        unsafe { ffi_dispatch::native_mem_read::<F>(self.ptr, field.offset) }
+    }
+
+    /// Read the field `name` through reflection (any [`FieldValue`] type).
+    pub fn field<F: FieldValue>(&self, name: &str) -> UikaResult<F> {
+        self.as_struct_ref().field(name)
+    }
+
+    /// A view of the nested struct field at `field`, in the same memory.
+    #[inline]
+    pub fn nested<U: UeStruct>(&self, field: &FieldOffset<T, OwnedStruct<U>>) -> StructView<'a, U> {
+        unsafe { StructView::from_raw(self.ptr.add(field.offset)) }
+    }
+
+    /// The viewed memory as a `UStructRef`, for the generated accessors.
+    #[inline]
+    pub fn as_struct_ref(&self) -> UStructRef<T> {
+        unsafe { UStructRef::from_raw(self.ptr) }
+    }
+
+    /// The viewed memory as a property API container handle.
+    #[inline]
+    pub fn as_ptr(&self) -> UObjectHandle {
+        UObjectHandle(self.ptr.cast())
+    }
+}
+
+impl<T: UeStruct> Clone for StructView<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: UeStruct> Copy for StructView<'_, T> {}
+
+impl<T: UeStruct> std::fmt::Debug for StructView<'_, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("StructView").field("ptr", &self.ptr).finish()
+    }
+}
+
+impl<T: UeStruct> OwnedStruct<T> {
+    /// A zero-copy view of this struct's bytes.
+    pub fn view(&self) -> StructView<'_, T> {
+        unsafe { StructView::from_raw(self.as_bytes().as_ptr() as NativePtr) }
+    }
+}
+
+impl<T: UeStruct> UStructRef<T> {
+    /// A zero-copy view of the referenced struct.
+    ///
+    /// # Safety
+    /// The memory must stay alive and unmodified for `'a`.
+    pub unsafe fn view<'a>(&self) -> StructView<'a, T> {
+        unsafe { StructView::from_raw(self.as_ptr().0.cast()) }
+    }
+}
@@ -0,0 +1,74 @@
+// Subsystems: typed access to world, game instance, local player and engine
+// subsystems, from the engine or plugins (or Blueprint subsystem classes).
+//
+// Lookups go through the engine every time; subsystems live as long as
+// their owner (world, game instance, ...), so don't keep a reference past
+// a level change.
+
+use uika_ffi::UObjectHandle;
+
+use crate::error::{UikaError, UikaResult};
+use crate::ffi_dispatch;
+use crate::object_ref::UObjectRef;
+use crate::traits::UeClass;
+
+/// The `UWorldSubsystem` `T` of `world_context`'s world (the world itself
+/// or any actor or component in it).
+///
+/// ```ignore
+/// let partition = subsystems::get_world_subsystem::<UWorldPartitionSubsystem, _>(actor)?;
+/// ```
+pub fn get_world_subsystem<T: UeClass, C: UeClass>(world_context: UObjectRef<C>) -> UikaResult<UObjectRef<T>> {
+    let ctx = world_context.checked()?.raw();
+    subsystem::<T>(unsafe { ffi_dispatch::subsystem_get_world_subsystem(ctx, T::static_class()) }, "world")
+}
+
+/// The `UGameInstanceSubsystem` `T` of the running game instance (in the
+/// editor, the first PIE instance).
+pub fn get_game_instance_subsystem<T: UeClass>() -> UikaResult<UObjectRef<T>> {
+    let handle =
+        unsafe { ffi_dispatch::subsystem_get_game_instance_subsystem(UObjectHandle::null(), T::static_class()) };
+    subsystem::<T>(handle, "game instance")
+}
+
+/// [`get_game_instance_subsystem`] for the game instance of
+/// `world_context`'s world, for when several are running (multi-client
+/// PIE).
+pub fn get_game_instance_subsystem_for<T: UeClass, C: UeClass>(
+    world_context: UObjectRef<C>,
+) -> UikaResult<UObjectRef<T>> {
+    let ctx = world_context.checked()?.raw();
+    subsystem::<T>(
+        unsafe { ffi_dispatch::subsystem_get_game_instance_subsystem(ctx, T::static_class()) },
+        "game instance",
+    )
+}
+
+/// The `ULocalPlayerSubsystem` `T` of `player`, a player controller or
+/// local player.
+///
+/// ```ignore
+/// let input = subsystems::get_local_player_subsystem::<UEnhancedInputLocalPlayerSubsystem, _>(controller)?;
+/// ```
+pub fn get_local_player_subsystem<T: UeClass, P: UeClass>(player: UObjectRef<P>) -> UikaResult<UObjectRef<T>> {
+    let player = player.checked()?.raw();
+    subsystem::<T>(
+        unsafe { ffi_dispatch::subsystem_get_local_player_subsystem(player, T::static_class()) },
+        "local player",
+    )
+}
+
+/// The `UEngineSubsystem` `T`.
+pub fn get_engine_subsystem<T: UeClass>() -> UikaResult<UObjectRef<T>> {
+    subsystem::<T>(unsafe { ffi_dispatch::subsystem_get_engine_subsystem(T::static_class()) }, "engine")
+}
+
+fn subsystem<T: UeClass>(handle: UObjectHandle, kind: &str) -> UikaResult<UObjectRef<T>> {
+    if handle.is_null() {
+        return Err(UikaError::InvalidOperation(format!(
+            "no {kind} subsystem {} (wrong subsystem kind, no such {kind}, or not created)",
+            std::any::type_name::<T>()
+        )));
+    }
+    Ok(unsafe { UObjectRef::from_raw(handle) })
+}
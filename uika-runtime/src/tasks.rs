@@ -0,0 +1,226 @@
+// Background tasks: run heavy work (pathfinding, procedural generation,
+// parsing) on worker threads and hand the results back to the game thread.
+//
+// UObjects, and every call through the API table, belong to the game
+// thread. Closures given to `spawn_background` run on a small worker pool
+// and must not touch UE; their result is delivered to a completion closure
+// that runs on the game thread, from the plugin's per-frame `tick`
+// callback, like anything queued with `run_on_game_thread`.
+//
+// On shutdown (and hot reload) queued game-thread closures and background
+// work that has not started are dropped without running, and the DLL waits
+// for running background work to finish: worker threads can't outlive the
+// code they are running.
+
+use std::panic::{catch_unwind, resume_unwind, AssertUnwindSafe};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::{Arc, Mutex, OnceLock};
+use std::thread::JoinHandle;
+
+use crate::ffi_guard::{restore_panic_report, take_panic_report};
+use crate::lock_or_recover;
+
+type Job = Box<dyn FnOnce() + Send>;
+
+/// Upper bound on worker threads; background work is meant to be coarse.
+const MAX_WORKERS: usize = 4;
+
+fn game_queue() -> &'static Mutex<Vec<Job>> {
+    static QUEUE: OnceLock<Mutex<Vec<Job>>> = OnceLock::new();
+    QUEUE.get_or_init(|| Mutex::new(Vec::new()))
+}
+
+/// Set while `shutdown` stops the pool: workers drop the jobs still queued
+/// instead of running them.
+static SHUTTING_DOWN: AtomicBool = AtomicBool::new(false);
+
+#[derive(Default)]
+struct Pool {
+    sender: Option<Sender<Job>>,
+    workers: Vec<JoinHandle<()>>,
+}
+
+fn pool() -> &'static Mutex<Pool> {
+    static POOL: OnceLock<Mutex<Pool>> = OnceLock::new();
+    POOL.get_or_init(|| Mutex::new(Pool::default()))
+}
+
+impl Pool {
+    fn sender(&mut self) -> &Sender<Job> {
+        self.sender.get_or_insert_with(|| {
+            let (sender, receiver) = mpsc::channel::<Job>();
+            let receiver = Arc::new(Mutex::new(receiver));
+            let count = std::thread::available_parallelism().map_or(2, |n| n.get()).min(MAX_WORKERS);
+            self.workers = (0..count)
+                .map(|i| {
+                    let receiver = Arc::clone(&receiver);
+                    std::thread::Builder::new()
+                        .name(format!("uika-background-{i}"))
+                        .spawn(move || worker_loop(&receiver, &SHUTTING_DOWN))
+                        .expect("failed to spawn background worker")
+                })
+                .collect();
+            sender
+        })
+    }
+}
+
+fn worker_loop(receiver: &Mutex<Receiver<Job>>, shutting_down: &AtomicBool) {
+    loop {
+        let job = lock_or_recover(receiver).recv();
+        match job {
+            Ok(job) if !shutting_down.load(Ordering::Acquire) => job(),
+            Ok(job) => drop(job),
+            // The sender is gone and the queue drained: shutdown.
+            Err(_) => return,
+        }
+    }
+}
+
+/// Run `f` on the game thread at the start of the next frame. Safe to call
+/// from any thread; closures run in the order they were queued.
+pub fn run_on_game_thread(f: impl FnOnce() + Send + 'static) {
+    lock_or_recover(game_queue()).push(Box::new(f));
+}
+
+/// Handle to work started with [`spawn_background`]. Dropping it does not
+/// cancel anything.
+#[derive(Clone, Debug)]
+pub struct BackgroundTask {
+    state: Arc<TaskState>,
+}
+
+#[derive(Debug, Default)]
+struct TaskState {
+    finished: AtomicBool,
+    cancelled: AtomicBool,
+}
+
+impl BackgroundTask {
+    /// Whether the completion closure has run (or the work panicked or was
+    /// cancelled).
+    pub fn is_finished(&self) -> bool {
+        self.state.finished.load(Ordering::Acquire)
+    }
+
+    /// Skip the completion closure. Work already running still runs to the
+    /// end; use this when whatever the result was for is gone.
+    pub fn cancel(&self) {
+        self.state.cancelled.store(true, Ordering::Release);
+    }
+}
+
+/// Run `work` on a worker thread, then `on_done` with its result on the game
+/// thread. `work` must not call into UE; `on_done` may.
+///
+/// A panic in `work` is re-raised on the game thread, where it is logged
+/// like any other callback panic; `on_done` is then not called.
+///
+/// ```ignore
+/// let target = self.as_ref();
+/// tasks::spawn_background(move || generate_chunk(seed), move |chunk| {
+///     if target.is_valid() { place_chunk(target, chunk); }
+/// });
+/// ```
+pub fn spawn_background<T, W, D>(work: W, on_done: D) -> BackgroundTask
+where
+    T: Send + 'static,
+    W: FnOnce() -> T + Send + 'static,
+    D: FnOnce(T) + Send + 'static,
+{
+    let state = Arc::new(TaskState::default());
+    let task_state = Arc::clone(&state);
+    let job: Job = Box::new(move || {
+        // The worker's panic report travels with the payload, so the game
+        // thread logs where the panic happened.
+        let result = catch_unwind(AssertUnwindSafe(work)).map_err(|payload| (payload, take_panic_report()));
+        run_on_game_thread(move || {
+            task_state.finished.store(true, Ordering::Release);
+            match result {
+                Ok(value) if !task_state.cancelled.load(Ordering::Acquire) => on_done(value),
+                Ok(_) => {}
+                Err((payload, report)) => {
+                    restore_panic_report(report);
+                    resume_unwind(payload)
+                }
+            }
+        });
+    });
+    // A send only fails if every worker died, which `worker_loop` rules out.
+    let _ = lock_or_recover(pool()).sender().send(job);
+    BackgroundTask { state }
+}
+
+/// Run the closures queued for the game thread. Called once per frame from
+/// the plugin's tick; closures queued while draining run next frame.
+pub fn tick(_delta_seconds: f32) {
+    let jobs = std::mem::take(&mut *lock_or_recover(game_queue()));
+    for job in jobs {
+        crate::ffi_boundary((), AssertUnwindSafe(job));
+    }
+}
+
+/// Stop the worker pool, waiting for running work, and drop queued
+/// background work and game-thread closures. Called during on_shutdown.
+pub fn shutdown() {
+    SHUTTING_DOWN.store(true, Ordering::Release);
+    let workers = {
+        let mut pool = lock_or_recover(pool());
+        pool.sender = None;
+        std::mem::take(&mut pool.workers)
+    };
+    for worker in workers {
+        let _ = worker.join();
+    }
+    lock_or_recover(game_queue()).clear();
+    // A later spawn_background starts a fresh pool.
+    SHUTTING_DOWN.store(false, Ordering::Release);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::{Duration, Instant};
+
+    /// Tick until `done` holds, as the plugin would each frame.
+    fn tick_until(done: impl Fn() -> bool) {
+        let deadline = Instant::now() + Duration::from_secs(5);
+        while !done() {
+            assert!(Instant::now() < deadline, "timed out waiting for the game-thread queue");
+            tick(0.016);
+            std::thread::sleep(Duration::from_millis(1));
+        }
+    }
+
+    #[test]
+    fn background_results_are_delivered_on_tick() {
+        let game_thread = std::thread::current().id();
+        let (tx, rx) = mpsc::channel();
+        let task = spawn_background(
+            || (1..=10u64).product::<u64>(),
+            move |value| tx.send((value, std::thread::current().id())).unwrap(),
+        );
+        tick_until(|| task.is_finished());
+        assert_eq!(rx.try_recv().unwrap(), (3_628_800, game_thread));
+
+        let (tx, rx) = mpsc::channel::<()>();
+        let cancelled = spawn_background(|| (), move |()| tx.send(()).unwrap());
+        cancelled.cancel();
+        tick_until(|| cancelled.is_finished());
+        assert!(rx.try_recv().is_err());
+    }
+
+    #[test]
+    fn queued_work_is_dropped_on_shutdown() {
+        let (sender, receiver) = mpsc::channel::<Job>();
+        let ran = Arc::new(AtomicBool::new(false));
+        for _ in 0..3 {
+            let ran = Arc::clone(&ran);
+            sender.send(Box::new(move || ran.store(true, Ordering::Release))).unwrap();
+        }
+        drop(sender);
+        worker_loop(&Mutex::new(receiver), &AtomicBool::new(true));
+        assert!(!ran.load(Ordering::Acquire));
+    }
+}
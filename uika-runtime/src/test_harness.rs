@@ -0,0 +1,2260 @@
+// In-memory fake engine for unit tests (feature = "test-harness").
+//
+// `install()` hands the runtime a `UikaApiTable` whose entries are served by
+// a process-global mock instead of UE, so code built on uika-runtime (and
+// the generated bindings) can run under plain `cargo test`.
+//
+// What is modelled:
+// - classes (by name, with single inheritance), objects (name, class,
+//   alive/destroyed), weak pointers and FNames
+// - scalar, string, FName, object, enum and struct-blob properties, keyed by
+//   property name (the mock has no per-class layout), with an optional
+//   declared type for property info lookups (`define_property`)
+// - TArray / TMap / TSet properties, storing each element as the bytes the
+//   runtime sent (so any `ContainerElement` type round-trips)
+// - deferred actor spawning (the actor is an object of the class, flagged
+//   as spawning until `finish_spawning`), and `K2_DestroyActor` as the one
+//   reflection call (it destroys the object)
+// - runtime components (owner and attach parent, no transforms)
+// - subsystems: one object per class and context object, created on first
+//   lookup
+// - log output, captured for assertions
+// - property change subscriptions, fired by `notify_changed` only
+//
+// Everything else (function calls through the func table, reflection calls,
+// reify, the rest of the world API, timers, ...) is stubbed: calls return null handles, zero or
+// `UikaErrorCode::InvalidOperation`.
+//
+// ```ignore
+// #[test]
+// fn damage_reduces_health() {
+//     uika_runtime::test_harness::install();
+//     let obj = test_harness::spawn(Character::static_class(), "Hero");
+//     let hero: UObjectRef<Character> = unsafe { UObjectRef::from_raw(obj) };
+//     hero.set_health(100.0).unwrap();
+//     apply_damage(&hero, 30.0).unwrap();
+//     assert_eq!(hero.get_health().unwrap(), 70.0);
+// }
+// ```
+//
+// The mock is global and the API table can only be installed once per
+// process, so tests share state: give each test its own objects rather than
+// relying on `reset()`, which races with tests running in parallel.
+
+use std::collections::HashMap;
+use std::sync::{Mutex, Once, OnceLock};
+
+use uika_ffi::*;
+
+use crate::lock_or_recover;
+
+#[allow(clippy::all, unused_unsafe)]
+mod mock_stubs {
+    include!(concat!(env!("OUT_DIR"), "/mock_stubs.rs"));
+}
+
+// ---------------------------------------------------------------------------
+// Public API
+// ---------------------------------------------------------------------------
+
+/// A property value stored on a mock object.
+#[derive(Clone, Debug, PartialEq)]
+pub enum MockValue {
+    Bool(bool),
+    /// Any integer property (i32, i64, u8, enum).
+    Int(i64),
+    /// f32 or f64 properties.
+    Float(f64),
+    /// FString / FText / soft object path.
+    Str(String),
+    Name(String),
+    Object(UObjectHandle),
+    /// A struct property, as the raw bytes last written.
+    Struct(Vec<u8>),
+    /// TArray elements, as the bytes the runtime wrote for each.
+    Array(Vec<Vec<u8>>),
+    /// TMap pairs (key bytes, value bytes), in insertion order.
+    Map(Vec<(Vec<u8>, Vec<u8>)>),
+    /// TSet elements, in insertion order.
+    Set(Vec<Vec<u8>>),
+}
+
+/// A captured log line.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LogLine {
+    /// `LogUika` for `ulog!` without a category.
+    pub category: String,
+    pub level: u8,
+    pub message: String,
+}
+
+/// Install the mock API table. Idempotent; call at the start of every test.
+///
+/// # Panics
+/// If a real API table was already installed in this process.
+pub fn install() {
+    static INSTALL: Once = Once::new();
+    INSTALL.call_once(|| {
+        let table = Box::leak(Box::new(build_table()));
+        // SAFETY: leaked, so it lives for the rest of the process.
+        unsafe { crate::api::init_api(table) };
+    });
+}
+
+/// Drop every object and captured log line. Classes, properties and FNames
+/// are kept: generated code caches their handles in statics.
+pub fn reset() {
+    let mut s = lock_or_recover(state());
+    // `object_order` keeps growing so stale weak pointers never resolve.
+    s.objects.clear();
+    s.logs.clear();
+}
+
+/// Define (or look up) a class. `parent` is only applied when the class is
+/// first defined. Classes are also created on demand by `StaticClass`
+/// lookups from generated code, without a parent.
+pub fn define_class(name: &str, parent: Option<UClassHandle>) -> UClassHandle {
+    let mut s = lock_or_recover(state());
+    let class = s.class_named(name);
+    if let Some(parent) = parent {
+        let entry = s.classes.get_mut(&class.to_addr()).expect("class just defined");
+        entry.parent.get_or_insert(parent.to_addr());
+    }
+    class
+}
+
+/// Create a live object of `class` named `name`.
+pub fn spawn(class: UClassHandle, name: &str) -> UObjectHandle {
+    let mut s = lock_or_recover(state());
+    let addr = s.next_handle();
+    s.object_order.push(addr);
+    let serial = s.object_order.len() as i32;
+    s.objects.insert(
+        addr,
+        MockObject {
+            name: name.to_string(),
+            class: class.to_addr(),
+            alive: true,
+            spawning: false,
+            owner: None,
+            attach_parent: None,
+            serial,
+            props: HashMap::new(),
+        },
+    );
+    UObjectHandle::from_addr(addr)
+}
+
+/// The actor component `obj` was added to.
+pub fn component_owner(obj: UObjectHandle) -> Option<UObjectHandle> {
+    let s = lock_or_recover(state());
+    s.objects.get(&obj.to_addr())?.owner.map(UObjectHandle::from_addr)
+}
+
+/// What `obj` is attached to, and at which socket (`"None"` for none).
+pub fn attach_parent(obj: UObjectHandle) -> Option<(UObjectHandle, String)> {
+    let s = lock_or_recover(state());
+    let (parent, socket) = s.objects.get(&obj.to_addr())?.attach_parent.clone()?;
+    Some((UObjectHandle::from_addr(parent), socket))
+}
+
+/// Whether `obj` was spawned deferred and has not finished spawning yet.
+pub fn is_spawning(obj: UObjectHandle) -> bool {
+    lock_or_recover(state()).objects.get(&obj.to_addr()).is_some_and(|o| o.spawning)
+}
+
+/// Mark `obj` destroyed: `is_valid` turns false and property access fails
+/// with `ObjectDestroyed`, as for a garbage-collected UObject.
+pub fn destroy(obj: UObjectHandle) {
+    if let Some(o) = lock_or_recover(state()).objects.get_mut(&obj.to_addr()) {
+        o.alive = false;
+    }
+}
+
+/// The property handle generated code will get for `name`.
+pub fn property(name: &str) -> FPropertyHandle {
+    lock_or_recover(state()).property_named(name)
+}
+
+/// Declare the reflected type of property `name`, as reported to the
+/// runtime's type checks. Undeclared properties describe as unsupported.
+pub fn define_property(name: &str, kind: UikaReifyPropType, extra: UikaReifyPropExtra) -> FPropertyHandle {
+    let mut s = lock_or_recover(state());
+    let prop = s.property_named(name);
+    s.prop_kinds.insert(prop.to_addr(), (kind, extra));
+    prop
+}
+
+/// Place property `name` at byte `offset` of its owner, as reported by
+/// `GetOffset_ForInternal`. Undeclared properties sit at offset 0.
+pub fn set_property_offset(name: &str, offset: u32) {
+    let mut s = lock_or_recover(state());
+    let prop = s.property_named(name);
+    s.prop_offsets.insert(prop.to_addr(), offset);
+}
+
+/// Read a property directly, bypassing the runtime.
+pub fn get_value(obj: UObjectHandle, prop: &str) -> Option<MockValue> {
+    let mut s = lock_or_recover(state());
+    let prop = s.property_named(prop).to_addr();
+    s.objects.get(&obj.to_addr())?.props.get(&prop).cloned()
+}
+
+/// Write a property directly, bypassing the runtime.
+pub fn set_value(obj: UObjectHandle, prop: &str, value: MockValue) {
+    let mut s = lock_or_recover(state());
+    let prop = s.property_named(prop).to_addr();
+    if let Some(o) = s.objects.get_mut(&obj.to_addr()) {
+        o.props.insert(prop, value);
+    }
+}
+
+/// Log lines captured so far.
+pub fn logs() -> Vec<LogLine> {
+    lock_or_recover(state()).logs.clone()
+}
+
+/// Log lines captured so far, clearing the capture buffer.
+pub fn take_logs() -> Vec<LogLine> {
+    std::mem::take(&mut lock_or_recover(state()).logs)
+}
+
+// ---------------------------------------------------------------------------
+// State
+// ---------------------------------------------------------------------------
+
+struct MockClass {
+    parent: Option<u64>,
+    /// Class default object, created on first request.
+    cdo: Option<u64>,
+}
+
+struct MockObject {
+    name: String,
+    class: u64,
+    alive: bool,
+    /// Spawned deferred, awaiting `finish_spawning`.
+    spawning: bool,
+    /// Actor a component was added to.
+    owner: Option<u64>,
+    /// Attach parent and socket name.
+    attach_parent: Option<(u64, String)>,
+    /// Weak pointer serial number (index in `object_order` + 1).
+    serial: i32,
+    props: HashMap<u64, MockValue>,
+}
+
+#[derive(Default)]
+struct MockState {
+    next_addr: u64,
+    classes: HashMap<u64, MockClass>,
+    class_by_name: HashMap<String, u64>,
+    objects: HashMap<u64, MockObject>,
+    /// Objects in creation order; the weak pointer object index.
+    object_order: Vec<u64>,
+    props_by_name: HashMap<String, u64>,
+    /// Subsystem objects by (context object, class); context 0 for game
+    /// instance and engine subsystems.
+    subsystems: HashMap<(u64, u64), u64>,
+    /// Types declared with `define_property`.
+    prop_kinds: HashMap<u64, (UikaReifyPropType, UikaReifyPropExtra)>,
+    /// Offsets set with `set_property_offset`.
+    prop_offsets: HashMap<u64, u32>,
+    /// FName entries; handle value is the index (0 is `None`).
+    names: Vec<String>,
+    name_ids: HashMap<String, u64>,
+    logs: Vec<LogLine>,
+    /// Log category verbosities (as `LOG_*` levels), set on first use.
+    log_levels: HashMap<String, u8>,
+    /// Property change subscriptions by binding id.
+    property_subscriptions: HashMap<u32, MockPropertySubscription>,
+    next_property_binding: u32,
+}
+
+struct MockPropertySubscription {
+    /// Subscribed object; 0 for any object.
+    object: u64,
+    /// Subscribed property name; empty for any property.
+    property: String,
+    callback_id: u64,
+}
+
+fn state() -> &'static Mutex<MockState> {
+    static STATE: OnceLock<Mutex<MockState>> = OnceLock::new();
+    STATE.get_or_init(|| Mutex::new(MockState::default()))
+}
+
+impl MockState {
+    /// Distinct, non-null, aligned fake addresses for every handle kind.
+    fn next_handle(&mut self) -> u64 {
+        self.next_addr += 0x10;
+        0x1000 + self.next_addr
+    }
+
+    fn class_named(&mut self, name: &str) -> UClassHandle {
+        if let Some(&addr) = self.class_by_name.get(name) {
+            return UClassHandle::from_addr(addr);
+        }
+        let addr = self.next_handle();
+        self.classes.insert(addr, MockClass { parent: None, cdo: None });
+        self.class_by_name.insert(name.to_string(), addr);
+        UClassHandle::from_addr(addr)
+    }
+
+    fn property_named(&mut self, name: &str) -> FPropertyHandle {
+        if let Some(&addr) = self.props_by_name.get(name) {
+            return FPropertyHandle::from_addr(addr);
+        }
+        let addr = self.next_handle();
+        self.props_by_name.insert(name.to_string(), addr);
+        FPropertyHandle::from_addr(addr)
+    }
+
+    fn fname(&mut self, s: &str) -> FNameHandle {
+        if s.is_empty() || s == "None" {
+            return FNameHandle(0);
+        }
+        if let Some(&id) = self.name_ids.get(s) {
+            return FNameHandle(id);
+        }
+        if self.names.is_empty() {
+            self.names.push("None".to_string());
+        }
+        let id = self.names.len() as u64;
+        self.names.push(s.to_string());
+        self.name_ids.insert(s.to_string(), id);
+        FNameHandle(id)
+    }
+
+    fn fname_str(&self, h: FNameHandle) -> &str {
+        self.names.get(h.0 as usize).map_or("None", |s| s.as_str())
+    }
+
+    fn is_child_of(&self, class: u64, parent: u64) -> bool {
+        let mut cur = Some(class);
+        while let Some(c) = cur {
+            if c == parent {
+                return true;
+            }
+            cur = self.classes.get(&c).and_then(|c| c.parent);
+        }
+        false
+    }
+
+    fn live_object(&mut self, obj: UObjectHandle) -> Result<&mut MockObject, UikaErrorCode> {
+        match self.objects.get_mut(&obj.to_addr()) {
+            Some(o) if o.alive => Ok(o),
+            _ => Err(UikaErrorCode::ObjectDestroyed),
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Helpers for the FFI entry points
+// ---------------------------------------------------------------------------
+
+/// Run `f` on a live object's property slot.
+fn with_prop<R>(
+    obj: UObjectHandle,
+    prop: FPropertyHandle,
+    f: impl FnOnce(&mut Option<MockValue>) -> Result<R, UikaErrorCode>,
+) -> Result<R, UikaErrorCode> {
+    let mut s = lock_or_recover(state());
+    let o = s.live_object(obj)?;
+    let mut slot = o.props.remove(&prop.to_addr());
+    let result = f(&mut slot);
+    if let Some(v) = slot {
+        o.props.insert(prop.to_addr(), v);
+    }
+    result
+}
+
+fn code(result: Result<(), UikaErrorCode>) -> UikaErrorCode {
+    result.err().unwrap_or(UikaErrorCode::Ok)
+}
+
+unsafe fn utf8<'a>(ptr: *const u8, len: u32) -> &'a str {
+    if ptr.is_null() || len == 0 {
+        return "";
+    }
+    std::str::from_utf8(unsafe { std::slice::from_raw_parts(ptr, len as usize) }).unwrap_or("")
+}
+
+unsafe fn bytes<'a>(ptr: *const u8, len: u32) -> &'a [u8] {
+    if ptr.is_null() || len == 0 {
+        return &[];
+    }
+    unsafe { std::slice::from_raw_parts(ptr, len as usize) }
+}
+
+/// Copy `data` into a caller buffer, setting `out_len` to the full length.
+/// With `strict`, a short buffer is `BufferTooSmall` (and nothing is
+/// copied); otherwise the data is truncated, like UE's name getters.
+unsafe fn write_out(data: &[u8], buf: *mut u8, buf_len: u32, out_len: *mut u32, strict: bool) -> UikaErrorCode {
+    if !out_len.is_null() {
+        unsafe { *out_len = data.len() as u32 };
+    }
+    if strict && data.len() > buf_len as usize {
+        return UikaErrorCode::BufferTooSmall;
+    }
+    if !buf.is_null() {
+        let n = data.len().min(buf_len as usize);
+        unsafe { std::ptr::copy_nonoverlapping(data.as_ptr(), buf, n) };
+    }
+    UikaErrorCode::Ok
+}
+
+/// Append `[u32 len][bytes]` framing, the bulk-copy wire format.
+fn push_framed(out: &mut Vec<u8>, data: &[u8]) {
+    out.extend_from_slice(&(data.len() as u32).to_ne_bytes());
+    out.extend_from_slice(data);
+}
+
+// ---------------------------------------------------------------------------
+// Table assembly
+// ---------------------------------------------------------------------------
+
+fn build_table() -> UikaApiTable {
+    let mut core = mock_stubs::core_api();
+    core.is_valid = core_is_valid;
+    core.get_name = core_get_name;
+    core.get_class = core_get_class;
+    core.is_a = core_is_a;
+    core.make_fname = core_make_fname;
+    core.fname_to_string = core_fname_to_string;
+    core.make_weak = core_make_weak;
+    core.resolve_weak = core_resolve_weak;
+    core.is_weak_valid = core_is_weak_valid;
+    core.is_child_of = core_is_child_of;
+    core.get_super_class = core_get_super_class;
+    core.get_path_name = core_get_path_name;
+
+    let mut reflection = mock_stubs::reflection_api();
+    reflection.find_class = reflection_find_class;
+    reflection.get_static_class = reflection_find_class;
+    reflection.find_property = reflection_find_property;
+    reflection.find_struct_property = reflection_find_struct_property;
+    reflection.get_property_offset = reflection_get_property_offset;
+    reflection.find_function = reflection_find_function;
+    reflection.alloc_params = reflection_alloc_params;
+    reflection.free_params = reflection_free_params;
+    reflection.call_function = reflection_call_function;
+
+    let mut property = mock_stubs::property_api();
+    property.get_bool = prop_get_bool;
+    property.set_bool = prop_set_bool;
+    property.get_i32 = prop_get_i32;
+    property.set_i32 = prop_set_i32;
+    property.get_i64 = prop_get_i64;
+    property.set_i64 = prop_set_i64;
+    property.get_u8 = prop_get_u8;
+    property.set_u8 = prop_set_u8;
+    property.get_f32 = prop_get_f32;
+    property.set_f32 = prop_set_f32;
+    property.get_f64 = prop_get_f64;
+    property.set_f64 = prop_set_f64;
+    property.get_enum = prop_get_i64;
+    property.set_enum = prop_set_i64;
+    property.get_string = prop_get_string;
+    property.set_string = prop_set_string;
+    property.get_fname = prop_get_fname;
+    property.set_fname = prop_set_fname;
+    property.get_object = prop_get_object;
+    property.set_object = prop_set_object;
+    property.get_struct = prop_get_struct;
+    property.set_struct = prop_set_struct;
+
+    let mut container = mock_stubs::container_api();
+    container.array_len = array_len;
+    container.array_get = array_get;
+    container.array_set = array_set;
+    container.array_add = array_add;
+    container.array_remove = array_remove;
+    container.array_clear = array_clear;
+    container.array_copy_all = array_copy_all;
+    container.array_set_all = array_set_all;
+    container.map_len = map_len;
+    container.map_find = map_find;
+    container.map_add = map_add;
+    container.map_remove = map_remove;
+    container.map_clear = map_clear;
+    container.map_get_pair = map_get_pair;
+    container.map_copy_all = map_copy_all;
+    container.set_len = set_len;
+    container.set_contains = set_contains;
+    container.set_add = set_add;
+    container.set_remove = set_remove;
+    container.set_clear = set_clear;
+    container.set_get_element = set_get_element;
+    container.set_copy_all = set_copy_all;
+
+    let mut array_ext = mock_stubs::array_ext_api();
+    array_ext.copy_range = array_copy_range;
+    array_ext.append_all = array_append_all;
+    array_ext.remove_range = array_remove_range;
+
+    let mut map_ext = mock_stubs::map_ext_api();
+    map_ext.contains_key = map_contains_key;
+    map_ext.find_or_add = map_find_or_add;
+    map_ext.remove_and_copy = map_remove_and_copy;
+    map_ext.copy_range = map_copy_range;
+
+    let mut set_ext = mock_stubs::set_ext_api();
+    set_ext.union_into = set_union_into;
+    set_ext.intersection = set_intersection;
+    set_ext.difference = set_difference;
+    set_ext.contains_each = set_contains_each;
+
+    let mut property_info = mock_stubs::property_info_api();
+    property_info.describe = property_info_describe;
+
+    let mut property_bulk = mock_stubs::property_bulk_api();
+    property_bulk.get_many = property_bulk_get_many;
+    property_bulk.set_many = property_bulk_set_many;
+
+    let mut property_notify = mock_stubs::property_notify_api();
+    property_notify.subscribe = property_notify_subscribe;
+    property_notify.unsubscribe = property_notify_unsubscribe;
+    property_notify.notify_changed = property_notify_notify_changed;
+
+    let mut object_iter = mock_stubs::object_iter_api();
+    object_iter.next_page = object_iter_next_page;
+
+    let mut core_ext = mock_stubs::core_ext_api();
+    core_ext.get_default_object = core_get_default_object;
+
+    let mut world = mock_stubs::world_api();
+    world.spawn_actor_deferred = world_spawn_actor_deferred;
+    world.finish_spawning = world_finish_spawning;
+
+    let mut component = mock_stubs::component_api();
+    component.add_component = component_add_component;
+    component.attach_component = component_attach;
+    component.attach_actor = component_attach;
+    component.detach = component_detach;
+
+    let mut subsystem = mock_stubs::subsystem_api();
+    subsystem.get_world_subsystem = subsystem_get_world;
+    subsystem.get_game_instance_subsystem = subsystem_get_game_instance;
+    subsystem.get_local_player_subsystem = subsystem_get_world;
+    subsystem.get_engine_subsystem = subsystem_get_engine;
+
+    let mut logging = mock_stubs::logging_api();
+    logging.log = log_log;
+    logging.log_category = log_log_category;
+
+    let mut logging_ext = mock_stubs::logging_ext_api();
+    logging_ext.register_category = log_register_category;
+    logging_ext.is_enabled = log_is_enabled;
+
+    fn leak<T>(t: T) -> *const T {
+        Box::leak(Box::new(t))
+    }
+
+    UikaApiTable {
+        version: UIKA_API_VERSION,
+        size: std::mem::size_of::<UikaApiTable>() as u32,
+        core: leak(core),
+        property: leak(property),
+        reflection: leak(reflection),
+        container: leak(container),
+        delegate: leak(mock_stubs::delegate_api()),
+        lifecycle: leak(mock_stubs::lifecycle_api()),
+        reify: leak(mock_stubs::reify_api()),
+        world: leak(world),
+        logging: leak(logging),
+        widget: leak(mock_stubs::widget_api()),
+        timer: leak(mock_stubs::timer_api()),
+        asset: leak(mock_stubs::asset_api()),
+        text: leak(mock_stubs::text_api()),
+        gameplay_tag: leak(mock_stubs::gameplay_tag_api()),
+        physics: leak(mock_stubs::physics_api()),
+        func_table: std::ptr::null(),
+        func_count: 0,
+        binding_stamp: UikaBindingStamp {
+            engine_version: std::ptr::null(),
+            engine_version_len: 0,
+            config_hash: 0,
+            func_sigs: std::ptr::null(),
+        },
+        input: leak(mock_stubs::input_api()),
+        audio: leak(mock_stubs::audio_api()),
+        navigation: leak(mock_stubs::navigation_api()),
+        ui: leak(mock_stubs::ui_api()),
+        asset_registry: leak(mock_stubs::asset_registry_api()),
+        save_game: leak(mock_stubs::save_game_api()),
+        config: leak(mock_stubs::config_api()),
+        gas: leak(mock_stubs::gas_api()),
+        array_ext: leak(array_ext),
+        map_ext: leak(map_ext),
+        set_ext: leak(set_ext),
+        property_info: leak(property_info),
+        object_iter: leak(object_iter),
+        core_ext: leak(core_ext),
+        component: leak(component),
+        subsystem: leak(subsystem),
+        latent: leak(mock_stubs::latent_api()),
+        logging_ext: leak(logging_ext),
+        panic: leak(mock_stubs::panic_api()),
+        guid: leak(mock_stubs::guid_api()),
+        hit_result: leak(mock_stubs::hit_result_api()),
+        property_bulk: leak(property_bulk),
+        property_notify: leak(property_notify),
+        output_stash: leak(mock_stubs::output_stash_api()),
+        gameplay_tag_ext: leak(mock_stubs::gameplay_tag_ext_api()),
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Core
+// ---------------------------------------------------------------------------
+
+unsafe extern "C" fn core_is_valid(obj: UObjectHandle) -> bool {
+    lock_or_recover(state()).objects.get(&obj.to_addr()).is_some_and(|o| o.alive)
+}
+
+unsafe extern "C" fn core_get_name(obj: UObjectHandle, buf: *mut u8, buf_len: u32, out_len: *mut u32) -> UikaErrorCode {
+    let mut s = lock_or_recover(state());
+    match s.live_object(obj) {
+        Ok(o) => unsafe { write_out(o.name.as_bytes(), buf, buf_len, out_len, false) },
+        Err(e) => e,
+    }
+}
+
+unsafe extern "C" fn core_get_path_name(obj: UObjectHandle, buf: *mut u8, buf_len: u32, out_len: *mut u32) -> UikaErrorCode {
+    let mut s = lock_or_recover(state());
+    match s.live_object(obj) {
+        Ok(o) => {
+            let path = format!("/Mock/{}", o.name);
+            unsafe { write_out(path.as_bytes(), buf, buf_len, out_len, true) }
+        }
+        Err(e) => e,
+    }
+}
+
+unsafe extern "C" fn core_get_class(obj: UObjectHandle) -> UClassHandle {
+    lock_or_recover(state())
+        .objects
+        .get(&obj.to_addr())
+        .map_or(UClassHandle::null(), |o| UClassHandle::from_addr(o.class))
+}
+
+unsafe extern "C" fn core_is_a(obj: UObjectHandle, target: UClassHandle) -> bool {
+    let s = lock_or_recover(state());
+    s.objects
+        .get(&obj.to_addr())
+        .is_some_and(|o| o.alive && s.is_child_of(o.class, target.to_addr()))
+}
+
+unsafe extern "C" fn core_make_fname(name: *const u8, len: u32) -> FNameHandle {
+    let name = unsafe { utf8(name, len) };
+    lock_or_recover(state()).fname(name)
+}
+
+unsafe extern "C" fn core_fname_to_string(h: FNameHandle, buf: *mut u8, buf_len: u32, out_len: *mut u32) -> UikaErrorCode {
+    let s = lock_or_recover(state());
+    unsafe { write_out(s.fname_str(h).as_bytes(), buf, buf_len, out_len, false) }
+}
+
+unsafe extern "C" fn core_make_weak(obj: UObjectHandle) -> FWeakObjectHandle {
+    let s = lock_or_recover(state());
+    match s.objects.get(&obj.to_addr()) {
+        Some(o) => FWeakObjectHandle { object_index: o.serial - 1, object_serial_number: o.serial },
+        None => FWeakObjectHandle::default(),
+    }
+}
+
+unsafe extern "C" fn core_resolve_weak(weak: FWeakObjectHandle) -> UObjectHandle {
+    let s = lock_or_recover(state());
+    let Some(&addr) = usize::try_from(weak.object_index).ok().and_then(|i| s.object_order.get(i)) else {
+        return UObjectHandle::null();
+    };
+    match s.objects.get(&addr) {
+        Some(o) if o.alive && o.serial == weak.object_serial_number => UObjectHandle::from_addr(addr),
+        _ => UObjectHandle::null(),
+    }
+}
+
+unsafe extern "C" fn core_is_weak_valid(weak: FWeakObjectHandle) -> bool {
+    !unsafe { core_resolve_weak(weak) }.is_null()
+}
+
+unsafe extern "C" fn core_is_child_of(class: UClassHandle, parent: UClassHandle) -> bool {
+    !class.is_null() && !parent.is_null() && lock_or_recover(state()).is_child_of(class.to_addr(), parent.to_addr())
+}
+
+unsafe extern "C" fn core_get_super_class(class: UClassHandle) -> UClassHandle {
+    lock_or_recover(state())
+        .classes
+        .get(&class.to_addr())
+        .and_then(|c| c.parent)
+        .map_or(UClassHandle::null(), UClassHandle::from_addr)
+}
+
+/// The CDO is a regular mock object named `Default__<class>`, kept out of
+/// `object_order` so iteration skips it (weak pointers to it never resolve).
+unsafe extern "C" fn core_get_default_object(class: UClassHandle) -> UObjectHandle {
+    let mut s = lock_or_recover(state());
+    let Some(cdo) = s.classes.get(&class.to_addr()).map(|c| c.cdo) else {
+        return UObjectHandle::null();
+    };
+    if let Some(addr) = cdo {
+        return UObjectHandle::from_addr(addr);
+    }
+    let addr = s.next_handle();
+    let name = s.class_by_name.iter().find(|(_, a)| **a == class.to_addr()).map(|(n, _)| n.clone());
+    s.objects.insert(
+        addr,
+        MockObject {
+            name: format!("Default__{}", name.unwrap_or_default()),
+            class: class.to_addr(),
+            alive: true,
+            spawning: false,
+            owner: None,
+            attach_parent: None,
+            serial: 0,
+            props: HashMap::new(),
+        },
+    );
+    s.classes.get_mut(&class.to_addr()).expect("class checked above").cdo = Some(addr);
+    UObjectHandle::from_addr(addr)
+}
+
+/// Scans `object_order` as the object array.
+unsafe extern "C" fn object_iter_next_page(
+    class: UClassHandle, cursor: *mut i32, out: *mut UObjectHandle, capacity: u32, out_count: *mut u32,
+) -> UikaErrorCode {
+    let s = lock_or_recover(state());
+    let (mut index, mut count) = (unsafe { *cursor }.max(0) as usize, 0u32);
+    while index < s.object_order.len() && count < capacity {
+        let addr = s.object_order[index];
+        index += 1;
+        if let Some(o) = s.objects.get(&addr)
+            && o.alive
+            && s.is_child_of(o.class, class.to_addr())
+        {
+            unsafe { *out.add(count as usize) = UObjectHandle::from_addr(addr) };
+            count += 1;
+        }
+    }
+    unsafe {
+        *out_count = count;
+        *cursor = if index < s.object_order.len() { index as i32 } else { -1 };
+    }
+    UikaErrorCode::Ok
+}
+
+// ---------------------------------------------------------------------------
+// World
+// ---------------------------------------------------------------------------
+
+/// The world and transform are ignored; the actor is a plain object.
+unsafe extern "C" fn world_spawn_actor_deferred(
+    _world: UObjectHandle, class: UClassHandle, _transform: *const u8, _transform_size: u32,
+    _owner: UObjectHandle, _instigator: UObjectHandle, _collision_method: u8,
+) -> UObjectHandle {
+    if class.is_null() {
+        return UObjectHandle::null();
+    }
+    let actor = spawn(class, "SpawnedActor");
+    if let Some(o) = lock_or_recover(state()).objects.get_mut(&actor.to_addr()) {
+        o.spawning = true;
+    }
+    actor
+}
+
+unsafe extern "C" fn world_finish_spawning(actor: UObjectHandle, _transform: *const u8, _transform_size: u32) -> UikaErrorCode {
+    let mut s = lock_or_recover(state());
+    match s.live_object(actor) {
+        Ok(o) if o.spawning => {
+            o.spawning = false;
+            UikaErrorCode::Ok
+        }
+        Ok(_) => UikaErrorCode::InvalidOperation,
+        Err(e) => e,
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Components
+// ---------------------------------------------------------------------------
+
+/// Any live object can own components; names must be unique per owner.
+/// Components start out attached to their owner.
+unsafe extern "C" fn component_add_component(
+    actor: UObjectHandle, class: UClassHandle, name: *const u8, len: u32,
+) -> UObjectHandle {
+    let name = unsafe { utf8(name, len) };
+    {
+        let mut s = lock_or_recover(state());
+        if class.is_null() || s.live_object(actor).is_err() {
+            return UObjectHandle::null();
+        }
+        let owner = Some(actor.to_addr());
+        if s.objects.values().any(|o| o.owner == owner && o.name == name) {
+            return UObjectHandle::null();
+        }
+    }
+    let component = spawn(class, name);
+    if let Some(o) = lock_or_recover(state()).objects.get_mut(&component.to_addr()) {
+        o.owner = Some(actor.to_addr());
+        o.attach_parent = Some((actor.to_addr(), "None".to_string()));
+    }
+    component
+}
+
+unsafe extern "C" fn component_attach(
+    child: UObjectHandle, parent: UObjectHandle, socket: FNameHandle,
+    location_rule: u8, rotation_rule: u8, scale_rule: u8, _weld: bool,
+) -> UikaErrorCode {
+    if location_rule > 2 || rotation_rule > 2 || scale_rule > 2 {
+        return UikaErrorCode::InvalidOperation;
+    }
+    let mut s = lock_or_recover(state());
+    if let Err(e) = s.live_object(parent) {
+        return e;
+    }
+    let socket = s.fname_str(socket).to_string();
+    match s.live_object(child) {
+        Ok(o) => {
+            o.attach_parent = Some((parent.to_addr(), socket));
+            UikaErrorCode::Ok
+        }
+        Err(e) => e,
+    }
+}
+
+unsafe extern "C" fn component_detach(target: UObjectHandle, rule: u8) -> UikaErrorCode {
+    if rule > 2 {
+        return UikaErrorCode::InvalidOperation;
+    }
+    let mut s = lock_or_recover(state());
+    match s.live_object(target) {
+        Ok(o) => {
+            o.attach_parent = None;
+            UikaErrorCode::Ok
+        }
+        Err(e) => e,
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Subsystems
+// ---------------------------------------------------------------------------
+
+fn subsystem_for(context: u64, class: UClassHandle) -> UObjectHandle {
+    if class.is_null() {
+        return UObjectHandle::null();
+    }
+    {
+        let mut s = lock_or_recover(state());
+        if context != 0 && s.live_object(UObjectHandle::from_addr(context)).is_err() {
+            return UObjectHandle::null();
+        }
+        if let Some(&addr) = s.subsystems.get(&(context, class.to_addr())) {
+            return UObjectHandle::from_addr(addr);
+        }
+    }
+    let obj = spawn(class, "Subsystem");
+    lock_or_recover(state()).subsystems.insert((context, class.to_addr()), obj.to_addr());
+    obj
+}
+
+unsafe extern "C" fn subsystem_get_world(context: UObjectHandle, class: UClassHandle) -> UObjectHandle {
+    if context.is_null() {
+        return UObjectHandle::null();
+    }
+    subsystem_for(context.to_addr(), class)
+}
+
+/// There is a single game instance, whatever the context.
+unsafe extern "C" fn subsystem_get_game_instance(_context: UObjectHandle, class: UClassHandle) -> UObjectHandle {
+    subsystem_for(0, class)
+}
+
+unsafe extern "C" fn subsystem_get_engine(class: UClassHandle) -> UObjectHandle {
+    subsystem_for(0, class)
+}
+
+// ---------------------------------------------------------------------------
+// Reflection
+// ---------------------------------------------------------------------------
+
+unsafe extern "C" fn reflection_find_class(name: *const u8, len: u32) -> UClassHandle {
+    let name = unsafe { utf8(name, len) };
+    lock_or_recover(state()).class_named(name)
+}
+
+unsafe extern "C" fn reflection_find_property(_class: UClassHandle, name: *const u8, len: u32) -> FPropertyHandle {
+    let name = unsafe { utf8(name, len) };
+    lock_or_recover(state()).property_named(name)
+}
+
+unsafe extern "C" fn reflection_find_struct_property(_s: UStructHandle, name: *const u8, len: u32) -> FPropertyHandle {
+    let name = unsafe { utf8(name, len) };
+    lock_or_recover(state()).property_named(name)
+}
+
+unsafe extern "C" fn reflection_get_property_offset(prop: FPropertyHandle) -> u32 {
+    lock_or_recover(state()).prop_offsets.get(&prop.to_addr()).copied().unwrap_or(0)
+}
+
+/// Handle of the mock `K2_DestroyActor`, below every allocated address.
+const DESTROY_ACTOR_FUNC: u64 = 0x10;
+
+unsafe extern "C" fn reflection_find_function(_obj: UObjectHandle, name: *const u8, len: u32) -> UFunctionHandle {
+    match unsafe { utf8(name, len) } {
+        "K2_DestroyActor" => UFunctionHandle::from_addr(DESTROY_ACTOR_FUNC),
+        _ => UFunctionHandle::null(),
+    }
+}
+
+/// `K2_DestroyActor` has no parameters; any non-null buffer will do.
+unsafe extern "C" fn reflection_alloc_params(_func: UFunctionHandle) -> *mut u8 {
+    Box::into_raw(Box::new(0u64)).cast()
+}
+
+unsafe extern "C" fn reflection_free_params(_func: UFunctionHandle, params: *mut u8) {
+    if !params.is_null() {
+        drop(unsafe { Box::from_raw(params.cast::<u64>()) });
+    }
+}
+
+unsafe extern "C" fn reflection_call_function(obj: UObjectHandle, func: UFunctionHandle, _params: *mut u8) -> UikaErrorCode {
+    if func.to_addr() != DESTROY_ACTOR_FUNC {
+        return UikaErrorCode::InvalidOperation;
+    }
+    if let Err(e) = lock_or_recover(state()).live_object(obj) {
+        return e;
+    }
+    destroy(obj);
+    UikaErrorCode::Ok
+}
+unsafe extern "C" fn property_info_describe(
+    prop: FPropertyHandle, out_type: *mut u32, out_extra: *mut UikaReifyPropExtra,
+) -> UikaErrorCode {
+    let Some(&(kind, extra)) = lock_or_recover(state()).prop_kinds.get(&prop.to_addr()) else {
+        return UikaErrorCode::TypeMismatch;
+    };
+    unsafe {
+        *out_type = kind as u32;
+        *out_extra = extra;
+    }
+    UikaErrorCode::Ok
+}
+
+/// The slot type of a declared scalar property; strings, text and structs
+/// are not scalar.
+fn scalar_kind(prop: FPropertyHandle) -> Result<UikaReifyPropType, UikaErrorCode> {
+    use UikaReifyPropType::*;
+    match lock_or_recover(state()).prop_kinds.get(&prop.to_addr()) {
+        Some(&(kind, _)) if !matches!(kind, String | Text | Struct) => Ok(kind),
+        _ => Err(UikaErrorCode::TypeMismatch),
+    }
+}
+
+unsafe extern "C" fn property_bulk_get_many(
+    obj: UObjectHandle, props: *const FPropertyHandle, count: u32, out_values: *mut u64, out_kinds: *mut u32,
+) -> UikaErrorCode {
+    use UikaReifyPropType::*;
+    let props = unsafe { std::slice::from_raw_parts(props, count as usize) };
+    for (i, &prop) in props.iter().enumerate() {
+        let read = scalar_kind(prop).and_then(|kind| {
+            let slot = match (kind, with_prop(obj, prop, |slot| Ok(slot.clone()))?) {
+                (_, None) => 0,
+                (Bool, Some(MockValue::Bool(b))) => u64::from(b),
+                (Float, Some(MockValue::Float(f))) => u64::from((f as f32).to_bits()),
+                (Double, Some(MockValue::Float(f))) => f.to_bits(),
+                (Name, Some(MockValue::Name(n))) => lock_or_recover(state()).fname(&n).0,
+                (Object | Class, Some(MockValue::Object(h))) => h.to_addr(),
+                (kind, Some(MockValue::Int(v))) if !matches!(kind, Bool | Float | Double | Name | Object | Class) => {
+                    v as u64
+                }
+                _ => return Err(UikaErrorCode::TypeMismatch),
+            };
+            Ok((kind, slot))
+        });
+        match read {
+            Ok((kind, slot)) => unsafe {
+                *out_values.add(i) = slot;
+                *out_kinds.add(i) = kind as u32;
+            },
+            Err(e) => return e,
+        }
+    }
+    UikaErrorCode::Ok
+}
+
+unsafe extern "C" fn property_bulk_set_many(
+    obj: UObjectHandle, props: *const FPropertyHandle, count: u32, values: *const u64, kinds: *const u32,
+) -> UikaErrorCode {
+    use UikaReifyPropType::*;
+    let props = unsafe { std::slice::from_raw_parts(props, count as usize) };
+    let values = unsafe { std::slice::from_raw_parts(values, count as usize) };
+    let kinds = unsafe { std::slice::from_raw_parts(kinds, count as usize) };
+    let mut checked = Vec::with_capacity(props.len());
+    for (&prop, &given) in props.iter().zip(kinds) {
+        let kind = match scalar_kind(prop) {
+            Ok(kind) => kind,
+            Err(e) => return e,
+        };
+        let object_kinds = [Object as u32, Class as u32];
+        if given != kind as u32 && !(object_kinds.contains(&given) && object_kinds.contains(&(kind as u32))) {
+            return UikaErrorCode::TypeMismatch;
+        }
+        checked.push(kind);
+    }
+    for ((&prop, &slot), kind) in props.iter().zip(values).zip(checked) {
+        let value = match kind {
+            Bool => MockValue::Bool(slot != 0),
+            Float => MockValue::Float(f32::from_bits(slot as u32) as f64),
+            Double => MockValue::Float(f64::from_bits(slot)),
+            Name => MockValue::Name(lock_or_recover(state()).fname_str(FNameHandle(slot)).to_string()),
+            Object | Class => MockValue::Object(UObjectHandle::from_addr(slot)),
+            _ => MockValue::Int(slot as i64),
+        };
+        if let Err(e) = with_prop(obj, prop, |s| {
+            *s = Some(value);
+            Ok(())
+        }) {
+            return e;
+        }
+    }
+    UikaErrorCode::Ok
+}
+
+unsafe extern "C" fn property_notify_subscribe(
+    obj: UObjectHandle, name: *const u8, name_len: u32, callback_id: u64,
+) -> u32 {
+    let mut s = lock_or_recover(state());
+    if !obj.is_null() && s.live_object(obj).is_err() {
+        return 0;
+    }
+    let property = unsafe { utf8(name, name_len) }.to_string();
+    s.next_property_binding += 1;
+    let binding = s.next_property_binding;
+    s.property_subscriptions
+        .insert(binding, MockPropertySubscription { object: obj.to_addr(), property, callback_id });
+    binding
+}
+
+unsafe extern "C" fn property_notify_unsubscribe(binding: u32) -> UikaErrorCode {
+    lock_or_recover(state()).property_subscriptions.remove(&binding);
+    UikaErrorCode::Ok
+}
+
+unsafe extern "C" fn property_notify_notify_changed(obj: UObjectHandle, prop: FPropertyHandle) -> UikaErrorCode {
+    let (mut change, callback_ids) = {
+        let mut s = lock_or_recover(state());
+        if let Err(e) = s.live_object(obj) {
+            return e;
+        }
+        let Some(name) = s.props_by_name.iter().find(|(_, a)| **a == prop.to_addr()).map(|(n, _)| n.clone()) else {
+            return UikaErrorCode::NullArgument;
+        };
+        let callback_ids: Vec<u64> = s
+            .property_subscriptions
+            .values()
+            .filter(|sub| sub.object == 0 || sub.object == obj.to_addr())
+            .filter(|sub| sub.property.is_empty() || sub.property == name)
+            .map(|sub| sub.callback_id)
+            .collect();
+        let name = s.fname(&name);
+        let change = UikaPropertyChange {
+            object: obj,
+            property: name,
+            member_property: name,
+            change_type: UIKA_PROPERTY_CHANGE_VALUE_SET,
+        };
+        (change, callback_ids)
+    };
+    // Outside the lock: callbacks may call back into the mock.
+    for id in callback_ids {
+        crate::delegate_registry::invoke(id, &mut change as *mut UikaPropertyChange as crate::ffi_dispatch::NativePtr);
+    }
+    UikaErrorCode::Ok
+}
+
+// ---------------------------------------------------------------------------
+// Properties
+// ---------------------------------------------------------------------------
+
+/// Scalar getter: unset properties read as the type's default, like a
+/// freshly constructed UObject.
+macro_rules! scalar_accessors {
+    ($get:ident, $set:ident, $ty:ty, $variant:ident, $default:expr, $to:expr, $from:expr) => {
+        unsafe extern "C" fn $get(obj: UObjectHandle, prop: FPropertyHandle, out: *mut $ty) -> UikaErrorCode {
+            let value = with_prop(obj, prop, |slot| match slot {
+                None => Ok($default),
+                Some(MockValue::$variant(v)) => Ok($to(v.clone())),
+                Some(_) => Err(UikaErrorCode::TypeMismatch),
+            });
+            match value {
+                Ok(v) => {
+                    unsafe { *out = v };
+                    UikaErrorCode::Ok
+                }
+                Err(e) => e,
+            }
+        }
+
+        unsafe extern "C" fn $set(obj: UObjectHandle, prop: FPropertyHandle, val: $ty) -> UikaErrorCode {
+            code(with_prop(obj, prop, |slot| {
+                *slot = Some(MockValue::$variant($from(val)));
+                Ok(())
+            }))
+        }
+    };
+}
+
+scalar_accessors!(prop_get_bool, prop_set_bool, bool, Bool, false, |v| v, |v| v);
+scalar_accessors!(prop_get_i32, prop_set_i32, i32, Int, 0, |v: i64| v as i32, |v: i32| v as i64);
+scalar_accessors!(prop_get_i64, prop_set_i64, i64, Int, 0, |v| v, |v| v);
+scalar_accessors!(prop_get_u8, prop_set_u8, u8, Int, 0, |v: i64| v as u8, |v: u8| v as i64);
+scalar_accessors!(prop_get_f32, prop_set_f32, f32, Float, 0.0, |v: f64| v as f32, |v: f32| v as f64);
+scalar_accessors!(prop_get_f64, prop_set_f64, f64, Float, 0.0, |v| v, |v| v);
+scalar_accessors!(prop_get_object, prop_set_object, UObjectHandle, Object, UObjectHandle::null(), |v| v, |v| v);
+
+unsafe extern "C" fn prop_get_string(
+    obj: UObjectHandle, prop: FPropertyHandle, buf: *mut u8, buf_len: u32, out_len: *mut u32,
+) -> UikaErrorCode {
+    let value = with_prop(obj, prop, |slot| match slot {
+        None => Ok(String::new()),
+        Some(MockValue::Str(v)) => Ok(v.clone()),
+        Some(_) => Err(UikaErrorCode::TypeMismatch),
+    });
+    match value {
+        Ok(v) => unsafe { write_out(v.as_bytes(), buf, buf_len, out_len, false) },
+        Err(e) => e,
+    }
+}
+
+unsafe extern "C" fn prop_set_string(obj: UObjectHandle, prop: FPropertyHandle, buf: *const u8, len: u32) -> UikaErrorCode {
+    let value = unsafe { utf8(buf, len) }.to_string();
+    code(with_prop(obj, prop, |slot| {
+        *slot = Some(MockValue::Str(value));
+        Ok(())
+    }))
+}
+
+unsafe extern "C" fn prop_get_fname(obj: UObjectHandle, prop: FPropertyHandle, out: *mut FNameHandle) -> UikaErrorCode {
+    let value = with_prop(obj, prop, |slot| match slot {
+        None => Ok(String::new()),
+        Some(MockValue::Name(v)) => Ok(v.clone()),
+        Some(_) => Err(UikaErrorCode::TypeMismatch),
+    });
+    match value {
+        Ok(v) => {
+            unsafe { *out = lock_or_recover(state()).fname(&v) };
+            UikaErrorCode::Ok
+        }
+        Err(e) => e,
+    }
+}
+
+unsafe extern "C" fn prop_set_fname(obj: UObjectHandle, prop: FPropertyHandle, val: FNameHandle) -> UikaErrorCode {
+    let name = lock_or_recover(state()).fname_str(val).to_string();
+    code(with_prop(obj, prop, |slot| {
+        *slot = Some(MockValue::Name(name));
+        Ok(())
+    }))
+}
+
+unsafe extern "C" fn prop_get_struct(obj: UObjectHandle, prop: FPropertyHandle, out: *mut u8, size: u32) -> UikaErrorCode {
+    let value = with_prop(obj, prop, |slot| match slot {
+        None => Ok(vec![0u8; size as usize]),
+        Some(MockValue::Struct(v)) => Ok(v.clone()),
+        Some(_) => Err(UikaErrorCode::TypeMismatch),
+    });
+    match value {
+        Ok(v) => {
+            let out = unsafe { std::slice::from_raw_parts_mut(out, size as usize) };
+            let n = v.len().min(out.len());
+            out[..n].copy_from_slice(&v[..n]);
+            out[n..].fill(0);
+            UikaErrorCode::Ok
+        }
+        Err(e) => e,
+    }
+}
+
+unsafe extern "C" fn prop_set_struct(obj: UObjectHandle, prop: FPropertyHandle, buf: *const u8, size: u32) -> UikaErrorCode {
+    let data = unsafe { bytes(buf, size) }.to_vec();
+    code(with_prop(obj, prop, |slot| {
+        *slot = Some(MockValue::Struct(data));
+        Ok(())
+    }))
+}
+
+// ---------------------------------------------------------------------------
+// Containers
+// ---------------------------------------------------------------------------
+
+/// Run `f` on an array property, creating it empty on first use.
+fn with_array<R>(
+    obj: UObjectHandle,
+    prop: FPropertyHandle,
+    f: impl FnOnce(&mut Vec<Vec<u8>>) -> Result<R, UikaErrorCode>,
+) -> Result<R, UikaErrorCode> {
+    with_prop(obj, prop, |slot| match slot.get_or_insert_with(|| MockValue::Array(Vec::new())) {
+        MockValue::Array(v) => f(v),
+        _ => Err(UikaErrorCode::TypeMismatch),
+    })
+}
+
+fn with_map<R>(
+    obj: UObjectHandle,
+    prop: FPropertyHandle,
+    f: impl FnOnce(&mut Vec<(Vec<u8>, Vec<u8>)>) -> Result<R, UikaErrorCode>,
+) -> Result<R, UikaErrorCode> {
+    with_prop(obj, prop, |slot| match slot.get_or_insert_with(|| MockValue::Map(Vec::new())) {
+        MockValue::Map(v) => f(v),
+        _ => Err(UikaErrorCode::TypeMismatch),
+    })
+}
+
+fn with_set<R>(
+    obj: UObjectHandle,
+    prop: FPropertyHandle,
+    f: impl FnOnce(&mut Vec<Vec<u8>>) -> Result<R, UikaErrorCode>,
+) -> Result<R, UikaErrorCode> {
+    with_prop(obj, prop, |slot| match slot.get_or_insert_with(|| MockValue::Set(Vec::new())) {
+        MockValue::Set(v) => f(v),
+        _ => Err(UikaErrorCode::TypeMismatch),
+    })
+}
+
+fn index(i: i32, len: usize) -> Result<usize, UikaErrorCode> {
+    usize::try_from(i).ok().filter(|&i| i < len).ok_or(UikaErrorCode::IndexOutOfRange)
+}
+
+/// Copy one element out, or report `BufferTooSmall` with the needed size.
+unsafe fn write_elem(data: &[u8], buf: *mut u8, size: u32, written: *mut u32) -> Result<(), UikaErrorCode> {
+    match unsafe { write_out(data, buf, size, written, true) } {
+        UikaErrorCode::Ok => Ok(()),
+        e => Err(e),
+    }
+}
+
+/// Serve a bulk copy in the framed format (positive count).
+unsafe fn write_bulk(data: Vec<u8>, count: usize, buf: *mut u8, size: u32, total: *mut u32, out_count: *mut i32) -> UikaErrorCode {
+    unsafe { *out_count = count as i32 };
+    unsafe { write_out(&data, buf, size, total, true) }
+}
+
+unsafe extern "C" fn array_len(obj: UObjectHandle, prop: FPropertyHandle) -> i32 {
+    with_array(obj, prop, |a| Ok(a.len() as i32)).unwrap_or(-1)
+}
+
+unsafe extern "C" fn array_get(
+    obj: UObjectHandle, prop: FPropertyHandle, i: i32, buf: *mut u8, size: u32, written: *mut u32,
+) -> UikaErrorCode {
+    code(with_array(obj, prop, |a| unsafe { write_elem(&a[index(i, a.len())?], buf, size, written) }))
+}
+
+unsafe extern "C" fn array_set(obj: UObjectHandle, prop: FPropertyHandle, i: i32, buf: *const u8, size: u32) -> UikaErrorCode {
+    let data = unsafe { bytes(buf, size) }.to_vec();
+    code(with_array(obj, prop, |a| {
+        let i = index(i, a.len())?;
+        a[i] = data;
+        Ok(())
+    }))
+}
+
+unsafe extern "C" fn array_add(obj: UObjectHandle, prop: FPropertyHandle, buf: *const u8, size: u32) -> UikaErrorCode {
+    let data = unsafe { bytes(buf, size) }.to_vec();
+    code(with_array(obj, prop, |a| {
+        a.push(data);
+        Ok(())
+    }))
+}
+
+unsafe extern "C" fn array_remove(obj: UObjectHandle, prop: FPropertyHandle, i: i32) -> UikaErrorCode {
+    code(with_array(obj, prop, |a| {
+        a.remove(index(i, a.len())?);
+        Ok(())
+    }))
+}
+
+unsafe extern "C" fn array_clear(obj: UObjectHandle, prop: FPropertyHandle) -> UikaErrorCode {
+    code(with_array(obj, prop, |a| {
+        a.clear();
+        Ok(())
+    }))
+}
+
+unsafe extern "C" fn array_copy_all(
+    obj: UObjectHandle, prop: FPropertyHandle, buf: *mut u8, size: u32, total: *mut u32, count: *mut i32,
+) -> UikaErrorCode {
+    match with_array(obj, prop, |a| {
+        let mut out = Vec::new();
+        a.iter().for_each(|e| push_framed(&mut out, e));
+        Ok((out, a.len()))
+    }) {
+        Ok((data, n)) => unsafe { write_bulk(data, n, buf, size, total, count) },
+        Err(e) => e,
+    }
+}
+
+unsafe extern "C" fn array_set_all(obj: UObjectHandle, prop: FPropertyHandle, buf: *const u8, size: u32, count: i32) -> UikaErrorCode {
+    let elems = match decode_elems(unsafe { bytes(buf, size) }, count) {
+        Ok(elems) => elems,
+        Err(e) => return e,
+    };
+    code(with_array(obj, prop, |a| {
+        *a = elems;
+        Ok(())
+    }))
+}
+
+/// Split a bulk write buffer into elements (negative count = raw format).
+fn decode_elems(data: &[u8], count: i32) -> Result<Vec<Vec<u8>>, UikaErrorCode> {
+    if count < 0 {
+        // Raw format: `-count` contiguous elements of equal size.
+        let n = (-count) as usize;
+        return Ok(data.chunks(data.len() / n.max(1)).take(n).map(<[u8]>::to_vec).collect());
+    }
+    let mut elems = Vec::with_capacity(count as usize);
+    let mut rest = data;
+    for _ in 0..count {
+        let (len, tail) = rest.split_first_chunk::<4>().ok_or(UikaErrorCode::InvalidOperation)?;
+        let len = u32::from_ne_bytes(*len) as usize;
+        if tail.len() < len {
+            return Err(UikaErrorCode::InvalidOperation);
+        }
+        elems.push(tail[..len].to_vec());
+        rest = &tail[len..];
+    }
+    Ok(elems)
+}
+
+unsafe extern "C" fn array_copy_range(
+    obj: UObjectHandle, prop: FPropertyHandle, start: i32, count: i32,
+    buf: *mut u8, size: u32, total: *mut u32, out_count: *mut i32,
+) -> UikaErrorCode {
+    match with_array(obj, prop, |a| {
+        let start = usize::try_from(start).ok().filter(|&s| s <= a.len()).ok_or(UikaErrorCode::IndexOutOfRange)?;
+        let end = start + usize::try_from(count).map_err(|_| UikaErrorCode::IndexOutOfRange)?.min(a.len() - start);
+        let mut out = Vec::new();
+        a[start..end].iter().for_each(|e| push_framed(&mut out, e));
+        Ok((out, end - start))
+    }) {
+        Ok((data, n)) => unsafe { write_bulk(data, n, buf, size, total, out_count) },
+        Err(e) => e,
+    }
+}
+
+unsafe extern "C" fn array_append_all(obj: UObjectHandle, prop: FPropertyHandle, buf: *const u8, size: u32, count: i32) -> UikaErrorCode {
+    let elems = match decode_elems(unsafe { bytes(buf, size) }, count) {
+        Ok(elems) => elems,
+        Err(e) => return e,
+    };
+    code(with_array(obj, prop, |a| {
+        a.extend(elems);
+        Ok(())
+    }))
+}
+
+unsafe extern "C" fn array_remove_range(obj: UObjectHandle, prop: FPropertyHandle, start: i32, count: i32) -> UikaErrorCode {
+    code(with_array(obj, prop, |a| {
+        let (Ok(start), Ok(count)) = (usize::try_from(start), usize::try_from(count)) else {
+            return Err(UikaErrorCode::IndexOutOfRange);
+        };
+        if start + count > a.len() {
+            return Err(UikaErrorCode::IndexOutOfRange);
+        }
+        a.drain(start..start + count);
+        Ok(())
+    }))
+}
+
+unsafe extern "C" fn map_len(obj: UObjectHandle, prop: FPropertyHandle) -> i32 {
+    with_map(obj, prop, |m| Ok(m.len() as i32)).unwrap_or(-1)
+}
+
+unsafe extern "C" fn map_find(
+    obj: UObjectHandle, prop: FPropertyHandle, key: *const u8, key_size: u32,
+    out: *mut u8, size: u32, written: *mut u32,
+) -> UikaErrorCode {
+    let key = unsafe { bytes(key, key_size) };
+    code(with_map(obj, prop, |m| match m.iter().find(|(k, _)| k == key) {
+        Some((_, v)) => unsafe { write_elem(v, out, size, written) },
+        None => Err(UikaErrorCode::PropertyNotFound),
+    }))
+}
+
+unsafe extern "C" fn map_add(
+    obj: UObjectHandle, prop: FPropertyHandle, key: *const u8, key_size: u32, val: *const u8, val_size: u32,
+) -> UikaErrorCode {
+    let key = unsafe { bytes(key, key_size) }.to_vec();
+    let val = unsafe { bytes(val, val_size) }.to_vec();
+    code(with_map(obj, prop, |m| {
+        match m.iter_mut().find(|(k, _)| *k == key) {
+            Some((_, v)) => *v = val,
+            None => m.push((key, val)),
+        }
+        Ok(())
+    }))
+}
+
+unsafe extern "C" fn map_remove(obj: UObjectHandle, prop: FPropertyHandle, key: *const u8, key_size: u32) -> UikaErrorCode {
+    let key = unsafe { bytes(key, key_size) };
+    code(with_map(obj, prop, |m| {
+        m.retain(|(k, _)| k != key);
+        Ok(())
+    }))
+}
+
+unsafe extern "C" fn map_clear(obj: UObjectHandle, prop: FPropertyHandle) -> UikaErrorCode {
+    code(with_map(obj, prop, |m| {
+        m.clear();
+        Ok(())
+    }))
+}
+
+unsafe extern "C" fn map_get_pair(
+    obj: UObjectHandle, prop: FPropertyHandle, i: i32,
+    key_out: *mut u8, key_size: u32, key_written: *mut u32,
+    val_out: *mut u8, val_size: u32, val_written: *mut u32,
+) -> UikaErrorCode {
+    code(with_map(obj, prop, |m| {
+        let (k, v) = &m[index(i, m.len())?];
+        unsafe { write_elem(k, key_out, key_size, key_written)? };
+        unsafe { write_elem(v, val_out, val_size, val_written) }
+    }))
+}
+
+unsafe extern "C" fn map_copy_all(
+    obj: UObjectHandle, prop: FPropertyHandle, buf: *mut u8, size: u32, total: *mut u32, count: *mut i32,
+) -> UikaErrorCode {
+    match with_map(obj, prop, |m| {
+        let mut out = Vec::new();
+        for (k, v) in m.iter() {
+            push_framed(&mut out, k);
+            push_framed(&mut out, v);
+        }
+        Ok((out, m.len()))
+    }) {
+        Ok((data, n)) => unsafe { write_bulk(data, n, buf, size, total, count) },
+        Err(e) => e,
+    }
+}
+
+unsafe extern "C" fn map_contains_key(obj: UObjectHandle, prop: FPropertyHandle, key: *const u8, key_size: u32) -> bool {
+    let key = unsafe { bytes(key, key_size) };
+    with_map(obj, prop, |m| Ok(m.iter().any(|(k, _)| k == key))).unwrap_or(false)
+}
+
+unsafe extern "C" fn map_find_or_add(
+    obj: UObjectHandle, prop: FPropertyHandle, key: *const u8, key_size: u32, default: *const u8, default_size: u32,
+    out: *mut u8, size: u32, written: *mut u32, added: *mut bool,
+) -> UikaErrorCode {
+    let key = unsafe { bytes(key, key_size) }.to_vec();
+    let default = unsafe { bytes(default, default_size) }.to_vec();
+    code(with_map(obj, prop, |m| {
+        let found = m.iter().position(|(k, _)| *k == key);
+        unsafe { *added = found.is_none() };
+        let i = found.unwrap_or_else(|| {
+            m.push((key, default));
+            m.len() - 1
+        });
+        unsafe { write_elem(&m[i].1, out, size, written) }
+    }))
+}
+
+unsafe extern "C" fn map_remove_and_copy(
+    obj: UObjectHandle, prop: FPropertyHandle, key: *const u8, key_size: u32, out: *mut u8, size: u32, written: *mut u32,
+) -> UikaErrorCode {
+    let key = unsafe { bytes(key, key_size) };
+    code(with_map(obj, prop, |m| {
+        let i = m.iter().position(|(k, _)| k == key).ok_or(UikaErrorCode::PropertyNotFound)?;
+        unsafe { write_elem(&m[i].1, out, size, written)? };
+        m.remove(i);
+        Ok(())
+    }))
+}
+
+unsafe extern "C" fn map_copy_range(
+    obj: UObjectHandle, prop: FPropertyHandle, start: i32, count: i32,
+    buf: *mut u8, size: u32, total: *mut u32, out_count: *mut i32,
+) -> UikaErrorCode {
+    match with_map(obj, prop, |m| {
+        let start = usize::try_from(start).ok().filter(|&s| s <= m.len()).ok_or(UikaErrorCode::IndexOutOfRange)?;
+        let end = start + usize::try_from(count).map_err(|_| UikaErrorCode::IndexOutOfRange)?.min(m.len() - start);
+        let mut out = Vec::new();
+        for (k, v) in &m[start..end] {
+            push_framed(&mut out, k);
+            push_framed(&mut out, v);
+        }
+        Ok((out, end - start))
+    }) {
+        Ok((data, n)) => unsafe { write_bulk(data, n, buf, size, total, out_count) },
+        Err(e) => e,
+    }
+}
+
+unsafe extern "C" fn set_len(obj: UObjectHandle, prop: FPropertyHandle) -> i32 {
+    with_set(obj, prop, |s| Ok(s.len() as i32)).unwrap_or(-1)
+}
+
+unsafe extern "C" fn set_contains(obj: UObjectHandle, prop: FPropertyHandle, elem: *const u8, size: u32) -> bool {
+    let elem = unsafe { bytes(elem, size) };
+    with_set(obj, prop, |s| Ok(s.iter().any(|e| e == elem))).unwrap_or(false)
+}
+
+unsafe extern "C" fn set_add(obj: UObjectHandle, prop: FPropertyHandle, elem: *const u8, size: u32) -> UikaErrorCode {
+    let elem = unsafe { bytes(elem, size) }.to_vec();
+    code(with_set(obj, prop, |s| {
+        if !s.contains(&elem) {
+            s.push(elem);
+        }
+        Ok(())
+    }))
+}
+
+unsafe extern "C" fn set_remove(obj: UObjectHandle, prop: FPropertyHandle, elem: *const u8, size: u32) -> UikaErrorCode {
+    let elem = unsafe { bytes(elem, size) };
+    code(with_set(obj, prop, |s| {
+        s.retain(|e| e != elem);
+        Ok(())
+    }))
+}
+
+unsafe extern "C" fn set_clear(obj: UObjectHandle, prop: FPropertyHandle) -> UikaErrorCode {
+    code(with_set(obj, prop, |s| {
+        s.clear();
+        Ok(())
+    }))
+}
+
+unsafe extern "C" fn set_get_element(
+    obj: UObjectHandle, prop: FPropertyHandle, i: i32, buf: *mut u8, size: u32, written: *mut u32,
+) -> UikaErrorCode {
+    code(with_set(obj, prop, |s| unsafe { write_elem(&s[index(i, s.len())?], buf, size, written) }))
+}
+
+unsafe extern "C" fn set_copy_all(
+    obj: UObjectHandle, prop: FPropertyHandle, buf: *mut u8, size: u32, total: *mut u32, count: *mut i32,
+) -> UikaErrorCode {
+    match with_set(obj, prop, |s| {
+        let mut out = Vec::new();
+        s.iter().for_each(|e| push_framed(&mut out, e));
+        Ok((out, s.len()))
+    }) {
+        Ok((data, n)) => unsafe { write_bulk(data, n, buf, size, total, count) },
+        Err(e) => e,
+    }
+}
+
+unsafe extern "C" fn set_union_into(obj: UObjectHandle, prop: FPropertyHandle, dst: UObjectHandle, dst_prop: FPropertyHandle) -> UikaErrorCode {
+    let elems = match with_set(obj, prop, |s| Ok(s.clone())) {
+        Ok(elems) => elems,
+        Err(e) => return e,
+    };
+    code(with_set(dst, dst_prop, |d| {
+        for e in elems {
+            if !d.contains(&e) {
+                d.push(e);
+            }
+        }
+        Ok(())
+    }))
+}
+
+/// The framed elements of one set that are (`in_other`) or aren't in another.
+fn set_compare(
+    obj: UObjectHandle, prop: FPropertyHandle, other: UObjectHandle, other_prop: FPropertyHandle, in_other: bool,
+) -> Result<(Vec<u8>, usize), UikaErrorCode> {
+    let o = with_set(other, other_prop, |o| Ok(o.clone()))?;
+    with_set(obj, prop, |s| {
+        let mut out = Vec::new();
+        let kept: Vec<_> = s.iter().filter(|e| o.contains(e) == in_other).collect();
+        kept.iter().for_each(|e| push_framed(&mut out, e));
+        Ok((out, kept.len()))
+    })
+}
+
+unsafe extern "C" fn set_intersection(
+    obj: UObjectHandle, prop: FPropertyHandle, other: UObjectHandle, other_prop: FPropertyHandle,
+    buf: *mut u8, size: u32, total: *mut u32, count: *mut i32,
+) -> UikaErrorCode {
+    match set_compare(obj, prop, other, other_prop, true) {
+        Ok((data, n)) => unsafe { write_bulk(data, n, buf, size, total, count) },
+        Err(e) => e,
+    }
+}
+
+unsafe extern "C" fn set_difference(
+    obj: UObjectHandle, prop: FPropertyHandle, other: UObjectHandle, other_prop: FPropertyHandle,
+    buf: *mut u8, size: u32, total: *mut u32, count: *mut i32,
+) -> UikaErrorCode {
+    match set_compare(obj, prop, other, other_prop, false) {
+        Ok((data, n)) => unsafe { write_bulk(data, n, buf, size, total, count) },
+        Err(e) => e,
+    }
+}
+
+unsafe extern "C" fn set_contains_each(
+    obj: UObjectHandle, prop: FPropertyHandle, buf: *const u8, size: u32, count: i32, found: *mut bool,
+) -> UikaErrorCode {
+    let elems = match decode_elems(unsafe { bytes(buf, size) }, count) {
+        Ok(elems) => elems,
+        Err(e) => return e,
+    };
+    code(with_set(obj, prop, |s| {
+        for (i, e) in elems.iter().enumerate() {
+            unsafe { *found.add(i) = s.contains(e) };
+        }
+        Ok(())
+    }))
+}
+
+// ---------------------------------------------------------------------------
+// Logging
+// ---------------------------------------------------------------------------
+
+unsafe extern "C" fn log_log(level: u8, msg: *const u8, len: u32) {
+    let message = unsafe { utf8(msg, len) }.to_string();
+    lock_or_recover(state()).logs.push(LogLine { category: "LogUika".into(), level, message });
+}
+
+unsafe extern "C" fn log_log_category(cat: *const u8, cat_len: u32, level: u8, msg: *const u8, len: u32) {
+    let category = unsafe { utf8(cat, cat_len) }.to_string();
+    let message = unsafe { utf8(msg, len) }.to_string();
+    lock_or_recover(state()).logs.push(LogLine { category, level, message });
+}
+
+/// UE's `ELogVerbosity` order for a `LOG_*` level: lower is more severe.
+fn log_severity(level: u8) -> u8 {
+    match level {
+        crate::logging::LOG_WARNING => 3,
+        crate::logging::LOG_DISPLAY => 4,
+        crate::logging::LOG_LOG => 5,
+        crate::logging::LOG_VERBOSE => 6,
+        crate::logging::LOG_VERY_VERBOSE => 7,
+        _ => 2,
+    }
+}
+
+unsafe extern "C" fn log_register_category(cat: *const u8, cat_len: u32, default_level: u8) {
+    let category = unsafe { utf8(cat, cat_len) }.to_string();
+    lock_or_recover(state()).log_levels.entry(category).or_insert(default_level);
+}
+
+unsafe extern "C" fn log_is_enabled(cat: *const u8, cat_len: u32, level: u8) -> bool {
+    let category = match unsafe { utf8(cat, cat_len) } {
+        "" => "LogUika",
+        name => name,
+    };
+    let mut s = lock_or_recover(state());
+    let verbosity = *s.log_levels.entry(category.to_string()).or_insert(crate::logging::LOG_LOG);
+    log_severity(level) <= log_severity(verbosity)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::containers::{UeArray, UeMap, UeSet};
+    use crate::error::{UikaError, UikaResult};
+    use crate::fname::FName;
+    use crate::object_ref::UObjectRef;
+    use crate::traits::{UeClass, UeStruct};
+    use crate::weak_ptr::TWeakObjectPtr;
+
+    struct MockDerived;
+    impl UeClass for MockDerived {
+        fn static_class() -> UClassHandle {
+            define_class("MockDerived", Some(define_class("MockBase", None)))
+        }
+    }
+
+    #[test]
+    fn scalar_and_string_properties_round_trip() {
+        install();
+        let class = define_class("MockActorA", None);
+        let obj = spawn(class, "ActorA");
+        let health = property("Health");
+        let label = property("Label");
+        unsafe {
+            assert_eq!(crate::ffi_dispatch::property_set_f32(obj, health, 42.5), UikaErrorCode::Ok);
+            let mut out = 0.0f32;
+            assert_eq!(crate::ffi_dispatch::property_get_f32(obj, health, &mut out), UikaErrorCode::Ok);
+            assert_eq!(out, 42.5);
+            let text = "hello";
+            crate::ffi_dispatch::property_set_string(obj, label, text.as_ptr(), text.len() as u32);
+        }
+        assert_eq!(get_value(obj, "Label"), Some(MockValue::Str("hello".into())));
+    }
+
+    #[test]
+    fn names_classes_and_destruction() {
+        install();
+        let base = define_class("MockBase", None);
+        let obj = spawn(MockDerived::static_class(), "Thing");
+        let r: UObjectRef<MockDerived> = unsafe { UObjectRef::from_raw(obj) };
+        assert_eq!(r.get_name().unwrap(), "Thing");
+        assert!(unsafe { crate::ffi_dispatch::core_is_a(obj, base) });
+        assert_eq!(FName::new("Spawn").to_string_lossy(), "Spawn");
+
+        let weak = TWeakObjectPtr::from_ref(&r);
+        destroy(obj);
+        assert!(!r.is_valid());
+        assert!(weak.get().is_none());
+    }
+
+    #[test]
+    fn array_property_round_trips_elements() {
+        install();
+        let obj = spawn(define_class("MockActorB", None), "ActorB");
+        let arr = UeArray::<i32>::new(obj, property("Scores"));
+        arr.push(&3).unwrap();
+        arr.push(&7).unwrap();
+        arr.set(0, &5).unwrap();
+        assert_eq!(arr.to_vec().unwrap(), vec![5, 7]);
+        arr.set_all(&[1, 2, 3]).unwrap();
+        assert_eq!(arr.len().unwrap(), 3);
+        assert_eq!(arr.get(2).unwrap(), 3);
+    }
+
+    #[test]
+    fn array_iterates_in_chunks_and_extends_and_drains() {
+        install();
+        let obj = spawn(define_class("MockActorC", None), "ActorC");
+        let arr = UeArray::<String>::new(obj, property("Tags"));
+        // More elements than fit in one iterator chunk of 4 KiB strings.
+        arr.extend((0..40).map(|i| format!("tag{i}"))).unwrap();
+        assert_eq!(arr.len().unwrap(), 40);
+
+        let iter = arr.iter();
+        assert_eq!(iter.len(), 40);
+        let all: Vec<String> = iter.collect::<UikaResult<_>>().unwrap();
+        assert_eq!(all[39], "tag39");
+        assert_eq!(UeArray::<String>::new(obj, property("Tags")).into_iter().count(), 40);
+
+        assert_eq!(arr.drain(1..=2).unwrap(), ["tag1", "tag2"]);
+        assert_eq!(arr.len().unwrap(), 38);
+        assert_eq!(arr.get(1).unwrap(), "tag3");
+        assert!(matches!(arr.drain(30..50), Err(UikaError::IndexOutOfRange)));
+        assert_eq!(arr.drain(..).unwrap().len(), 38);
+        assert!(arr.is_empty().unwrap());
+    }
+
+    #[test]
+    fn map_key_operations_and_entry_upserts() {
+        install();
+        let obj = spawn(define_class("MockActorD", None), "ActorD");
+        let kills = UeMap::<String, i32>::new(obj, property("Kills"));
+        assert_eq!(kills.get_by_key(&"ann".into()).unwrap(), None);
+        assert_eq!(kills.entry("ann".into()).and_modify(|n| *n += 1).unwrap().or_insert(1).unwrap(), 1);
+        assert_eq!(kills.entry("ann".into()).and_modify(|n| *n += 1).unwrap().or_insert(1).unwrap(), 2);
+        assert_eq!(kills.entry("bob".into()).or_insert_with(|| 7).unwrap(), 7);
+        assert!(kills.contains_key(&"bob".into()).unwrap());
+
+        let pairs: Vec<(String, i32)> = kills.iter().collect::<UikaResult<_>>().unwrap();
+        assert_eq!(pairs, [("ann".to_string(), 2), ("bob".to_string(), 7)]);
+
+        assert_eq!(kills.remove_by_key(&"ann".into()).unwrap(), Some(2));
+        assert_eq!(kills.remove_by_key(&"ann".into()).unwrap(), None);
+        assert!(!kills.contains_key(&"ann".into()).unwrap());
+        assert_eq!(kills.len().unwrap(), 1);
+    }
+
+    #[test]
+    fn set_algebra_runs_against_a_second_set() {
+        install();
+        let obj = spawn(define_class("MockActorE", None), "ActorE");
+        let wanted = UeSet::<i32>::new(obj, property("Wanted"));
+        let owned = UeSet::<i32>::new(obj, property("Owned"));
+        for i in [1, 2, 3, 4] {
+            wanted.add(&i).unwrap();
+        }
+        for i in [3, 4, 5] {
+            owned.add(&i).unwrap();
+        }
+        assert_eq!(wanted.intersection(&owned).unwrap(), [3, 4]);
+        assert_eq!(wanted.difference(&owned).unwrap(), [1, 2]);
+        assert_eq!(owned.contains_each(&[5, 1, 4]).unwrap(), [true, false, true]);
+
+        wanted.union_into(&owned).unwrap();
+        assert_eq!(owned.len().unwrap(), 5);
+        assert_eq!(owned.difference(&wanted).unwrap(), [5]);
+    }
+
+    #[test]
+    fn objects_of_class_pages_through_live_instances() {
+        install();
+        let class = define_class("MockScanned", None);
+        let mut spawned: Vec<_> = (0..300).map(|i| spawn(class, &format!("Scanned{i}"))).collect();
+        spawn(define_class("MockUnscanned", None), "Other");
+        destroy(spawned.remove(7));
+
+        struct MockScanned;
+        impl UeClass for MockScanned {
+            fn static_class() -> UClassHandle {
+                define_class("MockScanned", None)
+            }
+        }
+        let found: Vec<_> = crate::object_iter::objects_of_class::<MockScanned>().map(|r| r.raw()).collect();
+        assert_eq!(found, spawned);
+    }
+
+    #[test]
+    fn default_object_is_created_once_per_class() {
+        install();
+        struct MockWithDefaults;
+        impl UeClass for MockWithDefaults {
+            fn static_class() -> UClassHandle {
+                define_class("MockWithDefaults", None)
+            }
+        }
+        let cdo = MockWithDefaults::default_object();
+        assert_eq!(cdo.get_name().unwrap(), "Default__MockWithDefaults");
+        assert_eq!(cdo.raw(), MockWithDefaults::default_object().raw());
+        assert_eq!(crate::object_iter::objects_of_class::<MockWithDefaults>().count(), 0);
+
+        let class = crate::class_ref::ClassRef::<MockWithDefaults>::of_self();
+        assert_eq!(class.default_object().unwrap().raw(), cdo.raw());
+        assert!(crate::class_ref::ClassRef::<MockWithDefaults>::null().default_object().is_err());
+    }
+
+    struct MockLoadout;
+    impl UeStruct for MockLoadout {
+        fn static_struct() -> UStructHandle {
+            UStructHandle::from_addr(0x10)
+        }
+    }
+
+    struct MockMuzzle;
+    impl UeStruct for MockMuzzle {
+        fn static_struct() -> UStructHandle {
+            UStructHandle::from_addr(0x20)
+        }
+    }
+
+    #[test]
+    fn reflected_fields_are_type_checked() {
+        use crate::containers::OwnedStruct;
+        use crate::field::{check_field_type, FieldValue};
+
+        install();
+        let ammo = define_property("Ammo", UikaReifyPropType::Int32, UikaReifyPropExtra::default());
+        let muzzle = define_property(
+            "Muzzle",
+            UikaReifyPropType::Struct,
+            UikaReifyPropExtra { struct_handle: MockMuzzle::static_struct(), ..Default::default() },
+        );
+        assert!(check_field_type::<i32>(ammo).is_ok());
+        assert!(matches!(check_field_type::<f32>(ammo), Err(UikaError::TypeMismatch)));
+        assert!(check_field_type::<OwnedStruct<MockMuzzle>>(muzzle).is_ok());
+        assert!(check_field_type::<OwnedStruct<MockLoadout>>(muzzle).is_err());
+
+        // Fields resolve the same way on objects as inside struct memory.
+        let obj = spawn(define_class("MockActorF", None), "ActorF");
+        unsafe {
+            30i32.write(obj, ammo).unwrap();
+            assert_eq!(i32::read(obj, ammo).unwrap(), 30);
+        }
+
+        // The type check runs before the struct memory is touched.
+        let loadout = OwnedStruct::<MockLoadout>::from_bytes(vec![0; 16]);
+        assert!(matches!(loadout.get_field::<f32>("Ammo"), Err(UikaError::TypeMismatch)));
+        assert!(matches!(loadout.get_field::<i32>("Undeclared"), Err(UikaError::TypeMismatch)));
+
+        // Nested structs are addressed in place, at the field's offset.
+        let outer = loadout.as_ref();
+        let inner = outer.field_struct::<MockMuzzle>("Muzzle").unwrap();
+        assert_eq!(inner.as_ptr(), outer.as_ptr());
+        assert!(matches!(outer.field_struct::<MockLoadout>("Muzzle"), Err(UikaError::TypeMismatch)));
+    }
+
+    #[test]
+    fn struct_views_read_fields_in_place() {
+        use crate::containers::OwnedStruct;
+        use crate::struct_view::FieldOffset;
+
+        install();
+        define_property("ViewAmmo", UikaReifyPropType::Int32, UikaReifyPropExtra::default());
+        define_property(
+            "ViewMuzzle",
+            UikaReifyPropType::Struct,
+            UikaReifyPropExtra { struct_handle: MockMuzzle::static_struct(), ..Default::default() },
+        );
+        define_property("ViewSpread", UikaReifyPropType::Float, UikaReifyPropExtra::default());
+        set_property_offset("ViewAmmo", 4);
+        set_property_offset("ViewMuzzle", 8);
+        set_property_offset("ViewSpread", 2);
+
+        let mut bytes = vec![0u8; 16];
+        bytes[4..8].copy_from_slice(&30i32.to_ne_bytes());
+        bytes[10..14].copy_from_slice(&0.5f32.to_ne_bytes());
+        let loadout = OwnedStruct::<MockLoadout>::from_bytes(bytes);
+        let view = loadout.view();
+
+        let ammo = FieldOffset::<MockLoadout, i32>::resolve("ViewAmmo").unwrap();
+        assert_eq!(ammo.offset(), 4);
+        assert_eq!(view.get(&ammo), 30);
+
+        // Nested views start at the field's offset; reads are unaligned.
+        let muzzle = FieldOffset::<MockLoadout, OwnedStruct<MockMuzzle>>::resolve("ViewMuzzle").unwrap();
+        let spread = FieldOffset::<MockMuzzle, f32>::resolve("ViewSpread").unwrap();
+        assert_eq!(view.nested(&muzzle).get(&spread), 0.5);
+        assert_eq!(view.nested(&muzzle).as_ptr().to_addr(), view.as_ptr().to_addr() + 8);
+
+        // Offsets are type checked when resolved, not on every read.
+        assert!(matches!(FieldOffset::<MockLoadout, f32>::resolve("ViewAmmo"), Err(UikaError::TypeMismatch)));
+        assert!(matches!(
+            FieldOffset::<MockLoadout, OwnedStruct<MockLoadout>>::resolve("ViewMuzzle"),
+            Err(UikaError::TypeMismatch)
+        ));
+    }
+
+    #[test]
+    fn scalar_properties_are_read_and_written_in_bulk() {
+        use crate::property_bulk::{self, PackedValues};
+
+        install();
+        let declare = |name: &str, kind| define_property(name, kind, UikaReifyPropExtra::default());
+        let props = [
+            declare("BulkHealth", UikaReifyPropType::Float),
+            declare("BulkLevel", UikaReifyPropType::Int32),
+            declare("BulkAlive", UikaReifyPropType::Bool),
+            declare("BulkTarget", UikaReifyPropType::Object),
+            declare("BulkMode", UikaReifyPropType::Enum),
+        ];
+        let label = declare("BulkLabel", UikaReifyPropType::String);
+
+        let obj = spawn(MockDerived::static_class(), "Bulk");
+        let actor: UObjectRef<MockDerived> = unsafe { UObjectRef::from_raw(obj) };
+        set_value(obj, "BulkHealth", MockValue::Float(75.0));
+        set_value(obj, "BulkLevel", MockValue::Int(-3));
+        set_value(obj, "BulkAlive", MockValue::Bool(true));
+        set_value(obj, "BulkTarget", MockValue::Object(obj));
+        set_value(obj, "BulkMode", MockValue::Int(2));
+
+        let mut values = property_bulk::get_many(&actor, &props).unwrap();
+        assert_eq!(values.len(), 5);
+        assert_eq!(values.get::<f32>(0).unwrap(), 75.0);
+        assert_eq!(values.get::<i32>(1).unwrap(), -3);
+        assert!(values.get::<bool>(2).unwrap());
+        assert_eq!(values.get::<UObjectHandle>(3).unwrap(), obj);
+        assert_eq!(values.get::<i64>(4).unwrap(), 2);
+        assert!(matches!(values.get::<f64>(0), Err(UikaError::TypeMismatch)));
+        assert!(matches!(values.get::<f32>(5), Err(UikaError::IndexOutOfRange)));
+        assert!(matches!(property_bulk::get_many(&actor, &[props[0], label]), Err(UikaError::TypeMismatch)));
+
+        values.set(0, 10.0f32).unwrap();
+        values.set(4, 1i64).unwrap();
+        property_bulk::set_many(&actor, &props, &values).unwrap();
+        assert_eq!(get_value(obj, "BulkHealth"), Some(MockValue::Float(10.0)));
+        assert_eq!(get_value(obj, "BulkMode"), Some(MockValue::Int(1)));
+
+        // A value of the wrong type rejects the whole write.
+        let mut pushed = PackedValues::new();
+        pushed.push(99i32);
+        pushed.push(20.0f64);
+        assert!(matches!(
+            property_bulk::set_many(&actor, &[props[1], props[0]], &pushed),
+            Err(UikaError::TypeMismatch)
+        ));
+        assert_eq!(get_value(obj, "BulkLevel"), Some(MockValue::Int(-3)));
+        assert!(matches!(property_bulk::set_many(&actor, &props, &pushed), Err(UikaError::InvalidOperation(_))));
+
+        let mut pushed = PackedValues::new();
+        pushed.push(99i32);
+        pushed.push_enum(0);
+        property_bulk::set_many(&actor, &[props[1], props[4]], &pushed).unwrap();
+        assert_eq!(get_value(obj, "BulkLevel"), Some(MockValue::Int(99)));
+        assert_eq!(get_value(obj, "BulkMode"), Some(MockValue::Int(0)));
+    }
+
+    #[test]
+    fn property_changes_reach_subscribers() {
+        use std::sync::Arc;
+
+        use crate::property_notify::{self, PropertyChange, UIKA_PROPERTY_CHANGE_VALUE_SET};
+
+        install();
+        let obj = spawn(MockDerived::static_class(), "Notified");
+        let other = spawn(MockDerived::static_class(), "NotNotified");
+        let actor: UObjectRef<MockDerived> = unsafe { UObjectRef::from_raw(obj) };
+        let other_actor: UObjectRef<MockDerived> = unsafe { UObjectRef::from_raw(other) };
+        let armor = define_property("NotifyArmor", UikaReifyPropType::Float, UikaReifyPropExtra::default());
+        let speed = define_property("NotifySpeed", UikaReifyPropType::Float, UikaReifyPropExtra::default());
+
+        let seen: Arc<Mutex<Vec<PropertyChange>>> = Arc::default();
+        let sink = seen.clone();
+        let by_name = property_notify::on_property_changed(&actor, Some("NotifyArmor"), move |change| {
+            sink.lock().unwrap().push(change);
+        })
+        .unwrap();
+        let all_of_actor = Arc::new(Mutex::new(0));
+        let count = all_of_actor.clone();
+        let any = property_notify::on_property_changed(&actor, None, move |_| *count.lock().unwrap() += 1).unwrap();
+
+        property_notify::notify_changed(&actor, armor).unwrap();
+        property_notify::notify_changed(&actor, speed).unwrap();
+        property_notify::notify_changed(&other_actor, armor).unwrap();
+
+        let changes = seen.lock().unwrap().clone();
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].object, obj);
+        assert_eq!(changes[0].property.to_string_lossy(), "NotifyArmor");
+        assert_eq!(changes[0].member_property, changes[0].property);
+        assert_eq!(changes[0].change_type, UIKA_PROPERTY_CHANGE_VALUE_SET);
+        assert!(!changes[0].is_interactive());
+        assert_eq!(*all_of_actor.lock().unwrap(), 2);
+
+        by_name.unsubscribe().unwrap();
+        property_notify::notify_changed(&actor, armor).unwrap();
+        assert_eq!(seen.lock().unwrap().len(), 1);
+        assert_eq!(*all_of_actor.lock().unwrap(), 3);
+        any.unsubscribe().unwrap();
+
+        destroy(other);
+        assert!(property_notify::on_property_changed(&other_actor, None, |_| {}).is_err());
+    }
+
+    #[test]
+    fn components_are_added_and_attached() {
+        use crate::components::{attach_component, detach_component, ActorComponentsExt, AttachmentRule, AttachmentRules};
+
+        install();
+        #[derive(Clone, Copy)]
+        struct MockMesh;
+        impl UeClass for MockMesh {
+            fn static_class() -> UClassHandle {
+                define_class("MockMesh", None)
+            }
+        }
+        let actor: UObjectRef<MockDerived> = unsafe { UObjectRef::from_raw(spawn(MockDerived::static_class(), "Assembled")) };
+        let body = actor.add_component::<MockMesh>("Body").unwrap();
+        let barrel = actor.add_component::<MockMesh>("Barrel").unwrap();
+        assert_eq!(body.get_name().unwrap(), "Body");
+        assert_eq!(component_owner(body.raw()), Some(actor.raw()));
+        assert!(matches!(actor.add_component::<MockMesh>("Body"), Err(UikaError::InvalidOperation(_))));
+
+        let muzzle = FName::new("Muzzle");
+        attach_component(barrel, body, Some(muzzle), AttachmentRules::SNAP_TO_TARGET).unwrap();
+        assert_eq!(attach_parent(barrel.raw()), Some((body.raw(), "Muzzle".to_string())));
+        detach_component(barrel, AttachmentRule::KeepWorld).unwrap();
+        assert_eq!(attach_parent(barrel.raw()), None);
+
+        destroy(actor.raw());
+        assert!(matches!(actor.add_component::<MockMesh>("Late"), Err(UikaError::ObjectDestroyed)));
+    }
+
+    #[test]
+    fn subsystems_resolve_per_owner() {
+        use crate::subsystems::{get_game_instance_subsystem, get_world_subsystem};
+
+        install();
+        #[derive(Clone, Copy)]
+        struct MockScoreSubsystem;
+        impl UeClass for MockScoreSubsystem {
+            fn static_class() -> UClassHandle {
+                define_class("MockScoreSubsystem", None)
+            }
+        }
+        #[derive(Clone, Copy)]
+        struct MockWorld;
+        impl UeClass for MockWorld {
+            fn static_class() -> UClassHandle {
+                define_class("MockWorld", None)
+            }
+        }
+        let world_a: UObjectRef<MockWorld> = unsafe { UObjectRef::from_raw(spawn(MockWorld::static_class(), "WorldA")) };
+        let world_b: UObjectRef<MockWorld> = unsafe { UObjectRef::from_raw(spawn(MockWorld::static_class(), "WorldB")) };
+        let a = get_world_subsystem::<MockScoreSubsystem, _>(world_a).unwrap();
+        assert_eq!(get_world_subsystem::<MockScoreSubsystem, _>(world_a).unwrap().raw(), a.raw());
+        assert_ne!(get_world_subsystem::<MockScoreSubsystem, _>(world_b).unwrap().raw(), a.raw());
+
+        let global = get_game_instance_subsystem::<MockScoreSubsystem>().unwrap();
+        assert_eq!(get_game_instance_subsystem::<MockScoreSubsystem>().unwrap().raw(), global.raw());
+
+        destroy(world_b.raw());
+        assert!(matches!(get_world_subsystem::<MockScoreSubsystem, _>(world_b), Err(UikaError::ObjectDestroyed)));
+    }
+
+    #[test]
+    fn spawn_builder_applies_values_before_finishing() {
+        use crate::world::SpawnBuilder;
+
+        install();
+        #[derive(Clone, Copy)]
+        struct MockSpawned;
+        impl UeClass for MockSpawned {
+            fn static_class() -> UClassHandle {
+                define_class("MockSpawned", None)
+            }
+        }
+        define_property("SpawnDamage", UikaReifyPropType::Float, UikaReifyPropExtra::default());
+        let actor = SpawnBuilder::<MockSpawned>::new(UObjectHandle::null(), &[0; 8])
+            .set("SpawnDamage", 25.0f32)
+            .configure(|actor| {
+                assert!(is_spawning(actor.raw()));
+                assert_eq!(get_value(actor.raw(), "SpawnDamage"), Some(MockValue::Float(25.0)));
+                Ok(())
+            })
+            .spawn()
+            .unwrap();
+        assert!(!is_spawning(actor.raw()));
+
+        // A wrong type is rejected before anything is allocated.
+        let before = crate::object_iter::objects_of_class::<MockSpawned>().count();
+        let result = SpawnBuilder::<MockSpawned>::new(UObjectHandle::null(), &[0; 8])
+            .set("SpawnDamage", 25i32)
+            .spawn();
+        assert!(matches!(result, Err(UikaError::TypeMismatch)));
+        assert_eq!(crate::object_iter::objects_of_class::<MockSpawned>().count(), before);
+
+        // A failing hook destroys the actor instead of finishing it.
+        let seen = std::rc::Rc::new(std::cell::Cell::new(UObjectHandle::null()));
+        let result = SpawnBuilder::<MockSpawned>::new(UObjectHandle::null(), &[0; 8])
+            .configure({
+                let seen = seen.clone();
+                move |actor| {
+                    seen.set(actor.raw());
+                    Err(UikaError::InvalidOperation("nope".into()))
+                }
+            })
+            .spawn();
+        assert!(matches!(result, Err(UikaError::InvalidOperation(_))));
+        let spawned = seen.get();
+        assert!(is_spawning(spawned));
+        assert!(!unsafe { core_is_valid(spawned) });
+    }
+
+    #[test]
+    fn log_categories_filter_by_verbosity() {
+        use crate::logging::{register_category, LOG_DISPLAY, LOG_ERROR, LOG_LOG, LOG_VERBOSE, LOG_WARNING};
+        install();
+        let captured = |category: &str| -> Vec<String> {
+            logs().into_iter().filter(|l| l.category == category).map(|l| l.message).collect()
+        };
+
+        register_category("LogHarnessQuiet", LOG_WARNING);
+        register_category("LogHarnessQuiet", LOG_VERBOSE); // already registered: kept
+        let formatted = std::cell::Cell::new(0);
+        let count = || {
+            formatted.set(formatted.get() + 1);
+            formatted.get()
+        };
+        crate::ulog!(category: LogHarnessQuiet, LOG_DISPLAY, "display {}", count());
+        crate::ulog!(category: LogHarnessQuiet, LOG_ERROR, "error {}", count());
+        assert_eq!(captured("LogHarnessQuiet"), ["error 1"]);
+        assert_eq!(formatted.get(), 1, "filtered messages are not formatted");
+
+        // Unregistered categories default to Log.
+        crate::ulog!(category: LogHarnessDefault, LOG_VERBOSE, "verbose");
+        crate::ulog!(category: LogHarnessDefault, LOG_LOG, "log");
+        assert_eq!(captured("LogHarnessDefault"), ["log"]);
+    }
+
+    #[test]
+    fn log_crate_records_reach_the_rust_category() {
+        install();
+        crate::logging::install_log_backend();
+        log::warn!(target: "harness", "low on {}", "ammo");
+        log::debug!(target: "harness", "filtered at the default verbosity");
+        let lines: Vec<_> = logs().into_iter().filter(|l| l.category == "LogRust").collect();
+        assert_eq!(lines.len(), 1);
+        assert_eq!(lines[0].level, crate::logging::LOG_WARNING);
+        assert_eq!(lines[0].message, "[harness] low on ammo");
+    }
+
+    #[test]
+    fn panics_are_logged_with_location_and_disable_their_class() {
+        use crate::ffi_guard::{install_panic_hook, set_panic_policy, PanicPolicy};
+        use crate::reify_registry::{self, RustTypeInfo};
+        install();
+        install_panic_hook();
+        let panics = || -> Vec<String> {
+            logs().into_iter().filter(|l| l.message.contains("harness panic")).map(|l| l.message).collect()
+        };
+
+        crate::ffi_boundary((), || panic!("harness panic one"));
+        let logged = panics();
+        assert_eq!(logged.len(), 1);
+        assert!(logged[0].contains("test_harness.rs:"), "{}", logged[0]);
+
+        const TYPE_ID: u64 = 0xD15A_B1ED;
+        reify_registry::register_type(TYPE_ID, RustTypeInfo {
+            name: "MockPanicky",
+            construct_fn: || Box::into_raw(Box::new(0u32)).cast(),
+            drop_fn: |data| drop(unsafe { Box::from_raw(data.cast::<u32>()) }),
+            references_fn: |_, _| {},
+        });
+        let calls = std::sync::Arc::new(std::sync::atomic::AtomicU32::new(0));
+        let callback = reify_registry::register_function({
+            let calls = std::sync::Arc::clone(&calls);
+            move |_, _, _| {
+                calls.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+                panic!("harness panic two");
+            }
+        });
+        let obj = spawn(define_class("MockPanicky", None), "Panicky");
+        reify_registry::construct_instance(obj, TYPE_ID);
+
+        set_panic_policy(PanicPolicy::DisableClass);
+        for _ in 0..2 {
+            crate::ffi_boundary((), || reify_registry::invoke_function(callback, obj, std::ptr::null_mut()));
+        }
+        set_panic_policy(PanicPolicy::LogAndContinue);
+        assert_eq!(calls.load(std::sync::atomic::Ordering::SeqCst), 1);
+        assert!(logs().iter().any(|l| l.message.contains("MockPanicky panicked")));
+        reify_registry::drop_instance(obj, TYPE_ID);
+    }
+
+    #[test]
+    fn pins_downgrade_upgrade_and_report_their_age() {
+        #[derive(Clone, Copy)]
+        struct MockPinnable;
+        impl UeClass for MockPinnable {
+            fn static_class() -> UClassHandle {
+                define_class("MockPinnable", None)
+            }
+        }
+        install();
+        let obj: UObjectRef<MockPinnable> =
+            unsafe { UObjectRef::from_raw(spawn(MockPinnable::static_class(), "Pinnable")) };
+
+        crate::pinned::set_pin_age_warning(Some(2));
+        let weak = obj
+            .pin_scoped(|pinned| {
+                for _ in 0..3 {
+                    crate::pinned::tick();
+                }
+                pinned.downgrade()
+            })
+            .unwrap();
+        crate::pinned::set_pin_age_warning(None);
+        let warnings: Vec<_> =
+            logs().into_iter().filter(|l| l.message.contains("Pinned<") && l.message.contains("MockPinnable")).collect();
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].message.contains("test_harness.rs:"), "{}", warnings[0].message);
+
+        let repinned = weak.pin().unwrap();
+        assert_eq!(repinned.handle(), obj.raw());
+        drop(repinned);
+        destroy(obj.raw());
+        assert!(matches!(weak.pin(), Err(UikaError::ObjectDestroyed)));
+    }
+
+    #[test]
+    fn api_manifest_matches_table() {
+        let manifest = crate::API_MANIFEST_JSON;
+        assert!(manifest.contains(&format!("\"api_version\": {}", uika_ffi::UIKA_API_VERSION)));
+        assert!(manifest.contains("\"table\": \"UikaPhysicsApi\""));
+        assert!(manifest.contains("\"name\": \"is_valid\""));
+    }
+
+    #[test]
+    fn older_plugin_table_is_zero_extended() {
+        let mut table = build_table();
+        // A version 4 plugin: everything up to the binding stamp, no input.
+        table.size = std::mem::offset_of!(UikaApiTable, input) as u32;
+        let completed = unsafe { &*crate::api::complete_table(&table) };
+        assert!(!completed.physics.is_null());
+        assert!(completed.input.is_null());
+    }
+}
@@ -0,0 +1,87 @@
+// In-engine test runner.
+//
+// Tests that need a live engine (spawning actors, calling UFunctions, ...)
+// cannot run under `cargo test`. They are registered with `uika::ue_test!`
+// instead and run inside UE by the `Uika.RunTests` console command or the
+// `UikaTestRunner` commandlet, both of which call `run_tests` through the
+// Rust callback table.
+//
+// Every test reports one line to `LogUikaTest`, followed by a summary:
+//
+//   LogUikaTest: Display: PASS my_game::tests::spawn_cube (0.42 ms)
+//   LogUikaTest: Error: FAIL my_game::tests::health: object destroyed
+//   LogUikaTest: Display: RESULT 1 passed, 1 failed
+//
+// `uika test` scrapes exactly these lines, so keep the format stable.
+
+use std::panic::{self, AssertUnwindSafe};
+use std::time::Instant;
+
+use crate::error::UikaResult;
+use crate::logging::{log_category, LOG_DISPLAY, LOG_ERROR};
+
+/// Log category every test result line is written to.
+pub const LOG_CATEGORY: &str = "LogUikaTest";
+
+/// Submitted by `uika::ue_test!` — one in-engine test.
+pub struct TestCase {
+    /// Fully qualified name (`module_path!()::fn_name`).
+    pub name: &'static str,
+    pub run: fn() -> UikaResult<()>,
+}
+inventory::collect!(TestCase);
+
+/// Outcome of a [`run_tests`] pass.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct TestSummary {
+    pub passed: u32,
+    pub failed: u32,
+}
+
+/// Run every registered test whose name contains `filter` (all tests if the
+/// filter is empty), logging one PASS/FAIL line per test and a final RESULT
+/// line. Panicking tests count as failures.
+pub fn run_tests(filter: &str) -> TestSummary {
+    let mut tests: Vec<&TestCase> = inventory::iter::<TestCase>
+        .into_iter()
+        .filter(|t| t.name.contains(filter))
+        .collect();
+    tests.sort_by_key(|t| t.name);
+
+    let mut summary = TestSummary::default();
+    for test in tests {
+        let start = Instant::now();
+        let outcome = panic::catch_unwind(AssertUnwindSafe(test.run));
+        let ms = start.elapsed().as_secs_f64() * 1000.0;
+        match outcome {
+            Ok(Ok(())) => {
+                summary.passed += 1;
+                log_category(LOG_CATEGORY, LOG_DISPLAY, &format!("PASS {} ({ms:.2} ms)", test.name));
+            }
+            Ok(Err(e)) => {
+                summary.failed += 1;
+                log_category(LOG_CATEGORY, LOG_ERROR, &format!("FAIL {}: {e}", test.name));
+            }
+            Err(payload) => {
+                summary.failed += 1;
+                let msg = if let Some(report) = crate::ffi_guard::take_panic_report() {
+                    report.message
+                } else if let Some(s) = payload.downcast_ref::<&str>() {
+                    s.to_string()
+                } else if let Some(s) = payload.downcast_ref::<String>() {
+                    s.clone()
+                } else {
+                    "unknown payload".to_string()
+                };
+                log_category(LOG_CATEGORY, LOG_ERROR, &format!("FAIL {}: panicked: {msg}", test.name));
+            }
+        }
+    }
+
+    log_category(
+        LOG_CATEGORY,
+        LOG_DISPLAY,
+        &format!("RESULT {} passed, {} failed", summary.passed, summary.failed),
+    );
+    summary
+}
@@ -0,0 +1,146 @@
+// FText: owning wrapper around FTextHandle.
+// Unlike String, an FText keeps its localization identity (namespace/key or
+// string table entry), so it re-resolves when the culture changes.
+
+use std::fmt;
+
+use uika_ffi::{FPropertyHandle, FTextHandle, UikaErrorCode};
+
+use crate::error::{check_ffi, UikaError, UikaResult};
+use crate::ffi_dispatch;
+use crate::object_ref::UObjectRef;
+use crate::traits::UeClass;
+
+/// A UE FText value. Owns a C++ FText copy, released on drop.
+pub struct FText(FTextHandle);
+
+impl FText {
+    /// `FText::FromString`: a culture-invariant text.
+    pub fn from_string(s: &str) -> UikaResult<Self> {
+        let h = unsafe { ffi_dispatch::text_from_string(s.as_ptr(), s.len() as u32) };
+        Self::from_new_handle(h, "text_from_string")
+    }
+
+    /// `FText::FromStringTable`: look up `key` in the string table `table_id`
+    /// (e.g. `/Game/Localization/ST_UI.ST_UI`).
+    pub fn from_string_table(table_id: &str, key: &str) -> UikaResult<Self> {
+        let h = unsafe {
+            ffi_dispatch::text_from_string_table(
+                table_id.as_ptr(),
+                table_id.len() as u32,
+                key.as_ptr(),
+                key.len() as u32,
+            )
+        };
+        Self::from_new_handle(h, "text_from_string_table")
+    }
+
+    /// `FText::AsLocalizable_Advanced`: a localizable text identified by
+    /// `namespace`/`key`, falling back to `source` (like `NSLOCTEXT`).
+    pub fn localized(namespace: &str, key: &str, source: &str) -> UikaResult<Self> {
+        let h = unsafe {
+            ffi_dispatch::text_as_localizable(
+                namespace.as_ptr(),
+                namespace.len() as u32,
+                key.as_ptr(),
+                key.len() as u32,
+                source.as_ptr(),
+                source.len() as u32,
+            )
+        };
+        Self::from_new_handle(h, "text_as_localizable")
+    }
+
+    /// Take ownership of a handle returned by the text API.
+    ///
+    /// # Safety
+    /// `handle` must be a live FText handle not owned by anything else.
+    pub unsafe fn from_raw(handle: FTextHandle) -> Self {
+        FText(handle)
+    }
+
+    /// Get the underlying FFI handle (still owned by `self`).
+    #[inline]
+    pub fn handle(&self) -> FTextHandle {
+        self.0
+    }
+
+    /// Whether the display string is empty.
+    pub fn is_empty(&self) -> bool {
+        unsafe { ffi_dispatch::text_is_empty(self.0) }
+    }
+
+    /// Same source and identity (`FText::IdenticalTo`), not merely the same
+    /// display string.
+    pub fn identical_to(&self, other: &FText) -> bool {
+        unsafe { ffi_dispatch::text_identical_to(self.0, other.0) }
+    }
+
+    /// The display string for the current culture.
+    pub fn to_string_lossy(&self) -> String {
+        let mut buf = vec![0u8; 256];
+        let mut out_len: u32 = 0;
+        let mut code = unsafe {
+            ffi_dispatch::text_to_string(self.0, buf.as_mut_ptr(), buf.len() as u32, &mut out_len)
+        };
+        if code == UikaErrorCode::BufferTooSmall {
+            buf.resize(out_len as usize, 0);
+            code = unsafe {
+                ffi_dispatch::text_to_string(self.0, buf.as_mut_ptr(), buf.len() as u32, &mut out_len)
+            };
+        }
+        if check_ffi(code).is_err() {
+            return String::from("<invalid FText>");
+        }
+        buf.truncate(out_len as usize);
+        String::from_utf8(buf).unwrap_or_else(|_| String::from("<invalid UTF-8>"))
+    }
+
+    /// Read an FTextProperty from `obj`.
+    pub fn get_property<C: UeClass>(obj: UObjectRef<C>, prop: FPropertyHandle) -> UikaResult<Self> {
+        let h = obj.checked()?.raw();
+        let mut out = FTextHandle::null();
+        check_ffi(unsafe { ffi_dispatch::text_get_property(h, prop, &mut out) })?;
+        Self::from_new_handle(out, "text_get_property")
+    }
+
+    /// Write this text to an FTextProperty on `obj`, keeping its localization
+    /// identity.
+    pub fn set_property<C: UeClass>(&self, obj: UObjectRef<C>, prop: FPropertyHandle) -> UikaResult<()> {
+        let h = obj.checked()?.raw();
+        check_ffi(unsafe { ffi_dispatch::text_set_property(h, prop, self.0) })
+    }
+
+    fn from_new_handle(h: FTextHandle, what: &str) -> UikaResult<Self> {
+        if h.is_null() {
+            return Err(UikaError::InvalidOperation(format!("{what} returned null")));
+        }
+        Ok(FText(h))
+    }
+}
+
+impl Clone for FText {
+    fn clone(&self) -> Self {
+        FText(unsafe { ffi_dispatch::text_clone(self.0) })
+    }
+}
+
+impl Drop for FText {
+    fn drop(&mut self) {
+        if !self.0.is_null() && crate::api::is_api_initialized() {
+            unsafe { ffi_dispatch::text_destroy(self.0) };
+        }
+    }
+}
+
+impl fmt::Display for FText {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.to_string_lossy())
+    }
+}
+
+impl fmt::Debug for FText {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("FText").field(&self.to_string_lossy()).finish()
+    }
+}
@@ -0,0 +1,142 @@
+// Timers: schedule Rust closures on UE's FTimerManager.
+//
+// Timer callbacks go through the delegate registry: C++ fires
+// `invoke_delegate_callback(callback_id, null)` when the timer elapses.
+
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use uika_ffi::UObjectHandle;
+
+use crate::delegate_registry::{is_registered, register_callback, unregister_callback};
+use crate::error::{check_ffi, UikaError, UikaResult};
+use crate::ffi_dispatch;
+use crate::object_ref::UObjectRef;
+use crate::traits::UeClass;
+
+/// How a timer fires.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TimerOptions {
+    /// Seconds between fires (and before the first fire, unless `first_delay` is set).
+    pub rate: f32,
+    /// Keep firing every `rate` seconds until cleared.
+    pub looping: bool,
+    /// Seconds before the first fire. `None` uses `rate`.
+    pub first_delay: Option<f32>,
+}
+
+impl TimerOptions {
+    /// Fire once after `delay` seconds.
+    pub fn once(delay: f32) -> Self {
+        TimerOptions { rate: delay, looping: false, first_delay: None }
+    }
+
+    /// Fire every `rate` seconds until cleared.
+    pub fn looping(rate: f32) -> Self {
+        TimerOptions { rate, looping: true, first_delay: None }
+    }
+
+    /// Override the delay before the first fire.
+    pub fn with_first_delay(mut self, delay: f32) -> Self {
+        self.first_delay = Some(delay);
+        self
+    }
+}
+
+/// Handle to a timer set via [`set_timer`]. Dropping the handle does NOT
+/// clear the timer; call [`clear`](Self::clear) for that.
+#[derive(Debug)]
+pub struct TimerHandle {
+    world_context: UObjectHandle,
+    timer: u64,
+    callback_id: u64,
+}
+
+impl TimerHandle {
+    /// Cancel the timer and release its closure. Clearing a one-shot timer
+    /// that already fired, or a timer whose world is gone, is a no-op.
+    pub fn clear(self) -> UikaResult<()> {
+        // A fired one-shot timer released its closure (and C++ its entry).
+        if !is_registered(self.callback_id) {
+            return Ok(());
+        }
+        unregister_callback(self.callback_id);
+        match check_ffi(unsafe { ffi_dispatch::timer_clear_timer(self.world_context, self.timer) }) {
+            Err(e) if matches!(e.kind(), UikaError::ObjectDestroyed) => Ok(()),
+            result => result,
+        }
+    }
+
+    /// Pause the timer, keeping its remaining time.
+    pub fn pause(&self) -> UikaResult<()> {
+        check_ffi(unsafe { ffi_dispatch::timer_pause_timer(self.world_context, self.timer, true) })
+    }
+
+    /// Resume a paused timer.
+    pub fn unpause(&self) -> UikaResult<()> {
+        check_ffi(unsafe { ffi_dispatch::timer_pause_timer(self.world_context, self.timer, false) })
+    }
+
+    /// Whether the timer is still pending and not paused.
+    pub fn is_active(&self) -> bool {
+        unsafe { ffi_dispatch::timer_is_timer_active(self.world_context, self.timer) }
+    }
+}
+
+/// Schedule `callback` on the timer manager of `world_context`'s world
+/// (an actor, component, or the world itself).
+///
+/// ```ignore
+/// let t = timers::set_timer(actor, TimerOptions::looping(0.5), || ulog!(LOG_DISPLAY, "tick"))?;
+/// ```
+pub fn set_timer<C: UeClass>(
+    world_context: UObjectRef<C>,
+    options: TimerOptions,
+    mut callback: impl FnMut() + Send + 'static,
+) -> UikaResult<TimerHandle> {
+    let ctx = world_context.checked()?.raw();
+
+    // One-shot timers release their closure after firing. The ID is only
+    // known after registration, so it is shared through an atomic.
+    let id_slot = Arc::new(AtomicU64::new(0));
+    let looping = options.looping;
+    let callback_id = {
+        let id_slot = Arc::clone(&id_slot);
+        register_callback(move |_params| {
+            callback();
+            if !looping {
+                unregister_callback(id_slot.load(Ordering::Relaxed));
+            }
+        })
+    };
+    id_slot.store(callback_id, Ordering::Relaxed);
+
+    let timer = unsafe {
+        ffi_dispatch::timer_set_timer(
+            ctx,
+            callback_id,
+            options.rate,
+            options.looping,
+            options.first_delay.unwrap_or(-1.0),
+        )
+    };
+    if timer == 0 {
+        unregister_callback(callback_id);
+        return Err(UikaError::InvalidOperation("set_timer failed (no world or rate <= 0)".into()));
+    }
+    Ok(TimerHandle { world_context: ctx, timer, callback_id })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn clearing_a_fired_one_shot_timer_is_ok() {
+        let callback_id = register_callback(|_params| {});
+        // The closure releases itself when a one-shot timer fires.
+        unregister_callback(callback_id);
+        let handle = TimerHandle { world_context: UObjectHandle::null(), timer: 1, callback_id };
+        assert!(handle.clear().is_ok());
+    }
+}
@@ -12,6 +12,20 @@ use crate::error::UikaResult;
 pub trait UeClass: 'static {
     /// Get the UClass* for this type (cached after first call).
     fn static_class() -> UClassHandle;
+
+    /// The class default object (CDO), holding the class's default property
+    /// values. Treat it as read-only: changes leak into every later spawn.
+    ///
+    /// ```ignore
+    /// let eye_height = ACharacter::default_object().get_base_eye_height()?;
+    /// ```
+    fn default_object() -> crate::object_ref::UObjectRef<Self>
+    where
+        Self: Sized,
+    {
+        let h = unsafe { crate::ffi_dispatch::core_ext_get_default_object(Self::static_class()) };
+        unsafe { crate::object_ref::UObjectRef::from_raw(h) }
+    }
 }
 
 /// Implemented by codegen for every exported UScriptStruct.
@@ -1,13 +1,14 @@
 // UE math types without direct glam equivalents.
 // These are simple Rust structs with conversions to/from glam types where applicable.
 
-use glam::{DQuat, DVec2, DVec3, Vec4};
+use glam::{DMat4, DQuat, DVec2, DVec3, IVec2, Vec4};
 
 // ---------------------------------------------------------------------------
 // Rotator (FRotator equivalent — pitch/yaw/roll in degrees)
 // ---------------------------------------------------------------------------
 
 #[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Rotator {
     pub pitch: f64,
     pub yaw: f64,
@@ -74,6 +75,7 @@ impl From<DQuat> for Rotator {
 // ---------------------------------------------------------------------------
 
 #[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Transform {
     pub rotation: DQuat,
     pub translation: DVec3,
@@ -98,6 +100,56 @@ impl Transform {
     pub fn from_rotation(rotation: DQuat) -> Self {
         Transform { rotation, ..Self::IDENTITY }
     }
+
+    /// Scale, then rotate, then translate, as `FTransform::ToMatrixWithScale`
+    /// (in glam's column-vector convention; see `OwnedFMatrixExt`).
+    pub fn to_matrix(&self) -> DMat4 {
+        DMat4::from_scale_rotation_translation(self.scale, self.rotation, self.translation)
+    }
+
+    /// Decompose a scale/rotation/translation matrix, as `FTransform(FMatrix)`.
+    /// Shear is lost.
+    pub fn from_matrix(m: DMat4) -> Self {
+        let (scale, rotation, translation) = m.to_scale_rotation_translation();
+        Transform { rotation, translation, scale }
+    }
+
+    pub fn transform_point(&self, p: DVec3) -> DVec3 {
+        self.translation + self.rotation * (self.scale * p)
+    }
+
+    /// Like [`transform_point`](Self::transform_point), without translation.
+    pub fn transform_vector(&self, v: DVec3) -> DVec3 {
+        self.rotation * (self.scale * v)
+    }
+
+    /// The transform that undoes this one (through the matrix, so exact
+    /// for uniform scale).
+    pub fn inverse(&self) -> Self {
+        Self::from_matrix(self.to_matrix().inverse())
+    }
+}
+
+/// `a * b` applies `a` first, then `b`, as `FTransform` multiplication
+/// (child * parent = child in world space).
+impl std::ops::Mul for Transform {
+    type Output = Transform;
+
+    fn mul(self, rhs: Transform) -> Transform {
+        Transform::from_matrix(rhs.to_matrix() * self.to_matrix())
+    }
+}
+
+impl From<Transform> for DMat4 {
+    fn from(t: Transform) -> Self {
+        t.to_matrix()
+    }
+}
+
+impl From<DMat4> for Transform {
+    fn from(m: DMat4) -> Self {
+        Transform::from_matrix(m)
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -106,6 +158,7 @@ impl Transform {
 
 /// Linear color (float RGBA, 0.0–1.0 range). Maps to FLinearColor.
 #[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct LinearColor {
     pub r: f32,
     pub g: f32,
@@ -140,6 +193,7 @@ impl From<Vec4> for LinearColor {
 /// 8-bit RGBA color. Maps to FColor (note: UE stores BGRA internally,
 /// conversions handle the reorder).
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Color {
     pub r: u8,
     pub g: u8,
@@ -165,6 +219,7 @@ impl Color {
 
 /// A plane defined by normal + distance from origin. Maps to FPlane.
 #[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Plane {
     pub normal: DVec3,
     pub d: f64,
@@ -174,10 +229,27 @@ impl Plane {
     pub fn new(normal: DVec3, d: f64) -> Self {
         Plane { normal, d }
     }
+
+    /// The plane through `point` facing `normal` (expected normalized).
+    pub fn from_point_normal(point: DVec3, normal: DVec3) -> Self {
+        Plane { normal, d: normal.dot(point) }
+    }
+
+    /// Distance of `p` from the plane, positive on the side `normal` points
+    /// to, as `FPlane::PlaneDot`.
+    pub fn signed_distance(&self, p: DVec3) -> f64 {
+        self.normal.dot(p) - self.d
+    }
+
+    /// The point on the plane closest to `p`.
+    pub fn project_point(&self, p: DVec3) -> DVec3 {
+        p - self.normal * self.signed_distance(p)
+    }
 }
 
 /// A ray defined by origin + direction. Maps to FRay.
 #[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Ray {
     pub origin: DVec3,
     pub direction: DVec3,
@@ -187,10 +259,80 @@ impl Ray {
     pub fn new(origin: DVec3, direction: DVec3) -> Self {
         Ray { origin, direction }
     }
+
+    /// `origin + direction * t`. Distances (`t`) returned by the
+    /// intersection methods are in units of `direction`, so they are world
+    /// distances when it is normalized.
+    pub fn at(&self, t: f64) -> DVec3 {
+        self.origin + self.direction * t
+    }
+
+    /// The point on the ray (not behind its origin) closest to `p`.
+    pub fn closest_point(&self, p: DVec3) -> DVec3 {
+        let len_sq = self.direction.length_squared();
+        if len_sq == 0.0 {
+            return self.origin;
+        }
+        self.at(((p - self.origin).dot(self.direction) / len_sq).max(0.0))
+    }
+
+    /// Where the ray first enters `plane`, if it isn't parallel to it or
+    /// pointing away.
+    pub fn intersect_plane(&self, plane: &Plane) -> Option<f64> {
+        let denom = plane.normal.dot(self.direction);
+        if denom == 0.0 {
+            return None;
+        }
+        let t = -plane.signed_distance(self.origin) / denom;
+        (t >= 0.0).then_some(t)
+    }
+
+    /// Where the ray first hits `sphere`; 0 if it starts inside.
+    pub fn intersect_sphere(&self, sphere: &Sphere) -> Option<f64> {
+        let a = self.direction.length_squared();
+        if a == 0.0 {
+            return None;
+        }
+        let m = self.origin - sphere.center;
+        let b = m.dot(self.direction);
+        let c = m.length_squared() - sphere.radius * sphere.radius;
+        if c <= 0.0 {
+            return Some(0.0);
+        }
+        let discriminant = b * b - a * c;
+        if b > 0.0 || discriminant < 0.0 {
+            return None;
+        }
+        Some((-b - discriminant.sqrt()) / a)
+    }
+
+    /// Where the ray first hits `aabb` (slab test); 0 if it starts inside.
+    pub fn intersect_box(&self, aabb: &UeBox) -> Option<f64> {
+        let mut t_min = 0.0f64;
+        let mut t_max = f64::INFINITY;
+        for axis in 0..3 {
+            let (o, d) = (self.origin[axis], self.direction[axis]);
+            let (lo, hi) = (aabb.min[axis], aabb.max[axis]);
+            if d == 0.0 {
+                if o < lo || o > hi {
+                    return None;
+                }
+                continue;
+            }
+            let (t0, t1) = ((lo - o) / d, (hi - o) / d);
+            t_min = t_min.max(t0.min(t1));
+            t_max = t_max.min(t0.max(t1));
+            if t_min > t_max {
+                return None;
+            }
+        }
+        Some(t_min)
+    }
 }
 
 /// A sphere defined by center + radius. Maps to FSphere.
 #[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Sphere {
     pub center: DVec3,
     pub radius: f64,
@@ -200,6 +342,36 @@ impl Sphere {
     pub fn new(center: DVec3, radius: f64) -> Self {
         Sphere { center, radius }
     }
+
+    pub fn contains(&self, p: DVec3) -> bool {
+        self.center.distance_squared(p) <= self.radius * self.radius
+    }
+
+    /// Whether the spheres touch or overlap.
+    pub fn intersects(&self, other: &Sphere) -> bool {
+        let r = self.radius + other.radius;
+        self.center.distance_squared(other.center) <= r * r
+    }
+
+    /// Whether the sphere touches or overlaps `aabb`.
+    pub fn intersects_box(&self, aabb: &UeBox) -> bool {
+        self.contains(aabb.closest_point(self.center))
+    }
+
+    /// The point in (or on) the sphere closest to `p`.
+    pub fn closest_point(&self, p: DVec3) -> DVec3 {
+        let offset = p - self.center;
+        if offset.length_squared() <= self.radius * self.radius {
+            return p;
+        }
+        self.center + offset.normalize() * self.radius
+    }
+
+    /// The sphere under `t`, radius scaled by the largest scale axis, as
+    /// `FSphere::TransformBy`.
+    pub fn transformed(&self, t: &Transform) -> Sphere {
+        Sphere { center: t.transform_point(self.center), radius: self.radius * t.scale.abs().max_element() }
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -209,6 +381,7 @@ impl Sphere {
 /// Axis-aligned bounding box. Named `UeBox` to avoid conflict with Rust's `Box`.
 /// Maps to FBox.
 #[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UeBox {
     pub min: DVec3,
     pub max: DVec3,
@@ -218,10 +391,63 @@ impl UeBox {
     pub fn new(min: DVec3, max: DVec3) -> Self {
         UeBox { min, max }
     }
+
+    pub fn from_center_extent(center: DVec3, extent: DVec3) -> Self {
+        UeBox { min: center - extent, max: center + extent }
+    }
+
+    pub fn center(&self) -> DVec3 {
+        (self.min + self.max) * 0.5
+    }
+
+    /// Half the size, as `FBox::GetExtent`.
+    pub fn extent(&self) -> DVec3 {
+        (self.max - self.min) * 0.5
+    }
+
+    pub fn size(&self) -> DVec3 {
+        self.max - self.min
+    }
+
+    /// Whether `p` is inside or on the surface.
+    pub fn contains(&self, p: DVec3) -> bool {
+        p.cmpge(self.min).all() && p.cmple(self.max).all()
+    }
+
+    /// Whether the boxes touch or overlap, as `FBox::Intersect`.
+    pub fn intersects(&self, other: &UeBox) -> bool {
+        self.min.cmple(other.max).all() && other.min.cmple(self.max).all()
+    }
+
+    /// The point in (or on) the box closest to `p`.
+    pub fn closest_point(&self, p: DVec3) -> DVec3 {
+        p.clamp(self.min, self.max)
+    }
+
+    /// Distance from `p` to the box; 0 inside.
+    pub fn distance_to(&self, p: DVec3) -> f64 {
+        self.closest_point(p).distance(p)
+    }
+
+    /// The smallest box holding both.
+    pub fn union(&self, other: &UeBox) -> UeBox {
+        UeBox { min: self.min.min(other.min), max: self.max.max(other.max) }
+    }
+
+    /// The axis-aligned box around this box under `t`, as `FBox::TransformBy`.
+    pub fn transformed(&self, t: &Transform) -> UeBox {
+        let center = t.transform_point(self.center());
+        // Each world axis extent is the sum of the projected local extents.
+        let m = glam::DMat3::from_quat(t.rotation) * glam::DMat3::from_diagonal(t.scale);
+        let e = self.extent();
+        let extent = m.x_axis.abs() * e.x + m.y_axis.abs() * e.y + m.z_axis.abs() * e.z;
+        UeBox::from_center_extent(center, extent)
+    }
 }
 
 /// 2D axis-aligned bounding box. Maps to FBox2D.
 #[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UeBox2d {
     pub min: DVec2,
     pub max: DVec2,
@@ -231,10 +457,75 @@ impl UeBox2d {
     pub fn new(min: DVec2, max: DVec2) -> Self {
         UeBox2d { min, max }
     }
+
+    /// Whether `p` is inside or on the edge.
+    pub fn contains(&self, p: DVec2) -> bool {
+        p.cmpge(self.min).all() && p.cmple(self.max).all()
+    }
+
+    /// Whether the boxes touch or overlap.
+    pub fn intersects(&self, other: &UeBox2d) -> bool {
+        self.min.cmple(other.max).all() && other.min.cmple(self.max).all()
+    }
+
+    /// The point in (or on) the box closest to `p`.
+    pub fn closest_point(&self, p: DVec2) -> DVec2 {
+        p.clamp(self.min, self.max)
+    }
+}
+
+/// Integer rectangle, `max` exclusive. Maps to FIntRect (FInt32Rect), and
+/// has its layout, so generated functions take and return it by value.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct IntRect {
+    pub min: IVec2,
+    pub max: IVec2,
+}
+
+impl IntRect {
+    pub fn new(min: IVec2, max: IVec2) -> Self {
+        IntRect { min, max }
+    }
+
+    pub fn width(&self) -> i32 {
+        self.max.x - self.min.x
+    }
+
+    pub fn height(&self) -> i32 {
+        self.max.y - self.min.y
+    }
+
+    pub fn size(&self) -> IVec2 {
+        self.max - self.min
+    }
+
+    pub fn area(&self) -> i32 {
+        self.width() * self.height()
+    }
+
+    /// Whether `p` is inside, as `FIntRect::Contains` (`max` excluded).
+    pub fn contains(&self, p: IVec2) -> bool {
+        p.cmpge(self.min).all() && p.cmplt(self.max).all()
+    }
+
+    /// The overlap of both rectangles, if they overlap.
+    pub fn intersection(&self, other: &IntRect) -> Option<IntRect> {
+        let r = IntRect::new(self.min.max(other.min), self.max.min(other.max));
+        (r.width() > 0 && r.height() > 0).then_some(r)
+    }
+
+    /// Every cell in the rectangle, row by row.
+    pub fn cells(&self) -> impl Iterator<Item = IVec2> + use<> {
+        let (min, max) = (self.min, self.max);
+        (min.y..max.y).flat_map(move |y| (min.x..max.x).map(move |x| IVec2::new(x, y)))
+    }
 }
 
 /// Combined box + sphere bounds. Maps to FBoxSphereBounds.
 #[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BoxSphereBounds {
     pub origin: DVec3,
     pub box_extent: DVec3,
@@ -247,6 +538,149 @@ impl BoxSphereBounds {
     }
 }
 
+// ---------------------------------------------------------------------------
+// Guid
+// ---------------------------------------------------------------------------
+
+/// 128-bit globally unique identifier. Maps to FGuid (same layout: four
+/// 32-bit words), so generated functions taking or returning FGuid use it
+/// directly.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Guid {
+    pub a: u32,
+    pub b: u32,
+    pub c: u32,
+    pub d: u32,
+}
+
+/// String formats of a [`Guid`], as UE's `EGuidFormats`. Shown for the guid
+/// `{a: 0x01234567, b: 0x89ABCDEF, c: 0x01234567, d: 0x89ABCDEF}`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GuidFormat {
+    /// `0123456789ABCDEF0123456789ABCDEF` (UE's default).
+    Digits,
+    /// `0123456789abcdef0123456789abcdef`
+    DigitsLower,
+    /// `01234567-89AB-CDEF-0123-456789ABCDEF`
+    DigitsWithHyphens,
+    /// `01234567-89ab-cdef-0123-456789abcdef`
+    DigitsWithHyphensLower,
+    /// `{01234567-89AB-CDEF-0123-456789ABCDEF}`
+    DigitsWithHyphensInBraces,
+    /// `(01234567-89AB-CDEF-0123-456789ABCDEF)`
+    DigitsWithHyphensInParentheses,
+    /// `{0x01234567,0x89AB,0xCDEF,{0x01,0x23,0x45,0x67,0x89,0xAB,0xCD,0xEF}}`
+    HexValuesInBraces,
+    /// `01234567-89ABCDEF-01234567-89ABCDEF`
+    UniqueObjectGuid,
+}
+
+impl GuidFormat {
+    const ALL: [GuidFormat; 8] = [
+        GuidFormat::Digits,
+        GuidFormat::DigitsLower,
+        GuidFormat::DigitsWithHyphens,
+        GuidFormat::DigitsWithHyphensLower,
+        GuidFormat::DigitsWithHyphensInBraces,
+        GuidFormat::DigitsWithHyphensInParentheses,
+        GuidFormat::HexValuesInBraces,
+        GuidFormat::UniqueObjectGuid,
+    ];
+}
+
+impl Guid {
+    /// The all-zero guid, which UE treats as invalid.
+    pub const ZERO: Guid = Guid { a: 0, b: 0, c: 0, d: 0 };
+
+    pub fn new(a: u32, b: u32, c: u32, d: u32) -> Self {
+        Guid { a, b, c, d }
+    }
+
+    /// A fresh guid from `FGuid::NewGuid`.
+    pub fn new_guid() -> Self {
+        let mut words = [0u32; 4];
+        unsafe { crate::ffi_dispatch::guid_new_guid(words.as_mut_ptr()) };
+        let [a, b, c, d] = words;
+        Guid { a, b, c, d }
+    }
+
+    /// Whether any word is non-zero, as `FGuid::IsValid`.
+    pub fn is_valid(&self) -> bool {
+        *self != Self::ZERO
+    }
+
+    /// Format as `format`, like `FGuid::ToString(EGuidFormats)`.
+    pub fn to_string_format(&self, format: GuidFormat) -> String {
+        let Guid { a, b, c, d } = *self;
+        let s = match format {
+            GuidFormat::Digits | GuidFormat::DigitsLower => format!("{a:08X}{b:08X}{c:08X}{d:08X}"),
+            GuidFormat::DigitsWithHyphens | GuidFormat::DigitsWithHyphensLower => {
+                format!("{a:08X}-{:04X}-{:04X}-{:04X}-{:04X}{d:08X}", b >> 16, b & 0xFFFF, c >> 16, c & 0xFFFF)
+            }
+            GuidFormat::DigitsWithHyphensInBraces => {
+                format!("{{{}}}", self.to_string_format(GuidFormat::DigitsWithHyphens))
+            }
+            GuidFormat::DigitsWithHyphensInParentheses => {
+                format!("({})", self.to_string_format(GuidFormat::DigitsWithHyphens))
+            }
+            GuidFormat::HexValuesInBraces => {
+                let [c0, c1, c2, c3] = c.to_be_bytes();
+                let [d0, d1, d2, d3] = d.to_be_bytes();
+                format!(
+                    "{{0x{a:08X},0x{:04X},0x{:04X},{{0x{c0:02X},0x{c1:02X},0x{c2:02X},0x{c3:02X},\
+                     0x{d0:02X},0x{d1:02X},0x{d2:02X},0x{d3:02X}}}}}",
+                    b >> 16,
+                    b & 0xFFFF
+                )
+            }
+            GuidFormat::UniqueObjectGuid => format!("{a:08X}-{b:08X}-{c:08X}-{d:08X}"),
+        };
+        match format {
+            GuidFormat::DigitsLower | GuidFormat::DigitsWithHyphensLower => s.to_ascii_lowercase(),
+            _ => s,
+        }
+    }
+
+    /// Parse a guid in exactly `format` (hex digits in either case), like
+    /// `FGuid::ParseExact`.
+    pub fn parse_exact(s: &str, format: GuidFormat) -> Option<Guid> {
+        let digits: String = match format {
+            GuidFormat::HexValuesInBraces => s.split("0x").skip(1).map(|part| part.trim_end_matches([',', '{', '}'])).collect(),
+            _ => s.chars().filter(char::is_ascii_hexdigit).collect(),
+        };
+        // Byte-indexed below, so anything but 32 ASCII hex digits is out.
+        if digits.len() != 32 || !digits.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return None;
+        }
+        let word = |i: usize| u32::from_str_radix(&digits[i * 8..i * 8 + 8], 16).ok();
+        let guid = Guid { a: word(0)?, b: word(1)?, c: word(2)?, d: word(3)? };
+        // Everything but the digits must match the format exactly.
+        guid.to_string_format(format).eq_ignore_ascii_case(s).then_some(guid)
+    }
+
+    /// Parse a guid in any [`GuidFormat`], like `FGuid::Parse`.
+    pub fn parse(s: &str) -> Option<Guid> {
+        GuidFormat::ALL.iter().find_map(|&format| Guid::parse_exact(s, format))
+    }
+}
+
+/// [`GuidFormat::Digits`], as `FGuid::ToString()`.
+impl std::fmt::Display for Guid {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.to_string_format(GuidFormat::Digits))
+    }
+}
+
+impl std::str::FromStr for Guid {
+    type Err = crate::error::UikaError;
+
+    fn from_str(s: &str) -> Result<Guid, Self::Err> {
+        Guid::parse(s).ok_or_else(|| crate::error::UikaError::InvalidOperation(format!("not a guid: '{s}'")))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -275,4 +709,93 @@ mod tests {
         let c2: LinearColor = v.into();
         assert_eq!(c, c2);
     }
+
+    #[test]
+    fn transform_matrix_composition() {
+        let child = Transform::new(
+            DQuat::from_rotation_z(std::f64::consts::FRAC_PI_2),
+            DVec3::new(10.0, 0.0, 0.0),
+            DVec3::splat(2.0),
+        );
+        let parent = Transform::from_translation(DVec3::new(0.0, 0.0, 5.0));
+        let p = DVec3::new(1.0, 0.0, 0.0);
+        assert!((child.to_matrix().transform_point3(p) - child.transform_point(p)).length() < 1e-9);
+
+        let world = child * parent;
+        let expected = parent.transform_point(child.transform_point(p));
+        assert!((world.transform_point(p) - expected).length() < 1e-9);
+        assert!((world.inverse().transform_point(expected) - p).length() < 1e-9);
+
+        let back = Transform::from_matrix(world.to_matrix());
+        assert!((back.translation - world.translation).length() < 1e-9);
+        assert!((back.scale - world.scale).length() < 1e-9);
+    }
+
+    #[test]
+    fn shape_queries() {
+        let aabb = UeBox::new(DVec3::splat(-1.0), DVec3::splat(1.0));
+        let ray = Ray::new(DVec3::new(-5.0, 0.0, 0.0), DVec3::X);
+        assert_eq!(ray.intersect_box(&aabb), Some(4.0));
+        assert_eq!(Ray::new(DVec3::new(-5.0, 3.0, 0.0), DVec3::X).intersect_box(&aabb), None);
+        assert_eq!(Ray::new(DVec3::ZERO, DVec3::X).intersect_box(&aabb), Some(0.0));
+
+        let sphere = Sphere::new(DVec3::new(10.0, 0.0, 0.0), 2.0);
+        assert_eq!(ray.intersect_sphere(&sphere), Some(13.0));
+        assert_eq!(Ray::new(DVec3::new(-5.0, 0.0, 0.0), -DVec3::X).intersect_sphere(&sphere), None);
+        assert!(!sphere.intersects_box(&aabb));
+        assert!(Sphere::new(DVec3::new(2.5, 0.0, 0.0), 2.0).intersects_box(&aabb));
+
+        let floor = Plane::from_point_normal(DVec3::new(0.0, 0.0, 2.0), DVec3::Z);
+        assert_eq!(floor.signed_distance(DVec3::new(7.0, 1.0, 5.0)), 3.0);
+        assert_eq!(Ray::new(DVec3::new(0.0, 0.0, 5.0), -DVec3::Z).intersect_plane(&floor), Some(3.0));
+        assert_eq!(aabb.closest_point(DVec3::new(3.0, 0.5, -4.0)), DVec3::new(1.0, 0.5, -1.0));
+        assert!(aabb.intersects(&UeBox::new(DVec3::splat(1.0), DVec3::splat(2.0))));
+
+        let t = Transform::new(DQuat::from_rotation_z(std::f64::consts::FRAC_PI_4), DVec3::new(5.0, 0.0, 0.0), DVec3::ONE);
+        let moved = aabb.transformed(&t);
+        assert!((moved.center() - DVec3::new(5.0, 0.0, 0.0)).length() < 1e-9);
+        assert!((moved.extent().x - std::f64::consts::SQRT_2).abs() < 1e-9);
+        assert_eq!(sphere.transformed(&t).radius, 2.0);
+    }
+
+    #[test]
+    fn int_rect_cells_and_overlap() {
+        let a = IntRect::new(IVec2::new(0, 0), IVec2::new(3, 2));
+        assert_eq!(a.area(), 6);
+        assert_eq!(a.cells().count(), 6);
+        assert!(a.contains(IVec2::new(2, 1)));
+        assert!(!a.contains(IVec2::new(3, 1)), "max is exclusive");
+
+        let b = IntRect::new(IVec2::new(2, 1), IVec2::new(5, 5));
+        assert_eq!(a.intersection(&b), Some(IntRect::new(IVec2::new(2, 1), IVec2::new(3, 2))));
+        assert_eq!(a.intersection(&IntRect::new(IVec2::new(3, 0), IVec2::new(4, 1))), None);
+    }
+
+    #[test]
+    fn guid_formats_round_trip() {
+        let guid = Guid::new(0x0123_4567, 0x89AB_CDEF, 0x0123_4567, 0x89AB_CDEF);
+        assert_eq!(guid.to_string(), "0123456789ABCDEF0123456789ABCDEF");
+        assert_eq!(
+            guid.to_string_format(GuidFormat::DigitsWithHyphensInBraces),
+            "{01234567-89AB-CDEF-0123-456789ABCDEF}"
+        );
+        assert_eq!(
+            guid.to_string_format(GuidFormat::HexValuesInBraces),
+            "{0x01234567,0x89AB,0xCDEF,{0x01,0x23,0x45,0x67,0x89,0xAB,0xCD,0xEF}}"
+        );
+        for format in GuidFormat::ALL {
+            let text = guid.to_string_format(format);
+            assert_eq!(Guid::parse_exact(&text, format), Some(guid), "{format:?}");
+            assert_eq!(Guid::parse(&text), Some(guid), "{format:?}");
+        }
+        assert_eq!(Guid::parse_exact("01234567-89ab-cdef-0123-456789abcdef", GuidFormat::DigitsWithHyphens), Some(guid));
+        assert_eq!(Guid::parse_exact("0123456789ABCDEF0123456789ABCDEF", GuidFormat::DigitsWithHyphens), None);
+        assert_eq!(Guid::parse("01234567-89AB-CDEF-0123-456789ABCDEG"), None);
+        assert!("not a guid".parse::<Guid>().is_err());
+        // Non-ASCII text between the `0x` prefixes.
+        assert_eq!(
+            Guid::parse_exact("{0x0123456é,0x89AB,0xCDEF,{0x01,0x23,0x45,0x67,0x89,0xAB,0xCD,0xE}}", GuidFormat::HexValuesInBraces),
+            None
+        );
+    }
 }
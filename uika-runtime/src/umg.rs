@@ -0,0 +1,111 @@
+// UMG: create widgets, show them in the viewport and look up named children.
+//
+// Typed counterpart of the raw `widget` module, for driving HUDs from Rust
+// without a Blueprint widget controller.
+
+use uika_ffi::UObjectHandle;
+
+use crate::class_ref::ClassRef;
+use crate::error::{check_ffi, UikaError, UikaResult};
+use crate::ffi_dispatch;
+use crate::object_ref::UObjectRef;
+use crate::traits::UeClass;
+use crate::widget::create_widget_raw;
+
+/// `ESlateVisibility`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[repr(u8)]
+pub enum Visibility {
+    /// Visible and hit-testable.
+    Visible = 0,
+    /// Invisible and takes up no layout space.
+    Collapsed = 1,
+    /// Invisible but still takes up layout space.
+    Hidden = 2,
+    /// Visible, but neither it nor its children can be clicked.
+    HitTestInvisible = 3,
+    /// Visible, but only its children can be clicked.
+    SelfHitTestInvisible = 4,
+}
+
+impl Visibility {
+    fn from_u8(v: u8) -> UikaResult<Self> {
+        Ok(match v {
+            0 => Visibility::Visible,
+            1 => Visibility::Collapsed,
+            2 => Visibility::Hidden,
+            3 => Visibility::HitTestInvisible,
+            4 => Visibility::SelfHitTestInvisible,
+            _ => return Err(UikaError::InvalidOperation(format!("unknown ESlateVisibility {v}"))),
+        })
+    }
+}
+
+/// Create a user widget of class `W` owned by `owning_player` (a
+/// PlayerController; a World or GameInstance also works).
+pub fn create_widget<W: UeClass, P: UeClass>(owning_player: UObjectRef<P>) -> UikaResult<UObjectRef<W>> {
+    let owner = owning_player.checked()?.raw();
+    let h = create_widget_raw(owner, W::static_class())?;
+    Ok(unsafe { UObjectRef::from_raw(h) })
+}
+
+/// Create a user widget of a runtime-chosen class (e.g. a Blueprint widget
+/// class loaded from a soft class path).
+pub fn create_widget_of_class<W: UeClass, P: UeClass>(
+    owning_player: UObjectRef<P>,
+    class: ClassRef<W>,
+) -> UikaResult<UObjectRef<W>> {
+    let owner = owning_player.checked()?.raw();
+    let h = create_widget_raw(owner, class.class())?;
+    Ok(unsafe { UObjectRef::from_raw(h) })
+}
+
+/// Add a user widget to the game viewport. Higher `z_order` draws on top.
+pub fn add_to_viewport<W: UeClass>(widget: UObjectRef<W>, z_order: i32) -> UikaResult<()> {
+    let w = widget.checked()?.raw();
+    check_ffi(unsafe { ffi_dispatch::ui_add_to_viewport(w, z_order, false) })
+}
+
+/// Add a user widget to its owning player's screen only (split-screen).
+pub fn add_to_player_screen<W: UeClass>(widget: UObjectRef<W>, z_order: i32) -> UikaResult<()> {
+    let w = widget.checked()?.raw();
+    check_ffi(unsafe { ffi_dispatch::ui_add_to_viewport(w, z_order, true) })
+}
+
+/// Remove a widget from its parent panel or from the viewport.
+pub fn remove_from_parent<W: UeClass>(widget: UObjectRef<W>) -> UikaResult<()> {
+    let w = widget.checked()?.raw();
+    check_ffi(unsafe { ffi_dispatch::ui_remove_from_parent(w) })
+}
+
+/// Whether a user widget is currently in the viewport.
+pub fn is_in_viewport<W: UeClass>(widget: UObjectRef<W>) -> bool {
+    widget.is_valid() && unsafe { ffi_dispatch::ui_is_in_viewport(widget.raw()) }
+}
+
+pub fn set_visibility<W: UeClass>(widget: UObjectRef<W>, visibility: Visibility) -> UikaResult<()> {
+    let w = widget.checked()?.raw();
+    check_ffi(unsafe { ffi_dispatch::ui_set_visibility(w, visibility as u8) })
+}
+
+pub fn get_visibility<W: UeClass>(widget: UObjectRef<W>) -> UikaResult<Visibility> {
+    let w = widget.checked()?.raw();
+    let mut out = 0u8;
+    check_ffi(unsafe { ffi_dispatch::ui_get_visibility(w, &mut out) })?;
+    Visibility::from_u8(out)
+}
+
+/// Find a named widget in a user widget's tree (the name set in the UMG
+/// designer) and cast it to `T`. `Ok(None)` if there is no such widget.
+pub fn find_widget<T: UeClass, W: UeClass>(
+    user_widget: UObjectRef<W>,
+    name: &str,
+) -> UikaResult<Option<UObjectRef<T>>> {
+    let w = user_widget.checked()?.raw();
+    let h: UObjectHandle =
+        unsafe { ffi_dispatch::ui_find_widget(w, name.as_ptr(), name.len() as u32) };
+    if h.is_null() {
+        return Ok(None);
+    }
+    unsafe { UObjectRef::<T>::from_raw(h) }.cast().map(Some)
+}
@@ -6,8 +6,10 @@ use std::marker::PhantomData;
 
 use uika_ffi::FWeakObjectHandle;
 
+use crate::error::{UikaError, UikaResult};
 use crate::ffi_dispatch;
 use crate::object_ref::UObjectRef;
+use crate::pinned::Pinned;
 use crate::traits::UeClass;
 
 /// A typed weak reference to a UObject.
@@ -46,6 +48,13 @@ impl<T: UeClass> TWeakObjectPtr<T> {
         }
     }
 
+    /// Resolve and pin the object, keeping it alive until the `Pinned` is
+    /// dropped. `ObjectDestroyed` if it is already gone.
+    #[track_caller]
+    pub fn pin(&self) -> UikaResult<Pinned<T>> {
+        Pinned::new(self.get().ok_or(UikaError::ObjectDestroyed)?)
+    }
+
     /// Check if the referenced object is still alive.
     pub fn is_valid(&self) -> bool {
         unsafe { ffi_dispatch::core_is_weak_valid(self.handle) }
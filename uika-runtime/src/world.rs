@@ -1,10 +1,21 @@
 // World-level gameplay template function wrappers (raw handle versions).
 // Type-safe wrappers live in uika-bindings/src/manual/world_ext.rs.
 
-use uika_ffi::{UClassHandle, UObjectHandle};
+use glam::DVec3;
+use uika_ffi::{
+    FPropertyHandle, UClassHandle, UObjectHandle, UikaCollisionShape, UikaErrorCode, UikaHitResult,
+    UIKA_SHAPE_BOX, UIKA_SHAPE_CAPSULE, UIKA_SHAPE_SPHERE,
+};
 
+use crate::class_ref::ClassRef;
+use crate::dynamic_call::DynamicCall;
 use crate::error::{check_ffi, UikaError, UikaResult};
 use crate::ffi_dispatch;
+use crate::field::{check_field_type, FieldValue};
+use crate::fname::FName;
+use crate::object_ref::UObjectRef;
+use crate::struct_ref::UStructRef;
+use crate::traits::{UeClass, UeStruct};
 
 /// Spawn an actor in the world.
 ///
@@ -115,6 +126,160 @@ pub fn finish_spawning_raw(
     })
 }
 
+// ---------------------------------------------------------------------------
+// Deferred spawn with property injection
+// ---------------------------------------------------------------------------
+
+type PropertyWrite = Box<dyn FnOnce(UObjectHandle, FPropertyHandle) -> UikaResult<()>>;
+type SpawnHook<T> = Box<dyn FnOnce(UObjectRef<T>) -> UikaResult<()>>;
+
+struct PendingProperty {
+    name: String,
+    check: fn(FPropertyHandle) -> UikaResult<()>,
+    write: PropertyWrite,
+}
+
+/// Spawns an actor with values applied between allocation and
+/// `FinishSpawning`, so they are in place before the construction script and
+/// BeginPlay run, like the exposed-on-spawn pins of Blueprint's SpawnActor.
+///
+/// Property names are resolved and type-checked against the spawned class
+/// before anything is allocated, so a typo or a wrong type fails without
+/// leaving an actor behind.
+///
+/// ```ignore
+/// let projectile = world.spawn_builder::<AProjectile>(&transform)
+///     .owner(shooter)
+///     .set("Damage", 25.0f32)
+///     .set("Team", FName::new("Red"))
+///     .configure(|actor| {
+///         // Rust-side fields of a #[uclass] actor.
+///         MyProjectile::from_obj(actor)?.set_homing_target(target);
+///         Ok(())
+///     })
+///     .spawn()?;
+/// ```
+pub struct SpawnBuilder<T: UeClass> {
+    world: UObjectHandle,
+    class: UClassHandle,
+    transform: Vec<u8>,
+    owner: UObjectHandle,
+    instigator: UObjectHandle,
+    collision_method: u8,
+    properties: Vec<PendingProperty>,
+    hooks: Vec<SpawnHook<T>>,
+}
+
+impl<T: UeClass> SpawnBuilder<T> {
+    /// Spawn a `T` in `world` at `transform_buf` (raw FTransform bytes).
+    pub fn new(world: UObjectHandle, transform_buf: &[u8]) -> Self {
+        SpawnBuilder {
+            world,
+            class: T::static_class(),
+            transform: transform_buf.to_vec(),
+            owner: UObjectHandle::null(),
+            instigator: UObjectHandle::null(),
+            collision_method: 0,
+            properties: Vec::new(),
+            hooks: Vec::new(),
+        }
+    }
+
+    /// Spawn a subclass of `T` chosen at runtime (e.g. a Blueprint class).
+    pub fn class(mut self, class: ClassRef<T>) -> Self {
+        self.class = class.class();
+        self
+    }
+
+    pub fn owner<O: UeClass>(mut self, owner: UObjectRef<O>) -> Self {
+        self.owner = owner.raw();
+        self
+    }
+
+    pub fn instigator<I: UeClass>(mut self, instigator: UObjectRef<I>) -> Self {
+        self.instigator = instigator.raw();
+        self
+    }
+
+    /// `ESpawnActorCollisionHandlingMethod` (0..4); 0 uses the class default.
+    pub fn collision_method(mut self, method: u8) -> Self {
+        self.collision_method = method;
+        self
+    }
+
+    /// Set the reflected property `name` before the actor finishes spawning.
+    pub fn set<F: FieldValue + 'static>(mut self, name: &str, value: F) -> Self {
+        self.properties.push(PendingProperty {
+            name: name.to_string(),
+            check: check_field_type::<F>,
+            write: Box::new(move |actor, prop| unsafe { value.write(actor, prop) }),
+        });
+        self
+    }
+
+    /// Run `f` on the allocated actor before it finishes spawning, after the
+    /// [`set`](Self::set) values are applied. Use it for Rust fields of
+    /// `#[uclass]` actors, or anything else that must happen before BeginPlay.
+    pub fn configure(mut self, f: impl FnOnce(UObjectRef<T>) -> UikaResult<()> + 'static) -> Self {
+        self.hooks.push(Box::new(f));
+        self
+    }
+
+    /// Allocate the actor, apply the values and finish spawning it.
+    ///
+    /// If applying a value fails once the actor exists, the actor is
+    /// destroyed without finishing (BeginPlay never runs) and the first error
+    /// is returned.
+    pub fn spawn(self) -> UikaResult<UObjectRef<T>> {
+        let mut resolved = Vec::with_capacity(self.properties.len());
+        for pending in self.properties {
+            let prop = unsafe {
+                ffi_dispatch::reflection_find_property(self.class, pending.name.as_ptr(), pending.name.len() as u32)
+            };
+            if prop.is_null() {
+                return Err(UikaError::PropertyNotFound(format!(
+                    "{}::{}",
+                    std::any::type_name::<T>(),
+                    pending.name
+                )));
+            }
+            (pending.check)(prop)?;
+            resolved.push((prop, pending.write));
+        }
+
+        let actor = spawn_actor_deferred_raw(
+            self.world,
+            self.class,
+            &self.transform,
+            self.owner,
+            self.instigator,
+            self.collision_method,
+        )?;
+        let mut result = Ok(());
+        for (prop, write) in resolved {
+            result = result.and_then(|()| write(actor, prop));
+        }
+        for hook in self.hooks {
+            result = result.and_then(|()| hook(unsafe { UObjectRef::from_raw(actor) }));
+        }
+        if let Err(e) = result {
+            destroy_actor_raw::<T>(actor);
+            return Err(e);
+        }
+        finish_spawning_raw(actor, &self.transform)?;
+        Ok(unsafe { UObjectRef::from_raw(actor) })
+    }
+}
+
+/// Destroy an actor through `AActor::K2_DestroyActor`; the world API has no
+/// entry for it. Best effort: the actor may already be gone.
+fn destroy_actor_raw<T: UeClass>(actor: UObjectHandle) {
+    let actor = unsafe { UObjectRef::<T>::from_raw(actor) };
+    if let Ok(call) = DynamicCall::new(&actor, "K2_DestroyActor") {
+        let _ = call.call();
+    }
+}
+
 /// Get the UWorld from an actor handle.
 pub fn get_world_raw(actor: UObjectHandle) -> UikaResult<UObjectHandle> {
     let result = unsafe { ffi_dispatch::world_get_world(actor) };
@@ -175,3 +340,284 @@ pub fn get_all_actors_of_class_raw(
 
     Ok(handles)
 }
+
+// ---------------------------------------------------------------------------
+// Traces, sweeps and overlaps
+// ---------------------------------------------------------------------------
+
+/// An `ECollisionChannel` value.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CollisionChannel(pub u8);
+
+impl CollisionChannel {
+    pub const WORLD_STATIC: Self = Self(0);
+    pub const WORLD_DYNAMIC: Self = Self(1);
+    pub const PAWN: Self = Self(2);
+    pub const VISIBILITY: Self = Self(3);
+    pub const CAMERA: Self = Self(4);
+    pub const PHYSICS_BODY: Self = Self(5);
+    pub const VEHICLE: Self = Self(6);
+    pub const DESTRUCTIBLE: Self = Self(7);
+
+    /// `ECC_GameTraceChannel{n}` (1..=18), i.e. a project-defined channel.
+    pub const fn game(n: u8) -> Self {
+        Self(13 + n)
+    }
+}
+
+impl Default for CollisionChannel {
+    fn default() -> Self {
+        Self::VISIBILITY
+    }
+}
+
+/// Shape for sweeps and overlap tests (FCollisionShape).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum CollisionShape {
+    Sphere { radius: f64 },
+    Box { half_extent: DVec3 },
+    Capsule { radius: f64, half_height: f64 },
+}
+
+impl CollisionShape {
+    fn to_ffi(self) -> UikaCollisionShape {
+        match self {
+            CollisionShape::Sphere { radius } => {
+                UikaCollisionShape { kind: UIKA_SHAPE_SPHERE, extent: [radius, 0.0, 0.0] }
+            }
+            CollisionShape::Box { half_extent } => {
+                UikaCollisionShape { kind: UIKA_SHAPE_BOX, extent: half_extent.to_array() }
+            }
+            CollisionShape::Capsule { radius, half_height } => {
+                UikaCollisionShape { kind: UIKA_SHAPE_CAPSULE, extent: [radius, 0.0, half_height] }
+            }
+        }
+    }
+}
+
+/// Query options shared by traces, sweeps and overlaps.
+#[derive(Clone, Debug, Default)]
+pub struct TraceParams {
+    pub channel: CollisionChannel,
+    /// Trace against complex (per-triangle) collision.
+    pub trace_complex: bool,
+    /// Actors to ignore.
+    pub ignore: Vec<UObjectHandle>,
+}
+
+impl TraceParams {
+    pub fn new(channel: CollisionChannel) -> Self {
+        TraceParams { channel, ..Default::default() }
+    }
+
+    pub fn complex(mut self) -> Self {
+        self.trace_complex = true;
+        self
+    }
+
+    pub fn ignoring<C: UeClass>(mut self, actor: UObjectRef<C>) -> Self {
+        self.ignore.push(actor.raw());
+        self
+    }
+}
+
+/// Result of a trace or sweep (FHitResult).
+#[derive(Clone, Copy, Debug)]
+pub struct HitResult {
+    /// Hit actor handle (null if none). See [`actor`](Self::actor).
+    pub actor: UObjectHandle,
+    /// Hit primitive component handle (null if none). See [`component`](Self::component).
+    pub component: UObjectHandle,
+    /// Location of the trace shape at the time of the hit.
+    pub location: DVec3,
+    /// Point of contact on the hit surface.
+    pub impact_point: DVec3,
+    pub normal: DVec3,
+    pub impact_normal: DVec3,
+    pub distance: f64,
+    /// Fraction along the trace (0..1).
+    pub time: f32,
+    pub face_index: i32,
+    /// Hit bone, or `FName::NONE`.
+    pub bone_name: FName,
+    pub blocking_hit: bool,
+    pub start_penetrating: bool,
+}
+
+impl HitResult {
+    /// The hit actor as `T`, if there is one and it is a `T`.
+    pub fn actor<T: UeClass>(&self) -> Option<UObjectRef<T>> {
+        if self.actor.is_null() {
+            return None;
+        }
+        unsafe { UObjectRef::<T>::from_raw(self.actor) }.cast().ok()
+    }
+
+    /// The hit component as `T`, if there is one and it is a `T`.
+    pub fn component<T: UeClass>(&self) -> Option<UObjectRef<T>> {
+        if self.component.is_null() {
+            return None;
+        }
+        unsafe { UObjectRef::<T>::from_raw(self.component) }.cast().ok()
+    }
+}
+
+impl From<UikaHitResult> for HitResult {
+    fn from(h: UikaHitResult) -> Self {
+        HitResult {
+            actor: h.actor,
+            component: h.component,
+            location: DVec3::from_array(h.location),
+            impact_point: DVec3::from_array(h.impact_point),
+            normal: DVec3::from_array(h.normal),
+            impact_normal: DVec3::from_array(h.impact_normal),
+            distance: h.distance,
+            time: h.time,
+            face_index: h.face_index,
+            bone_name: FName(h.bone_name),
+            blocking_hit: h.blocking_hit,
+            start_penetrating: h.start_penetrating,
+        }
+    }
+}
+
+impl HitResult {
+    /// Decode an FHitResult in UE memory, such as an `FHitResult` property.
+    /// Generated functions returning one already decode it this way.
+    /// `TypeMismatch` if `T` is not FHitResult.
+    ///
+    /// ```ignore
+    /// let hit: OwnedStruct<FHitResult> = ...; // an FHitResult property
+    /// let hit = HitResult::from_struct(&hit.as_ref())?;
+    /// let actor = hit.actor::<AActor>();
+    /// ```
+    pub fn from_struct<T: UeStruct>(hit: &UStructRef<T>) -> UikaResult<HitResult> {
+        let mut out = UikaHitResult::default();
+        check_ffi(unsafe {
+            ffi_dispatch::hit_result_decode(T::static_struct(), hit.as_ptr().0 as *const u8, &mut out)
+        })?;
+        Ok(out.into())
+    }
+}
+
+/// First blocking hit along the line from `start` to `end`, on the world of
+/// `world_context`.
+pub fn line_trace_single<C: UeClass>(
+    world_context: UObjectRef<C>,
+    start: DVec3,
+    end: DVec3,
+    params: &TraceParams,
+) -> UikaResult<Option<HitResult>> {
+    let ctx = world_context.checked()?.raw();
+    let (start, end) = (start.to_array(), end.to_array());
+    let mut hit = UikaHitResult::default();
+    let found = unsafe {
+        ffi_dispatch::physics_line_trace_single(
+            ctx,
+            start.as_ptr(),
+            end.as_ptr(),
+            params.channel.0,
+            params.trace_complex,
+            params.ignore.as_ptr(),
+            params.ignore.len() as u32,
+            &mut hit,
+        )
+    };
+    Ok(found.then(|| hit.into()))
+}
+
+/// All hits along the line, in order, up to and including the first blocking hit.
+pub fn line_trace_multi<C: UeClass>(
+    world_context: UObjectRef<C>,
+    start: DVec3,
+    end: DVec3,
+    params: &TraceParams,
+) -> UikaResult<Vec<HitResult>> {
+    let ctx = world_context.checked()?.raw();
+    let (start, end) = (start.to_array(), end.to_array());
+    let mut hits = vec![UikaHitResult::default(); 16];
+    let mut count: u32 = 0;
+    let trace = |hits: &mut Vec<UikaHitResult>, count: &mut u32| unsafe {
+        ffi_dispatch::physics_line_trace_multi(
+            ctx,
+            start.as_ptr(),
+            end.as_ptr(),
+            params.channel.0,
+            params.trace_complex,
+            params.ignore.as_ptr(),
+            params.ignore.len() as u32,
+            hits.as_mut_ptr(),
+            hits.len() as u32,
+            count,
+        )
+    };
+    let mut code = trace(&mut hits, &mut count);
+    if code == UikaErrorCode::BufferTooSmall {
+        hits.resize(count as usize, UikaHitResult::default());
+        code = trace(&mut hits, &mut count);
+    }
+    check_ffi(code)?;
+    hits.truncate(count as usize);
+    Ok(hits.into_iter().map(HitResult::from).collect())
+}
+
+/// First blocking hit of `shape` swept from `start` to `end`.
+pub fn sweep_single<C: UeClass>(
+    world_context: UObjectRef<C>,
+    start: DVec3,
+    end: DVec3,
+    shape: CollisionShape,
+    params: &TraceParams,
+) -> UikaResult<Option<HitResult>> {
+    let ctx = world_context.checked()?.raw();
+    let (start, end) = (start.to_array(), end.to_array());
+    let shape = shape.to_ffi();
+    let mut hit = UikaHitResult::default();
+    let found = unsafe {
+        ffi_dispatch::physics_sweep_single(
+            ctx,
+            start.as_ptr(),
+            end.as_ptr(),
+            &shape,
+            params.channel.0,
+            params.trace_complex,
+            params.ignore.as_ptr(),
+            params.ignore.len() as u32,
+            &mut hit,
+        )
+    };
+    Ok(found.then(|| hit.into()))
+}
+
+/// [`sweep_single`] with a sphere of `radius`.
+pub fn sphere_sweep<C: UeClass>(
+    world_context: UObjectRef<C>,
+    start: DVec3,
+    end: DVec3,
+    radius: f64,
+    params: &TraceParams,
+) -> UikaResult<Option<HitResult>> {
+    sweep_single(world_context, start, end, CollisionShape::Sphere { radius }, params)
+}
+
+/// Whether `shape` placed at `center` overlaps any blocking geometry.
+pub fn overlap_test<C: UeClass>(
+    world_context: UObjectRef<C>,
+    center: DVec3,
+    shape: CollisionShape,
+    params: &TraceParams,
+) -> UikaResult<bool> {
+    let ctx = world_context.checked()?.raw();
+    let center = center.to_array();
+    let shape = shape.to_ffi();
+    Ok(unsafe {
+        ffi_dispatch::physics_overlap_blocking_test(
+            ctx,
+            center.as_ptr(),
+            &shape,
+            params.channel.0,
+            params.ignore.as_ptr(),
+            params.ignore.len() as u32,
+        )
+    })
+}
@@ -0,0 +1,186 @@
+// Flag decoding helpers for runtime introspection.
+//
+// Raw-integer entry points over the typed sets in `typed`, for code that gets
+// flags straight from the reflection API or UHT JSON.
+
+use crate::*;
+
+// ---------------------------------------------------------------------------
+// Property flags
+// ---------------------------------------------------------------------------
+
+/// UE names of the set property flags, e.g. `["Edit", "BlueprintVisible"]`.
+pub fn describe_property(flags: u64) -> Vec<&'static str> {
+    PropertyFlags::from_bits_retain(flags).iter_names().collect()
+}
+
+/// Readable (and possibly writable) from Blueprint.
+pub const fn is_blueprint_visible(flags: u64) -> bool {
+    flags & CPF_BLUEPRINT_VISIBLE != 0
+}
+
+/// Visible to Blueprint but not writable from it.
+pub const fn is_blueprint_read_only(flags: u64) -> bool {
+    flags & CPF_BLUEPRINT_VISIBLE != 0 && flags & CPF_BLUEPRINT_READ_ONLY != 0
+}
+
+/// Editable in the Details panel (shown and not greyed out).
+pub const fn is_editable(flags: u64) -> bool {
+    flags & CPF_EDIT != 0 && flags & CPF_EDIT_CONST == 0
+}
+
+/// Replicated over the network.
+pub const fn is_net_replicated(flags: u64) -> bool {
+    flags & CPF_NET != 0
+}
+
+/// Replicated with a RepNotify callback.
+pub const fn has_rep_notify(flags: u64) -> bool {
+    flags & CPF_REP_NOTIFY != 0
+}
+
+/// Not saved or loaded.
+pub const fn is_transient(flags: u64) -> bool {
+    flags & CPF_TRANSIENT != 0
+}
+
+/// Serialized into save games.
+pub const fn is_save_game(flags: u64) -> bool {
+    flags & CPF_SAVE_GAME != 0
+}
+
+/// A function parameter or return value rather than a member.
+pub const fn is_param(flags: u64) -> bool {
+    flags & CPF_PARM != 0
+}
+
+/// The function's return value.
+pub const fn is_return_param(flags: u64) -> bool {
+    flags & CPF_RETURN_PARM != 0
+}
+
+/// A parameter the callee writes back (out or non-const reference).
+///
+/// `const&` parameters also carry `CPF_OutParm` but are inputs, and the
+/// return value is reported by [`is_return_param`] instead.
+pub const fn is_out_param(flags: u64) -> bool {
+    flags & CPF_OUT_PARM != 0 && flags & (CPF_CONST_PARM | CPF_RETURN_PARM) == 0
+}
+
+/// Passed by reference (`T&` / `const T&`).
+pub const fn is_reference_param(flags: u64) -> bool {
+    flags & CPF_REFERENCE_PARM != 0
+}
+
+// ---------------------------------------------------------------------------
+// Function flags
+// ---------------------------------------------------------------------------
+
+/// UE names of the set function flags, e.g. `["Native", "BlueprintCallable"]`.
+pub fn describe_function(flags: u32) -> Vec<&'static str> {
+    FunctionFlags::from_bits_retain(flags).iter_names().collect()
+}
+
+/// Callable from Blueprint (including pure functions).
+pub const fn is_blueprint_callable(flags: u32) -> bool {
+    flags & (FUNC_BLUEPRINT_CALLABLE | FUNC_BLUEPRINT_PURE) != 0
+}
+
+/// Callable from Blueprint without an exec pin.
+pub const fn is_blueprint_pure(flags: u32) -> bool {
+    flags & FUNC_BLUEPRINT_PURE != 0
+}
+
+/// Can be implemented or overridden in Blueprint.
+pub const fn is_blueprint_event(flags: u32) -> bool {
+    flags & FUNC_BLUEPRINT_EVENT != 0
+}
+
+/// Static function (no `this`).
+pub const fn is_static_function(flags: u32) -> bool {
+    flags & FUNC_STATIC != 0
+}
+
+/// Has a native (C++ or Rust) implementation.
+pub const fn is_native_function(flags: u32) -> bool {
+    flags & FUNC_NATIVE != 0
+}
+
+/// Network-replicated function (RPC).
+pub const fn is_rpc(flags: u32) -> bool {
+    flags & FUNC_NET != 0
+}
+
+/// Client → server RPC.
+pub const fn is_server_rpc(flags: u32) -> bool {
+    flags & FUNC_NET != 0 && flags & FUNC_NET_SERVER != 0
+}
+
+/// Server → owning client RPC.
+pub const fn is_client_rpc(flags: u32) -> bool {
+    flags & FUNC_NET != 0 && flags & FUNC_NET_CLIENT != 0
+}
+
+/// Server → all clients RPC.
+pub const fn is_multicast_rpc(flags: u32) -> bool {
+    flags & FUNC_NET != 0 && flags & FUNC_NET_MULTICAST != 0
+}
+
+/// RPC sent reliably.
+pub const fn is_reliable_rpc(flags: u32) -> bool {
+    flags & FUNC_NET != 0 && flags & FUNC_NET_RELIABLE != 0
+}
+
+// ---------------------------------------------------------------------------
+// Class flags
+// ---------------------------------------------------------------------------
+
+/// UE names of the set class flags, e.g. `["Abstract", "Native"]`.
+pub fn describe_class(flags: u32) -> Vec<&'static str> {
+    ClassFlags::from_bits_retain(flags).iter_names().collect()
+}
+
+/// Can't be instantiated directly.
+pub const fn is_abstract_class(flags: u32) -> bool {
+    flags & CLASS_ABSTRACT != 0
+}
+
+/// A UInterface.
+pub const fn is_interface_class(flags: u32) -> bool {
+    flags & CLASS_INTERFACE != 0
+}
+
+/// Declared in native code (C++ or reified from Rust).
+pub const fn is_native_class(flags: u32) -> bool {
+    flags & CLASS_NATIVE != 0
+}
+
+/// Marked deprecated.
+pub const fn is_deprecated_class(flags: u32) -> bool {
+    flags & CLASS_DEPRECATED != 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_describe_property() {
+        assert_eq!(
+            describe_property(CPF_EDIT | CPF_BLUEPRINT_VISIBLE | CPF_NET),
+            ["Edit", "BlueprintVisible", "Net"]
+        );
+        assert!(describe_property(0).is_empty());
+    }
+
+    #[test]
+    fn test_param_direction() {
+        let out = CPF_PARM | CPF_OUT_PARM;
+        let inout = out | CPF_REFERENCE_PARM;
+        let const_ref = inout | CPF_CONST_PARM;
+        let ret = out | CPF_RETURN_PARM;
+        assert!(is_out_param(out) && is_out_param(inout));
+        assert!(!is_out_param(const_ref) && !is_out_param(ret) && !is_out_param(CPF_PARM));
+        assert!(is_return_param(ret) && is_reference_param(const_ref));
+    }
+}
@@ -1,8 +1,18 @@
 // UE reflection flag constants.
 //
-// Mirrors EPropertyFlags, EFunctionFlags, and EClassFlags from UE 5.7 source:
+// Mirrors EPropertyFlags, EFunctionFlags, EClassFlags, EObjectFlags,
+// EPackageFlags and EStructFlags from UE 5.7 source:
 //   - Engine/Source/Runtime/CoreUObject/Public/UObject/ObjectMacros.h
 //   - Engine/Source/Runtime/CoreUObject/Public/UObject/Script.h
+//
+// The property, function and class families also have typed wrappers
+// (`PropertyFlags`, `FunctionFlags`, `ClassFlags`) in `typed`, and decoding
+// helpers (`describe_property`, `is_out_param`, ...) in `decode`.
+
+mod typed;
+mod decode;
+pub use typed::{ClassFlags, FunctionFlags, PropertyFlags};
+pub use decode::*;
 
 // ---------------------------------------------------------------------------
 // EPropertyFlags (CPF_*) — uint64
@@ -297,3 +307,232 @@ pub const CLASS_INHERIT: u32 = CLASS_TRANSIENT
     | CLASS_PROJECT_USER_CONFIG
     | CLASS_PER_PLATFORM_CONFIG
     | CLASS_NEEDS_DEFERRED_DEPENDENCY_LOADING;
+
+// ---------------------------------------------------------------------------
+// EObjectFlags (RF_*) — uint32
+// ---------------------------------------------------------------------------
+
+/// No flags.
+pub const RF_NO_FLAGS: u32 = 0x0000_0000;
+/// Object is visible outside its package.
+pub const RF_PUBLIC: u32 = 0x0000_0001;
+/// Keep object around for editing even if unreferenced.
+pub const RF_STANDALONE: u32 = 0x0000_0002;
+/// Object (UField) will be marked as native on construction.
+pub const RF_MARK_AS_NATIVE: u32 = 0x0000_0004;
+/// Object is transactional.
+pub const RF_TRANSACTIONAL: u32 = 0x0000_0008;
+/// This object is its class's default object.
+pub const RF_CLASS_DEFAULT_OBJECT: u32 = 0x0000_0010;
+/// This object is a template for another object; treat like a class default object.
+pub const RF_ARCHETYPE_OBJECT: u32 = 0x0000_0020;
+/// Don't save object.
+pub const RF_TRANSIENT: u32 = 0x0000_0040;
+/// Object will be marked as root set on construction and not be garbage collected.
+pub const RF_MARK_AS_ROOT_SET: u32 = 0x0000_0080;
+/// Temporary GC flag, only used by utility functions.
+pub const RF_TAG_GARBAGE_TEMP: u32 = 0x0000_0100;
+/// This object has not completed its initialization process.
+pub const RF_NEED_INITIALIZATION: u32 = 0x0000_0200;
+/// During load, indicates object needs loading.
+pub const RF_NEED_LOAD: u32 = 0x0000_0400;
+/// Keep this object during garbage collection because it's still being used by the cooker.
+pub const RF_KEEP_FOR_COOKER: u32 = 0x0000_0800;
+/// Object needs to be postloaded.
+pub const RF_NEED_POST_LOAD: u32 = 0x0000_1000;
+/// During load, indicates that the object still needs to instance subobjects and fixup serialized component references.
+pub const RF_NEED_POST_LOAD_SUBOBJECTS: u32 = 0x0000_2000;
+/// Object has been consigned to oblivion due to its owner package being reloaded, and a newer version exists.
+pub const RF_NEWER_VERSION_EXISTS: u32 = 0x0000_4000;
+/// BeginDestroy has been called on the object.
+pub const RF_BEGIN_DESTROYED: u32 = 0x0000_8000;
+/// FinishDestroy has been called on the object.
+pub const RF_FINISH_DESTROYED: u32 = 0x0001_0000;
+/// Flagged on UObjects that are used to create UClasses (e.g. Blueprints) while they are regenerating their UClass on load.
+pub const RF_BEING_REGENERATED: u32 = 0x0002_0000;
+/// Flagged on subobject templates that were created in a class constructor, and all instances created from those templates.
+pub const RF_DEFAULT_SUB_OBJECT: u32 = 0x0004_0000;
+/// Flagged on UObjects that were loaded.
+pub const RF_WAS_LOADED: u32 = 0x0008_0000;
+/// Do not export object to text form (e.g. copy/paste).
+pub const RF_TEXT_EXPORT_TRANSIENT: u32 = 0x0010_0000;
+/// Object has been completely serialized by linkerload at least once.
+pub const RF_LOAD_COMPLETED: u32 = 0x0020_0000;
+/// Flagged on subobject templates stored inside a class instead of the class default object.
+pub const RF_INHERITABLE_COMPONENT_TEMPLATE: u32 = 0x0040_0000;
+/// Object should not be included in any type of duplication (copy/paste, binary duplication, etc.).
+pub const RF_DUPLICATE_TRANSIENT: u32 = 0x0080_0000;
+/// References to this object from persistent function frame are handled as strong ones.
+pub const RF_STRONG_REF_ON_FRAME: u32 = 0x0100_0000;
+/// Object should not be included for duplication unless it's being duplicated for a PIE session.
+pub const RF_NON_PIE_DUPLICATE_TRANSIENT: u32 = 0x0200_0000;
+// 0x0400_0000 — reserved (formerly RF_Dynamic)
+/// This object was constructed during load and will be loaded shortly.
+pub const RF_WILL_BE_LOADED: u32 = 0x0800_0000;
+/// This object has an external package assigned and should look it up when getting the outermost package.
+pub const RF_HAS_EXTERNAL_PACKAGE: u32 = 0x1000_0000;
+/// This object was instanced from a placeholder type.
+pub const RF_HAS_PLACEHOLDER_TYPE: u32 = 0x2000_0000;
+/// Garbage from logical point of view; mirrors the internal EInternalObjectFlags::Garbage flag.
+pub const RF_MIRRORED_GARBAGE: u32 = 0x4000_0000;
+/// Allocated from a ref-counted page shared with other UObjects.
+pub const RF_ALLOCATED_IN_SHARED_PAGE: u32 = 0x8000_0000;
+
+/// Flags that are loaded from packages.
+pub const RF_LOAD: u32 = RF_PUBLIC
+    | RF_STANDALONE
+    | RF_TRANSACTIONAL
+    | RF_CLASS_DEFAULT_OBJECT
+    | RF_ARCHETYPE_OBJECT
+    | RF_DEFAULT_SUB_OBJECT
+    | RF_TEXT_EXPORT_TRANSIENT
+    | RF_INHERITABLE_COMPONENT_TEMPLATE
+    | RF_DUPLICATE_TRANSIENT
+    | RF_NON_PIE_DUPLICATE_TRANSIENT;
+
+/// Sub-objects will inherit these flags from their super-objects.
+pub const RF_PROPAGATE_TO_SUB_OBJECTS: u32 =
+    RF_PUBLIC | RF_ARCHETYPE_OBJECT | RF_TRANSACTIONAL | RF_TRANSIENT;
+
+// ---------------------------------------------------------------------------
+// EPackageFlags (PKG_*) — uint32
+// ---------------------------------------------------------------------------
+
+/// No flags.
+pub const PKG_NONE: u32 = 0x0000_0000;
+/// Newly created package, not saved yet. In editor only.
+pub const PKG_NEWLY_CREATED: u32 = 0x0000_0001;
+/// Purely optional for clients.
+pub const PKG_CLIENT_OPTIONAL: u32 = 0x0000_0002;
+/// Only needed on the server side.
+pub const PKG_SERVER_SIDE_ONLY: u32 = 0x0000_0004;
+// 0x0000_0008 — reserved
+/// This package is from "compiled in" classes.
+pub const PKG_COMPILED_IN: u32 = 0x0000_0010;
+/// This package was loaded just for the purposes of diffing.
+pub const PKG_FOR_DIFFING: u32 = 0x0000_0020;
+/// This is editor-only package (for example: editor module script package).
+pub const PKG_EDITOR_ONLY: u32 = 0x0000_0040;
+/// Developer module.
+pub const PKG_DEVELOPER: u32 = 0x0000_0080;
+/// Loaded only in uncooked builds (i.e. runtime in editor).
+pub const PKG_UNCOOKED_ONLY: u32 = 0x0000_0100;
+/// Package is cooked.
+pub const PKG_COOKED: u32 = 0x0000_0200;
+/// Package doesn't contain any asset object (although asset tags can be present).
+pub const PKG_CONTAINS_NO_ASSET: u32 = 0x0000_0400;
+/// Objects in this package cannot be referenced in a different plugin or mount point.
+pub const PKG_NOT_EXTERNALLY_REFERENCEABLE: u32 = 0x0000_0800;
+// 0x0000_1000 — reserved
+/// Uses unversioned property serialization instead of versioned tagged property serialization.
+pub const PKG_UNVERSIONED_PROPERTIES: u32 = 0x0000_2000;
+/// Contains map data (UObjects only referenced by a single ULevel) but is stored in a different package.
+pub const PKG_CONTAINS_MAP_DATA: u32 = 0x0000_4000;
+/// Temporarily set on a package while it is being saved.
+pub const PKG_IS_SAVING: u32 = 0x0000_8000;
+/// Package is currently being compiled.
+pub const PKG_COMPILING: u32 = 0x0001_0000;
+/// Set if the package contains a ULevel/UWorld object.
+pub const PKG_CONTAINS_MAP: u32 = 0x0002_0000;
+/// Set if the package contains any data to be gathered by localization.
+pub const PKG_REQUIRES_LOCALIZATION_GATHER: u32 = 0x0004_0000;
+/// This package must be loaded uncooked, even in cooked builds.
+pub const PKG_LOAD_UNCOOKED: u32 = 0x0008_0000;
+/// Set if the package was created for the purpose of PIE.
+pub const PKG_PLAY_IN_EDITOR: u32 = 0x0010_0000;
+/// Package is allowed to contain UClass objects.
+pub const PKG_CONTAINS_SCRIPT: u32 = 0x0020_0000;
+/// Editor should not export asset in this package.
+pub const PKG_DISALLOW_EXPORT: u32 = 0x0040_0000;
+// 0x0080_0000 .. 0x0400_0000 — reserved
+/// This package was generated by the cooker and does not exist in the WorkspaceDomain.
+pub const PKG_COOK_GENERATED: u32 = 0x0800_0000;
+/// This package should resolve dynamic imports from its export at runtime.
+pub const PKG_DYNAMIC_IMPORTS: u32 = 0x1000_0000;
+/// This package contains elements that are runtime generated, and may not follow standard loading order rules.
+pub const PKG_RUNTIME_GENERATED: u32 = 0x2000_0000;
+/// This package is reloading in the cooker, try to avoid getting data we will never need.
+pub const PKG_RELOADING_FOR_COOKER: u32 = 0x4000_0000;
+/// Package has editor-only data filtered out.
+pub const PKG_FILTER_EDITOR_ONLY: u32 = 0x8000_0000;
+
+/// Flags that should not be saved to disk.
+pub const PKG_TRANSIENT_FLAGS: u32 =
+    PKG_NEWLY_CREATED | PKG_IS_SAVING | PKG_RELOADING_FOR_COOKER;
+
+// ---------------------------------------------------------------------------
+// EStructFlags (STRUCT_*) — uint32
+// ---------------------------------------------------------------------------
+
+/// No flags.
+pub const STRUCT_NO_FLAGS: u32 = 0x0000_0000;
+/// Struct is native.
+pub const STRUCT_NATIVE: u32 = 0x0000_0001;
+/// If set, this struct will be compared using native code.
+pub const STRUCT_IDENTICAL_NATIVE: u32 = 0x0000_0002;
+/// Struct has instanced (component) references.
+pub const STRUCT_HAS_INSTANCED_REFERENCE: u32 = 0x0000_0004;
+/// Struct is not exported by UHT.
+pub const STRUCT_NO_EXPORT: u32 = 0x0000_0008;
+/// Indicates that this struct should always be serialized as a single unit.
+pub const STRUCT_ATOMIC: u32 = 0x0000_0010;
+/// Indicates that this struct uses binary serialization; it is unsafe to add/remove members.
+pub const STRUCT_IMMUTABLE: u32 = 0x0000_0020;
+/// If set, native code needs to be run to find referenced objects.
+pub const STRUCT_ADD_STRUCT_REFERENCED_OBJECTS: u32 = 0x0000_0040;
+// 0x0000_0080 — reserved
+// 0x0000_0100 — reserved
+/// Indicates that this struct should be exportable/importable at the DLL layer.
+pub const STRUCT_REQUIRED_API: u32 = 0x0000_0200;
+/// If set, this struct will be serialized using the CPP net serializer.
+pub const STRUCT_NET_SERIALIZE_NATIVE: u32 = 0x0000_0400;
+/// If set, this struct will be serialized using the CPP serializer.
+pub const STRUCT_SERIALIZE_NATIVE: u32 = 0x0000_0800;
+/// If set, this struct will be copied using the CPP operator=.
+pub const STRUCT_COPY_NATIVE: u32 = 0x0000_1000;
+/// If set, this struct will be copied using memcpy.
+pub const STRUCT_IS_PLAIN_OLD_DATA: u32 = 0x0000_2000;
+/// If set, this struct has no destructor and none will be called.
+pub const STRUCT_NO_DESTRUCTOR: u32 = 0x0000_4000;
+/// If set, this struct will not be constructed because it is assumed that memory is zero before construction.
+pub const STRUCT_ZERO_CONSTRUCTOR: u32 = 0x0000_8000;
+/// If set, native code will be used to export text.
+pub const STRUCT_EXPORT_TEXT_ITEM_NATIVE: u32 = 0x0001_0000;
+/// If set, native code will be used to import text.
+pub const STRUCT_IMPORT_TEXT_ITEM_NATIVE: u32 = 0x0002_0000;
+/// If set, this struct will have PostSerialize called on it after CPP serializer or tagged property serialization.
+pub const STRUCT_POST_SERIALIZE_NATIVE: u32 = 0x0004_0000;
+/// If set, this struct will have SerializeFromMismatchedTag called on it if a mismatched tag is encountered.
+pub const STRUCT_SERIALIZE_FROM_MISMATCHED_TAG: u32 = 0x0008_0000;
+/// If set, this struct will be serialized using the CPP net delta serializer.
+pub const STRUCT_NET_DELTA_SERIALIZE_NATIVE: u32 = 0x0010_0000;
+/// If set, this struct will have PostScriptConstruct called on it after a temporary object is constructed in a running blueprint.
+pub const STRUCT_POST_SCRIPT_CONSTRUCT: u32 = 0x0020_0000;
+/// If set, this struct can share net serialization state across connections.
+pub const STRUCT_NET_SHARED_SERIALIZATION: u32 = 0x0040_0000;
+/// If set, this struct has been cleaned and sanitized (trashed) and should not be used.
+pub const STRUCT_TRASHED: u32 = 0x0080_0000;
+/// If set, this structure has been replaced via reinstancing.
+pub const STRUCT_NEWER_VERSION_EXISTS: u32 = 0x0100_0000;
+/// If set, this struct will have CanEditChange called on it in the editor to determine if a child property can be edited.
+pub const STRUCT_CAN_EDIT_CHANGE: u32 = 0x0200_0000;
+
+/// Struct flags that are automatically inherited.
+pub const STRUCT_INHERIT: u32 = STRUCT_HAS_INSTANCED_REFERENCE | STRUCT_ATOMIC;
+
+/// Flags that are always computed, never loaded or done with code generation.
+pub const STRUCT_COMPUTED_FLAGS: u32 = STRUCT_NET_DELTA_SERIALIZE_NATIVE
+    | STRUCT_NET_SERIALIZE_NATIVE
+    | STRUCT_SERIALIZE_NATIVE
+    | STRUCT_POST_SERIALIZE_NATIVE
+    | STRUCT_COPY_NATIVE
+    | STRUCT_IS_PLAIN_OLD_DATA
+    | STRUCT_NO_DESTRUCTOR
+    | STRUCT_ZERO_CONSTRUCTOR
+    | STRUCT_IDENTICAL_NATIVE
+    | STRUCT_ADD_STRUCT_REFERENCED_OBJECTS
+    | STRUCT_EXPORT_TEXT_ITEM_NATIVE
+    | STRUCT_IMPORT_TEXT_ITEM_NATIVE
+    | STRUCT_SERIALIZE_FROM_MISMATCHED_TAG
+    | STRUCT_POST_SCRIPT_CONSTRUCT
+    | STRUCT_NET_SHARED_SERIALIZATION;
@@ -0,0 +1,366 @@
+// Typed wrappers over the raw flag constants.
+//
+// Each type is a transparent newtype over the UE integer with one associated
+// const per flag, set operators, and `Display`/`Debug` impls that print the
+// UE spelling of the set bits ("BlueprintCallable | Net | Const"). Bits with
+// no known name are kept and printed as hex.
+
+use crate::*;
+
+macro_rules! flags_type {
+    (
+        $(#[$meta:meta])*
+        pub struct $name:ident: $bits:ty {
+            $($flag:ident = $raw:ident => $ue:literal,)*
+        }
+    ) => {
+        $(#[$meta])*
+        #[repr(transparent)]
+        #[derive(Clone, Copy, PartialEq, Eq, Hash, Default)]
+        pub struct $name($bits);
+
+        impl $name {
+            $(
+                #[doc = concat!("[`", stringify!($raw), "`]")]
+                pub const $flag: Self = Self($raw);
+            )*
+
+            /// Every named flag with its UE spelling, in bit order.
+            pub const NAMED: &'static [(&'static str, Self)] = &[$(($ue, Self::$flag)),*];
+
+            /// The empty set.
+            pub const fn empty() -> Self {
+                Self(0)
+            }
+
+            /// All named flags.
+            pub const fn all() -> Self {
+                Self(0 $(| $raw)*)
+            }
+
+            /// Wrap raw bits as-is, keeping bits that have no named flag.
+            pub const fn from_bits_retain(bits: $bits) -> Self {
+                Self(bits)
+            }
+
+            /// Wrap raw bits, dropping bits that have no named flag.
+            pub const fn from_bits_truncate(bits: $bits) -> Self {
+                Self(bits & Self::all().0)
+            }
+
+            /// Wrap raw bits, or `None` if any bit has no named flag.
+            pub const fn from_bits(bits: $bits) -> Option<Self> {
+                if bits & !Self::all().0 == 0 { Some(Self(bits)) } else { None }
+            }
+
+            /// The raw bits, as passed across the FFI boundary.
+            pub const fn bits(self) -> $bits {
+                self.0
+            }
+
+            pub const fn is_empty(self) -> bool {
+                self.0 == 0
+            }
+
+            /// Whether every flag in `other` is set.
+            pub const fn contains(self, other: Self) -> bool {
+                self.0 & other.0 == other.0
+            }
+
+            /// Whether any flag in `other` is set.
+            pub const fn intersects(self, other: Self) -> bool {
+                self.0 & other.0 != 0
+            }
+
+            pub const fn union(self, other: Self) -> Self {
+                Self(self.0 | other.0)
+            }
+
+            pub fn insert(&mut self, other: Self) {
+                self.0 |= other.0;
+            }
+
+            pub fn remove(&mut self, other: Self) {
+                self.0 &= !other.0;
+            }
+
+            pub fn set(&mut self, other: Self, value: bool) {
+                if value { self.insert(other) } else { self.remove(other) }
+            }
+
+            /// UE names of the set flags, in bit order.
+            pub fn iter_names(self) -> impl Iterator<Item = &'static str> {
+                Self::NAMED
+                    .iter()
+                    .filter(move |(_, f)| self.contains(*f))
+                    .map(|(name, _)| *name)
+            }
+
+            /// Set bits with no named flag.
+            pub const fn unknown_bits(self) -> $bits {
+                self.0 & !Self::all().0
+            }
+        }
+
+        impl From<$bits> for $name {
+            fn from(bits: $bits) -> Self {
+                Self(bits)
+            }
+        }
+
+        impl From<$name> for $bits {
+            fn from(flags: $name) -> Self {
+                flags.0
+            }
+        }
+
+        impl core::ops::BitOr for $name {
+            type Output = Self;
+            fn bitor(self, rhs: Self) -> Self {
+                Self(self.0 | rhs.0)
+            }
+        }
+
+        impl core::ops::BitOrAssign for $name {
+            fn bitor_assign(&mut self, rhs: Self) {
+                self.0 |= rhs.0;
+            }
+        }
+
+        impl core::ops::BitAnd for $name {
+            type Output = Self;
+            fn bitand(self, rhs: Self) -> Self {
+                Self(self.0 & rhs.0)
+            }
+        }
+
+        impl core::ops::BitAndAssign for $name {
+            fn bitand_assign(&mut self, rhs: Self) {
+                self.0 &= rhs.0;
+            }
+        }
+
+        impl core::ops::BitXor for $name {
+            type Output = Self;
+            fn bitxor(self, rhs: Self) -> Self {
+                Self(self.0 ^ rhs.0)
+            }
+        }
+
+        impl core::ops::Sub for $name {
+            type Output = Self;
+            fn sub(self, rhs: Self) -> Self {
+                Self(self.0 & !rhs.0)
+            }
+        }
+
+        impl core::ops::Not for $name {
+            type Output = Self;
+            fn not(self) -> Self {
+                Self(!self.0)
+            }
+        }
+
+        impl core::fmt::Display for $name {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                if self.0 == 0 {
+                    return f.write_str("None");
+                }
+                let mut first = true;
+                for name in self.iter_names() {
+                    if !first {
+                        f.write_str(" | ")?;
+                    }
+                    f.write_str(name)?;
+                    first = false;
+                }
+                let unknown = self.unknown_bits();
+                if unknown != 0 {
+                    if !first {
+                        f.write_str(" | ")?;
+                    }
+                    write!(f, "{unknown:#x}")?;
+                }
+                Ok(())
+            }
+        }
+
+        impl core::fmt::Debug for $name {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                write!(f, concat!(stringify!($name), "({})"), self)
+            }
+        }
+    };
+}
+
+flags_type! {
+    /// `EPropertyFlags` (`CPF_*`) as a typed flag set.
+    pub struct PropertyFlags: u64 {
+        EDIT = CPF_EDIT => "Edit",
+        CONST_PARM = CPF_CONST_PARM => "ConstParm",
+        BLUEPRINT_VISIBLE = CPF_BLUEPRINT_VISIBLE => "BlueprintVisible",
+        EXPORT_OBJECT = CPF_EXPORT_OBJECT => "ExportObject",
+        BLUEPRINT_READ_ONLY = CPF_BLUEPRINT_READ_ONLY => "BlueprintReadOnly",
+        NET = CPF_NET => "Net",
+        EDIT_FIXED_SIZE = CPF_EDIT_FIXED_SIZE => "EditFixedSize",
+        PARM = CPF_PARM => "Parm",
+        OUT_PARM = CPF_OUT_PARM => "OutParm",
+        ZERO_CONSTRUCTOR = CPF_ZERO_CONSTRUCTOR => "ZeroConstructor",
+        RETURN_PARM = CPF_RETURN_PARM => "ReturnParm",
+        DISABLE_EDIT_ON_TEMPLATE = CPF_DISABLE_EDIT_ON_TEMPLATE => "DisableEditOnTemplate",
+        NON_NULLABLE = CPF_NON_NULLABLE => "NonNullable",
+        TRANSIENT = CPF_TRANSIENT => "Transient",
+        CONFIG = CPF_CONFIG => "Config",
+        REQUIRED_PARM = CPF_REQUIRED_PARM => "RequiredParm",
+        DISABLE_EDIT_ON_INSTANCE = CPF_DISABLE_EDIT_ON_INSTANCE => "DisableEditOnInstance",
+        EDIT_CONST = CPF_EDIT_CONST => "EditConst",
+        GLOBAL_CONFIG = CPF_GLOBAL_CONFIG => "GlobalConfig",
+        INSTANCED_REFERENCE = CPF_INSTANCED_REFERENCE => "InstancedReference",
+        EXPERIMENTAL_EXTERNAL_OBJECTS = CPF_EXPERIMENTAL_EXTERNAL_OBJECTS => "ExperimentalExternalObjects",
+        DUPLICATE_TRANSIENT = CPF_DUPLICATE_TRANSIENT => "DuplicateTransient",
+        SAVE_GAME = CPF_SAVE_GAME => "SaveGame",
+        NO_CLEAR = CPF_NO_CLEAR => "NoClear",
+        VIRTUAL = CPF_VIRTUAL => "Virtual",
+        REFERENCE_PARM = CPF_REFERENCE_PARM => "ReferenceParm",
+        BLUEPRINT_ASSIGNABLE = CPF_BLUEPRINT_ASSIGNABLE => "BlueprintAssignable",
+        DEPRECATED = CPF_DEPRECATED => "Deprecated",
+        IS_PLAIN_OLD_DATA = CPF_IS_PLAIN_OLD_DATA => "IsPlainOldData",
+        REP_SKIP = CPF_REP_SKIP => "RepSkip",
+        REP_NOTIFY = CPF_REP_NOTIFY => "RepNotify",
+        INTERP = CPF_INTERP => "Interp",
+        NON_TRANSACTIONAL = CPF_NON_TRANSACTIONAL => "NonTransactional",
+        EDITOR_ONLY = CPF_EDITOR_ONLY => "EditorOnly",
+        NO_DESTRUCTOR = CPF_NO_DESTRUCTOR => "NoDestructor",
+        AUTO_WEAK = CPF_AUTO_WEAK => "AutoWeak",
+        CONTAINS_INSTANCED_REFERENCE = CPF_CONTAINS_INSTANCED_REFERENCE => "ContainsInstancedReference",
+        ASSET_REGISTRY_SEARCHABLE = CPF_ASSET_REGISTRY_SEARCHABLE => "AssetRegistrySearchable",
+        SIMPLE_DISPLAY = CPF_SIMPLE_DISPLAY => "SimpleDisplay",
+        ADVANCED_DISPLAY = CPF_ADVANCED_DISPLAY => "AdvancedDisplay",
+        PROTECTED = CPF_PROTECTED => "Protected",
+        BLUEPRINT_CALLABLE = CPF_BLUEPRINT_CALLABLE => "BlueprintCallable",
+        BLUEPRINT_AUTHORITY_ONLY = CPF_BLUEPRINT_AUTHORITY_ONLY => "BlueprintAuthorityOnly",
+        TEXT_EXPORT_TRANSIENT = CPF_TEXT_EXPORT_TRANSIENT => "TextExportTransient",
+        NON_PIE_DUPLICATE_TRANSIENT = CPF_NON_PIE_DUPLICATE_TRANSIENT => "NonPIEDuplicateTransient",
+        EXPOSE_ON_SPAWN = CPF_EXPOSE_ON_SPAWN => "ExposeOnSpawn",
+        PERSISTENT_INSTANCE = CPF_PERSISTENT_INSTANCE => "PersistentInstance",
+        UOBJECT_WRAPPER = CPF_UOBJECT_WRAPPER => "UObjectWrapper",
+        HAS_GET_VALUE_TYPE_HASH = CPF_HAS_GET_VALUE_TYPE_HASH => "HasGetValueTypeHash",
+        NATIVE_ACCESS_SPECIFIER_PUBLIC = CPF_NATIVE_ACCESS_SPECIFIER_PUBLIC => "NativeAccessSpecifierPublic",
+        NATIVE_ACCESS_SPECIFIER_PROTECTED = CPF_NATIVE_ACCESS_SPECIFIER_PROTECTED => "NativeAccessSpecifierProtected",
+        NATIVE_ACCESS_SPECIFIER_PRIVATE = CPF_NATIVE_ACCESS_SPECIFIER_PRIVATE => "NativeAccessSpecifierPrivate",
+        SKIP_SERIALIZATION = CPF_SKIP_SERIALIZATION => "SkipSerialization",
+        TOBJECT_PTR = CPF_TOBJECT_PTR => "TObjectPtr",
+        EXPERIMENTAL_OVERRIDABLE_LOGIC = CPF_EXPERIMENTAL_OVERRIDABLE_LOGIC => "ExperimentalOverridableLogic",
+        EXPERIMENTAL_ALWAYS_OVERRIDEN = CPF_EXPERIMENTAL_ALWAYS_OVERRIDEN => "ExperimentalAlwaysOverriden",
+        EXPERIMENTAL_NEVER_OVERRIDEN = CPF_EXPERIMENTAL_NEVER_OVERRIDEN => "ExperimentalNeverOverriden",
+        ALLOW_SELF_REFERENCE = CPF_ALLOW_SELF_REFERENCE => "AllowSelfReference",
+    }
+}
+
+flags_type! {
+    /// `EFunctionFlags` (`FUNC_*`) as a typed flag set.
+    pub struct FunctionFlags: u32 {
+        FINAL = FUNC_FINAL => "Final",
+        REQUIRED_API = FUNC_REQUIRED_API => "RequiredAPI",
+        BLUEPRINT_AUTHORITY_ONLY = FUNC_BLUEPRINT_AUTHORITY_ONLY => "BlueprintAuthorityOnly",
+        BLUEPRINT_COSMETIC = FUNC_BLUEPRINT_COSMETIC => "BlueprintCosmetic",
+        NET = FUNC_NET => "Net",
+        NET_RELIABLE = FUNC_NET_RELIABLE => "NetReliable",
+        NET_REQUEST = FUNC_NET_REQUEST => "NetRequest",
+        EXEC = FUNC_EXEC => "Exec",
+        NATIVE = FUNC_NATIVE => "Native",
+        EVENT = FUNC_EVENT => "Event",
+        NET_RESPONSE = FUNC_NET_RESPONSE => "NetResponse",
+        STATIC = FUNC_STATIC => "Static",
+        NET_MULTICAST = FUNC_NET_MULTICAST => "NetMulticast",
+        UBERGRAPH_FUNCTION = FUNC_UBERGRAPH_FUNCTION => "UbergraphFunction",
+        MULTICAST_DELEGATE = FUNC_MULTICAST_DELEGATE => "MulticastDelegate",
+        PUBLIC = FUNC_PUBLIC => "Public",
+        PRIVATE = FUNC_PRIVATE => "Private",
+        PROTECTED = FUNC_PROTECTED => "Protected",
+        DELEGATE = FUNC_DELEGATE => "Delegate",
+        NET_SERVER = FUNC_NET_SERVER => "NetServer",
+        HAS_OUT_PARMS = FUNC_HAS_OUT_PARMS => "HasOutParms",
+        HAS_DEFAULTS = FUNC_HAS_DEFAULTS => "HasDefaults",
+        NET_CLIENT = FUNC_NET_CLIENT => "NetClient",
+        DLL_IMPORT = FUNC_DLL_IMPORT => "DLLImport",
+        BLUEPRINT_CALLABLE = FUNC_BLUEPRINT_CALLABLE => "BlueprintCallable",
+        BLUEPRINT_EVENT = FUNC_BLUEPRINT_EVENT => "BlueprintEvent",
+        BLUEPRINT_PURE = FUNC_BLUEPRINT_PURE => "BlueprintPure",
+        EDITOR_ONLY = FUNC_EDITOR_ONLY => "EditorOnly",
+        CONST = FUNC_CONST => "Const",
+        NET_VALIDATE = FUNC_NET_VALIDATE => "NetValidate",
+    }
+}
+
+flags_type! {
+    /// `EClassFlags` (`CLASS_*`) as a typed flag set.
+    pub struct ClassFlags: u32 {
+        ABSTRACT = CLASS_ABSTRACT => "Abstract",
+        DEFAULT_CONFIG = CLASS_DEFAULT_CONFIG => "DefaultConfig",
+        CONFIG = CLASS_CONFIG => "Config",
+        TRANSIENT = CLASS_TRANSIENT => "Transient",
+        OPTIONAL = CLASS_OPTIONAL => "Optional",
+        MATCHED_SERIALIZERS = CLASS_MATCHED_SERIALIZERS => "MatchedSerializers",
+        PROJECT_USER_CONFIG = CLASS_PROJECT_USER_CONFIG => "ProjectUserConfig",
+        NATIVE = CLASS_NATIVE => "Native",
+        NOT_PLACEABLE = CLASS_NOT_PLACEABLE => "NotPlaceable",
+        PER_OBJECT_CONFIG = CLASS_PER_OBJECT_CONFIG => "PerObjectConfig",
+        REPLICATION_DATA_IS_SET_UP = CLASS_REPLICATION_DATA_IS_SET_UP => "ReplicationDataIsSetUp",
+        EDIT_INLINE_NEW = CLASS_EDIT_INLINE_NEW => "EditInlineNew",
+        COLLAPSE_CATEGORIES = CLASS_COLLAPSE_CATEGORIES => "CollapseCategories",
+        INTERFACE = CLASS_INTERFACE => "Interface",
+        PER_PLATFORM_CONFIG = CLASS_PER_PLATFORM_CONFIG => "PerPlatformConfig",
+        CONST = CLASS_CONST => "Const",
+        NEEDS_DEFERRED_DEPENDENCY_LOADING = CLASS_NEEDS_DEFERRED_DEPENDENCY_LOADING => "NeedsDeferredDependencyLoading",
+        COMPILED_FROM_BLUEPRINT = CLASS_COMPILED_FROM_BLUEPRINT => "CompiledFromBlueprint",
+        MINIMAL_API = CLASS_MINIMAL_API => "MinimalAPI",
+        REQUIRED_API = CLASS_REQUIRED_API => "RequiredAPI",
+        DEFAULT_TO_INSTANCED = CLASS_DEFAULT_TO_INSTANCED => "DefaultToInstanced",
+        TOKEN_STREAM_ASSEMBLED = CLASS_TOKEN_STREAM_ASSEMBLED => "TokenStreamAssembled",
+        HAS_INSTANCED_REFERENCE = CLASS_HAS_INSTANCED_REFERENCE => "HasInstancedReference",
+        HIDDEN = CLASS_HIDDEN => "Hidden",
+        DEPRECATED = CLASS_DEPRECATED => "Deprecated",
+        HIDE_DROP_DOWN = CLASS_HIDE_DROP_DOWN => "HideDropDown",
+        GLOBAL_USER_CONFIG = CLASS_GLOBAL_USER_CONFIG => "GlobalUserConfig",
+        INTRINSIC = CLASS_INTRINSIC => "Intrinsic",
+        CONSTRUCTED = CLASS_CONSTRUCTED => "Constructed",
+        CONFIG_DO_NOT_CHECK_DEFAULTS = CLASS_CONFIG_DO_NOT_CHECK_DEFAULTS => "ConfigDoNotCheckDefaults",
+        NEWER_VERSION_EXISTS = CLASS_NEWER_VERSION_EXISTS => "NewerVersionExists",
+    }
+}
+impl PropertyFlags {
+    /// [`CPF_PARM_FLAGS`]
+    pub const PARM_FLAGS: Self = Self(CPF_PARM_FLAGS);
+    /// [`CPF_NATIVE_ACCESS_SPECIFIERS`]
+    pub const NATIVE_ACCESS_SPECIFIERS: Self = Self(CPF_NATIVE_ACCESS_SPECIFIERS);
+}
+
+impl ClassFlags {
+    /// [`CLASS_INHERIT`]
+    pub const INHERIT: Self = Self(CLASS_INHERIT);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display_uses_ue_names() {
+        let f = FunctionFlags::BLUEPRINT_CALLABLE | FunctionFlags::NET | FunctionFlags::CONST;
+        assert_eq!(f.to_string(), "Net | BlueprintCallable | Const");
+        assert_eq!(format!("{:?}", PropertyFlags::empty()), "PropertyFlags(None)");
+        assert_eq!(
+            PropertyFlags::from_bits_retain(CPF_OUT_PARM | 0x0000_0020_0000_0000).to_string(),
+            "OutParm | 0x2000000000"
+        );
+    }
+
+    #[test]
+    fn test_from_bits() {
+        assert_eq!(PropertyFlags::from_bits(CPF_PARM_FLAGS), Some(PropertyFlags::PARM_FLAGS));
+        assert_eq!(PropertyFlags::from_bits(0x0000_0020_0000_0000), None);
+        assert!(PropertyFlags::from_bits_truncate(u64::MAX).unknown_bits() == 0);
+        assert!(ClassFlags::INHERIT.contains(ClassFlags::CONFIG));
+    }
+}
@@ -12,6 +12,7 @@
 //! | `slate`              | Slate UI types                              |
 //! | `umg`                | UMG (Widget) types                          |
 //! | `niagara`            | Niagara particle system types               |
+//! | `gameplay-tags`      | GameplayTags types                          |
 //! | `gameplay-abilities` | Gameplay Ability System types                |
 //! | `level-sequence`     | Level Sequence / Sequencer types            |
 //! | `cinematic`          | Cinematic camera types                      |
@@ -22,7 +23,7 @@ pub use uika_ffi as ffi;
 pub use uika_runtime as runtime;
 pub use uika_ue_flags as ue_flags;
 pub use uika_bindings as bindings;
-pub use uika_macros::{uclass, uclass_impl};
+pub use uika_macros::{uclass, uclass_impl, ustruct};
 
 // For proc macro generated inventory::submit! invocations.
 #[doc(hidden)]
@@ -78,6 +79,10 @@ extern "C" fn real_on_shutdown() {
         runtime::reify_registry::clear_all();
         runtime::delegate_registry::clear_all();
         runtime::pinned::clear_all();
+        runtime::gc::clear_all();
+        runtime::tasks::shutdown();
+        runtime::executor::clear_all();
+        runtime::fname::clear_cache();
     });
 }
 
@@ -87,14 +92,45 @@ extern "C" fn real_notify_pinned_destroyed(handle: ffi::UObjectHandle) {
     });
 }
 
+extern "C" fn real_run_tests(filter: *const u8, filter_len: u32) -> u32 {
+    runtime::ffi_boundary(u32::MAX, || {
+        let filter = if filter.is_null() || filter_len == 0 {
+            ""
+        } else {
+            let bytes = unsafe { std::slice::from_raw_parts(filter, filter_len as usize) };
+            std::str::from_utf8(bytes).unwrap_or("")
+        };
+        runtime::testing::run_tests(filter).failed
+    })
+}
+
+extern "C" fn real_collect_references(report: ffi::UikaReportReferenceFn, ctx: *mut std::ffi::c_void) {
+    runtime::ffi_boundary((), || {
+        runtime::gc::collect_references(&mut |obj| report(ctx, obj));
+    });
+}
+
+extern "C" fn real_tick(delta_seconds: f32) {
+    runtime::ffi_boundary((), || {
+        runtime::tasks::tick(delta_seconds);
+        runtime::executor::tick(delta_seconds);
+        runtime::pinned::tick();
+    });
+}
+
 #[doc(hidden)]
 pub static __CALLBACKS: ffi::UikaRustCallbacks = ffi::UikaRustCallbacks {
+    version: ffi::UIKA_API_VERSION,
+    size: std::mem::size_of::<ffi::UikaRustCallbacks>() as u32,
     drop_rust_instance: real_drop_rust_instance,
     invoke_rust_function: real_invoke_rust_function,
     invoke_delegate_callback: real_invoke_delegate_callback,
     on_shutdown: real_on_shutdown,
     construct_rust_instance: real_construct_rust_instance,
     notify_pinned_destroyed: real_notify_pinned_destroyed,
+    run_tests: real_run_tests,
+    collect_references: real_collect_references,
+    tick: real_tick,
 };
 
 // ---------------------------------------------------------------------------
@@ -104,21 +140,145 @@ pub static __CALLBACKS: ffi::UikaRustCallbacks = ffi::UikaRustCallbacks {
 /// Initialize the Uika runtime. Called by the `entry!()` generated `uika_init`.
 ///
 /// Stores the API table, registers all reified classes, and returns the
-/// callback table pointer. Returns null on failure.
+/// callback table pointer. Returns null on failure, including when the
+/// plugin's generated wrappers don't match the bindings compiled into this
+/// DLL (see [`check_binding_stamp`]).
 pub fn init(api_table: *const ffi::UikaApiTable) -> *const ffi::UikaRustCallbacks {
-    std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+    runtime::ffi_boundary(std::ptr::null(), std::panic::AssertUnwindSafe(|| {
         if api_table.is_null() {
             return std::ptr::null();
         }
 
         // Delegate API table storage to uika-runtime.
-        runtime::init_api(api_table);
+        // SAFETY: the plugin's static GApiTable outlives the DLL.
+        unsafe { runtime::init_api(api_table) };
 
+        if let Err(msg) =
+            check_api_version(runtime::api()).and_then(|()| check_binding_stamp(runtime::api()))
+        {
+            runtime::logging::log_category("LogUika", runtime::logging::LOG_ERROR, &msg);
+            return std::ptr::null();
+        }
+
+        runtime::ffi_guard::install_panic_hook();
+        runtime::logging::install_log_backend();
         log_greeting();
         register_all_classes();
         &__CALLBACKS as *const ffi::UikaRustCallbacks
     }))
-    .unwrap_or(std::ptr::null())
+}
+
+/// Negotiate the API table version. Plugins older than
+/// `UIKA_API_MIN_VERSION` are refused; newer-but-older-than-us plugins are
+/// accepted with the sub-tables they lack disabled (their dispatch wrappers
+/// fail instead of calling through a null pointer).
+fn check_api_version(table: &ffi::UikaApiTable) -> Result<(), String> {
+    if table.version < ffi::UIKA_API_MIN_VERSION {
+        return Err(format!(
+            "[Uika] Plugin API table version {} is older than this DLL supports ({}). \
+             Rebuild the plugin (`uika build --from 3`). Refusing to initialize.",
+            table.version,
+            ffi::UIKA_API_MIN_VERSION
+        ));
+    }
+    if table.version < ffi::UIKA_API_VERSION {
+        let missing = runtime::ffi_dispatch::missing_sub_tables();
+        runtime::logging::log_category(
+            "LogUika",
+            runtime::logging::LOG_WARNING,
+            &format!(
+                "[Uika] Plugin API table version {} is older than this DLL's ({}); \
+                 disabled APIs: {}. Rebuild the plugin (`uika build --from 3`) to enable them.",
+                table.version,
+                ffi::UIKA_API_VERSION,
+                if missing.is_empty() { "none".to_string() } else { missing.join(", ") }
+            ),
+        );
+    }
+    Ok(())
+}
+
+/// Verify that the plugin's generated C++ (UikaFillFuncTable.cpp) and this
+/// DLL's generated Rust (`func_ids.rs`) agree on every FuncId they share.
+///
+/// FuncIds index straight into the plugin's function table and are stable
+/// across regenerations, so the two sides may come from different codegen
+/// runs (e.g. a hot-reloaded DLL with new functions). An id whose signature
+/// differs on the two sides would call the wrong wrapper and is fatal; ids
+/// the plugin doesn't have only fail when called (see `runtime::func_ptr`).
+fn check_binding_stamp(table: &ffi::UikaApiTable) -> Result<(), String> {
+    use bindings::func_ids::{
+        BINDING_CONFIG_HASH, BINDING_ENGINE_VERSION, BINDING_FUNC_SIGS, FUNC_COUNT,
+    };
+
+    let stamp = &table.binding_stamp;
+    let plugin_engine = if stamp.engine_version.is_null() {
+        ""
+    } else {
+        // SAFETY: points at a static string literal in the plugin.
+        let bytes = unsafe {
+            std::slice::from_raw_parts(stamp.engine_version, stamp.engine_version_len as usize)
+        };
+        std::str::from_utf8(bytes).unwrap_or("<invalid>")
+    };
+    if plugin_engine != BINDING_ENGINE_VERSION {
+        return Err(format!(
+            "[Uika] Binding mismatch: the DLL was generated from a UE '{BINDING_ENGINE_VERSION}' \
+             export but the plugin from UE '{plugin_engine}'. Run `uika build --from 2` to \
+             regenerate and rebuild both sides. Refusing to initialize."
+        ));
+    }
+
+    let plugin_sigs: &[u64] = if stamp.func_sigs.is_null() {
+        &[]
+    } else {
+        // SAFETY: the plugin's signature array has func_count entries.
+        unsafe { std::slice::from_raw_parts(stamp.func_sigs, table.func_count as usize) }
+    };
+    let mut conflicts: Vec<usize> = Vec::new();
+    let mut missing = 0usize;
+    for (id, &sig) in BINDING_FUNC_SIGS.iter().enumerate() {
+        let theirs = plugin_sigs.get(id).copied().unwrap_or(0);
+        if sig == 0 {
+            continue;
+        } else if theirs == 0 {
+            missing += 1;
+        } else if theirs != sig {
+            conflicts.push(id);
+        }
+    }
+    if !conflicts.is_empty() {
+        conflicts.truncate(8);
+        return Err(format!(
+            "[Uika] Binding mismatch: FuncIds {conflicts:?} refer to different functions in \
+             the DLL and the plugin (was one side generated with `--renumber`?). Run \
+             `uika build --from 2` to regenerate and rebuild both sides. Refusing to initialize."
+        ));
+    }
+
+    if missing > 0 {
+        runtime::logging::log_category(
+            "LogUika",
+            runtime::logging::LOG_WARNING,
+            &format!(
+                "[Uika] {missing} of the DLL's {FUNC_COUNT} FuncIds are not in the plugin \
+                 ({} functions); calling them panics until the plugin is rebuilt.",
+                table.func_count
+            ),
+        );
+    }
+    if stamp.config_hash != BINDING_CONFIG_HASH {
+        runtime::logging::log_category(
+            "LogUika",
+            runtime::logging::LOG_WARNING,
+            &format!(
+                "[Uika] The DLL and the plugin were generated with different [codegen] \
+                 configs (DLL {BINDING_CONFIG_HASH:016x}, plugin {:016x}).",
+                stamp.config_hash
+            ),
+        );
+    }
+    Ok(())
 }
 
 /// Build a greeting string listing all compiled feature flags.
@@ -134,7 +294,7 @@ fn build_feature_greeting(prefix: &str) -> String {
     let mut s = format!("[Uika] {} (features:", prefix);
     collect_features!(s,
         "core", "engine", "physics-core", "input", "slate", "umg",
-        "niagara", "gameplay-abilities", "level-sequence", "cinematic", "movie"
+        "niagara", "gameplay-tags", "gameplay-abilities", "level-sequence", "cinematic", "movie"
     );
     s.push(')');
     s
@@ -156,11 +316,40 @@ fn register_all_classes() {
 
 /// Shut down the Uika runtime. Called by the `entry!()` generated `uika_shutdown`.
 pub fn shutdown() {
-    let _ = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+    runtime::ffi_boundary((), std::panic::AssertUnwindSafe(|| {
         (__CALLBACKS.on_shutdown)();
     }));
 }
 
+/// Register a function as an in-engine test.
+///
+/// The function takes no arguments and returns `UikaResult<()>`; an `Err` or
+/// a panic fails the test. Registered tests run inside UE via the
+/// `Uika.RunTests [filter]` console command, the `UikaTestRunner`
+/// commandlet, or `uika test` from a terminal.
+///
+/// ```ignore
+/// fn fname_round_trip() -> UikaResult<()> {
+///     let name = FName::new("PlayerStart");
+///     assert_eq!(name.to_string_lossy(), "PlayerStart");
+///     Ok(())
+/// }
+/// uika::ue_test!(fname_round_trip);
+/// ```
+#[macro_export]
+macro_rules! ue_test {
+    ($($test:ident),+ $(,)?) => {
+        $(
+            $crate::__inventory::submit! {
+                $crate::runtime::testing::TestCase {
+                    name: concat!(module_path!(), "::", stringify!($test)),
+                    run: $test,
+                }
+            }
+        )+
+    };
+}
+
 /// Generates DLL exports for the Uika runtime entry points.
 ///
 /// Place this at the top of your cdylib crate's `lib.rs`:
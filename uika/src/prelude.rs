@@ -4,24 +4,26 @@
 
 // Core runtime types
 pub use uika_runtime::{
-    UObjectRef, Pinned, UikaResult, UikaError, UeClass, UeStruct, UeEnum,
+    UObjectRef, ComponentRef, ClassRef, Pinned, UikaResult, UikaResultExt, UikaError, UeClass, UeStruct, UeEnum,
     OwnedStruct, UStructRef, UeArray, UeMap, UeSet,
-    DynamicCall, DynamicCallResult, DelegateBinding,
-    FName, TWeakObjectPtr,
-    LOG_DISPLAY, LOG_WARNING, LOG_ERROR,
+    DynamicCall, DynamicCallResult, DelegateBinding, DelegateParams,
+    FName, FText, GameplayTag, GameplayTagContainer, TWeakObjectPtr,
+    SoftObjectPtr, SoftClassPtr,
+    ActorLifecycle, EndPlayReason, HitResult,
+    LOG_DISPLAY, LOG_WARNING, LOG_ERROR, LOG_LOG, LOG_VERBOSE, LOG_VERY_VERBOSE,
 };
 
 // UE math types (uika-runtime)
 pub use uika_runtime::{
     Rotator, Transform, LinearColor, Color,
-    Plane, Ray, Sphere, UeBox, UeBox2d, BoxSphereBounds,
+    Plane, Ray, Sphere, UeBox, UeBox2d, BoxSphereBounds, IntRect, Guid, GuidFormat,
 };
 
 // FFI handles (rarely needed directly, but useful for advanced cases)
 pub use uika_runtime::{UObjectHandle, UClassHandle, FPropertyHandle, UStructHandle, FNameHandle};
 
 // Proc macros
-pub use uika_macros::{uclass, uclass_impl};
+pub use uika_macros::{uclass, uclass_impl, ustruct};
 
 // glam re-exports (common math types users will interact with)
 pub use glam::{DVec2, DVec3, DVec4, DQuat, DMat4, IVec2, IVec3};
@@ -39,6 +41,10 @@ pub use uika_bindings::core_ue::{
     FColor, FColorExt,
     FPlane, FPlaneExt,
     FBox2D, FBox2DExt,
+    FBox, FBoxExt,
+    FMatrix, FMatrixExt,
+    FIntVector, FIntVectorExt,
+    FIntPoint, FIntPointExt,
 };
 
 // Manual conversion traits (feature-gated)
@@ -62,6 +68,18 @@ pub use uika_bindings::manual::color::OwnedFColorExt;
 pub use uika_bindings::manual::plane::OwnedFPlaneExt;
 #[cfg(feature = "core")]
 pub use uika_bindings::manual::ue_box2d::OwnedFBox2DExt;
+#[cfg(feature = "core")]
+pub use uika_bindings::manual::ue_box::OwnedFBoxExt;
+#[cfg(feature = "core")]
+pub use uika_bindings::manual::matrix::OwnedFMatrixExt;
+#[cfg(feature = "core")]
+pub use uika_bindings::manual::int_vector::OwnedFIntVectorExt;
+#[cfg(feature = "core")]
+pub use uika_bindings::manual::int_point::OwnedFIntPointExt;
+#[cfg(feature = "core")]
+pub use uika_bindings::manual::guid::OwnedFGuidExt;
+#[cfg(feature = "gameplay-tags")]
+pub use uika_bindings::manual::gameplay_tags::{OwnedFGameplayTagExt, OwnedFGameplayTagContainerExt};
 
 // Engine types (feature-gated)
 #[cfg(feature = "engine")]
@@ -76,3 +94,5 @@ pub use uika_bindings::manual::fkey::FKeyExt;
 // World spawn/query extensions (feature-gated)
 #[cfg(feature = "engine")]
 pub use uika_bindings::manual::world_ext::{WorldSpawnExt, find_object, load_object};
+#[cfg(feature = "engine")]
+pub use uika_bindings::manual::hit_result::OwnedFHitResultExt;